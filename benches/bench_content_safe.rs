@@ -0,0 +1,42 @@
+#![feature(test)]
+
+#[cfg(test)]
+mod benches {
+    extern crate test;
+
+    use self::test::Bencher;
+    use serenity::cache::Cache;
+    use serenity::utils::{content_safe, ContentSafeOptions};
+
+    const PLAIN: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do \
+        eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+
+    const MENTIONS: &str = "<@100000000000000000> said to <@!200000000000000000> in \
+        <#300000000000000000> that <@&400000000000000000> and @everyone should read \
+        <#300000000000000000>, but <@100000000000000000> is @here.";
+
+    #[bench]
+    fn content_safe_plain(b: &mut Bencher) {
+        let cache = Cache::default();
+        let options = ContentSafeOptions::default();
+
+        b.iter(|| content_safe(&cache, PLAIN, &options))
+    }
+
+    #[bench]
+    fn content_safe_mentions(b: &mut Bencher) {
+        let cache = Cache::default();
+        let options = ContentSafeOptions::default();
+
+        b.iter(|| content_safe(&cache, MENTIONS, &options))
+    }
+
+    #[bench]
+    fn content_safe_long_mentions(b: &mut Bencher) {
+        let cache = Cache::default();
+        let options = ContentSafeOptions::default();
+        let content = MENTIONS.repeat(16);
+
+        b.iter(|| content_safe(&cache, &content, &options))
+    }
+}
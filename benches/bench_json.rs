@@ -0,0 +1,44 @@
+#![feature(test)]
+
+#[cfg(test)]
+mod benches {
+    extern crate test;
+
+    use self::test::Bencher;
+    use serde_json::Value;
+    use serenity::model::event::GuildCreateEvent;
+
+    const GUILD_CREATE: &[u8] = include_bytes!("../tests/resources/guild_create_1.json");
+
+    #[bench]
+    fn serde_json_guild_create(b: &mut Bencher) {
+        b.iter(|| {
+            serde_json::from_slice::<GuildCreateEvent>(GUILD_CREATE).unwrap();
+        })
+    }
+
+    #[bench]
+    fn serde_json_guild_create_value(b: &mut Bencher) {
+        b.iter(|| {
+            serde_json::from_slice::<Value>(GUILD_CREATE).unwrap();
+        })
+    }
+
+    #[cfg(feature = "simd_json")]
+    #[bench]
+    fn simd_json_guild_create(b: &mut Bencher) {
+        b.iter(|| {
+            let mut bytes = GUILD_CREATE.to_vec();
+            simd_json::from_slice::<GuildCreateEvent>(&mut bytes).unwrap();
+        })
+    }
+
+    #[cfg(feature = "simd_json")]
+    #[bench]
+    fn simd_json_guild_create_value(b: &mut Bencher) {
+        b.iter(|| {
+            let mut bytes = GUILD_CREATE.to_vec();
+            simd_json::from_slice::<Value>(&mut bytes).unwrap();
+        })
+    }
+}
@@ -355,4 +355,4 @@ macro_rules! attr_option_num {
     }
 }
 
-attr_option_num!(u16, u32, usize);
+attr_option_num!(u16, u32, u64, usize);
@@ -701,6 +701,7 @@ pub fn group(attr: TokenStream, input: TokenStream) -> TokenStream {
 /// | `#[name(s)]` </br> `#[name = s]`                   | How the check should be listed in help.                                  | `s` is a string. If this option isn't provided, the value is assumed to be `"<fn>"`. |
 /// | `#[display_in_help]` </br> `#[display_in_help(b)]` | If the check should be listed in help. Has no effect on `check_in_help`. | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.      |
 /// | `#[check_in_help]` </br> `#[check_in_help(b)]`     | If the check should be evaluated in help.                                | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.      |
+/// | `#[cache_result(s)]`                               | For how long the result is reused per user and guild.                    | `s` is a number of seconds.                                                          |
 #[proc_macro_attribute]
 pub fn check(_attr: TokenStream, input: TokenStream) -> TokenStream {
     let mut fun = parse_macro_input!(input as CommandFun);
@@ -709,6 +710,7 @@ pub fn check(_attr: TokenStream, input: TokenStream) -> TokenStream {
     let mut name = "<fn>".to_string();
     let mut display_in_help = true;
     let mut check_in_help = true;
+    let mut cache_result: Option<u64> = None;
 
     for attribute in &fun.attributes {
         let span = attribute.span();
@@ -721,6 +723,7 @@ pub fn check(_attr: TokenStream, input: TokenStream) -> TokenStream {
             "name" => name = propagate_err!(attributes::parse(values)),
             "display_in_help" => display_in_help = propagate_err!(attributes::parse(values)),
             "check_in_help" => check_in_help = propagate_err!(attributes::parse(values)),
+            "cache_result" => cache_result = propagate_err!(attributes::parse(values)),
             _ => {
                 return Error::new(span, format_args!("invalid attribute: {:?}", n))
                     .to_compile_error()
@@ -744,6 +747,10 @@ pub fn check(_attr: TokenStream, input: TokenStream) -> TokenStream {
     let name = name.with_suffix(CHECK);
 
     let check = quote!(serenity::framework::standard::Check);
+    let cache_result = match cache_result {
+        Some(secs) => quote!(Some(std::time::Duration::from_secs(#secs))),
+        None => quote!(None),
+    };
     let struct_name = format_ident!("_{}", n);
     let struct_name_upper = struct_name.to_uppercase();
 
@@ -754,7 +761,8 @@ pub fn check(_attr: TokenStream, input: TokenStream) -> TokenStream {
             name: #n2,
             function: &#struct_name_upper,
             display_in_help: #display_in_help,
-            check_in_help: #check_in_help
+            check_in_help: #check_in_help,
+            cache_result: #cache_result
         };
 
         #fun
@@ -330,7 +330,7 @@ async fn say(ctx: &mut Context, msg: &Message, args: Args) -> CommandResult {
     };
 
     let rest = &args.rest();
-    let content = content_safe(&ctx.cache, rest, &settings).await;
+    let content = content_safe(&ctx.cache, rest, &settings);
 
     if let Err(why) = msg.channel_id.say(&ctx.http, &content).await {
         println!("Error sending message: {:?}", why);
@@ -376,8 +376,8 @@ async fn owner_check(_ctx: &mut Context, msg: &Message, _: &mut Args, _: &'stati
 // Whether the check shall be displayed in the help-system.
 #[display_in_help(true)]
 async fn admin_check(ctx: &mut Context, msg: &Message, _: &mut Args, _: &'static CommandOptions) -> CheckResult {
-    if let Some(member) = msg.member(&ctx.cache).await {
-        if let Ok(permissions) = member.permissions(&ctx.cache).await {
+    if let Some(member) = msg.member(&ctx.cache) {
+        if let Ok(permissions) = member.permissions(&ctx.cache) {
             return permissions.administrator().into();
         }
     }
@@ -401,10 +401,10 @@ async fn some_long_command(ctx: &mut Context, msg: &Message, args: Args) -> Comm
 async fn about_role(ctx: &mut Context, msg: &Message, args: Args) -> CommandResult {
     let potential_role_name = args.rest();
 
-    if let Some(guild) = msg.guild(&ctx.cache).await {
+    if let Some(guild) = msg.guild(&ctx.cache) {
         // `role_by_name()` allows us to attempt attaining a reference to a role
         // via its name.
-        if let Some(role) = guild.read().await.role_by_name(&potential_role_name) {
+        if let Some(role) = guild.role_by_name(&potential_role_name) {
             let res = format!("Role-ID: {}", role.id);
             if let Err(why) = msg.channel_id.say(&ctx.http, &res).await {
                 println!("Error sending message: {:?}", why);
@@ -562,8 +562,8 @@ async fn slow_mode(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandR
         } else {
             format!("Successfully set slow mode rate to `{}` seconds.", slow_mode_rate_seconds)
         }
-    } else if let Some(Channel::Guild(channel)) = msg.channel_id.to_channel_cached(&ctx.cache).await {
-        format!("Current slow mode rate is `{}` seconds.", channel.read().await.slow_mode_rate.unwrap_or(0))
+    } else if let Some(Channel::Guild(channel)) = msg.channel_id.to_channel_cached(&ctx.cache) {
+        format!("Current slow mode rate is `{}` seconds.", channel.slow_mode_rate.unwrap_or(0))
     } else {
         "Failed to find channel in cache.".to_string()
     };
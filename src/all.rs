@@ -0,0 +1,58 @@
+//! Every public item of the library, re-exported in one place, so that a
+//! single glob import can replace many deeply nested ones.
+//!
+//! Where items of different modules share a name, the one from the [`model`]
+//! module is exported: for example, [`Timestamp`] is the model type rather
+//! than the [`builder::Timestamp`] used by embeds, which can still be
+//! imported from its own module.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use async_trait::async_trait;
+//! use serenity::all::*;
+//!
+//! struct Handler;
+//!
+//! #[async_trait]
+//! impl EventHandler for Handler {
+//!     async fn message(&self, ctx: Context, msg: Message) {
+//!         if msg.content == "!ping" {
+//!             let _ = msg.channel_id.say(&ctx, "Pong!").await;
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`builder::Timestamp`]: ../builder/struct.Timestamp.html
+//! [`model`]: ../model/index.html
+//! [`Timestamp`]: ../model/timestamp/struct.Timestamp.html
+
+#[cfg(feature = "builder")]
+pub use crate::builder::*;
+#[cfg(feature = "cache")]
+pub use crate::cache::*;
+#[cfg(feature = "client")]
+pub use crate::client::bridge::gateway::*;
+#[cfg(feature = "client")]
+pub use crate::client::*;
+#[cfg(feature = "standard_framework")]
+pub use crate::framework::standard::macros::*;
+#[cfg(feature = "standard_framework")]
+pub use crate::framework::standard::*;
+#[cfg(feature = "framework")]
+pub use crate::framework::*;
+#[cfg(feature = "gateway")]
+pub use crate::gateway::*;
+#[cfg(feature = "http")]
+pub use crate::http::*;
+pub use crate::model::prelude::*;
+// Names which the globs above export more than once.
+pub use crate::model::{error, event, Timestamp};
+pub use crate::prelude::*;
+#[cfg(feature = "utils")]
+pub use crate::utils::*;
+#[cfg(feature = "voice")]
+pub use crate::voice::*;
+#[cfg(feature = "http")]
+pub use crate::CacheAndHttp;
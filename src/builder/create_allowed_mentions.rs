@@ -0,0 +1,171 @@
+use crate::internal::prelude::*;
+use crate::model::id::{RoleId, UserId};
+
+use std::collections::HashMap;
+
+/// A type of mention which Discord parses from a message's content, for use
+/// with [`CreateAllowedMentions::parse`].
+///
+/// [`CreateAllowedMentions::parse`]: struct.CreateAllowedMentions.html#method.parse
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ParseValue {
+    /// `@everyone` and `@here` mentions.
+    Everyone,
+    /// Mentions of users.
+    Users,
+    /// Mentions of roles.
+    Roles,
+}
+
+impl ParseValue {
+    fn name(self) -> &'static str {
+        match self {
+            ParseValue::Everyone => "everyone",
+            ParseValue::Users => "users",
+            ParseValue::Roles => "roles",
+        }
+    }
+}
+
+/// A builder to restrict which mentions in a message's content notify their
+/// targets, for use with [`CreateMessage::allowed_mentions`],
+/// [`EditMessage::allowed_mentions`] and [`ExecuteWebhook::allowed_mentions`].
+///
+/// Once allowed mentions are specified, nothing is mentioned unless it is
+/// allowed, either by a type of mention being [`parse`]d or by its Id being
+/// listed. This prevents user-provided content from pinging `@everyone` or
+/// arbitrary roles, which [`content_safe`] alone can not guarantee.
+///
+/// **Note**: Discord rejects a message which both parses a type of mention
+/// and lists Ids of that type.
+///
+/// # Examples
+///
+/// Only mention a single user, even if the content mentions others:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use std::sync::Arc;
+/// #
+/// # let http = Arc::new(Http::default());
+/// use serenity::model::id::{ChannelId, UserId};
+///
+/// let _ = ChannelId(7).send_message(&http, |m| {
+///     m.content("Hello <@1>, <@2> and @everyone")
+///         .allowed_mentions(|am| am.users(vec![UserId(1)]))
+/// });
+/// ```
+///
+/// [`CreateMessage::allowed_mentions`]: struct.CreateMessage.html#method.allowed_mentions
+/// [`EditMessage::allowed_mentions`]: struct.EditMessage.html#method.allowed_mentions
+/// [`ExecuteWebhook::allowed_mentions`]: struct.ExecuteWebhook.html#method.allowed_mentions
+/// [`content_safe`]: ../utils/fn.content_safe.html
+/// [`parse`]: #method.parse
+#[derive(Clone, Debug)]
+pub struct CreateAllowedMentions(pub HashMap<&'static str, Value>);
+
+impl Default for CreateAllowedMentions {
+    fn default() -> Self {
+        let mut map = HashMap::new();
+        map.insert("parse", Value::Array(Vec::new()));
+
+        CreateAllowedMentions(map)
+    }
+}
+
+impl CreateAllowedMentions {
+    /// Allows all mentions of a type to notify their targets.
+    pub fn parse(&mut self, value: ParseValue) -> &mut Self {
+        let name = Value::String(value.name().to_string());
+        let parse = self
+            .0
+            .entry("parse")
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+        if let Value::Array(parse) = parse {
+            if !parse.contains(&name) {
+                parse.push(name);
+            }
+        }
+
+        self
+    }
+
+    /// Clears the types of mentions allowed via [`parse`].
+    ///
+    /// [`parse`]: #method.parse
+    pub fn empty_parse(&mut self) -> &mut Self {
+        self.0.insert("parse", Value::Array(Vec::new()));
+        self
+    }
+
+    /// Sets the users which may be notified if the content mentions them.
+    ///
+    /// Discord allows at most 100 users to be listed.
+    pub fn users<U: Into<UserId>, It: IntoIterator<Item = U>>(&mut self, users: It) -> &mut Self {
+        let users = users
+            .into_iter()
+            .map(|user| Value::String(user.into().0.to_string()))
+            .collect();
+
+        self.0.insert("users", Value::Array(users));
+        self
+    }
+
+    /// Sets the roles which may be notified if the content mentions them.
+    ///
+    /// Discord allows at most 100 roles to be listed.
+    pub fn roles<R: Into<RoleId>, It: IntoIterator<Item = R>>(&mut self, roles: It) -> &mut Self {
+        let roles = roles
+            .into_iter()
+            .map(|role| Value::String(role.into().0.to_string()))
+            .collect();
+
+        self.0.insert("roles", Value::Array(roles));
+        self
+    }
+
+    /// Sets whether the author of the message being replied to is notified.
+    ///
+    /// Defaults to `false`.
+    pub fn replied_user(&mut self, mention: bool) -> &mut Self {
+        self.0.insert("replied_user", Value::Bool(mention));
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CreateAllowedMentions, ParseValue};
+    use crate::model::id::{RoleId, UserId};
+    use crate::utils;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_allowed_mentions() {
+        let mut builder = CreateAllowedMentions::default();
+        builder
+            .parse(ParseValue::Everyone)
+            .parse(ParseValue::Everyone)
+            .parse(ParseValue::Roles)
+            .users(vec![UserId(1), UserId(2)])
+            .replied_user(true);
+
+        let value = Value::Object(utils::hashmap_to_json_map(builder.0.clone()));
+        assert_eq!(
+            value,
+            json!({
+                "parse": ["everyone", "roles"],
+                "users": ["1", "2"],
+                "replied_user": true,
+            })
+        );
+
+        builder.empty_parse().roles(vec![RoleId(3)]);
+
+        let value = Value::Object(utils::hashmap_to_json_map(builder.0));
+        assert_eq!(value["parse"], json!([]));
+        assert_eq!(value["roles"], json!(["3"]));
+    }
+}
@@ -0,0 +1,181 @@
+use crate::internal::prelude::*;
+use crate::model::interactions::ApplicationCommandOptionType;
+use crate::utils;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// A builder to create or edit an [`ApplicationCommand`].
+///
+/// Refer to [`ApplicationCommand::create_global_command`] for an example.
+///
+/// [`ApplicationCommand`]: ../model/interactions/struct.ApplicationCommand.html
+/// [`ApplicationCommand::create_global_command`]: ../model/interactions/struct.ApplicationCommand.html#method.create_global_command
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommand(pub HashMap<&'static str, Value>);
+
+impl CreateApplicationCommand {
+    /// The name of the command, 1 to 32 lowercase characters.
+    ///
+    /// **Note**: This field is required when creating a command.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// The description of the command, 1 to 100 characters.
+    ///
+    /// **Note**: This field is required when creating a command.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0
+            .insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Adds an option to the command.
+    pub fn create_option<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateApplicationCommandOption) -> &mut CreateApplicationCommandOption,
+    {
+        let mut option = CreateApplicationCommandOption::default();
+        f(&mut option);
+
+        push_option(&mut self.0, option);
+        self
+    }
+}
+
+/// A builder for an option of a [`CreateApplicationCommand`].
+///
+/// [`CreateApplicationCommand`]: struct.CreateApplicationCommand.html
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommandOption(pub HashMap<&'static str, Value>);
+
+impl CreateApplicationCommandOption {
+    /// The type of the option.
+    ///
+    /// **Note**: This field is required.
+    pub fn kind(&mut self, kind: ApplicationCommandOptionType) -> &mut Self {
+        self.0
+            .insert("type", Value::Number(Number::from(kind.num())));
+        self
+    }
+
+    /// The name of the option, 1 to 32 lowercase characters.
+    ///
+    /// **Note**: This field is required.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// The description of the option, 1 to 100 characters.
+    ///
+    /// **Note**: This field is required.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0
+            .insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Whether the option must be given.
+    ///
+    /// Defaults to `false`.
+    pub fn required(&mut self, required: bool) -> &mut Self {
+        self.0.insert("required", Value::Bool(required));
+        self
+    }
+
+    /// Adds a choice to a [`String`] option.
+    ///
+    /// [`String`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.String
+    pub fn add_string_choice<D: ToString, V: ToString>(&mut self, name: D, value: V) -> &mut Self {
+        self.add_choice(json!({
+            "name": name.to_string(),
+            "value": value.to_string(),
+        }))
+    }
+
+    /// Adds a choice to an [`Integer`] option.
+    ///
+    /// [`Integer`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.Integer
+    pub fn add_int_choice<D: ToString>(&mut self, name: D, value: i64) -> &mut Self {
+        self.add_choice(json!({
+            "name": name.to_string(),
+            "value": value,
+        }))
+    }
+
+    fn add_choice(&mut self, choice: Value) -> &mut Self {
+        let choices = self
+            .0
+            .entry("choices")
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+        if let Value::Array(choices) = choices {
+            choices.push(choice);
+        }
+
+        self
+    }
+
+    /// Adds an option to a [`SubCommand`] or [`SubCommandGroup`] option.
+    ///
+    /// [`SubCommand`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.SubCommand
+    /// [`SubCommandGroup`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.SubCommandGroup
+    pub fn create_sub_option<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateApplicationCommandOption) -> &mut CreateApplicationCommandOption,
+    {
+        let mut option = CreateApplicationCommandOption::default();
+        f(&mut option);
+
+        push_option(&mut self.0, option);
+        self
+    }
+}
+
+fn push_option(map: &mut HashMap<&'static str, Value>, option: CreateApplicationCommandOption) {
+    let option = Value::Object(utils::hashmap_to_json_map(option.0));
+    let options = map
+        .entry("options")
+        .or_insert_with(|| Value::Array(Vec::new()));
+
+    if let Value::Array(options) = options {
+        options.push(option);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_build_command() {
+        let mut command = CreateApplicationCommand::default();
+        command
+            .name("roll")
+            .description("Rolls a die")
+            .create_option(|o| {
+                o.kind(ApplicationCommandOptionType::Integer)
+                    .name("sides")
+                    .description("The number of sides")
+                    .required(true)
+                    .add_int_choice("six", 6)
+            });
+
+        assert_eq!(
+            Value::Object(utils::hashmap_to_json_map(command.0)),
+            json!({
+                "name": "roll",
+                "description": "Rolls a die",
+                "options": [{
+                    "type": 4,
+                    "name": "sides",
+                    "description": "The number of sides",
+                    "required": true,
+                    "choices": [{"name": "six", "value": 6}],
+                }],
+            }),
+        );
+    }
+}
@@ -13,7 +13,7 @@ use std::collections::HashMap;
 /// [`Guild`]: ../model/guild/struct.Guild.html
 /// [`name`]: #method.name
 #[derive(Debug, Clone)]
-pub struct CreateChannel(pub HashMap<&'static str, Value>);
+pub struct CreateChannel(pub HashMap<&'static str, Value>, pub Option<String>);
 
 impl CreateChannel {
     /// Specify how to call this new channel.
@@ -27,7 +27,7 @@ impl CreateChannel {
     /// Specify what type the channel is, whether it's a text, voice, category or news channel.
     pub fn kind(&mut self, kind: ChannelType) -> &mut Self {
         self.0
-            .insert("type", Value::Number(Number::from(kind as u8)));
+            .insert("type", Value::Number(Number::from(kind.num())));
 
         self
     }
@@ -130,6 +130,13 @@ impl CreateChannel {
 
         self
     }
+
+    /// Sets the reason to show in the guild's audit log for this change.
+    pub fn audit_log_reason<D: ToString>(&mut self, reason: D) -> &mut Self {
+        self.1 = Some(reason.to_string());
+
+        self
+    }
 }
 
 impl Default for CreateChannel {
@@ -145,7 +152,7 @@ impl Default for CreateChannel {
     /// let channel_builder = CreateChannel::default();
     /// ```
     fn default() -> Self {
-        let mut builder = CreateChannel(HashMap::new());
+        let mut builder = CreateChannel(HashMap::new(), None);
         builder.kind(ChannelType::Text);
 
         builder
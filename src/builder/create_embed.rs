@@ -19,12 +19,13 @@ use crate::internal::prelude::*;
 use crate::model::channel::Embed;
 use crate::utils;
 
-use chrono::{DateTime, TimeZone};
 use serde_json::{json, Value};
 
 use std::collections::HashMap;
 use std::fmt::Display;
 
+use chrono::{DateTime, TimeZone};
+
 #[cfg(feature = "utils")]
 use crate::utils::Colour;
 
@@ -195,7 +196,10 @@ impl CreateEmbed {
         self
     }
 
-    /// Set the image associated with the embed. This only supports HTTP(S).
+    /// Set the image associated with the embed. This only supports HTTP(S),
+    /// or an attached file as described in [`attachment`].
+    ///
+    /// [`attachment`]: #method.attachment
     #[inline]
     pub fn image<S: ToString>(&mut self, url: S) -> &mut Self {
         self.url_object("image", url.to_string());
@@ -203,7 +207,10 @@ impl CreateEmbed {
         self
     }
 
-    /// Set the thumbnail of the embed. This only supports HTTP(S).
+    /// Set the thumbnail of the embed. This only supports HTTP(S), or an
+    /// attached file as described in [`attachment_thumbnail`].
+    ///
+    /// [`attachment_thumbnail`]: #method.attachment_thumbnail
     #[inline]
     pub fn thumbnail<S: ToString>(&mut self, url: S) -> &mut Self {
         self.url_object("thumbnail", url.to_string());
@@ -220,8 +227,9 @@ impl CreateEmbed {
     ///
     /// This timestamp must be in ISO-8601 format. It must also be in UTC format.
     ///
-    /// You can also pass an instance of `chrono::DateTime<Utc>`,
-    /// which will construct the timestamp string out of it.
+    /// You can also pass a [`model::Timestamp`], or an instance of
+    /// `chrono::DateTime<Utc>` when the `chrono` feature is enabled, which
+    /// will construct the timestamp string out of it.
     ///
     /// # Examples
     ///
@@ -278,8 +286,6 @@ impl CreateEmbed {
     /// #[async_trait]
     /// impl EventHandler for Handler {
     ///     async fn guild_member_addition(&self, context: Context, guild_id: GuildId, member: Member) {
-    ///         let cache = context.cache.read();
-    ///
     ///         if let Ok(guild) = guild_id.to_partial_guild(&context).await {
     ///             let channels = guild.channels(&context).await.unwrap();
     ///
@@ -287,7 +293,7 @@ impl CreateEmbed {
     ///                 .find(|c| c.name == "join-log");
     ///
     ///             if let Some(channel) = channel_search {
-    ///                 let user = &member.user.read();
+    ///                 let user = &member.user;
     ///
     ///                 let _ = channel.send_message(&context, |m| {
     ///                     m.embed(|e| {
@@ -316,6 +322,8 @@ impl CreateEmbed {
     /// # #[cfg(not(all(feature = "cache", feature = "client")))]
     /// # fn main() {}
     /// ```
+    ///
+    /// [`model::Timestamp`]: ../model/timestamp/struct.Timestamp.html
     #[inline]
     pub fn timestamp<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
         self._timestamp(timestamp.into());
@@ -342,20 +350,61 @@ impl CreateEmbed {
 
     /// Same as calling [`image`] with "attachment://filename.(jpg, png)".
     ///
-    /// Note however, you have to be sure you set an attachment (with [`ChannelId::send_files`])
-    /// with the provided filename. Or else this won't work.
+    /// A file with the provided filename must be attached to the message, such
+    /// as with [`CreateMessage::add_file`], or else sending the message
+    /// returns a [`ModelError::MissingAttachment`].
+    ///
+    /// # Examples
     ///
-    /// [`ChannelId::send_files`]: ../model/id/struct.ChannelId.html#send_files
+    /// Send a locally generated image inside an embed:
     ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use std::sync::Arc;
+    /// #
+    /// # let http = Arc::new(Http::default());
+    /// use serenity::http::AttachmentType;
+    /// use serenity::model::id::ChannelId;
+    ///
+    /// let chart: Vec<u8> = vec![/* PNG data */];
+    ///
+    /// let _ = ChannelId(7).send_message(&http, |m| {
+    ///     m.add_file(AttachmentType::Bytes {
+    ///         data: chart.into(),
+    ///         filename: "chart.png".to_string(),
+    ///     });
+    ///     m.embed(|e| e.title("Activity").attachment("chart.png"))
+    /// });
+    /// ```
+    ///
+    /// [`CreateMessage::add_file`]: struct.CreateMessage.html#method.add_file
+    /// [`ModelError::MissingAttachment`]: ../model/error/enum.Error.html#variant.MissingAttachment
     /// [`image`]: #method.image
     #[inline]
     pub fn attachment<S: ToString>(&mut self, filename: S) -> &mut Self {
-        let mut filename = filename.to_string();
-        filename.insert_str(0, "attachment://");
-        self.url_object("image", filename);
+        self.url_object("image", attachment_url(filename.to_string()));
 
         self
     }
+
+    /// Same as calling [`thumbnail`] with "attachment://filename.(jpg, png)".
+    ///
+    /// Refer to [`attachment`] for the requirements on the attached file.
+    ///
+    /// [`attachment`]: #method.attachment
+    /// [`thumbnail`]: #method.thumbnail
+    #[inline]
+    pub fn attachment_thumbnail<S: ToString>(&mut self, filename: S) -> &mut Self {
+        self.url_object("thumbnail", attachment_url(filename.to_string()));
+
+        self
+    }
+}
+
+fn attachment_url(mut filename: String) -> String {
+    filename.insert_str(0, "attachment://");
+
+    filename
 }
 
 impl Default for CreateEmbed {
@@ -510,6 +559,22 @@ impl<'a> From<&'a str> for Timestamp {
     }
 }
 
+impl From<crate::model::Timestamp> for Timestamp {
+    fn from(timestamp: crate::model::Timestamp) -> Self {
+        Self {
+            ts: timestamp.to_rfc3339(),
+        }
+    }
+}
+
+impl<'a> From<&'a crate::model::Timestamp> for Timestamp {
+    fn from(timestamp: &'a crate::model::Timestamp) -> Self {
+        Self {
+            ts: timestamp.to_rfc3339(),
+        }
+    }
+}
+
 impl<'a, Tz: TimeZone> From<&'a DateTime<Tz>> for Timestamp
 where
     Tz::Offset: Display,
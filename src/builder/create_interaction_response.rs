@@ -0,0 +1,149 @@
+use super::CreateEmbed;
+use crate::internal::prelude::*;
+use crate::model::interactions::InteractionResponseType;
+use crate::utils;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The flag marking a response as only visible to the user who invoked the
+/// interaction.
+const EPHEMERAL: u64 = 1 << 6;
+
+/// A builder to respond to an [`Interaction`].
+///
+/// Refer to [`Interaction::create_interaction_response`] for an example.
+///
+/// [`Interaction`]: ../model/interactions/struct.Interaction.html
+/// [`Interaction::create_interaction_response`]: ../model/interactions/struct.Interaction.html#method.create_interaction_response
+#[derive(Clone, Debug)]
+pub struct CreateInteractionResponse(pub HashMap<&'static str, Value>);
+
+impl CreateInteractionResponse {
+    /// The type of the response.
+    ///
+    /// Defaults to [`ChannelMessageWithSource`].
+    ///
+    /// [`ChannelMessageWithSource`]: ../model/interactions/enum.InteractionResponseType.html#variant.ChannelMessageWithSource
+    pub fn kind(&mut self, kind: InteractionResponseType) -> &mut Self {
+        self.0
+            .insert("type", Value::Number(Number::from(kind.num())));
+        self
+    }
+
+    /// Sets the message to respond with.
+    pub fn interaction_response_data<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateInteractionResponseData) -> &mut CreateInteractionResponseData,
+    {
+        let mut data = CreateInteractionResponseData::default();
+        f(&mut data);
+
+        self.0
+            .insert("data", Value::Object(utils::hashmap_to_json_map(data.0)));
+        self
+    }
+}
+
+impl Default for CreateInteractionResponse {
+    /// Creates a builder responding with a message, with no content set.
+    fn default() -> CreateInteractionResponse {
+        let mut map = HashMap::new();
+        map.insert(
+            "type",
+            Value::Number(Number::from(
+                InteractionResponseType::ChannelMessageWithSource.num(),
+            )),
+        );
+
+        CreateInteractionResponse(map)
+    }
+}
+
+/// A builder for the message of an interaction response, which is also used
+/// to edit the response and to send follow-up messages.
+#[derive(Clone, Debug, Default)]
+pub struct CreateInteractionResponseData(pub HashMap<&'static str, Value>);
+
+impl CreateInteractionResponseData {
+    /// Sets the content of the message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    pub fn content<D: ToString>(&mut self, content: D) -> &mut Self {
+        self.0.insert("content", Value::String(content.to_string()));
+        self
+    }
+
+    /// Adds an embed to the message, up to 10 of which may be sent.
+    pub fn embed<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed,
+    {
+        let mut embed = CreateEmbed::default();
+        f(&mut embed);
+        let embed = Value::Object(utils::hashmap_to_json_map(embed.0));
+
+        let embeds = self
+            .0
+            .entry("embeds")
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+        if let Value::Array(embeds) = embeds {
+            embeds.push(embed);
+        }
+
+        self
+    }
+
+    /// Set whether the message is text-to-speech.
+    ///
+    /// Defaults to `false`.
+    pub fn tts(&mut self, tts: bool) -> &mut Self {
+        self.0.insert("tts", Value::Bool(tts));
+        self
+    }
+
+    /// Set whether the message is only visible to the user who invoked the
+    /// interaction.
+    ///
+    /// Defaults to `false`.
+    pub fn ephemeral(&mut self, ephemeral: bool) -> &mut Self {
+        let flags = self.0.get("flags").and_then(Value::as_u64).unwrap_or(0);
+
+        let flags = if ephemeral {
+            flags | EPHEMERAL
+        } else {
+            flags & !EPHEMERAL
+        };
+
+        self.0.insert("flags", Value::Number(Number::from(flags)));
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_build_response() {
+        let mut response = CreateInteractionResponse::default();
+        response.interaction_response_data(|d| d.content("pong").ephemeral(true));
+
+        assert_eq!(
+            Value::Object(utils::hashmap_to_json_map(response.0)),
+            json!({
+                "type": 4,
+                "data": {"content": "pong", "flags": 64},
+            }),
+        );
+
+        let mut response = CreateInteractionResponse::default();
+        response.kind(InteractionResponseType::DeferredChannelMessageWithSource);
+
+        assert_eq!(
+            Value::Object(utils::hashmap_to_json_map(response.0)),
+            json!({"type": 5}),
+        );
+    }
+}
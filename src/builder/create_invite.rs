@@ -26,7 +26,7 @@ use std::collections::HashMap;
 /// impl EventHandler for Handler {
 ///     async fn message(&self, context: Context, msg: Message) {
 ///         if msg.content == "!createinvite" {
-///             let channel = match context.cache.read().await.guild_channel(msg.channel_id) {
+///             let channel = match context.cache.guild_channel(msg.channel_id) {
 ///                 Some(channel) => channel,
 ///                 None => {
 ///                     let _ = msg.channel_id.say(&context, "Error creating invite").await;
@@ -34,8 +34,6 @@ use std::collections::HashMap;
 ///                 },
 ///             };
 ///
-///             let channel = channel.read().await;
-///
 ///             let creation = channel.create_invite(&context, |i| {
 ///                 i.max_age(3600).max_uses(10)
 ///             }).await;
@@ -93,8 +91,7 @@ impl CreateInvite {
     /// # #[cfg(all(feature = "cache", feature = "client", feature = "framework", feature = "http"))]
     /// # #[command]
     /// # async fn example(context: &mut Context) -> CommandResult {
-    /// #     let channel = context.cache.read().await.guild_channel(81384788765712384).unwrap();
-    /// #     let channel = channel.read().await;
+    /// #     let channel = context.cache.guild_channel(81384788765712384).unwrap();
     /// #
     /// let invite = channel.create_invite(context, |i| {
     ///     i.max_age(3600)
@@ -130,8 +127,7 @@ impl CreateInvite {
     /// # #[cfg(all(feature = "cache", feature = "client", feature = "framework", feature = "http"))]
     /// # #[command]
     /// # async fn example(context: &mut Context) -> CommandResult {
-    /// #     let channel = context.cache.read().await.guild_channel(81384788765712384).unwrap();
-    /// #     let channel = channel.read().await;
+    /// #     let channel = context.cache.guild_channel(81384788765712384).unwrap();
     /// #
     /// let invite = channel.create_invite(context, |i| {
     ///     i.max_uses(5)
@@ -165,8 +161,7 @@ impl CreateInvite {
     /// # #[cfg(all(feature = "cache", feature = "client", feature = "framework", feature = "http"))]
     /// # #[command]
     /// # async fn example(context: &mut Context) -> CommandResult {
-    /// #     let channel = context.cache.read().await.guild_channel(81384788765712384).unwrap();
-    /// #     let channel = channel.read().await;
+    /// #     let channel = context.cache.guild_channel(81384788765712384).unwrap();
     /// #
     /// let invite = channel.create_invite(context, |i| {
     ///     i.temporary(true)
@@ -199,8 +194,7 @@ impl CreateInvite {
     /// # #[cfg(all(feature = "cache", feature = "client", feature = "framework", feature = "http"))]
     /// # #[command]
     /// # async fn example(context: &mut Context) -> CommandResult {
-    /// #     let channel = context.cache.read().await.guild_channel(81384788765712384).unwrap();
-    /// #     let channel = channel.read().await;
+    /// #     let channel = context.cache.guild_channel(81384788765712384).unwrap();
     /// #
     /// let invite = channel.create_invite(context, |i| {
     ///     i.unique(true)
@@ -1,7 +1,8 @@
-use super::CreateEmbed;
+use super::{CreateAllowedMentions, CreateEmbed};
 use crate::http::AttachmentType;
 use crate::internal::prelude::*;
 use crate::model::channel::ReactionType;
+use crate::model::id::StickerId;
 use crate::utils;
 
 use std::collections::HashMap;
@@ -47,6 +48,29 @@ use std::collections::HashMap;
 /// });
 /// ```
 ///
+/// Files can be attached from within the builder, so that functions taking a
+/// builder closure can send them too:
+///
+/// ```rust,no_run
+/// use serenity::builder::CreateMessage;
+/// use serenity::http::AttachmentType;
+/// use serenity::model::id::ChannelId;
+/// # use serenity::http::Http;
+/// # use std::sync::Arc;
+/// #
+/// # let http = Arc::new(Http::default());
+///
+/// fn report<'a, 'b>(m: &'b mut CreateMessage<'a>, log: &'a [u8]) -> &'b mut CreateMessage<'a> {
+///     m.content("Here is the log:").add_file(AttachmentType::Bytes {
+///         data: log.into(),
+///         filename: "log.txt".to_string(),
+///     })
+/// }
+///
+/// let log = b"everything is fine";
+/// let _ = ChannelId(7).send_message(&http, |m| report(m, log));
+/// ```
+///
 /// [`ChannelId::say`]: ../model/id/struct.ChannelId.html#method.say
 /// [`ChannelId::send_message`]: ../model/id/struct.ChannelId.html#method.send_message
 /// [`content`]: #method.content
@@ -87,6 +111,23 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Restricts which mentions in the content notify their targets.
+    ///
+    /// Refer to [`CreateAllowedMentions`] for more information.
+    ///
+    /// [`CreateAllowedMentions`]: struct.CreateAllowedMentions.html
+    pub fn allowed_mentions<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateAllowedMentions) -> &mut CreateAllowedMentions,
+    {
+        let mut allowed_mentions = CreateAllowedMentions::default();
+        f(&mut allowed_mentions);
+        let map = utils::hashmap_to_json_map(allowed_mentions.0);
+
+        self.0.insert("allowed_mentions", Value::Object(map));
+        self
+    }
+
     /// Set whether the message is text-to-speech.
     ///
     /// Think carefully before setting this to `true`.
@@ -97,6 +138,21 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Sets the stickers to send with the message, of which there can be up
+    /// to 3.
+    pub fn sticker_ids<S: Into<StickerId>, It: IntoIterator<Item = S>>(
+        &mut self,
+        sticker_ids: It,
+    ) -> &mut Self {
+        let ids = sticker_ids
+            .into_iter()
+            .map(|id| Value::Number(Number::from(id.into().0)))
+            .collect();
+
+        self.0.insert("sticker_ids", Value::Array(ids));
+        self
+    }
+
     /// Adds a list of reactions to create after the message's sent.
     #[inline]
     pub fn reactions<R: Into<ReactionType>, It: IntoIterator<Item = R>>(
@@ -112,6 +168,12 @@ impl<'a> CreateMessage<'a> {
     }
 
     /// Appends a file to the message.
+    ///
+    /// Files are uploaded along with the message by
+    /// [`ChannelId::send_message`] and [`ChannelId::send_files`].
+    ///
+    /// [`ChannelId::send_files`]: ../model/id/struct.ChannelId.html#method.send_files
+    /// [`ChannelId::send_message`]: ../model/id/struct.ChannelId.html#method.send_message
     pub fn add_file<T: Into<AttachmentType<'a>>>(&mut self, file: T) -> &mut Self {
         self.2.push(file.into());
         self
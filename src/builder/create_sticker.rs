@@ -0,0 +1,57 @@
+use crate::internal::prelude::*;
+use std::collections::HashMap;
+
+/// A builder to upload a [`Sticker`] to a guild, to be used in conjunction
+/// with [`GuildId::create_sticker`].
+///
+/// # Examples
+///
+/// Upload a sticker from a file:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// use serenity::model::id::GuildId;
+///
+/// let sticker = GuildId(7)
+///     .create_sticker(&http, "./ferris.png", |s| {
+///         s.name("ferris").description("A friendly crab").tags("crab")
+///     })
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`GuildId::create_sticker`]: ../model/id/struct.GuildId.html#method.create_sticker
+/// [`Sticker`]: ../model/sticker/struct.Sticker.html
+#[derive(Clone, Debug, Default)]
+pub struct CreateSticker(pub HashMap<&'static str, Value>);
+
+impl CreateSticker {
+    /// Sets the name of the sticker, between 2 and 30 characters long.
+    ///
+    /// **Note**: This is required.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// Sets the description of the sticker, either empty or between 2 and
+    /// 100 characters long.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0
+            .insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Sets the autocomplete tags of the sticker, which should be the name of
+    /// a unicode emoji.
+    ///
+    /// **Note**: This is required.
+    pub fn tags<D: ToString>(&mut self, tags: D) -> &mut Self {
+        self.0.insert("tags", Value::String(tags.to_string()));
+        self
+    }
+}
@@ -0,0 +1,59 @@
+use crate::internal::prelude::*;
+use std::collections::HashMap;
+
+/// A builder to start a [`GuildThread`], to be used in conjunction with
+/// [`ChannelId::start_thread_with_message`] and
+/// [`ChannelId::start_private_thread`].
+///
+/// # Examples
+///
+/// Start a thread from a message, archiving it after a day of inactivity:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// use serenity::model::id::ChannelId;
+///
+/// let thread = ChannelId(7)
+///     .start_thread_with_message(&http, 8, |t| t.name("discussion").auto_archive_duration(1440))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`ChannelId::start_private_thread`]: ../model/id/struct.ChannelId.html#method.start_private_thread
+/// [`ChannelId::start_thread_with_message`]: ../model/id/struct.ChannelId.html#method.start_thread_with_message
+/// [`GuildThread`]: ../model/channel/struct.GuildThread.html
+#[derive(Clone, Debug, Default)]
+pub struct CreateThread(pub HashMap<&'static str, Value>);
+
+impl CreateThread {
+    /// Sets the name of the thread, between 1 and 100 characters long.
+    ///
+    /// **Note**: This is required.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// Sets the number of minutes of inactivity after which the thread is
+    /// automatically archived: one of `60`, `1440`, `4320` or `10080`.
+    pub fn auto_archive_duration(&mut self, minutes: u64) -> &mut Self {
+        self.0.insert(
+            "auto_archive_duration",
+            Value::Number(Number::from(minutes)),
+        );
+        self
+    }
+
+    /// Sets whether members who aren't moderators can add other members to
+    /// the thread.
+    ///
+    /// **Note**: This only applies to private threads.
+    pub fn invitable(&mut self, invitable: bool) -> &mut Self {
+        self.0.insert("invitable", Value::Bool(invitable));
+        self
+    }
+}
@@ -0,0 +1,40 @@
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A builder for creating a new [`Webhook`] in a channel.
+///
+/// Except [`name`], all fields are optional.
+///
+/// [`Webhook`]: ../model/webhook/struct.Webhook.html
+/// [`name`]: #method.name
+#[derive(Clone, Debug, Default)]
+pub struct CreateWebhook(pub HashMap<&'static str, Value>, pub Option<String>);
+
+impl CreateWebhook {
+    /// The default name of the webhook.
+    ///
+    /// **Note**: Must be between 1 and 80 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+
+        self
+    }
+
+    /// The default avatar of the webhook, as base64-encoded image data.
+    ///
+    /// [`utils::read_image`] can be used to read and encode an image file.
+    ///
+    /// [`utils::read_image`]: ../utils/fn.read_image.html
+    pub fn avatar<D: ToString>(&mut self, avatar: D) -> &mut Self {
+        self.0.insert("avatar", Value::String(avatar.to_string()));
+
+        self
+    }
+
+    /// Sets the reason to show in the guild's audit log for this change.
+    pub fn audit_log_reason<D: ToString>(&mut self, reason: D) -> &mut Self {
+        self.1 = Some(reason.to_string());
+
+        self
+    }
+}
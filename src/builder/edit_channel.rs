@@ -20,7 +20,7 @@ use std::collections::HashMap;
 /// [`GuildChannel`]: ../model/channel/struct.GuildChannel.html
 /// [`GuildChannel::edit`]: ../model/channel/struct.GuildChannel.html#method.edit
 #[derive(Clone, Debug, Default)]
-pub struct EditChannel(pub HashMap<&'static str, Value>);
+pub struct EditChannel(pub HashMap<&'static str, Value>, pub Option<String>);
 
 impl EditChannel {
     /// The bitrate of the channel in bits.
@@ -115,4 +115,11 @@ impl EditChannel {
 
         self
     }
+
+    /// Sets the reason to show in the guild's audit log for this change.
+    pub fn audit_log_reason<D: ToString>(&mut self, reason: D) -> &mut Self {
+        self.1 = Some(reason.to_string());
+
+        self
+    }
 }
@@ -0,0 +1,122 @@
+use crate::internal::prelude::*;
+use crate::model::channel::ReactionType;
+use crate::model::id::ChannelId;
+use crate::utils;
+use std::collections::HashMap;
+
+/// A builder to edit a guild's [`WelcomeScreen`], to be used in conjunction
+/// with [`GuildId::edit_welcome_screen`].
+///
+/// # Examples
+///
+/// Enable the welcome screen and highlight the rules channel:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// use serenity::model::id::GuildId;
+///
+/// GuildId(7)
+///     .edit_welcome_screen(&http, |w| {
+///         w.enabled(true)
+///             .description("A place to talk about crabs")
+///             .add_welcome_channel(|c| c.id(8).description("Read me first").emoji('📜'))
+///     })
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`GuildId::edit_welcome_screen`]: ../model/id/struct.GuildId.html#method.edit_welcome_screen
+/// [`WelcomeScreen`]: ../model/guild/struct.WelcomeScreen.html
+#[derive(Clone, Debug, Default)]
+pub struct EditGuildWelcomeScreen(pub HashMap<&'static str, Value>);
+
+impl EditGuildWelcomeScreen {
+    /// Sets whether the welcome screen is shown to new members.
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.0.insert("enabled", Value::Bool(enabled));
+        self
+    }
+
+    /// Sets the description of the guild shown on the welcome screen.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0
+            .insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Adds a channel to show on the welcome screen.
+    ///
+    /// **Note**: Once a channel is added, the channels sent replace all of the
+    /// current ones, so every channel which should stay must be added again.
+    /// Discord allows up to 5 channels.
+    pub fn add_welcome_channel<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateGuildWelcomeChannel) -> &mut CreateGuildWelcomeChannel,
+    {
+        let mut channel = CreateGuildWelcomeChannel::default();
+        f(&mut channel);
+        let channel = Value::Object(utils::hashmap_to_json_map(channel.0));
+
+        let channels = self
+            .0
+            .entry("welcome_channels")
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+        if let Value::Array(channels) = channels {
+            channels.push(channel);
+        }
+
+        self
+    }
+
+    /// Removes all channels from the welcome screen.
+    pub fn clear_welcome_channels(&mut self) -> &mut Self {
+        self.0.insert("welcome_channels", Value::Array(Vec::new()));
+        self
+    }
+}
+
+/// A builder for a channel shown on a guild's [`WelcomeScreen`], to be used
+/// with [`EditGuildWelcomeScreen::add_welcome_channel`].
+///
+/// [`EditGuildWelcomeScreen::add_welcome_channel`]: struct.EditGuildWelcomeScreen.html#method.add_welcome_channel
+/// [`WelcomeScreen`]: ../model/guild/struct.WelcomeScreen.html
+#[derive(Clone, Debug, Default)]
+pub struct CreateGuildWelcomeChannel(pub HashMap<&'static str, Value>);
+
+impl CreateGuildWelcomeChannel {
+    /// Sets the channel to show. This is required.
+    pub fn id<C: Into<ChannelId>>(&mut self, channel_id: C) -> &mut Self {
+        self.0
+            .insert("channel_id", Value::String(channel_id.into().0.to_string()));
+        self
+    }
+
+    /// Sets the description shown for the channel. This is required.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0
+            .insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Sets the emoji shown for the channel.
+    pub fn emoji<R: Into<ReactionType>>(&mut self, emoji: R) -> &mut Self {
+        match emoji.into() {
+            ReactionType::Custom { id, name, .. } => {
+                self.0.insert("emoji_id", Value::String(id.0.to_string()));
+                self.0
+                    .insert("emoji_name", name.map_or(Value::Null, Value::String));
+            }
+            ReactionType::Unicode(name) => {
+                self.0.insert("emoji_id", Value::Null);
+                self.0.insert("emoji_name", Value::String(name));
+            }
+        }
+
+        self
+    }
+}
@@ -0,0 +1,61 @@
+use crate::internal::prelude::*;
+use crate::model::guild::IntegrationExpireBehaviour;
+use std::collections::HashMap;
+
+/// A builder to edit the expiry settings of an [`Integration`], to be used in
+/// conjunction with [`GuildId::edit_integration`].
+///
+/// # Examples
+///
+/// Kick subscribers a week after their subscription expired:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// use serenity::model::guild::IntegrationExpireBehaviour;
+/// use serenity::model::id::GuildId;
+///
+/// GuildId(7)
+///     .edit_integration(&http, 8, |i| {
+///         i.expire_behaviour(IntegrationExpireBehaviour::Kick)
+///             .expire_grace_period(7)
+///     })
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`GuildId::edit_integration`]: ../model/id/struct.GuildId.html#method.edit_integration
+/// [`Integration`]: ../model/guild/struct.Integration.html
+#[derive(Clone, Debug, Default)]
+pub struct EditIntegration(pub HashMap<&'static str, Value>);
+
+impl EditIntegration {
+    /// Sets what happens to subscribers once their subscription expires.
+    pub fn expire_behaviour(&mut self, behaviour: IntegrationExpireBehaviour) -> &mut Self {
+        self.0.insert(
+            "expire_behavior",
+            Value::Number(Number::from(behaviour.num())),
+        );
+        self
+    }
+
+    /// Sets the number of days to wait before applying the expire behaviour.
+    ///
+    /// Discord only allows 1, 3, 7, 14 or 30 days.
+    pub fn expire_grace_period(&mut self, days: u64) -> &mut Self {
+        self.0
+            .insert("expire_grace_period", Value::Number(Number::from(days)));
+        self
+    }
+
+    /// Sets whether emoticons of the integration are synced.
+    ///
+    /// This only applies to Twitch integrations.
+    pub fn enable_emoticons(&mut self, enable: bool) -> &mut Self {
+        self.0.insert("enable_emoticons", Value::Bool(enable));
+        self
+    }
+}
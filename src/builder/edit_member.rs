@@ -8,7 +8,7 @@ use std::collections::HashMap;
 /// [`Member`]: ../model/guild/struct.Member.html
 /// [`Member::edit`]: ../model/guild/struct.Member.html#method.edit
 #[derive(Clone, Debug, Default)]
-pub struct EditMember(pub HashMap<&'static str, Value>);
+pub struct EditMember(pub HashMap<&'static str, Value>, pub Option<String>);
 
 impl EditMember {
     /// Whether to deafen the member.
@@ -77,4 +77,11 @@ impl EditMember {
         let num = Value::Number(Number::from(channel_id.0));
         self.0.insert("channel_id", num);
     }
+
+    /// Sets the reason to show in the guild's audit log for this change.
+    pub fn audit_log_reason<D: ToString>(&mut self, reason: D) -> &mut Self {
+        self.1 = Some(reason.to_string());
+
+        self
+    }
 }
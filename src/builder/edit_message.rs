@@ -1,4 +1,4 @@
-use super::CreateEmbed;
+use super::{CreateAllowedMentions, CreateEmbed};
 use crate::internal::prelude::*;
 use crate::utils;
 
@@ -57,4 +57,21 @@ impl EditMessage {
         self.0.insert("embed", embed);
         self
     }
+
+    /// Restricts which mentions in the content notify their targets.
+    ///
+    /// Refer to [`CreateAllowedMentions`] for more information.
+    ///
+    /// [`CreateAllowedMentions`]: struct.CreateAllowedMentions.html
+    pub fn allowed_mentions<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateAllowedMentions) -> &mut CreateAllowedMentions,
+    {
+        let mut allowed_mentions = CreateAllowedMentions::default();
+        f(&mut allowed_mentions);
+        let map = utils::hashmap_to_json_map(allowed_mentions.0);
+
+        self.0.insert("allowed_mentions", Value::Object(map));
+        self
+    }
 }
@@ -38,8 +38,8 @@ impl EditProfile {
     ///         let base64 = utils::read_image("./my_image.jpg")
     ///         .expect("Failed to read image");
     ///
-    ///         let mut guard = context.cache.write().await;
-    ///         let _ = guard.user.edit(&context, |p|
+    ///         let mut user = context.cache.current_user();
+    ///         let _ = user.edit(&context, |p|
     ///             p.avatar(Some(&base64)));
     ///    # }
     /// # }
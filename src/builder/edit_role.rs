@@ -41,7 +41,7 @@ use std::collections::HashMap;
 /// [`Role`]: ../model/guild/struct.Role.html
 /// [`Role::edit`]: ../model/guild/struct.Role.html#method.edit
 #[derive(Clone, Debug, Default)]
-pub struct EditRole(pub HashMap<&'static str, Value>);
+pub struct EditRole(pub HashMap<&'static str, Value>, pub Option<String>);
 
 impl EditRole {
     /// Creates a new builder with the values of the given [`Role`].
@@ -70,7 +70,7 @@ impl EditRole {
         );
         map.insert("position", Value::Number(Number::from(role.position)));
 
-        EditRole(map)
+        EditRole(map, None)
     }
 
     /// Sets the colour of the role.
@@ -114,4 +114,10 @@ impl EditRole {
             .insert("position", Value::Number(Number::from(position)));
         self
     }
+
+    /// Sets the reason to show in the guild's audit log for this change.
+    pub fn audit_log_reason<D: ToString>(&mut self, reason: D) -> &mut Self {
+        self.1 = Some(reason.to_string());
+        self
+    }
 }
@@ -0,0 +1,33 @@
+use crate::internal::prelude::*;
+use std::collections::HashMap;
+
+/// A builder to edit a guild's [`Sticker`], to be used in conjunction with
+/// [`GuildId::edit_sticker`].
+///
+/// [`GuildId::edit_sticker`]: ../model/id/struct.GuildId.html#method.edit_sticker
+/// [`Sticker`]: ../model/sticker/struct.Sticker.html
+#[derive(Clone, Debug, Default)]
+pub struct EditSticker(pub HashMap<&'static str, Value>);
+
+impl EditSticker {
+    /// Sets the name of the sticker, between 2 and 30 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// Sets the description of the sticker, either empty or between 2 and
+    /// 100 characters long.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0
+            .insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Sets the autocomplete tags of the sticker, which should be the name of
+    /// a unicode emoji.
+    pub fn tags<D: ToString>(&mut self, tags: D) -> &mut Self {
+        self.0.insert("tags", Value::String(tags.to_string()));
+        self
+    }
+}
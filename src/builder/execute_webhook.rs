@@ -1,3 +1,6 @@
+use super::{CreateAllowedMentions, CreateEmbed};
+use crate::http::AttachmentType;
+use crate::utils;
 use serde_json::Value;
 use std::collections::HashMap;
 
@@ -50,9 +53,29 @@ use std::collections::HashMap;
 /// [`Webhook::execute`]: ../model/webhook/struct.Webhook.html#method.execute
 /// [`execute_webhook`]: ../http/client/struct.Http.html#method.execute_webhook
 #[derive(Clone, Debug)]
-pub struct ExecuteWebhook(pub HashMap<&'static str, Value>);
+pub struct ExecuteWebhook<'a>(
+    pub HashMap<&'static str, Value>,
+    pub Vec<AttachmentType<'a>>,
+);
+
+impl<'a> ExecuteWebhook<'a> {
+    /// Restricts which mentions in the content notify their targets.
+    ///
+    /// Refer to [`CreateAllowedMentions`] for more information.
+    ///
+    /// [`CreateAllowedMentions`]: struct.CreateAllowedMentions.html
+    pub fn allowed_mentions<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateAllowedMentions) -> &mut CreateAllowedMentions,
+    {
+        let mut allowed_mentions = CreateAllowedMentions::default();
+        f(&mut allowed_mentions);
+        let map = utils::hashmap_to_json_map(allowed_mentions.0);
+
+        self.0.insert("allowed_mentions", Value::Object(map));
+        self
+    }
 
-impl ExecuteWebhook {
     /// Override the default avatar of the webhook with an image URL.
     ///
     /// # Examples
@@ -131,6 +154,54 @@ impl ExecuteWebhook {
         self
     }
 
+    /// Appends an embed to the message, up to 10 of which may be sent.
+    pub fn embed<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed,
+    {
+        let mut embed = CreateEmbed::default();
+        f(&mut embed);
+        let embed = Value::Object(utils::hashmap_to_json_map(embed.0));
+
+        let embeds = self
+            .0
+            .entry("embeds")
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+        if let Value::Array(embeds) = embeds {
+            embeds.push(embed);
+        }
+
+        self
+    }
+
+    /// Appends a file to the message.
+    pub fn add_file<T: Into<AttachmentType<'a>>>(&mut self, file: T) -> &mut Self {
+        self.1.push(file.into());
+        self
+    }
+
+    /// Appends a list of files to the message.
+    pub fn add_files<T: Into<AttachmentType<'a>>, It: IntoIterator<Item = T>>(
+        &mut self,
+        files: It,
+    ) -> &mut Self {
+        self.1.extend(files.into_iter().map(|f| f.into()));
+        self
+    }
+
+    /// Sets a list of files to include in the message.
+    ///
+    /// Calling this multiple times will overwrite the file list.
+    /// To append files, call `add_file` or `add_files` instead.
+    pub fn files<T: Into<AttachmentType<'a>>, It: IntoIterator<Item = T>>(
+        &mut self,
+        files: It,
+    ) -> &mut Self {
+        self.1 = files.into_iter().map(|f| f.into()).collect();
+        self
+    }
+
     /// Whether the message is a text-to-speech message.
     ///
     /// # Examples
@@ -189,7 +260,7 @@ impl ExecuteWebhook {
     }
 }
 
-impl Default for ExecuteWebhook {
+impl<'a> Default for ExecuteWebhook<'a> {
     /// Returns a default set of values for a [`Webhook`] execution.
     ///
     /// The only default value is [`tts`] being set to `false`.
@@ -206,10 +277,10 @@ impl Default for ExecuteWebhook {
     ///
     /// [`Webhook`]: ../model/webhook/struct.Webhook.html
     /// [`tts`]: #method.tts
-    fn default() -> ExecuteWebhook {
+    fn default() -> ExecuteWebhook<'a> {
         let mut map = HashMap::new();
         map.insert("tts", Value::Bool(false));
 
-        ExecuteWebhook(map)
+        ExecuteWebhook(map, Vec::new())
     }
 }
@@ -5,30 +5,48 @@
 //! optional, and/or sane default values for required parameters can be applied
 //! by a builder.
 
+mod create_allowed_mentions;
+mod create_application_command;
 mod create_channel;
 mod create_embed;
+mod create_interaction_response;
 mod create_invite;
 mod create_message;
+mod create_sticker;
+mod create_thread;
+mod create_webhook;
 mod edit_channel;
 mod edit_guild;
+mod edit_guild_welcome_screen;
+mod edit_integration;
 mod edit_member;
 mod edit_message;
 mod edit_profile;
 mod edit_role;
+mod edit_sticker;
 mod execute_webhook;
 mod get_messages;
 
 pub use self::{
+    create_allowed_mentions::{CreateAllowedMentions, ParseValue},
+    create_application_command::{CreateApplicationCommand, CreateApplicationCommandOption},
     create_channel::CreateChannel,
     create_embed::{CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter, Timestamp},
+    create_interaction_response::{CreateInteractionResponse, CreateInteractionResponseData},
     create_invite::CreateInvite,
     create_message::CreateMessage,
+    create_sticker::CreateSticker,
+    create_thread::CreateThread,
+    create_webhook::CreateWebhook,
     edit_channel::EditChannel,
     edit_guild::EditGuild,
+    edit_guild_welcome_screen::{CreateGuildWelcomeChannel, EditGuildWelcomeScreen},
+    edit_integration::EditIntegration,
     edit_member::EditMember,
     edit_message::EditMessage,
     edit_profile::EditProfile,
     edit_role::EditRole,
+    edit_sticker::EditSticker,
     execute_webhook::ExecuteWebhook,
     get_messages::GetMessages,
 };
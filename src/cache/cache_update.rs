@@ -1,4 +1,5 @@
 use super::Cache;
+use crate::model::event::Event;
 use async_trait::async_trait;
 
 /// Trait used for updating the cache with a type.
@@ -21,13 +22,8 @@ use async_trait::async_trait;
 ///         id::UserId,
 ///         user::User,
 ///     },
-///     SyncRwLock,
 /// };
-/// use std::{
-///     collections::hash_map::Entry,
-///     sync::Arc,
-/// };
-/// use async_std::sync::RwLock;
+/// use dashmap::mapref::entry::Entry;
 /// use async_trait::async_trait;
 ///
 /// // For example, an update to the user's record in the database was
@@ -45,13 +41,11 @@ use async_trait::async_trait;
 ///     // A copy of the old user's data, if it existed in the cache.
 ///     type Output = User;
 ///
-///     async fn update(&mut self, cache: &mut Cache) -> Option<Self::Output> {
+///     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
 ///         // If an entry for the user already exists, update its fields.
-///         use serenity::SyncRwLock;
-/// match cache.users.entry(self.user_id) {
-///             Entry::Occupied(entry) => {
-///                 let user = entry.get();
-///                 let mut writer = user.write();
+///         match cache.users.entry(self.user_id) {
+///             Entry::Occupied(mut entry) => {
+///                 let writer = entry.get_mut();
 ///                 let old = writer.clone();
 ///
 ///                 writer.bot = self.user_is_bot;
@@ -80,7 +74,7 @@ use async_trait::async_trait;
 ///                     "username": self.user_name.clone(),
 ///                 })).expect("Error making user");
 ///
-///                 entry.insert(Arc::new(SyncRwLock::new(user)));
+///                 entry.insert(user);
 ///
 ///                 // There was no old copy, so return None.
 ///                 None
@@ -91,7 +85,7 @@ use async_trait::async_trait;
 ///
 /// # fn main() {
 /// // Create an instance of the cache.
-/// let mut cache = Cache::new();
+/// let cache = Cache::new();
 ///
 /// // This is a sample pubsub message that you might receive from your
 /// // database.
@@ -117,5 +111,58 @@ pub trait CacheUpdate {
     type Output;
 
     /// Updates the cache with the implementation.
-    async fn update(&mut self, _: &mut Cache) -> Option<Self::Output>;
+    async fn update(&mut self, _: &Cache) -> Option<Self::Output>;
+}
+
+/// A user-defined step run after the library has updated the cache with an
+/// event.
+///
+/// Hooks are registered on the [`Client`] via [`Extras::cache_update_hook`],
+/// and are run in the order they were registered, before the event is handed
+/// to the event handlers. This allows maintaining derived data next to the
+/// cache, such as an index of users by name.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use async_trait::async_trait;
+/// use dashmap::DashMap;
+/// use serenity::cache::{Cache, CacheUpdateHook};
+/// use serenity::model::{event::Event, id::UserId};
+/// # use serenity::prelude::EventHandler;
+/// #
+/// # struct Handler;
+/// #
+/// # impl EventHandler for Handler {}
+///
+/// #[derive(Default)]
+/// struct UsersByName(DashMap<String, UserId>);
+///
+/// #[async_trait]
+/// impl CacheUpdateHook for UsersByName {
+///     async fn update(&self, _: &Cache, event: &Event) {
+///         if let Event::GuildMemberAdd(event) = event {
+///             self.0.insert(event.member.user.name.clone(), event.member.user.id);
+///         }
+///     }
+/// }
+///
+/// # async fn run() -> serenity::Result<()> {
+/// use serenity::Client;
+///
+/// let client = Client::new_with_extras("token", |extras| {
+///     extras
+///         .event_handler(Handler)
+///         .cache_update_hook(UsersByName::default())
+/// }).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Client`]: ../client/struct.Client.html
+/// [`Extras::cache_update_hook`]: ../client/struct.Extras.html#method.cache_update_hook
+#[async_trait]
+pub trait CacheUpdateHook: Send + Sync {
+    /// Runs the hook for an event the cache has been updated with.
+    async fn update(&self, cache: &Cache, event: &Event);
 }
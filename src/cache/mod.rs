@@ -37,45 +37,87 @@
 //! [`Role`]: ../model/guild/struct.Role.html
 //! [`http`]: ../http/index.html
 
-use crate::internal::{AsyncRwLock, SyncRwLock};
+#[cfg(feature = "http")]
+use crate::http::Http;
+use crate::internal::SyncRwLock;
 use crate::model::prelude::*;
-use std::collections::{HashMap, HashSet, VecDeque};
+use dashmap::{DashMap, DashSet};
+use log::warn;
+use parking_lot::{Mutex as SyncMutex, RwLockReadGuard, RwLockWriteGuard};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::default::Default;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::mem::{self, size_of};
 use std::str::FromStr;
-use std::{default::Default, ops::Deref, sync::Arc};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::{Mutex as AsyncMutex, Notify};
 
 mod cache_update;
+mod recent_events;
+#[cfg(feature = "cache_redis")]
+mod redis_cache;
 mod settings;
+mod snapshot;
+mod view;
 
-pub use self::cache_update::CacheUpdate;
+pub use self::cache_update::{CacheUpdate, CacheUpdateHook};
+pub use self::recent_events::RecentEvent;
+#[cfg(feature = "cache_redis")]
+pub use self::redis_cache::{CacheKey, CacheKeys, RedisCache};
 pub use self::settings::Settings;
-use async_trait::async_trait;
+use self::snapshot::{Snapshot, SNAPSHOT_VERSION};
+pub use self::view::{CacheView, ChannelRef};
 
-type MessageCache = HashMap<ChannelId, HashMap<MessageId, Message>>;
+type MessageCache = DashMap<ChannelId, HashMap<MessageId, Message>>;
+
+/// A writer which only counts the bytes written to it.
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The length of the JSON representation of a value.
+fn json_size<T: Serialize>(value: &T) -> usize {
+    let mut counter = ByteCounter(0);
+    let _ = serde_json::to_writer(&mut counter, value);
+
+    counter.0
+}
 
-#[async_trait]
 pub trait FromStrAndCache: Sized {
     type Err;
 
-    async fn from_str(cache: &CacheRwLock, s: &str) -> Result<Self, Self::Err>;
+    fn from_str(cache: &Cache, s: &str) -> Result<Self, Self::Err>;
 }
 
-#[async_trait]
 pub trait StrExt: Sized {
-    async fn parse_cached<F: FromStrAndCache>(&self, cache: &CacheRwLock) -> Result<F, F::Err>;
+    fn parse_cached<F: FromStrAndCache>(&self, cache: &Cache) -> Result<F, F::Err>;
 }
 
-#[async_trait]
 impl<'a> StrExt for &'a str {
-    async fn parse_cached<F: FromStrAndCache>(&self, cache: &CacheRwLock) -> Result<F, F::Err> {
-        F::from_str(&cache, &self).await
+    fn parse_cached<F: FromStrAndCache>(&self, cache: &Cache) -> Result<F, F::Err> {
+        F::from_str(cache, self)
     }
 }
 
-#[async_trait]
 impl<F: FromStr> FromStrAndCache for F {
     type Err = F::Err;
 
-    async fn from_str(_cache: &CacheRwLock, s: &str) -> Result<Self, Self::Err> {
+    fn from_str(_cache: &Cache, s: &str) -> Result<Self, Self::Err> {
         s.parse::<F>()
     }
 }
@@ -89,10 +131,15 @@ impl<F: FromStr> FromStrAndCache for F {
 /// allows data to be "corrupted", and _may or may not_ cause misfunctions
 /// within the library. Mutate data at your own discretion.
 ///
+/// Entries are stored by value in concurrent maps, so the cache can be shared
+/// behind an `Arc` and read or updated without locking it as a whole. The
+/// accessor methods return clones of the entries; to avoid cloning, borrow an
+/// entry from the respective map instead, taking care not to hold the
+/// reference across an `.await`.
 ///
 /// [`Shard`]: ../gateway/struct.Shard.html
 /// [`http`]: ../http/index.html
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 #[non_exhaustive]
 pub struct Cache {
     /// A map of channels in [`Guild`]s that the current user has received data
@@ -105,26 +152,52 @@ pub struct Cache {
     /// [`Event::GuildDelete`]: ../model/event/struct.GuildDeleteEvent.html
     /// [`Event::GuildUnavailable`]: ../model/event/struct.GuildUnavailableEvent.html
     /// [`Guild`]: ../model/guild/struct.Guild.html
-    pub channels: HashMap<ChannelId, Arc<AsyncRwLock<GuildChannel>>>,
+    pub channels: DashMap<ChannelId, GuildChannel>,
     /// A map of channel categories.
-    pub categories: HashMap<ChannelId, Arc<AsyncRwLock<ChannelCategory>>>,
+    pub categories: DashMap<ChannelId, ChannelCategory>,
+    /// The Ids of the channels in each category, kept up to date as channels
+    /// are created, moved and deleted.
+    ///
+    /// Use [`category_channels`] to retrieve the channels of a category.
+    ///
+    /// [`category_channels`]: #method.category_channels
+    pub(crate) category_children: DashMap<ChannelId, HashSet<ChannelId>>,
+    /// The Ids of each guild's channels by name, kept up to date as channels
+    /// are created, renamed and deleted.
+    ///
+    /// Use [`channel_by_name`] to look up a channel.
+    ///
+    /// [`channel_by_name`]: #method.channel_by_name
+    pub(crate) channel_names: DashMap<GuildId, HashMap<String, BTreeSet<ChannelId>>>,
     /// A map of the groups that the current user is in.
     ///
     /// For bot users this will always be empty, except for in [special cases].
     ///
     /// [special cases]: index.html#special-cases-in-the-cache
-    pub groups: HashMap<ChannelId, Arc<AsyncRwLock<Group>>>,
+    pub groups: DashMap<ChannelId, Group>,
     /// A map of guilds with full data available. This includes data like
     /// [`Role`]s and [`Emoji`]s that are not available through the REST API.
     ///
     /// [`Emoji`]: ../model/guild/struct.Emoji.html
     /// [`Role`]: ../model/guild/struct.Role.html
-    pub guilds: HashMap<GuildId, Arc<AsyncRwLock<Guild>>>,
+    pub guilds: DashMap<GuildId, Guild>,
+    /// The Ids of each guild's roles by name, kept up to date as guilds are
+    /// received and roles are created, renamed and deleted.
+    ///
+    /// Use [`role_by_name`] to look up a role.
+    ///
+    /// [`role_by_name`]: #method.role_by_name
+    pub(crate) role_names: DashMap<GuildId, HashMap<String, BTreeSet<RoleId>>>,
     /// A map of channels to messages.
     ///
     /// This is a map of channel IDs to another map of message IDs to messages.
     ///
-    /// This keeps only the ten most recent messages.
+    /// This keeps only the most recent messages, as configured through
+    /// [`Settings::max_messages`]. Use [`messages_in`] to retrieve them in
+    /// the order they were received.
+    ///
+    /// [`Settings::max_messages`]: struct.Settings.html#structfield.max_messages
+    /// [`messages_in`]: #method.messages_in
     pub messages: MessageCache,
     /// A map of notes that a user has made for individual users.
     ///
@@ -132,16 +205,28 @@ pub struct Cache {
     /// note is equivalent to deleting a note.
     ///
     /// This will always be empty for bot users.
-    pub notes: HashMap<UserId, String>,
+    pub notes: DashMap<UserId, String>,
     /// A map of users' presences. This is updated in real-time. Note that
     /// status updates are often "eaten" by the gateway, and this should not
     /// be treated as being entirely 100% accurate.
-    pub presences: HashMap<UserId, Presence>,
+    pub presences: DashMap<UserId, Presence>,
     /// A map of direct message channels that the current user has open with
     /// other users.
-    pub private_channels: HashMap<ChannelId, Arc<AsyncRwLock<PrivateChannel>>>,
+    pub private_channels: DashMap<ChannelId, PrivateChannel>,
     /// The total number of shards being used by the bot.
-    pub shard_count: u64,
+    ///
+    /// Use [`shard_count`] to read it.
+    ///
+    /// [`shard_count`]: #method.shard_count
+    pub shard_count: AtomicU64,
+    /// A map of the active threads in [`Guild`]s that the current user has
+    /// received data for.
+    ///
+    /// Threads are also removed from this map when they are deleted, or when
+    /// their guild is deleted.
+    ///
+    /// [`Guild`]: ../model/guild/struct.Guild.html
+    pub threads: DashMap<ChannelId, GuildThread>,
     /// A list of guilds which are "unavailable". Refer to the documentation for
     /// [`Event::GuildUnavailable`] for more information on when this can occur.
     ///
@@ -151,7 +236,28 @@ pub struct Cache {
     ///
     /// [`Event::GuildCreate`]: ../model/event/enum.Event.html#variant.GuildCreate
     /// [`Event::GuildUnavailable`]: ../model/event/enum.Event.html#variant.GuildUnavailable
-    pub unavailable_guilds: HashSet<GuildId>,
+    pub unavailable_guilds: DashSet<GuildId>,
+    /// The Ids of the shards which have received a [`Ready`] event.
+    ///
+    /// [`Ready`]: ../model/event/enum.Event.html#variant.Ready
+    pub(crate) ready_shards: DashSet<u64>,
+    /// The number of shards the client runs, or 0 if unknown, in which case
+    /// [`shard_count`] is used.
+    ///
+    /// [`shard_count`]: #structfield.shard_count
+    pub(crate) startup_shards: AtomicU64,
+    /// Whether the initial set of guilds has been received.
+    startup_complete: AtomicBool,
+    /// Wakes the tasks waiting in [`wait_for_startup`].
+    ///
+    /// [`wait_for_startup`]: #method.wait_for_startup
+    startup_notify: Notify,
+    /// When startup is forced to complete, if [`Settings::startup_timeout`]
+    /// is set and a [`Ready`] was received.
+    ///
+    /// [`Ready`]: ../model/event/enum.Event.html#variant.Ready
+    /// [`Settings::startup_timeout`]: struct.Settings.html#structfield.startup_timeout
+    startup_deadline: SyncRwLock<Option<Instant>>,
     /// The current user "logged in" and for which events are being received
     /// for.
     ///
@@ -160,9 +266,12 @@ pub struct Cache {
     ///
     /// Refer to the documentation for [`CurrentUser`] for more information.
     ///
+    /// Use [`current_user`] to retrieve a copy of it.
+    ///
     /// [`CurrentUser`]: ../model/user/struct.CurrentUser.html
     /// [`User`]: ../model/user/struct.User.html
-    pub user: CurrentUser,
+    /// [`current_user`]: #method.current_user
+    pub user: SyncRwLock<CurrentUser>,
     /// A map of users that the current user sees.
     ///
     /// Users are added to - and updated from - this map via the following
@@ -186,15 +295,52 @@ pub struct Cache {
     /// [`GuildMembersChunkEvent`]: ../model/event/struct.GuildMembersChunkEvent.html
     /// [`PresenceUpdateEvent`]: ../model/event/struct.PresenceUpdateEvent.html
     /// [`ReadyEvent`]: ../model/event/struct.ReadyEvent.html
-    pub users: HashMap<UserId, Arc<SyncRwLock<User>>>,
+    pub users: DashMap<UserId, User>,
+    /// The Id of each cached user by name and discriminator.
+    ///
+    /// Use [`user_by_tag`] to look up a user.
+    ///
+    /// [`user_by_tag`]: #method.user_by_tag
+    pub(crate) user_tags: DashMap<(String, u16), UserId>,
     /// Queue of message IDs for each channel.
     ///
     /// This is simply a vecdeque so we can keep track of the order of messages
     /// inserted into the cache. When a maximum number of messages are in a
     /// channel's cache, we can pop the front and remove that ID from the cache.
-    pub(crate) message_queue: HashMap<ChannelId, VecDeque<MessageId>>,
+    pub(crate) message_queue: DashMap<ChannelId, VecDeque<MessageId>>,
+    /// When each user's presence was last stored, used to expire presences
+    /// once [`Settings::presence_ttl`] has passed and to evict the least
+    /// recently updated ones first in [`trim_to`].
+    ///
+    /// [`Settings::presence_ttl`]: struct.Settings.html#structfield.presence_ttl
+    /// [`trim_to`]: #method.trim_to
+    pub(crate) presence_timestamps: DashMap<UserId, Instant>,
+    /// The most recently applied events, oldest first, up to
+    /// [`Settings::max_recent_events`].
+    ///
+    /// [`Settings::max_recent_events`]: struct.Settings.html#structfield.max_recent_events
+    recent_events: SyncRwLock<VecDeque<RecentEvent>>,
+    /// The hooks registered on the client, run after each event the client
+    /// updates the cache with.
+    update_hooks: UpdateHooks,
+    /// Events queued by the client to be applied in the next batch, see
+    /// [`update_queued`].
+    ///
+    /// [`update_queued`]: #method.update_queued
+    queued_updates: SyncMutex<Vec<Event>>,
+    /// Held while a batch of queued events is applied.
+    batch_lock: AsyncMutex<()>,
     /// The settings for the cache.
-    settings: Settings,
+    settings: SyncRwLock<Settings>,
+}
+
+#[derive(Default)]
+struct UpdateHooks(Vec<Arc<dyn CacheUpdateHook>>);
+
+impl fmt::Debug for UpdateHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{} hooks]", self.0.len())
+    }
 }
 
 impl Cache {
@@ -218,11 +364,97 @@ impl Cache {
     /// ```
     pub fn new_with_settings(settings: Settings) -> Self {
         Self {
-            settings,
+            settings: SyncRwLock::new(settings),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new cache which runs the given hooks after updates made by
+    /// the client.
+    pub(crate) fn with_update_hooks(hooks: Vec<Arc<dyn CacheUpdateHook>>) -> Self {
+        Self {
+            update_hooks: UpdateHooks(hooks),
             ..Default::default()
         }
     }
 
+    /// Returns records of the most recently applied events, oldest first.
+    ///
+    /// This is meant for debugging the contents of the cache: it shows which
+    /// events the cache was updated with, and when, without keeping their
+    /// data around. Events are only recorded when
+    /// [`Settings::max_recent_events`] is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # let cache = Cache::default();
+    /// for event in cache.recent_events() {
+    ///     println!(
+    ///         "{}: {:?} in guild {:?}",
+    ///         event.applied_at, event.kind, event.guild_id,
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// [`Settings::max_recent_events`]: struct.Settings.html#structfield.max_recent_events
+    pub fn recent_events(&self) -> Vec<RecentEvent> {
+        self.recent_events.read().iter().cloned().collect()
+    }
+
+    /// Whether applied events should be recorded for [`recent_events`].
+    ///
+    /// [`recent_events`]: #method.recent_events
+    pub(crate) fn records_events(&self) -> bool {
+        self.settings().max_recent_events > 0
+    }
+
+    /// Records an event the cache was updated with, forgetting the oldest
+    /// records beyond [`Settings::max_recent_events`].
+    ///
+    /// [`Settings::max_recent_events`]: struct.Settings.html#structfield.max_recent_events
+    pub(crate) fn record_event(&self, event: &Event) {
+        self.record_events(Some(event));
+    }
+
+    /// Records events like [`record_event`], under a single acquisition of
+    /// the lock on the records.
+    ///
+    /// [`record_event`]: #method.record_event
+    fn record_events<'a>(&self, events: impl IntoIterator<Item = &'a Event>) {
+        let max = self.settings().max_recent_events;
+
+        if max == 0 {
+            return;
+        }
+
+        let mut recent_events = self.recent_events.write();
+        recent_events.extend(events.into_iter().map(RecentEvent::new));
+
+        while recent_events.len() > max {
+            recent_events.pop_front();
+        }
+    }
+
+    /// Whether any [`CacheUpdateHook`]s are registered.
+    ///
+    /// [`CacheUpdateHook`]: trait.CacheUpdateHook.html
+    pub(crate) fn has_update_hooks(&self) -> bool {
+        !self.update_hooks.0.is_empty()
+    }
+
+    /// Runs the registered [`CacheUpdateHook`]s for an event the cache has
+    /// been updated with.
+    ///
+    /// [`CacheUpdateHook`]: trait.CacheUpdateHook.html
+    pub(crate) async fn run_update_hooks(&self, event: &Event) {
+        for hook in &self.update_hooks.0 {
+            hook.update(self, event).await;
+        }
+    }
+
     /// Fetches the number of [`Member`]s that have not had data received.
     ///
     /// The important detail to note here is that this is the number of
@@ -254,8 +486,7 @@ impl Cache {
     ///         // seconds.
     ///         tokio::time::sleep(Duration::from_secs(5)).await;
     ///
-    ///         let guard = ctx.cache.read().await;
-    ///         println!("{} unknown members", guard.unknown_members().await);
+    ///         println!("{} unknown members", ctx.cache.unknown_members());
     ///     }
     /// }
     /// # #[cfg(feature = "client")]
@@ -273,12 +504,10 @@ impl Cache {
     /// [`Member`]: ../model/guild/struct.Member.html
     /// [`Shard::chunk_guilds`]: ../gateway/struct.Shard.html#method.chunk_guilds
     /// [`User`]: ../model/user/struct.User.html
-    pub async fn unknown_members(&self) -> u64 {
+    pub fn unknown_members(&self) -> u64 {
         let mut total = 0;
 
-        for guild in self.guilds.values() {
-            let guild = guild.read().await;
-
+        for guild in self.guilds.iter() {
             let members = guild.members.len() as u64;
 
             if guild.member_count > members {
@@ -289,6 +518,24 @@ impl Cache {
         total
     }
 
+    /// Fetches the Ids of guilds which have [`Member`]s that have not had data
+    /// received.
+    ///
+    /// These are the guilds to request members for with
+    /// [`Shard::chunk_guilds`]. This is done automatically when
+    /// [`Settings::chunk_guilds_on_ready`] is enabled.
+    ///
+    /// [`Member`]: ../model/guild/struct.Member.html
+    /// [`Settings::chunk_guilds_on_ready`]: struct.Settings.html#structfield.chunk_guilds_on_ready
+    /// [`Shard::chunk_guilds`]: ../gateway/struct.Shard.html#method.chunk_guilds
+    pub fn guilds_missing_members(&self) -> Vec<GuildId> {
+        self.guilds
+            .iter()
+            .filter(|guild| guild.member_count > guild.members.len() as u64)
+            .map(|guild| guild.id)
+            .collect()
+    }
+
     /// Fetches a vector of all [`PrivateChannel`] and [`Group`] Ids that are
     /// stored in the cache.
     ///
@@ -300,24 +547,21 @@ impl Cache {
     /// Printing the count of all private channels and groups:
     ///
     /// ```rust,no_run
-    /// # use serenity::{cache::{Cache, CacheRwLock}};
-    /// # use async_std::sync::RwLock;
-    /// # use std::sync::Arc;
+    /// # use serenity::cache::Cache;
     /// #
-    /// # async fn try_main() {
-    /// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// let amount = cache.read().await.all_private_channels().len();
+    /// # let cache = Cache::default();
+    /// let amount = cache.all_private_channels().len();
     ///
     /// println!("There are {} private channels", amount);
-    /// # }
     /// ```
     ///
     /// [`Group`]: ../model/channel/struct.Group.html
     /// [`PrivateChannel`]: ../model/channel/struct.PrivateChannel.html
-    pub fn all_private_channels(&self) -> Vec<&ChannelId> {
+    pub fn all_private_channels(&self) -> Vec<ChannelId> {
         self.groups
-            .keys()
-            .chain(self.private_channels.keys())
+            .iter()
+            .map(|group| *group.key())
+            .chain(self.private_channels.iter().map(|channel| *channel.key()))
             .collect()
     }
 
@@ -344,7 +588,7 @@ impl Cache {
     /// #[async_trait]
     /// impl EventHandler for Handler {
     ///     async fn ready(&self, context: Context, _: Ready) {
-    ///         let guilds = context.cache.read().await.guilds.len();
+    ///         let guilds = context.cache.guilds.len();
     ///
     ///         println!("Guilds in the Cache: {}", guilds);
     ///     }
@@ -358,10 +602,11 @@ impl Cache {
     /// [`Context`]: ../client/struct.Context.html
     /// [`Guild`]: ../model/guild/struct.Guild.html
     /// [`Shard`]: ../gateway/struct.Shard.html
-    pub fn all_guilds(&self) -> Vec<&GuildId> {
+    pub fn all_guilds(&self) -> Vec<GuildId> {
         self.guilds
-            .keys()
-            .chain(self.unavailable_guilds.iter())
+            .iter()
+            .map(|guild| *guild.key())
+            .chain(self.unavailable_guilds.iter().map(|id| *id))
             .collect()
     }
 
@@ -393,52 +638,90 @@ impl Cache {
 
     fn _channel(&self, id: ChannelId) -> Option<Channel> {
         if let Some(channel) = self.channels.get(&id) {
-            return Some(Channel::Guild(Arc::clone(channel)));
+            return Some(Channel::Guild(channel.clone()));
         }
 
         if let Some(private_channel) = self.private_channels.get(&id) {
-            return Some(Channel::Private(Arc::clone(private_channel)));
+            return Some(Channel::Private(private_channel.clone()));
         }
 
         if let Some(group) = self.groups.get(&id) {
-            return Some(Channel::Group(Arc::clone(group)));
+            return Some(Channel::Group(group.clone()));
+        }
+
+        if let Some(thread) = self.threads.get(&id) {
+            return Some(Channel::Thread(thread.clone()));
         }
 
         None
     }
 
-    /// Retrieves a guild from the cache based on the given Id.
+    /// Retrieves a clone of a guild from the cache based on the given Id.
+    ///
+    /// **Note**: This will clone the entire guild. To only read some of its
+    /// data, use [`guild_field`] instead.
     ///
     /// The only advantage of this method is that you can pass in anything that
     /// is indirectly a [`GuildId`].
     ///
     /// [`GuildId`]: ../model/id/struct.GuildId.html
+    /// [`guild_field`]: #method.guild_field
     ///
     /// # Examples
     ///
     /// Retrieve a guild from the cache and print its name:
     ///
     /// ```rust,no_run
-    /// # use serenity::{cache::{Cache, CacheRwLock}};
-    /// # use async_std::sync::RwLock;
-    /// # use std::{error::Error, sync::Arc};
+    /// # use serenity::cache::Cache;
     /// #
-    /// # async fn try_main() -> Result<(), Box<dyn Error>> {
-    /// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
+    /// # let cache = Cache::default();
     /// // assuming the cache is in scope, e.g. via `Context`
-    /// if let Some(guild) = cache.read().await.guild(7) {
-    ///     println!("Guild name: {}", guild.read().await.name);
+    /// if let Some(guild) = cache.guild(7) {
+    ///     println!("Guild name: {}", guild.name);
     /// }
-    /// #   Ok(())
-    /// # }
     /// ```
     #[inline]
-    pub fn guild<G: Into<GuildId>>(&self, id: G) -> Option<Arc<AsyncRwLock<Guild>>> {
+    pub fn guild<G: Into<GuildId>>(&self, id: G) -> Option<Guild> {
         self._guild(id.into())
     }
 
-    fn _guild(&self, id: GuildId) -> Option<Arc<AsyncRwLock<Guild>>> {
-        self.guilds.get(&id).cloned()
+    fn _guild(&self, id: GuildId) -> Option<Guild> {
+        self.guilds.get(&id).map(|guild| guild.clone())
+    }
+
+    /// Runs a closure against a cached [`Guild`] and returns its result,
+    /// without cloning the guild.
+    ///
+    /// The guild's entry is locked for the duration of the closure, so avoid
+    /// doing anything slow, and do not access the [`guilds`] map from within
+    /// it.
+    ///
+    /// Returns `None` if the guild is not cached.
+    ///
+    /// # Examples
+    ///
+    /// Retrieve only the name of a guild from the cache:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # let cache = Cache::default();
+    /// if let Some(name) = cache.guild_field(7, |guild| guild.name.clone()) {
+    ///     println!("Guild name: {}", name);
+    /// }
+    /// ```
+    ///
+    /// [`Guild`]: ../model/guild/struct.Guild.html
+    /// [`guilds`]: #structfield.guilds
+    #[inline]
+    pub fn guild_field<G, F, T>(&self, id: G, field_selector: F) -> Option<T>
+    where
+        G: Into<GuildId>,
+        F: FnOnce(&Guild) -> T,
+    {
+        self.guilds
+            .get(&id.into())
+            .map(|guild| field_selector(&guild))
     }
 
     /// Retrieves a reference to a [`Guild`]'s channel. Unlike [`channel`],
@@ -466,9 +749,7 @@ impl Cache {
     /// #[async_trait]
     /// impl EventHandler for Handler {
     ///     async fn message(&self, context: Context, message: Message) {
-    ///         let cache = context.cache.read().await;
-    ///
-    ///         let channel = match cache.guild_channel(message.channel_id) {
+    ///         let channel = match context.cache.guild_channel(message.channel_id) {
     ///             Some(channel) => channel,
     ///             None => {
     /// if let Err(why) = message.channel_id.say(&context.http, "Could not find guild's
@@ -496,15 +777,32 @@ impl Cache {
     /// [`Guild`]: ../model/guild/struct.Guild.html
     /// [`channel`]: #method.channel
     #[inline]
-    pub fn guild_channel<C: Into<ChannelId>>(
-        &self,
-        id: C,
-    ) -> Option<Arc<AsyncRwLock<GuildChannel>>> {
+    pub fn guild_channel<C: Into<ChannelId>>(&self, id: C) -> Option<GuildChannel> {
         self._guild_channel(id.into())
     }
 
-    fn _guild_channel(&self, id: ChannelId) -> Option<Arc<AsyncRwLock<GuildChannel>>> {
-        self.channels.get(&id).cloned()
+    fn _guild_channel(&self, id: ChannelId) -> Option<GuildChannel> {
+        self.channels.get(&id).map(|channel| channel.clone())
+    }
+
+    /// Runs a closure against a cached [`GuildChannel`] and returns its
+    /// result, without cloning the channel.
+    ///
+    /// Returns `None` if the channel is not cached.
+    ///
+    /// Refer to [`guild_field`] for caveats.
+    ///
+    /// [`GuildChannel`]: ../model/channel/struct.GuildChannel.html
+    /// [`guild_field`]: #method.guild_field
+    #[inline]
+    pub fn guild_channel_field<C, F, T>(&self, id: C, field_selector: F) -> Option<T>
+    where
+        C: Into<ChannelId>,
+        F: FnOnce(&GuildChannel) -> T,
+    {
+        self.channels
+            .get(&id.into())
+            .map(|channel| field_selector(&channel))
     }
 
     /// Retrieves a reference to a [`Group`] from the cache based on the given
@@ -521,32 +819,27 @@ impl Cache {
     /// Retrieve a group from the cache and print its owner's id:
     ///
     /// ```rust,no_run
-    /// # use serenity::cache::{Cache, CacheRwLock};
-    /// # use async_std::sync::RwLock;
-    /// # use std::{error::Error, sync::Arc};
+    /// # use serenity::cache::Cache;
     /// #
-    /// # async fn try_main() -> Result<(), Box<dyn Error>> {
-    /// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// if let Some(group) = cache.read().await.group(7) {
-    ///     println!("Owner Id: {}", group.read().await.owner_id);
+    /// # let cache = Cache::default();
+    /// if let Some(group) = cache.group(7) {
+    ///     println!("Owner Id: {}", group.owner_id);
     /// }
-    /// #     Ok(())
-    /// # }
     /// ```
     #[inline]
-    pub fn group<C: Into<ChannelId>>(&self, id: C) -> Option<Arc<AsyncRwLock<Group>>> {
+    pub fn group<C: Into<ChannelId>>(&self, id: C) -> Option<Group> {
         self._group(id.into())
     }
 
-    fn _group(&self, id: ChannelId) -> Option<Arc<AsyncRwLock<Group>>> {
-        self.groups.get(&id).cloned()
+    fn _group(&self, id: ChannelId) -> Option<Group> {
+        self.groups.get(&id).map(|group| group.clone())
     }
 
     /// Retrieves a [`Guild`]'s member from the cache based on the guild's and
     /// user's given Ids.
     ///
-    /// **Note**: This will clone the entire member. Instead, retrieve the guild
-    /// and retrieve from the guild's [`members`] map to avoid this.
+    /// **Note**: This will clone the entire member. To only read some of its
+    /// data, use [`member_field`] instead.
     ///
     /// # Examples
     ///
@@ -554,13 +847,9 @@ impl Cache {
     /// [`Client::on_message`] context:
     ///
     /// ```rust,ignore
-    /// # use serenity::{cache::{Cache, CacheRwLock}, model::prelude::*, prelude::*};
-    /// # use async_std::sync::RwLock;
-    /// # use std::sync::Arc;
+    /// # use serenity::{cache::Cache, model::prelude::*, prelude::*};
     /// #
-    /// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// let cache = cache.read();
-    ///
+    /// # let cache = Cache::default();
     /// let member = {
     ///     let channel = match cache.guild_channel(message.channel_id) {
     ///         Some(channel) => channel,
@@ -590,21 +879,138 @@ impl Cache {
     ///
     /// [`Client::on_message`]: ../client/struct.Client.html#method.on_message
     /// [`Guild`]: ../model/guild/struct.Guild.html
-    /// [`members`]: ../model/guild/struct.Guild.html#structfield.members
+    /// [`member_field`]: #method.member_field
     #[inline]
-    pub async fn member<G, U>(&self, guild_id: G, user_id: U) -> Option<Member>
+    pub fn member<G, U>(&self, guild_id: G, user_id: U) -> Option<Member>
     where
         G: Into<GuildId>,
         U: Into<UserId>,
     {
-        self._member(guild_id.into(), user_id.into()).await
+        self._member(guild_id.into(), user_id.into())
     }
 
-    async fn _member(&self, guild_id: GuildId, user_id: UserId) -> Option<Member> {
-        match self.guilds.get(&guild_id) {
-            Some(guild) => guild.read().await.members.get(&user_id).cloned(),
-            None => None,
-        }
+    fn _member(&self, guild_id: GuildId, user_id: UserId) -> Option<Member> {
+        self.guilds
+            .get(&guild_id)
+            .and_then(|guild| guild.members.get(&user_id).cloned())
+    }
+
+    /// Runs a closure against a cached [`Member`] and returns its result,
+    /// without cloning the member.
+    ///
+    /// Returns `None` if either the guild or the member is not cached.
+    ///
+    /// Refer to [`guild_field`] for caveats.
+    ///
+    /// # Examples
+    ///
+    /// Count the roles of a member without cloning them:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # let cache = Cache::default();
+    /// let role_count = cache.member_field(7, 8, |member| member.roles.len());
+    ///
+    /// println!("Roles: {}", role_count.unwrap_or(0));
+    /// ```
+    ///
+    /// [`Member`]: ../model/guild/struct.Member.html
+    /// [`guild_field`]: #method.guild_field
+    #[inline]
+    pub fn member_field<G, U, F, T>(&self, guild_id: G, user_id: U, field_selector: F) -> Option<T>
+    where
+        G: Into<GuildId>,
+        U: Into<UserId>,
+        F: FnOnce(&Member) -> T,
+    {
+        let user_id = user_id.into();
+
+        self.guilds
+            .get(&guild_id.into())
+            .and_then(|guild| guild.members.get(&user_id).map(field_selector))
+    }
+
+    /// Retrieves clones of all of a [`Guild`]'s cached [`Member`]s that match
+    /// the given predicate.
+    ///
+    /// Returns an empty `Vec` if the guild is not cached.
+    ///
+    /// The guild's entry is locked while the predicate runs, so avoid doing
+    /// anything slow within it.
+    ///
+    /// # Examples
+    ///
+    /// Find all members of a guild that have a nickname:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # let cache = Cache::default();
+    /// let nicked = cache.members_matching(7, |member| member.nick.is_some());
+    ///
+    /// println!("{} members have a nickname", nicked.len());
+    /// ```
+    ///
+    /// [`Guild`]: ../model/guild/struct.Guild.html
+    /// [`Member`]: ../model/guild/struct.Member.html
+    pub fn members_matching<G, F>(&self, guild_id: G, mut predicate: F) -> Vec<Member>
+    where
+        G: Into<GuildId>,
+        F: FnMut(&Member) -> bool,
+    {
+        self.guilds
+            .get(&guild_id.into())
+            .map(|guild| {
+                guild
+                    .members
+                    .values()
+                    .filter(|member| predicate(member))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Retrieves clones of all of a [`Guild`]'s cached [`Member`]s whose
+    /// username starts with the given `prefix`.
+    ///
+    /// If `include_nick` is `true`, members whose nickname starts with the
+    /// `prefix` are matched as well.
+    ///
+    /// Refer to [`members_matching`] for matching on other criteria.
+    ///
+    /// [`Guild`]: ../model/guild/struct.Guild.html
+    /// [`Member`]: ../model/guild/struct.Member.html
+    /// [`members_matching`]: #method.members_matching
+    pub fn members_starting_with<G>(
+        &self,
+        guild_id: G,
+        prefix: &str,
+        case_insensitive: bool,
+        include_nick: bool,
+    ) -> Vec<Member>
+    where
+        G: Into<GuildId>,
+    {
+        let prefix = if case_insensitive {
+            prefix.to_lowercase()
+        } else {
+            prefix.to_owned()
+        };
+
+        let starts_with = |name: &str| {
+            if case_insensitive {
+                name.to_lowercase().starts_with(&prefix)
+            } else {
+                name.starts_with(&prefix)
+            }
+        };
+
+        self.members_matching(guild_id, |member| {
+            starts_with(&member.user.name)
+                || (include_nick && member.nick.iter().any(|nick| starts_with(nick)))
+        })
     }
 
     /// Retrieves a [`Channel`]'s message from the cache based on the channel's and
@@ -618,17 +1024,15 @@ impl Cache {
     /// [`EventHandler::message`] context:
     ///
     /// ```rust,no_run
-    /// # use serenity::{cache::{Cache, CacheRwLock}, http::Http, model::id::{ChannelId, MessageId}};
-    /// # use async_std::sync::RwLock;
+    /// # use serenity::{cache::Cache, http::Http, model::id::{ChannelId, MessageId}};
     /// # use std::sync::Arc;
     /// #
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let http = Arc::new(Http::new_with_token("DISCORD_TOKEN"));
     /// # let message = ChannelId(0).message(&http, MessageId(1)).await.unwrap();
-    /// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
+    /// # let cache = Cache::default();
     /// #
-    /// let cache = cache.read().await;
     /// let fetched_message = cache.message(message.channel_id, message.id);
     ///
     /// match fetched_message {
@@ -659,6 +1063,77 @@ impl Cache {
             .and_then(|messages| messages.get(&message_id).cloned())
     }
 
+    /// Retrieves clones of all cached messages of a [`Channel`], oldest
+    /// first.
+    ///
+    /// Returns an empty `Vec` if no messages of the channel are cached.
+    ///
+    /// **Note**: Only as many messages as configured through
+    /// [`Settings::max_messages`] are kept per channel.
+    ///
+    /// # Examples
+    ///
+    /// Find the most recently cached message containing an invite:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{cache::Cache, model::id::ChannelId};
+    /// #
+    /// # let cache = Cache::default();
+    /// let invite = cache
+    ///     .messages_in(ChannelId(7))
+    ///     .into_iter()
+    ///     .rev()
+    ///     .find(|message| message.content.contains("discord.gg/"));
+    /// ```
+    ///
+    /// [`Channel`]: ../model/channel/enum.Channel.html
+    /// [`Settings::max_messages`]: struct.Settings.html#structfield.max_messages
+    #[inline]
+    pub fn messages_in<C: Into<ChannelId>>(&self, channel_id: C) -> Vec<Message> {
+        self._messages_in(channel_id.into(), |_| true)
+    }
+
+    /// Retrieves clones of all cached messages of a [`Channel`] sent by the
+    /// given user, oldest first.
+    ///
+    /// Refer to [`messages_in`] for more information.
+    ///
+    /// [`Channel`]: ../model/channel/enum.Channel.html
+    /// [`messages_in`]: #method.messages_in
+    #[inline]
+    pub fn messages_by_author<C, U>(&self, channel_id: C, user_id: U) -> Vec<Message>
+    where
+        C: Into<ChannelId>,
+        U: Into<UserId>,
+    {
+        let user_id = user_id.into();
+
+        self._messages_in(channel_id.into(), |message| message.author.id == user_id)
+    }
+
+    fn _messages_in(
+        &self,
+        channel_id: ChannelId,
+        filter: impl Fn(&Message) -> bool,
+    ) -> Vec<Message> {
+        // Lock the queue first, in the same order as when messages are cached.
+        let queue = match self.message_queue.get(&channel_id) {
+            Some(queue) => queue,
+            None => return Vec::new(),
+        };
+        let messages = match self.messages.get(&channel_id) {
+            Some(messages) => messages,
+            None => return Vec::new(),
+        };
+
+        queue
+            .iter()
+            .filter_map(|id| messages.get(id))
+            .filter(|message| filter(message))
+            .cloned()
+            .collect()
+    }
+
     /// Retrieves a [`PrivateChannel`] from the cache's [`private_channels`]
     /// map, if it exists.
     ///
@@ -673,20 +1148,12 @@ impl Cache {
     /// ```rust,no_run
     /// # use std::error::Error;
     /// #
-    /// # use serenity::{cache::{Cache, CacheRwLock}, model::prelude::*, prelude::*};
-    /// # use async_std::sync::RwLock;
-    /// # use std::sync::Arc;
+    /// # use serenity::{cache::Cache, model::prelude::*, prelude::*};
     /// #
-    /// # async fn try_main() -> Result<(), Box<dyn Error>> {
-    /// #   let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// #   let cache = cache.read().await;
-    /// // assuming the cache has been unlocked
-    ///
+    /// # fn try_main() -> Result<(), Box<dyn Error>> {
+    /// #   let cache = Cache::default();
     /// if let Some(channel) = cache.private_channel(7) {
-    ///     let channel_reader = channel.read().await;
-    ///     let user_reader = &channel_reader.recipient.read();
-    ///
-    ///     println!("The recipient is {}", user_reader.name);
+    ///     println!("The recipient is {}", channel.recipient.name);
     /// }
     /// #     Ok(())
     /// # }
@@ -695,15 +1162,14 @@ impl Cache {
     ///
     /// [`private_channels`]: #structfield.private_channels
     #[inline]
-    pub fn private_channel<C: Into<ChannelId>>(
-        &self,
-        channel_id: C,
-    ) -> Option<Arc<AsyncRwLock<PrivateChannel>>> {
+    pub fn private_channel<C: Into<ChannelId>>(&self, channel_id: C) -> Option<PrivateChannel> {
         self._private_channel(channel_id.into())
     }
 
-    fn _private_channel(&self, channel_id: ChannelId) -> Option<Arc<AsyncRwLock<PrivateChannel>>> {
-        self.private_channels.get(&channel_id).cloned()
+    fn _private_channel(&self, channel_id: ChannelId) -> Option<PrivateChannel> {
+        self.private_channels
+            .get(&channel_id)
+            .map(|channel| channel.clone())
     }
 
     /// Retrieves a [`Guild`]'s role by their Ids.
@@ -719,37 +1185,30 @@ impl Cache {
     /// Retrieve a role from the cache and print its name:
     ///
     /// ```rust,no_run
-    /// # use serenity::cache::{Cache, CacheRwLock};
-    /// # use async_std::sync::RwLock;
-    /// # use std::{error::Error, sync::Arc};
+    /// # use serenity::cache::Cache;
     /// #
-    /// # async fn try_main() -> Result<(), Box<dyn Error>> {
-    /// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
+    /// # let cache = Cache::default();
     /// // assuming the cache is in scope, e.g. via `Context`
-    /// let guard = cache.read().await;
-    /// if let Some(role) = guard.role(7, 77).await {
+    /// if let Some(role) = cache.role(7, 77) {
     ///     println!("Role with Id 77 is called {}", role.name);
     /// }
-    /// #     Ok(())
-    /// # }
     /// ```
     #[inline]
-    pub async fn role<G, R>(&self, guild_id: G, role_id: R) -> Option<Role>
+    pub fn role<G, R>(&self, guild_id: G, role_id: R) -> Option<Role>
     where
         G: Into<GuildId>,
         R: Into<RoleId>,
     {
-        self._role(guild_id.into(), role_id.into()).await
+        self._role(guild_id.into(), role_id.into())
     }
 
-    async fn _role(&self, guild_id: GuildId, role_id: RoleId) -> Option<Role> {
-        match self.guilds.get(&guild_id) {
-            Some(guild) => guild.read().await.roles.get(&role_id).cloned(),
-            None => None,
-        }
+    fn _role(&self, guild_id: GuildId, role_id: RoleId) -> Option<Role> {
+        self.guilds
+            .get(&guild_id)
+            .and_then(|guild| guild.roles.get(&role_id).cloned())
     }
 
-    /// Returns an immutable reference to the settings.
+    /// Returns a read guard to the settings.
     ///
     /// # Examples
     ///
@@ -758,14 +1217,14 @@ impl Cache {
     /// ```rust
     /// use serenity::cache::Cache;
     ///
-    /// let mut cache = Cache::new();
+    /// let cache = Cache::new();
     /// println!("Max settings: {}", cache.settings().max_messages);
     /// ```
-    pub fn settings(&self) -> &Settings {
-        &self.settings
+    pub fn settings(&self) -> RwLockReadGuard<'_, Settings> {
+        self.settings.read()
     }
 
-    /// Returns a mutable reference to the settings.
+    /// Returns a write guard to the settings.
     ///
     /// # Examples
     ///
@@ -774,267 +1233,2765 @@ impl Cache {
     /// ```rust
     /// use serenity::cache::Cache;
     ///
-    /// let mut cache = Cache::new();
+    /// let cache = Cache::new();
     /// cache.settings_mut().max_messages(10);
     /// ```
-    pub fn settings_mut(&mut self) -> &mut Settings {
-        &mut self.settings
+    pub fn settings_mut(&self) -> RwLockWriteGuard<'_, Settings> {
+        self.settings.write()
     }
 
-    /// Retrieves a `User` from the cache's [`users`] map, if it exists.
-    ///
-    /// The only advantage of this method is that you can pass in anything that
-    /// is indirectly a [`UserId`].
-    ///
-    /// [`UserId`]: ../model/id/struct.UserId.html
-    /// [`users`]: #structfield.users
+    /// Retrieves a copy of the current user.
     ///
     /// # Examples
     ///
-    /// Retrieve a user from the cache and print their name:
+    /// Print the name of the current user:
     ///
-    /// ```rust,no_run
-    /// # use serenity::client::Context;
-    /// # use serenity::framework::standard::{CommandResult, macros::command};
-    /// #
-    /// # #[command]
-    /// # async fn test(context: &mut Context) -> CommandResult {
-    /// if let Some(user) = context.cache.read().await.user(7) {
-    ///     println!("User with Id 7 is currently named {}", user.read().name);
-    /// }
-    /// # Ok(())
-    /// # }
-    /// #
-    /// # fn main() {}
+    /// ```rust
+    /// use serenity::cache::Cache;
+    ///
+    /// let cache = Cache::new();
+    /// println!("Logged in as {}", cache.current_user().name);
     /// ```
-    #[inline]
-    pub fn user<U: Into<UserId>>(&self, user_id: U) -> Option<Arc<SyncRwLock<User>>> {
-        self._user(user_id.into())
+    pub fn current_user(&self) -> CurrentUser {
+        self.user.read().clone()
     }
 
-    fn _user(&self, user_id: UserId) -> Option<Arc<SyncRwLock<User>>> {
-        self.users.get(&user_id).cloned()
+    /// Retrieves the Id of the current user.
+    #[inline]
+    pub fn current_user_id(&self) -> UserId {
+        self.user.read().id
     }
 
+    /// Retrieves the total number of shards being used by the bot.
     #[inline]
-    pub fn categories<C: Into<ChannelId>>(
-        &self,
-        channel_id: C,
-    ) -> Option<Arc<AsyncRwLock<ChannelCategory>>> {
-        self._categories(channel_id.into())
+    pub fn shard_count(&self) -> u64 {
+        self.shard_count.load(Ordering::Relaxed)
     }
 
-    fn _categories(&self, channel_id: ChannelId) -> Option<Arc<AsyncRwLock<ChannelCategory>>> {
-        self.categories.get(&channel_id).cloned()
+    /// Whether the initial set of guilds has been received, i.e. every shard
+    /// run by the client has received its [`Ready`] and the [`GuildCreate`]s
+    /// of all guilds listed in it.
+    ///
+    /// [`GuildCreate`]: ../model/event/enum.Event.html#variant.GuildCreate
+    /// [`Ready`]: ../model/event/enum.Event.html#variant.Ready
+    #[inline]
+    pub fn is_startup_complete(&self) -> bool {
+        self.startup_complete.load(Ordering::Acquire)
     }
 
-    /// Updates the cache with the update implementation for an event or other
-    /// custom update implementation.
+    /// Waits until the initial set of guilds has been received, returning
+    /// immediately if it already has.
     ///
-    /// Refer to the documentation for [`CacheUpdate`] for more information.
+    /// If guilds are still missing once the [`Settings::startup_timeout`]
+    /// passed, startup is completed regardless.
+    ///
+    /// Refer to [`is_startup_complete`] for more information.
     ///
     /// # Examples
     ///
-    /// Refer to the [`CacheUpdate` examples].
+    /// Wait for the cache to be filled before reporting the bot's guild
+    /// count:
     ///
-    /// [`CacheUpdate`]: trait.CacheUpdate.html
-    /// [`CacheUpdate` examples]: trait.CacheUpdate.html#examples
-    pub async fn update<E: CacheUpdate>(&mut self, e: &mut E) -> Option<E::Output> {
-        e.update(self).await
-    }
-
-    pub(crate) fn update_user_entry(&mut self, user: &User) {
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # async fn run(cache: &Cache) {
+    /// cache.wait_for_startup().await;
+    ///
+    /// println!("In {} guilds", cache.all_guilds().len());
+    /// # }
+    /// ```
+    ///
+    /// [`Settings::startup_timeout`]: struct.Settings.html#structfield.startup_timeout
+    /// [`is_startup_complete`]: #method.is_startup_complete
+    pub async fn wait_for_startup(&self) {
+        loop {
+            // Register for the notification before checking, so that it is
+            // not missed in between.
+            let notified = self.startup_notify.notified();
+
+            if self.is_startup_complete() {
+                return;
+            }
+
+            let deadline = *self.startup_deadline.read();
+
+            match deadline {
+                Some(deadline) => {
+                    let deadline = tokio::time::Instant::from_std(deadline);
+
+                    if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                        self.force_startup();
+                    }
+                }
+                None => notified.await,
+            }
+        }
+    }
+
+    /// Restarts the [`Settings::startup_timeout`], such as when a shard
+    /// received its [`Ready`].
+    ///
+    /// [`Ready`]: ../model/event/enum.Event.html#variant.Ready
+    /// [`Settings::startup_timeout`]: struct.Settings.html#structfield.startup_timeout
+    pub(crate) fn restart_startup_timeout(&self) {
+        if self.is_startup_complete() {
+            return;
+        }
+
+        *self.startup_deadline.write() = self
+            .settings()
+            .startup_timeout
+            .map(|timeout| Instant::now() + timeout);
+
+        // Let waiters pick up the new deadline.
+        self.startup_notify.notify_waiters();
+    }
+
+    /// Marks startup as complete although guilds are still missing, logging
+    /// which ones.
+    fn force_startup(&self) {
+        let completed = self
+            .startup_complete
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok();
+
+        if completed {
+            let pending = self
+                .unavailable_guilds
+                .iter()
+                .map(|guild_id| *guild_id)
+                .collect::<Vec<_>>();
+
+            warn!(
+                "Startup timed out with {} shard(s) ready, still missing guilds: {:?}",
+                self.ready_shards.len(),
+                pending
+            );
+
+            self.startup_notify.notify_waiters();
+        }
+    }
+
+    /// Marks startup as complete if the initial set of guilds has been
+    /// received.
+    ///
+    /// Returns `true` only on the call which completed startup.
+    pub(crate) fn check_startup(&self) -> bool {
+        if self.is_startup_complete() {
+            return false;
+        }
+
+        if matches!(*self.startup_deadline.read(), Some(deadline) if deadline <= Instant::now()) {
+            self.force_startup();
+
+            return false;
+        }
+
+        if !self.unavailable_guilds.is_empty() {
+            return false;
+        }
+
+        let expected = match self.startup_shards.load(Ordering::Relaxed) {
+            0 => self.shard_count(),
+            shards => shards,
+        };
+
+        if (self.ready_shards.len() as u64) < expected {
+            return false;
+        }
+
+        let completed = self
+            .startup_complete
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok();
+
+        if completed {
+            self.startup_notify.notify_waiters();
+        }
+
+        completed
+    }
+
+    /// Retrieves a `User` from the cache's [`users`] map, if it exists.
+    ///
+    /// The only advantage of this method is that you can pass in anything that
+    /// is indirectly a [`UserId`].
+    ///
+    /// [`UserId`]: ../model/id/struct.UserId.html
+    /// [`users`]: #structfield.users
+    ///
+    /// # Examples
+    ///
+    /// Retrieve a user from the cache and print their name:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::client::Context;
+    /// # use serenity::framework::standard::{CommandResult, macros::command};
+    /// #
+    /// # #[command]
+    /// # async fn test(context: &mut Context) -> CommandResult {
+    /// if let Some(user) = context.cache.user(7) {
+    ///     println!("User with Id 7 is currently named {}", user.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// #
+    /// # fn main() {}
+    /// ```
+    #[inline]
+    pub fn user<U: Into<UserId>>(&self, user_id: U) -> Option<User> {
+        self._user(user_id.into())
+    }
+
+    fn _user(&self, user_id: UserId) -> Option<User> {
+        self.users.get(&user_id).map(|user| user.clone())
+    }
+
+    /// Retrieves a clone of an active [`GuildThread`] from the cache based on
+    /// its Id.
+    ///
+    /// [`GuildThread`]: ../model/channel/struct.GuildThread.html
+    #[inline]
+    pub fn thread<C: Into<ChannelId>>(&self, id: C) -> Option<GuildThread> {
+        self._thread(id.into())
+    }
+
+    fn _thread(&self, id: ChannelId) -> Option<GuildThread> {
+        self.threads.get(&id).map(|thread| thread.clone())
+    }
+
+    #[inline]
+    pub fn categories<C: Into<ChannelId>>(&self, channel_id: C) -> Option<ChannelCategory> {
+        self._categories(channel_id.into())
+    }
+
+    fn _categories(&self, channel_id: ChannelId) -> Option<ChannelCategory> {
+        self.categories
+            .get(&channel_id)
+            .map(|category| category.clone())
+    }
+
+    /// Retrieves clones of the channels in a category, ordered by their
+    /// position.
+    ///
+    /// The children of each category are tracked as channels are received,
+    /// so this does not need to look through every cached channel.
+    ///
+    /// # Examples
+    ///
+    /// Print the names of the channels in a category:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # let cache = Cache::default();
+    /// for channel in cache.category_channels(7) {
+    ///     println!("#{}", channel.name);
+    /// }
+    /// ```
+    #[inline]
+    pub fn category_channels<C: Into<ChannelId>>(&self, category_id: C) -> Vec<GuildChannel> {
+        self._category_channels(category_id.into())
+    }
+
+    fn _category_channels(&self, category_id: ChannelId) -> Vec<GuildChannel> {
+        let ids = match self.category_children.get(&category_id) {
+            Some(children) => children.iter().copied().collect::<Vec<_>>(),
+            None => return Vec::new(),
+        };
+
+        let mut channels = ids
+            .into_iter()
+            .filter_map(|id| self.channels.get(&id).map(|channel| channel.clone()))
+            .collect::<Vec<_>>();
+        channels.sort_by_key(|channel| (channel.position, channel.id));
+
+        channels
+    }
+
+    /// Retrieves a clone of a guild's channel with the given name.
+    ///
+    /// Channels are indexed by name as they are received, so this does not
+    /// need to look through every channel of the guild. If several channels
+    /// share the name, the oldest one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # let cache = Cache::default();
+    /// if let Some(channel) = cache.channel_by_name(7, "general") {
+    ///     println!("#general has the Id {}", channel.id);
+    /// }
+    /// ```
+    #[inline]
+    pub fn channel_by_name<G: Into<GuildId>>(
+        &self,
+        guild_id: G,
+        name: &str,
+    ) -> Option<GuildChannel> {
+        self._channel_by_name(guild_id.into(), name)
+    }
+
+    fn _channel_by_name(&self, guild_id: GuildId, name: &str) -> Option<GuildChannel> {
+        let ids = self
+            .channel_names
+            .get(&guild_id)?
+            .get(name)?
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+
+        ids.iter().find_map(|id| {
+            self.channels
+                .get(id)
+                .filter(|channel| channel.name == name)
+                .map(|channel| channel.clone())
+        })
+    }
+
+    /// Retrieves a clone of a guild's role with the given name.
+    ///
+    /// Roles are indexed by name as they are received, so this does not need
+    /// to look through every role of the guild. If several roles share the
+    /// name, the oldest one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # let cache = Cache::default();
+    /// if let Some(role) = cache.role_by_name(7, "Moderator") {
+    ///     println!("The Moderator role has the Id {}", role.id);
+    /// }
+    /// ```
+    #[inline]
+    pub fn role_by_name<G: Into<GuildId>>(&self, guild_id: G, name: &str) -> Option<Role> {
+        self._role_by_name(guild_id.into(), name)
+    }
+
+    fn _role_by_name(&self, guild_id: GuildId, name: &str) -> Option<Role> {
+        let ids = self
+            .role_names
+            .get(&guild_id)?
+            .get(name)?
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+        let guild = self.guilds.get(&guild_id)?;
+
+        ids.iter()
+            .filter_map(|id| guild.roles.get(id))
+            .find(|role| role.name == name)
+            .cloned()
+    }
+
+    /// Retrieves a clone of the user with the given name and discriminator,
+    /// such as from a `name#1234` mention.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # let cache = Cache::default();
+    /// if let Some(user) = cache.user_by_tag("ferris", 1234) {
+    ///     println!("ferris#1234 has the Id {}", user.id);
+    /// }
+    /// ```
+    pub fn user_by_tag(&self, name: &str, discriminator: u16) -> Option<User> {
+        let id = *self.user_tags.get(&(name.to_string(), discriminator))?;
+
         self.users
-            .insert(user.id, Arc::new(SyncRwLock::new(user.clone())));
+            .get(&id)
+            .filter(|user| user.name == name && user.discriminator == discriminator)
+            .map(|user| user.clone())
+    }
+
+    /// Serializes the cached guilds, channels, threads, users, presences and
+    /// messages to the writer as JSON.
+    ///
+    /// The snapshot can later be restored with [`load_snapshot`], allowing a
+    /// restarted bot to start out with a warm cache instead of fetching
+    /// everything over the REST API. The [`Settings`] are not included.
+    ///
+    /// # Examples
+    ///
+    /// Save the cache to a file:
+    ///
+    /// ```rust,no_run
+    /// # fn run() -> serenity::Result<()> {
+    /// use serenity::cache::Cache;
+    /// use std::fs::File;
+    ///
+    /// let cache = Cache::new();
+    /// cache.save_snapshot(File::create("cache.json")?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Json`] if serializing or writing failed.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Settings`]: struct.Settings.html
+    /// [`load_snapshot`]: #method.load_snapshot
+    pub fn save_snapshot<W: Write>(&self, writer: W) -> crate::Result<()> {
+        let messages = self
+            .message_queue
+            .iter()
+            .filter_map(|queue| {
+                self.messages.get(queue.key()).map(|messages| {
+                    queue
+                        .iter()
+                        .filter_map(|id| messages.get(id).cloned())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .flatten()
+            .collect();
+
+        let snapshot = Snapshot {
+            version: SNAPSHOT_VERSION,
+            categories: values(&self.categories),
+            channels: values(&self.channels),
+            groups: values(&self.groups),
+            guilds: values(&self.guilds),
+            messages,
+            notes: self
+                .notes
+                .iter()
+                .map(|note| (*note.key(), note.value().clone()))
+                .collect(),
+            presences: values(&self.presences),
+            private_channels: values(&self.private_channels),
+            shard_count: self.shard_count(),
+            threads: values(&self.threads),
+            unavailable_guilds: self.unavailable_guilds.iter().map(|id| *id).collect(),
+            user: self.current_user(),
+            users: values(&self.users),
+        };
+
+        serde_json::to_writer(writer, &snapshot)?;
+
+        Ok(())
+    }
+
+    /// Replaces the cached data with a snapshot previously written by
+    /// [`save_snapshot`].
+    ///
+    /// The current [`Settings`] are kept.
+    ///
+    /// # Examples
+    ///
+    /// Restore the cache from a file before starting the client:
+    ///
+    /// ```rust,no_run
+    /// # fn run() -> serenity::Result<()> {
+    /// use serenity::cache::Cache;
+    /// use std::fs::File;
+    ///
+    /// let cache = Cache::new();
+    /// cache.load_snapshot(File::open("cache.json")?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Json`] if reading or deserializing failed, and an
+    /// [`Error::Decode`] if the snapshot was written in an unsupported format
+    /// version. The cache is left untouched on error.
+    ///
+    /// [`Error::Decode`]: ../enum.Error.html#variant.Decode
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Settings`]: struct.Settings.html
+    /// [`save_snapshot`]: #method.save_snapshot
+    pub fn load_snapshot<R: Read>(&self, reader: R) -> crate::Result<()> {
+        let snapshot: Snapshot = serde_json::from_reader(reader)?;
+
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(crate::Error::Decode(
+                "Unsupported cache snapshot version",
+                Value::from(snapshot.version),
+            ));
+        }
+
+        self.categories.clear();
+        self.category_children.clear();
+        self.channel_names.clear();
+        self.role_names.clear();
+        self.user_tags.clear();
+        self.channels.clear();
+        self.groups.clear();
+        self.guilds.clear();
+        self.messages.clear();
+        self.message_queue.clear();
+        self.notes.clear();
+        self.presences.clear();
+        self.presence_timestamps.clear();
+        self.private_channels.clear();
+        self.threads.clear();
+        self.unavailable_guilds.clear();
+        self.users.clear();
+
+        for guild in snapshot.guilds {
+            for channel in guild.channels.values() {
+                self.insert_channel(channel.clone());
+            }
+
+            for thread in guild.threads.values() {
+                self.threads.insert(thread.id, thread.clone());
+            }
+
+            self.replace_guild(guild);
+        }
+
+        for channel in snapshot.channels {
+            if !self.channels.contains_key(&channel.id) {
+                self.insert_channel(channel);
+            }
+        }
+
+        for category in snapshot.categories {
+            self.categories.insert(category.id, category);
+        }
+
+        for group in snapshot.groups {
+            self.groups.insert(group.channel_id, group);
+        }
+
+        for channel in snapshot.private_channels {
+            self.private_channels.insert(channel.id, channel);
+        }
+
+        for thread in snapshot.threads {
+            self.threads.entry(thread.id).or_insert(thread);
+        }
+
+        for presence in snapshot.presences {
+            self.presences.insert(presence.user_id, presence);
+        }
+
+        for user in snapshot.users {
+            self.insert_user(user);
+        }
+
+        for message in snapshot.messages {
+            self.message_queue
+                .entry(message.channel_id)
+                .or_default()
+                .push_back(message.id);
+            self.messages
+                .entry(message.channel_id)
+                .or_default()
+                .insert(message.id, message);
+        }
+
+        for (user_id, note) in snapshot.notes {
+            self.notes.insert(user_id, note);
+        }
+
+        for guild_id in snapshot.unavailable_guilds {
+            self.unavailable_guilds.insert(guild_id);
+        }
+
+        self.shard_count
+            .store(snapshot.shard_count, Ordering::Relaxed);
+        *self.user.write() = snapshot.user;
+
+        Ok(())
+    }
+
+    /// Copies the cached data into an immutable [`CacheView`].
+    ///
+    /// Reading from the view never waits on the cache's locks, and its
+    /// entries can be borrowed instead of cloned. Each map is copied in turn,
+    /// so an update applied while the snapshot is taken may be visible in
+    /// some maps of the view but not in others.
+    ///
+    /// **Note**: This clones the entire cache. Take a snapshot once for a
+    /// batch of reads rather than for every read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # let cache = Cache::default();
+    /// let view = cache.snapshot();
+    ///
+    /// for (guild_id, guild) in &view.guilds {
+    ///     if let Some(owner) = view.user(guild.owner_id) {
+    ///         println!("{} is owned by {}", guild_id, owner.name);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`CacheView`]: struct.CacheView.html
+    pub fn snapshot(&self) -> CacheView {
+        CacheView {
+            channels: to_map(&self.channels),
+            categories: to_map(&self.categories),
+            groups: to_map(&self.groups),
+            guilds: to_map(&self.guilds),
+            messages: to_map(&self.messages),
+            notes: to_map(&self.notes),
+            presences: to_map(&self.presences),
+            private_channels: to_map(&self.private_channels),
+            shard_count: self.shard_count(),
+            unavailable_guilds: self.unavailable_guilds.iter().map(|id| *id).collect(),
+            user: self.current_user(),
+            users: to_map(&self.users),
+        }
+    }
+
+    /// Removes presences which have not been updated within
+    /// [`Settings::presence_ttl`], both globally and from guilds, returning
+    /// the number of users whose presences expired.
+    ///
+    /// Presences otherwise stay in the cache until the user goes offline, so
+    /// this should be called periodically, such as from a task spawned once
+    /// the client is ready. Nothing is removed if no TTL is set.
+    ///
+    /// # Examples
+    ///
+    /// Expire presences after an hour, checking every ten minutes:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::{Cache, Settings};
+    /// # use std::{sync::Arc, time::Duration};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut settings = Settings::new();
+    /// settings.presence_ttl(Duration::from_secs(60 * 60));
+    /// let cache = Arc::new(Cache::new_with_settings(settings));
+    ///
+    /// tokio::spawn(async move {
+    ///     loop {
+    ///         tokio::time::sleep(Duration::from_secs(10 * 60)).await;
+    ///         cache.prune_expired();
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// [`Settings::presence_ttl`]: struct.Settings.html#structfield.presence_ttl
+    pub fn prune_expired(&self) -> usize {
+        let ttl = match self.settings().presence_ttl {
+            Some(ttl) => ttl,
+            None => return 0,
+        };
+
+        let now = Instant::now();
+        let mut expired = Vec::new();
+
+        self.presence_timestamps.retain(|user_id, updated_at| {
+            let keep = now.duration_since(*updated_at) < ttl;
+
+            if !keep {
+                expired.push(*user_id);
+            }
+
+            keep
+        });
+
+        if expired.is_empty() {
+            return 0;
+        }
+
+        for user_id in &expired {
+            self.presences.remove(user_id);
+        }
+
+        for mut guild in self.guilds.iter_mut() {
+            for user_id in &expired {
+                guild.presences.remove(user_id);
+            }
+        }
+
+        expired.len()
+    }
+
+    /// Estimates the memory used by the cached data, in bytes.
+    ///
+    /// Each entry is measured by the length of its JSON representation, which
+    /// follows its size in memory closely enough to budget against, but is
+    /// not exact. This serializes the whole cache, so avoid calling it on
+    /// every event.
+    pub fn approximate_size_bytes(&self) -> usize {
+        fn map_size<K, V: Serialize>(map: &DashMap<K, V>) -> usize
+        where
+            K: Eq + std::hash::Hash,
+        {
+            map.iter()
+                .map(|entry| size_of::<K>() + json_size(entry.value()))
+                .sum()
+        }
+
+        let messages = self
+            .messages
+            .iter()
+            .flat_map(|channel| {
+                channel
+                    .values()
+                    .map(|message| size_of::<MessageId>() + json_size(message))
+                    .collect::<Vec<_>>()
+            })
+            .sum::<usize>();
+        let message_queue = self
+            .message_queue
+            .iter()
+            .map(|queue| queue.len() * size_of::<MessageId>())
+            .sum::<usize>();
+        let notes = self
+            .notes
+            .iter()
+            .map(|note| size_of::<UserId>() + note.len())
+            .sum::<usize>();
+
+        map_size(&self.channels)
+            + map_size(&self.categories)
+            + map_size(&self.groups)
+            + map_size(&self.guilds)
+            + messages
+            + message_queue
+            + notes
+            + map_size(&self.presences)
+            + self.presence_timestamps.len() * size_of::<(UserId, Instant)>()
+            + map_size(&self.private_channels)
+            + map_size(&self.threads)
+            + self.unavailable_guilds.len() * size_of::<GuildId>()
+            + map_size(&self.users)
+    }
+
+    /// Evicts cached data until the [approximate size] of the cache is within
+    /// the given number of bytes, returning the approximate number of bytes
+    /// freed.
+    ///
+    /// Messages are evicted first, oldest first, followed by presences, those
+    /// updated least recently first, and finally users, starting with those
+    /// without a presence. Guilds and channels are never evicted, as much of
+    /// the library relies on them.
+    ///
+    /// # Examples
+    ///
+    /// Keep the cache under 256 MiB, checking every minute:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// # use std::{sync::Arc, time::Duration};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let cache = Arc::new(Cache::new());
+    ///
+    /// tokio::spawn(async move {
+    ///     loop {
+    ///         tokio::time::sleep(Duration::from_secs(60)).await;
+    ///         cache.trim_to(256 * 1024 * 1024);
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// [approximate size]: #method.approximate_size_bytes
+    pub fn trim_to(&self, bytes: usize) -> usize {
+        let initial = self.approximate_size_bytes();
+        let mut size = initial;
+
+        if size <= bytes {
+            return 0;
+        }
+
+        let mut messages = self
+            .messages
+            .iter()
+            .flat_map(|channel| {
+                let channel_id = *channel.key();
+
+                channel
+                    .values()
+                    .map(|message| (message.id, channel_id, json_size(message)))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        messages.sort_unstable_by_key(|(message_id, ..)| *message_id);
+
+        for (message_id, channel_id, message_size) in messages {
+            if size <= bytes {
+                break;
+            }
+
+            if let Some(mut queue) = self.message_queue.get_mut(&channel_id) {
+                if queue.front() == Some(&message_id) {
+                    queue.pop_front();
+                } else {
+                    queue.retain(|id| *id != message_id);
+                }
+            }
+
+            if let Some(mut channel) = self.messages.get_mut(&channel_id) {
+                channel.remove(&message_id);
+            }
+
+            size = size.saturating_sub(size_of::<MessageId>() * 2 + message_size);
+        }
+
+        // Presences are stored both globally and per guild, so a user's
+        // presences are evicted everywhere at once.
+        let mut presence_sizes = HashMap::new();
+
+        for presence in self.presences.iter() {
+            *presence_sizes.entry(*presence.key()).or_insert(0) +=
+                size_of::<UserId>() + json_size(presence.value());
+        }
+
+        for guild in self.guilds.iter() {
+            for (user_id, presence) in &guild.presences {
+                *presence_sizes.entry(*user_id).or_insert(0) += json_size(presence);
+            }
+        }
+
+        let mut presences = presence_sizes
+            .into_iter()
+            .map(|(user_id, presence_size)| {
+                let updated_at = self.presence_timestamps.get(&user_id).map(|t| *t);
+
+                (updated_at, user_id, presence_size)
+            })
+            .collect::<Vec<_>>();
+        presences.sort_unstable_by_key(|(updated_at, ..)| *updated_at);
+
+        let mut evicted = Vec::new();
+
+        for (_, user_id, presence_size) in presences {
+            if size <= bytes {
+                break;
+            }
+
+            self.presences.remove(&user_id);
+
+            if self.presence_timestamps.remove(&user_id).is_some() {
+                size = size.saturating_sub(size_of::<(UserId, Instant)>());
+            }
+
+            size = size.saturating_sub(presence_size);
+            evicted.push(user_id);
+        }
+
+        if !evicted.is_empty() {
+            for mut guild in self.guilds.iter_mut() {
+                for user_id in &evicted {
+                    guild.presences.remove(user_id);
+                }
+            }
+        }
+
+        let current_user_id = self.current_user_id();
+        let mut users = self
+            .users
+            .iter()
+            .filter(|user| *user.key() != current_user_id)
+            .map(|user| {
+                let has_presence = self.presences.contains_key(user.key());
+                let updated_at = self.presence_timestamps.get(user.key()).map(|t| *t);
+
+                (
+                    has_presence,
+                    updated_at,
+                    *user.key(),
+                    json_size(user.value()),
+                )
+            })
+            .collect::<Vec<_>>();
+        users.sort_unstable_by_key(|(has_presence, updated_at, ..)| (*has_presence, *updated_at));
+
+        for (_, _, user_id, user_size) in users {
+            if size <= bytes {
+                break;
+            }
+
+            self.remove_user(user_id);
+            size = size.saturating_sub(size_of::<UserId>() + user_size);
+        }
+
+        initial - size
+    }
+
+    /// Fills the cache with the channels, roles, and members of the given
+    /// guilds by requesting them over the REST API.
+    ///
+    /// Guilds are otherwise only cached once their [`GuildCreate`] event is
+    /// received, which may take a while after startup for bots in many guilds.
+    /// Warming the cache allows methods which rely on it to work in the
+    /// meantime.
+    ///
+    /// Guilds which are already cached keep their gateway-only data, such as
+    /// presences and voice states, and have their channels, roles, emojis,
+    /// and members refreshed.
+    ///
+    /// **Note**: This makes at least three requests per guild, and one more
+    /// for every thousand members. Retrieving members requires the
+    /// `GUILD_MEMBERS` intent to be enabled for the bot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{cache::Cache, http::Http, model::id::GuildId};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::new_with_token("DISCORD_TOKEN");
+    /// let cache = Cache::new();
+    /// cache.warm_from_http(&http, vec![GuildId(81384788765712384)]).await?;
+    ///
+    /// assert!(cache.guild(81384788765712384).is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if a request fails. Guilds warmed before
+    /// the failing request remain cached.
+    ///
+    /// [`Error::Http`]: ../enum.Error.html#variant.Http
+    /// [`GuildCreate`]: ../model/event/enum.Event.html#variant.GuildCreate
+    #[cfg(feature = "http")]
+    pub async fn warm_from_http<I>(&self, http: impl AsRef<Http>, guild_ids: I) -> crate::Result<()>
+    where
+        I: IntoIterator<Item = GuildId>,
+    {
+        let http = http.as_ref();
+
+        for guild_id in guild_ids {
+            let partial = http.get_guild(guild_id.0).await?;
+            let channels = http.get_channels(guild_id.0).await?;
+            let mut members = Vec::new();
+
+            loop {
+                let after = members.last().map(|member: &Member| member.user.id.0);
+                let page = http
+                    .get_guild_members(guild_id.0, Some(1000), after)
+                    .await?;
+                let done = page.len() < 1000;
+
+                members.extend(page);
+
+                if done {
+                    break;
+                }
+            }
+
+            for member in &members {
+                self.update_user_entry(&member.user);
+            }
+
+            for channel in &channels {
+                self.insert_channel(channel.clone());
+            }
+
+            let channels = channels.into_iter().map(|c| (c.id, c)).collect();
+            let members = members.into_iter().map(|m| (m.user.id, m)).collect();
+
+            if let Some(mut guild) = self.guilds.get_mut(&guild_id) {
+                guild.channels = channels;
+                guild.emojis = partial.emojis;
+                guild.roles = partial.roles;
+                guild.members = members;
+                guild.member_count = guild.members.len() as u64;
+                self.strip_guild(&mut guild);
+                self.index_roles(guild_id, &guild.roles);
+
+                continue;
+            }
+
+            let current_user_id = self.current_user_id();
+            let joined_at = members
+                .get(&current_user_id)
+                .and_then(|member: &Member| member.joined_at)
+                .unwrap_or_else(Timestamp::now);
+            let member_count = members.len() as u64;
+
+            let mut guild = Guild {
+                afk_channel_id: partial.afk_channel_id,
+                afk_timeout: partial.afk_timeout,
+                application_id: None,
+                channels,
+                default_message_notifications: partial.default_message_notifications,
+                emojis: partial.emojis,
+                explicit_content_filter: ExplicitContentFilter::None,
+                features: partial.features,
+                icon: partial.icon,
+                id: guild_id,
+                joined_at,
+                large: member_count > u64::from(crate::constants::LARGE_THRESHOLD),
+                member_count,
+                members,
+                mfa_level: partial.mfa_level,
+                name: partial.name,
+                owner_id: partial.owner_id,
+                presences: HashMap::new(),
+                region: partial.region,
+                roles: partial.roles,
+                splash: partial.splash,
+                system_channel_id: None,
+                verification_level: partial.verification_level,
+                voice_states: HashMap::new(),
+                threads: HashMap::new(),
+                description: partial.description,
+                premium_tier: partial.premium_tier,
+                premium_subscription_count: partial.premium_subscription_count,
+                banner: partial.banner,
+                vanity_url_code: partial.vanity_url_code,
+                preferred_locale: crate::constants::DEFAULT_LOCALE.to_string(),
+            };
+
+            self.strip_guild(&mut guild);
+            self.unavailable_guilds.remove(&guild_id);
+            self.replace_guild(guild);
+        }
+
+        Ok(())
+    }
+
+    /// Re-fetches the data, roles, and channels of a guild over the REST
+    /// API, and reconciles the cache with them.
+    ///
+    /// This is a recovery tool for when the cache is suspected to have
+    /// drifted from Discord, such as after events were missed, without
+    /// restarting the shard. Channels which no longer exist are removed, and
+    /// members lose roles which were deleted. Members, presences, and voice
+    /// states are kept as they are.
+    ///
+    /// If the guild is not cached yet, it is warmed as by
+    /// [`warm_from_http`], which also retrieves its members.
+    ///
+    /// **Note**: This makes two requests.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{cache::Cache, http::Http, model::id::GuildId};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::new_with_token("DISCORD_TOKEN");
+    /// # let cache = Cache::new();
+    /// cache.refresh_guild(&http, GuildId(81384788765712384)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if a request fails, in which case the cache
+    /// is left untouched.
+    ///
+    /// [`Error::Http`]: ../enum.Error.html#variant.Http
+    /// [`warm_from_http`]: #method.warm_from_http
+    #[cfg(feature = "http")]
+    pub async fn refresh_guild(
+        &self,
+        http: impl AsRef<Http>,
+        guild_id: impl Into<GuildId>,
+    ) -> crate::Result<()> {
+        let http = http.as_ref();
+        let guild_id = guild_id.into();
+
+        if !self.guilds.contains_key(&guild_id) {
+            return self.warm_from_http(http, Some(guild_id)).await;
+        }
+
+        let partial = http.get_guild(guild_id.0).await?;
+        let channels = http
+            .get_channels(guild_id.0)
+            .await?
+            .into_iter()
+            .map(|channel| (channel.id, channel))
+            .collect::<HashMap<_, _>>();
+
+        let stale = self
+            .channels
+            .iter()
+            .filter(|channel| channel.guild_id == guild_id && !channels.contains_key(channel.key()))
+            .map(|channel| *channel.key())
+            .collect::<Vec<_>>();
+
+        for channel_id in stale {
+            self.remove_channel(channel_id);
+        }
+
+        for channel in channels.values() {
+            self.insert_channel(channel.clone());
+        }
+
+        if let Some(mut guild) = self.guilds.get_mut(&guild_id) {
+            guild.merge_partial(&partial);
+            guild.channels = channels;
+
+            let Guild { members, roles, .. } = &mut *guild;
+
+            for member in members.values_mut() {
+                member.roles.retain(|role_id| roles.contains_key(role_id));
+            }
+
+            self.index_roles(guild_id, &guild.roles);
+        }
+
+        Ok(())
+    }
+
+    /// Inserts a guild received in full, such as from a [`GuildCreate`],
+    /// along with its channels and its members' users.
+    ///
+    /// [`GuildCreate`]: ../model/event/enum.Event.html#variant.GuildCreate
+    pub(crate) fn insert_guild(&self, mut guild: Guild) {
+        self.unavailable_guilds.remove(&guild.id);
+
+        if self.settings().cache_users {
+            for member in guild.members.values() {
+                self.insert_user(member.user.clone());
+            }
+        }
+
+        self.strip_guild(&mut guild);
+
+        for channel in guild.channels.values() {
+            self.insert_channel(channel.clone());
+        }
+
+        for thread in guild.threads.values() {
+            self.threads.insert(thread.id, thread.clone());
+        }
+
+        self.replace_guild(guild);
+    }
+
+    /// Inserts a thread into [`threads`] and its guild, returning the
+    /// previous version.
+    ///
+    /// [`threads`]: #structfield.threads
+    pub(crate) fn insert_thread(&self, thread: GuildThread) -> Option<GuildThread> {
+        if let Some(mut guild) = self.guilds.get_mut(&thread.guild_id) {
+            guild.threads.insert(thread.id, thread.clone());
+        }
+
+        self.threads.insert(thread.id, thread)
+    }
+
+    /// Removes a thread from [`threads`] and its guild, along with its cached
+    /// messages.
+    ///
+    /// [`threads`]: #structfield.threads
+    pub(crate) fn remove_thread(&self, guild_id: GuildId, id: ChannelId) -> Option<GuildThread> {
+        if let Some(mut guild) = self.guilds.get_mut(&guild_id) {
+            guild.threads.remove(&id);
+        }
+
+        self.messages.remove(&id);
+        self.message_queue.remove(&id);
+
+        self.threads.remove(&id).map(|(_, thread)| thread)
+    }
+
+    /// Inserts a channel into [`channels`], moving it to the children of its
+    /// new category if it changed, and returns the previous version.
+    ///
+    /// [`channels`]: #structfield.channels
+    pub(crate) fn insert_channel(&self, channel: GuildChannel) -> Option<GuildChannel> {
+        let (id, category_id, guild_id) = (channel.id, channel.category_id, channel.guild_id);
+        let name = channel.name.clone();
+        let old = self.channels.insert(id, channel);
+
+        if let Some(old) = &old {
+            if old.guild_id != guild_id || old.name != name {
+                unlink_name(&self.channel_names, old.guild_id, &old.name, id);
+            }
+        }
+
+        self.channel_names
+            .entry(guild_id)
+            .or_default()
+            .entry(name)
+            .or_default()
+            .insert(id);
+
+        if let Some(old_category_id) = old.as_ref().and_then(|old| old.category_id) {
+            if category_id != Some(old_category_id) {
+                self.unlink_category_child(old_category_id, id);
+            }
+        }
+
+        if let Some(category_id) = category_id {
+            self.category_children
+                .entry(category_id)
+                .or_default()
+                .insert(id);
+        }
+
+        old
+    }
+
+    /// Removes a channel from [`channels`] and from the children of its
+    /// category.
+    ///
+    /// [`channels`]: #structfield.channels
+    pub(crate) fn remove_channel(&self, id: ChannelId) -> Option<GuildChannel> {
+        let (_, old) = self.channels.remove(&id)?;
+
+        unlink_name(&self.channel_names, old.guild_id, &old.name, id);
+
+        if let Some(category_id) = old.category_id {
+            self.unlink_category_child(category_id, id);
+        }
+
+        Some(old)
+    }
+
+    fn unlink_category_child(&self, category_id: ChannelId, id: ChannelId) {
+        if let Some(mut children) = self.category_children.get_mut(&category_id) {
+            children.remove(&id);
+        }
+
+        self.category_children
+            .remove_if(&category_id, |_, children| children.is_empty());
+    }
+
+    /// Inserts a guild into [`guilds`] as-is, indexing its roles by name, and
+    /// returns the previous version.
+    ///
+    /// [`guilds`]: #structfield.guilds
+    pub(crate) fn replace_guild(&self, guild: Guild) -> Option<Guild> {
+        self.index_roles(guild.id, &guild.roles);
+
+        self.guilds.insert(guild.id, guild)
+    }
+
+    /// Removes a guild from [`guilds`] along with its role name index.
+    ///
+    /// The guild's channels are left in place.
+    ///
+    /// [`guilds`]: #structfield.guilds
+    pub(crate) fn remove_guild(&self, id: GuildId) -> Option<Guild> {
+        self.role_names.remove(&id);
+
+        self.guilds.remove(&id).map(|(_, guild)| guild)
+    }
+
+    /// Rebuilds the role name index of a guild from all of its roles.
+    pub(crate) fn index_roles(&self, guild_id: GuildId, roles: &HashMap<RoleId, Role>) {
+        let mut names = HashMap::<String, BTreeSet<RoleId>>::with_capacity(roles.len());
+
+        for role in roles.values() {
+            names.entry(role.name.clone()).or_default().insert(role.id);
+        }
+
+        self.role_names.insert(guild_id, names);
+    }
+
+    /// Indexes a role that was created or updated, unlinking the name of the
+    /// previous version if it was renamed.
+    pub(crate) fn index_role(&self, guild_id: GuildId, role: &Role, old: Option<&Role>) {
+        if let Some(old) = old {
+            if old.name != role.name {
+                unlink_name(&self.role_names, guild_id, &old.name, old.id);
+            }
+        }
+
+        self.role_names
+            .entry(guild_id)
+            .or_default()
+            .entry(role.name.clone())
+            .or_default()
+            .insert(role.id);
+    }
+
+    /// Removes a deleted role from the role name index.
+    pub(crate) fn unindex_role(&self, guild_id: GuildId, role: &Role) {
+        unlink_name(&self.role_names, guild_id, &role.name, role.id);
+    }
+
+    /// Inserts a user into [`users`], keeping the index of users by name and
+    /// discriminator up to date.
+    ///
+    /// This ignores [`Settings::cache_users`]; use [`update_user_entry`] to
+    /// respect it.
+    ///
+    /// [`Settings::cache_users`]: struct.Settings.html#structfield.cache_users
+    /// [`update_user_entry`]: #method.update_user_entry
+    /// [`users`]: #structfield.users
+    pub(crate) fn insert_user(&self, user: User) -> Option<User> {
+        let (id, tag) = (user.id, (user.name.clone(), user.discriminator));
+        let old = self.users.insert(id, user);
+
+        if let Some(old) = &old {
+            if old.name != tag.0 || old.discriminator != tag.1 {
+                self.user_tags
+                    .remove_if(&(old.name.clone(), old.discriminator), |_, v| *v == id);
+            }
+        }
+
+        self.user_tags.insert(tag, id);
+
+        old
+    }
+
+    /// Removes a user from [`users`] and from the index of users by name and
+    /// discriminator.
+    ///
+    /// [`users`]: #structfield.users
+    pub(crate) fn remove_user(&self, id: UserId) -> Option<User> {
+        let (_, old) = self.users.remove(&id)?;
+
+        self.user_tags
+            .remove_if(&(old.name.clone(), old.discriminator), |_, v| *v == id);
+
+        Some(old)
+    }
+
+    /// Updates the cache with the update implementation for an event or other
+    /// custom update implementation.
+    ///
+    /// Refer to the documentation for [`CacheUpdate`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Refer to the [`CacheUpdate` examples].
+    ///
+    /// [`CacheUpdate`]: trait.CacheUpdate.html
+    /// [`CacheUpdate` examples]: trait.CacheUpdate.html#examples
+    pub async fn update<E: CacheUpdate>(&self, e: &mut E) -> Option<E::Output> {
+        e.update(self).await
+    }
+
+    /// Updates the cache with many events at once, such as the burst of
+    /// [`GuildCreate`]s received on startup.
+    ///
+    /// Events are applied in order, as by [`update`], so a batch produces the
+    /// same cache as updating with each event individually. They are then
+    /// recorded for [`recent_events`] under a single acquisition of the lock
+    /// on the records, and passed to the registered [`CacheUpdateHook`]s as
+    /// the client does for the events it receives.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{cache::Cache, model::event::Event};
+    /// #
+    /// # async fn run(cache: &Cache, mut events: Vec<Event>) {
+    /// cache.update_batch(&mut events).await;
+    /// # }
+    /// ```
+    ///
+    /// [`CacheUpdateHook`]: trait.CacheUpdateHook.html
+    /// [`GuildCreate`]: ../model/event/enum.Event.html#variant.GuildCreate
+    /// [`recent_events`]: #method.recent_events
+    /// [`update`]: #method.update
+    pub async fn update_batch(&self, events: &mut [Event]) {
+        let mut applied = Vec::with_capacity(events.len());
+
+        for event in events.iter_mut() {
+            applied.push(event.update_unrecorded(self).await);
+        }
+
+        self.record_events(events.iter().zip(applied).filter_map(|(event, applied)| {
+            if applied {
+                Some(event)
+            } else {
+                None
+            }
+        }));
+
+        if self.has_update_hooks() {
+            for event in events.iter() {
+                self.run_update_hooks(event).await;
+            }
+        }
+    }
+
+    /// Queues an event to be applied by [`update_batch`] along with the
+    /// events queued while the previous batch was being applied, such as by
+    /// other shards receiving their guilds at the same time.
+    ///
+    /// Returns once the event has been applied, by this call or another.
+    ///
+    /// [`update_batch`]: #method.update_batch
+    pub(crate) async fn update_queued(&self, event: Event) {
+        self.queued_updates.lock().push(event);
+
+        let _batch = self.batch_lock.lock().await;
+        let mut events = mem::take(&mut *self.queued_updates.lock());
+
+        if !events.is_empty() {
+            self.update_batch(&mut events).await;
+        }
+    }
+
+    /// Removes data from a guild which the [`Settings`] specify should not be
+    /// cached.
+    ///
+    /// [`Settings`]: struct.Settings.html
+    pub(crate) fn strip_guild(&self, guild: &mut Guild) {
+        let settings = self.settings();
+
+        if !settings.cache_presences {
+            guild.presences.clear();
+        } else if settings.compact_presences {
+            guild.presences.values_mut().for_each(Presence::compact);
+        }
+
+        if settings.cache_presences {
+            let now = Instant::now();
+
+            for user_id in guild.presences.keys() {
+                self.presence_timestamps.insert(*user_id, now);
+            }
+        }
+
+        if !settings.cache_voice_states {
+            guild.voice_states.clear();
+        }
+
+        if !settings.cache_emojis {
+            guild.emojis.clear();
+        }
+    }
+
+    /// Returns a copy of the presence to be stored in the cache, compacted if
+    /// [`Settings::compact_presences`] is enabled.
+    ///
+    /// [`Settings::compact_presences`]: struct.Settings.html#structfield.compact_presences
+    pub(crate) fn prepare_presence(&self, presence: &Presence) -> Presence {
+        let mut presence = presence.clone();
+
+        if self.settings().compact_presences {
+            presence.compact();
+        }
+
+        self.touch_presence(presence.user_id);
+
+        presence
+    }
+
+    /// Records that the user's presence was just stored.
+    pub(crate) fn touch_presence(&self, user_id: UserId) {
+        self.presence_timestamps.insert(user_id, Instant::now());
+    }
+
+    /// Stores a copy of the user in the cache, unless
+    /// [`Settings::cache_users`] is disabled.
+    ///
+    /// [`Settings::cache_users`]: struct.Settings.html#structfield.cache_users
+    pub(crate) fn update_user_entry(&self, user: &User) {
+        if self.settings().cache_users {
+            self.insert_user(user.clone());
+        }
+    }
+}
+
+/// Removes an Id from the set under a name in a per-guild name index,
+/// dropping the set and the guild's map once they are empty.
+fn unlink_name<K: Ord>(
+    index: &DashMap<GuildId, HashMap<String, BTreeSet<K>>>,
+    guild_id: GuildId,
+    name: &str,
+    id: K,
+) {
+    if let Some(mut names) = index.get_mut(&guild_id) {
+        if let Some(ids) = names.get_mut(name) {
+            ids.remove(&id);
+
+            if ids.is_empty() {
+                names.remove(name);
+            }
+        }
+    }
+
+    index.remove_if(&guild_id, |_, names| names.is_empty());
+}
+
+/// Clones all entries of a map into a plain map.
+fn to_map<K, V>(map: &DashMap<K, V>) -> HashMap<K, V>
+where
+    K: Copy + Eq + std::hash::Hash,
+    V: Clone,
+{
+    map.iter()
+        .map(|entry| (*entry.key(), entry.value().clone()))
+        .collect()
+}
+
+/// Clones all values of a map.
+fn values<K, V>(map: &DashMap<K, V>) -> Vec<V>
+where
+    K: Eq + std::hash::Hash,
+    V: Clone,
+{
+    map.iter().map(|entry| entry.value().clone()).collect()
+}
+
+impl Default for Cache {
+    fn default() -> Cache {
+        Cache {
+            channels: DashMap::default(),
+            categories: DashMap::default(),
+            category_children: DashMap::default(),
+            channel_names: DashMap::default(),
+            groups: DashMap::with_capacity(128),
+            guilds: DashMap::default(),
+            role_names: DashMap::default(),
+            messages: DashMap::default(),
+            notes: DashMap::default(),
+            presences: DashMap::default(),
+            private_channels: DashMap::with_capacity(128),
+            threads: DashMap::default(),
+            settings: SyncRwLock::new(Settings::default()),
+            shard_count: AtomicU64::new(1),
+            unavailable_guilds: DashSet::default(),
+            ready_shards: DashSet::default(),
+            startup_shards: AtomicU64::new(0),
+            startup_complete: AtomicBool::new(false),
+            startup_notify: Notify::new(),
+            startup_deadline: SyncRwLock::new(None),
+            user: SyncRwLock::new(CurrentUser::default()),
+            users: DashMap::default(),
+            user_tags: DashMap::default(),
+            message_queue: DashMap::default(),
+            presence_timestamps: DashMap::default(),
+            recent_events: SyncRwLock::new(VecDeque::new()),
+            update_hooks: UpdateHooks::default(),
+            queued_updates: SyncMutex::new(Vec::new()),
+            batch_lock: AsyncMutex::new(()),
+        }
+    }
+}
+
+impl AsRef<Cache> for Cache {
+    fn as_ref(&self) -> &Cache {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::guild::PremiumTier::Tier2;
+    use crate::{
+        cache::{Cache, CacheUpdate, Settings},
+        model::prelude::*,
+        utils::run_async_test,
+    };
+    use serde_json::{Number, Value};
+    use std::{collections::HashMap, sync::atomic::Ordering, time::Duration};
+
+    #[test]
+    fn test_cache_messages() {
+        run_async_test(async move {
+            let mut settings = Settings::new();
+            settings.max_messages(2);
+            let cache = Cache::new_with_settings(settings);
+
+            // Test inserting one message into a channel's message cache.
+            let datetime = Timestamp::parse("1983-04-13T12:09:14.274+00:00").unwrap();
+            let mut event = MessageCreateEvent {
+                message: Message {
+                    id: MessageId(3),
+                    attachments: vec![],
+                    author: User {
+                        id: UserId(2),
+                        avatar: None,
+                        bot: false,
+                        discriminator: 1,
+                        name: "user 1".to_owned(),
+                    },
+                    channel_id: ChannelId(2),
+                    guild_id: Some(GuildId(1)),
+                    content: String::new(),
+                    edited_timestamp: None,
+                    embeds: vec![],
+                    kind: MessageType::Regular,
+                    member: None,
+                    mention_everyone: false,
+                    mention_roles: vec![],
+                    mention_channels: None,
+                    mentions: vec![],
+                    nonce: Value::Number(Number::from(1)),
+                    pinned: false,
+                    reactions: vec![],
+                    timestamp: datetime.clone(),
+                    tts: false,
+                    webhook_id: None,
+                    activity: None,
+                    application: None,
+                    message_reference: None,
+                    flags: None,
+                    sticker_items: Vec::new(),
+                },
+            };
+            // Check that the channel cache doesn't exist.
+            assert!(!cache.messages.contains_key(&event.message.channel_id));
+            // Add first message, none because message ID 2 doesn't already exist.
+            assert!(event.update(&cache).await.is_none());
+            // None, it only returns the oldest message if the cache was already full.
+            assert!(event.update(&cache).await.is_none());
+            // Assert there's only 1 message in the channel's message cache.
+            assert_eq!(
+                cache.messages.get(&event.message.channel_id).unwrap().len(),
+                1
+            );
+
+            // Add a second message, assert that channel message cache length is 2.
+            event.message.id = MessageId(4);
+            assert!(event.update(&cache).await.is_none());
+            assert_eq!(
+                cache.messages.get(&event.message.channel_id).unwrap().len(),
+                2
+            );
+
+            // Add a third message, the first should now be removed.
+            event.message.id = MessageId(5);
+            assert!(event.update(&cache).await.is_some());
+
+            {
+                let channel = cache.messages.get(&event.message.channel_id).unwrap();
+
+                assert_eq!(channel.len(), 2);
+                // Check that the first message is now removed.
+                assert!(!channel.contains_key(&MessageId(3)));
+            }
+
+            let guild_channel = GuildChannel {
+                id: event.message.channel_id,
+                bitrate: None,
+                category_id: None,
+                guild_id: event.message.guild_id.unwrap(),
+                kind: ChannelType::Text,
+                last_message_id: None,
+                last_pin_timestamp: None,
+                name: String::new(),
+                permission_overwrites: vec![],
+                position: 0,
+                topic: None,
+                user_limit: None,
+                nsfw: false,
+                slow_mode_rate: Some(0),
+            };
+
+            // Add a channel delete event to the cache, the cached messages for that
+            // channel should now be gone.
+            let mut delete = ChannelDeleteEvent {
+                channel: Channel::Guild(guild_channel.clone()),
+            };
+            assert!(cache.update(&mut delete).await.is_none());
+            assert!(!cache.messages.contains_key(&delete.channel.id()));
+
+            // Test deletion of a guild channel's message cache when a GuildDeleteEvent
+            // is received.
+            let mut guild_create = {
+                let mut channels = HashMap::new();
+                channels.insert(ChannelId(2), guild_channel.clone());
+
+                GuildCreateEvent {
+                    guild: Guild {
+                        id: GuildId(1),
+                        afk_channel_id: None,
+                        afk_timeout: 0,
+                        application_id: None,
+                        default_message_notifications: DefaultMessageNotificationLevel::All,
+                        emojis: HashMap::new(),
+                        explicit_content_filter: ExplicitContentFilter::None,
+                        features: vec![],
+                        icon: None,
+                        joined_at: datetime,
+                        large: false,
+                        member_count: 0,
+                        members: HashMap::new(),
+                        mfa_level: MfaLevel::None,
+                        name: String::new(),
+                        owner_id: UserId(3),
+                        presences: HashMap::new(),
+                        region: String::new(),
+                        roles: HashMap::new(),
+                        splash: None,
+                        system_channel_id: None,
+                        verification_level: VerificationLevel::Low,
+                        voice_states: HashMap::new(),
+                        threads: HashMap::new(),
+                        description: None,
+                        premium_tier: PremiumTier::Tier0,
+                        channels,
+                        premium_subscription_count: 0,
+                        banner: None,
+                        vanity_url_code: Some("bruhmoment".to_string()),
+                        preferred_locale: "en-US".to_string(),
+                    },
+                }
+            };
+            assert!(cache.update(&mut guild_create).await.is_none());
+            assert!(cache.update(&mut event).await.is_none());
+
+            let mut guild_delete = GuildDeleteEvent {
+                guild: PartialGuild {
+                    id: GuildId(1),
+                    afk_channel_id: None,
+                    afk_timeout: 0,
+                    default_message_notifications: DefaultMessageNotificationLevel::All,
+                    embed_channel_id: None,
+                    embed_enabled: false,
+                    emojis: HashMap::new(),
+                    features: vec![],
+                    icon: None,
+                    mfa_level: MfaLevel::None,
+                    name: String::new(),
+                    owner_id: UserId(3),
+                    region: String::new(),
+                    roles: HashMap::new(),
+                    splash: None,
+                    verification_level: VerificationLevel::Low,
+                    description: None,
+                    premium_tier: Tier2,
+                    premium_subscription_count: 12,
+                    banner: None,
+                    vanity_url_code: Some("bruhmoment".to_string()),
+                },
+            };
+
+            // The guild existed in the cache, so the cache's guild is returned by the
+            // update.
+            assert!(cache.update(&mut guild_delete).await.is_some());
+
+            // Assert that the channel's message cache no longer exists.
+            assert!(!cache.messages.contains_key(&ChannelId(2)));
+        });
     }
-}
 
-impl Default for Cache {
-    fn default() -> Cache {
-        Cache {
-            channels: HashMap::default(),
-            categories: HashMap::default(),
-            groups: HashMap::with_capacity(128),
-            guilds: HashMap::default(),
-            messages: HashMap::default(),
-            notes: HashMap::default(),
-            presences: HashMap::default(),
-            private_channels: HashMap::with_capacity(128),
-            settings: Settings::default(),
-            shard_count: 1,
-            unavailable_guilds: HashSet::default(),
-            user: CurrentUser::default(),
-            users: HashMap::default(),
-            message_queue: HashMap::default(),
+    fn message(channel_id: u64, id: u64) -> Message {
+        Message {
+            id: MessageId(id),
+            attachments: vec![],
+            author: User {
+                id: UserId(2),
+                avatar: None,
+                bot: false,
+                discriminator: 1,
+                name: "user 1".to_owned(),
+            },
+            channel_id: ChannelId(channel_id),
+            guild_id: Some(GuildId(1)),
+            content: String::new(),
+            edited_timestamp: None,
+            embeds: vec![],
+            kind: MessageType::Regular,
+            member: None,
+            mention_everyone: false,
+            mention_roles: vec![],
+            mention_channels: None,
+            mentions: vec![],
+            nonce: Value::Number(Number::from(1)),
+            pinned: false,
+            reactions: vec![],
+            timestamp: Timestamp::parse("2016-01-01T00:00:00+00:00").unwrap(),
+            tts: false,
+            webhook_id: None,
+            activity: None,
+            application: None,
+            message_reference: None,
+            flags: None,
+            sticker_items: Vec::new(),
         }
     }
-}
 
-#[cfg(test)]
-mod test {
-    use crate::internal::AsyncRwLock;
-    use crate::model::guild::PremiumTier::Tier2;
-    use crate::{
-        cache::{Cache, CacheUpdate, Settings},
-        model::prelude::*,
-        utils::run_async_test,
-    };
-    use chrono::DateTime;
-    use serde_json::{Number, Value};
-    use std::{collections::HashMap, sync::Arc};
+    fn guild(id: u64) -> Guild {
+        Guild {
+            id: GuildId(id),
+            afk_channel_id: None,
+            afk_timeout: 0,
+            application_id: None,
+            default_message_notifications: DefaultMessageNotificationLevel::All,
+            emojis: HashMap::new(),
+            explicit_content_filter: ExplicitContentFilter::None,
+            features: vec![],
+            icon: None,
+            joined_at: Timestamp::parse("2016-01-01T00:00:00+00:00").unwrap(),
+            large: false,
+            member_count: 0,
+            members: HashMap::new(),
+            mfa_level: MfaLevel::None,
+            name: "guild".to_owned(),
+            owner_id: UserId(3),
+            presences: HashMap::new(),
+            region: String::new(),
+            roles: HashMap::new(),
+            splash: None,
+            system_channel_id: None,
+            verification_level: VerificationLevel::Low,
+            voice_states: HashMap::new(),
+            threads: HashMap::new(),
+            description: None,
+            premium_tier: PremiumTier::Tier0,
+            channels: HashMap::new(),
+            premium_subscription_count: 0,
+            banner: None,
+            vanity_url_code: None,
+            preferred_locale: "en-US".to_string(),
+        }
+    }
+
+    fn member(guild_id: u64, user_id: u64, nick: Option<&str>) -> Member {
+        Member {
+            deaf: false,
+            guild_id: GuildId(guild_id),
+            joined_at: None,
+            mute: false,
+            nick: nick.map(str::to_owned),
+            roles: vec![RoleId(4)],
+            user: User {
+                id: UserId(user_id),
+                avatar: None,
+                bot: false,
+                discriminator: 1,
+                name: format!("user {}", user_id),
+            },
+        }
+    }
 
     #[test]
-    fn test_cache_messages() {
+    fn test_cache_messages_per_channel() {
         run_async_test(async move {
             let mut settings = Settings::new();
-            settings.max_messages(2);
-            let mut cache = Cache::new_with_settings(settings);
+            settings.max_messages(1).max_messages_in(ChannelId(2), 3);
+            settings.max_messages_in(ChannelId(3), 0);
+            let cache = Cache::new_with_settings(settings);
 
-            // Test inserting one message into a channel's message cache.
-            let datetime = DateTime::parse_from_str(
-                "1983 Apr 13 12:09:14.274 +0000",
-                "%Y %b %d %H:%M:%S%.3f %z",
-            )
-            .unwrap();
+            for id in 1..=4 {
+                for channel_id in 1..=3 {
+                    let mut event = MessageCreateEvent {
+                        message: message(channel_id, id),
+                    };
+                    cache.update(&mut event).await;
+                }
+            }
+
+            // Channel 1 uses the default limit, channel 2 its override.
+            assert_eq!(cache.messages.get(&ChannelId(1)).unwrap().len(), 1);
+            assert_eq!(cache.messages.get(&ChannelId(2)).unwrap().len(), 3);
+            assert!(!cache
+                .messages
+                .get(&ChannelId(2))
+                .unwrap()
+                .contains_key(&MessageId(1)));
+            // Caching is disabled entirely for channel 3.
+            assert!(!cache.messages.contains_key(&ChannelId(3)));
+
+            // Lowering a channel's limit evicts down to it on the next message.
+            cache.settings_mut().max_messages_in(ChannelId(2), 1);
             let mut event = MessageCreateEvent {
-                message: Message {
-                    id: MessageId(3),
-                    attachments: vec![],
-                    author: User {
-                        id: UserId(2),
-                        avatar: None,
-                        bot: false,
-                        discriminator: 1,
-                        name: "user 1".to_owned(),
+                message: message(2, 5),
+            };
+            cache.update(&mut event).await;
+
+            assert_eq!(cache.messages.get(&ChannelId(2)).unwrap().len(), 1);
+            assert!(cache
+                .messages
+                .get(&ChannelId(2))
+                .unwrap()
+                .contains_key(&MessageId(5)));
+        });
+    }
+
+    #[test]
+    fn test_cache_last_message_id() {
+        run_async_test(async move {
+            // Message caching is disabled, the last message Id is kept anyway.
+            let cache = Cache::new();
+
+            let channel = PrivateChannel {
+                id: ChannelId(1),
+                last_message_id: None,
+                last_pin_timestamp: None,
+                kind: ChannelType::Private,
+                recipient: message(1, 1).author,
+            };
+            cache.private_channels.insert(ChannelId(1), channel);
+
+            for id in 1..=2 {
+                let mut event = MessageCreateEvent {
+                    message: message(1, id),
+                };
+                cache.update(&mut event).await;
+            }
+
+            let channel = cache.private_channels.get(&ChannelId(1)).unwrap();
+            assert_eq!(channel.last_message_id, Some(MessageId(2)));
+        });
+    }
+
+    #[test]
+    fn test_cache_field_accessors() {
+        let cache = Cache::default();
+        let mut guild = guild(1);
+        guild.members.insert(UserId(2), member(1, 2, Some("nick")));
+        cache.guilds.insert(guild.id, guild);
+
+        assert_eq!(
+            cache.guild_field(1, |guild| guild.name.clone()),
+            Some("guild".to_owned())
+        );
+        assert_eq!(cache.guild_field(2, |guild| guild.owner_id), None);
+        assert_eq!(
+            cache.member_field(1, 2, |member| member.nick.clone()),
+            Some(Some("nick".to_owned()))
+        );
+        assert_eq!(cache.member_field(1, 3, |member| member.roles.len()), None);
+        assert_eq!(cache.member_field(2, 2, |member| member.roles.len()), None);
+        assert_eq!(
+            cache.guild_channel_field(1, |channel| channel.position),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cache_members_starting_with() {
+        let cache = Cache::default();
+        let mut guild = guild(1);
+        guild.members.insert(UserId(2), member(1, 2, Some("Zeyla")));
+        guild.members.insert(UserId(3), member(1, 3, None));
+        cache.guilds.insert(guild.id, guild);
+
+        let ids = |members: Vec<Member>| {
+            let mut ids = members
+                .into_iter()
+                .map(|member| member.user.id.0)
+                .collect::<Vec<_>>();
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(
+            ids(cache.members_starting_with(1, "user", false, false)),
+            vec![2, 3]
+        );
+        assert_eq!(
+            ids(cache.members_starting_with(1, "user 3", false, false)),
+            vec![3]
+        );
+        assert!(cache
+            .members_starting_with(1, "zey", false, true)
+            .is_empty());
+        assert_eq!(
+            ids(cache.members_starting_with(1, "zey", true, true)),
+            vec![2]
+        );
+        assert!(cache
+            .members_starting_with(1, "zey", true, false)
+            .is_empty());
+        assert!(cache
+            .members_starting_with(2, "user", false, false)
+            .is_empty());
+        assert_eq!(
+            ids(cache.members_matching(1, |member| member.nick.is_none())),
+            vec![3]
+        );
+    }
+
+    #[test]
+    fn test_cache_messages_in() {
+        run_async_test(async move {
+            let mut settings = Settings::new();
+            settings.max_messages(3);
+            let cache = Cache::new_with_settings(settings);
+
+            for id in &[4, 2, 3, 1] {
+                let mut message = message(1, *id);
+                message.author.id = UserId(id % 2);
+                let mut event = MessageCreateEvent { message };
+                cache.update(&mut event).await;
+            }
+
+            let ids = |messages: Vec<Message>| {
+                messages
+                    .into_iter()
+                    .map(|message| message.id.0)
+                    .collect::<Vec<_>>()
+            };
+
+            // Message 4 was evicted, the rest are in the order received.
+            assert_eq!(ids(cache.messages_in(1)), vec![2, 3, 1]);
+            assert_eq!(ids(cache.messages_by_author(1, 1)), vec![3, 1]);
+            assert_eq!(ids(cache.messages_by_author(1, 0)), vec![2]);
+            assert!(cache.messages_in(2).is_empty());
+        });
+    }
+
+    #[test]
+    fn test_cache_message_delete_bulk() {
+        run_async_test(async move {
+            let mut settings = Settings::new();
+            settings.max_messages(5);
+            let cache = Cache::new_with_settings(settings);
+
+            for id in 1..=3 {
+                let mut event = MessageCreateEvent {
+                    message: message(1, id),
+                };
+                cache.update(&mut event).await;
+            }
+
+            let mut event = MessageDeleteBulkEvent {
+                guild_id: None,
+                channel_id: ChannelId(1),
+                ids: vec![MessageId(1), MessageId(3), MessageId(4)],
+            };
+            let mut removed = cache
+                .update(&mut event)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|message| message.id.0)
+                .collect::<Vec<_>>();
+            removed.sort_unstable();
+
+            assert_eq!(removed, vec![1, 3]);
+            assert_eq!(cache.message_queue.get(&ChannelId(1)).unwrap().len(), 1);
+            assert!(cache.message(1, 2).is_some());
+        });
+    }
+
+    #[test]
+    fn test_cache_channel_update_returns_old() {
+        run_async_test(async move {
+            let cache = Cache::new();
+            let category = ChannelCategory {
+                id: ChannelId(1),
+                guild_id: GuildId(1),
+                category_id: None,
+                position: 0,
+                kind: ChannelType::Category,
+                name: "old".to_owned(),
+                nsfw: false,
+                permission_overwrites: vec![],
+            };
+            cache.categories.insert(category.id, category.clone());
+
+            let mut renamed = category;
+            renamed.name = "new".to_owned();
+            let mut event = ChannelUpdateEvent {
+                channel: Channel::Category(renamed),
+            };
+
+            match cache.update(&mut event).await {
+                Some(Channel::Category(old)) => assert_eq!(old.name, "old"),
+                other => panic!("unexpected old channel: {:?}", other),
+            }
+            assert_eq!(cache.categories.get(&ChannelId(1)).unwrap().name, "new");
+        });
+    }
+
+    #[test]
+    fn test_cache_startup_guild_delete() {
+        run_async_test(async move {
+            let cache = Cache::new();
+            cache.startup_shards.store(1, Ordering::Relaxed);
+            cache.ready_shards.insert(0);
+            cache.unavailable_guilds.insert(GuildId(1));
+            assert!(!cache.check_startup());
+
+            // The bot was removed from the guild while offline.
+            let mut event: GuildDeleteEvent = serde_json::from_value(serde_json::json!({
+                "id": "1",
+                "afk_channel_id": null,
+                "afk_timeout": 300,
+                "default_message_notifications": 0,
+                "embed_channel_id": null,
+                "embed_enabled": false,
+                "emojis": [],
+                "features": [],
+                "icon": null,
+                "mfa_level": 0,
+                "name": "guild",
+                "owner_id": "3",
+                "region": "us-west",
+                "roles": [],
+                "splash": null,
+                "verification_level": 0,
+                "description": null,
+                "premium_tier": 0,
+                "premium_subscription_count": 0,
+                "banner": null,
+                "vanity_url_code": null,
+            }))
+            .unwrap();
+            cache.update(&mut event).await;
+
+            assert!(cache.unavailable_guilds.is_empty());
+            assert!(cache.is_startup_complete());
+        });
+    }
+
+    #[tokio::test]
+    async fn test_cache_startup_timeout() {
+        let mut settings = Settings::new();
+        settings.startup_timeout(Duration::from_millis(50));
+        let cache = Cache::new_with_settings(settings);
+        cache.startup_shards.store(1, Ordering::Relaxed);
+        cache.ready_shards.insert(0);
+
+        // A guild listed as unavailable in the Ready stays unavailable.
+        cache.unavailable_guilds.insert(GuildId(1));
+        cache.restart_startup_timeout();
+        assert!(!cache.check_startup());
+
+        tokio::time::timeout(Duration::from_secs(5), cache.wait_for_startup())
+            .await
+            .expect("startup was not forced to complete");
+        assert!(cache.is_startup_complete());
+        assert!(cache.unavailable_guilds.contains(&GuildId(1)));
+    }
+
+    #[test]
+    fn test_cache_startup_complete() {
+        let cache = Cache::new();
+        cache.startup_shards.store(2, Ordering::Relaxed);
+        cache.unavailable_guilds.insert(GuildId(1));
+
+        cache.ready_shards.insert(0);
+        assert!(!cache.check_startup());
+
+        cache.ready_shards.insert(1);
+        assert!(!cache.check_startup());
+
+        cache.unavailable_guilds.remove(&GuildId(1));
+        assert!(cache.check_startup());
+        assert!(cache.is_startup_complete());
+        assert!(!cache.check_startup());
+
+        run_async_test(async move {
+            cache.wait_for_startup().await;
+        });
+    }
+
+    #[test]
+    fn test_cache_trim_to() {
+        run_async_test(async move {
+            let mut settings = Settings::new();
+            settings.max_messages(5);
+            let cache = Cache::new_with_settings(settings);
+
+            for id in 1..=3 {
+                let mut event = MessageCreateEvent {
+                    message: message(1, id),
+                };
+                cache.update(&mut event).await;
+            }
+
+            let mut guild = guild(1);
+            for user_id in 5..=6 {
+                guild.presences.insert(
+                    UserId(user_id),
+                    Presence {
+                        activity: None,
+                        client_status: None,
+                        last_modified: None,
+                        nick: None,
+                        status: OnlineStatus::Online,
+                        user_id: UserId(user_id),
+                        user: None,
                     },
-                    channel_id: ChannelId(2),
-                    guild_id: Some(GuildId(1)),
-                    content: String::new(),
-                    edited_timestamp: None,
-                    embeds: vec![],
-                    kind: MessageType::Regular,
-                    member: None,
-                    mention_everyone: false,
-                    mention_roles: vec![],
-                    mention_channels: None,
-                    mentions: vec![],
-                    nonce: Value::Number(Number::from(1)),
-                    pinned: false,
-                    reactions: vec![],
-                    timestamp: datetime.clone(),
-                    tts: false,
-                    webhook_id: None,
-                    activity: None,
-                    application: None,
-                    message_reference: None,
-                    flags: None,
-                },
+                );
+            }
+            let mut event = GuildCreateEvent { guild };
+            cache.update(&mut event).await;
+
+            let size = cache.approximate_size_bytes();
+            assert!(size > 0);
+            assert_eq!(cache.trim_to(size), 0);
+
+            // Only the oldest message needs to go.
+            assert!(cache.trim_to(size - 1) > 0);
+            assert!(cache.message(1, 1).is_none());
+            assert!(cache.message(1, 2).is_some());
+            assert!(cache.approximate_size_bytes() < size);
+
+            // Everything evictable goes, but guilds stay.
+            cache.trim_to(0);
+            assert!(cache.messages_in(1).is_empty());
+            assert!(cache.guilds.get(&GuildId(1)).unwrap().presences.is_empty());
+            assert!(cache.guild(1).is_some());
+        });
+    }
+
+    #[test]
+    fn test_cache_update_hooks() {
+        use super::CacheUpdateHook;
+        use async_trait::async_trait;
+        use dashmap::DashMap;
+        use std::sync::Arc;
+
+        #[derive(Default)]
+        struct UsersByName(DashMap<String, UserId>);
+
+        #[async_trait]
+        impl CacheUpdateHook for Arc<UsersByName> {
+            async fn update(&self, cache: &Cache, event: &Event) {
+                if let Event::GuildMemberAdd(event) = event {
+                    // The built-in update has already been applied.
+                    assert!(cache.user(event.member.user.id).is_some());
+
+                    self.0
+                        .insert(event.member.user.name.clone(), event.member.user.id);
+                }
+            }
+        }
+
+        run_async_test(async move {
+            let index = Arc::new(UsersByName::default());
+            let cache = Cache::with_update_hooks(vec![Arc::new(Arc::clone(&index))]);
+            assert!(cache.has_update_hooks());
+
+            let mut event = GuildMemberAddEvent {
+                guild_id: GuildId(1),
+                member: member(1, 2, None),
             };
-            // Check that the channel cache doesn't exist.
-            assert!(!cache.messages.contains_key(&event.message.channel_id));
-            // Add first message, none because message ID 2 doesn't already exist.
-            assert!(event.update(&mut cache).await.is_none());
-            // None, it only returns the oldest message if the cache was already full.
-            assert!(event.update(&mut cache).await.is_none());
-            // Assert there's only 1 message in the channel's message cache.
+            cache.update(&mut event).await;
+            cache.run_update_hooks(&event.into()).await;
+
+            assert_eq!(index.0.get("user 2").map(|id| *id), Some(UserId(2)));
+        });
+    }
+
+    #[test]
+    fn test_cache_view() {
+        run_async_test(async move {
+            let mut settings = Settings::new();
+            settings.max_messages(5);
+            let cache = Cache::new_with_settings(settings);
+
+            let mut guild = guild(1);
+            guild.members.insert(UserId(2), member(1, 2, Some("nick")));
+            cache.update(&mut GuildCreateEvent { guild }).await;
+            cache
+                .update(&mut MessageCreateEvent {
+                    message: message(3, 4),
+                })
+                .await;
+
+            let view = cache.snapshot();
+            cache.guilds.clear();
+
+            assert!(cache.guild(1).is_none());
+            assert!(view.guild(1).is_some());
             assert_eq!(
-                cache.messages.get(&event.message.channel_id).unwrap().len(),
-                1
+                view.member(1, 2).and_then(|member| member.nick.as_deref()),
+                Some("nick")
             );
+            assert!(view.member(1, 3).is_none());
+            assert_eq!(
+                view.message(3, 4).map(|message| message.id),
+                Some(MessageId(4))
+            );
+            assert!(view.channel(3).is_none());
+        });
+    }
 
-            // Add a second message, assert that channel message cache length is 2.
-            event.message.id = MessageId(4);
-            assert!(event.update(&mut cache).await.is_none());
+    #[test]
+    fn test_cache_recent_events() {
+        run_async_test(async move {
+            let cache = Cache::default();
+            let mut event = Event::GuildCreate(GuildCreateEvent { guild: guild(1) });
+            cache.update(&mut event).await;
+            assert!(cache.recent_events().is_empty());
+
+            cache.settings_mut().max_recent_events(2);
+
+            for user_id in 2..=4 {
+                let mut event = Event::GuildMemberAdd(GuildMemberAddEvent {
+                    guild_id: GuildId(1),
+                    member: member(1, user_id, None),
+                });
+                cache.update(&mut event).await;
+            }
+
+            // Events which do not affect the cache are not recorded.
+            let mut event = Event::TypingStart(TypingStartEvent {
+                guild_id: None,
+                channel_id: ChannelId(5),
+                timestamp: 0,
+                user_id: UserId(2),
+            });
+            cache.update(&mut event).await;
+
+            let recent = cache.recent_events();
+            assert_eq!(recent.len(), 2);
+            assert_eq!(recent[0].kind, EventType::GuildMemberAdd);
+            assert_eq!(recent[0].guild_id, Some(GuildId(1)));
+            assert_eq!(recent[0].user_id, Some(UserId(3)));
+            assert_eq!(recent[1].user_id, Some(UserId(4)));
+            assert!(recent[0].applied_at <= recent[1].applied_at);
+        });
+    }
+
+    #[test]
+    fn test_cache_category_channels() {
+        fn channel(
+            id: u64,
+            kind: ChannelType,
+            category_id: Option<u64>,
+            position: i64,
+        ) -> GuildChannel {
+            GuildChannel {
+                id: ChannelId(id),
+                bitrate: None,
+                category_id: category_id.map(ChannelId),
+                guild_id: GuildId(1),
+                kind,
+                last_message_id: None,
+                last_pin_timestamp: None,
+                name: format!("channel {}", id),
+                permission_overwrites: vec![],
+                position,
+                topic: None,
+                user_limit: None,
+                nsfw: false,
+                slow_mode_rate: None,
+            }
+        }
+
+        fn ids(channels: Vec<GuildChannel>) -> Vec<u64> {
+            channels.into_iter().map(|channel| channel.id.0).collect()
+        }
+
+        run_async_test(async move {
+            let cache = Cache::default();
+
+            let mut guild = guild(1);
+            for channel in vec![
+                channel(10, ChannelType::Category, None, 0),
+                channel(11, ChannelType::Text, Some(10), 2),
+                channel(12, ChannelType::Text, Some(10), 1),
+            ] {
+                guild.channels.insert(channel.id, channel);
+            }
+            cache.update(&mut GuildCreateEvent { guild }).await;
+
+            assert_eq!(ids(cache.category_channels(10)), vec![12, 11]);
+
+            let text = cache.guild_channel(11).unwrap();
             assert_eq!(
-                cache.messages.get(&event.message.channel_id).unwrap().len(),
-                2
+                text.category(&cache).map(|category| category.id),
+                Some(ChannelId(10))
             );
 
-            // Add a third message, the first should now be removed.
-            event.message.id = MessageId(5);
-            assert!(event.update(&mut cache).await.is_some());
+            // Moving a channel out of the category.
+            let mut update = ChannelUpdateEvent {
+                channel: Channel::Guild(channel(11, ChannelType::Text, None, 2)),
+            };
+            cache.update(&mut update).await;
+            assert_eq!(ids(cache.category_channels(10)), vec![12]);
 
-            {
-                let channel = cache.messages.get(&event.message.channel_id).unwrap();
+            let mut create = ChannelCreateEvent {
+                channel: Channel::Guild(channel(13, ChannelType::Voice, Some(10), 0)),
+            };
+            cache.update(&mut create).await;
+            assert_eq!(ids(cache.category_channels(10)), vec![13, 12]);
+
+            for id in &[12, 13] {
+                let mut delete = ChannelDeleteEvent {
+                    channel: Channel::Guild(channel(*id, ChannelType::Text, Some(10), 0)),
+                };
+                cache.update(&mut delete).await;
+            }
+            assert!(cache.category_channels(10).is_empty());
+            assert!(cache.category_children.is_empty());
+        });
+    }
+
+    fn thread(id: u64, name: &str, archived: bool) -> GuildThread {
+        serde_json::from_value(serde_json::json!({
+            "id": id.to_string(),
+            "guild_id": "1",
+            "parent_id": "10",
+            "owner_id": "6",
+            "type": 11,
+            "name": name,
+            "last_message_id": null,
+            "thread_metadata": {
+                "archived": archived,
+                "auto_archive_duration": 1440,
+                "archive_timestamp": "2021-01-01T00:00:00+00:00",
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_cache_threads() {
+        run_async_test(async move {
+            let cache = Cache::default();
+
+            let mut guild = guild(1);
+            guild
+                .threads
+                .insert(ChannelId(30), thread(30, "old", false));
+            cache.update(&mut GuildCreateEvent { guild }).await;
+            assert_eq!(cache.thread(30).map(|t| t.name), Some("old".to_string()));
+
+            let mut create = ThreadCreateEvent {
+                thread: thread(31, "new", false),
+            };
+            assert!(cache.update(&mut create).await.is_none());
+            assert!(matches!(cache.channel(31), Some(Channel::Thread(_))));
+            assert!(cache.guild(1).unwrap().threads.contains_key(&ChannelId(31)));
+
+            let mut update = ThreadUpdateEvent {
+                thread: thread(31, "new", true),
+            };
+            let old = cache.update(&mut update).await.unwrap();
+            assert!(!old.is_archived());
+            assert!(cache.thread(31).unwrap().is_archived());
 
-                assert_eq!(channel.len(), 2);
-                // Check that the first message is now removed.
-                assert!(!channel.contains_key(&MessageId(3)));
-            }
+            let mut delete = ThreadDeleteEvent {
+                id: ChannelId(31),
+                guild_id: GuildId(1),
+                parent_id: ChannelId(10),
+                kind: ChannelType::PublicThread,
+            };
+            assert!(cache.update(&mut delete).await.is_some());
+            assert!(cache.thread(31).is_none());
+            assert!(!cache.guild(1).unwrap().threads.contains_key(&ChannelId(31)));
+        });
+    }
 
-            let guild_channel = GuildChannel {
-                id: event.message.channel_id,
+    #[test]
+    fn test_cache_name_lookups() {
+        fn channel(id: u64, name: &str) -> GuildChannel {
+            GuildChannel {
+                id: ChannelId(id),
                 bitrate: None,
                 category_id: None,
-                guild_id: event.message.guild_id.unwrap(),
+                guild_id: GuildId(1),
                 kind: ChannelType::Text,
                 last_message_id: None,
                 last_pin_timestamp: None,
-                name: String::new(),
+                name: name.to_string(),
                 permission_overwrites: vec![],
                 position: 0,
                 topic: None,
                 user_limit: None,
                 nsfw: false,
-                slow_mode_rate: Some(0),
+                slow_mode_rate: None,
+            }
+        }
+
+        fn role(id: u64, name: &str) -> Role {
+            Role {
+                id: RoleId(id),
+                colour: crate::utils::Colour::ROSEWATER,
+                hoist: false,
+                managed: false,
+                mentionable: false,
+                name: name.to_string(),
+                permissions: Permissions::empty(),
+                position: 0,
+            }
+        }
+
+        run_async_test(async move {
+            let cache = Cache::default();
+
+            let mut guild = guild(1);
+            for channel in [
+                channel(10, "general"),
+                channel(11, "general"),
+                channel(12, "memes"),
+            ] {
+                guild.channels.insert(channel.id, channel);
+            }
+            guild.roles.insert(RoleId(20), role(20, "mods"));
+            guild.members.insert(UserId(6), member(1, 6, None));
+            cache.update(&mut GuildCreateEvent { guild }).await;
+
+            let id = |channel: Option<GuildChannel>| channel.map(|channel| channel.id.0);
+            assert_eq!(id(cache.channel_by_name(1, "general")), Some(10));
+            assert_eq!(id(cache.channel_by_name(1, "memes")), Some(12));
+            assert_eq!(id(cache.channel_by_name(2, "general")), None);
+            assert_eq!(
+                cache
+                    .guild(1)
+                    .unwrap()
+                    .channel_id_from_name(&cache, "memes"),
+                Some(ChannelId(12))
+            );
+
+            let mut update = ChannelUpdateEvent {
+                channel: Channel::Guild(channel(10, "offtopic")),
             };
+            cache.update(&mut update).await;
+            assert_eq!(id(cache.channel_by_name(1, "general")), Some(11));
+            assert_eq!(id(cache.channel_by_name(1, "offtopic")), Some(10));
 
-            // Add a channel delete event to the cache, the cached messages for that
-            // channel should now be gone.
             let mut delete = ChannelDeleteEvent {
-                channel: Channel::Guild(Arc::new(AsyncRwLock::new(guild_channel.clone()))),
+                channel: Channel::Guild(channel(11, "general")),
             };
-            assert!(cache.update(&mut delete).await.is_none());
-            assert!(!cache.messages.contains_key(&delete.channel.id().await));
+            cache.update(&mut delete).await;
+            assert_eq!(id(cache.channel_by_name(1, "general")), None);
 
-            // Test deletion of a guild channel's message cache when a GuildDeleteEvent
-            // is received.
-            let mut guild_create = {
-                let mut channels = HashMap::new();
-                channels.insert(
-                    ChannelId(2),
-                    Arc::new(AsyncRwLock::new(guild_channel.clone())),
-                );
+            assert_eq!(
+                cache.role_by_name(1, "mods").map(|r| r.id),
+                Some(RoleId(20))
+            );
 
-                GuildCreateEvent {
-                    guild: Guild {
-                        id: GuildId(1),
-                        afk_channel_id: None,
-                        afk_timeout: 0,
-                        application_id: None,
-                        default_message_notifications: DefaultMessageNotificationLevel::All,
-                        emojis: HashMap::new(),
-                        explicit_content_filter: ExplicitContentFilter::None,
-                        features: vec![],
-                        icon: None,
-                        joined_at: datetime,
-                        large: false,
-                        member_count: 0,
-                        members: HashMap::new(),
-                        mfa_level: MfaLevel::None,
-                        name: String::new(),
-                        owner_id: UserId(3),
-                        presences: HashMap::new(),
-                        region: String::new(),
-                        roles: HashMap::new(),
-                        splash: None,
-                        system_channel_id: None,
-                        verification_level: VerificationLevel::Low,
-                        voice_states: HashMap::new(),
-                        description: None,
-                        premium_tier: PremiumTier::Tier0,
-                        channels,
-                        premium_subscription_count: 0,
-                        banner: None,
-                        vanity_url_code: Some("bruhmoment".to_string()),
-                        preferred_locale: "en-US".to_string(),
-                    },
-                }
+            let mut update = GuildRoleUpdateEvent {
+                guild_id: GuildId(1),
+                role: role(20, "admins"),
             };
-            assert!(cache.update(&mut guild_create).await.is_none());
-            assert!(cache.update(&mut event).await.is_none());
+            cache.update(&mut update).await;
+            assert!(cache.role_by_name(1, "mods").is_none());
+            assert_eq!(
+                cache.role_by_name(1, "admins").map(|r| r.id),
+                Some(RoleId(20))
+            );
 
-            let mut guild_delete = GuildDeleteEvent {
+            let mut create = GuildRoleCreateEvent {
+                guild_id: GuildId(1),
+                role: role(21, "mods"),
+            };
+            cache.update(&mut create).await;
+            assert_eq!(
+                cache.role_by_name(1, "mods").map(|r| r.id),
+                Some(RoleId(21))
+            );
+
+            let mut delete = GuildRoleDeleteEvent {
+                guild_id: GuildId(1),
+                role_id: RoleId(21),
+            };
+            cache.update(&mut delete).await;
+            assert!(cache.role_by_name(1, "mods").is_none());
+
+            assert_eq!(
+                cache.user_by_tag("user 6", 1).map(|u| u.id),
+                Some(UserId(6))
+            );
+            assert!(cache.user_by_tag("user 6", 2).is_none());
+
+            let mut renamed = member(1, 6, None).user;
+            renamed.name = "ferris".to_string();
+            cache.update_user_entry(&renamed);
+            assert!(cache.user_by_tag("user 6", 1).is_none());
+            assert_eq!(
+                cache.user_by_tag("ferris", 1).map(|u| u.id),
+                Some(UserId(6))
+            );
+
+            let mut unavailable = GuildUnavailableEvent {
+                guild_id: GuildId(1),
+            };
+            cache.update(&mut unavailable).await;
+            assert!(cache.role_by_name(1, "admins").is_none());
+            assert!(cache.role_names.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_message_mentions_ordered() {
+        fn user(id: u64) -> User {
+            User {
+                id: UserId(id),
+                avatar: None,
+                bot: false,
+                discriminator: 1,
+                name: format!("user {}", id),
+            }
+        }
+
+        let cache = Cache::default();
+        cache.user.write().id = UserId(5);
+
+        let mut message = message(3, 4);
+        message.content = "<@!5> <<@6> <@&7> <#8> <@x> <@6>".to_string();
+        message.mentions = vec![user(6), user(5)];
+
+        let mentions = message
+            .mentions_ordered()
+            .into_iter()
+            .map(|mention| (mention.mention, mention.start, mention.end))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            mentions,
+            vec![
+                (Mention::User(UserId(5)), 0, 5),
+                (Mention::User(UserId(6)), 7, 11),
+                (Mention::Role(RoleId(7)), 12, 17),
+                (Mention::Channel(ChannelId(8)), 18, 22),
+                (Mention::User(UserId(6)), 28, 32),
+            ],
+        );
+        assert_eq!(
+            message
+                .first_user_mention_excluding_bot(&cache)
+                .map(|u| u.id),
+            Some(UserId(6)),
+        );
+    }
+
+    #[test]
+    fn test_cache_disabled_entities() {
+        run_async_test(async move {
+            let mut settings = Settings::new();
+            settings.cache_presences(false).cache_users(false);
+            let cache = Cache::new_with_settings(settings);
+
+            let user = User {
+                id: UserId(2),
+                avatar: None,
+                bot: false,
+                discriminator: 1,
+                name: "user 1".to_owned(),
+            };
+            let mut event = PresenceUpdateEvent {
+                guild_id: None,
+                presence: Presence {
+                    activity: None,
+                    client_status: None,
+                    last_modified: None,
+                    nick: None,
+                    status: OnlineStatus::Online,
+                    user_id: UserId(2),
+                    user: Some(user),
+                },
+                roles: None,
+            };
+            cache.update(&mut event).await;
+
+            assert!(cache.presences.is_empty());
+            assert!(cache.users.is_empty());
+            // The event still carries the user data.
+            assert_eq!(event.presence.user.unwrap().id, UserId(2));
+        });
+    }
+
+    #[test]
+    fn test_cache_compact_presences() {
+        run_async_test(async move {
+            let mut settings = Settings::new();
+            settings.compact_presences(true);
+            let cache = Cache::new_with_settings(settings);
+
+            let mut activity = Activity::playing("a game");
+            activity.details = Some("details".to_owned());
+            activity.assets = Some(ActivityAssets {
+                large_image: Some("image".to_owned()),
+                large_text: None,
+                small_image: None,
+                small_text: None,
+            });
+
+            let mut event = PresenceUpdateEvent {
+                guild_id: None,
+                presence: Presence {
+                    activity: Some(activity),
+                    client_status: None,
+                    last_modified: None,
+                    nick: None,
+                    status: OnlineStatus::Idle,
+                    user_id: UserId(2),
+                    user: Some(member(1, 2, None).user),
+                },
+                roles: None,
+            };
+            cache.update(&mut event).await;
+
+            let presence = cache.presences.get(&UserId(2)).unwrap();
+            let activity = presence.activity.as_ref().unwrap();
+            assert_eq!(presence.status, OnlineStatus::Idle);
+            assert_eq!(activity.name, "a game");
+            assert!(activity.details.is_none());
+            assert!(activity.assets.is_none());
+            assert!(presence.user.is_none());
+            // The user is still cached on its own.
+            assert!(cache.users.contains_key(&UserId(2)));
+        });
+    }
+
+    #[test]
+    fn test_cache_prune_expired() {
+        run_async_test(async move {
+            let presence = |user_id| Presence {
+                activity: None,
+                client_status: None,
+                last_modified: None,
+                nick: None,
+                status: OnlineStatus::Online,
+                user_id: UserId(user_id),
+                user: None,
+            };
+
+            let mut settings = Settings::new();
+            settings.presence_ttl(Duration::from_secs(0));
+            let cache = Cache::new_with_settings(settings);
+
+            let mut guild = guild(1);
+            guild.presences.insert(UserId(2), presence(2));
+            let mut event = GuildCreateEvent { guild };
+            cache.update(&mut event).await;
+
+            let mut event = PresenceUpdateEvent {
+                guild_id: None,
+                presence: presence(3),
+                roles: None,
+            };
+            cache.update(&mut event).await;
+
+            assert_eq!(cache.prune_expired(), 2);
+            assert!(cache.presences.is_empty());
+            assert!(cache.guilds.get(&GuildId(1)).unwrap().presences.is_empty());
+            assert_eq!(cache.prune_expired(), 0);
+
+            // Presences are kept while within the TTL.
+            cache.settings_mut().presence_ttl(Duration::from_secs(60));
+            cache.update(&mut event).await;
+            assert_eq!(cache.prune_expired(), 0);
+            assert!(cache.presences.contains_key(&UserId(3)));
+
+            // Nothing expires without a TTL.
+            cache.settings_mut().presence_ttl(None);
+            assert_eq!(cache.prune_expired(), 0);
+        });
+    }
+
+    #[test]
+    fn test_cache_guild_delete_presence_timestamps() {
+        run_async_test(async move {
+            let presence = |user_id| Presence {
+                activity: None,
+                client_status: None,
+                last_modified: None,
+                nick: None,
+                status: OnlineStatus::Online,
+                user_id: UserId(user_id),
+                user: None,
+            };
+
+            let cache = Cache::new();
+
+            for (guild_id, user_ids) in [(1, vec![2, 3]), (2, vec![3])] {
+                let mut guild = guild(guild_id);
+
+                for user_id in user_ids {
+                    guild.presences.insert(UserId(user_id), presence(user_id));
+                }
+
+                cache.update(&mut GuildCreateEvent { guild }).await;
+            }
+
+            let mut event = GuildDeleteEvent {
                 guild: PartialGuild {
                     id: GuildId(1),
                     afk_channel_id: None,
@@ -1053,50 +4010,522 @@ mod test {
                     splash: None,
                     verification_level: VerificationLevel::Low,
                     description: None,
-                    premium_tier: Tier2,
-                    premium_subscription_count: 12,
+                    premium_tier: PremiumTier::Tier0,
+                    premium_subscription_count: 0,
                     banner: None,
-                    vanity_url_code: Some("bruhmoment".to_string()),
+                    vanity_url_code: None,
                 },
             };
+            assert!(cache.update(&mut event).await.is_some());
 
-            // The guild existed in the cache, so the cache's guild is returned by the
-            // update.
-            assert!(cache.update(&mut guild_delete).await.is_some());
+            // The presence of user 3 is still cached in guild 2.
+            assert!(!cache.presence_timestamps.contains_key(&UserId(2)));
+            assert!(cache.presence_timestamps.contains_key(&UserId(3)));
+        });
+    }
 
-            // Assert that the channel's message cache no longer exists.
-            assert!(!cache.messages.contains_key(&ChannelId(2)));
+    #[test]
+    fn test_cache_snapshot() {
+        run_async_test(async move {
+            let mut settings = Settings::new();
+            settings.max_messages(2);
+            let cache = Cache::new_with_settings(settings.clone());
+
+            for id in 1..=3 {
+                let mut event = MessageCreateEvent {
+                    message: message(1, id),
+                };
+                cache.update(&mut event).await;
+            }
+
+            let user = User {
+                id: UserId(2),
+                avatar: None,
+                bot: false,
+                discriminator: 1,
+                name: "user 1".to_owned(),
+            };
+            let mut event = PresenceUpdateEvent {
+                guild_id: None,
+                presence: Presence {
+                    activity: None,
+                    client_status: None,
+                    last_modified: None,
+                    nick: None,
+                    status: OnlineStatus::Online,
+                    user_id: UserId(2),
+                    user: Some(user),
+                },
+                roles: None,
+            };
+            cache.update(&mut event).await;
+
+            let mut buf = Vec::new();
+            cache.save_snapshot(&mut buf).unwrap();
+
+            let restored = Cache::new_with_settings(settings);
+            restored.load_snapshot(&buf[..]).unwrap();
+
+            assert_eq!(restored.users.len(), 1);
+            let presence = restored.presences.get(&UserId(2)).unwrap();
+            assert_eq!(presence.user.as_ref().unwrap().id, UserId(2));
+
+            // Message order is kept, so eviction continues where it left off.
+            assert_eq!(
+                *restored.message_queue.get(&ChannelId(1)).unwrap(),
+                *cache.message_queue.get(&ChannelId(1)).unwrap(),
+            );
+            let mut event = MessageCreateEvent {
+                message: message(1, 4),
+            };
+            restored.update(&mut event).await;
+            assert!(!restored
+                .messages
+                .get(&ChannelId(1))
+                .unwrap()
+                .contains_key(&MessageId(2)));
+            assert!(restored
+                .messages
+                .get(&ChannelId(1))
+                .unwrap()
+                .contains_key(&MessageId(3)));
         });
     }
-}
 
-/// A neworphantype to allow implementing `AsRef<CacheRwLock>`
-/// for the automatically dereferenced underlying type.
-#[derive(Clone)]
-pub struct CacheRwLock(Arc<AsyncRwLock<Cache>>);
+    #[test]
+    fn test_cache_snapshot_guild() {
+        run_async_test(async move {
+            let cache = Cache::new();
+
+            let mut guild = guild(1);
+            for user_id in 5..=6 {
+                guild
+                    .members
+                    .insert(UserId(user_id), member(1, user_id, None));
+            }
+            guild.roles.insert(
+                RoleId(4),
+                Role {
+                    id: RoleId(4),
+                    colour: crate::utils::Colour::ROSEWATER,
+                    hoist: false,
+                    managed: false,
+                    mentionable: false,
+                    name: "role".to_string(),
+                    permissions: Permissions::empty(),
+                    position: 1,
+                },
+            );
+            for (id, name) in [(10, "general"), (11, "memes")] {
+                guild.channels.insert(
+                    ChannelId(id),
+                    GuildChannel {
+                        id: ChannelId(id),
+                        bitrate: None,
+                        category_id: None,
+                        guild_id: GuildId(1),
+                        kind: ChannelType::Text,
+                        last_message_id: None,
+                        last_pin_timestamp: None,
+                        name: name.to_string(),
+                        permission_overwrites: vec![],
+                        position: 0,
+                        topic: None,
+                        user_limit: None,
+                        nsfw: false,
+                        slow_mode_rate: None,
+                    },
+                );
+            }
+            guild
+                .threads
+                .insert(ChannelId(30), thread(30, "guild thread", false));
+            cache.update(&mut GuildCreateEvent { guild }).await;
+            // A thread cached without its guild.
+            cache
+                .threads
+                .insert(ChannelId(31), thread(31, "lone thread", false));
+
+            let mut buf = Vec::new();
+            cache.save_snapshot(&mut buf).unwrap();
+
+            let restored = Cache::new();
+            restored.load_snapshot(&buf[..]).unwrap();
+
+            assert_eq!(restored.threads.len(), 2);
+            assert_eq!(restored.thread(30).unwrap().name, "guild thread");
+            assert_eq!(restored.thread(31).unwrap().name, "lone thread");
+
+            let guild = restored.guild(1).unwrap();
+            assert!(guild.threads.contains_key(&ChannelId(30)));
+            assert_eq!(guild.members.len(), 2);
+            assert_eq!(guild.channels.len(), 2);
+            assert_eq!(restored.member(1, 5).unwrap().roles, vec![RoleId(4)]);
+            assert!(restored.member(1, 6).is_some());
+            assert!(restored.user(6).is_some());
+            assert_eq!(restored.role(1, 4).unwrap().name, "role");
+            assert_eq!(restored.guild_channel(11).unwrap().name, "memes");
+
+            // The lookup indexes are rebuilt from the restored guild.
+            assert_eq!(
+                restored.channel_by_name(1, "general").unwrap().id,
+                ChannelId(10)
+            );
+            assert_eq!(restored.role_by_name(1, "role").unwrap().id, RoleId(4));
+        });
+    }
+
+    #[test]
+    fn test_cache_snapshot_version() {
+        let cache = Cache::new();
+        cache.guilds.insert(GuildId(1), guild(1));
+
+        let mut buf = Vec::new();
+        cache.save_snapshot(&mut buf).unwrap();
+
+        let mut snapshot: Value = serde_json::from_slice(&buf).unwrap();
+        snapshot["version"] = Value::from(super::SNAPSHOT_VERSION + 1);
+        let buf = serde_json::to_vec(&snapshot).unwrap();
 
-impl From<Arc<AsyncRwLock<Cache>>> for CacheRwLock {
-    fn from(cache: Arc<AsyncRwLock<Cache>>) -> Self {
-        Self(cache)
+        let restored = Cache::new();
+        restored.guilds.insert(GuildId(2), guild(2));
+
+        match restored.load_snapshot(&buf[..]) {
+            Err(crate::Error::Decode(message, version)) => {
+                assert_eq!(message, "Unsupported cache snapshot version");
+                assert_eq!(version, Value::from(super::SNAPSHOT_VERSION + 1));
+            }
+            other => panic!("Expected a decode error, got {:?}", other),
+        }
+
+        // The cache is left untouched.
+        assert!(restored.guild(1).is_none());
+        assert!(restored.guild(2).is_some());
     }
-}
 
-impl AsRef<CacheRwLock> for CacheRwLock {
-    fn as_ref(&self) -> &CacheRwLock {
-        &self
+    #[test]
+    fn test_cache_guilds_missing_members() {
+        let cache = Cache::new();
+
+        let mut complete = guild(1);
+        complete.members.insert(UserId(5), member(1, 5, None));
+        complete.member_count = 1;
+        cache.guilds.insert(GuildId(1), complete);
+
+        let mut incomplete = guild(2);
+        incomplete.members.insert(UserId(5), member(2, 5, None));
+        incomplete.member_count = 300;
+        cache.guilds.insert(GuildId(2), incomplete);
+
+        assert_eq!(cache.guilds_missing_members(), vec![GuildId(2)]);
+        assert_eq!(cache.unknown_members(), 299);
     }
-}
 
-impl Default for CacheRwLock {
-    fn default() -> Self {
-        Self(Arc::new(AsyncRwLock::new(Cache::default())))
+    #[test]
+    fn test_cache_update_batch() {
+        run_async_test(async move {
+            let mut settings = Settings::new();
+            settings.max_recent_events(10);
+            let cache = Cache::new_with_settings(settings);
+            cache.startup_shards.store(1, Ordering::Relaxed);
+            cache.ready_shards.insert(0);
+            cache.unavailable_guilds.insert(GuildId(1));
+            cache.unavailable_guilds.insert(GuildId(2));
+
+            let mut first = guild(1);
+            first.members.insert(UserId(5), member(1, 5, None));
+
+            let mut events = vec![
+                Event::GuildCreate(GuildCreateEvent { guild: first }),
+                Event::GuildCreate(GuildCreateEvent { guild: guild(3) }),
+                Event::GuildUnavailable(GuildUnavailableEvent {
+                    guild_id: GuildId(3),
+                }),
+            ];
+            cache.update_batch(&mut events).await;
+
+            assert!(cache.guild(1).is_some());
+            assert!(cache.member(1, 5).is_some());
+            assert!(cache.user(5).is_some());
+            assert!(cache.guild(3).is_none());
+            assert!(!cache.unavailable_guilds.contains(&GuildId(1)));
+            assert!(cache.unavailable_guilds.contains(&GuildId(3)));
+            assert!(!cache.is_startup_complete());
+
+            let recorded = cache
+                .recent_events()
+                .into_iter()
+                .map(|event| event.kind)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                recorded,
+                vec![
+                    EventType::GuildCreate,
+                    EventType::GuildCreate,
+                    EventType::GuildUnavailable
+                ]
+            );
+
+            // Startup completes once the last unavailable guilds arrive.
+            let mut events = vec![
+                Event::GuildCreate(GuildCreateEvent { guild: guild(2) }),
+                Event::GuildCreate(GuildCreateEvent { guild: guild(3) }),
+            ];
+            cache.update_batch(&mut events).await;
+            assert!(cache.is_startup_complete());
+        });
     }
-}
 
-impl Deref for CacheRwLock {
-    type Target = Arc<AsyncRwLock<Cache>>;
+    #[tokio::test]
+    async fn test_cache_update_queued() {
+        use std::sync::Arc;
+
+        let cache = Arc::new(Cache::new());
+
+        // While a batch is being applied, further events queue up.
+        let batch = cache.batch_lock.lock().await;
+        let tasks = (1..=3)
+            .map(|id| {
+                let cache = Arc::clone(&cache);
+                let event = Event::GuildCreate(GuildCreateEvent { guild: guild(id) });
+
+                tokio::spawn(async move { cache.update_queued(event).await })
+            })
+            .collect::<Vec<_>>();
+
+        while cache.queued_updates.lock().len() < 3 {
+            tokio::task::yield_now().await;
+        }
+
+        assert!(cache.guilds.is_empty());
+        drop(batch);
+
+        // The first task to take the lock applies the whole queue at once.
+        let mut tasks = tasks.into_iter();
+        tasks.next().unwrap().await.unwrap();
+        assert_eq!(cache.guilds.len(), 3);
+        assert!(cache.queued_updates.lock().is_empty());
+
+        for task in tasks {
+            task.await.unwrap();
+        }
+    }
+
+    #[cfg(feature = "http")]
+    #[tokio::test]
+    async fn test_cache_warm_from_http() {
+        use crate::http::mock::{MockResponse, MockServer};
+        use serde_json::{json, Value};
+
+        fn enqueue(server: &MockServer, emojis: Value) {
+            server.enqueue(MockResponse::json(
+                200,
+                &json!({
+                    "id": "1",
+                    "afk_channel_id": null,
+                    "afk_timeout": 300,
+                    "default_message_notifications": 0,
+                    "embed_channel_id": null,
+                    "embed_enabled": false,
+                    "emojis": emojis,
+                    "features": [],
+                    "icon": null,
+                    "mfa_level": 0,
+                    "name": "guild",
+                    "owner_id": "3",
+                    "region": "us-west",
+                    "roles": [{
+                        "id": "4",
+                        "color": 0,
+                        "hoist": false,
+                        "managed": false,
+                        "mentionable": false,
+                        "name": "role",
+                        "permissions": 0,
+                        "position": 1,
+                    }],
+                    "splash": null,
+                    "verification_level": 0,
+                    "description": null,
+                    "premium_tier": 0,
+                    "premium_subscription_count": 0,
+                    "banner": null,
+                    "vanity_url_code": null,
+                }),
+            ));
+            server.enqueue(MockResponse::json(
+                200,
+                &json!([{
+                    "id": "2",
+                    "guild_id": "1",
+                    "type": 0,
+                    "name": "general",
+                    "permission_overwrites": [],
+                    "position": 0,
+                }]),
+            ));
+            server.enqueue(MockResponse::json(
+                200,
+                &json!([{
+                    "deaf": false,
+                    "joined_at": "2020-01-01T00:00:00+00:00",
+                    "mute": false,
+                    "nick": null,
+                    "roles": ["4"],
+                    "user": {
+                        "id": "3",
+                        "avatar": null,
+                        "bot": false,
+                        "discriminator": "0001",
+                        "username": "user 3",
+                    },
+                }]),
+            ));
+        }
+
+        let emojis = json!([{
+            "id": "5",
+            "name": "emoji",
+            "animated": false,
+            "managed": false,
+            "require_colons": true,
+            "roles": [],
+        }]);
+
+        let server = MockServer::start().await;
+        enqueue(&server, emojis.clone());
+
+        let http = server.http("Bot token");
+        let cache = Cache::new();
+        cache.warm_from_http(&http, vec![GuildId(1)]).await.unwrap();
+
+        assert_eq!(
+            cache.guild_field(1, |g| g.name.clone()).as_deref(),
+            Some("guild")
+        );
+        assert!(cache.role(1, 4).is_some());
+        assert!(cache.guild_channel(2).is_some());
+        assert!(cache.member(1, 3).is_some());
+        assert!(cache.user(3).is_some());
+        assert_eq!(cache.guild_field(1, |g| g.emojis.len()), Some(1));
+
+        let paths = server
+            .requests()
+            .into_iter()
+            .map(|request| request.path)
+            .collect::<Vec<_>>();
+        assert_eq!(paths.len(), 3);
+        assert!(paths[2].ends_with("/guilds/1/members?&limit=1000"));
+
+        // Warming a guild which is already cached still applies the settings.
+        cache.settings_mut().cache_emojis(false);
+        enqueue(&server, emojis);
+        cache.warm_from_http(&http, vec![GuildId(1)]).await.unwrap();
+
+        assert_eq!(cache.guild_field(1, |g| g.emojis.len()), Some(0));
+        assert!(cache.member(1, 3).is_some());
+    }
+
+    #[cfg(feature = "http")]
+    #[tokio::test]
+    async fn test_cache_refresh_guild() {
+        use crate::http::mock::{MockResponse, MockServer};
+        use serde_json::{json, Value};
+
+        fn guild(name: &str, roles: Value) -> Value {
+            json!({
+                "id": "1",
+                "afk_channel_id": null,
+                "afk_timeout": 300,
+                "default_message_notifications": 0,
+                "embed_channel_id": null,
+                "embed_enabled": false,
+                "emojis": [],
+                "features": [],
+                "icon": null,
+                "mfa_level": 0,
+                "name": name,
+                "owner_id": "3",
+                "region": "us-west",
+                "roles": roles,
+                "splash": null,
+                "verification_level": 0,
+                "description": null,
+                "premium_tier": 0,
+                "premium_subscription_count": 0,
+                "banner": null,
+                "vanity_url_code": null,
+            })
+        }
+
+        fn channel(id: &str, name: &str) -> Value {
+            json!({
+                "id": id,
+                "guild_id": "1",
+                "type": 0,
+                "name": name,
+                "permission_overwrites": [],
+                "position": 0,
+            })
+        }
+
+        let role = json!({
+            "id": "4",
+            "color": 0,
+            "hoist": false,
+            "managed": false,
+            "mentionable": false,
+            "name": "role",
+            "permissions": 0,
+            "position": 1,
+        });
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(200, &guild("guild", json!([role]))));
+        server.enqueue(MockResponse::json(200, &json!([channel("2", "general")])));
+        server.enqueue(MockResponse::json(
+            200,
+            &json!([{
+                "deaf": false,
+                "joined_at": "2020-01-01T00:00:00+00:00",
+                "mute": false,
+                "nick": null,
+                "roles": ["4"],
+                "user": {
+                    "id": "3",
+                    "avatar": null,
+                    "bot": false,
+                    "discriminator": "0001",
+                    "username": "user 3",
+                },
+            }]),
+        ));
+        server.enqueue(MockResponse::json(200, &guild("renamed", json!([]))));
+        server.enqueue(MockResponse::json(200, &json!([channel("5", "lounge")])));
+
+        let http = server.http("Bot token");
+        let cache = Cache::new();
+
+        // The guild is not cached yet, so it is warmed instead.
+        cache.refresh_guild(&http, 1).await.unwrap();
+        assert_eq!(cache.member(1, 3).unwrap().roles, vec![RoleId(4)]);
+
+        cache.refresh_guild(&http, 1).await.unwrap();
 
-    fn deref(&self) -> &Arc<AsyncRwLock<Cache>> {
-        &self.0
+        assert_eq!(
+            cache.guild_field(1, |g| g.name.clone()).as_deref(),
+            Some("renamed")
+        );
+        assert!(cache.role(1, 4).is_none());
+        assert!(cache.role_by_name(1, "role").is_none());
+        assert!(cache.member(1, 3).unwrap().roles.is_empty());
+        assert!(cache.guild_channel(2).is_none());
+        assert!(cache.channel_by_name(1, "general").is_none());
+        assert!(cache.guild_channel(5).is_some());
+        assert_eq!(
+            cache.guild_field(1, |g| g.channels.keys().copied().collect::<Vec<_>>()),
+            Some(vec![ChannelId(5)])
+        );
+        assert_eq!(server.requests().len(), 5);
     }
 }
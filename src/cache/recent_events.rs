@@ -0,0 +1,175 @@
+use crate::model::event::{Event, EventType};
+use crate::model::prelude::*;
+
+/// A record of an event the cache was updated with, as returned by
+/// [`Cache::recent_events`].
+///
+/// Only the type of the event and the Ids it refers to are kept, not its
+/// data.
+///
+/// [`Cache::recent_events`]: struct.Cache.html#method.recent_events
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RecentEvent {
+    /// The type of the event.
+    pub kind: EventType,
+    /// The guild the event happened in, if any.
+    pub guild_id: Option<GuildId>,
+    /// The channel the event happened in, if any.
+    pub channel_id: Option<ChannelId>,
+    /// The user the event is about, if any.
+    pub user_id: Option<UserId>,
+    /// The message the event is about, if any.
+    pub message_id: Option<MessageId>,
+    /// When the cache was updated with the event.
+    pub applied_at: Timestamp,
+}
+
+impl RecentEvent {
+    pub(crate) fn new(event: &Event) -> Self {
+        let mut record = RecentEvent {
+            kind: event.event_type(),
+            guild_id: None,
+            channel_id: None,
+            user_id: None,
+            message_id: None,
+            applied_at: Timestamp::now(),
+        };
+
+        match event {
+            Event::ChannelCreate(e) => record.channel(&e.channel),
+            Event::ChannelDelete(e) => record.channel(&e.channel),
+            Event::ChannelUpdate(e) => record.channel(&e.channel),
+            Event::ChannelPinsUpdate(e) => {
+                record.guild_id = e.guild_id;
+                record.channel_id = Some(e.channel_id);
+            }
+            Event::ChannelRecipientAdd(e) => {
+                record.channel_id = Some(e.channel_id);
+                record.user_id = Some(e.user.id);
+            }
+            Event::ChannelRecipientRemove(e) => {
+                record.channel_id = Some(e.channel_id);
+                record.user_id = Some(e.user.id);
+            }
+            Event::GuildBanAdd(e) => {
+                record.guild_id = Some(e.guild_id);
+                record.user_id = Some(e.user.id);
+            }
+            Event::GuildBanRemove(e) => {
+                record.guild_id = Some(e.guild_id);
+                record.user_id = Some(e.user.id);
+            }
+            Event::GuildCreate(e) => record.guild_id = Some(e.guild.id),
+            Event::GuildDelete(e) => record.guild_id = Some(e.guild.id),
+            Event::GuildEmojisUpdate(e) => record.guild_id = Some(e.guild_id),
+            Event::GuildIntegrationsUpdate(e) => record.guild_id = Some(e.guild_id),
+            Event::GuildMemberAdd(e) => {
+                record.guild_id = Some(e.guild_id);
+                record.user_id = Some(e.member.user.id);
+            }
+            Event::GuildMemberRemove(e) => {
+                record.guild_id = Some(e.guild_id);
+                record.user_id = Some(e.user.id);
+            }
+            Event::GuildMemberUpdate(e) => {
+                record.guild_id = Some(e.guild_id);
+                record.user_id = Some(e.user.id);
+            }
+            Event::GuildMembersChunk(e) => record.guild_id = Some(e.guild_id),
+            Event::GuildRoleCreate(e) => record.guild_id = Some(e.guild_id),
+            Event::GuildRoleDelete(e) => record.guild_id = Some(e.guild_id),
+            Event::GuildRoleUpdate(e) => record.guild_id = Some(e.guild_id),
+            Event::GuildUnavailable(e) => record.guild_id = Some(e.guild_id),
+            Event::GuildUpdate(e) => record.guild_id = Some(e.guild.id),
+            Event::MessageCreate(e) => {
+                record.guild_id = e.message.guild_id;
+                record.channel_id = Some(e.message.channel_id);
+                record.user_id = Some(e.message.author.id);
+                record.message_id = Some(e.message.id);
+            }
+            Event::MessageDelete(e) => {
+                record.guild_id = e.guild_id;
+                record.channel_id = Some(e.channel_id);
+                record.message_id = Some(e.message_id);
+            }
+            Event::MessageDeleteBulk(e) => {
+                record.guild_id = e.guild_id;
+                record.channel_id = Some(e.channel_id);
+            }
+            Event::MessageUpdate(e) => {
+                record.guild_id = e.guild_id;
+                record.channel_id = Some(e.channel_id);
+                record.message_id = Some(e.id);
+            }
+            Event::PresenceUpdate(e) => {
+                record.guild_id = e.guild_id;
+                record.user_id = Some(e.presence.user_id);
+            }
+            Event::ReactionAdd(e) => record.reaction(&e.reaction),
+            Event::ReactionRemove(e) => record.reaction(&e.reaction),
+            Event::ReactionRemoveAll(e) => {
+                record.guild_id = e.guild_id;
+                record.channel_id = Some(e.channel_id);
+                record.message_id = Some(e.message_id);
+            }
+            Event::Ready(e) => record.user_id = Some(e.ready.user.id),
+            Event::TypingStart(e) => {
+                record.guild_id = e.guild_id;
+                record.channel_id = Some(e.channel_id);
+                record.user_id = Some(e.user_id);
+            }
+            Event::ThreadCreate(e) => {
+                record.guild_id = Some(e.thread.guild_id);
+                record.channel_id = Some(e.thread.id);
+            }
+            Event::ThreadDelete(e) => {
+                record.guild_id = Some(e.guild_id);
+                record.channel_id = Some(e.id);
+            }
+            Event::ThreadUpdate(e) => {
+                record.guild_id = Some(e.thread.guild_id);
+                record.channel_id = Some(e.thread.id);
+            }
+            Event::UserUpdate(e) => record.user_id = Some(e.current_user.id),
+            Event::VoiceStateUpdate(e) => {
+                record.guild_id = e.guild_id;
+                record.channel_id = e.voice_state.channel_id;
+                record.user_id = Some(e.voice_state.user_id);
+            }
+            Event::VoiceServerUpdate(e) => {
+                record.guild_id = e.guild_id;
+                record.channel_id = e.channel_id;
+            }
+            Event::WebhookUpdate(e) => {
+                record.guild_id = Some(e.guild_id);
+                record.channel_id = Some(e.channel_id);
+            }
+            Event::PresencesReplace(_) | Event::Resumed(_) | Event::Unknown(_) => {}
+        }
+
+        record
+    }
+
+    fn channel(&mut self, channel: &Channel) {
+        self.channel_id = Some(channel.id());
+        self.guild_id = match channel {
+            Channel::Guild(channel) => Some(channel.guild_id),
+            Channel::Category(category) => Some(category.guild_id),
+            Channel::Thread(thread) => Some(thread.guild_id),
+            Channel::Private(channel) => {
+                self.user_id = Some(channel.recipient.id);
+
+                None
+            }
+            Channel::Group(_) => None,
+        };
+    }
+
+    fn reaction(&mut self, reaction: &Reaction) {
+        self.guild_id = reaction.guild_id;
+        self.channel_id = Some(reaction.channel_id);
+        self.user_id = Some(reaction.user_id);
+        self.message_id = Some(reaction.message_id);
+    }
+}
@@ -0,0 +1,691 @@
+//! A [`Cache`] shared between processes through Redis.
+//!
+//! [`Cache`]: ../struct.Cache.html
+
+use super::{Cache, CacheUpdate};
+use crate::model::prelude::*;
+use futures::StreamExt;
+use redis::{aio::MultiplexedConnection, AsyncCommands, Client};
+use std::{
+    fmt,
+    str::FromStr,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// A key identifying a value shared through a [`RedisCache`].
+///
+/// [`RedisCache`]: struct.RedisCache.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CacheKey {
+    /// A [`Channel`] of any kind, including categories.
+    ///
+    /// [`Channel`]: ../model/channel/enum.Channel.html
+    Channel(ChannelId),
+    /// A [`Guild`], along with its members, roles and emojis.
+    ///
+    /// [`Guild`]: ../model/guild/struct.Guild.html
+    Guild(GuildId),
+    /// A [`User`].
+    ///
+    /// [`User`]: ../model/user/struct.User.html
+    User(UserId),
+}
+
+impl fmt::Display for CacheKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheKey::Channel(id) => write!(f, "channel:{}", id.0),
+            CacheKey::Guild(id) => write!(f, "guild:{}", id.0),
+            CacheKey::User(id) => write!(f, "user:{}", id.0),
+        }
+    }
+}
+
+impl FromStr for CacheKey {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.splitn(2, ':');
+        let kind = split.next().ok_or(())?;
+        let id = split.next().ok_or(())?.parse::<u64>().map_err(|_| ())?;
+
+        match kind {
+            "channel" => Ok(CacheKey::Channel(ChannelId(id))),
+            "guild" => Ok(CacheKey::Guild(GuildId(id))),
+            "user" => Ok(CacheKey::User(UserId(id))),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Trait implemented by [`CacheUpdate`]s which can tell which shared values
+/// they touch.
+///
+/// The keys are collected _before_ the update is applied, so that values
+/// removed by the update can still be found.
+///
+/// [`CacheUpdate`]: trait.CacheUpdate.html
+pub trait CacheKeys {
+    /// Returns the keys of the values this update will change.
+    fn cache_keys(&self, cache: &Cache) -> Vec<CacheKey>;
+}
+
+fn channel_keys(channel: &Channel) -> Vec<CacheKey> {
+    let mut keys = vec![CacheKey::Channel(channel.id())];
+
+    match channel {
+        Channel::Guild(c) => keys.push(CacheKey::Guild(c.guild_id)),
+        Channel::Thread(c) => keys.push(CacheKey::Guild(c.guild_id)),
+        _ => {}
+    }
+
+    keys
+}
+
+fn guild_keys(cache: &Cache, guild_id: GuildId) -> Vec<CacheKey> {
+    let mut keys = vec![CacheKey::Guild(guild_id)];
+
+    if let Some(channels) =
+        cache.guild_field(guild_id, |g| g.channels.keys().copied().collect::<Vec<_>>())
+    {
+        keys.extend(channels.into_iter().map(CacheKey::Channel));
+    }
+
+    keys
+}
+
+/// Collects the keys of the values an update will change, in a stable order
+/// and without duplicates.
+fn update_keys<E: CacheKeys>(e: &E, cache: &Cache) -> Vec<CacheKey> {
+    let mut keys = e.cache_keys(cache);
+    keys.sort_by_key(|k| k.to_string());
+    keys.dedup();
+
+    keys
+}
+
+/// A change to a value stored in Redis, mirroring the local cache.
+#[derive(Debug, Eq, PartialEq)]
+enum RedisWrite {
+    /// Stores the JSON of the local value under the key.
+    Set(CacheKey, String),
+    /// Removes the key, as the local cache no longer has a value for it.
+    Delete(CacheKey),
+}
+
+impl RedisWrite {
+    /// Determines how to mirror the local value of a key to Redis.
+    fn new(cache: &Cache, key: CacheKey) -> crate::Result<Self> {
+        Ok(match local_json(cache, key)? {
+            Some(value) => RedisWrite::Set(key, value),
+            None => RedisWrite::Delete(key),
+        })
+    }
+
+    fn key(&self) -> CacheKey {
+        match self {
+            RedisWrite::Set(key, _) | RedisWrite::Delete(key) => *key,
+        }
+    }
+}
+
+fn local_json(cache: &Cache, key: CacheKey) -> crate::Result<Option<String>> {
+    let json = match key {
+        CacheKey::Channel(id) => match local_channel(cache, id) {
+            Some(channel) => Some(serde_json::to_string(&channel)?),
+            None => None,
+        },
+        CacheKey::Guild(id) => match cache.guilds.get(&id) {
+            Some(guild) => Some(serde_json::to_string(guild.value())?),
+            None => None,
+        },
+        CacheKey::User(id) => match cache.users.get(&id) {
+            Some(user) => Some(serde_json::to_string(user.value())?),
+            None => None,
+        },
+    };
+
+    Ok(json)
+}
+
+fn local_channel(cache: &Cache, id: ChannelId) -> Option<Channel> {
+    if let Some(category) = cache.categories.get(&id) {
+        return Some(Channel::Category(category.clone()));
+    }
+
+    cache.channel(id)
+}
+
+/// Drops the local copy of the value of a key.
+fn invalidate(cache: &Cache, key: CacheKey) {
+    match key {
+        CacheKey::Channel(id) => {
+            cache.categories.remove(&id);
+            cache.remove_channel(id);
+            cache.groups.remove(&id);
+            cache.private_channels.remove(&id);
+
+            if let Some(guild_id) = cache.threads.get(&id).map(|t| t.guild_id) {
+                cache.remove_thread(guild_id, id);
+            }
+        }
+        CacheKey::Guild(id) => {
+            cache.remove_guild(id);
+        }
+        CacheKey::User(id) => {
+            cache.remove_user(id);
+        }
+    }
+}
+
+impl CacheKeys for Event {
+    fn cache_keys(&self, cache: &Cache) -> Vec<CacheKey> {
+        match self {
+            Event::ChannelCreate(e) => channel_keys(&e.channel),
+            Event::ChannelDelete(e) => channel_keys(&e.channel),
+            Event::ChannelUpdate(e) => channel_keys(&e.channel),
+            Event::ChannelPinsUpdate(e) => vec![CacheKey::Channel(e.channel_id)],
+            Event::ChannelRecipientAdd(e) => {
+                vec![CacheKey::Channel(e.channel_id), CacheKey::User(e.user.id)]
+            }
+            Event::ChannelRecipientRemove(e) => vec![CacheKey::Channel(e.channel_id)],
+            Event::GuildCreate(e) => {
+                let mut keys = guild_keys(cache, e.guild.id);
+                keys.extend(e.guild.channels.keys().copied().map(CacheKey::Channel));
+                keys.extend(e.guild.members.keys().copied().map(CacheKey::User));
+
+                keys
+            }
+            Event::GuildDelete(e) => guild_keys(cache, e.guild.id),
+            Event::GuildEmojisUpdate(e) => vec![CacheKey::Guild(e.guild_id)],
+            Event::GuildMemberAdd(e) => vec![
+                CacheKey::Guild(e.guild_id),
+                CacheKey::User(e.member.user.id),
+            ],
+            Event::GuildMemberRemove(e) => vec![CacheKey::Guild(e.guild_id)],
+            Event::GuildMemberUpdate(e) => {
+                vec![CacheKey::Guild(e.guild_id), CacheKey::User(e.user.id)]
+            }
+            Event::GuildMembersChunk(e) => {
+                let mut keys = vec![CacheKey::Guild(e.guild_id)];
+                keys.extend(e.members.keys().copied().map(CacheKey::User));
+
+                keys
+            }
+            Event::GuildRoleCreate(e) => vec![CacheKey::Guild(e.guild_id)],
+            Event::GuildRoleDelete(e) => vec![CacheKey::Guild(e.guild_id)],
+            Event::GuildRoleUpdate(e) => vec![CacheKey::Guild(e.guild_id)],
+            Event::GuildUnavailable(e) => guild_keys(cache, e.guild_id),
+            Event::GuildUpdate(e) => vec![CacheKey::Guild(e.guild.id)],
+            Event::PresenceUpdate(e) => {
+                let mut keys = vec![CacheKey::User(e.presence.user_id)];
+                keys.extend(e.guild_id.map(CacheKey::Guild));
+
+                keys
+            }
+            Event::Ready(e) => {
+                let mut keys = Vec::new();
+
+                for status in &e.ready.guilds {
+                    keys.extend(guild_keys(cache, status.id()));
+                }
+
+                keys.extend(
+                    e.ready
+                        .private_channels
+                        .keys()
+                        .copied()
+                        .map(CacheKey::Channel),
+                );
+
+                keys
+            }
+            Event::VoiceStateUpdate(e) => e.guild_id.map(CacheKey::Guild).into_iter().collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// A [`Cache`] whose guilds, channels and users are mirrored to Redis, so
+/// that multiple shard processes can share their view of Discord.
+///
+/// Every process keeps its own local [`Cache`], which remains the source of
+/// truth for the shards it runs. Events are applied through
+/// [`RedisCache::update`]: the local cache is updated first, then every value
+/// the event touched is written to (or removed from) Redis, and its key is
+/// published on an invalidation channel. Processes running [`listen`] drop
+/// their local copy of those values, so the next read through the
+/// `RedisCache` fetches the fresh one from Redis.
+///
+/// Values are stored as JSON, in the same representation the gateway and REST
+/// API use.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # async fn run() -> serenity::Result<()> {
+/// use serenity::cache::{Cache, RedisCache};
+/// use std::sync::Arc;
+///
+/// let cache = Arc::new(Cache::new());
+/// let shared = RedisCache::new(cache, "redis://127.0.0.1/").await?.prefix("my-bot");
+///
+/// // Keep the local cache in sync with other processes.
+/// let listener = shared.clone();
+/// tokio::spawn(async move { listener.listen().await });
+///
+/// if let Some(guild) = shared.guild(381880193251409931).await? {
+///     println!("Found {}", guild.name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Cache`]: struct.Cache.html
+/// [`RedisCache::update`]: #method.update
+/// [`listen`]: #method.listen
+#[derive(Clone)]
+pub struct RedisCache {
+    cache: Arc<Cache>,
+    client: Client,
+    connection: MultiplexedConnection,
+    origin: String,
+    prefix: String,
+}
+
+impl RedisCache {
+    /// Connects to the Redis server at the given URL, sharing the values of
+    /// the given cache.
+    pub async fn new(cache: Arc<Cache>, url: &str) -> crate::Result<Self> {
+        let client = Client::open(url)?;
+        let connection = client.get_multiplexed_tokio_connection().await?;
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+
+        Ok(Self {
+            cache,
+            client,
+            connection,
+            origin: format!("{}-{}", std::process::id(), nanos),
+            prefix: "serenity".to_string(),
+        })
+    }
+
+    /// Sets the prefix of every key written to Redis, allowing several bots
+    /// to share one server.
+    ///
+    /// Defaults to `serenity`.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+
+        self
+    }
+
+    /// Returns the local cache backing this one.
+    pub fn cache(&self) -> &Arc<Cache> {
+        &self.cache
+    }
+
+    fn redis_key(&self, key: CacheKey) -> String {
+        format!("{}:{}", self.prefix, key)
+    }
+
+    fn channel_name(&self) -> String {
+        format!("{}:invalidate", self.prefix)
+    }
+
+    /// Updates the local cache with the given update, then writes every value
+    /// it touched to Redis and notifies the other processes.
+    ///
+    /// Returns the output of the update, as [`Cache::update`] does.
+    ///
+    /// [`Cache::update`]: struct.Cache.html#method.update
+    pub async fn update<E>(&self, e: &mut E) -> crate::Result<Option<E::Output>>
+    where
+        E: CacheUpdate + CacheKeys,
+    {
+        let keys = update_keys(e, &self.cache);
+        let output = self.cache.update(e).await;
+
+        for key in keys {
+            self.publish(key).await?;
+        }
+
+        Ok(output)
+    }
+
+    /// Writes the local value of a key to Redis, or removes it if the local
+    /// cache no longer has one, and notifies the other processes.
+    pub async fn publish(&self, key: CacheKey) -> crate::Result<()> {
+        self.write(RedisWrite::new(&self.cache, key)?).await
+    }
+
+    async fn write(&self, write: RedisWrite) -> crate::Result<()> {
+        let mut connection = self.connection.clone();
+        let key = write.key();
+        let redis_key = self.redis_key(key);
+
+        match write {
+            RedisWrite::Set(_, value) => connection.set::<_, _, ()>(&redis_key, value).await?,
+            RedisWrite::Delete(_) => connection.del::<_, ()>(&redis_key).await?,
+        }
+
+        let message = format!("{} {}", self.origin, key);
+        connection
+            .publish::<_, _, ()>(self.channel_name(), message)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Drops the local copy of a value, so that the next read fetches it from
+    /// Redis.
+    pub fn invalidate(&self, key: CacheKey) {
+        invalidate(&self.cache, key);
+    }
+
+    /// Subscribes to the invalidations published by other processes, dropping
+    /// the local copy of every value they changed.
+    ///
+    /// This only returns if the connection to Redis is lost.
+    pub async fn listen(&self) -> crate::Result<()> {
+        let mut pubsub = self.client.get_async_connection().await?.into_pubsub();
+        pubsub.subscribe(self.channel_name()).await?;
+
+        let mut messages = pubsub.on_message();
+
+        while let Some(message) = messages.next().await {
+            let payload = message.get_payload::<String>()?;
+            let mut split = payload.splitn(2, ' ');
+
+            if split.next() == Some(self.origin.as_str()) {
+                continue;
+            }
+
+            if let Some(key) = split.next().and_then(|k| k.parse().ok()) {
+                self.invalidate(key);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn fetch<T: serde::de::DeserializeOwned>(
+        &self,
+        key: CacheKey,
+    ) -> crate::Result<Option<T>> {
+        let mut connection = self.connection.clone();
+        let value: Option<String> = connection.get(self.redis_key(key)).await?;
+
+        match value {
+            Some(value) => Ok(Some(serde_json::from_str(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Retrieves a channel, from the local cache if present or from Redis
+    /// otherwise.
+    pub async fn channel<C: Into<ChannelId>>(&self, id: C) -> crate::Result<Option<Channel>> {
+        let id = id.into();
+
+        if let Some(channel) = local_channel(&self.cache, id) {
+            return Ok(Some(channel));
+        }
+
+        let channel = self.fetch::<Channel>(CacheKey::Channel(id)).await?;
+
+        match &channel {
+            Some(Channel::Category(c)) => {
+                self.cache.categories.insert(id, c.clone());
+            }
+            Some(Channel::Group(c)) => {
+                self.cache.groups.insert(id, c.clone());
+            }
+            Some(Channel::Guild(c)) => {
+                self.cache.insert_channel(c.clone());
+            }
+            Some(Channel::Private(c)) => {
+                self.cache.private_channels.insert(id, c.clone());
+            }
+            Some(Channel::Thread(c)) => {
+                self.cache.insert_thread(c.clone());
+            }
+            None => {}
+        }
+
+        Ok(channel)
+    }
+
+    /// Retrieves a guild, from the local cache if present or from Redis
+    /// otherwise.
+    pub async fn guild<G: Into<GuildId>>(&self, id: G) -> crate::Result<Option<Guild>> {
+        let id = id.into();
+
+        if let Some(guild) = self.cache.guild(id) {
+            return Ok(Some(guild));
+        }
+
+        let guild = self.fetch::<Guild>(CacheKey::Guild(id)).await?;
+
+        if let Some(guild) = &guild {
+            self.cache.replace_guild(guild.clone());
+        }
+
+        Ok(guild)
+    }
+
+    /// Retrieves a user, from the local cache if present or from Redis
+    /// otherwise.
+    pub async fn user<U: Into<UserId>>(&self, id: U) -> crate::Result<Option<User>> {
+        let id = id.into();
+
+        if let Some(user) = self.cache.user(id) {
+            return Ok(Some(user));
+        }
+
+        let user = self.fetch::<User>(CacheKey::User(id)).await?;
+
+        if let Some(user) = &user {
+            self.cache.insert_user(user.clone());
+        }
+
+        Ok(user)
+    }
+}
+
+impl fmt::Debug for RedisCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedisCache")
+            .field("origin", &self.origin)
+            .field("prefix", &self.prefix)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{invalidate, update_keys, CacheKey, RedisWrite};
+    use crate::cache::Cache;
+    use crate::model::event::*;
+    use crate::model::prelude::*;
+    use serde_json::{json, Value};
+
+    fn channel(id: u64) -> Value {
+        json!({
+            "id": id.to_string(),
+            "guild_id": "1",
+            "type": 0,
+            "name": format!("channel {}", id),
+            "permission_overwrites": [],
+            "position": 0,
+        })
+    }
+
+    fn guild_create() -> Event {
+        Event::GuildCreate(
+            serde_json::from_value(json!({
+                "id": "1",
+                "afk_channel_id": null,
+                "afk_timeout": 300,
+                "channels": [channel(10), channel(11)],
+                "default_message_notifications": 0,
+                "emojis": [],
+                "explicit_content_filter": 0,
+                "features": [],
+                "icon": null,
+                "joined_at": "2020-01-01T00:00:00+00:00",
+                "large": false,
+                "member_count": 1,
+                "members": [{
+                    "deaf": false,
+                    "joined_at": "2020-01-01T00:00:00+00:00",
+                    "mute": false,
+                    "nick": null,
+                    "roles": [],
+                    "user": {
+                        "id": "5",
+                        "avatar": null,
+                        "bot": false,
+                        "discriminator": "0001",
+                        "username": "user 5",
+                    },
+                }],
+                "mfa_level": 0,
+                "name": "guild",
+                "owner_id": "5",
+                "presences": [],
+                "region": "us-west",
+                "roles": [],
+                "splash": null,
+                "system_channel_id": null,
+                "verification_level": 0,
+                "voice_states": [],
+                "description": null,
+                "premium_tier": 0,
+                "premium_subscription_count": 0,
+                "banner": null,
+                "vanity_url_code": null,
+                "preferred_locale": "en-US",
+            }))
+            .unwrap(),
+        )
+    }
+
+    fn writes(cache: &Cache, keys: &[CacheKey]) -> Vec<RedisWrite> {
+        keys.iter()
+            .map(|key| RedisWrite::new(cache, *key).unwrap())
+            .collect()
+    }
+
+    fn is_set(write: &RedisWrite) -> bool {
+        matches!(write, RedisWrite::Set(..))
+    }
+
+    #[tokio::test]
+    async fn test_redis_channel_keys() {
+        let cache = Cache::new();
+        cache.update(&mut guild_create()).await;
+
+        let channel = Channel::Guild(serde_json::from_value(channel(12)).unwrap());
+        let mut event = Event::ChannelCreate(ChannelCreateEvent {
+            channel: channel.clone(),
+        });
+        let keys = update_keys(&event, &cache);
+        assert_eq!(
+            keys,
+            vec![
+                CacheKey::Channel(ChannelId(12)),
+                CacheKey::Guild(GuildId(1))
+            ]
+        );
+
+        cache.update(&mut event).await;
+        let written = writes(&cache, &keys);
+        assert!(written.iter().all(is_set));
+        match &written[0] {
+            RedisWrite::Set(_, json) => {
+                assert_eq!(serde_json::from_str::<Value>(json).unwrap()["id"], 12)
+            }
+            other => panic!("Expected the channel to be written, got {:?}", other),
+        }
+
+        // A deleted channel is removed from Redis, while its guild is
+        // rewritten.
+        let mut event = Event::ChannelDelete(ChannelDeleteEvent { channel });
+        let keys = update_keys(&event, &cache);
+        cache.update(&mut event).await;
+        let written = writes(&cache, &keys);
+        assert_eq!(
+            written[0],
+            RedisWrite::Delete(CacheKey::Channel(ChannelId(12)))
+        );
+        assert!(is_set(&written[1]));
+
+        // Invalidating a channel drops the local copy.
+        invalidate(&cache, CacheKey::Channel(ChannelId(10)));
+        assert!(cache.guild_channel(10).is_none());
+        assert!(cache.guild_channel(11).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_redis_guild_keys() {
+        let cache = Cache::new();
+
+        let mut event = guild_create();
+        let keys = update_keys(&event, &cache);
+        assert_eq!(
+            keys,
+            vec![
+                CacheKey::Channel(ChannelId(10)),
+                CacheKey::Channel(ChannelId(11)),
+                CacheKey::Guild(GuildId(1)),
+                CacheKey::User(UserId(5)),
+            ]
+        );
+
+        cache.update(&mut event).await;
+        assert!(writes(&cache, &keys).iter().all(is_set));
+
+        // The keys of a guild's channels are collected before it is removed.
+        let mut event = Event::GuildUnavailable(GuildUnavailableEvent {
+            guild_id: GuildId(1),
+        });
+        let keys = update_keys(&event, &cache);
+        assert_eq!(
+            keys,
+            vec![
+                CacheKey::Channel(ChannelId(10)),
+                CacheKey::Channel(ChannelId(11)),
+                CacheKey::Guild(GuildId(1)),
+            ]
+        );
+
+        cache.update(&mut event).await;
+        let written = writes(&cache, &keys);
+        assert_eq!(written[2], RedisWrite::Delete(CacheKey::Guild(GuildId(1))));
+
+        // Invalidating a guild or user drops the local copy.
+        cache.update(&mut guild_create()).await;
+        invalidate(&cache, CacheKey::Guild(GuildId(1)));
+        invalidate(&cache, CacheKey::User(UserId(5)));
+        assert!(cache.guild(1).is_none());
+        assert!(cache.user(5).is_none());
+    }
+
+    #[test]
+    fn test_cache_key_round_trip() {
+        for key in &[
+            CacheKey::Channel(ChannelId(1)),
+            CacheKey::Guild(GuildId(2)),
+            CacheKey::User(UserId(3)),
+        ] {
+            assert_eq!(key.to_string().parse::<CacheKey>(), Ok(*key));
+        }
+
+        assert!("role:4".parse::<CacheKey>().is_err());
+    }
+}
@@ -1,3 +1,6 @@
+use crate::model::id::ChannelId;
+use std::{collections::HashMap, time::Duration};
+
 /// Settings for the cache.
 ///
 /// # Examples
@@ -15,14 +18,120 @@
 pub struct Settings {
     /// The maximum number of messages to store in a channel's message cache.
     ///
+    /// This is the default for channels without an entry in
+    /// [`max_messages_per_channel`].
+    ///
     /// Defaults to 0.
+    ///
+    /// [`max_messages_per_channel`]: #structfield.max_messages_per_channel
     pub max_messages: usize,
+    /// Overrides of [`max_messages`] for individual channels.
+    ///
+    /// Defaults to an empty map.
+    ///
+    /// [`max_messages`]: #structfield.max_messages
+    pub max_messages_per_channel: HashMap<ChannelId, usize>,
+    /// Whether to store [`Presence`]s, both globally and within guilds.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`Presence`]: ../model/gateway/struct.Presence.html
+    pub cache_presences: bool,
+    /// Whether to only store the status, nickname and a summary of the
+    /// activity of [`Presence`]s, dropping activity assets, party and timing
+    /// information, client statuses and the user object.
+    ///
+    /// Presences are usually the largest consumer of memory in the cache for
+    /// bots in many guilds, and most bots only need to know users' statuses.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`Presence`]: ../model/gateway/struct.Presence.html
+    pub compact_presences: bool,
+    /// How long to keep a [`Presence`] after it was last updated.
+    ///
+    /// Expired presences are removed by [`Cache::prune_expired`]. Without a
+    /// TTL, presences stay cached until the user goes offline.
+    ///
+    /// Defaults to `None`.
+    ///
+    /// [`Cache::prune_expired`]: struct.Cache.html#method.prune_expired
+    /// [`Presence`]: ../model/gateway/struct.Presence.html
+    pub presence_ttl: Option<Duration>,
+    /// Whether to store [`User`]s in [`Cache::users`].
+    ///
+    /// When disabled, members and recipients still carry their user data, but
+    /// it is not shared between them and can not be looked up by Id.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`Cache::users`]: struct.Cache.html#structfield.users
+    /// [`User`]: ../model/user/struct.User.html
+    pub cache_users: bool,
+    /// Whether to store guilds' [`VoiceState`]s.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`VoiceState`]: ../model/voice/struct.VoiceState.html
+    pub cache_voice_states: bool,
+    /// Whether to store guilds' [`Emoji`]s.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`Emoji`]: ../model/guild/struct.Emoji.html
+    pub cache_emojis: bool,
+    /// Whether the client should request the members of guilds whose member
+    /// lists are incomplete as they become available.
+    ///
+    /// Discord only sends all members of small guilds. When this is enabled,
+    /// members of larger guilds are requested over the shard once their
+    /// [`GuildCreate`] is received, and stored in the cache as the
+    /// [`GuildMembersChunk`] events arrive.
+    ///
+    /// **Note**: Requesting members requires the `GUILD_MEMBERS` intent to be
+    /// enabled for the bot.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`GuildCreate`]: ../model/event/enum.Event.html#variant.GuildCreate
+    /// [`GuildMembersChunk`]: ../model/event/enum.Event.html#variant.GuildMembersChunk
+    pub chunk_guilds_on_ready: bool,
+    /// The number of the most recently applied events to remember, which can
+    /// be retrieved via [`Cache::recent_events`] to help find out how the
+    /// cache came to be in its current state.
+    ///
+    /// Defaults to 0, which records no events.
+    ///
+    /// [`Cache::recent_events`]: struct.Cache.html#method.recent_events
+    pub max_recent_events: usize,
+    /// How long to wait for the initial set of guilds after the most recent
+    /// [`Ready`], before startup is considered complete regardless.
+    ///
+    /// Guilds which are unavailable during an outage may not be sent for a
+    /// long time, which would otherwise hold back [`Cache::wait_for_startup`]
+    /// indefinitely. The guilds still missing once it passes are logged.
+    ///
+    /// Defaults to 60 seconds. `None` waits indefinitely.
+    ///
+    /// [`Cache::wait_for_startup`]: struct.Cache.html#method.wait_for_startup
+    /// [`Ready`]: ../model/event/enum.Event.html#variant.Ready
+    pub startup_timeout: Option<Duration>,
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Settings {
             max_messages: usize::default(),
+            max_messages_per_channel: HashMap::default(),
+            cache_presences: true,
+            compact_presences: false,
+            presence_ttl: None,
+            cache_users: true,
+            cache_voice_states: true,
+            cache_emojis: true,
+            chunk_guilds_on_ready: false,
+            max_recent_events: 0,
+            startup_timeout: Some(Duration::from_secs(60)),
         }
     }
 }
@@ -55,4 +164,197 @@ impl Settings {
 
         self
     }
+
+    /// Sets the maximum number of messages to cache in a specific channel,
+    /// overriding [`max_messages`] for that channel.
+    ///
+    /// Refer to [`max_messages_per_channel`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Only cache messages in a moderation log channel:
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    /// use serenity::model::id::ChannelId;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings.max_messages(0).max_messages_in(ChannelId(7), 100);
+    ///
+    /// assert_eq!(settings.max_messages_for(ChannelId(7)), 100);
+    /// assert_eq!(settings.max_messages_for(ChannelId(8)), 0);
+    /// ```
+    ///
+    /// [`max_messages`]: #structfield.max_messages
+    /// [`max_messages_per_channel`]: #structfield.max_messages_per_channel
+    pub fn max_messages_in<C: Into<ChannelId>>(&mut self, channel_id: C, max: usize) -> &mut Self {
+        self.max_messages_per_channel.insert(channel_id.into(), max);
+
+        self
+    }
+
+    /// Returns the maximum number of messages to cache in the given channel,
+    /// falling back to [`max_messages`] if the channel has no override.
+    ///
+    /// [`max_messages`]: #structfield.max_messages
+    pub fn max_messages_for<C: Into<ChannelId>>(&self, channel_id: C) -> usize {
+        self.max_messages_per_channel
+            .get(&channel_id.into())
+            .copied()
+            .unwrap_or(self.max_messages)
+    }
+
+    /// Sets whether to store presences.
+    ///
+    /// Refer to [`cache_presences`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Disable caching of presences, users and voice states for a bot that
+    /// only needs guild and channel data:
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings
+    ///     .cache_presences(false)
+    ///     .cache_users(false)
+    ///     .cache_voice_states(false);
+    /// ```
+    ///
+    /// [`cache_presences`]: #structfield.cache_presences
+    pub fn cache_presences(&mut self, cache: bool) -> &mut Self {
+        self.cache_presences = cache;
+
+        self
+    }
+
+    /// Sets whether to store presences in their compact form.
+    ///
+    /// Refer to [`compact_presences`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Only keep users' statuses and activity names:
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings.compact_presences(true);
+    /// ```
+    ///
+    /// [`compact_presences`]: #structfield.compact_presences
+    pub fn compact_presences(&mut self, compact: bool) -> &mut Self {
+        self.compact_presences = compact;
+
+        self
+    }
+
+    /// Sets how long to keep presences after they were last updated.
+    ///
+    /// Refer to [`presence_ttl`] for more information.
+    ///
+    /// [`presence_ttl`]: #structfield.presence_ttl
+    pub fn presence_ttl<D: Into<Option<Duration>>>(&mut self, ttl: D) -> &mut Self {
+        self.presence_ttl = ttl.into();
+
+        self
+    }
+
+    /// Sets whether to store users.
+    ///
+    /// Refer to [`cache_users`] for more information.
+    ///
+    /// [`cache_users`]: #structfield.cache_users
+    pub fn cache_users(&mut self, cache: bool) -> &mut Self {
+        self.cache_users = cache;
+
+        self
+    }
+
+    /// Sets whether to store voice states.
+    ///
+    /// Refer to [`cache_voice_states`] for more information.
+    ///
+    /// [`cache_voice_states`]: #structfield.cache_voice_states
+    pub fn cache_voice_states(&mut self, cache: bool) -> &mut Self {
+        self.cache_voice_states = cache;
+
+        self
+    }
+
+    /// Sets whether to store emojis.
+    ///
+    /// Refer to [`cache_emojis`] for more information.
+    ///
+    /// [`cache_emojis`]: #structfield.cache_emojis
+    pub fn cache_emojis(&mut self, cache: bool) -> &mut Self {
+        self.cache_emojis = cache;
+
+        self
+    }
+
+    /// Sets whether to request the members of guilds with incomplete member
+    /// lists.
+    ///
+    /// Refer to [`chunk_guilds_on_ready`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings.chunk_guilds_on_ready(true);
+    /// ```
+    ///
+    /// [`chunk_guilds_on_ready`]: #structfield.chunk_guilds_on_ready
+    pub fn chunk_guilds_on_ready(&mut self, chunk: bool) -> &mut Self {
+        self.chunk_guilds_on_ready = chunk;
+
+        self
+    }
+
+    /// Sets the number of recently applied events to remember.
+    ///
+    /// Refer to [`max_recent_events`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings.max_recent_events(100);
+    /// ```
+    ///
+    /// [`max_recent_events`]: #structfield.max_recent_events
+    pub fn max_recent_events(&mut self, max: usize) -> &mut Self {
+        self.max_recent_events = max;
+
+        self
+    }
+
+    /// Sets how long to wait for the initial set of guilds.
+    ///
+    /// Refer to [`startup_timeout`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    /// use std::time::Duration;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings.startup_timeout(Duration::from_secs(120));
+    /// ```
+    ///
+    /// [`startup_timeout`]: #structfield.startup_timeout
+    pub fn startup_timeout<D: Into<Option<Duration>>>(&mut self, timeout: D) -> &mut Self {
+        self.startup_timeout = timeout.into();
+
+        self
+    }
 }
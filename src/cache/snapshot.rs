@@ -0,0 +1,35 @@
+use crate::model::prelude::*;
+use std::collections::HashMap;
+
+/// The current version of the snapshot format.
+///
+/// This is bumped whenever the representation changes in a way that older
+/// snapshots can no longer be read.
+pub(crate) const SNAPSHOT_VERSION: u8 = 1;
+
+/// The serialized form of a [`Cache`], with the entries of every map stored
+/// as a list.
+///
+/// [`Cache`]: struct.Cache.html
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct Snapshot {
+    pub version: u8,
+    pub categories: Vec<ChannelCategory>,
+    pub channels: Vec<GuildChannel>,
+    pub groups: Vec<Group>,
+    pub guilds: Vec<Guild>,
+    /// Messages of all channels, in the order they were cached.
+    pub messages: Vec<Message>,
+    pub notes: HashMap<UserId, String>,
+    pub presences: Vec<Presence>,
+    pub private_channels: Vec<PrivateChannel>,
+    pub shard_count: u64,
+    /// Threads are also stored with their guilds, but kept here as well for
+    /// those whose guild is not cached. Snapshots written before threads were
+    /// cached lack them.
+    #[serde(default)]
+    pub threads: Vec<GuildThread>,
+    pub unavailable_guilds: Vec<GuildId>,
+    pub user: CurrentUser,
+    pub users: Vec<User>,
+}
@@ -0,0 +1,186 @@
+use crate::model::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// An immutable copy of the data in a [`Cache`] at one point in time, made
+/// via [`Cache::snapshot`].
+///
+/// The view holds plain maps instead of concurrent ones, so reading from it
+/// never waits on a lock and entries can be borrowed for as long as the view
+/// lives, including across `.await`s. This makes it suited to hot read paths
+/// which look up many entries in a row, such as parsing command arguments.
+///
+/// **Note**: Updates made to the cache after the view was taken are not
+/// reflected in it. Take a new snapshot to see them.
+///
+/// [`Cache`]: struct.Cache.html
+/// [`Cache::snapshot`]: struct.Cache.html#method.snapshot
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct CacheView {
+    /// A map of channels in guilds, as in [`Cache::channels`].
+    ///
+    /// [`Cache::channels`]: struct.Cache.html#structfield.channels
+    pub channels: HashMap<ChannelId, GuildChannel>,
+    /// A map of channel categories.
+    pub categories: HashMap<ChannelId, ChannelCategory>,
+    /// A map of the groups that the current user is in.
+    pub groups: HashMap<ChannelId, Group>,
+    /// A map of guilds with full data available.
+    pub guilds: HashMap<GuildId, Guild>,
+    /// A map of channels to their cached messages.
+    pub messages: HashMap<ChannelId, HashMap<MessageId, Message>>,
+    /// A map of notes that the current user has made for users.
+    pub notes: HashMap<UserId, String>,
+    /// A map of users' presences.
+    pub presences: HashMap<UserId, Presence>,
+    /// A map of direct message channels.
+    pub private_channels: HashMap<ChannelId, PrivateChannel>,
+    /// The total number of shards being used by the bot.
+    pub shard_count: u64,
+    /// The guilds which are unavailable.
+    pub unavailable_guilds: HashSet<GuildId>,
+    /// The current user.
+    pub user: CurrentUser,
+    /// A map of users that the current user sees.
+    pub users: HashMap<UserId, User>,
+}
+
+impl CacheView {
+    /// Retrieves a guild channel, private channel or group by its Id.
+    ///
+    /// Unlike [`Cache::channel`], this borrows the channel instead of
+    /// cloning it, so the result is wrapped in a [`ChannelRef`].
+    ///
+    /// [`Cache::channel`]: struct.Cache.html#method.channel
+    /// [`ChannelRef`]: enum.ChannelRef.html
+    #[inline]
+    pub fn channel<C: Into<ChannelId>>(&self, id: C) -> Option<ChannelRef<'_>> {
+        self._channel(id.into())
+    }
+
+    fn _channel(&self, id: ChannelId) -> Option<ChannelRef<'_>> {
+        if let Some(channel) = self.channels.get(&id) {
+            return Some(ChannelRef::Guild(channel));
+        }
+
+        if let Some(private_channel) = self.private_channels.get(&id) {
+            return Some(ChannelRef::Private(private_channel));
+        }
+
+        self.groups.get(&id).map(ChannelRef::Group)
+    }
+
+    /// Retrieves a channel category by its Id.
+    #[inline]
+    pub fn category<C: Into<ChannelId>>(&self, id: C) -> Option<&ChannelCategory> {
+        self.categories.get(&id.into())
+    }
+
+    /// Retrieves a group by its Id.
+    #[inline]
+    pub fn group<C: Into<ChannelId>>(&self, id: C) -> Option<&Group> {
+        self.groups.get(&id.into())
+    }
+
+    /// Retrieves a guild by its Id.
+    #[inline]
+    pub fn guild<G: Into<GuildId>>(&self, id: G) -> Option<&Guild> {
+        self.guilds.get(&id.into())
+    }
+
+    /// Retrieves a channel of a guild by its Id.
+    #[inline]
+    pub fn guild_channel<C: Into<ChannelId>>(&self, id: C) -> Option<&GuildChannel> {
+        self.channels.get(&id.into())
+    }
+
+    /// Retrieves a member of a guild.
+    #[inline]
+    pub fn member<G, U>(&self, guild_id: G, user_id: U) -> Option<&Member>
+    where
+        G: Into<GuildId>,
+        U: Into<UserId>,
+    {
+        self._member(guild_id.into(), user_id.into())
+    }
+
+    fn _member(&self, guild_id: GuildId, user_id: UserId) -> Option<&Member> {
+        self.guilds.get(&guild_id)?.members.get(&user_id)
+    }
+
+    /// Retrieves a message by its Id from the messages cached for a channel.
+    #[inline]
+    pub fn message<C, M>(&self, channel_id: C, message_id: M) -> Option<&Message>
+    where
+        C: Into<ChannelId>,
+        M: Into<MessageId>,
+    {
+        self._message(channel_id.into(), message_id.into())
+    }
+
+    fn _message(&self, channel_id: ChannelId, message_id: MessageId) -> Option<&Message> {
+        self.messages.get(&channel_id)?.get(&message_id)
+    }
+
+    /// Retrieves a private channel by its Id.
+    #[inline]
+    pub fn private_channel<C: Into<ChannelId>>(&self, id: C) -> Option<&PrivateChannel> {
+        self.private_channels.get(&id.into())
+    }
+
+    /// Retrieves a role of a guild.
+    #[inline]
+    pub fn role<G, R>(&self, guild_id: G, role_id: R) -> Option<&Role>
+    where
+        G: Into<GuildId>,
+        R: Into<RoleId>,
+    {
+        self._role(guild_id.into(), role_id.into())
+    }
+
+    fn _role(&self, guild_id: GuildId, role_id: RoleId) -> Option<&Role> {
+        self.guilds.get(&guild_id)?.roles.get(&role_id)
+    }
+
+    /// Retrieves a user by their Id.
+    #[inline]
+    pub fn user<U: Into<UserId>>(&self, id: U) -> Option<&User> {
+        self.users.get(&id.into())
+    }
+}
+
+/// A borrowed channel of any kind, as returned by [`CacheView::channel`].
+///
+/// [`CacheView::channel`]: struct.CacheView.html#method.channel
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum ChannelRef<'a> {
+    /// A channel of a guild.
+    Guild(&'a GuildChannel),
+    /// A direct message channel.
+    Private(&'a PrivateChannel),
+    /// A group.
+    Group(&'a Group),
+}
+
+impl ChannelRef<'_> {
+    /// The Id of the channel.
+    pub fn id(self) -> ChannelId {
+        match self {
+            ChannelRef::Guild(channel) => channel.id,
+            ChannelRef::Private(channel) => channel.id,
+            ChannelRef::Group(group) => group.channel_id,
+        }
+    }
+
+    /// Clones the channel into an owned [`Channel`].
+    ///
+    /// [`Channel`]: ../model/channel/enum.Channel.html
+    pub fn to_channel(self) -> Channel {
+        match self {
+            ChannelRef::Guild(channel) => Channel::Guild(channel.clone()),
+            ChannelRef::Private(channel) => Channel::Private(channel.clone()),
+            ChannelRef::Group(group) => Channel::Group(group.clone()),
+        }
+    }
+}
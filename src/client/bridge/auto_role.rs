@@ -0,0 +1,548 @@
+//! Automatic assignment of roles to members joining a guild.
+//!
+//! An [`AutoRole`] holds a set of "on join, assign roles X" rules per guild,
+//! persisted through an [`AutoRoleStorage`] implementation. It is driven by
+//! calling [`AutoRole::member_join`] from your [`EventHandler`]'s
+//! [`guild_member_addition`] handler.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use serenity::client::bridge::auto_role::{AutoRole, InMemoryAutoRoleStorage};
+//! use serenity::model::prelude::*;
+//! use serenity::prelude::*;
+//! use async_trait::async_trait;
+//!
+//! struct Handler {
+//!     auto_role: AutoRole<InMemoryAutoRoleStorage>,
+//! }
+//!
+//! #[async_trait]
+//! impl EventHandler for Handler {
+//!     async fn guild_member_addition(&self, ctx: Context, _: GuildId, member: Member) {
+//!         if let Err(why) = self.auto_role.member_join(&ctx, &member).await {
+//!             println!("Failed to assign join roles: {:?}", why);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`AutoRole`]: struct.AutoRole.html
+//! [`AutoRole::member_join`]: struct.AutoRole.html#method.member_join
+//! [`AutoRoleStorage`]: trait.AutoRoleStorage.html
+//! [`EventHandler`]: ../../trait.EventHandler.html
+//! [`guild_member_addition`]: ../../trait.EventHandler.html#method.guild_member_addition
+
+use crate::http::{CacheHttp, HttpError};
+use crate::internal::prelude::*;
+use crate::internal::AsyncRwLock;
+use crate::model::prelude::*;
+use async_trait::async_trait;
+use log::warn;
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[cfg(all(feature = "cache", feature = "model"))]
+use crate::cache::Cache;
+
+/// Persistence for the roles an [`AutoRole`] assigns in each guild.
+///
+/// Implement this to back the rules with a database or file; for simple use
+/// cases, [`InMemoryAutoRoleStorage`] is provided.
+///
+/// [`AutoRole`]: struct.AutoRole.html
+/// [`InMemoryAutoRoleStorage`]: struct.InMemoryAutoRoleStorage.html
+#[async_trait]
+pub trait AutoRoleStorage: Send + Sync {
+    /// Loads the roles to assign to members joining the guild.
+    async fn load(&self, guild_id: GuildId) -> Result<Vec<RoleId>>;
+
+    /// Stores the roles to assign to members joining the guild, replacing any
+    /// previously stored roles.
+    async fn store(&self, guild_id: GuildId, roles: Vec<RoleId>) -> Result<()>;
+}
+
+/// An [`AutoRoleStorage`] which keeps rules in memory, losing them on restart.
+///
+/// [`AutoRoleStorage`]: trait.AutoRoleStorage.html
+#[derive(Debug, Default)]
+pub struct InMemoryAutoRoleStorage {
+    rules: AsyncRwLock<HashMap<GuildId, Vec<RoleId>>>,
+}
+
+#[async_trait]
+impl AutoRoleStorage for InMemoryAutoRoleStorage {
+    async fn load(&self, guild_id: GuildId) -> Result<Vec<RoleId>> {
+        Ok(self
+            .rules
+            .read()
+            .await
+            .get(&guild_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn store(&self, guild_id: GuildId, roles: Vec<RoleId>) -> Result<()> {
+        if roles.is_empty() {
+            self.rules.write().await.remove(&guild_id);
+        } else {
+            self.rules.write().await.insert(guild_id, roles);
+        }
+
+        Ok(())
+    }
+}
+
+/// Assigns a configured set of roles to members when they join a guild.
+///
+/// Refer to the [module-level documentation] for an example.
+///
+/// [module-level documentation]: index.html
+#[derive(Debug)]
+pub struct AutoRole<S> {
+    storage: S,
+    max_attempts: u32,
+    retry_delay: Duration,
+}
+
+impl<S: AutoRoleStorage> AutoRole<S> {
+    /// Creates a new `AutoRole` persisting its rules in the given storage.
+    ///
+    /// By default, assigning a role is attempted up to 3 times, waiting 1
+    /// second longer after each failed attempt.
+    pub fn new(storage: S) -> Self {
+        AutoRole {
+            storage,
+            max_attempts: 3,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+
+    /// Sets how many times assigning a role is attempted before giving up.
+    ///
+    /// Only server errors and failures to send the request are retried.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+
+        self
+    }
+
+    /// Sets the base delay between attempts. The delay grows linearly with
+    /// each failed attempt.
+    pub fn retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+
+        self
+    }
+
+    /// Returns a reference to the underlying storage.
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Retrieves the roles assigned to members joining the guild.
+    pub async fn roles(&self, guild_id: impl Into<GuildId>) -> Result<Vec<RoleId>> {
+        self.storage.load(guild_id.into()).await
+    }
+
+    /// Sets the roles assigned to members joining the guild. Passing an empty
+    /// list disables automatic assignment for the guild.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns the same errors as [`validate`]
+    /// without storing the roles.
+    ///
+    /// [`validate`]: #method.validate
+    pub async fn set_roles(
+        &self,
+        cache_http: impl CacheHttp,
+        guild_id: impl Into<GuildId>,
+        roles: Vec<RoleId>,
+    ) -> Result<()> {
+        let guild_id = guild_id.into();
+
+        #[cfg(all(feature = "cache", feature = "model"))]
+        {
+            if let Some(cache) = cache_http.cache() {
+                validate(cache, guild_id, &roles)?;
+            }
+        }
+
+        self.storage.store(guild_id, roles).await
+    }
+
+    /// Checks that the current user is able to assign the given roles in the
+    /// guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::GuildNotFound`] or [`ModelError::RoleNotFound`]
+    /// if the guild or a role is not cached, a
+    /// [`ModelError::InvalidPermissions`] if the current user lacks the
+    /// [Manage Roles] permission, and a [`ModelError::Hierarchy`] if a role is
+    /// not below the current user's highest role.
+    ///
+    /// [`ModelError::GuildNotFound`]: ../../../model/error/enum.Error.html#variant.GuildNotFound
+    /// [`ModelError::Hierarchy`]: ../../../model/error/enum.Error.html#variant.Hierarchy
+    /// [`ModelError::InvalidPermissions`]: ../../../model/error/enum.Error.html#variant.InvalidPermissions
+    /// [`ModelError::RoleNotFound`]: ../../../model/error/enum.Error.html#variant.RoleNotFound
+    /// [Manage Roles]: ../../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[cfg(all(feature = "cache", feature = "model"))]
+    pub fn validate(
+        &self,
+        cache: impl AsRef<Cache>,
+        guild_id: impl Into<GuildId>,
+        roles: &[RoleId],
+    ) -> Result<()> {
+        validate(cache.as_ref(), guild_id.into(), roles)
+    }
+
+    /// Assigns the guild's configured roles to a member that joined, returning
+    /// the roles that were newly assigned.
+    ///
+    /// Roles the member already has are skipped.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns the same errors as [`validate`].
+    ///
+    /// Returns the last [`Error::Http`] if assigning a role failed after all
+    /// attempts. Roles assigned before the failure remain assigned.
+    ///
+    /// [`Error::Http`]: ../../../enum.Error.html#variant.Http
+    /// [`validate`]: #method.validate
+    pub async fn member_join(
+        &self,
+        cache_http: impl CacheHttp,
+        member: &Member,
+    ) -> Result<Vec<RoleId>> {
+        let roles = self
+            .storage
+            .load(member.guild_id)
+            .await?
+            .into_iter()
+            .filter(|role_id| !member.roles.contains(role_id))
+            .collect::<Vec<_>>();
+
+        if roles.is_empty() {
+            return Ok(roles);
+        }
+
+        #[cfg(all(feature = "cache", feature = "model"))]
+        {
+            if let Some(cache) = cache_http.cache() {
+                validate(cache, member.guild_id, &roles)?;
+            }
+        }
+
+        let user_id = member.user.id;
+
+        for role_id in &roles {
+            self.add_role(&cache_http, member.guild_id, user_id, *role_id)
+                .await?;
+        }
+
+        Ok(roles)
+    }
+
+    async fn add_role(
+        &self,
+        cache_http: &impl CacheHttp,
+        guild_id: GuildId,
+        user_id: UserId,
+        role_id: RoleId,
+    ) -> Result<()> {
+        let mut attempt = 1;
+
+        loop {
+            let res = cache_http
+                .http()
+                .add_member_role(guild_id.0, user_id.0, role_id.0)
+                .await;
+
+            match res {
+                Err(ref why) if attempt < self.max_attempts && is_retryable(why) => {
+                    warn!(
+                        "[AutoRole] Failed to add role {} to {} in {} (attempt {}): {:?}",
+                        role_id, user_id, guild_id, attempt, why,
+                    );
+
+                    tokio::time::sleep(self.retry_delay * attempt).await;
+                    attempt += 1;
+                }
+                other => return other,
+            }
+        }
+    }
+}
+
+impl Default for AutoRole<InMemoryAutoRoleStorage> {
+    fn default() -> Self {
+        AutoRole::new(InMemoryAutoRoleStorage::default())
+    }
+}
+
+fn is_retryable(error: &Error) -> bool {
+    match error {
+        Error::Http(why) => match &**why {
+            HttpError::UnsuccessfulRequest(response) => response.status_code.is_server_error(),
+            HttpError::Request(_) | HttpError::Timeout(_) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(all(feature = "cache", feature = "model"))]
+fn validate(cache: &Cache, guild_id: GuildId, roles: &[RoleId]) -> Result<()> {
+    let user_id = cache.current_user_id();
+
+    let guild = match cache.guilds.get(&guild_id) {
+        Some(guild) => guild,
+        None => return Err(Error::Model(ModelError::GuildNotFound)),
+    };
+
+    let required = Permissions::MANAGE_ROLES;
+
+    if !guild.member_permissions(user_id).contains(required) {
+        return Err(Error::Model(ModelError::InvalidPermissions(required)));
+    }
+
+    let highest = if guild.owner_id == user_id {
+        None
+    } else {
+        Some(
+            guild
+                .members
+                .get(&user_id)
+                .map(|member| {
+                    member
+                        .roles
+                        .iter()
+                        .filter_map(|id| guild.roles.get(id))
+                        .map(|role| role.position)
+                        .max()
+                        .unwrap_or(0)
+                })
+                .unwrap_or(0),
+        )
+    };
+
+    for role_id in roles {
+        let role = match guild.roles.get(role_id) {
+            Some(role) => role,
+            None => return Err(Error::Model(ModelError::RoleNotFound)),
+        };
+
+        if role.managed {
+            return Err(Error::Model(ModelError::Hierarchy));
+        }
+
+        if let Some(highest) = highest {
+            if role.position >= highest {
+                return Err(Error::Model(ModelError::Hierarchy));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AutoRole, AutoRoleStorage, InMemoryAutoRoleStorage};
+    use crate::http::mock::{MockResponse, MockServer};
+    use crate::model::prelude::*;
+    use serde_json::{json, Value};
+    use std::time::Duration;
+
+    #[cfg(feature = "cache")]
+    use crate::{cache::Cache, Error};
+
+    fn role(id: u64, position: i64, permissions: u64, managed: bool) -> Value {
+        json!({
+            "id": id.to_string(),
+            "color": 0,
+            "hoist": false,
+            "managed": managed,
+            "mentionable": false,
+            "name": format!("role {}", id),
+            "permissions": permissions,
+            "position": position,
+        })
+    }
+
+    fn member(user_id: u64, roles: &[u64]) -> Value {
+        json!({
+            "deaf": false,
+            "guild_id": "1",
+            "joined_at": "2020-01-01T00:00:00+00:00",
+            "mute": false,
+            "nick": null,
+            "roles": roles.iter().map(u64::to_string).collect::<Vec<_>>(),
+            "user": {
+                "id": user_id.to_string(),
+                "avatar": null,
+                "bot": false,
+                "discriminator": "0001",
+                "username": format!("user {}", user_id),
+            },
+        })
+    }
+
+    /// A cache with a guild in which the current user, 10, has a role at
+    /// position 5 allowing it to manage roles.
+    #[cfg(feature = "cache")]
+    fn cache(permissions: u64) -> Cache {
+        let guild: Guild = serde_json::from_value(json!({
+            "id": "1",
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "channels": [],
+            "default_message_notifications": 0,
+            "emojis": [],
+            "explicit_content_filter": 0,
+            "features": [],
+            "icon": null,
+            "joined_at": "2020-01-01T00:00:00+00:00",
+            "large": false,
+            "member_count": 2,
+            "members": [member(10, &[2]), member(11, &[])],
+            "mfa_level": 0,
+            "name": "guild",
+            "owner_id": "3",
+            "presences": [],
+            "region": "us-west",
+            "roles": [
+                role(1, 0, 0, false),
+                role(2, 5, permissions, false),
+                role(3, 1, 0, false),
+                role(4, 1, 0, true),
+                role(5, 10, 0, false),
+            ],
+            "splash": null,
+            "system_channel_id": null,
+            "verification_level": 0,
+            "voice_states": [],
+            "description": null,
+            "premium_tier": 0,
+            "premium_subscription_count": 0,
+            "banner": null,
+            "vanity_url_code": null,
+            "preferred_locale": "en-US",
+        }))
+        .unwrap();
+
+        let cache = Cache::new();
+        cache.user.write().id = UserId(10);
+        cache.guilds.insert(guild.id, guild);
+
+        cache
+    }
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn test_auto_role_validate() {
+        let auto_role = AutoRole::default();
+        let cache = cache(Permissions::MANAGE_ROLES.bits());
+        let validate = |guild_id: u64, roles: &[u64]| {
+            let roles = roles.iter().copied().map(RoleId).collect::<Vec<_>>();
+
+            auto_role.validate(&cache, guild_id, &roles)
+        };
+
+        assert!(validate(1, &[3]).is_ok());
+        assert!(matches!(
+            validate(2, &[3]),
+            Err(Error::Model(ModelError::GuildNotFound))
+        ));
+        assert!(matches!(
+            validate(1, &[3, 9]),
+            Err(Error::Model(ModelError::RoleNotFound))
+        ));
+
+        // Managed roles, and roles which are not below the current user's
+        // highest role, can not be assigned.
+        for roles in &[&[4][..], &[2], &[3, 5]] {
+            assert!(matches!(
+                validate(1, roles),
+                Err(Error::Model(ModelError::Hierarchy))
+            ));
+        }
+
+        let cache = self::cache(0);
+        assert!(matches!(
+            auto_role.validate(&cache, 1, &[RoleId(3)]),
+            Err(Error::Model(ModelError::InvalidPermissions(permissions)))
+                if permissions == Permissions::MANAGE_ROLES
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_auto_role_retry() {
+        let server = MockServer::start().await;
+        let http = server.http("Bot token");
+        let auto_role = AutoRole::default()
+            .max_attempts(2)
+            .retry_delay(Duration::from_millis(1));
+        auto_role
+            .set_roles(&http, 1, vec![RoleId(3), RoleId(4)])
+            .await
+            .unwrap();
+
+        // A server error is retried, and roles the member has are skipped.
+        server.enqueue(MockResponse::new(502));
+        server.enqueue(MockResponse::new(204));
+        let member: Member = serde_json::from_value(member(11, &[4])).unwrap();
+        assert_eq!(
+            auto_role.member_join(&http, &member).await.unwrap(),
+            vec![RoleId(3)]
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests.iter().all(
+            |request| request.method == "PUT" && request.path == "/guilds/1/members/11/roles/3"
+        ));
+
+        // Attempts are limited.
+        server.enqueue(MockResponse::new(502));
+        server.enqueue(MockResponse::new(503));
+        assert!(auto_role.member_join(&http, &member).await.is_err());
+        assert_eq!(server.requests().len(), 4);
+
+        // Client errors are not retried.
+        server.enqueue(MockResponse::json(
+            403,
+            &json!({"code": 50013, "message": "Missing Permissions"}),
+        ));
+        assert!(auto_role.member_join(&http, &member).await.is_err());
+        assert_eq!(server.requests().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_auto_role_storage() {
+        let storage = InMemoryAutoRoleStorage::default();
+        assert!(storage.load(GuildId(1)).await.unwrap().is_empty());
+
+        storage.store(GuildId(1), vec![RoleId(3)]).await.unwrap();
+        storage.store(GuildId(2), vec![RoleId(4)]).await.unwrap();
+        assert_eq!(storage.load(GuildId(1)).await.unwrap(), vec![RoleId(3)]);
+
+        storage
+            .store(GuildId(1), vec![RoleId(5), RoleId(6)])
+            .await
+            .unwrap();
+        assert_eq!(
+            storage.load(GuildId(1)).await.unwrap(),
+            vec![RoleId(5), RoleId(6)]
+        );
+
+        // Storing no roles removes the guild's rules.
+        storage.store(GuildId(1), vec![]).await.unwrap();
+        assert!(storage.load(GuildId(1)).await.unwrap().is_empty());
+        assert!(!storage.rules.read().await.contains_key(&GuildId(1)));
+        assert_eq!(storage.load(GuildId(2)).await.unwrap(), vec![RoleId(4)]);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::model::event::{Event, EventType};
+use crate::model::Timestamp;
+use std::collections::{HashSet, VecDeque};
+
+/// The identity of an event, used to recognise it when Discord sends it a
+/// second time.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct EventKey {
+    kind: EventType,
+    id: u64,
+    timestamp: Option<Timestamp>,
+}
+
+impl EventKey {
+    /// Creates the key of an event, if it is one that can be told apart from
+    /// other events of its type.
+    ///
+    /// Events such as reactions are left out, as the same payload is sent
+    /// again when a user removes and re-adds a reaction.
+    fn new(event: &Event) -> Option<Self> {
+        let (id, timestamp) = match event {
+            Event::ChannelCreate(e) => (e.channel.id().0, None),
+            Event::GuildMemberAdd(e) => (e.member.user.id.0, e.member.joined_at),
+            Event::GuildRoleCreate(e) => (e.role.id.0, None),
+            Event::MessageCreate(e) => (e.message.id.0, None),
+            Event::MessageDelete(e) => (e.message_id.0, None),
+            // Updates without an edit time, such as embeds being resolved,
+            // can't be told apart from each other.
+            Event::MessageUpdate(e) => (e.id.0, Some(e.edited_timestamp?)),
+            _ => return None,
+        };
+
+        Some(Self {
+            kind: event.event_type(),
+            id,
+            timestamp,
+        })
+    }
+}
+
+/// The keys of the most recent events dispatched by a shard runner.
+///
+/// When a shard resumes, Discord replays every event since the last sequence
+/// it acknowledged, some of which may already have been dispatched.
+pub(super) struct EventDeduplicator {
+    capacity: usize,
+    order: VecDeque<EventKey>,
+    seen: HashSet<EventKey>,
+}
+
+impl EventDeduplicator {
+    /// Creates a deduplicator remembering up to `capacity` events. A capacity
+    /// of `0` disables it.
+    pub(super) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            order: VecDeque::with_capacity(capacity),
+            seen: HashSet::with_capacity(capacity),
+        }
+    }
+
+    /// Records the event, returning whether it was already seen.
+    pub(super) fn is_duplicate(&mut self, event: &Event) -> bool {
+        if self.capacity == 0 {
+            return false;
+        }
+
+        let key = match EventKey::new(event) {
+            Some(key) => key,
+            None => return false,
+        };
+
+        if self.seen.contains(&key) {
+            return true;
+        }
+
+        if self.order.len() == self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+
+        self.seen.insert(key.clone());
+        self.order.push_back(key);
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EventDeduplicator;
+    use crate::model::event::Event;
+    use serde_json::json;
+
+    fn message_create(id: u64) -> Event {
+        Event::MessageCreate(
+            serde_json::from_value(json!({
+                "id": id.to_string(),
+                "channel_id": "1",
+                "author": {"id": "2", "username": "a", "discriminator": "0001", "avatar": null},
+                "content": "",
+                "timestamp": "2021-01-01T00:00:00+00:00",
+                "edited_timestamp": null,
+                "tts": false,
+                "mention_everyone": false,
+                "mentions": [],
+                "mention_roles": [],
+                "attachments": [],
+                "embeds": [],
+                "pinned": false,
+                "type": 0,
+            }))
+            .unwrap(),
+        )
+    }
+
+    fn message_update(id: u64, edited_timestamp: Option<&str>) -> Event {
+        Event::MessageUpdate(
+            serde_json::from_value(json!({
+                "id": id.to_string(),
+                "channel_id": "1",
+                "edited_timestamp": edited_timestamp,
+            }))
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let mut dedup = EventDeduplicator::new(2);
+
+        assert!(!dedup.is_duplicate(&message_create(3)));
+        assert!(dedup.is_duplicate(&message_create(3)));
+
+        let first_edit = Some("2021-01-01T00:00:01+00:00");
+        let second_edit = Some("2021-01-01T00:00:02+00:00");
+        assert!(!dedup.is_duplicate(&message_update(3, first_edit)));
+        assert!(!dedup.is_duplicate(&message_update(3, second_edit)));
+        assert!(!dedup.is_duplicate(&message_update(3, None)));
+        assert!(!dedup.is_duplicate(&message_update(3, None)));
+
+        // The capacity is 2, so the creation has been forgotten.
+        assert!(dedup.is_duplicate(&message_update(3, second_edit)));
+        assert!(!dedup.is_duplicate(&message_create(3)));
+    }
+
+    #[test]
+    fn test_disabled() {
+        let mut dedup = EventDeduplicator::new(0);
+
+        assert!(!dedup.is_duplicate(&message_create(3)));
+        assert!(!dedup.is_duplicate(&message_create(3)));
+    }
+}
@@ -50,6 +50,8 @@
 
 pub mod event;
 
+mod event_dedup;
+mod reaction_filter;
 mod shard_manager;
 mod shard_manager_monitor;
 mod shard_messenger;
@@ -57,20 +59,26 @@ mod shard_queuer;
 mod shard_runner;
 mod shard_runner_message;
 
-pub use self::shard_manager::{ShardManager, ShardManagerOptions};
+pub use self::reaction_filter::ReactionFilter;
+pub use self::shard_manager::{ShardManager, ShardManagerOptions, ShardShutdown};
 pub use self::shard_manager_monitor::ShardManagerMonitor;
 pub use self::shard_messenger::ShardMessenger;
 pub use self::shard_queuer::ShardQueuer;
 pub use self::shard_runner::{ShardRunner, ShardRunnerOptions};
 pub use self::shard_runner_message::ShardRunnerMessage;
 
+#[cfg(feature = "metrics")]
+use crate::gateway::StageMetrics;
 use crate::gateway::{ConnectionStage, InterMessage};
+#[cfg(feature = "metrics")]
+use std::sync::Arc;
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     time::Duration as StdDuration,
 };
 
 use futures::channel::mpsc::UnboundedSender;
+use tokio::sync::watch;
 
 /// A message either for a [`ShardManager`] or a [`ShardRunner`].
 ///
@@ -140,6 +148,11 @@ pub enum ShardQueuerMessage {
     /// Message to start a shard, where the 0-index element is the ID of the
     /// Shard to start and the 1-index element is the total shards in use.
     Start(ShardId, ShardId),
+    /// Message to set the number of shards which may identify at the same
+    /// time, as given by the [`SessionStartLimit`] of the bot.
+    ///
+    /// [`SessionStartLimit`]: ../../../model/gateway/struct.SessionStartLimit.html
+    SetMaxConcurrency(u64),
     /// Message to shutdown the shard queuer.
     Shutdown,
 }
@@ -172,4 +185,9 @@ pub struct ShardRunnerInfo {
     pub runner_tx: UnboundedSender<InterMessage>,
     /// The current connection stage of the shard.
     pub stage: ConnectionStage,
+    /// The time spent in each stage of the shard's event pipeline.
+    #[cfg(feature = "metrics")]
+    pub metrics: Arc<StageMetrics>,
+    /// Closed once the shard runner has exited.
+    pub(crate) finished: watch::Receiver<()>,
 }
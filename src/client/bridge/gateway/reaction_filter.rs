@@ -0,0 +1,110 @@
+use crate::model::channel::Reaction;
+use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+
+type Predicate = dyn Fn(&Reaction) -> bool + Send + Sync;
+
+/// Forwards the reactions added on a shard which match a predicate, once set
+/// via [`ShardMessenger::set_reaction_filter`].
+///
+/// The filter is removed from the shard once its receiver is dropped.
+///
+/// # Examples
+///
+/// Wait for the next reaction a user adds on a shard:
+///
+/// ```rust,no_run
+/// # use serenity::client::Context;
+/// #
+/// # async fn run(ctx: &Context) {
+/// use futures::StreamExt;
+/// use serenity::client::bridge::gateway::ReactionFilter;
+/// use serenity::model::id::UserId;
+///
+/// let (filter, mut reactions) = ReactionFilter::new(|r| r.user_id == UserId(7));
+/// ctx.shard.clone().set_reaction_filter(filter).await;
+///
+/// if let Some(reaction) = reactions.next().await {
+///     println!("Reacted with {}", reaction.emoji);
+/// }
+/// # }
+/// ```
+///
+/// [`ShardMessenger::set_reaction_filter`]: struct.ShardMessenger.html#method.set_reaction_filter
+#[derive(Clone)]
+pub struct ReactionFilter {
+    predicate: Arc<Predicate>,
+    tx: UnboundedSender<Reaction>,
+}
+
+impl ReactionFilter {
+    /// Creates a new filter, along with the receiver of the reactions which
+    /// match the predicate.
+    pub fn new<F>(predicate: F) -> (Self, UnboundedReceiver<Reaction>)
+    where
+        F: Fn(&Reaction) -> bool + Send + Sync + 'static,
+    {
+        let (tx, rx) = unbounded();
+        let filter = ReactionFilter {
+            predicate: Arc::new(predicate),
+            tx,
+        };
+
+        (filter, rx)
+    }
+
+    /// Forwards the reaction if it matches, returning whether the receiver
+    /// still exists.
+    pub(crate) fn send(&self, reaction: &Reaction) -> bool {
+        if self.tx.is_closed() {
+            return false;
+        }
+
+        if (self.predicate)(reaction) {
+            return self.tx.unbounded_send(reaction.clone()).is_ok();
+        }
+
+        true
+    }
+}
+
+impl Debug for ReactionFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ReactionFilter")
+            .field("closed", &self.tx.is_closed())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ReactionFilter;
+    use crate::model::{channel::Reaction, id::UserId};
+    use serde_json::json;
+
+    fn reaction(user_id: u64) -> Reaction {
+        serde_json::from_value(json!({
+            "channel_id": "1",
+            "message_id": "2",
+            "user_id": user_id.to_string(),
+            "emoji": {"id": null, "name": "✅"},
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_reaction_filter() {
+        let (filter, mut reactions) = ReactionFilter::new(|r| r.user_id == UserId(3));
+
+        assert!(filter.send(&reaction(3)));
+        assert!(filter.send(&reaction(4)));
+        assert_eq!(reactions.try_recv().unwrap().user_id, UserId(3));
+        assert!(reactions.try_recv().is_err());
+
+        drop(reactions);
+        assert!(!filter.send(&reaction(4)));
+    }
+}
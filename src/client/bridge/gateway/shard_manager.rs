@@ -1,15 +1,28 @@
 use super::super::super::{EventHandler, RawEventHandler};
 use super::{
     ShardClientMessage, ShardId, ShardManagerMessage, ShardManagerMonitor, ShardQueuer,
-    ShardQueuerMessage, ShardRunnerInfo,
+    ShardQueuerMessage, ShardRunnerInfo, ShardRunnerMessage,
 };
-use crate::gateway::InterMessage;
+#[cfg(feature = "metrics")]
+use crate::gateway::StageMetrics;
+use crate::gateway::{ConnectionTimeouts, CurrentPresence, InterMessage, WsProxy};
 use crate::internal::prelude::*;
 use crate::internal::AsyncRwLock;
-use crate::CacheAndHttp;
+use crate::model::gateway::Activity;
+use crate::model::user::OnlineStatus;
+use crate::{CacheAndHttp, SyncRwLock};
+use futures::future::{self, BoxFuture, FutureExt};
 use futures::lock::Mutex;
 use log::{info, warn};
-use std::{collections::VecDeque, sync::Arc};
+use std::{
+    collections::VecDeque,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tokio::sync::watch;
 use typemap::ShareMap;
 
 #[cfg(feature = "voice")]
@@ -47,6 +60,7 @@ use futures::sink::SinkExt;
 /// #
 /// use futures::lock::{Mutex};
 /// use serenity::client::bridge::gateway::{ShardManager, ShardManagerOptions};
+/// use serenity::gateway::ConnectionTimeouts;
 /// use serenity::client::{EventHandler, RawEventHandler};
 /// // Of note, this imports `typemap`'s `ShareMap` type.
 /// use serenity::prelude::*;
@@ -75,6 +89,8 @@ use futures::sink::SinkExt;
 ///     event_handler: &Some(event_handler),
 ///     raw_event_handler: &None,
 ///     framework: &framework,
+///     message_passthrough: true,
+///     defer_until_startup: false,
 ///     // the shard index to start initiating from
 ///     shard_index: 0,
 ///     // the number of shards to initiate (this initiates 0, 1, and 2)
@@ -86,6 +102,9 @@ use futures::sink::SinkExt;
 ///     ws_url: &gateway_url,
 ///     # cache_and_http: &cache_and_http,
 ///     guild_subscriptions: true,
+///     ws_proxy: &None,
+///     connection_timeouts: ConnectionTimeouts::default(),
+///     event_dedup_capacity: 0,
 /// });
 /// #     Ok(())
 /// # }
@@ -109,7 +128,7 @@ pub struct ShardManager {
     /// **Note**: It is highly unrecommended to mutate this yourself unless you
     /// need to. Instead prefer to use methods on this struct that are provided
     /// where possible.
-    pub runners: DashMap<ShardId, ShardRunnerInfo>,
+    pub runners: Arc<DashMap<ShardId, ShardRunnerInfo>>,
     /// The index of the first shard to initialize, 0-indexed.
     shard_index: u64,
     /// The number of shards to initialize.
@@ -117,7 +136,11 @@ pub struct ShardManager {
     /// The total shards in use, 1-indexed.
     shard_total: u64,
     shard_queuer: UnboundedSender<ShardQueuerMessage>,
-    //shard_shutdown: UnboundedReceiver<ShardId>,
+    /// The presence set via [`set_presence_all`], shared with the queuer to
+    /// be applied to newly started shards.
+    ///
+    /// [`set_presence_all`]: #method.set_presence_all
+    presence: Arc<SyncRwLock<Option<CurrentPresence>>>,
 }
 
 impl ShardManager {
@@ -126,7 +149,8 @@ impl ShardManager {
     pub async fn new(opt: ShardManagerOptions<'_>) -> (Arc<Mutex<Self>>, ShardManagerMonitor) {
         let (thread_tx, thread_rx) = mpsc::unbounded();
         let (shard_queue_tx, shard_queue_rx) = mpsc::unbounded();
-        let runners = DashMap::default();
+        let runners = Arc::new(DashMap::default());
+        let presence = Arc::new(SyncRwLock::new(None));
 
         let mut shard_queuer = ShardQueuer {
             data: Arc::clone(opt.data),
@@ -134,7 +158,12 @@ impl ShardManager {
             raw_event_handler: opt.raw_event_handler.as_ref().map(|rh| Arc::clone(rh)),
             #[cfg(feature = "framework")]
             framework: Arc::clone(opt.framework),
+            #[cfg(feature = "framework")]
+            message_passthrough: opt.message_passthrough,
+            #[cfg(feature = "cache")]
+            defer_until_startup: opt.defer_until_startup,
             last_start: None,
+            max_concurrency: 1,
             manager_tx: thread_tx.clone(),
             queue: VecDeque::new(),
             rx: shard_queue_rx,
@@ -143,6 +172,11 @@ impl ShardManager {
             ws_url: Arc::clone(opt.ws_url),
             cache_and_http: Arc::clone(&opt.cache_and_http),
             guild_subscriptions: opt.guild_subscriptions,
+            ws_proxy: opt.ws_proxy.clone(),
+            connection_timeouts: opt.connection_timeouts,
+            event_dedup_capacity: opt.event_dedup_capacity,
+            runners: Arc::clone(&runners),
+            presence: Arc::clone(&presence),
         };
 
         tokio::spawn(async move { shard_queuer.run().await });
@@ -154,6 +188,7 @@ impl ShardManager {
             shard_queuer: shard_queue_tx,
             shard_total: opt.shard_total,
             runners,
+            presence,
         }));
 
         (
@@ -208,6 +243,20 @@ impl ShardManager {
         self.shard_total = total;
     }
 
+    /// Sets the number of shards which may identify at the same time, as given
+    /// by the [`SessionStartLimit`] of the bot.
+    ///
+    /// Defaults to 1, starting shards one at a time.
+    ///
+    /// [`SessionStartLimit`]: ../../../model/gateway/struct.SessionStartLimit.html
+    pub fn set_max_concurrency(&mut self, max_concurrency: u64) {
+        let msg = ShardQueuerMessage::SetMaxConcurrency(max_concurrency);
+
+        if let Err(why) = self.shard_queuer.unbounded_send(msg) {
+            warn!("Failed to send max concurrency to shard queuer: {:?}", why);
+        }
+    }
+
     /// Restarts a shard runner.
     ///
     /// This sends a shutdown signal to a shard's associated [`ShardRunner`],
@@ -267,57 +316,124 @@ impl ShardManager {
         shard_ids
     }
 
+    /// Sets the presence of every shard the manager is responsible for.
+    ///
+    /// Unlike setting it via a shard's [`Context`], the presence is
+    /// remembered and also applied to shards started or restarted later on,
+    /// so it is not lost when a shard reconnects.
+    ///
+    /// Note that [`Offline`] is not a valid online status, so it is
+    /// automatically converted to [`Invisible`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::client::bridge::gateway::ShardManager;
+    /// use serenity::model::gateway::Activity;
+    /// use serenity::model::user::OnlineStatus;
+    ///
+    /// # fn run(manager: &ShardManager) {
+    /// manager.set_presence_all(Some(Activity::playing("on every shard")), OnlineStatus::Idle);
+    /// # }
+    /// ```
+    ///
+    /// [`Context`]: ../../struct.Context.html#method.set_presence
+    /// [`Invisible`]: ../../../model/user/enum.OnlineStatus.html#variant.Invisible
+    /// [`Offline`]: ../../../model/user/enum.OnlineStatus.html#variant.Offline
+    pub fn set_presence_all(&self, activity: Option<Activity>, mut status: OnlineStatus) {
+        if status == OnlineStatus::Offline {
+            status = OnlineStatus::Invisible;
+        }
+
+        *self.presence.write() = Some((activity.clone(), status));
+
+        for runner in self.runners.iter() {
+            let set_presence = ShardRunnerMessage::SetPresence(status, activity.clone());
+            let msg = InterMessage::Client(Box::new(ShardClientMessage::Runner(set_presence)));
+
+            if let Err(why) = runner.runner_tx.unbounded_send(msg) {
+                warn!(
+                    "Failed to set presence of shard {}: {:?}",
+                    runner.key(),
+                    why
+                );
+            }
+        }
+    }
+
+    /// Returns the time spent in each stage of a shard's event pipeline, if
+    /// the shard has a runner.
+    ///
+    /// # Examples
+    ///
+    /// Logging the average time spent updating the cache by each shard:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::client::bridge::gateway::ShardManager;
+    /// # async fn run(manager: &ShardManager) {
+    /// for shard_id in manager.shards_instantiated() {
+    ///     if let Some(metrics) = manager.stage_metrics(shard_id) {
+    ///         println!("Shard {}: {:?}", shard_id, metrics.cache_update.mean());
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "metrics")]
+    pub fn stage_metrics(&self, shard_id: ShardId) -> Option<Arc<StageMetrics>> {
+        self.runners
+            .get(&shard_id)
+            .map(|runner| Arc::clone(&runner.metrics))
+    }
+
     /// Attempts to shut down the shard runner by Id.
     ///
-    /// Returns a boolean indicating whether a shard runner was present. This is
-    /// _not_ necessary an indicator of whether the shard runner was
-    /// successfully shut down.
+    /// The shutdown message is sent immediately. The returned [`ShardShutdown`]
+    /// may be awaited to wait for the shard runner to close its WebSocket
+    /// connection and exit, resolving to whether a shard runner was present.
     ///
     /// **Note**: If the receiving end of an mpsc channel - theoretically owned
     /// by the shard runner - no longer exists, then the shard runner will not
     /// know it should shut down. This _should never happen_. It may already be
     /// stopped.
-    pub fn shutdown(&mut self, shard_id: ShardId) -> bool {
+    ///
+    /// [`ShardShutdown`]: struct.ShardShutdown.html
+    pub fn shutdown(&mut self, shard_id: ShardId) -> ShardShutdown {
+        ShardShutdown::new(self.stop(shard_id).into_iter().collect())
+    }
+
+    // Sends a shutdown message to the shard runner by Id and removes it,
+    // returning a receiver which is closed once the runner has exited.
+    fn stop(&mut self, shard_id: ShardId) -> Option<watch::Receiver<()>> {
         info!("Shutting down shard {}", shard_id);
 
-        if let Some(runner) = self.runners.get_mut(&shard_id) {
-            let shutdown = ShardManagerMessage::Shutdown(shard_id);
-            let client_msg = ShardClientMessage::Manager(shutdown);
-            let msg = InterMessage::Client(Box::new(client_msg));
+        let (_, runner) = self.runners.remove(&shard_id)?;
 
-            if let Err(why) = runner.runner_tx.unbounded_send(msg) {
-                warn!("Failed to cleanly shutdown shard {}: {:?}", shard_id, why,);
-            }
-            /*match self.shard_shutdown.recv_timeout(Duration::from_secs(5)) {
-                Ok(shutdown_shard_id) =>
-                    if shutdown_shard_id != shard_id {
-                        warn!(
-                            "Failed to cleanly shutdown shard {}: Shutdown channel sent incorrect ID",
-                            shard_id,
-                        );
-                    },
-                Err(why) => warn!(
-                    "Failed to cleanly shutdown shard {}: {:?}",
-                    shard_id,
-                    why,
-                )
-            }*/
+        let shutdown = ShardManagerMessage::Shutdown(shard_id);
+        let client_msg = ShardClientMessage::Manager(shutdown);
+        let msg = InterMessage::Client(Box::new(client_msg));
+
+        if let Err(why) = runner.runner_tx.unbounded_send(msg) {
+            warn!("Failed to cleanly shutdown shard {}: {:?}", shard_id, why,);
         }
 
-        self.runners.remove(&shard_id).is_some()
+        Some(runner.finished)
     }
 
     /// Sends a shutdown message for all shards that the manager is responsible
     /// for that are still known to be running.
     ///
+    /// The returned [`ShardShutdown`] may be awaited to wait for all of the
+    /// shard runners to exit, resolving to whether any were present.
+    ///
     /// If you only need to shutdown a select number of shards, prefer looping
     /// over the [`shutdown`] method.
     ///
+    /// [`ShardShutdown`]: struct.ShardShutdown.html
     /// [`shutdown`]: #method.shutdown
-    pub fn shutdown_all(&mut self) {
+    pub fn shutdown_all(&mut self) -> ShardShutdown {
         let keys = {
             if self.runners.is_empty() {
-                return;
+                return ShardShutdown::new(Vec::new());
             }
 
             self.runners.iter().map(|v| *v.key()).collect::<Vec<_>>()
@@ -325,9 +441,10 @@ impl ShardManager {
 
         info!("Shutting down all shards");
 
-        for shard_id in keys {
-            self.shutdown(shard_id);
-        }
+        let finished = keys
+            .into_iter()
+            .filter_map(|shard_id| self.stop(shard_id))
+            .collect();
 
         let _ = self
             .shard_queuer
@@ -335,6 +452,8 @@ impl ShardManager {
         let _ = self
             .monitor_tx
             .unbounded_send(ShardManagerMessage::ShutdownInitiated);
+
+        ShardShutdown::new(finished)
     }
 
     async fn boot(&mut self, shard_info: [ShardId; 2]) {
@@ -365,12 +484,73 @@ impl Drop for ShardManager {
     }
 }
 
+/// A future which resolves once the shard runners asked to shut down by
+/// [`ShardManager::shutdown`] or [`ShardManager::shutdown_all`] have closed
+/// their WebSocket connections and exited.
+///
+/// The shutdown has already been requested when this is returned, so it only
+/// needs to be awaited if the caller wants to wait for it to complete.
+///
+/// **Note**: Commands are run by the shard which received them, so awaiting
+/// the shutdown of that shard from within a command never resolves.
+///
+/// [`ShardManager::shutdown`]: struct.ShardManager.html#method.shutdown
+/// [`ShardManager::shutdown_all`]: struct.ShardManager.html#method.shutdown_all
+pub struct ShardShutdown {
+    future: BoxFuture<'static, bool>,
+}
+
+impl ShardShutdown {
+    fn new(finished: Vec<watch::Receiver<()>>) -> Self {
+        let present = !finished.is_empty();
+
+        let future = async move {
+            // The senders are dropped once the runners exit, at which point
+            // waiting for a change returns an error.
+            future::join_all(
+                finished
+                    .into_iter()
+                    .map(|mut finished| async move { while finished.changed().await.is_ok() {} }),
+            )
+            .await;
+
+            present
+        };
+
+        Self {
+            future: future.boxed(),
+        }
+    }
+}
+
+impl Future for ShardShutdown {
+    type Output = bool;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+        self.future.poll_unpin(cx)
+    }
+}
+
+impl fmt::Debug for ShardShutdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShardShutdown").finish()
+    }
+}
+
 pub struct ShardManagerOptions<'a> {
     pub data: &'a Arc<AsyncRwLock<ShareMap>>,
     pub event_handler: &'a Option<Arc<dyn EventHandler>>,
     pub raw_event_handler: &'a Option<Arc<dyn RawEventHandler>>,
     #[cfg(feature = "framework")]
     pub framework: &'a Arc<Mutex<Option<Box<dyn Framework + Send>>>>,
+    /// Whether message events are passed to the event handler as well as
+    /// the framework.
+    #[cfg(feature = "framework")]
+    pub message_passthrough: bool,
+    /// Whether event handler methods wait for the initial set of guilds to
+    /// be received.
+    #[cfg(feature = "cache")]
+    pub defer_until_startup: bool,
     pub shard_index: u64,
     pub shard_init: u64,
     pub shard_total: u64,
@@ -379,4 +559,111 @@ pub struct ShardManagerOptions<'a> {
     pub ws_url: &'a Arc<Mutex<String>>,
     pub cache_and_http: &'a Arc<CacheAndHttp>,
     pub guild_subscriptions: bool,
+    pub ws_proxy: &'a Option<WsProxy>,
+    /// How long shards wait for each stage of connecting.
+    pub connection_timeouts: ConnectionTimeouts,
+    /// The number of recent events each shard remembers to drop duplicates
+    /// of, or `0` to dispatch every event.
+    pub event_dedup_capacity: usize,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ShardManager, ShardManagerOptions, ShardShutdown};
+    use crate::client::bridge::gateway::{
+        ShardClientMessage, ShardId, ShardRunnerInfo, ShardRunnerMessage,
+    };
+    use crate::gateway::{ConnectionStage, InterMessage};
+    use crate::internal::AsyncRwLock;
+    use crate::model::prelude::*;
+    use crate::CacheAndHttp;
+    use futures::channel::mpsc;
+    use futures::lock::Mutex;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::watch;
+    use tokio::time::timeout;
+    use typemap::ShareMap;
+
+    #[tokio::test]
+    async fn test_set_presence_all() {
+        #[cfg(feature = "voice")]
+        let voice_manager = Arc::new(Mutex::new(
+            crate::client::bridge::voice::ClientVoiceManager::new(1, UserId(0)),
+        ));
+
+        let (manager, _monitor) = ShardManager::new(ShardManagerOptions {
+            data: &Arc::new(AsyncRwLock::new(ShareMap::custom())),
+            event_handler: &None,
+            raw_event_handler: &None,
+            #[cfg(feature = "framework")]
+            framework: &Arc::new(Mutex::new(None)),
+            #[cfg(feature = "framework")]
+            message_passthrough: false,
+            #[cfg(feature = "cache")]
+            defer_until_startup: false,
+            shard_index: 0,
+            shard_init: 1,
+            shard_total: 1,
+            #[cfg(feature = "voice")]
+            voice_manager: &voice_manager,
+            ws_url: &Arc::new(Mutex::new(String::new())),
+            cache_and_http: &Arc::new(CacheAndHttp::default()),
+            guild_subscriptions: true,
+            ws_proxy: &None,
+            connection_timeouts: Default::default(),
+            event_dedup_capacity: 0,
+        })
+        .await;
+        let manager = manager.lock().await;
+
+        let (runner_tx, mut runner_rx) = mpsc::unbounded();
+        let (_finished_tx, finished) = watch::channel(());
+        manager.runners.insert(
+            ShardId(0),
+            ShardRunnerInfo {
+                latency: None,
+                runner_tx,
+                stage: ConnectionStage::Connected,
+                #[cfg(feature = "metrics")]
+                metrics: Arc::default(),
+                finished,
+            },
+        );
+
+        manager.set_presence_all(Some(Activity::playing("tests")), OnlineStatus::Offline);
+
+        match runner_rx.try_recv().unwrap() {
+            InterMessage::Client(msg) => match *msg {
+                ShardClientMessage::Runner(ShardRunnerMessage::SetPresence(status, activity)) => {
+                    assert_eq!(status, OnlineStatus::Invisible);
+                    assert_eq!(activity.unwrap().name, "tests");
+                }
+                other => panic!("unexpected message: {:?}", other),
+            },
+            other => panic!("unexpected message: {:?}", other),
+        }
+
+        // Shards started later on are given the same presence.
+        let presence = manager.presence.read().clone().unwrap();
+        assert_eq!(presence.0.unwrap().name, "tests");
+        assert_eq!(presence.1, OnlineStatus::Invisible);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_waits_for_runners() {
+        assert!(!ShardShutdown::new(Vec::new()).await);
+
+        let (first_tx, first_rx) = watch::channel(());
+        let (second_tx, second_rx) = watch::channel(());
+        let mut shutdown = ShardShutdown::new(vec![first_rx, second_rx]);
+
+        drop(first_tx);
+        assert!(timeout(Duration::from_millis(50), &mut shutdown)
+            .await
+            .is_err());
+
+        drop(second_tx);
+        assert!(shutdown.await);
+    }
 }
@@ -19,8 +19,6 @@ pub struct ShardManagerMonitor {
     pub manager: Arc<Mutex<ShardManager>>,
     /// The mpsc Receiver channel to receive shard manager messages over.
     pub rx: UnboundedReceiver<ShardManagerMessage>,
-    // The mpsc Sender channel to inform the manager that a shard has just properly shut down
-    //pub shutdown: UnboundedSender<ShardId>,
 }
 
 impl ShardManagerMonitor {
@@ -67,66 +65,12 @@ impl ShardManagerMonitor {
                     guard.shutdown_all();
                 }
                 ShardManagerMessage::ShutdownInitiated => break,
-                ShardManagerMessage::ShutdownFinished(_shard_id) => {
-                    /*
-                    TODO: figure out why I care about this
-                    if let Err(why) = self.shutdown.send(shard_id).await {
-                        warn!(
-                            "[ShardMonitor] Could not forward Shutdown signal to ShardManager for shard {}: {:#?}",
-                            shard_id,
-                            why
-                        );
-                    }*/
-                }
-            }
-        }
-    }
-
-    /*
-        while let Some(value) = self.rx.next().await {
-            match value {
-                ShardManagerMessage::Restart(shard_id) => {
-                    {
-                        let mut guard = self.manager.lock().await;
-                        guard.restart(shard_id).await;
-                    }
-                },
-                ShardManagerMessage::ShardUpdate { id, latency, stage } => {
-                    let manager = self.manager.lock().await;
-                    let mut runners = manager.runners.lock().await;
-
-                    if let Some(runner) = runners.get_mut(&id) {
-                        runner.latency = latency;
-                        runner.stage = stage;
-                    }
-                }
-                ShardManagerMessage::Shutdown(shard_id) => {
-                    {
-                        let mut guard = self.manager.lock().await;
-
-                        guard.shutdown(shard_id).await;
-                    }
-                },
-                ShardManagerMessage::ShutdownAll => {
-                    {
-                        let mut guard = self.manager.lock().await;
-                        guard.shutdown_all().await;
-                    }
-
-                    break;
-                },
-                ShardManagerMessage::ShutdownInitiated => break,
+                // Callers waiting on the shutdown are notified through the
+                // `ShardShutdown` returned when it was requested.
                 ShardManagerMessage::ShutdownFinished(shard_id) => {
-                    if let Err(why) = self.shutdown.send(shard_id).await {
-                        warn!(
-                            "[ShardMonitor] Could not forward Shutdown signal to ShardManager for shard {}: {:#?}",
-                            shard_id,
-                            why
-                        );
-                    }
+                    debug!("Shard {} finished shutting down", shard_id);
                 }
             }
         }
     }
-    */
 }
@@ -1,8 +1,11 @@
-use super::{ShardClientMessage, ShardRunnerMessage};
-use crate::gateway::InterMessage;
+use super::{ReactionFilter, ShardClientMessage, ShardRunnerMessage};
+use crate::constants::OpCode;
+use crate::gateway::{GatewayError, InterMessage};
 use crate::model::prelude::*;
+use crate::{Error, Result as SerenityResult};
 use futures::channel::mpsc::{SendError, UnboundedSender};
 use futures::sink::SinkExt;
+use serde_json::Value;
 use tungstenite::Message;
 
 /// A lightweight wrapper around an mpsc sender.
@@ -116,6 +119,54 @@ impl ShardMessenger {
             .await;
     }
 
+    /// Sends a gateway command with the given opcode and data, such as to
+    /// experiment with gateway features the library does not support yet.
+    ///
+    /// Opcodes which are only received, and the ones the shard manages
+    /// itself (heartbeats, identifying and resuming), are rejected. Unknown
+    /// opcodes are sent as is.
+    ///
+    /// Discord allows 120 commands per minute on each connection, so the
+    /// runner drops raw commands once the commands it sent on behalf of users
+    /// use up the part of the budget not reserved for heartbeats.
+    ///
+    /// # Examples
+    ///
+    /// Request the members of a guild by Id:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::client::Context;
+    /// #
+    /// # async fn run(ctx: &Context) -> serenity::Result<()> {
+    /// use serde_json::json;
+    ///
+    /// let data = json!({"guild_id": "81384788765712384", "user_ids": ["7"], "limit": 0});
+    /// ctx.shard.clone().send_raw(8, data).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GatewayError::UnsendableOpCode`] if the opcode can not be
+    /// sent.
+    ///
+    /// [`GatewayError::UnsendableOpCode`]: ../../../gateway/enum.GatewayError.html#variant.UnsendableOpCode
+    pub async fn send_raw(&mut self, op: u8, data: Value) -> SerenityResult<()> {
+        match OpCode::from(u64::from(op)) {
+            OpCode::StatusUpdate
+            | OpCode::VoiceStateUpdate
+            | OpCode::VoiceServerPing
+            | OpCode::GetGuildMembers
+            | OpCode::Unknown(_) => {}
+            _ => return Err(Error::Gateway(GatewayError::UnsendableOpCode(op))),
+        }
+
+        let _ = self.send(ShardRunnerMessage::Raw(op, data)).await;
+
+        Ok(())
+    }
+
     /// Sets the user's current activity, if any.
     ///
     /// Other presence settings are maintained.
@@ -230,6 +281,18 @@ impl ShardMessenger {
             .await;
     }
 
+    /// Forwards the reactions added on the shard which match the filter,
+    /// until its receiver is dropped.
+    ///
+    /// Refer to [`ReactionFilter`] for more information.
+    ///
+    /// [`ReactionFilter`]: struct.ReactionFilter.html
+    pub async fn set_reaction_filter(&mut self, filter: ReactionFilter) {
+        let _ = self
+            .send(ShardRunnerMessage::SetReactionFilter(filter))
+            .await;
+    }
+
     /// Shuts down the websocket by attempting to cleanly close the
     /// connection.
     pub async fn shutdown_clean(&mut self) {
@@ -258,3 +321,46 @@ impl ShardMessenger {
             .await
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::ShardMessenger;
+    use crate::client::bridge::gateway::{ShardClientMessage, ShardRunnerMessage};
+    use crate::gateway::{GatewayError, InterMessage};
+    use crate::Error;
+    use futures::channel::mpsc::unbounded;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_send_raw() {
+        let (tx, mut rx) = unbounded();
+        let mut messenger = ShardMessenger::new(tx);
+
+        for op in &[0, 1, 2, 6, 10] {
+            match messenger.send_raw(*op, json!({})).await {
+                Err(Error::Gateway(GatewayError::UnsendableOpCode(rejected))) => {
+                    assert_eq!(rejected, *op)
+                }
+                other => panic!("op {} was not rejected: {:?}", op, other),
+            }
+        }
+
+        messenger
+            .send_raw(14, json!({"guild_id": "1"}))
+            .await
+            .unwrap();
+
+        match rx.try_recv().unwrap() {
+            InterMessage::Client(message) => match *message {
+                ShardClientMessage::Runner(ShardRunnerMessage::Raw(op, data)) => {
+                    assert_eq!(op, 14);
+                    assert_eq!(data, json!({"guild_id": "1"}));
+                }
+                other => panic!("unexpected message: {:?}", other),
+            },
+            other => panic!("unexpected message: {:?}", other),
+        }
+
+        assert!(rx.try_recv().is_err());
+    }
+}
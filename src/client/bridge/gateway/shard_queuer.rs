@@ -4,10 +4,11 @@ use super::{
     ShardRunnerOptions,
 };
 use crate::gateway::ConnectionStage;
-use crate::gateway::Shard;
+use crate::gateway::{ConnectionTimeouts, CurrentPresence, Shard, WsProxy};
 use crate::internal::prelude::*;
 use crate::internal::AsyncRwLock;
-use crate::CacheAndHttp;
+use crate::{CacheAndHttp, SyncRwLock};
+use dashmap::DashMap;
 use futures::lock::Mutex;
 use log::{info, warn};
 use std::{
@@ -24,7 +25,7 @@ use crate::framework::Framework;
 
 use futures::channel::mpsc::{UnboundedReceiver, UnboundedSender};
 use futures::stream::StreamExt;
-use tokio::time::sleep;
+use tokio::{sync::watch, time::sleep};
 
 const WAIT_BETWEEN_BOOTS_IN_SECONDS: u64 = 5;
 
@@ -53,10 +54,23 @@ pub struct ShardQueuer {
     /// A copy of the framework
     #[cfg(feature = "framework")]
     pub framework: Arc<Mutex<Option<Box<dyn Framework + Send>>>>,
+    /// Whether message events are passed to the event handler as well as
+    /// the framework.
+    #[cfg(feature = "framework")]
+    pub message_passthrough: bool,
+    /// Whether event handler methods wait for the initial set of guilds to
+    /// be received.
+    #[cfg(feature = "cache")]
+    pub defer_until_startup: bool,
     /// The instant that a shard was last started.
     ///
     /// This is used to determine how long to wait between shard IDENTIFYs.
     pub last_start: Option<Instant>,
+    /// The number of shards which may identify at the same time.
+    ///
+    /// Shards are started in batches of this size, waiting 5 seconds between
+    /// each batch.
+    pub max_concurrency: u64,
     /// A copy of the sender channel to communicate with the
     /// [`ShardManagerMonitor`].
     ///
@@ -75,6 +89,23 @@ pub struct ShardQueuer {
     pub ws_url: Arc<Mutex<String>>,
     pub cache_and_http: Arc<CacheAndHttp>,
     pub guild_subscriptions: bool,
+    /// The proxy to open the shards' websocket connections through, if any.
+    pub ws_proxy: Option<WsProxy>,
+    /// How long shards wait for each stage of connecting.
+    pub connection_timeouts: ConnectionTimeouts,
+    /// The number of recent events each shard remembers to drop duplicates
+    /// of, or `0` to dispatch every event.
+    pub event_dedup_capacity: usize,
+    /// The shard runners managed by the [`ShardManager`], for contexts to
+    /// reach shards other than their own.
+    ///
+    /// [`ShardManager`]: struct.ShardManager.html
+    pub runners: Arc<DashMap<ShardId, ShardRunnerInfo>>,
+    /// The presence to start shards with, as set via
+    /// [`ShardManager::set_presence_all`].
+    ///
+    /// [`ShardManager::set_presence_all`]: struct.ShardManager.html#method.set_presence_all
+    pub presence: Arc<SyncRwLock<Option<CurrentPresence>>>,
 }
 
 impl ShardQueuer {
@@ -86,8 +117,8 @@ impl ShardQueuer {
     /// If a [`ShardQueuerMessage::Start`] is received, this will:
     ///
     /// 1. Check how much time has passed since the last shard was started
-    /// 2. If the amount of time is less than the ratelimit, it will sleep until
-    /// that time has passed
+    /// 2. If the shard starts a new batch of [`max_concurrency`] shards, it will
+    /// sleep until the ratelimit has passed
     /// 3. Start the shard by ID
     ///
     /// If a [`ShardQueuerMessage::Shutdown`] is received, this will return and
@@ -97,6 +128,7 @@ impl ShardQueuer {
     /// nature of the loop.
     ///
     /// [`ShardQueuerMessage`]: enum.ShardQueuerMessage.html
+    /// [`max_concurrency`]: #structfield.max_concurrency
     /// [`ShardQueuerMessage::Shutdown`]: enum.ShardQueuerMessage.html#variant.Shutdown
     /// [`ShardQueuerMessage::Start`]: enum.ShardQueuerMessage.html#variant.Start
     /// [`rx`]: #structfield.rx
@@ -107,6 +139,9 @@ impl ShardQueuer {
                 ShardQueuerMessage::Start(id, total) => {
                     self.checked_start(id.0, total.0).await;
                 }
+                ShardQueuerMessage::SetMaxConcurrency(max_concurrency) => {
+                    self.max_concurrency = max_concurrency.max(1);
+                }
             }
         }
         /*let wait_duration = Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS);
@@ -131,12 +166,19 @@ impl ShardQueuer {
         }*/
     }
 
-    async fn check_last_start(&mut self) {
+    async fn check_last_start(&mut self, id: u64) {
         let instant = match self.last_start {
             Some(instant) => instant,
             None => return,
         };
 
+        // Shards in the same batch may identify at the same time.
+        let position_in_batch = id % self.max_concurrency.max(1);
+
+        if position_in_batch != 0 {
+            return;
+        }
+
         // We must wait 5 seconds between IDENTIFYs to avoid session
         // invalidations.
         let duration = Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS);
@@ -152,7 +194,7 @@ impl ShardQueuer {
     }
 
     async fn checked_start(&mut self, id: u64, total: u64) {
-        self.check_last_start().await;
+        self.check_last_start(id).await;
 
         if let Err(why) = self.start(id, total).await {
             warn!("Err starting shard {}: {:?}", id, why);
@@ -167,35 +209,55 @@ impl ShardQueuer {
     async fn start(&mut self, shard_id: u64, shard_total: u64) -> Result<()> {
         let shard_info = [shard_id, shard_total];
 
-        let shard = Shard::new(
+        let mut shard = Shard::new_with_proxy(
             Arc::clone(&self.ws_url),
             &self.cache_and_http.http.token,
             shard_info,
             self.guild_subscriptions,
+            self.ws_proxy.clone(),
+            self.connection_timeouts,
         )
         .await?;
 
+        if let Some((activity, status)) = self.presence.read().clone() {
+            shard.set_presence(status, activity);
+        }
+
         let mut runner = ShardRunner::new(ShardRunnerOptions {
             data: Arc::clone(&self.data),
             event_handler: self.event_handler.as_ref().map(|eh| Arc::clone(eh)),
             raw_event_handler: self.raw_event_handler.as_ref().map(|rh| Arc::clone(rh)),
             #[cfg(feature = "framework")]
             framework: Arc::clone(&self.framework),
+            #[cfg(feature = "framework")]
+            message_passthrough: self.message_passthrough,
+            #[cfg(feature = "cache")]
+            defer_until_startup: self.defer_until_startup,
+            event_dedup_capacity: self.event_dedup_capacity,
             manager_tx: self.manager_tx.clone(),
             #[cfg(feature = "voice")]
             voice_manager: Arc::clone(&self.voice_manager),
             shard,
             cache_and_http: Arc::clone(&self.cache_and_http),
+            runners: Arc::clone(&self.runners),
         });
 
+        let (finished_tx, finished_rx) = watch::channel(());
+
         let runner_info = ShardRunnerInfo {
             latency: None,
             runner_tx: runner.runner_tx(),
             stage: ConnectionStage::Disconnected,
+            #[cfg(feature = "metrics")]
+            metrics: runner.metrics(),
+            finished: finished_rx,
         };
 
         tokio::spawn(async move {
             let _ = runner.run().await;
+
+            // Let anyone waiting on the runner's shutdown know it has exited.
+            drop(finished_tx);
         });
 
         self.manager_tx
@@ -1,13 +1,18 @@
 use super::super::super::dispatch::{dispatch, DispatchEvent};
 use super::super::super::{EventHandler, RawEventHandler};
 use super::event::{ClientEvent, ShardStageUpdateEvent};
-use super::{ShardClientMessage, ShardId, ShardManagerMessage, ShardRunnerMessage};
+use super::event_dedup::EventDeduplicator;
+use super::{
+    ReactionFilter, ShardClientMessage, ShardId, ShardManagerMessage, ShardRunnerInfo,
+    ShardRunnerMessage,
+};
 use crate::gateway::{InterMessage, ReconnectType, Shard, ShardAction};
 use crate::internal::prelude::*;
 use crate::internal::ws_impl::{ReceiverExt, SenderExt};
 use crate::internal::AsyncRwLock;
 use crate::model::event::{Event, GatewayEvent};
 use crate::CacheAndHttp;
+use dashmap::DashMap;
 use futures::lock::Mutex;
 use serde::Deserialize;
 use std::{borrow::Cow, sync::Arc};
@@ -18,14 +23,23 @@ use typemap::ShareMap;
 use super::super::voice::ClientVoiceManager;
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
+#[cfg(feature = "metrics")]
+use crate::gateway::StageMetrics;
+#[cfg(feature = "metrics")]
+use crate::internal::json;
 use log::{debug, error, warn};
 
 use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
 use futures::sink::SinkExt;
 use futures::stream::StreamExt;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::time::timeout;
 
+// Discord allows 120 commands per minute on each connection. Some of these are
+// left for heartbeats, identifying and resuming, which the shard sends itself.
+const COMMAND_LIMIT: u32 = 110;
+const COMMAND_WINDOW: Duration = Duration::from_secs(60);
+
 /// A runner for managing a [`Shard`] and its respective WebSocket client.
 ///
 /// [`Shard`]: ../../../gateway/struct.Shard.html
@@ -35,15 +49,27 @@ pub struct ShardRunner {
     raw_event_handler: Option<Arc<dyn RawEventHandler>>,
     #[cfg(feature = "framework")]
     framework: Arc<Mutex<Option<Box<dyn Framework + Send>>>>,
+    #[cfg(feature = "framework")]
+    message_passthrough: bool,
+    #[cfg(feature = "cache")]
+    defer_until_startup: bool,
     manager_tx: UnboundedSender<ShardManagerMessage>,
     // channel to receive messages from the shard manager and dispatches
     runner_rx: UnboundedReceiver<InterMessage>,
     // channel to send messages to the shard runner from the shard manager
     runner_tx: UnboundedSender<InterMessage>,
     shard: Shard,
+    reaction_filters: Vec<ReactionFilter>,
+    dedup: EventDeduplicator,
+    // The start of the current command window, and the number of commands
+    // sent on behalf of users during it.
+    commands: (Instant, u32),
     #[cfg(feature = "voice")]
     voice_manager: Arc<Mutex<ClientVoiceManager>>,
     cache_and_http: Arc<CacheAndHttp>,
+    runners: Arc<DashMap<ShardId, ShardRunnerInfo>>,
+    #[cfg(feature = "metrics")]
+    metrics: Arc<StageMetrics>,
 }
 
 impl ShardRunner {
@@ -59,11 +85,21 @@ impl ShardRunner {
             raw_event_handler: opt.raw_event_handler,
             #[cfg(feature = "framework")]
             framework: opt.framework,
+            #[cfg(feature = "framework")]
+            message_passthrough: opt.message_passthrough,
+            #[cfg(feature = "cache")]
+            defer_until_startup: opt.defer_until_startup,
             manager_tx: opt.manager_tx,
             shard: opt.shard,
+            reaction_filters: Vec::new(),
+            dedup: EventDeduplicator::new(opt.event_dedup_capacity),
+            commands: (Instant::now(), 0),
             #[cfg(feature = "voice")]
             voice_manager: opt.voice_manager,
             cache_and_http: opt.cache_and_http,
+            runners: opt.runners,
+            #[cfg(feature = "metrics")]
+            metrics: Arc::new(StageMetrics::default()),
         }
     }
 
@@ -75,7 +111,7 @@ impl ShardRunner {
     /// [`ShardManager`], and if there is one, acts on it.
     ///
     /// 2. checks if a heartbeat should be sent to the discord Gateway, and if
-    /// so, sends one.
+    /// so, sends one, and whether the shard has been connecting for too long.
     ///
     /// 3. attempts to retrieve a message from the WebSocket, processing it into
     /// a [`GatewayEvent`]. This will block for 100ms before assuming there is
@@ -111,6 +147,16 @@ impl ShardRunner {
                 return self.request_restart().await;
             }
 
+            if let Some(stage) = self.shard.check_connection_timeout() {
+                warn!(
+                    "[ShardRunner {:?}] Timed out while {}",
+                    self.shard.shard_info(),
+                    stage,
+                );
+
+                return self.request_restart().await;
+            }
+
             let pre = self.shard.stage();
             let (event, action, successful) = self.recv_event().await;
             let post = self.shard.stage();
@@ -136,7 +182,12 @@ impl ShardRunner {
                 None => {}
             }
 
-            if let Some(event) = event {
+            if let Some(event) = event.filter(|event| !self.is_duplicate(event)) {
+                if let Event::ReactionAdd(ref event) = event {
+                    self.reaction_filters
+                        .retain(|filter| filter.send(&event.reaction));
+                }
+
                 self.dispatch(DispatchEvent::Model(event)).await;
             }
 
@@ -151,6 +202,28 @@ impl ShardRunner {
         self.runner_tx.clone()
     }
 
+    /// Clones the stage timings recorded by the runner.
+    #[cfg(feature = "metrics")]
+    pub(super) fn metrics(&self) -> Arc<StageMetrics> {
+        Arc::clone(&self.metrics)
+    }
+
+    /// Checks whether an event was already dispatched, such as when it is
+    /// replayed after resuming.
+    fn is_duplicate(&mut self, event: &Event) -> bool {
+        let duplicate = self.dedup.is_duplicate(event);
+
+        if duplicate {
+            debug!(
+                "[ShardRunner {:?}] Dropping duplicate {:?} event",
+                self.shard.shard_info(),
+                event.event_type(),
+            );
+        }
+
+        duplicate
+    }
+
     /// Takes an action that a [`Shard`] has determined should happen and then
     /// does it.
     ///
@@ -224,18 +297,33 @@ impl ShardRunner {
 
     #[inline]
     async fn dispatch(&self, event: DispatchEvent) {
+        #[cfg(feature = "metrics")]
+        let start = Instant::now();
+
         dispatch(
             event,
             #[cfg(feature = "framework")]
             &self.framework,
+            #[cfg(feature = "framework")]
+            self.message_passthrough,
+            #[cfg(feature = "cache")]
+            self.defer_until_startup,
             &self.data,
             &self.event_handler,
             &self.raw_event_handler,
             &self.runner_tx,
-            self.shard.shard_info()[0],
+            &self.runners,
+            self.shard.shard_info(),
             Arc::clone(&self.cache_and_http),
+            #[cfg(feature = "metrics")]
+            Arc::clone(&self.metrics),
+            #[cfg(not(feature = "metrics"))]
+            (),
         )
         .await;
+
+        #[cfg(feature = "metrics")]
+        self.metrics.dispatch.record(start.elapsed());
     }
 
     // Handles a received value over the shard runner rx channel.
@@ -245,6 +333,12 @@ impl ShardRunner {
     // This always returns true, except in the case that the shard manager asked
     // the runner to shutdown.
     async fn handle_rx_value(&mut self, value: InterMessage) -> bool {
+        // Other commands sent on behalf of users use up the budget of raw
+        // commands too.
+        if is_user_command(&value) {
+            self.take_command();
+        }
+
         match value {
             InterMessage::Client(value) => match *value {
                 ShardClientMessage::Manager(ShardManagerMessage::Start(_, _)) => true,
@@ -318,6 +412,29 @@ impl ShardRunner {
 
                     self.shard.update_presence().is_ok()
                 }
+                ShardClientMessage::Runner(ShardRunnerMessage::SetReactionFilter(filter)) => {
+                    self.reaction_filters.push(filter);
+
+                    true
+                }
+                ShardClientMessage::Runner(ShardRunnerMessage::Raw(op, data)) => {
+                    if !self.take_command() {
+                        warn!(
+                            "[ShardRunner {:?}] Dropping raw command with opcode {}, as the command budget is used up",
+                            self.shard.shard_info(),
+                            op,
+                        );
+
+                        return true;
+                    }
+
+                    let value = serde_json::json!({
+                        "op": op,
+                        "d": data,
+                    });
+
+                    self.shard.client.send_json(&value).is_ok()
+                }
                 ShardClientMessage::Runner(ShardRunnerMessage::SetStatus(status)) => {
                     self.shard.set_status(status);
 
@@ -331,6 +448,21 @@ impl ShardRunner {
         }
     }
 
+    // Counts a command sent on behalf of a user towards the budget, returning
+    // whether it is within the budget.
+    fn take_command(&mut self) -> bool {
+        let (start, sent) = &mut self.commands;
+
+        if start.elapsed() >= COMMAND_WINDOW {
+            *start = Instant::now();
+            *sent = 0;
+        }
+
+        *sent += 1;
+
+        *sent <= COMMAND_LIMIT
+    }
+
     #[cfg(feature = "voice")]
     fn handle_voice_event(&self, event: &Event) {
         match *event {
@@ -391,13 +523,41 @@ impl ShardRunner {
         Ok(true)
     }
 
+    #[cfg(not(feature = "metrics"))]
+    fn recv_gateway_event(&mut self) -> Result<Option<GatewayEvent>> {
+        match self.shard.client.recv_json()? {
+            Some(value) => GatewayEvent::deserialize(value)
+                .map(Some)
+                .map_err(From::from),
+            None => Ok(None),
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    fn recv_gateway_event(&mut self) -> Result<Option<GatewayEvent>> {
+        let mut bytes = match self.shard.client.recv_inflated(&self.metrics)? {
+            Some(bytes) => bytes,
+            None => return Ok(None),
+        };
+
+        let start = Instant::now();
+        let event = json::from_slice::<Value>(&mut bytes)
+            .and_then(|value| GatewayEvent::deserialize(value).map_err(From::from))
+            .map_err(|why| {
+                warn!("Err deserializing payload: {:?}", why);
+
+                why
+            })?;
+        self.metrics.deserialize.record(start.elapsed());
+
+        Ok(Some(event))
+    }
+
     /// Returns a received event, as well as whether reading the potentially
     /// present event was successful.
     async fn recv_event(&mut self) -> (Option<Event>, Option<ShardAction>, bool) {
-        let gw_event = match self.shard.client.recv_json() {
-            Ok(Some(value)) => GatewayEvent::deserialize(value)
-                .map(Some)
-                .map_err(From::from),
+        let gw_event = match self.recv_gateway_event() {
+            Ok(Some(event)) => Ok(Some(event)),
             Ok(None) => Ok(None),
             Err(Error::Tungstenite(TungsteniteError::Io(_))) => {
                 // Check that an amount of time at least double the
@@ -506,6 +666,24 @@ impl ShardRunner {
     }
 }
 
+/// Whether the value is a command sent on behalf of a user, other than a raw
+/// one.
+fn is_user_command(value: &InterMessage) -> bool {
+    match value {
+        InterMessage::Client(message) => matches!(
+            **message,
+            ShardClientMessage::Runner(
+                ShardRunnerMessage::ChunkGuilds { .. }
+                    | ShardRunnerMessage::Message(_)
+                    | ShardRunnerMessage::SetActivity(_)
+                    | ShardRunnerMessage::SetPresence(..)
+                    | ShardRunnerMessage::SetStatus(_)
+            )
+        ),
+        InterMessage::Json(_) => true,
+    }
+}
+
 /// Options to be passed to [`ShardRunner::new`].
 ///
 /// [`ShardRunner::new`]: struct.ShardRunner.html#method.new
@@ -515,9 +693,15 @@ pub struct ShardRunnerOptions {
     pub raw_event_handler: Option<Arc<dyn RawEventHandler>>,
     #[cfg(feature = "framework")]
     pub framework: Arc<Mutex<Option<Box<dyn Framework + Send>>>>,
+    #[cfg(feature = "framework")]
+    pub message_passthrough: bool,
+    #[cfg(feature = "cache")]
+    pub defer_until_startup: bool,
+    pub event_dedup_capacity: usize,
     pub manager_tx: UnboundedSender<ShardManagerMessage>,
     pub shard: Shard,
     #[cfg(feature = "voice")]
     pub voice_manager: Arc<Mutex<ClientVoiceManager>>,
     pub cache_and_http: Arc<CacheAndHttp>,
+    pub runners: Arc<DashMap<ShardId, ShardRunnerInfo>>,
 }
@@ -1,4 +1,6 @@
+use super::ReactionFilter;
 use crate::model::{gateway::Activity, id::GuildId, user::OnlineStatus};
+use serde_json::Value;
 use tungstenite::Message;
 
 /// A message to send from a shard over a WebSocket.
@@ -36,11 +38,16 @@ pub enum ShardRunnerMessage {
     Close(u16, Option<String>),
     /// Indicates that the client is to send a custom WebSocket message.
     Message(Message),
+    /// Indicates that the client is to send a gateway command with the given
+    /// opcode and data, if the shard's command budget allows it.
+    Raw(u8, Value),
     /// Indicates that the client is to update the shard's presence's activity.
     SetActivity(Option<Activity>),
     /// Indicates that the client is to update the shard's presence in its
     /// entirity.
     SetPresence(OnlineStatus, Option<Activity>),
+    /// Indicates that the runner is to forward added reactions to the filter.
+    SetReactionFilter(ReactionFilter),
     /// Indicates that the client is to update the shard's presence's status.
     SetStatus(OnlineStatus),
 }
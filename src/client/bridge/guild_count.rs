@@ -0,0 +1,385 @@
+//! Periodic publishing of the number of guilds the bot is in, such as to bot
+//! lists.
+//!
+//! A [`GuildCountPublisher`] keeps track of the guilds on each shard as they
+//! are received, and passes the counts to a [`GuildCountHandler`] once they
+//! settled and at most once per interval.
+//!
+//! Each shard's guilds are taken from its [`Ready`] payload, so the guilds
+//! streamed in afterwards via `GUILD_CREATE` events while the shard starts up
+//! are not counted twice, and a count is only published once no shard became
+//! ready for a while.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use serenity::client::bridge::guild_count::{GuildCountHandler, GuildCountPublisher, GuildCounts};
+//! use serenity::model::prelude::*;
+//! use serenity::prelude::*;
+//! use async_trait::async_trait;
+//!
+//! struct BotList;
+//!
+//! #[async_trait]
+//! impl GuildCountHandler for BotList {
+//!     async fn publish(&self, counts: &GuildCounts) {
+//!         println!("In {} guilds", counts.total());
+//!     }
+//! }
+//!
+//! struct Handler {
+//!     guild_count: GuildCountPublisher<BotList>,
+//! }
+//!
+//! #[async_trait]
+//! impl EventHandler for Handler {
+//!     async fn ready(&self, _: Context, ready: Ready) {
+//!         self.guild_count.ready(&ready);
+//!     }
+//!
+//!     async fn guild_create(&self, ctx: Context, guild: Guild, _: bool) {
+//!         self.guild_count.guild_create(&ctx, guild.id);
+//!     }
+//!
+//!     async fn guild_delete(&self, ctx: Context, guild: PartialGuild, _: Option<Guild>) {
+//!         self.guild_count.guild_delete(&ctx, guild.id);
+//!     }
+//! }
+//! ```
+//!
+//! [`GuildCountHandler`]: trait.GuildCountHandler.html
+//! [`GuildCountPublisher`]: struct.GuildCountPublisher.html
+//! [`Ready`]: ../../../model/gateway/struct.Ready.html
+
+use crate::client::Context;
+use crate::model::prelude::*;
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::{Arc, Weak},
+    time::{Duration, Instant},
+};
+use tokio::time::sleep;
+
+/// Receives the guild counts published by a [`GuildCountPublisher`].
+///
+/// [`GuildCountPublisher`]: struct.GuildCountPublisher.html
+#[async_trait]
+pub trait GuildCountHandler: Send + Sync + 'static {
+    /// Called with the current guild counts, when they changed since they
+    /// were last published.
+    async fn publish(&self, counts: &GuildCounts);
+}
+
+/// The number of guilds on each shard of the current process.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct GuildCounts {
+    /// The total number of shards the bot uses, including ones run by other
+    /// processes.
+    pub shard_count: u64,
+    /// The number of guilds on each shard which became ready, by shard Id.
+    pub shards: BTreeMap<u64, usize>,
+}
+
+impl GuildCounts {
+    /// The number of guilds on all shards of the current process.
+    pub fn total(&self) -> usize {
+        self.shards.values().sum()
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    shard_count: u64,
+    shards: HashMap<u64, HashSet<GuildId>>,
+    changed_at: Option<Instant>,
+    published_at: Option<Instant>,
+    running: bool,
+}
+
+impl State {
+    fn changed(&mut self) {
+        self.changed_at = Some(Instant::now());
+    }
+
+    fn counts(&self) -> GuildCounts {
+        GuildCounts {
+            shard_count: self.shard_count,
+            shards: self
+                .shards
+                .iter()
+                .map(|(shard_id, guilds)| (*shard_id, guilds.len()))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Inner<H> {
+    handler: H,
+    interval: Duration,
+    debounce: Duration,
+    state: Mutex<State>,
+}
+
+/// Publishes the number of guilds the bot is in.
+///
+/// Refer to the [module-level documentation] for more information.
+///
+/// [module-level documentation]: index.html
+#[derive(Debug)]
+pub struct GuildCountPublisher<H> {
+    inner: Arc<Inner<H>>,
+}
+
+impl<H: GuildCountHandler> GuildCountPublisher<H> {
+    /// Creates a new `GuildCountPublisher`, passing the counts to the given
+    /// handler.
+    ///
+    /// By default, counts are published at most once every 30 minutes, and
+    /// only once they did not change for 30 seconds.
+    pub fn new(handler: H) -> Self {
+        Self::with_timings(
+            handler,
+            Duration::from_secs(30 * 60),
+            Duration::from_secs(30),
+        )
+    }
+
+    /// Creates a new `GuildCountPublisher` which publishes counts at most
+    /// once per `interval`, and only once they did not change for `debounce`.
+    ///
+    /// The `debounce` should be longer than the time between two shards
+    /// starting, which is 5 seconds, so that counts are not published while
+    /// shards are still starting up.
+    pub fn with_timings(handler: H, interval: Duration, debounce: Duration) -> Self {
+        GuildCountPublisher {
+            inner: Arc::new(Inner {
+                handler,
+                interval,
+                debounce,
+                state: Mutex::new(State {
+                    shard_count: 0,
+                    shards: HashMap::new(),
+                    changed_at: None,
+                    published_at: None,
+                    running: false,
+                }),
+            }),
+        }
+    }
+
+    /// Returns a reference to the handler.
+    pub fn handler(&self) -> &H {
+        &self.inner.handler
+    }
+
+    /// Returns the current guild counts, whether or not they were published.
+    pub fn counts(&self) -> GuildCounts {
+        self.inner.state.lock().counts()
+    }
+
+    /// Records the guilds of a shard which became ready, replacing any
+    /// previously known guilds of the shard.
+    ///
+    /// This should be called from [`EventHandler::ready`].
+    ///
+    /// [`EventHandler::ready`]: ../../trait.EventHandler.html#method.ready
+    pub fn ready(&self, ready: &Ready) {
+        let [shard_id, shard_count] = ready.shard.unwrap_or([0, 1]);
+        let guilds = ready.guilds.iter().map(GuildStatus::id).collect();
+
+        let mut state = self.inner.state.lock();
+        state.shard_count = shard_count;
+        state.shards.insert(shard_id, guilds);
+        state.changed();
+
+        self.start(&mut state);
+    }
+
+    /// Records a guild becoming available or being joined.
+    ///
+    /// This should be called from [`EventHandler::guild_create`].
+    ///
+    /// [`EventHandler::guild_create`]: ../../trait.EventHandler.html#method.guild_create
+    pub fn guild_create(&self, ctx: &Context, guild_id: GuildId) {
+        self.insert(ctx.shard_id, guild_id);
+    }
+
+    /// Records a guild being left.
+    ///
+    /// This should be called from [`EventHandler::guild_delete`].
+    ///
+    /// **Note**: Guilds which became unavailable due to an outage are also
+    /// removed, until they become available again.
+    ///
+    /// [`EventHandler::guild_delete`]: ../../trait.EventHandler.html#method.guild_delete
+    pub fn guild_delete(&self, ctx: &Context, guild_id: GuildId) {
+        self.remove(ctx.shard_id, guild_id);
+    }
+
+    fn insert(&self, shard_id: u64, guild_id: GuildId) {
+        let mut state = self.inner.state.lock();
+
+        if state.shards.entry(shard_id).or_default().insert(guild_id) {
+            state.changed();
+            self.start(&mut state);
+        }
+    }
+
+    fn remove(&self, shard_id: u64, guild_id: GuildId) {
+        let mut state = self.inner.state.lock();
+        let removed = state
+            .shards
+            .get_mut(&shard_id)
+            .is_some_and(|guilds| guilds.remove(&guild_id));
+
+        if removed {
+            state.changed();
+            self.start(&mut state);
+        }
+    }
+
+    // Spawns the task publishing the counts, if it is not running yet. The
+    // task stops once the publisher is dropped.
+    fn start(&self, state: &mut State) {
+        if state.running {
+            return;
+        }
+
+        state.running = true;
+
+        let inner = Arc::downgrade(&self.inner);
+        let tick = self.inner.debounce.min(self.inner.interval);
+
+        tokio::spawn(async move {
+            loop {
+                sleep(tick).await;
+
+                if publish(&inner).await.is_none() {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+/// Publishes the counts if they settled and the interval passed, returning
+/// `None` if the publisher was dropped.
+async fn publish<H: GuildCountHandler>(inner: &Weak<Inner<H>>) -> Option<()> {
+    let inner = inner.upgrade()?;
+
+    let counts = {
+        let mut state = inner.state.lock();
+
+        let settled = match state.changed_at {
+            Some(changed_at) => changed_at.elapsed() >= inner.debounce,
+            None => false,
+        };
+        let due = state
+            .published_at
+            .is_none_or(|published_at| published_at.elapsed() >= inner.interval);
+
+        if !settled || !due {
+            return Some(());
+        }
+
+        state.changed_at = None;
+        state.published_at = Some(Instant::now());
+        state.counts()
+    };
+
+    inner.handler.publish(&counts).await;
+
+    Some(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GuildCountHandler, GuildCountPublisher, GuildCounts};
+    use crate::model::prelude::*;
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+    use serde_json::json;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::time::sleep;
+
+    #[derive(Default)]
+    struct Collector(Arc<Mutex<Vec<GuildCounts>>>);
+
+    #[async_trait]
+    impl GuildCountHandler for Collector {
+        async fn publish(&self, counts: &GuildCounts) {
+            self.0.lock().push(counts.clone());
+        }
+    }
+
+    fn ready(shard_id: u64, guilds: &[u64]) -> Ready {
+        let guilds = guilds
+            .iter()
+            .map(|id| json!({"id": id.to_string(), "unavailable": true}))
+            .collect::<Vec<_>>();
+
+        serde_json::from_value(json!({
+            "guilds": guilds,
+            "presences": [],
+            "private_channels": [],
+            "session_id": "session",
+            "shard": [shard_id, 2],
+            "_trace": [],
+            "user": {
+                "id": "1",
+                "username": "bot",
+                "discriminator": "0001",
+                "avatar": null,
+                "bot": true,
+                "email": null,
+                "mfa_enabled": false,
+                "verified": true,
+            },
+            "v": 8,
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_guild_count_publisher() {
+        let published = Arc::new(Mutex::new(Vec::new()));
+        let publisher = GuildCountPublisher::with_timings(
+            Collector(Arc::clone(&published)),
+            Duration::from_millis(0),
+            Duration::from_millis(50),
+        );
+
+        publisher.ready(&ready(0, &[1, 2]));
+        publisher.ready(&ready(1, &[3]));
+        // Guilds streamed in after becoming ready are already counted.
+        publisher.insert(0, GuildId(1));
+        publisher.insert(1, GuildId(3));
+        assert_eq!(publisher.counts().total(), 3);
+
+        sleep(Duration::from_millis(200)).await;
+
+        {
+            let published = published.lock();
+            assert_eq!(published.len(), 1);
+            assert_eq!(published[0].shard_count, 2);
+            assert_eq!(published[0].shards[&0], 2);
+            assert_eq!(published[0].shards[&1], 1);
+        }
+
+        publisher.insert(1, GuildId(4));
+        publisher.remove(0, GuildId(1));
+        publisher.remove(0, GuildId(1));
+
+        sleep(Duration::from_millis(200)).await;
+
+        let published = published.lock();
+        assert_eq!(published.len(), 2);
+        assert_eq!(published[1].total(), 3);
+        assert_eq!(published[1].shards[&1], 2);
+    }
+}
@@ -0,0 +1,259 @@
+//! Best-effort attribution of members joining a guild to the invite they used.
+//!
+//! Discord does not report which invite a member joined through. An
+//! [`InviteTracker`] works around this by keeping a snapshot of each guild's
+//! invites and, when a member joins, comparing it against the current invites:
+//! the invite whose use count went up is the one that was used.
+//!
+//! This is only a best guess. If several members join at nearly the same time,
+//! or an invite expires as a member joins, the invite used can not be
+//! determined and [`InviteTrackerHandler::member_join`] is given `None`.
+//!
+//! Retrieving a guild's invites requires the [Manage Guild] permission.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use serenity::client::bridge::invite_tracker::{InviteTracker, InviteTrackerHandler};
+//! use serenity::model::prelude::*;
+//! use serenity::prelude::*;
+//! use async_trait::async_trait;
+//!
+//! struct Logger;
+//!
+//! #[async_trait]
+//! impl InviteTrackerHandler for Logger {
+//!     async fn member_join(&self, _: &Context, member: &Member, invite: Option<&RichInvite>) {
+//!         match invite {
+//!             Some(invite) => println!("{} joined using {}", member.user.name, invite.code),
+//!             None => println!("{} joined using an unknown invite", member.user.name),
+//!         }
+//!     }
+//! }
+//!
+//! struct Handler {
+//!     invites: InviteTracker<Logger>,
+//! }
+//!
+//! #[async_trait]
+//! impl EventHandler for Handler {
+//!     async fn guild_create(&self, ctx: Context, guild: Guild, _: bool) {
+//!         if let Err(why) = self.invites.snapshot(&ctx, guild.id).await {
+//!             println!("Failed to snapshot invites: {:?}", why);
+//!         }
+//!     }
+//!
+//!     async fn guild_member_addition(&self, ctx: Context, _: GuildId, member: Member) {
+//!         if let Err(why) = self.invites.member_join(&ctx, &member).await {
+//!             println!("Failed to track invite: {:?}", why);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`InviteTracker`]: struct.InviteTracker.html
+//! [`InviteTrackerHandler::member_join`]: trait.InviteTrackerHandler.html#tymethod.member_join
+//! [Manage Guild]: ../../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+
+use crate::client::Context;
+use crate::http::Http;
+use crate::internal::prelude::*;
+use crate::internal::AsyncRwLock;
+use crate::model::prelude::*;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// Receives the results of an [`InviteTracker`].
+///
+/// [`InviteTracker`]: struct.InviteTracker.html
+#[async_trait]
+pub trait InviteTrackerHandler: Send + Sync {
+    /// Called when a member joined a guild, with the invite they most likely
+    /// used, if it could be determined.
+    ///
+    /// If the invite was used up and deleted by the member joining, it is
+    /// given as it was last seen, before the member used it.
+    async fn member_join(&self, ctx: &Context, member: &Member, invite: Option<&RichInvite>);
+}
+
+/// Attributes members joining a guild to the invite they used.
+///
+/// Refer to the [module-level documentation] for more information.
+///
+/// [module-level documentation]: index.html
+#[derive(Debug)]
+pub struct InviteTracker<H> {
+    handler: H,
+    invites: AsyncRwLock<HashMap<GuildId, HashMap<String, RichInvite>>>,
+}
+
+impl<H: InviteTrackerHandler> InviteTracker<H> {
+    /// Creates a new `InviteTracker`, passing its results to the given
+    /// handler.
+    pub fn new(handler: H) -> Self {
+        InviteTracker {
+            handler,
+            invites: AsyncRwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Returns a reference to the handler.
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    /// Retrieves the guild's current invites, replacing its snapshot.
+    ///
+    /// This should be called when a guild becomes available, and whenever
+    /// invites may have changed without a member joining.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks the [Manage Guild]
+    /// permission.
+    ///
+    /// [`Error::Http`]: ../../../enum.Error.html#variant.Http
+    /// [Manage Guild]: ../../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn snapshot(
+        &self,
+        http: impl AsRef<Http>,
+        guild_id: impl Into<GuildId>,
+    ) -> Result<()> {
+        let guild_id = guild_id.into();
+        let current = fetch(http.as_ref(), guild_id).await?;
+
+        self.invites.write().await.insert(guild_id, current);
+
+        Ok(())
+    }
+
+    /// Removes the snapshot of a guild's invites, such as when the current
+    /// user left it.
+    pub async fn forget(&self, guild_id: impl Into<GuildId>) {
+        self.invites.write().await.remove(&guild_id.into());
+    }
+
+    /// Determines the invite a member joined with and passes it to the
+    /// handler, updating the guild's snapshot.
+    ///
+    /// If no snapshot of the guild was taken yet, the invite can not be
+    /// determined.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`snapshot`], in which case the handler is
+    /// not called.
+    ///
+    /// [`snapshot`]: #method.snapshot
+    pub async fn member_join(&self, ctx: &Context, member: &Member) -> Result<()> {
+        // Hold the lock while fetching so that joins are compared in order.
+        let mut invites = self.invites.write().await;
+        let current = fetch(&ctx.http, member.guild_id).await?;
+
+        let previous = invites.insert(member.guild_id, current);
+        let used = previous
+            .as_ref()
+            .and_then(|previous| used_invite(previous, &invites[&member.guild_id]))
+            .cloned();
+
+        drop(invites);
+
+        self.handler.member_join(ctx, member, used.as_ref()).await;
+
+        Ok(())
+    }
+}
+
+async fn fetch(http: &Http, guild_id: GuildId) -> Result<HashMap<String, RichInvite>> {
+    let invites = http.get_guild_invites(guild_id.0).await?;
+
+    Ok(invites
+        .into_iter()
+        .map(|invite| (invite.code.clone(), invite))
+        .collect())
+}
+
+/// Finds the invite used between two snapshots, if exactly one was.
+///
+/// An invite was used if its use count went up, or if it disappeared with one
+/// use left.
+fn used_invite<'a>(
+    previous: &'a HashMap<String, RichInvite>,
+    current: &'a HashMap<String, RichInvite>,
+) -> Option<&'a RichInvite> {
+    let increased = current.values().filter(|invite| {
+        let uses = previous
+            .get(&invite.code)
+            .map_or(0, |previous| previous.uses);
+
+        invite.uses > uses
+    });
+
+    let used_up = previous.values().filter(|invite| {
+        !current.contains_key(&invite.code)
+            && invite.max_uses != 0
+            && invite.uses + 1 == invite.max_uses
+    });
+
+    let mut candidates = increased.chain(used_up);
+
+    match (candidates.next(), candidates.next()) {
+        (Some(invite), None) => Some(invite),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::used_invite;
+    use crate::model::prelude::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn invite(code: &str, uses: u64, max_uses: u64) -> RichInvite {
+        serde_json::from_value(json!({
+            "channel": {"id": "1", "name": "general", "type": 0},
+            "code": code,
+            "created_at": "2021-01-01T00:00:00+00:00",
+            "guild": null,
+            "inviter": {"id": "2", "username": "user", "discriminator": "0001", "avatar": null},
+            "max_age": 0,
+            "max_uses": max_uses,
+            "temporary": false,
+            "uses": uses,
+        }))
+        .unwrap()
+    }
+
+    fn invites(invites: Vec<RichInvite>) -> HashMap<String, RichInvite> {
+        invites
+            .into_iter()
+            .map(|invite| (invite.code.clone(), invite))
+            .collect()
+    }
+
+    #[test]
+    fn test_used_invite() {
+        let previous = invites(vec![invite("a", 1, 0), invite("b", 4, 5)]);
+
+        let current = invites(vec![invite("a", 2, 0), invite("b", 4, 5)]);
+        assert_eq!(used_invite(&previous, &current).unwrap().code, "a");
+
+        // A new invite used right after being created.
+        let current = invites(vec![
+            invite("a", 1, 0),
+            invite("b", 4, 5),
+            invite("c", 1, 0),
+        ]);
+        assert_eq!(used_invite(&previous, &current).unwrap().code, "c");
+
+        // An invite with a single use left was used up.
+        let current = invites(vec![invite("a", 1, 0)]);
+        assert_eq!(used_invite(&previous, &current).unwrap().code, "b");
+
+        // Several invites were used, or none was.
+        let current = invites(vec![invite("a", 2, 0), invite("b", 5, 5)]);
+        assert!(used_invite(&previous, &current).is_none());
+        assert!(used_invite(&previous, &previous).is_none());
+    }
+}
@@ -7,7 +7,12 @@
 //! [`Client`]: ../struct.Client.html
 //! [`client`]: ../
 
+pub mod auto_role;
 pub mod gateway;
+pub mod guild_count;
+pub mod invite_tracker;
+#[cfg(all(feature = "cache", feature = "model"))]
+pub mod starboard;
 
 #[cfg(feature = "voice")]
 pub mod voice;
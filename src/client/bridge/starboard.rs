@@ -0,0 +1,553 @@
+//! A starboard, reposting messages which received enough of a reaction to a
+//! dedicated channel.
+//!
+//! A [`Starboard`] holds the settings of each guild, and which messages it
+//! posted to the starboard, persisted through a [`StarboardStorage`]
+//! implementation. It is driven by calling its methods from the reaction and
+//! message deletion handlers of your [`EventHandler`].
+//!
+//! Once a message has received as many of the configured reaction as the
+//! threshold, it is posted to the starboard channel. The post is updated with
+//! the number of reactions as they change, and deleted when the number drops
+//! below the threshold or the message is deleted.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use serenity::client::bridge::starboard::{InMemoryStarboardStorage, Starboard};
+//! use serenity::model::prelude::*;
+//! use serenity::prelude::*;
+//! use async_trait::async_trait;
+//!
+//! struct Handler {
+//!     starboard: Starboard<InMemoryStarboardStorage>,
+//! }
+//!
+//! #[async_trait]
+//! impl EventHandler for Handler {
+//!     async fn reaction_add(&self, ctx: Context, reaction: Reaction) {
+//!         if let Err(why) = self.starboard.reaction_add(&ctx, &reaction).await {
+//!             println!("Failed to update the starboard: {:?}", why);
+//!         }
+//!     }
+//!
+//!     async fn reaction_remove(&self, ctx: Context, reaction: Reaction) {
+//!         if let Err(why) = self.starboard.reaction_remove(&ctx, &reaction).await {
+//!             println!("Failed to update the starboard: {:?}", why);
+//!         }
+//!     }
+//!
+//!     async fn reaction_remove_all(&self, ctx: Context, _: ChannelId, message_id: MessageId) {
+//!         if let Err(why) = self.starboard.message_delete(&ctx, message_id).await {
+//!             println!("Failed to update the starboard: {:?}", why);
+//!         }
+//!     }
+//!
+//!     async fn message_delete(&self, ctx: Context, _: ChannelId, message_id: MessageId) {
+//!         if let Err(why) = self.starboard.message_delete(&ctx, message_id).await {
+//!             println!("Failed to update the starboard: {:?}", why);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`EventHandler`]: ../../trait.EventHandler.html
+//! [`Starboard`]: struct.Starboard.html
+//! [`StarboardStorage`]: trait.StarboardStorage.html
+
+use crate::builder::CreateEmbed;
+use crate::http::{CacheHttp, HttpError};
+use crate::internal::prelude::*;
+use crate::internal::AsyncRwLock;
+use crate::model::prelude::*;
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use reqwest::StatusCode;
+use std::collections::HashMap;
+
+/// The starboard settings of a guild.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct StarboardConfig {
+    /// The channel messages are posted to.
+    pub channel_id: ChannelId,
+    /// The reaction which stars a message.
+    pub emoji: ReactionType,
+    /// The number of reactions a message needs to be posted.
+    pub threshold: u64,
+}
+
+impl StarboardConfig {
+    /// Creates settings posting messages to the channel once they received
+    /// `threshold` of the `emoji` reaction.
+    pub fn new(
+        channel_id: impl Into<ChannelId>,
+        emoji: impl Into<ReactionType>,
+        threshold: u64,
+    ) -> Self {
+        StarboardConfig {
+            channel_id: channel_id.into(),
+            emoji: emoji.into(),
+            threshold: threshold.max(1),
+        }
+    }
+}
+
+/// A message posted to the starboard.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct StarboardEntry {
+    /// The channel the post was sent in.
+    pub channel_id: ChannelId,
+    /// The Id of the post.
+    pub message_id: MessageId,
+}
+
+/// Persistence for the settings of a [`Starboard`] and the posts it made.
+///
+/// Implement this to back the starboard with a database or file; for simple
+/// use cases, [`InMemoryStarboardStorage`] is provided.
+///
+/// [`InMemoryStarboardStorage`]: struct.InMemoryStarboardStorage.html
+/// [`Starboard`]: struct.Starboard.html
+#[async_trait]
+pub trait StarboardStorage: Send + Sync {
+    /// Loads the starboard settings of the guild, if it has a starboard.
+    async fn load_config(&self, guild_id: GuildId) -> Result<Option<StarboardConfig>>;
+
+    /// Stores the starboard settings of the guild. `None` removes the
+    /// starboard of the guild.
+    async fn store_config(&self, guild_id: GuildId, config: Option<StarboardConfig>) -> Result<()>;
+
+    /// Loads the post made for a message, if it was posted to the starboard.
+    async fn load_entry(&self, message_id: MessageId) -> Result<Option<StarboardEntry>>;
+
+    /// Stores the post made for a message. `None` forgets the post.
+    async fn store_entry(&self, message_id: MessageId, entry: Option<StarboardEntry>)
+        -> Result<()>;
+}
+
+/// A [`StarboardStorage`] which keeps everything in memory, losing it on
+/// restart.
+///
+/// [`StarboardStorage`]: trait.StarboardStorage.html
+#[derive(Debug, Default)]
+pub struct InMemoryStarboardStorage {
+    configs: AsyncRwLock<HashMap<GuildId, StarboardConfig>>,
+    entries: AsyncRwLock<HashMap<MessageId, StarboardEntry>>,
+}
+
+#[async_trait]
+impl StarboardStorage for InMemoryStarboardStorage {
+    async fn load_config(&self, guild_id: GuildId) -> Result<Option<StarboardConfig>> {
+        Ok(self.configs.read().await.get(&guild_id).cloned())
+    }
+
+    async fn store_config(&self, guild_id: GuildId, config: Option<StarboardConfig>) -> Result<()> {
+        match config {
+            Some(config) => self.configs.write().await.insert(guild_id, config),
+            None => self.configs.write().await.remove(&guild_id),
+        };
+
+        Ok(())
+    }
+
+    async fn load_entry(&self, message_id: MessageId) -> Result<Option<StarboardEntry>> {
+        Ok(self.entries.read().await.get(&message_id).copied())
+    }
+
+    async fn store_entry(
+        &self,
+        message_id: MessageId,
+        entry: Option<StarboardEntry>,
+    ) -> Result<()> {
+        match entry {
+            Some(entry) => self.entries.write().await.insert(message_id, entry),
+            None => self.entries.write().await.remove(&message_id),
+        };
+
+        Ok(())
+    }
+}
+
+/// Reposts messages which received enough of a reaction to a starboard
+/// channel.
+///
+/// Refer to the [module-level documentation] for an example.
+///
+/// [module-level documentation]: index.html
+#[derive(Debug)]
+pub struct Starboard<S> {
+    storage: S,
+    updating: Mutex<()>,
+}
+
+impl<S: StarboardStorage> Starboard<S> {
+    /// Creates a new `Starboard` persisting its settings and posts in the
+    /// given storage.
+    pub fn new(storage: S) -> Self {
+        Starboard {
+            storage,
+            updating: Mutex::new(()),
+        }
+    }
+
+    /// Returns a reference to the underlying storage.
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Retrieves the starboard settings of the guild.
+    pub async fn config(&self, guild_id: impl Into<GuildId>) -> Result<Option<StarboardConfig>> {
+        self.storage.load_config(guild_id.into()).await
+    }
+
+    /// Sets the starboard settings of the guild. Passing `None` disables the
+    /// starboard of the guild.
+    ///
+    /// Messages already posted to the starboard are left as they are.
+    pub async fn set_config(
+        &self,
+        guild_id: impl Into<GuildId>,
+        config: Option<StarboardConfig>,
+    ) -> Result<()> {
+        self.storage.store_config(guild_id.into(), config).await
+    }
+
+    /// Updates the starboard after a reaction was added to a message.
+    #[inline]
+    pub async fn reaction_add(
+        &self,
+        cache_http: impl CacheHttp,
+        reaction: &Reaction,
+    ) -> Result<()> {
+        self.reaction(cache_http, reaction).await
+    }
+
+    /// Updates the starboard after a reaction was removed from a message.
+    #[inline]
+    pub async fn reaction_remove(
+        &self,
+        cache_http: impl CacheHttp,
+        reaction: &Reaction,
+    ) -> Result<()> {
+        self.reaction(cache_http, reaction).await
+    }
+
+    /// Removes the post of a message from the starboard, such as when the
+    /// message was deleted or all of its reactions were removed.
+    pub async fn message_delete(
+        &self,
+        cache_http: impl CacheHttp,
+        message_id: impl Into<MessageId>,
+    ) -> Result<()> {
+        let message_id = message_id.into();
+        let _updating = self.updating.lock().await;
+
+        match self.storage.load_entry(message_id).await? {
+            Some(entry) => self.remove(cache_http.http(), message_id, entry).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Posts, updates or removes the post of a message, according to the
+    /// current number of reactions it has.
+    ///
+    /// This is called by the reaction handlers, and only needs to be called
+    /// directly to update a message after events were missed.
+    pub async fn update(&self, cache_http: impl CacheHttp, message: &Message) -> Result<()> {
+        let guild_id = match message.guild_id {
+            Some(guild_id) => guild_id,
+            None => return Ok(()),
+        };
+
+        let config = match self.storage.load_config(guild_id).await? {
+            Some(config) if config.channel_id != message.channel_id => config,
+            _ => return Ok(()),
+        };
+
+        let _updating = self.updating.lock().await;
+        let http = cache_http.http();
+        let count = reaction_count(message, &config.emoji);
+        let entry = self.storage.load_entry(message.id).await?;
+
+        if count < config.threshold {
+            return match entry {
+                Some(entry) => self.remove(http, message.id, entry).await,
+                None => Ok(()),
+            };
+        }
+
+        let content = post_content(count, &config.emoji, message.channel_id);
+
+        if let Some(entry) = entry {
+            let edited = entry
+                .channel_id
+                .edit_message(http, entry.message_id, |m| {
+                    m.content(&content)
+                        .embed(|e| post_embed(e, guild_id, message))
+                })
+                .await;
+
+            match edited {
+                Err(ref why) if is_not_found(why) => {}
+                other => return other.map(|_| ()),
+            }
+        }
+
+        let post = config
+            .channel_id
+            .send_message(http, |m| {
+                m.content(content)
+                    .embed(|e| post_embed(e, guild_id, message))
+                    .allowed_mentions(|am| am.empty_parse())
+            })
+            .await?;
+
+        let entry = StarboardEntry {
+            channel_id: post.channel_id,
+            message_id: post.id,
+        };
+
+        self.storage.store_entry(message.id, Some(entry)).await
+    }
+
+    async fn reaction(&self, cache_http: impl CacheHttp, reaction: &Reaction) -> Result<()> {
+        let guild_id = match reaction.guild_id {
+            Some(guild_id) => guild_id,
+            None => return Ok(()),
+        };
+
+        match self.storage.load_config(guild_id).await? {
+            Some(config) if same_emoji(&config.emoji, &reaction.emoji) => {}
+            _ => return Ok(()),
+        }
+
+        let mut message = reaction.message(cache_http.http()).await?;
+        message.guild_id = Some(guild_id);
+
+        self.update(cache_http, &message).await
+    }
+
+    async fn remove(
+        &self,
+        http: &crate::http::Http,
+        message_id: MessageId,
+        entry: StarboardEntry,
+    ) -> Result<()> {
+        let deleted = entry
+            .channel_id
+            .delete_message(http, entry.message_id)
+            .await;
+
+        match deleted {
+            Err(ref why) if is_not_found(why) => {}
+            other => other?,
+        }
+
+        self.storage.store_entry(message_id, None).await
+    }
+}
+
+impl Default for Starboard<InMemoryStarboardStorage> {
+    fn default() -> Self {
+        Starboard::new(InMemoryStarboardStorage::default())
+    }
+}
+
+/// Compares emojis by Id for custom emojis, as their name and whether they are
+/// animated are not always sent.
+fn same_emoji(a: &ReactionType, b: &ReactionType) -> bool {
+    match (a, b) {
+        (ReactionType::Custom { id: a, .. }, ReactionType::Custom { id: b, .. }) => a == b,
+        (ReactionType::Unicode(a), ReactionType::Unicode(b)) => a == b,
+        _ => false,
+    }
+}
+
+fn reaction_count(message: &Message, emoji: &ReactionType) -> u64 {
+    message
+        .reactions
+        .iter()
+        .find(|reaction| same_emoji(&reaction.reaction_type, emoji))
+        .map_or(0, |reaction| reaction.count)
+}
+
+fn post_content(count: u64, emoji: &ReactionType, channel_id: ChannelId) -> String {
+    format!("{} **{}** {}", emoji, count, channel_id.mention())
+}
+
+fn post_embed<'a>(
+    embed: &'a mut CreateEmbed,
+    guild_id: GuildId,
+    message: &Message,
+) -> &'a mut CreateEmbed {
+    embed
+        .author(|a| a.name(message.author.tag()).icon_url(message.author.face()))
+        .colour(0xFFAC33)
+        .timestamp(message.timestamp)
+        .field(
+            "Source",
+            format!(
+                "[Jump to message](https://discord.com/channels/{}/{}/{})",
+                guild_id, message.channel_id, message.id
+            ),
+            false,
+        );
+
+    if !message.content.is_empty() {
+        embed.description(&message.content);
+    }
+
+    let image = message
+        .attachments
+        .iter()
+        .find(|attachment| attachment.dimensions().is_some());
+
+    if let Some(image) = image {
+        embed.image(&image.url);
+    }
+
+    embed
+}
+
+fn is_not_found(error: &Error) -> bool {
+    match error {
+        Error::Http(why) => match &**why {
+            HttpError::UnsuccessfulRequest(response) => {
+                response.status_code == StatusCode::NOT_FOUND
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{InMemoryStarboardStorage, Starboard, StarboardConfig};
+    use crate::http::mock::{MockResponse, MockServer};
+    use crate::model::prelude::*;
+    use serde_json::{json, Value};
+
+    fn message(stars: u64) -> Value {
+        json!({
+            "id": "3",
+            "channel_id": "2",
+            "guild_id": "1",
+            "author": {"id": "4", "username": "a", "discriminator": "0001", "avatar": null},
+            "content": "starworthy",
+            "timestamp": "2021-01-01T00:00:00+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+            "reactions": [
+                {"count": 9, "me": false, "emoji": {"id": null, "name": "👍"}},
+                {"count": stars, "me": false, "emoji": {"id": null, "name": "⭐"}},
+            ],
+        })
+    }
+
+    fn post(id: u64) -> Value {
+        let mut post = message(0);
+        post["id"] = json!(id.to_string());
+        post["channel_id"] = json!("10");
+
+        post
+    }
+
+    fn reaction(emoji: &str) -> Reaction {
+        serde_json::from_value(json!({
+            "channel_id": "2",
+            "message_id": "3",
+            "user_id": "5",
+            "guild_id": "1",
+            "emoji": {"id": null, "name": emoji},
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_starboard() {
+        let server = MockServer::start().await;
+        let http = server.http("Bot token");
+        let starboard = Starboard::new(InMemoryStarboardStorage::default());
+
+        starboard
+            .set_config(1, Some(StarboardConfig::new(10, '⭐', 2)))
+            .await
+            .unwrap();
+
+        // Other reactions and messages below the threshold are ignored.
+        starboard
+            .reaction_add(&http, &reaction("👍"))
+            .await
+            .unwrap();
+        server.enqueue(MockResponse::json(200, &message(1)));
+        starboard
+            .reaction_add(&http, &reaction("⭐"))
+            .await
+            .unwrap();
+        assert_eq!(server.requests().len(), 1);
+
+        server.enqueue(MockResponse::json(200, &message(2)));
+        server.enqueue(MockResponse::json(200, &post(11)));
+        starboard
+            .reaction_add(&http, &reaction("⭐"))
+            .await
+            .unwrap();
+
+        server.enqueue(MockResponse::json(200, &message(3)));
+        server.enqueue(MockResponse::json(200, &post(11)));
+        starboard
+            .reaction_add(&http, &reaction("⭐"))
+            .await
+            .unwrap();
+
+        server.enqueue(MockResponse::json(200, &message(1)));
+        server.enqueue(MockResponse::new(204));
+        starboard
+            .reaction_remove(&http, &reaction("⭐"))
+            .await
+            .unwrap();
+
+        // Nothing is left to delete once the post was removed.
+        starboard.message_delete(&http, 3).await.unwrap();
+
+        let requests = server.requests();
+        let methods = requests
+            .iter()
+            .map(|request| (request.method.as_str(), request.path.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            methods,
+            [
+                ("GET", "/channels/2/messages/3"),
+                ("GET", "/channels/2/messages/3"),
+                ("POST", "/channels/10/messages"),
+                ("GET", "/channels/2/messages/3"),
+                ("PATCH", "/channels/10/messages/11"),
+                ("GET", "/channels/2/messages/3"),
+                ("DELETE", "/channels/10/messages/11"),
+            ]
+        );
+
+        let posted: Value = serde_json::from_slice(&requests[2].body).unwrap();
+        assert_eq!(posted["content"], "⭐ **2** <#2>");
+        assert_eq!(posted["embed"]["description"], "starworthy");
+        assert_eq!(
+            posted["embed"]["fields"][0]["value"],
+            "[Jump to message](https://discord.com/channels/1/2/3)"
+        );
+
+        let edited: Value = serde_json::from_slice(&requests[4].body).unwrap();
+        assert_eq!(edited["content"], "⭐ **3** <#2>");
+    }
+}
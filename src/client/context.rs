@@ -1,14 +1,16 @@
-use crate::client::bridge::gateway::ShardMessenger;
+use crate::client::bridge::gateway::{ShardId, ShardMessenger, ShardRunnerInfo};
+use crate::constants;
 use crate::gateway::InterMessage;
 use crate::internal::AsyncRwLock;
 use crate::model::prelude::*;
+use dashmap::DashMap;
 use std::sync::Arc;
 use typemap::ShareMap;
 
 use crate::http::Http;
 
 #[cfg(feature = "cache")]
-pub use crate::cache::{Cache, CacheRwLock};
+pub use crate::cache::Cache;
 
 use futures::channel::mpsc::UnboundedSender;
 
@@ -40,7 +42,11 @@ pub struct Context {
     pub shard_id: u64,
     pub http: Arc<Http>,
     #[cfg(feature = "cache")]
-    pub cache: CacheRwLock,
+    pub cache: Arc<Cache>,
+    /// The runners of all shards started by the client.
+    runners: Arc<DashMap<ShardId, ShardRunnerInfo>>,
+    /// The total number of shards the bot uses.
+    shard_total: u64,
 }
 
 impl Context {
@@ -49,16 +55,19 @@ impl Context {
     pub(crate) fn new(
         data: Arc<AsyncRwLock<ShareMap>>,
         runner_tx: UnboundedSender<InterMessage>,
-        shard_id: u64,
+        runners: Arc<DashMap<ShardId, ShardRunnerInfo>>,
+        shard_info: [u64; 2],
         http: Arc<Http>,
-        cache: Arc<AsyncRwLock<Cache>>,
+        cache: Arc<Cache>,
     ) -> Context {
         Context {
             shard: ShardMessenger::new(runner_tx),
-            shard_id,
+            shard_id: shard_info[0],
             data,
             http,
-            cache: cache.into(),
+            cache,
+            runners,
+            shard_total: shard_info[1],
         }
     }
 
@@ -67,14 +76,17 @@ impl Context {
     pub(crate) fn new(
         data: Arc<AsyncRwLock<ShareMap>>,
         runner_tx: UnboundedSender<InterMessage>,
-        shard_id: u64,
+        runners: Arc<DashMap<ShardId, ShardRunnerInfo>>,
+        shard_info: [u64; 2],
         http: Arc<Http>,
     ) -> Context {
         Context {
             shard: ShardMessenger::new(runner_tx),
-            shard_id,
+            shard_id: shard_info[0],
             data,
             http,
+            runners,
+            shard_total: shard_info[1],
         }
     }
 
@@ -384,6 +396,141 @@ impl Context {
     pub async fn set_presence(&mut self, activity: Option<Activity>, status: OnlineStatus) {
         self.shard.set_presence(activity, status).await;
     }
+
+    /// Sets the current user's presence on every shard started by the
+    /// client, rather than only the shard the context belongs to.
+    ///
+    /// # Examples
+    ///
+    /// Set the bot as under maintenance on all shards:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::channel::Message;
+    /// #
+    /// use async_trait::async_trait;
+    /// use serenity::model::gateway::Activity;
+    /// use serenity::model::user::OnlineStatus;
+    ///
+    /// struct Handler;
+    ///
+    /// #[async_trait]
+    /// impl EventHandler for Handler {
+    ///     async fn message(&self, ctx: Context, msg: Message) {
+    ///         if msg.content == "!maintenance" {
+    ///             let activity = Activity::playing("under maintenance");
+    ///
+    ///             ctx.set_presence_for_shards(Some(activity), OnlineStatus::DoNotDisturb).await;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn set_presence_for_shards(&self, activity: Option<Activity>, status: OnlineStatus) {
+        // Collect the senders first, to not hold the map's locks while
+        // sending.
+        let senders = self
+            .runners
+            .iter()
+            .map(|runner| runner.runner_tx.clone())
+            .collect::<Vec<_>>();
+
+        for runner_tx in senders {
+            ShardMessenger::new(runner_tx)
+                .set_presence(activity.clone(), status)
+                .await;
+        }
+    }
+
+    /// Requests the members of a guild over the shard which receives the
+    /// guild's events, which may differ from the shard the context belongs
+    /// to.
+    ///
+    /// The members are received as [`GuildMembersChunk`] events. Refer to
+    /// [`ShardMessenger::chunk_guilds`] for the meaning of `limit` and `query`.
+    ///
+    /// Returns `false` if the guild's shard is not run by this client, in
+    /// which case nothing is requested.
+    ///
+    /// [`GuildMembersChunk`]: ../model/event/enum.Event.html#variant.GuildMembersChunk
+    /// [`ShardMessenger::chunk_guilds`]: bridge/gateway/struct.ShardMessenger.html#method.chunk_guilds
+    pub async fn request_guild_members<G: Into<GuildId>>(
+        &self,
+        guild_id: G,
+        limit: Option<u16>,
+        query: Option<String>,
+    ) -> bool {
+        let guild_id = guild_id.into();
+        let shard_id = (guild_id.0 >> 22) % self.shard_total.max(1);
+
+        let mut shard = if shard_id == self.shard_id {
+            self.shard.clone()
+        } else {
+            match self.runners.get(&ShardId(shard_id)) {
+                Some(runner) => ShardMessenger::new(runner.runner_tx.clone()),
+                None => return false,
+            }
+        };
+
+        shard.chunk_guilds(vec![guild_id], limit, query).await;
+
+        true
+    }
+
+    /// Picks the locale to respond to an [`Interaction`] in.
+    ///
+    /// This is the locale of the user who invoked the interaction if Discord
+    /// sent it, falling back to the locale of its guild, as picked by
+    /// [`guild_locale`].
+    ///
+    /// [`Interaction`]: ../model/interactions/struct.Interaction.html
+    /// [`guild_locale`]: #method.guild_locale
+    pub fn interaction_locale(&self, interaction: &Interaction) -> String {
+        interaction
+            .locale
+            .clone()
+            .or_else(|| interaction.guild_locale.clone())
+            .unwrap_or_else(|| self.guild_locale(interaction.guild_id))
+    }
+
+    /// Picks the locale to respond to a [`Message`] in.
+    ///
+    /// Messages do not carry the locale of their author, so this is the
+    /// locale of the guild the message was sent in, as picked by
+    /// [`guild_locale`].
+    ///
+    /// [`Message`]: ../model/channel/struct.Message.html
+    /// [`guild_locale`]: #method.guild_locale
+    #[inline]
+    pub fn message_locale(&self, message: &Message) -> String {
+        self.guild_locale(message.guild_id)
+    }
+
+    /// Picks the locale to respond in within a guild, or within direct
+    /// messages if `guild_id` is `None`.
+    ///
+    /// This is the preferred locale of the guild if it is cached, and
+    /// [`DEFAULT_LOCALE`] otherwise.
+    ///
+    /// [`DEFAULT_LOCALE`]: ../constants/constant.DEFAULT_LOCALE.html
+    pub fn guild_locale(&self, guild_id: Option<GuildId>) -> String {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(guild_id) = guild_id {
+                let locale = self
+                    .cache
+                    .guild_field(guild_id, |guild| guild.preferred_locale.clone());
+
+                if let Some(locale) = locale.filter(|locale| !locale.is_empty()) {
+                    return locale;
+                }
+            }
+        }
+
+        #[cfg(not(feature = "cache"))]
+        let _ = guild_id;
+
+        constants::DEFAULT_LOCALE.to_string()
+    }
 }
 
 impl AsRef<Http> for Context {
@@ -393,8 +540,122 @@ impl AsRef<Http> for Context {
 }
 
 #[cfg(feature = "cache")]
-impl AsRef<CacheRwLock> for Context {
-    fn as_ref(&self) -> &CacheRwLock {
+impl AsRef<Cache> for Context {
+    fn as_ref(&self) -> &Cache {
         &self.cache
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Context;
+    use crate::client::bridge::gateway::{
+        ShardClientMessage, ShardId, ShardRunnerInfo, ShardRunnerMessage,
+    };
+    use crate::constants::DEFAULT_LOCALE;
+    use crate::gateway::{ConnectionStage, InterMessage};
+    use crate::http::Http;
+    use crate::model::id::GuildId;
+    use crate::model::interactions::Interaction;
+    use crate::utils::run_async_test;
+    use dashmap::DashMap;
+    use futures::channel::mpsc::{unbounded, UnboundedReceiver};
+    use serde_json::json;
+    use std::sync::Arc;
+    use tokio::sync::watch;
+    use typemap::ShareMap;
+
+    fn requested_guilds(rx: &mut UnboundedReceiver<InterMessage>) -> Vec<GuildId> {
+        let mut guilds = Vec::new();
+
+        while let Ok(message) = rx.try_recv() {
+            if let InterMessage::Client(message) = message {
+                if let ShardClientMessage::Runner(ShardRunnerMessage::ChunkGuilds {
+                    guild_ids,
+                    ..
+                }) = *message
+                {
+                    guilds.extend(guild_ids);
+                }
+            }
+        }
+
+        guilds
+    }
+
+    #[test]
+    fn test_request_guild_members_routes_to_shard() {
+        run_async_test(async move {
+            let runners = Arc::new(DashMap::new());
+            let mut receivers = Vec::new();
+
+            for id in 0..2 {
+                let (tx, rx) = unbounded();
+                receivers.push(rx);
+                runners.insert(
+                    ShardId(id),
+                    ShardRunnerInfo {
+                        latency: None,
+                        runner_tx: tx,
+                        stage: ConnectionStage::Connected,
+                        #[cfg(feature = "metrics")]
+                        metrics: Default::default(),
+                        finished: watch::channel(()).1,
+                    },
+                );
+            }
+
+            let context = Context::new(
+                Arc::new(crate::internal::AsyncRwLock::new(ShareMap::custom())),
+                runners.get(&ShardId(0)).unwrap().runner_tx.clone(),
+                Arc::clone(&runners),
+                [0, 3],
+                Arc::new(Http::new_with_token("")),
+                #[cfg(feature = "cache")]
+                Default::default(),
+            );
+
+            // Shard 1 of 3 is run by this client, shard 2 is not.
+            assert!(context.request_guild_members(1 << 22, None, None).await);
+            assert!(!context.request_guild_members(2 << 22, None, None).await);
+            assert!(context.request_guild_members(3 << 22, None, None).await);
+
+            assert_eq!(requested_guilds(&mut receivers[0]), vec![GuildId(3 << 22)]);
+            assert_eq!(requested_guilds(&mut receivers[1]), vec![GuildId(1 << 22)]);
+        });
+    }
+
+    #[test]
+    fn test_interaction_locale() {
+        let (tx, _rx) = unbounded();
+        let context = Context::new(
+            Arc::new(crate::internal::AsyncRwLock::new(ShareMap::custom())),
+            tx,
+            Arc::new(DashMap::new()),
+            [0, 1],
+            Arc::new(Http::new_with_token("")),
+            #[cfg(feature = "cache")]
+            Default::default(),
+        );
+
+        let mut interaction: Interaction = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 1,
+            "guild_id": "3",
+            "token": "token",
+            "version": 1,
+            "locale": "de",
+            "guild_locale": "fr",
+        }))
+        .unwrap();
+
+        assert_eq!(context.interaction_locale(&interaction), "de");
+
+        interaction.locale = None;
+        assert_eq!(context.interaction_locale(&interaction), "fr");
+
+        interaction.guild_locale = None;
+        assert_eq!(context.interaction_locale(&interaction), DEFAULT_LOCALE);
+    }
+}
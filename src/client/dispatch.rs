@@ -1,5 +1,5 @@
 use super::{
-    bridge::gateway::event::ClientEvent,
+    bridge::gateway::{event::ClientEvent, ShardId, ShardRunnerInfo},
     event_handler::{EventHandler, RawEventHandler},
     Context,
 };
@@ -9,8 +9,10 @@ use crate::model::{
     event::Event,
     guild::Member,
 };
+use dashmap::DashMap;
 use futures::channel::mpsc::UnboundedSender;
 use futures::lock::Mutex;
+use std::future::Future;
 use std::sync::Arc;
 use typemap::ShareMap;
 
@@ -22,40 +24,121 @@ use crate::cache::{Cache, CacheUpdate};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
 use crate::internal::AsyncRwLock;
+
+#[cfg(feature = "metrics")]
+use crate::gateway::StageMetrics;
+#[cfg(feature = "metrics")]
+use std::time::Instant;
+
+/// The stage timings of the shard dispatching the event, if they are being
+/// recorded.
+#[cfg(feature = "metrics")]
+pub(crate) type Metrics = Arc<StageMetrics>;
+#[cfg(not(feature = "metrics"))]
+pub(crate) type Metrics = ();
+
+/// The cache to wait on for startup to complete before running event handler
+/// methods, if they are being deferred.
 #[cfg(feature = "cache")]
-use crate::model::id::GuildId;
+type Startup = Option<Arc<Cache>>;
+#[cfg(not(feature = "cache"))]
+type Startup = ();
+
+/// Spawns a task running an event handler method, which first waits for the
+/// initial set of guilds to be received if handlers are being deferred.
 #[cfg(feature = "cache")]
-use log::warn;
+fn spawn_handler<F>(startup: &Startup, f: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    match startup {
+        Some(cache) => {
+            let cache = Arc::clone(cache);
+
+            tokio::spawn(async move {
+                cache.wait_for_startup().await;
+                f.await;
+            });
+        }
+        None => {
+            tokio::spawn(f);
+        }
+    }
+}
+
+#[cfg(not(feature = "cache"))]
+fn spawn_handler<F>(_startup: &Startup, f: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    tokio::spawn(f);
+}
+
+/// Whether event handler methods should wait for startup to complete.
 #[cfg(feature = "cache")]
-use std::fmt;
+fn startup(cache_and_http: &Arc<CacheAndHttp>, defer_until_startup: bool) -> Startup {
+    let cache = &cache_and_http.cache;
+
+    if defer_until_startup && !cache.is_startup_complete() {
+        Some(Arc::clone(cache))
+    } else {
+        None
+    }
+}
 
 #[inline]
 #[cfg(feature = "cache")]
-async fn update<E: CacheUpdate + fmt::Debug>(
+async fn update<E: CacheUpdate + Clone + Into<Event>>(
     cache_and_http: &Arc<CacheAndHttp>,
+    _metrics: &Metrics,
     event: &mut E,
 ) -> Option<E::Output> {
-    // TODO: use timeout here
-    if let Some(_millis_timeout) = cache_and_http.update_cache_timeout {
-        if let Some(mut lock) = cache_and_http.cache.try_write() {
-            lock.update(event).await
-        } else {
-            warn!(
-                "[dispatch] Possible deadlock: Couldn't unlock cache to update with event: {:?}",
-                event
-            );
+    #[cfg(feature = "metrics")]
+    let start = Instant::now();
 
-            None
-        }
-    } else {
-        let mut guard = cache_and_http.cache.write().await;
-        guard.update(event).await
+    let cache = &cache_and_http.cache;
+    let output = cache.update(event).await;
+
+    if cache.records_events() || cache.has_update_hooks() {
+        let event = event.clone().into();
+
+        cache.record_event(&event);
+        cache.run_update_hooks(&event).await;
     }
+
+    #[cfg(feature = "metrics")]
+    _metrics.cache_update.record(start.elapsed());
+
+    output
+}
+
+/// Updates the cache with an event in a batch with the events other shards
+/// are dispatching at the same time, see [`Cache::update_queued`].
+///
+/// [`Cache::update_queued`]: ../cache/struct.Cache.html#method.update_queued
+#[inline]
+#[cfg(feature = "cache")]
+async fn update_queued(cache_and_http: &Arc<CacheAndHttp>, _metrics: &Metrics, event: Event) {
+    #[cfg(feature = "metrics")]
+    let start = Instant::now();
+
+    cache_and_http.cache.update_queued(event).await;
+
+    #[cfg(feature = "metrics")]
+    _metrics.cache_update.record(start.elapsed());
 }
 
 #[inline]
 #[cfg(not(feature = "cache"))]
-fn update<E>(_cache_and_http: &Arc<CacheAndHttp>, _event: &mut E) -> Option<()> {
+async fn update_queued(_cache_and_http: &Arc<CacheAndHttp>, _metrics: &Metrics, _event: Event) {}
+
+#[inline]
+#[cfg(not(feature = "cache"))]
+async fn update<E>(
+    _cache_and_http: &Arc<CacheAndHttp>,
+    _metrics: &Metrics,
+    _event: &mut E,
+) -> Option<()> {
     None
 }
 
@@ -63,14 +146,16 @@ fn update<E>(_cache_and_http: &Arc<CacheAndHttp>, _event: &mut E) -> Option<()>
 fn context(
     data: &Arc<AsyncRwLock<ShareMap>>,
     runner_tx: &UnboundedSender<InterMessage>,
-    shard_id: u64,
+    runners: &Arc<DashMap<ShardId, ShardRunnerInfo>>,
+    shard_info: [u64; 2],
     http: &Arc<Http>,
-    cache: &Arc<AsyncRwLock<Cache>>,
+    cache: &Arc<Cache>,
 ) -> Context {
     Context::new(
         Arc::clone(data),
         runner_tx.clone(),
-        shard_id,
+        Arc::clone(runners),
+        shard_info,
         Arc::clone(http),
         Arc::clone(cache),
     )
@@ -80,13 +165,15 @@ fn context(
 fn context(
     data: &Arc<AsyncRwLock<ShareMap>>,
     runner_tx: &UnboundedSender<InterMessage>,
-    shard_id: u64,
+    runners: &Arc<DashMap<ShardId, ShardRunnerInfo>>,
+    shard_info: [u64; 2],
     http: &Arc<Http>,
 ) -> Context {
     Context::new(
         Arc::clone(data),
         runner_tx.clone(),
-        shard_id,
+        Arc::clone(runners),
+        shard_info,
         Arc::clone(http),
     )
 }
@@ -100,163 +187,106 @@ pub(crate) enum DispatchEvent {
     Model(Event),
 }
 
-#[cfg(feature = "framework")]
 #[allow(clippy::too_many_arguments)]
 pub(crate) async fn dispatch(
     event: DispatchEvent,
-    framework: &Arc<Mutex<Option<Box<dyn Framework + Send>>>>,
+    #[cfg(feature = "framework")] framework: &Arc<Mutex<Option<Box<dyn Framework + Send>>>>,
+    #[cfg(feature = "framework")] message_passthrough: bool,
+    #[cfg(feature = "cache")] defer_until_startup: bool,
     data: &Arc<AsyncRwLock<ShareMap>>,
     event_handler: &Option<Arc<dyn EventHandler>>,
     raw_event_handler: &Option<Arc<dyn RawEventHandler>>,
     runner_tx: &UnboundedSender<InterMessage>,
-    shard_id: u64,
+    runners: &Arc<DashMap<ShardId, ShardRunnerInfo>>,
+    shard_info: [u64; 2],
     cache_and_http: Arc<CacheAndHttp>,
+    metrics: Metrics,
 ) {
-    if let Some(ref h) = event_handler {
-        let event = event.clone();
-        match event {
-            DispatchEvent::Model(Event::MessageCreate(mut event)) => {
-                let tmp = Arc::clone(&cache_and_http);
-                update(&tmp, &mut event).await;
-
-                #[cfg(not(feature = "cache"))]
-                let context = context(data, runner_tx, shard_id, &cache_and_http.http);
-                #[cfg(feature = "cache")]
-                let context = context(
-                    data,
-                    runner_tx,
-                    shard_id,
-                    &cache_and_http.http,
-                    &cache_and_http.cache,
-                );
+    #[cfg(feature = "cache")]
+    let startup = startup(&cache_and_http, defer_until_startup);
+    #[cfg(not(feature = "cache"))]
+    let startup = ();
 
-                dispatch_message(context.clone(), event.message.clone(), h).await;
+    match event {
+        DispatchEvent::Model(Event::MessageCreate(ref event)) => {
+            let mut event = event.clone();
+            update(&cache_and_http, &metrics, &mut event).await;
 
+            #[cfg(not(feature = "cache"))]
+            let context = context(data, runner_tx, runners, shard_info, &cache_and_http.http);
+            #[cfg(feature = "cache")]
+            let context = context(
+                data,
+                runner_tx,
+                runners,
+                shard_info,
+                &cache_and_http.http,
+                &cache_and_http.cache,
+            );
+
+            // Unless configured otherwise, the event handler receives every
+            // message, including those the framework handles as commands.
+            #[cfg(feature = "framework")]
+            let to_handler = message_passthrough || framework.lock().await.is_none();
+            #[cfg(not(feature = "framework"))]
+            let to_handler = true;
+
+            if let Some(ref h) = event_handler {
+                if to_handler {
+                    dispatch_message(context.clone(), event.message.clone(), h, &startup).await;
+                }
+            }
+
+            #[cfg(feature = "framework")]
+            {
                 if let Some(ref mut framework) = *framework.lock().await {
                     framework.dispatch(context, event.message).await;
                 }
             }
-            other => {
+        }
+        ref other => {
+            if let Some(ref h) = event_handler {
                 handle_event(
-                    other,
+                    other.clone(),
                     data,
                     h,
                     runner_tx,
-                    shard_id,
+                    runners,
+                    shard_info,
                     Arc::clone(&cache_and_http),
+                    metrics,
+                    &startup,
                 )
                 .await;
             }
         }
-    };
+    }
 
     if let Some(ref rh) = raw_event_handler {
         if let DispatchEvent::Model(e) = event {
             #[cfg(not(feature = "cache"))]
-            let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+            let context = context(data, runner_tx, runners, shard_info, &cache_and_http.http);
             #[cfg(feature = "cache")]
             let context = context(
                 data,
                 runner_tx,
-                shard_id,
+                runners,
+                shard_info,
                 &cache_and_http.http,
                 &cache_and_http.cache,
             );
 
-            // TODO: investigate changes necessary here
             let event_handler = Arc::clone(rh);
             event_handler.raw_event(context, e).await;
         }
     };
 }
 
-#[cfg(not(feature = "framework"))]
-pub(crate) async fn dispatch(
-    event: DispatchEvent,
-    data: &Arc<RwLock<ShareMap>>,
-    event_handler: &Option<Arc<dyn EventHandler>>,
-    raw_event_handler: &Option<Arc<dyn RawEventHandler>>,
-    runner_tx: &UnboundedSender<InterMessage>,
-    shard_id: u64,
-    cache_and_http: Arc<CacheAndHttp>,
-) {
-    match (event_handler, raw_event_handler) {
-        (None, None) => {} // Do nothing
-        (Some(ref h), None) => match event {
-            DispatchEvent::Model(Event::MessageCreate(mut event)) => {
-                update(&cache_and_http, &mut event);
-
-                #[cfg(not(feature = "cache"))]
-                let context = context(data, runner_tx, shard_id, &cache_and_http.http);
-                #[cfg(feature = "cache")]
-                let context = context(
-                    data,
-                    runner_tx,
-                    shard_id,
-                    &cache_and_http.http,
-                    &cache_and_http.cache,
-                );
-
-                dispatch_message(context.clone(), event.message.clone(), h).await;
-            }
-            other => {
-                handle_event(other, data, h, runner_tx, shard_id, cache_and_http).await;
-            }
-        },
-        (None, Some(ref rh)) => match event {
-            DispatchEvent::Model(e) => {
-                #[cfg(not(feature = "cache"))]
-                let context = context(data, runner_tx, shard_id, &cache_and_http.http);
-                #[cfg(feature = "cache")]
-                let context = context(
-                    data,
-                    runner_tx,
-                    shard_id,
-                    &cache_and_http.http,
-                    &cache_and_http.cache,
-                );
-
-                let event_handler = Arc::clone(rh);
-                tokio::spawn(async move {
-                    event_handler.raw_event(context, e).await;
-                });
-            }
-            _ => {}
-        },
-        (Some(ref h), Some(ref rh)) => {
-            match event {
-                DispatchEvent::Model(ref e) => {
-                    dispatch(
-                        DispatchEvent::Model(e.clone()),
-                        data,
-                        &None,
-                        raw_event_handler,
-                        runner_tx,
-                        shard_id,
-                        Arc::clone(&cache_and_http),
-                    )
-                    .await
-                }
-                _ => {}
-            }
-            dispatch(
-                event,
-                data,
-                event_handler,
-                &None,
-                runner_tx,
-                shard_id,
-                cache_and_http,
-            )
-            .await;
-        }
-    };
-}
-
 async fn dispatch_message(
     context: Context,
     mut message: Message,
     event_handler: &Arc<dyn EventHandler>,
+    startup: &Startup,
 ) {
     #[cfg(feature = "model")]
     {
@@ -265,7 +295,7 @@ async fn dispatch_message(
 
     let event_handler = Arc::clone(event_handler);
 
-    tokio::spawn(async move {
+    spawn_handler(startup, async move {
         event_handler.message(context, message).await;
     });
 }
@@ -276,16 +306,20 @@ async fn handle_event(
     data: &Arc<AsyncRwLock<ShareMap>>,
     event_handler: &Arc<dyn EventHandler>,
     runner_tx: &UnboundedSender<InterMessage>,
-    shard_id: u64,
+    runners: &Arc<DashMap<ShardId, ShardRunnerInfo>>,
+    shard_info: [u64; 2],
     cache_and_http: Arc<CacheAndHttp>,
+    metrics: Metrics,
+    startup: &Startup,
 ) {
     #[cfg(not(feature = "cache"))]
-    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+    let context = context(data, runner_tx, runners, shard_info, &cache_and_http.http);
     #[cfg(feature = "cache")]
     let context = context(
         data,
         runner_tx,
-        shard_id,
+        runners,
+        shard_info,
         &cache_and_http.http,
         &cache_and_http.cache,
     );
@@ -294,12 +328,12 @@ async fn handle_event(
         DispatchEvent::Client(ClientEvent::ShardStageUpdate(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler.shard_stage_update(context, event).await;
             });
         }
         DispatchEvent::Model(Event::ChannelCreate(mut event)) => {
-            update(&cache_and_http, &mut event).await;
+            update(&cache_and_http, &metrics, &mut event).await;
             // Discord sends both a MessageCreate and a ChannelCreate upon a new message in a private channel.
             // This could potentially be annoying to handle when otherwise wanting to normally take care of a new channel.
             // So therefore, private channels are dispatched to their own handler code.
@@ -307,43 +341,44 @@ async fn handle_event(
                 Channel::Private(channel) => {
                     let event_handler = Arc::clone(event_handler);
 
-                    tokio::spawn(async move {
+                    spawn_handler(startup, async move {
                         event_handler.private_channel_create(context, channel).await;
                     });
                 }
-                Channel::Group(_) => {}
+                // Threads are created through their own event.
+                Channel::Group(_) | Channel::Thread(_) => {}
                 Channel::Guild(channel) => {
                     let event_handler = Arc::clone(event_handler);
 
-                    tokio::spawn(async move {
+                    spawn_handler(startup, async move {
                         event_handler.channel_create(context, channel).await;
                     });
                 }
                 Channel::Category(channel) => {
                     let event_handler = Arc::clone(event_handler);
 
-                    tokio::spawn(async move {
+                    spawn_handler(startup, async move {
                         event_handler.category_create(context, channel).await;
                     });
                 }
             }
         }
         DispatchEvent::Model(Event::ChannelDelete(mut event)) => {
-            update(&cache_and_http, &mut event).await;
+            update(&cache_and_http, &metrics, &mut event).await;
 
             match event.channel {
-                Channel::Private(_) | Channel::Group(_) => {}
+                Channel::Private(_) | Channel::Group(_) | Channel::Thread(_) => {}
                 Channel::Guild(channel) => {
                     let event_handler = Arc::clone(event_handler);
 
-                    tokio::spawn(async move {
+                    spawn_handler(startup, async move {
                         event_handler.channel_delete(context, channel).await;
                     });
                 }
                 Channel::Category(channel) => {
                     let event_handler = Arc::clone(event_handler);
 
-                    tokio::spawn(async move {
+                    spawn_handler(startup, async move {
                         event_handler.category_delete(context, channel).await;
                     });
                 }
@@ -352,45 +387,40 @@ async fn handle_event(
         DispatchEvent::Model(Event::ChannelPinsUpdate(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler.channel_pins_update(context, event).await;
             });
         }
         DispatchEvent::Model(Event::ChannelRecipientAdd(mut event)) => {
-            update(&cache_and_http, &mut event).await;
+            update(&cache_and_http, &metrics, &mut event).await;
 
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .channel_recipient_addition(context, event.channel_id, event.user)
                     .await;
             });
         }
         DispatchEvent::Model(Event::ChannelRecipientRemove(mut event)) => {
-            update(&cache_and_http, &mut event).await;
+            update(&cache_and_http, &metrics, &mut event).await;
 
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .channel_recipient_removal(context, event.channel_id, event.user)
                     .await;
             });
         }
         DispatchEvent::Model(Event::ChannelUpdate(mut event)) => {
+            let _before = update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 feature_cache! {{
-                    let channel_id = event.channel.id().await;
-                    let before = cache_and_http.cache.as_ref().read().await.channel(channel_id);
-                    update(&cache_and_http, &mut event).await;
-
-                    event_handler.channel_update(context, before, event.channel).await;
+                    event_handler.channel_update(context, _before, event.channel).await;
                 } else {
-                    update(&cache_and_http, &mut event).await;
-
                     event_handler.channel_update(context, event.channel).await;
                 }}
             });
@@ -398,7 +428,7 @@ async fn handle_event(
         DispatchEvent::Model(Event::GuildBanAdd(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .guild_ban_addition(context, event.guild_id, event.user)
                     .await;
@@ -407,36 +437,45 @@ async fn handle_event(
         DispatchEvent::Model(Event::GuildBanRemove(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .guild_ban_removal(context, event.guild_id, event.user)
                     .await;
             });
         }
-        DispatchEvent::Model(Event::GuildCreate(mut event)) => {
+        DispatchEvent::Model(Event::GuildCreate(event)) => {
             #[cfg(feature = "cache")]
-            let _is_new = {
-                let cache = cache_and_http.cache.as_ref().read().await;
-
-                !cache.unavailable_guilds.contains(&event.guild.id)
-            };
+            let _is_new = !cache_and_http
+                .cache
+                .unavailable_guilds
+                .contains(&event.guild.id);
 
-            update(&cache_and_http, &mut event).await;
+            // Guilds arrive in bursts on startup, so they are applied in
+            // batches rather than each on their own.
+            update_queued(&cache_and_http, &metrics, Event::GuildCreate(event.clone())).await;
 
             #[cfg(feature = "cache")]
             {
-                let locked_cache = cache_and_http.cache.as_ref().read().await;
+                let cache = &cache_and_http.cache;
                 let context = context.clone();
 
-                if locked_cache.unavailable_guilds.is_empty() {
-                    let guild_amount = locked_cache
-                        .guilds
-                        .iter()
-                        .map(|(&id, _)| id)
-                        .collect::<Vec<GuildId>>();
+                let chunk = cache.settings().chunk_guilds_on_ready
+                    && cache
+                        .guild_field(event.guild.id, |guild| {
+                            guild.member_count > guild.members.len() as u64
+                        })
+                        .unwrap_or(false);
+
+                if chunk {
+                    let mut shard = context.shard.clone();
+                    shard.chunk_guilds(vec![event.guild.id], None, None).await;
+                }
+
+                if cache.unavailable_guilds.is_empty() {
+                    let guild_amount = cache.all_guilds();
                     let event_handler = Arc::clone(event_handler);
 
-                    tokio::spawn(async move {
+                    spawn_handler(startup, async move {
                         event_handler.cache_ready(context, guild_amount).await;
                     });
                 }
@@ -444,7 +483,7 @@ async fn handle_event(
 
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 feature_cache! {{
                     event_handler.guild_create(context, event.guild, _is_new).await;
                 } else {
@@ -453,10 +492,10 @@ async fn handle_event(
             });
         }
         DispatchEvent::Model(Event::GuildDelete(mut event)) => {
-            let _full = update(&cache_and_http, &mut event).await;
+            let _full = update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 feature_cache! {{
                     event_handler.guild_delete(context, event.guild, _full).await;
                 } else {
@@ -465,10 +504,10 @@ async fn handle_event(
             });
         }
         DispatchEvent::Model(Event::GuildEmojisUpdate(mut event)) => {
-            update(&cache_and_http, &mut event).await;
+            update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .guild_emojis_update(context, event.guild_id, event.emojis)
                     .await;
@@ -477,28 +516,28 @@ async fn handle_event(
         DispatchEvent::Model(Event::GuildIntegrationsUpdate(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .guild_integrations_update(context, event.guild_id)
                     .await;
             });
         }
         DispatchEvent::Model(Event::GuildMemberAdd(mut event)) => {
-            update(&cache_and_http, &mut event).await;
+            update(&cache_and_http, &metrics, &mut event).await;
 
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .guild_member_addition(context, event.guild_id, event.member)
                     .await;
             });
         }
         DispatchEvent::Model(Event::GuildMemberRemove(mut event)) => {
-            let _member = update(&cache_and_http, &mut event).await;
+            let _member = update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 feature_cache! {{
                     event_handler.guild_member_removal(context, event.guild_id, event.user, _member).await;
                 } else {
@@ -507,17 +546,16 @@ async fn handle_event(
             });
         }
         DispatchEvent::Model(Event::GuildMemberUpdate(mut event)) => {
-            let _before = update(&cache_and_http, &mut event).await;
+            let _before = update(&cache_and_http, &metrics, &mut event).await;
             let _after: Option<Member> = feature_cache! {{
-                let guard = cache_and_http.cache.as_ref().read().await;
-                guard.member(event.guild_id, event.user.id).await
+                cache_and_http.cache.member(event.guild_id, event.user.id)
             } else {
                 None
             }};
 
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 feature_cache! {{
                     if let Some(after) = _after {
                         event_handler.guild_member_update(context, _before, after).await;
@@ -528,30 +566,30 @@ async fn handle_event(
             });
         }
         DispatchEvent::Model(Event::GuildMembersChunk(mut event)) => {
-            update(&cache_and_http, &mut event).await;
+            update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .guild_members_chunk(context, event.guild_id, event.members)
                     .await;
             });
         }
         DispatchEvent::Model(Event::GuildRoleCreate(mut event)) => {
-            update(&cache_and_http, &mut event).await;
+            update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .guild_role_create(context, event.guild_id, event.role)
                     .await;
             });
         }
         DispatchEvent::Model(Event::GuildRoleDelete(mut event)) => {
-            let _role = update(&cache_and_http, &mut event).await;
+            let _role = update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 feature_cache! {{
                     event_handler.guild_role_delete(context, event.guild_id, event.role_id, _role).await;
                 } else {
@@ -560,10 +598,10 @@ async fn handle_event(
             });
         }
         DispatchEvent::Model(Event::GuildRoleUpdate(mut event)) => {
-            let _before = update(&cache_and_http, &mut event).await;
+            let _before = update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 feature_cache! {{
                     event_handler.guild_role_update(context, event.guild_id, _before, event.role).await;
                 } else {
@@ -572,62 +610,63 @@ async fn handle_event(
             });
         }
         DispatchEvent::Model(Event::GuildUnavailable(mut event)) => {
-            update(&cache_and_http, &mut event).await;
+            update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .guild_unavailable(context, event.guild_id)
                     .await;
             });
         }
         DispatchEvent::Model(Event::GuildUpdate(mut event)) => {
+            let _before = update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 feature_cache! {{
-                    let before = cache_and_http.cache.as_ref().read()
-                        .await
-                        .guilds
-                        .get(&event.guild.id)
-                        .cloned();
-                    update(&cache_and_http, &mut event).await;
-
-                    event_handler.guild_update(context, before, event.guild).await;
+                    event_handler.guild_update(context, _before, event.guild).await;
                 } else {
-                    update(&cache_and_http, &mut event).await;
-
                     event_handler.guild_update(context, event.guild).await;
                 }}
             });
         }
-        // Already handled by the framework check macro
+        // Already handled by `dispatch`.
         DispatchEvent::Model(Event::MessageCreate(_)) => {}
-        DispatchEvent::Model(Event::MessageDeleteBulk(event)) => {
+        DispatchEvent::Model(Event::MessageDeleteBulk(mut event)) => {
+            let _removed = update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
-                event_handler
-                    .message_delete_bulk(context, event.channel_id, event.ids)
-                    .await;
+            spawn_handler(startup, async move {
+                feature_cache! {{
+                    let removed = _removed.unwrap_or_default();
+
+                    event_handler
+                        .message_delete_bulk(context, event.channel_id, event.ids, removed)
+                        .await;
+                } else {
+                    event_handler
+                        .message_delete_bulk(context, event.channel_id, event.ids)
+                        .await;
+                }}
             });
         }
         DispatchEvent::Model(Event::MessageDelete(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .message_delete(context, event.channel_id, event.message_id)
                     .await;
             });
         }
         DispatchEvent::Model(Event::MessageUpdate(mut event)) => {
-            let _before = update(&cache_and_http, &mut event).await;
+            let _before = update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 feature_cache! {{
-                    let _after = cache_and_http.cache.as_ref().read().await.message(event.channel_id, event.id);
+                    let _after = cache_and_http.cache.message(event.channel_id, event.id);
                     event_handler.message_update(context, _before, _after, event).await;
                 } else {
                     event_handler.message_update(context, event).await;
@@ -635,83 +674,111 @@ async fn handle_event(
             });
         }
         DispatchEvent::Model(Event::PresencesReplace(mut event)) => {
-            update(&cache_and_http, &mut event).await;
+            update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .presence_replace(context, event.presences)
                     .await;
             });
         }
         DispatchEvent::Model(Event::PresenceUpdate(mut event)) => {
-            update(&cache_and_http, &mut event).await;
+            update(&cache_and_http, &metrics, &mut event).await;
 
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler.presence_update(context, event).await;
             });
         }
         DispatchEvent::Model(Event::ReactionAdd(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler.reaction_add(context, event.reaction).await;
             });
         }
         DispatchEvent::Model(Event::ReactionRemove(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler.reaction_remove(context, event.reaction).await;
             });
         }
         DispatchEvent::Model(Event::ReactionRemoveAll(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .reaction_remove_all(context, event.channel_id, event.message_id)
                     .await;
             });
         }
         DispatchEvent::Model(Event::Ready(mut event)) => {
-            update(&cache_and_http, &mut event).await;
+            update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(&event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler.ready(context, event.ready).await;
             });
         }
         DispatchEvent::Model(Event::Resumed(event)) => {
             let event_handler = Arc::clone(&event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler.resume(context, event).await;
             });
         }
+        DispatchEvent::Model(Event::ThreadCreate(mut event)) => {
+            update(&cache_and_http, &metrics, &mut event).await;
+            let event_handler = Arc::clone(event_handler);
+
+            spawn_handler(startup, async move {
+                event_handler.thread_create(context, event.thread).await;
+            });
+        }
+        DispatchEvent::Model(Event::ThreadDelete(mut event)) => {
+            update(&cache_and_http, &metrics, &mut event).await;
+            let event_handler = Arc::clone(event_handler);
+
+            spawn_handler(startup, async move {
+                event_handler.thread_delete(context, event).await;
+            });
+        }
+        DispatchEvent::Model(Event::ThreadUpdate(mut event)) => {
+            let _before = update(&cache_and_http, &metrics, &mut event).await;
+            let event_handler = Arc::clone(event_handler);
+
+            spawn_handler(startup, async move {
+                feature_cache! {{
+                    event_handler.thread_update(context, _before, event.thread).await;
+                } else {
+                    event_handler.thread_update(context, event.thread).await;
+                }}
+            });
+        }
         DispatchEvent::Model(Event::TypingStart(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler.typing_start(context, event).await;
             });
         }
         DispatchEvent::Model(Event::Unknown(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .unknown(context, event.kind, event.value)
                     .await;
             });
         }
         DispatchEvent::Model(Event::UserUpdate(mut event)) => {
-            let _before = update(&cache_and_http, &mut event).await;
+            let _before = update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 feature_cache! {{
                     event_handler.user_update(context, _before.unwrap(), event.current_user).await;
                 } else {
@@ -722,15 +789,15 @@ async fn handle_event(
         DispatchEvent::Model(Event::VoiceServerUpdate(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler.voice_server_update(context, event).await;
             });
         }
         DispatchEvent::Model(Event::VoiceStateUpdate(mut event)) => {
-            let _before = update(&cache_and_http, &mut event).await;
+            let _before = update(&cache_and_http, &metrics, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 feature_cache! {{
                     event_handler.voice_state_update(context, event.guild_id, _before, event.voice_state).await;
                 } else {
@@ -741,7 +808,7 @@ async fn handle_event(
         DispatchEvent::Model(Event::WebhookUpdate(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawn_handler(startup, async move {
                 event_handler
                     .webhook_update(context, event.guild_id, event.channel_id)
                     .await;
@@ -749,3 +816,100 @@ async fn handle_event(
         }
     }
 }
+
+#[cfg(all(test, feature = "framework"))]
+mod test {
+    use super::*;
+    use crate::model::event::MessageCreateEvent;
+    use async_trait::async_trait;
+    use futures::channel::mpsc::{unbounded, UnboundedReceiver};
+    use futures::StreamExt;
+    use serde_json::json;
+    use std::time::Duration;
+
+    struct Recorder(UnboundedSender<&'static str>);
+
+    #[async_trait]
+    impl EventHandler for Recorder {
+        async fn message(&self, _: Context, _: Message) {
+            let _ = self.0.unbounded_send("handler");
+        }
+    }
+
+    #[async_trait]
+    impl Framework for Recorder {
+        async fn dispatch(&mut self, _: Context, _: Message) {
+            let _ = self.0.unbounded_send("framework");
+        }
+    }
+
+    async fn receivers(message_passthrough: bool) -> UnboundedReceiver<&'static str> {
+        let (tx, rx) = unbounded();
+        let (runner_tx, _runner_rx) = unbounded();
+
+        let event: MessageCreateEvent = serde_json::from_value(json!({
+            "id": "1",
+            "channel_id": "2",
+            "author": {"id": "3", "username": "user", "discriminator": "0001", "avatar": null},
+            "content": "~ping",
+            "timestamp": "2021-01-01T00:00:00+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+        }))
+        .unwrap();
+
+        let framework: Arc<Mutex<Option<Box<dyn Framework + Send>>>> =
+            Arc::new(Mutex::new(Some(Box::new(Recorder(tx.clone())))));
+        let handler: Arc<dyn EventHandler> = Arc::new(Recorder(tx));
+
+        dispatch(
+            DispatchEvent::Model(Event::MessageCreate(event)),
+            &framework,
+            message_passthrough,
+            #[cfg(feature = "cache")]
+            false,
+            &Arc::new(AsyncRwLock::new(ShareMap::custom())),
+            &Some(handler),
+            &None,
+            &runner_tx,
+            &Arc::new(DashMap::new()),
+            [0, 1],
+            Arc::new(CacheAndHttp::default()),
+            #[cfg(feature = "metrics")]
+            Default::default(),
+            #[cfg(not(feature = "metrics"))]
+            (),
+        )
+        .await;
+
+        rx
+    }
+
+    async fn received(rx: &mut UnboundedReceiver<&'static str>) -> Vec<&'static str> {
+        let mut received = Vec::new();
+
+        while let Ok(Some(name)) = tokio::time::timeout(Duration::from_millis(200), rx.next()).await
+        {
+            received.push(name);
+        }
+
+        received.sort_unstable();
+        received
+    }
+
+    #[tokio::test]
+    async fn test_message_passthrough() {
+        let mut rx = receivers(true).await;
+        assert_eq!(received(&mut rx).await, vec!["framework", "handler"]);
+
+        let mut rx = receivers(false).await;
+        assert_eq!(received(&mut rx).await, vec!["framework"]);
+    }
+}
@@ -1,6 +1,7 @@
 use std::{
     error::Error as StdError,
     fmt::{Display, Formatter, Result as FmtResult},
+    time::Duration,
 };
 
 /// An error returned from the [`Client`].
@@ -24,6 +25,12 @@ pub enum Error {
     /// When a shard has completely failed to reboot after resume and/or
     /// reconnect attempts.
     ShardBootFailure,
+    /// When the bot can't start enough sessions to autoshard, as its
+    /// [`SessionStartLimit`] was reached. The attached value is the time until
+    /// the limit resets.
+    ///
+    /// [`SessionStartLimit`]: ../model/gateway/struct.SessionStartLimit.html
+    SessionStartLimitReached(Duration),
     /// When all shards that the client is responsible for have shutdown with an
     /// error.
     Shutdown,
@@ -34,6 +41,11 @@ impl Display for Error {
         match self {
             Error::InvalidToken => f.write_str("The provided token was invalid"),
             Error::ShardBootFailure => f.write_str("Failed to (re-)boot a shard"),
+            Error::SessionStartLimitReached(reset_after) => write!(
+                f,
+                "The session start limit was reached, resetting in {:?}",
+                reset_after
+            ),
             Error::Shutdown => f.write_str("The clients shards shutdown"),
         }
     }
@@ -44,6 +56,7 @@ impl StdError for Error {
         match *self {
             Error::InvalidToken => "The provided token was invalid",
             Error::ShardBootFailure => "Failed to (re-)boot a shard",
+            Error::SessionStartLimitReached(_) => "The session start limit was reached",
             Error::Shutdown => "The clients shards shutdown",
         }
     }
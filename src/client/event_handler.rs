@@ -1,10 +1,9 @@
 use super::context::Context;
 use crate::client::bridge::gateway::event::*;
-use crate::internal::AsyncRwLock;
 use crate::model::prelude::*;
 use async_trait::async_trait;
 use serde_json::Value;
-use std::{collections::HashMap, sync::Arc};
+use std::collections::HashMap;
 
 /// The core trait for handling events by serenity.
 #[async_trait]
@@ -23,32 +22,27 @@ pub trait EventHandler: Send + Sync {
     /// Dispatched when a channel is created.
     ///
     /// Provides said channel's data.
-    async fn channel_create(&self, _ctx: Context, _channel: Arc<AsyncRwLock<GuildChannel>>) {}
+    async fn channel_create(&self, _ctx: Context, _channel: GuildChannel) {}
 
     /// Dispatched when a category is created.
     ///
     /// Provides said category's data.
-    async fn category_create(&self, _ctx: Context, _category: Arc<AsyncRwLock<ChannelCategory>>) {}
+    async fn category_create(&self, _ctx: Context, _category: ChannelCategory) {}
 
     /// Dispatched when a category is deleted.
     ///
     /// Provides said category's data.
-    async fn category_delete(&self, _ctx: Context, _category: Arc<AsyncRwLock<ChannelCategory>>) {}
+    async fn category_delete(&self, _ctx: Context, _category: ChannelCategory) {}
 
     /// Dispatched when a private channel is created.
     ///
     /// Provides said channel's data.
-    async fn private_channel_create(
-        &self,
-        _ctx: Context,
-        _channel: Arc<AsyncRwLock<PrivateChannel>>,
-    ) {
-    }
+    async fn private_channel_create(&self, _ctx: Context, _channel: PrivateChannel) {}
 
     /// Dispatched when a channel is deleted.
     ///
     /// Provides said channel's data.
-    async fn channel_delete(&self, _ctx: Context, _channel: Arc<AsyncRwLock<GuildChannel>>) {}
+    async fn channel_delete(&self, _ctx: Context, _channel: GuildChannel) {}
 
     /// Dispatched when a pin is added, deleted.
     ///
@@ -106,13 +100,7 @@ pub trait EventHandler: Send + Sync {
     /// Provides the partial data of the guild sent by discord,
     /// and the full data from the cache, if available.
     #[cfg(feature = "cache")]
-    async fn guild_delete(
-        &self,
-        _ctx: Context,
-        _incomplete: PartialGuild,
-        _full: Option<Arc<AsyncRwLock<Guild>>>,
-    ) {
-    }
+    async fn guild_delete(&self, _ctx: Context, _incomplete: PartialGuild, _full: Option<Guild>) {}
 
     /// Dispatched when a guild is deleted.
     ///
@@ -247,7 +235,7 @@ pub trait EventHandler: Send + Sync {
     async fn guild_update(
         &self,
         _ctx: Context,
-        _old_data_if_available: Option<Arc<AsyncRwLock<Guild>>>,
+        _old_data_if_available: Option<Guild>,
         _new_but_incomplete: PartialGuild,
     ) {
     }
@@ -274,9 +262,26 @@ pub trait EventHandler: Send + Sync {
     ) {
     }
 
+    /// Dispatched when multiple messages were deleted at once.
+    ///
+    /// Provides the channel's id, the deleted messages' ids, and those of the
+    /// deleted messages which were in the cache.
+    ///
+    /// The deleted messages are removed from the cache.
+    #[cfg(feature = "cache")]
+    async fn message_delete_bulk(
+        &self,
+        _ctx: Context,
+        _channel_id: ChannelId,
+        _multiple_deleted_messages_ids: Vec<MessageId>,
+        _deleted_messages_if_available: Vec<Message>,
+    ) {
+    }
+
     /// Dispatched when multiple messages were deleted at once.
     ///
     /// Provides the channel's id and the deleted messages' ids.
+    #[cfg(not(feature = "cache"))]
     async fn message_delete_bulk(
         &self,
         _ctx: Context,
@@ -349,6 +354,29 @@ pub trait EventHandler: Send + Sync {
     /// Provides the context of the shard and the event information about the update.
     async fn shard_stage_update(&self, _ctx: Context, _: ShardStageUpdateEvent) {}
 
+    /// Dispatched when a thread is created, or the current user is added to a
+    /// private thread.
+    ///
+    /// Provides said thread's data.
+    async fn thread_create(&self, _ctx: Context, _thread: GuildThread) {}
+
+    /// Dispatched when a thread is updated, including when it is archived.
+    ///
+    /// The old thread data is only provided when the thread was cached.
+    #[cfg(feature = "cache")]
+    async fn thread_update(&self, _ctx: Context, _old: Option<GuildThread>, _new: GuildThread) {}
+
+    /// Dispatched when a thread is updated, including when it is archived.
+    ///
+    /// Provides the new data.
+    #[cfg(not(feature = "cache"))]
+    async fn thread_update(&self, _ctx: Context, _new: GuildThread) {}
+
+    /// Dispatched when a thread is deleted.
+    ///
+    /// Provides the Ids of the deleted thread.
+    async fn thread_delete(&self, _ctx: Context, _thread: ThreadDeleteEvent) {}
+
     /// Dispatched when a user starts typing.
     async fn typing_start(&self, _ctx: Context, _: TypingStartEvent) {}
 
@@ -1,11 +1,13 @@
 use super::{EventHandler, RawEventHandler};
+#[cfg(feature = "cache")]
+use crate::cache::CacheUpdateHook;
+use crate::constants;
+use crate::gateway::{ConnectionTimeouts, WsProxy};
+use crate::http::HttpTimeouts;
 
 use std::fmt;
 use std::sync::Arc;
 
-#[cfg(feature = "cache")]
-use std::time::Duration;
-
 /// A builder to extra things for altering the [`Client`].
 ///
 /// [`Client`]: ../struct.Client.html
@@ -13,9 +15,19 @@ use std::time::Duration;
 pub struct Extras {
     pub(crate) event_handler: Option<Arc<dyn EventHandler>>,
     pub(crate) raw_event_handler: Option<Arc<dyn RawEventHandler>>,
-    #[cfg(feature = "cache")]
-    pub(crate) timeout: Option<Duration>,
     pub(crate) guild_subscriptions: bool,
+    pub(crate) ws_proxy: Option<WsProxy>,
+    pub(crate) connection_timeouts: ConnectionTimeouts,
+    pub(crate) http_client: Option<reqwest::Client>,
+    pub(crate) http_timeouts: HttpTimeouts,
+    pub(crate) api_version: u8,
+    #[cfg(feature = "framework")]
+    pub(crate) message_passthrough: bool,
+    #[cfg(feature = "cache")]
+    pub(crate) cache_update_hooks: Vec<Arc<dyn CacheUpdateHook>>,
+    #[cfg(feature = "cache")]
+    pub(crate) defer_until_startup: bool,
+    pub(crate) event_dedup_capacity: usize,
 }
 
 impl Extras {
@@ -40,21 +52,134 @@ impl Extras {
         self
     }
 
-    /// Set the duration the library is permitted to update the cache
-    /// before giving up acquiring a write-lock.
+    /// Set whether the library should subscribe for listening to presence and typing events.
+    ///
+    /// By default, this is `true`.
+    pub fn guild_subscriptions(&mut self, guild_subscriptions: bool) -> &mut Self {
+        self.guild_subscriptions = guild_subscriptions;
+        self
+    }
+
+    /// Set a proxy to open the shards' websocket connections through.
+    ///
+    /// This does not affect HTTP requests. Refer to [`WsProxy`] for more
+    /// information.
+    ///
+    /// [`WsProxy`]: ../gateway/enum.WsProxy.html
+    pub fn ws_proxy(&mut self, proxy: WsProxy) -> &mut Self {
+        self.ws_proxy = Some(proxy);
+        self
+    }
+
+    /// Set how long shards wait for each stage of connecting to the gateway,
+    /// after which they are restarted.
+    ///
+    /// Refer to [`ConnectionTimeouts`] for the defaults.
+    ///
+    /// [`ConnectionTimeouts`]: ../gateway/struct.ConnectionTimeouts.html
+    pub fn connection_timeouts(&mut self, timeouts: ConnectionTimeouts) -> &mut Self {
+        self.connection_timeouts = timeouts;
+        self
+    }
+
+    /// Set the `reqwest` client used to send HTTP requests.
+    ///
+    /// Refer to [`Http::new_with_client`] for more information.
+    ///
+    /// [`Http::new_with_client`]: ../http/client/struct.Http.html#method.new_with_client
+    pub fn http_client(&mut self, client: reqwest::Client) -> &mut Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Set how long HTTP requests wait for Discord to respond before failing.
+    ///
+    /// This is ignored if a client was set with [`http_client`], in which
+    /// case its timeouts apply. Refer to [`HttpTimeouts`] for the defaults.
+    ///
+    /// [`HttpTimeouts`]: ../http/struct.HttpTimeouts.html
+    /// [`http_client`]: #method.http_client
+    pub fn http_timeouts(&mut self, timeouts: HttpTimeouts) -> &mut Self {
+        self.http_timeouts = timeouts;
+        self
+    }
+
+    /// Set the version of Discord's API HTTP requests are sent to.
     ///
-    /// This can be useful for avoiding deadlocks.
+    /// The version applies from the first request the client makes, which
+    /// fetches the gateway URL. Refer to [`Http::set_api_version`] for more
+    /// information.
+    ///
+    /// By default, this is [`API_VERSION`].
+    ///
+    /// [`API_VERSION`]: ../constants/constant.API_VERSION.html
+    /// [`Http::set_api_version`]: ../http/client/struct.Http.html#method.set_api_version
+    pub fn api_version(&mut self, version: u8) -> &mut Self {
+        self.api_version = version;
+        self
+    }
+
+    /// Set whether message events are passed to [`EventHandler::message`]
+    /// when a framework is set, or only to the framework.
+    ///
+    /// By default, this is `true`, and the event handler receives every
+    /// message, including commands handled by the framework.
+    ///
+    /// [`EventHandler::message`]: trait.EventHandler.html#method.message
+    #[cfg(feature = "framework")]
+    pub fn message_passthrough(&mut self, message_passthrough: bool) -> &mut Self {
+        self.message_passthrough = message_passthrough;
+        self
+    }
+
+    /// Add a hook to run after the cache has been updated with an event.
+    ///
+    /// Hooks run in the order they were added. Refer to [`CacheUpdateHook`]
+    /// for more information.
+    ///
+    /// [`CacheUpdateHook`]: ../cache/trait.CacheUpdateHook.html
     #[cfg(feature = "cache")]
-    pub fn cache_update_timeout(&mut self, duration: Duration) -> &mut Self {
-        self.timeout = Some(duration);
+    pub fn cache_update_hook<H>(&mut self, hook: H) -> &mut Self
+    where
+        H: CacheUpdateHook + 'static,
+    {
+        self.cache_update_hooks.push(Arc::new(hook));
         self
     }
 
-    /// Set whether the library should subscribe for listening to presence and typing events.
+    /// Set whether [`EventHandler`] methods wait for the initial set of
+    /// guilds to be received before running.
     ///
-    /// By default, this is `true`.
-    pub fn guild_subscriptions(&mut self, guild_subscriptions: bool) -> &mut Self {
-        self.guild_subscriptions = guild_subscriptions;
+    /// While guilds are streamed in on startup, the cache is still updated
+    /// with every event as it arrives, but the handler methods are held back
+    /// until [`Cache::is_startup_complete`] is `true`, so that they never
+    /// observe a partially filled cache. The framework and the
+    /// [`RawEventHandler`] are not affected.
+    ///
+    /// By default, this is `false`.
+    ///
+    /// [`Cache::is_startup_complete`]: ../cache/struct.Cache.html#method.is_startup_complete
+    /// [`EventHandler`]: trait.EventHandler.html
+    /// [`RawEventHandler`]: trait.RawEventHandler.html
+    #[cfg(feature = "cache")]
+    pub fn defer_until_startup(&mut self, defer_until_startup: bool) -> &mut Self {
+        self.defer_until_startup = defer_until_startup;
+        self
+    }
+
+    /// Set how many recent events each shard remembers in order to drop
+    /// duplicates of them.
+    ///
+    /// When a shard resumes its session, Discord replays the events it
+    /// missed, which may include events that were already dispatched before
+    /// the connection was lost. With this set, a shard keeps the type, Id
+    /// and edit time of the last `capacity` message, member join, channel and
+    /// role creation events it dispatched, and silently drops any event
+    /// matching one of them.
+    ///
+    /// By default, this is `0`, which disables de-duplication.
+    pub fn event_dedup_capacity(&mut self, capacity: usize) -> &mut Self {
+        self.event_dedup_capacity = capacity;
         self
     }
 }
@@ -64,9 +189,19 @@ impl Default for Extras {
         Extras {
             event_handler: None,
             raw_event_handler: None,
-            #[cfg(feature = "cache")]
-            timeout: None,
             guild_subscriptions: true,
+            ws_proxy: None,
+            connection_timeouts: ConnectionTimeouts::default(),
+            http_client: None,
+            http_timeouts: HttpTimeouts::default(),
+            api_version: constants::API_VERSION,
+            #[cfg(feature = "framework")]
+            message_passthrough: true,
+            #[cfg(feature = "cache")]
+            cache_update_hooks: Vec::new(),
+            #[cfg(feature = "cache")]
+            defer_until_startup: false,
+            event_dedup_capacity: 0,
         }
     }
 }
@@ -83,8 +218,23 @@ impl fmt::Debug for Extras {
 
         ds.field("event_handler", &EventHandler);
         ds.field("raw_event_handler", &RawEventHandler);
+        ds.field("guild_subscriptions", &self.guild_subscriptions);
+        ds.field("ws_proxy", &self.ws_proxy);
+        ds.field("connection_timeouts", &self.connection_timeouts);
+        ds.field("http_client", &self.http_client);
+        ds.field("http_timeouts", &self.http_timeouts);
+        ds.field("api_version", &self.api_version);
+
+        #[cfg(feature = "framework")]
+        ds.field("message_passthrough", &self.message_passthrough);
+
+        #[cfg(feature = "cache")]
+        ds.field("cache_update_hooks", &self.cache_update_hooks.len());
+
         #[cfg(feature = "cache")]
-        ds.field("cache_update_timeout", &self.timeout);
+        ds.field("defer_until_startup", &self.defer_until_startup);
+
+        ds.field("event_dedup_capacity", &self.event_dedup_capacity);
 
         ds.finish()
     }
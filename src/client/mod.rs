@@ -36,10 +36,7 @@ pub use self::{
 pub use crate::CacheAndHttp;
 
 #[cfg(feature = "cache")]
-pub use crate::cache::{Cache, CacheRwLock};
-
-#[cfg(feature = "cache")]
-use std::time::Duration;
+pub use crate::cache::Cache;
 
 use self::bridge::gateway::{ShardManager, ShardManagerMonitor, ShardManagerOptions};
 use crate::internal::prelude::*;
@@ -47,8 +44,12 @@ use crate::internal::AsyncRwLock;
 use futures::lock::Mutex;
 use log::{debug, error, info};
 use std::sync::Arc;
+use std::time::Duration;
 use typemap::ShareMap;
 
+#[cfg(feature = "cache")]
+use std::sync::atomic::Ordering;
+
 #[cfg(feature = "voice")]
 use self::bridge::voice::ClientVoiceManager;
 #[cfg(feature = "framework")]
@@ -274,7 +275,8 @@ pub struct Client {
     /// tokio::spawn(async move {
     ///     tokio::time::sleep(Duration::from_secs(60)).await;
     ///
-    ///     shard_manager.lock().await.shutdown_all();
+    ///     let shutdown = shard_manager.lock().await.shutdown_all();
+    ///     shutdown.await;
     ///
     ///     println!("Shutdown shard manager!");
     /// });
@@ -374,46 +376,12 @@ impl Client {
         .await
     }
 
-    /// Creates a Client for a bot user and sets a cache update timeout.
-    /// If set to some duration, updating the cache will try to claim a
-    /// write-lock for given duration and skip received event but also
-    /// issue a deadlock-warning upon failure.
-    /// If `duration` is set to `None`, updating the cache will try to claim
-    /// a write-lock until success and potentially deadlock.
-    #[cfg(feature = "cache")]
-    #[deprecated(since = "0.8.0", note = "Replaced by `new_with_extras`.")]
-    pub async fn new_with_cache_update_timeout<H>(
-        token: impl AsRef<str>,
-        handler: H,
-        duration: Option<Duration>,
-    ) -> Result<Self>
-    where
-        H: EventHandler + 'static,
-    {
-        Self::new_with_extras(token, |e| {
-            e.event_handler(handler);
-
-            if let Some(duration) = duration {
-                e.cache_update_timeout(duration);
-            }
-
-            e
-        })
-        .await
-    }
-
     /// Creates a client with extra configuration.
     pub async fn new_with_extras(
         token: impl AsRef<str>,
         f: impl FnOnce(&mut Extras) -> &mut Extras,
     ) -> Result<Self> {
-        let token = token.as_ref().trim();
-
-        let token = if token.starts_with("Bot ") {
-            token.to_string()
-        } else {
-            format!("Bot {}", token)
-        };
+        let token = crate::bot_token(token.as_ref());
 
         let mut extras = Extras::default();
 
@@ -422,12 +390,26 @@ impl Client {
         let Extras {
             event_handler,
             raw_event_handler,
-            #[cfg(feature = "cache")]
-            timeout,
             guild_subscriptions,
+            ws_proxy,
+            connection_timeouts,
+            http_client,
+            http_timeouts,
+            api_version,
+            #[cfg(feature = "framework")]
+            message_passthrough,
+            #[cfg(feature = "cache")]
+            cache_update_hooks,
+            #[cfg(feature = "cache")]
+            defer_until_startup,
+            event_dedup_capacity,
         } = extras;
 
-        let http = Http::new_with_token(&token);
+        let http = match http_client {
+            Some(client) => Http::new_with_client(client, &token),
+            None => Http::new_with_timeouts(&token, http_timeouts),
+        };
+        http.set_api_version(api_version);
 
         let url = Arc::new(Mutex::new(http.get_gateway().await?.url));
         let data = Arc::new(AsyncRwLock::new(ShareMap::custom()));
@@ -439,9 +421,7 @@ impl Client {
 
         let cache_and_http = Arc::new(CacheAndHttp {
             #[cfg(feature = "cache")]
-            cache: CacheRwLock::default(),
-            #[cfg(feature = "cache")]
-            update_cache_timeout: timeout,
+            cache: Arc::new(Cache::with_update_hooks(cache_update_hooks)),
             http: Arc::new(http),
         });
 
@@ -452,6 +432,10 @@ impl Client {
                 raw_event_handler: &raw_event_handler,
                 #[cfg(feature = "framework")]
                 framework: &framework,
+                #[cfg(feature = "framework")]
+                message_passthrough,
+                #[cfg(feature = "cache")]
+                defer_until_startup,
                 shard_index: 0,
                 shard_init: 0,
                 shard_total: 0,
@@ -460,6 +444,9 @@ impl Client {
                 ws_url: &url,
                 cache_and_http: &cache_and_http,
                 guild_subscriptions,
+                ws_proxy: &ws_proxy,
+                connection_timeouts,
+                event_dedup_capacity,
             })
             .await
         };
@@ -479,7 +466,7 @@ impl Client {
 
     /// Sets a framework to be used with the client. All message events will be
     /// passed through the framework _after_ being passed to the [`message`]
-    /// event handler.
+    /// event handler, unless disabled with [`Extras::message_passthrough`].
     ///
     /// See the [framework module-level documentation][framework docs] for more
     /// information on usage.
@@ -592,6 +579,7 @@ impl Client {
     /// Refer to the documentation for the `framework` module for more in-depth
     /// information.
     ///
+    /// [`Extras::message_passthrough`]: struct.Extras.html#method.message_passthrough
     /// [`message`]: trait.EventHandler.html#method.message
     /// [framework docs]: ../framework/index.html
     #[cfg(feature = "framework")]
@@ -688,21 +676,40 @@ impl Client {
     /// # }
     /// ```
     ///
+    /// Shards are started in batches of the `max_concurrency` of the bot's
+    /// [`SessionStartLimit`].
+    ///
     /// # Errors
     ///
+    /// Returns a [`ClientError::SessionStartLimitReached`] if fewer sessions
+    /// can be started than the number of shards.
+    ///
     /// Returns a [`ClientError::Shutdown`] when all shards have shutdown due to
     /// an error.
     ///
+    /// [`ClientError::SessionStartLimitReached`]: enum.ClientError.html#variant.SessionStartLimitReached
     /// [`ClientError::Shutdown`]: enum.ClientError.html#variant.Shutdown
+    /// [`SessionStartLimit`]: ../model/gateway/struct.SessionStartLimit.html
     /// [gateway docs]: ../gateway/index.html#sharding
     pub async fn start_autosharded(&mut self) -> Result<()> {
-        let (x, y) = {
-            let res = self.cache_and_http.http.get_bot_gateway().await?;
+        let gateway = self.cache_and_http.http.get_bot_gateway().await?;
+        let limit = gateway.session_start_limit;
 
-            (res.shards as u64 - 1, res.shards as u64)
-        };
+        if limit.remaining < gateway.shards {
+            let reset_after = Duration::from_millis(limit.reset_after);
+
+            return Err(Error::Client(ClientError::SessionStartLimitReached(
+                reset_after,
+            )));
+        }
 
-        self.start_connection([0, x, y]).await
+        self.shard_manager
+            .lock()
+            .await
+            .set_max_concurrency(limit.max_concurrency);
+
+        self.start_connection([0, gateway.shards - 1, gateway.shards])
+            .await
     }
 
     /// Establish a sharded connection and start listening for events.
@@ -948,6 +955,12 @@ impl Client {
 
             let init = shard_data[1] - shard_data[0] + 1;
 
+            #[cfg(feature = "cache")]
+            self.cache_and_http
+                .cache
+                .startup_shards
+                .store(init, Ordering::Relaxed);
+
             manager.set_shards(shard_data[0], init, shard_data[2]);
 
             debug!(
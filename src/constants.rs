@@ -2,6 +2,12 @@
 
 /// The maximum length of the textual size of an embed.
 pub const EMBED_MAX_LENGTH: u16 = 6000;
+/// The version of Discord's REST API used by default, which can be changed via
+/// [`Extras::api_version`] or [`Http::set_api_version`].
+///
+/// [`Extras::api_version`]: ../client/struct.Extras.html#method.api_version
+/// [`Http::set_api_version`]: ../http/client/struct.Http.html#method.set_api_version
+pub const API_VERSION: u8 = 6;
 /// The gateway version used by the library. The gateway URI is retrieved via
 /// the REST API.
 pub const GATEWAY_VERSION: u8 = 6;
@@ -11,6 +17,11 @@ pub const VOICE_GATEWAY_VERSION: u8 = 3;
 pub const LARGE_THRESHOLD: u8 = 250;
 /// The maximum unicode code points allowed within a message by Discord.
 pub const MESSAGE_CODE_LIMIT: u16 = 2000;
+/// The maximum total size in bytes of the files uploaded with a message, in
+/// guilds without premium subscriptions.
+pub const UPLOAD_MAX_SIZE: u64 = 8 * 1024 * 1024;
+/// The locale Discord falls back to when neither a user nor a guild has one.
+pub const DEFAULT_LOCALE: &str = "en-US";
 /// The [UserAgent] sent along with every request.
 ///
 /// [UserAgent]: ../../reqwest/header/constant.USER_AGENT.html
@@ -63,131 +74,67 @@ pub static JOIN_MESSAGES: &[&str] = &[
     "Roses are red, violets are blue, $user joined this server with you",
 ];
 
-/// Enum to map gateway opcodes.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
-#[non_exhaustive]
-pub enum OpCode {
-    /// Dispatches an event.
-    Event = 0,
-    /// Used for ping checking.
-    Heartbeat = 1,
-    /// Used for client handshake.
-    Identify = 2,
-    /// Used to update the client status.
-    StatusUpdate = 3,
-    /// Used to join/move/leave voice channels.
-    VoiceStateUpdate = 4,
-    /// Used for voice ping checking.
-    VoiceServerPing = 5,
-    /// Used to resume a closed connection.
-    Resume = 6,
-    /// Used to tell clients to reconnect to the gateway.
-    Reconnect = 7,
-    /// Used to request guild members.
-    GetGuildMembers = 8,
-    /// Used to notify clients that they have an invalid session Id.
-    InvalidSession = 9,
-    /// Sent immediately after connection, contains heartbeat + server info.
-    Hello = 10,
-    /// Sent immediately following a client heartbeat that was received.
-    HeartbeatAck = 11,
-}
-
-enum_number!(OpCode {
-    Event,
-    Heartbeat,
-    Identify,
-    StatusUpdate,
-    VoiceStateUpdate,
-    VoiceServerPing,
-    Resume,
-    Reconnect,
-    GetGuildMembers,
-    InvalidSession,
-    Hello,
-    HeartbeatAck,
-});
-
-impl OpCode {
-    pub fn num(self) -> u64 {
-        match self {
-            OpCode::Event => 0,
-            OpCode::Heartbeat => 1,
-            OpCode::Identify => 2,
-            OpCode::StatusUpdate => 3,
-            OpCode::VoiceStateUpdate => 4,
-            OpCode::VoiceServerPing => 5,
-            OpCode::Resume => 6,
-            OpCode::Reconnect => 7,
-            OpCode::GetGuildMembers => 8,
-            OpCode::InvalidSession => 9,
-            OpCode::Hello => 10,
-            OpCode::HeartbeatAck => 11,
-        }
+enum_number! {
+    /// Enum to map gateway opcodes.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum OpCode {
+        /// Dispatches an event.
+        Event = 0,
+        /// Used for ping checking.
+        Heartbeat = 1,
+        /// Used for client handshake.
+        Identify = 2,
+        /// Used to update the client status.
+        StatusUpdate = 3,
+        /// Used to join/move/leave voice channels.
+        VoiceStateUpdate = 4,
+        /// Used for voice ping checking.
+        VoiceServerPing = 5,
+        /// Used to resume a closed connection.
+        Resume = 6,
+        /// Used to tell clients to reconnect to the gateway.
+        Reconnect = 7,
+        /// Used to request guild members.
+        GetGuildMembers = 8,
+        /// Used to notify clients that they have an invalid session Id.
+        InvalidSession = 9,
+        /// Sent immediately after connection, contains heartbeat + server info.
+        Hello = 10,
+        /// Sent immediately following a client heartbeat that was received.
+        HeartbeatAck = 11,
     }
 }
 
-/// Enum to map voice opcodes.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
-#[non_exhaustive]
-pub enum VoiceOpCode {
-    /// Used to begin a voice websocket connection.
-    Identify = 0,
-    /// Used to select the voice protocol.
-    SelectProtocol = 1,
-    /// Used to complete the websocket handshake.
-    Ready = 2,
-    /// Used to keep the websocket connection alive.
-    Heartbeat = 3,
-    /// Used to describe the session.
-    SessionDescription = 4,
-    /// Used to indicate which users are speaking.
-    Speaking = 5,
-    /// Heartbeat ACK, received by the client to show the server's receipt of a heartbeat.
-    HeartbeatAck = 6,
-    /// Sent after a disconnect to attempt to resume a session.
-    Resume = 7,
-    /// Used to determine how often the client must send a heartbeat.
-    Hello = 8,
-    /// Sent by the server if a session coulkd successfully be resumed.
-    Resumed = 9,
-    /// Message indicating that another user has connected to the voice channel.
-    ClientConnect = 12,
-    /// Message indicating that another user has disconnected from the voice channel.
-    ClientDisconnect = 13,
-}
-
-enum_number!(VoiceOpCode {
-    Identify,
-    SelectProtocol,
-    Ready,
-    Heartbeat,
-    SessionDescription,
-    Speaking,
-    HeartbeatAck,
-    Resume,
-    Hello,
-    Resumed,
-    ClientConnect,
-    ClientDisconnect,
-});
-
-impl VoiceOpCode {
-    pub fn num(self) -> u64 {
-        match self {
-            VoiceOpCode::Identify => 0,
-            VoiceOpCode::SelectProtocol => 1,
-            VoiceOpCode::Ready => 2,
-            VoiceOpCode::Heartbeat => 3,
-            VoiceOpCode::SessionDescription => 4,
-            VoiceOpCode::Speaking => 5,
-            VoiceOpCode::HeartbeatAck => 6,
-            VoiceOpCode::Resume => 7,
-            VoiceOpCode::Hello => 8,
-            VoiceOpCode::Resumed => 9,
-            VoiceOpCode::ClientConnect => 12,
-            VoiceOpCode::ClientDisconnect => 13,
-        }
+enum_number! {
+    /// Enum to map voice opcodes.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum VoiceOpCode {
+        /// Used to begin a voice websocket connection.
+        Identify = 0,
+        /// Used to select the voice protocol.
+        SelectProtocol = 1,
+        /// Used to complete the websocket handshake.
+        Ready = 2,
+        /// Used to keep the websocket connection alive.
+        Heartbeat = 3,
+        /// Used to describe the session.
+        SessionDescription = 4,
+        /// Used to indicate which users are speaking.
+        Speaking = 5,
+        /// Heartbeat ACK, received by the client to show the server's receipt of a heartbeat.
+        HeartbeatAck = 6,
+        /// Sent after a disconnect to attempt to resume a session.
+        Resume = 7,
+        /// Used to determine how often the client must send a heartbeat.
+        Hello = 8,
+        /// Sent by the server if a session coulkd successfully be resumed.
+        Resumed = 9,
+        /// Message indicating that another user has connected to the voice channel.
+        ClientConnect = 12,
+        /// Message indicating that another user has disconnected from the voice channel.
+        ClientDisconnect = 13,
     }
 }
 
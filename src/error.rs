@@ -20,8 +20,12 @@ use crate::internal::ws_impl::RustlsError;
 use crate::voice::VoiceError;
 #[cfg(feature = "voice")]
 use audiopus::Error as OpusError;
+#[cfg(feature = "cache_redis")]
+use redis::RedisError;
 #[cfg(feature = "http")]
 use reqwest::{header::InvalidHeaderValue, Error as ReqwestError};
+#[cfg(feature = "simd_json")]
+use simd_json::Error as SimdJsonError;
 #[cfg(feature = "gateway")]
 use tungstenite::error::Error as TungsteniteError;
 
@@ -94,6 +98,13 @@ pub enum Error {
     /// An error occuring in rustls
     #[cfg(all(feature = "gateway", not(feature = "native_tls_backend")))]
     Rustls(RustlsError),
+    /// An error from the `redis` crate.
+    #[cfg(feature = "cache_redis")]
+    Redis(RedisError),
+    /// An error from the `simd-json` crate, used instead of `serde_json` to
+    /// parse payloads when the `simd_json` feature is enabled.
+    #[cfg(feature = "simd_json")]
+    SimdJson(SimdJsonError),
     /// An error from the `tungstenite` crate.
     #[cfg(feature = "gateway")]
     Tungstenite(TungsteniteError),
@@ -165,6 +176,20 @@ impl From<RustlsError> for Error {
     }
 }
 
+#[cfg(feature = "cache_redis")]
+impl From<RedisError> for Error {
+    fn from(e: RedisError) -> Error {
+        Error::Redis(e)
+    }
+}
+
+#[cfg(feature = "simd_json")]
+impl From<SimdJsonError> for Error {
+    fn from(e: SimdJsonError) -> Error {
+        Error::SimdJson(e)
+    }
+}
+
 #[cfg(feature = "gateway")]
 impl From<TungsteniteError> for Error {
     fn from(e: TungsteniteError) -> Error {
@@ -189,7 +214,7 @@ impl From<InvalidHeaderValue> for Error {
 #[cfg(feature = "http")]
 impl From<ReqwestError> for Error {
     fn from(e: ReqwestError) -> Error {
-        HttpError::Request(e).into()
+        HttpError::from(e).into()
     }
 }
 
@@ -214,6 +239,10 @@ impl Display for Error {
             Error::Opus(inner) => fmt::Display::fmt(&inner, f),
             #[cfg(all(feature = "gateway", not(feature = "native_tls_backend")))]
             Error::Rustls(inner) => fmt::Display::fmt(&inner, f),
+            #[cfg(feature = "cache_redis")]
+            Error::Redis(inner) => fmt::Display::fmt(&inner, f),
+            #[cfg(feature = "simd_json")]
+            Error::SimdJson(inner) => fmt::Display::fmt(&inner, f),
             #[cfg(feature = "gateway")]
             Error::Tungstenite(inner) => fmt::Display::fmt(&inner, f),
             #[cfg(feature = "voice")]
@@ -240,6 +269,10 @@ impl StdError for Error {
             Error::Opus(inner) => Some(inner),
             #[cfg(all(feature = "gateway", not(feature = "native_tls_backend")))]
             Error::Rustls(inner) => Some(inner),
+            #[cfg(feature = "cache_redis")]
+            Error::Redis(inner) => Some(inner),
+            #[cfg(feature = "simd_json")]
+            Error::SimdJson(inner) => Some(inner),
             #[cfg(feature = "gateway")]
             Error::Tungstenite(inner) => Some(inner),
             _ => None,
@@ -180,6 +180,32 @@ fn remove_quotes(s: &str) -> &str {
     s
 }
 
+/// An argument that [`Args::single`] or [`Args::single_quoted`] failed to
+/// parse, kept so that the framework can explain the failure if the command
+/// returns an error.
+///
+/// [`Args::single`]: struct.Args.html#method.single
+/// [`Args::single_quoted`]: struct.Args.html#method.single_quoted
+#[derive(Debug, Clone)]
+pub(crate) struct ArgFailure {
+    /// The name of the type the argument was parsed as.
+    pub(crate) expected: &'static str,
+    /// The argument, or `None` if there were no arguments left.
+    pub(crate) got: Option<String>,
+}
+
+/// Returns the name of a type without its module path, such as `UserId` for
+/// `serenity::model::id::UserId`.
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let path_end = name.find('<').unwrap_or(name.len());
+
+    match name[..path_end].rfind("::") {
+        Some(index) => &name[index + 2..],
+        None => name,
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum State {
     None,
@@ -283,6 +309,7 @@ pub struct Args {
     args: Vec<Token>,
     offset: usize,
     state: Arc<RwLock<State>>,
+    failure: Arc<RwLock<Option<ArgFailure>>>,
 }
 
 impl Args {
@@ -351,6 +378,7 @@ impl Args {
             message: message.to_string(),
             offset: 0,
             state: Arc::new(RwLock::new(State::None)),
+            failure: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -586,7 +614,9 @@ impl Args {
     /// [`next`]: #method.next
     #[inline]
     pub fn single<T: FromStr>(&mut self) -> Result<T, T::Err> {
-        let p = self.parse::<T>()?;
+        let p = self.parse::<T>();
+        self.record_failure(&p);
+        let p = p?;
         self.advance();
         Ok(p)
     }
@@ -609,7 +639,9 @@ impl Args {
     ///
     #[inline]
     pub fn single_quoted<T: FromStr>(&mut self) -> Result<T, T::Err> {
-        let p = self.quoted().parse::<T>()?;
+        let p = self.quoted().parse::<T>();
+        self.record_failure(&p);
+        let p = p?;
         self.advance();
         Ok(p)
     }
@@ -838,6 +870,30 @@ impl Args {
     fn update_state(&self, state: State) {
         *self.state.write().unwrap() = state;
     }
+
+    /// Remembers the current argument if it failed to parse, or forgets the
+    /// previous failure if it succeeded.
+    fn record_failure<T: FromStr>(&self, result: &Result<T, T::Err>) {
+        let failure = result.as_ref().err().map(|_| ArgFailure {
+            expected: short_type_name::<T>(),
+            got: if self.is_empty() {
+                None
+            } else {
+                Some(self.slice().to_string())
+            },
+        });
+
+        *self.failure.write().unwrap() = failure;
+    }
+
+    /// Returns a handle to the last failure of [`single`] or
+    /// [`single_quoted`], shared by all clones of these arguments.
+    ///
+    /// [`single`]: #method.single
+    /// [`single_quoted`]: #method.single_quoted
+    pub(crate) fn failure(&self) -> Arc<RwLock<Option<ArgFailure>>> {
+        Arc::clone(&self.failure)
+    }
 }
 
 /// Parse each argument individually, as an iterator.
@@ -925,3 +981,34 @@ impl<'a> Iterator for RawArguments<'a> {
         Some(s)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Args, Delimiter};
+    use crate::model::id::UserId;
+
+    #[test]
+    fn test_failure() {
+        let mut args = Args::new("4 two", &[Delimiter::Single(' ')]);
+        let failure = args.failure();
+
+        assert_eq!(args.single::<u32>().unwrap(), 4);
+        assert!(args.single::<UserId>().is_err());
+        {
+            let failure = failure.read().unwrap();
+            let failure = failure.as_ref().unwrap();
+            assert_eq!(failure.expected, "UserId");
+            assert_eq!(failure.got.as_deref(), Some("two"));
+        }
+
+        // A successful parse forgets the failure.
+        assert_eq!(args.single::<String>().unwrap(), "two");
+        assert!(failure.read().unwrap().is_none());
+
+        assert!(args.single_quoted::<u8>().is_err());
+        let failure = failure.read().unwrap();
+        let failure = failure.as_ref().unwrap();
+        assert_eq!(failure.expected, "u8");
+        assert!(failure.got.is_none());
+    }
+}
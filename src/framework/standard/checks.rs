@@ -0,0 +1,79 @@
+//! Ready-made [`Check`]s for common use cases.
+//!
+//! [`Check`]: ../struct.Check.html
+
+use super::{Args, AsyncCheckFunction, Check, CheckResult, CommandOptions};
+use crate::client::Context;
+use crate::model::channel::Message;
+use std::{future::Future, pin::Pin, time::Duration};
+
+/// The function of the check returned by [`min_account_age`], which only
+/// lets users whose account is at least `SECS` seconds old pass.
+///
+/// [`min_account_age`]: fn.min_account_age.html
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MinAccountAge<const SECS: u64>;
+
+impl<const SECS: u64> AsyncCheckFunction for MinAccountAge<SECS> {
+    fn check<'life0, 'life1, 'life2, 'life3, 'async_trait>(
+        &'life0 self,
+        _: &'life1 mut Context,
+        msg: &'life2 Message,
+        _: &'life3 mut Args,
+        _: &'static CommandOptions,
+    ) -> Pin<Box<dyn Future<Output = CheckResult> + Send + 'async_trait>>
+    where
+        'life0: 'async_trait,
+        'life1: 'async_trait,
+        'life2: 'async_trait,
+        'life3: 'async_trait,
+        Self: 'async_trait,
+    {
+        let result = if msg.author.created_at().elapsed() >= Duration::from_secs(SECS) {
+            CheckResult::Success
+        } else {
+            CheckResult::new_user("Your account is too new to use this command.")
+        };
+
+        Box::pin(async move { result })
+    }
+}
+
+/// Creates a check which only lets users whose account is at least `SECS`
+/// seconds old pass, such as to keep freshly created accounts of a raid from
+/// using commands.
+///
+/// The age is derived from the user's Id, so no request is made.
+///
+/// As checks are statics, the check must be assigned to a static named after
+/// the identifier passed to `#[checks]`, in upper case and suffixed with
+/// `_CHECK`.
+///
+/// # Examples
+///
+/// Only allow accounts which are at least a week old to use a command:
+///
+/// ```rust,no_run
+/// use serenity::framework::standard::{checks, macros::command, Check, CommandResult};
+/// use serenity::model::channel::Message;
+/// use serenity::prelude::*;
+///
+/// static MINACCOUNTAGE_CHECK: Check = checks::min_account_age::<{ 7 * 24 * 60 * 60 }>();
+///
+/// #[command]
+/// #[checks(MinAccountAge)]
+/// async fn report(ctx: &mut Context, msg: &Message) -> CommandResult {
+///     msg.reply(&*ctx, "Thanks for the report!").await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub const fn min_account_age<const SECS: u64>() -> Check {
+    Check {
+        name: "min_account_age",
+        function: &MinAccountAge::<SECS>,
+        check_in_help: true,
+        display_in_help: true,
+        cache_result: None,
+    }
+}
@@ -1,13 +1,33 @@
-use super::Delimiter;
+use super::{CommandError, Delimiter};
 use crate::client::Context;
 use crate::model::{
     channel::Message,
     id::{ChannelId, GuildId, UserId},
 };
 use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
 
 type DynamicPrefixHook = dyn Fn(&mut Context, &Message) -> Option<String> + Send + Sync + 'static;
 
+pub(crate) type ErrorReplyFormatter = dyn Fn(&str, &CommandError) -> String + Send + Sync + 'static;
+
+/// How the framework tells the user a command failed, when configured to
+/// through [`Configuration::reply_on_error`].
+///
+/// [`Configuration::reply_on_error`]: struct.Configuration.html#method.reply_on_error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorReplyStyle {
+    /// Sends the error to the channel the command was invoked in.
+    Message,
+    /// Replies to the invoking user, mentioning them.
+    Reply,
+    /// Sends the error to the channel the command was invoked in, and deletes
+    /// it again after the given time.
+    SelfDeleting(Duration),
+}
+
 /// A configuration struct for deciding whether the framework
 /// should allow optional whitespace between prefixes, group prefixes and command names.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -129,6 +149,16 @@ pub struct Configuration {
     pub delimiters: Vec<Delimiter>,
     #[doc(hidden)]
     pub case_insensitive: bool,
+    #[doc(hidden)]
+    pub command_timeout: Option<Duration>,
+    #[doc(hidden)]
+    pub timeout_message: Option<String>,
+    #[doc(hidden)]
+    pub usage_on_invalid_arguments: bool,
+    #[doc(hidden)]
+    pub error_reply: Option<ErrorReplyStyle>,
+    #[doc(hidden)]
+    pub error_reply_formatter: Option<Arc<ErrorReplyFormatter>>,
 }
 
 impl Configuration {
@@ -631,6 +661,139 @@ impl Configuration {
 
         self
     }
+
+    /// Sets the maximum time a command may run for before it is cancelled.
+    ///
+    /// If a command exceeds the timeout, its future is dropped and the
+    /// [`after`] hook is called with a [`CommandError::Timeout`]. This
+    /// protects the bot from a hung command (e.g. a stuck HTTP request) being
+    /// kept alive forever.
+    ///
+    /// **Note**: Defaults to no timeout.
+    ///
+    /// # Examples
+    ///
+    /// Cancel commands that take longer than 30 seconds:
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::StandardFramework;
+    /// use std::time::Duration;
+    ///
+    /// let framework = StandardFramework::new()
+    ///     .configure(|c| c.command_timeout(Duration::from_secs(30)));
+    /// ```
+    ///
+    /// [`after`]: struct.StandardFramework.html#method.after
+    /// [`CommandError::Timeout`]: enum.CommandError.html#variant.Timeout
+    pub fn command_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.command_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Sets a message to send to the invoking channel when a command exceeds
+    /// the [`command_timeout`].
+    ///
+    /// **Note**: Defaults to sending no message.
+    ///
+    /// [`command_timeout`]: #method.command_timeout
+    pub fn timeout_message(&mut self, message: &str) -> &mut Self {
+        self.timeout_message = Some(message.to_string());
+
+        self
+    }
+
+    /// Whether to reply with the command's usage when it returns an error
+    /// after [`Args::single`] or [`Args::single_quoted`] failed to parse an
+    /// argument.
+    ///
+    /// The reply names the expected type of the argument and includes the
+    /// command's `#[usage]` and first `#[example]`, if it has them. The
+    /// failure is also passed to the [`on_dispatch_error`] hook as a
+    /// [`DispatchError::InvalidArguments`] either way.
+    ///
+    /// **Note**: Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// let framework = StandardFramework::new()
+    ///     .configure(|c| c.usage_on_invalid_arguments(true));
+    /// ```
+    ///
+    /// [`Args::single`]: struct.Args.html#method.single
+    /// [`Args::single_quoted`]: struct.Args.html#method.single_quoted
+    /// [`DispatchError::InvalidArguments`]: enum.DispatchError.html#variant.InvalidArguments
+    /// [`on_dispatch_error`]: struct.StandardFramework.html#method.on_dispatch_error
+    pub fn usage_on_invalid_arguments(&mut self, send: bool) -> &mut Self {
+        self.usage_on_invalid_arguments = send;
+
+        self
+    }
+
+    /// Sets how to tell the user a command failed when it returns an error,
+    /// so that each command does not have to do so itself.
+    ///
+    /// The error is formatted by the [`error_reply_formatter`], which by
+    /// default sends the error's message as-is. Commands which timed out are
+    /// not replied to, as the [`timeout_message`] covers them, nor are those
+    /// which failed to parse an argument while [`usage_on_invalid_arguments`]
+    /// is enabled.
+    ///
+    /// **Note**: Defaults to sending no reply.
+    ///
+    /// # Examples
+    ///
+    /// Reply to the user with the error, deleting the reply after 10 seconds:
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::standard::{ErrorReplyStyle, StandardFramework};
+    /// use std::time::Duration;
+    ///
+    /// let framework = StandardFramework::new().configure(|c| {
+    ///     c.reply_on_error(ErrorReplyStyle::SelfDeleting(Duration::from_secs(10)))
+    /// });
+    /// ```
+    ///
+    /// [`error_reply_formatter`]: #method.error_reply_formatter
+    /// [`timeout_message`]: #method.timeout_message
+    /// [`usage_on_invalid_arguments`]: #method.usage_on_invalid_arguments
+    pub fn reply_on_error(&mut self, style: ErrorReplyStyle) -> &mut Self {
+        self.error_reply = Some(style);
+
+        self
+    }
+
+    /// Sets the function formatting the reply sent when a command returns an
+    /// error, given the name of the command and the error.
+    ///
+    /// This only has an effect once [`reply_on_error`] is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::standard::{CommandError, ErrorReplyStyle, StandardFramework};
+    ///
+    /// let framework = StandardFramework::new().configure(|c| {
+    ///     c.reply_on_error(ErrorReplyStyle::Reply)
+    ///         .error_reply_formatter(|name, error| match error {
+    ///             CommandError::Message(why) => format!("`{}` failed: {}", name, why),
+    ///             _ => format!("`{}` failed.", name),
+    ///         })
+    /// });
+    /// ```
+    ///
+    /// [`reply_on_error`]: #method.reply_on_error
+    pub fn error_reply_formatter<F>(&mut self, formatter: F) -> &mut Self
+    where
+        F: Fn(&str, &CommandError) -> String + Send + Sync + 'static,
+    {
+        self.error_reply_formatter = Some(Arc::new(formatter));
+
+        self
+    }
 }
 
 impl Default for Configuration {
@@ -652,6 +815,11 @@ impl Default for Configuration {
     /// - **on_mention** to `false`
     /// - **owners** to an empty HashSet
     /// - **prefix** to an empty vector
+    /// - **command_timeout** to `None`
+    /// - **timeout_message** to `None`
+    /// - **usage_on_invalid_arguments** to `false`
+    /// - **error_reply** to `None`
+    /// - **error_reply_formatter** to `None`
     fn default() -> Configuration {
         Configuration {
             allow_dm: true,
@@ -670,6 +838,11 @@ impl Default for Configuration {
             on_mention: None,
             owners: HashSet::default(),
             prefixes: vec![],
+            command_timeout: None,
+            timeout_message: None,
+            usage_on_invalid_arguments: false,
+            error_reply: None,
+            error_reply_formatter: None,
         }
     }
 }
@@ -60,12 +60,13 @@
 
 #[cfg(all(feature = "cache", feature = "http"))]
 use super::{
-    has_correct_permissions, has_correct_roles, structures::Command as InternalCommand, Args,
-    CheckResult, CommandGroup, CommandOptions, CommandResult, HelpBehaviour, HelpOptions, OnlyIn,
+    has_correct_permissions, has_correct_roles, has_permission_requirements,
+    structures::Command as InternalCommand, Args, CheckResult, CommandGroup, CommandOptions,
+    CommandResult, FetchedPermissions, HelpBehaviour, HelpOptions, OnlyIn,
 };
 #[cfg(all(feature = "cache", feature = "http"))]
 use crate::{
-    cache::CacheRwLock,
+    cache::Cache,
     client::Context,
     framework::standard::CommonOptions,
     http::Http,
@@ -275,22 +276,21 @@ pub(crate) fn levenshtein_distance(word_a: &str, word_b: &str) -> usize {
 /// Checks whether a user is member of required roles
 /// and given the required permissions.
 #[cfg(feature = "cache")]
-pub async fn has_all_requirements(
-    cache: impl AsRef<CacheRwLock>,
-    cmd: &CommandOptions,
-    msg: &Message,
-) -> bool {
-    if let Some(guild) = msg.guild(&cache).await {
-        let guild = guild.read().await;
+pub fn has_all_requirements(cache: impl AsRef<Cache>, cmd: &CommandOptions, msg: &Message) -> bool {
+    let cache = cache.as_ref();
 
+    if let Some(guild) = msg
+        .guild_id
+        .and_then(|guild_id| cache.guilds.get(&guild_id))
+    {
         if let Some(member) = guild.members.get(&msg.author.id) {
-            if let Ok(permissions) = member.permissions(&cache).await {
+            if let Ok(permissions) = member.permissions(cache) {
                 return if cmd.allowed_roles.is_empty() {
-                    permissions.administrator() || has_correct_permissions(&cache, &cmd, msg).await
+                    permissions.administrator() || has_correct_permissions(cache, &cmd, msg)
                 } else {
                     permissions.administrator()
-                        || (has_correct_roles(&cmd, &guild, member)
-                            && has_correct_permissions(&cache, &cmd, msg).await)
+                        || (has_correct_roles(&cmd, &guild.roles, member)
+                            && has_correct_permissions(cache, &cmd, msg))
                 };
             }
         }
@@ -341,11 +341,12 @@ fn find_any_command_matches(
 
 #[cfg(all(feature = "cache", feature = "http"))]
 async fn check_common_behaviour(
-    cache: impl AsRef<CacheRwLock>,
+    ctx: &Context,
     msg: &Message,
     options: &impl CommonOptions,
     owners: &HashSet<UserId>,
     help_options: &HelpOptions,
+    fetched: &FetchedPermissions,
 ) -> HelpBehaviour {
     if !options.help_available() {
         return HelpBehaviour::Hide;
@@ -365,18 +366,49 @@ async fn check_common_behaviour(
         return HelpBehaviour::Nothing;
     }
 
-    if !has_correct_permissions(&cache, options, msg).await {
-        return help_options.lacking_permissions;
+    let guild_id = match msg.guild_id {
+        Some(guild_id) => guild_id,
+        None if options.required_permissions().is_empty() => return HelpBehaviour::Nothing,
+        None => return help_options.lacking_permissions,
+    };
+
+    if !has_permission_requirements(options) {
+        return HelpBehaviour::Nothing;
     }
 
-    if let Some(guild) = msg.guild(&cache).await {
-        let guild = guild.read().await;
+    let cached = ctx.cache.guilds.get(&guild_id).map(|guild| {
+        let has_roles = match guild.members.get(&msg.author.id) {
+            Some(member) => has_correct_roles(options, &guild.roles, member),
+            None => true,
+        };
 
-        if let Some(member) = guild.members.get(&msg.author.id) {
-            if !has_correct_roles(options, &guild, &member) {
-                return help_options.lacking_role;
-            }
-        }
+        let permissions = guild.user_permissions_in(msg.channel_id, msg.author.id);
+
+        (
+            permissions.contains(*options.required_permissions()),
+            has_roles,
+        )
+    });
+
+    // Fall back to the REST API if the guild is missing from the cache, rather
+    // than hiding commands the author may be able to use.
+    let (has_permissions, has_roles) = match cached {
+        Some(cached) => cached,
+        None => match fetched.get(ctx, msg).await {
+            Some((guild, member, permissions)) => (
+                permissions.contains(*options.required_permissions()),
+                has_correct_roles(options, &guild.roles, member),
+            ),
+            None => return help_options.lacking_permissions,
+        },
+    };
+
+    if !has_permissions {
+        return help_options.lacking_permissions;
+    }
+
+    if !has_roles {
+        return help_options.lacking_role;
     }
 
     HelpBehaviour::Nothing
@@ -392,8 +424,9 @@ async fn check_command_behaviour(
     options: &'static CommandOptions,
     owners: &HashSet<UserId>,
     help_options: &HelpOptions,
+    fetched: &FetchedPermissions,
 ) -> HelpBehaviour {
-    let b = check_common_behaviour(&ctx, msg, &options, owners, help_options).await;
+    let b = check_common_behaviour(ctx, msg, &options, owners, help_options, fetched).await;
 
     if b == HelpBehaviour::Nothing {
         for check in options.checks {
@@ -424,13 +457,14 @@ async fn nested_group_command_search<'a>(
     help_options: &'a HelpOptions,
     similar_commands: &mut Vec<SuggestedCommandName>,
     owners: &HashSet<UserId>,
+    fetched: &FetchedPermissions,
 ) -> Result<CustomisedHelpData<'a>, ()> {
     for group in groups {
         let group = *group;
         let mut found: Option<&'static InternalCommand> = None;
 
         let group_behaviour =
-            check_common_behaviour(&ctx, msg, &group.options, &owners, &help_options).await;
+            check_common_behaviour(ctx, msg, &group.options, &owners, &help_options, fetched).await;
 
         match &group_behaviour {
             HelpBehaviour::Nothing => (),
@@ -455,8 +489,15 @@ async fn nested_group_command_search<'a>(
 
             if search_command_name_matched.is_some() {
                 if HelpBehaviour::Nothing
-                    == check_command_behaviour(ctx, msg, &command.options, &owners, &help_options)
-                        .await
+                    == check_command_behaviour(
+                        ctx,
+                        msg,
+                        &command.options,
+                        &owners,
+                        &help_options,
+                        fetched,
+                    )
+                    .await
                 {
                     found = Some(command);
                 } else {
@@ -479,6 +520,7 @@ async fn nested_group_command_search<'a>(
                             &command.options,
                             &owners,
                             &help_options,
+                            fetched,
                         )
                         .await
                 {
@@ -547,6 +589,7 @@ async fn nested_group_command_search<'a>(
             help_options,
             similar_commands,
             owners,
+            fetched,
         )
         .await
         {
@@ -568,6 +611,7 @@ async fn fetch_single_command<'a>(
     name: &str,
     help_options: &'a HelpOptions,
     owners: &HashSet<UserId>,
+    fetched: &FetchedPermissions,
 ) -> Result<CustomisedHelpData<'a>, Vec<SuggestedCommandName>> {
     let mut similar_commands: Vec<SuggestedCommandName> = Vec::new();
     let mut name = name.to_string();
@@ -580,6 +624,7 @@ async fn fetch_single_command<'a>(
         &help_options,
         &mut similar_commands,
         &owners,
+        fetched,
     )
     .await
     {
@@ -599,6 +644,7 @@ async fn fill_eligible_commands<'a>(
     group: &'a CommandGroup,
     to_fill: &mut GroupCommandsPair,
     highest_formatter: &mut HelpBehaviour,
+    fetched: &FetchedPermissions,
 ) {
     to_fill.name = group.name;
     to_fill.prefixes = group.options.prefixes.to_vec();
@@ -609,7 +655,8 @@ async fn fill_eligible_commands<'a>(
         } else {
             std::cmp::max(
                 *highest_formatter,
-                check_common_behaviour(&ctx, msg, &group.options, owners, help_options).await,
+                check_common_behaviour(ctx, msg, &group.options, owners, help_options, fetched)
+                    .await,
             )
         }
     };
@@ -632,7 +679,8 @@ async fn fill_eligible_commands<'a>(
         }
 
         let command_behaviour =
-            check_command_behaviour(ctx, msg, &command.options, owners, help_options).await;
+            check_command_behaviour(ctx, msg, &command.options, owners, help_options, fetched)
+                .await;
 
         let name = format_command_name!(command_behaviour, &name);
         to_fill.command_names.push(name);
@@ -652,6 +700,7 @@ async fn fetch_all_eligible_commands_in_group<'a>(
     help_options: &'a HelpOptions,
     group: &'a CommandGroup,
     highest_formatter: HelpBehaviour,
+    fetched: &FetchedPermissions,
 ) -> GroupCommandsPair {
     let mut group_with_cmds = GroupCommandsPair::default();
     let mut highest_formatter = highest_formatter;
@@ -665,6 +714,7 @@ async fn fetch_all_eligible_commands_in_group<'a>(
         &group,
         &mut group_with_cmds,
         &mut highest_formatter,
+        fetched,
     )
     .await;
 
@@ -683,6 +733,7 @@ async fn fetch_all_eligible_commands_in_group<'a>(
             &help_options,
             &sub_group,
             highest_formatter,
+            fetched,
         )
         .await;
 
@@ -700,13 +751,15 @@ async fn create_command_group_commands_pair_from_groups<'a>(
     groups: &[&'static CommandGroup],
     owners: &HashSet<UserId>,
     help_options: &'a HelpOptions,
+    fetched: &FetchedPermissions,
 ) -> Vec<GroupCommandsPair> {
     let mut listed_groups: Vec<GroupCommandsPair> = Vec::default();
 
     for group in groups {
         let group = *group;
 
-        let group_with_cmds = create_single_group(ctx, msg, group, &owners, &help_options).await;
+        let group_with_cmds =
+            create_single_group(ctx, msg, group, &owners, &help_options, fetched).await;
 
         if !group_with_cmds.command_names.is_empty() || !group_with_cmds.sub_groups.is_empty() {
             listed_groups.push(group_with_cmds);
@@ -724,6 +777,7 @@ async fn create_single_group(
     group: &CommandGroup,
     owners: &HashSet<UserId>,
     help_options: &HelpOptions,
+    fetched: &FetchedPermissions,
 ) -> GroupCommandsPair {
     let mut group_with_cmds = fetch_all_eligible_commands_in_group(
         ctx,
@@ -733,6 +787,7 @@ async fn create_single_group(
         &help_options,
         &group,
         HelpBehaviour::Nothing,
+        fetched,
     )
     .await;
 
@@ -759,7 +814,6 @@ fn trim_prefixless_group(group_name: &str, searched_group: &mut String) -> bool
     false
 }
 
-#[async_recursion]
 #[cfg(feature = "cache")]
 #[allow(clippy::implicit_hasher)]
 pub async fn searched_lowercase<'a>(
@@ -770,6 +824,32 @@ pub async fn searched_lowercase<'a>(
     owners: &HashSet<UserId>,
     help_options: &'a HelpOptions,
     searched_named_lowercase: &mut String,
+) -> Option<CustomisedHelpData<'a>> {
+    find_searched_lowercase(
+        ctx,
+        msg,
+        args,
+        group,
+        owners,
+        help_options,
+        searched_named_lowercase,
+        &FetchedPermissions::default(),
+    )
+    .await
+}
+
+#[async_recursion]
+#[cfg(feature = "cache")]
+#[allow(clippy::too_many_arguments)]
+async fn find_searched_lowercase<'a>(
+    ctx: &mut Context,
+    msg: &Message,
+    args: &'a Args,
+    group: &CommandGroup,
+    owners: &HashSet<UserId>,
+    help_options: &'a HelpOptions,
+    searched_named_lowercase: &mut String,
+    fetched: &FetchedPermissions,
 ) -> Option<CustomisedHelpData<'a>> {
     let is_prefixless_group = {
         group.options.prefixes.is_empty()
@@ -786,7 +866,8 @@ pub async fn searched_lowercase<'a>(
     });
 
     if is_prefixless_group || is_word_prefix {
-        let single_group = create_single_group(ctx, msg, &group, owners, &help_options).await;
+        let single_group =
+            create_single_group(ctx, msg, &group, owners, &help_options, fetched).await;
 
         if !single_group.command_names.is_empty() {
             return Some(CustomisedHelpData::GroupedCommands {
@@ -801,7 +882,7 @@ pub async fn searched_lowercase<'a>(
         }
     } else if progressed || group.options.prefixes.is_empty() {
         for sub_group in group.options.sub_groups {
-            if let Some(found_set) = searched_lowercase(
+            if let Some(found_set) = find_searched_lowercase(
                 ctx,
                 msg,
                 args,
@@ -809,6 +890,7 @@ pub async fn searched_lowercase<'a>(
                 owners,
                 help_options,
                 searched_named_lowercase,
+                fetched,
             )
             .await
             {
@@ -833,16 +915,20 @@ pub async fn create_customised_help_data<'a, 'b>(
     owners: &HashSet<UserId>,
     help_options: &'a HelpOptions,
 ) -> CustomisedHelpData<'a> {
+    let fetched = FetchedPermissions::default();
+
     if !args.is_empty() {
         let name = args.message();
 
-        return match fetch_single_command(ctx, msg, &groups, &name, &help_options, owners).await {
+        return match fetch_single_command(ctx, msg, &groups, &name, &help_options, owners, &fetched)
+            .await
+        {
             Ok(single_command) => single_command,
             Err(suggestions) => {
                 let mut searched_named_lowercase = name.to_lowercase();
 
                 for group in groups {
-                    if let Some(found_command) = searched_lowercase(
+                    if let Some(found_command) = find_searched_lowercase(
                         ctx,
                         msg,
                         args,
@@ -850,6 +936,7 @@ pub async fn create_customised_help_data<'a, 'b>(
                         owners,
                         help_options,
                         &mut searched_named_lowercase,
+                        &fetched,
                     )
                     .await
                     {
@@ -886,9 +973,15 @@ pub async fn create_customised_help_data<'a, 'b>(
         help_options.individual_command_tip.to_string()
     };
 
-    let listed_groups =
-        create_command_group_commands_pair_from_groups(ctx, msg, &groups, owners, &help_options)
-            .await;
+    let listed_groups = create_command_group_commands_pair_from_groups(
+        ctx,
+        msg,
+        &groups,
+        owners,
+        &help_options,
+        &fetched,
+    )
+    .await;
 
     if listed_groups.is_empty() {
         CustomisedHelpData::NoCommandFound {
@@ -1505,13 +1598,13 @@ mod matrix_tests {
     #[should_panic(expected = "the len is 4 but the index is 9")]
     fn panic_index_too_high() {
         let matrix = Matrix::new(2, 2);
-        matrix[(3, 3)];
+        assert_eq!(matrix[(3, 3)], 0);
     }
 
     #[test]
     #[should_panic(expected = "the len is 0 but the index is 0")]
     fn panic_indexing_when_empty() {
         let matrix = Matrix::new(0, 0);
-        matrix[(0, 0)];
+        assert_eq!(matrix[(0, 0)], 0);
     }
 }
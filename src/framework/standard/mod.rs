@@ -1,3 +1,4 @@
+pub mod checks;
 pub mod help_commands;
 pub mod macros {
     pub use command_attr::{check, command, group, help};
@@ -6,10 +7,13 @@ pub mod macros {
 mod args;
 mod configuration;
 mod parse;
+mod prefixes;
 mod structures;
 
+use args::ArgFailure;
 pub use args::{Args, Delimiter, Error as ArgError, Iter, RawArguments};
-pub use configuration::{Configuration, WithWhiteSpace};
+pub use configuration::{Configuration, ErrorReplyStyle, WithWhiteSpace};
+pub use prefixes::{EffectivePrefixes, PrefixCollision};
 pub use structures::*;
 
 pub use structures::buckets::BucketBuilder;
@@ -20,6 +24,7 @@ use parse::{Invoke, ParseError};
 
 use super::Framework;
 use crate::client::Context;
+use crate::http::Http;
 use crate::model::{
     channel::{Channel, Message},
     permissions::Permissions,
@@ -30,12 +35,21 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use futures::{future::BoxFuture, FutureExt};
+use tokio::task::JoinHandle;
 use uwl::Stream;
 
 #[cfg(feature = "cache")]
-use crate::cache::CacheRwLock;
+use crate::cache::Cache;
 #[cfg(feature = "cache")]
-use crate::model::guild::{Guild, Member};
+use crate::model::guild::Member;
+#[cfg(all(feature = "cache", feature = "http"))]
+use crate::model::guild::{PartialGuild, Role};
+#[cfg(all(feature = "cache", feature = "http"))]
+use crate::model::id::RoleId;
+#[cfg(all(feature = "cache", feature = "http"))]
+use log::warn;
+#[cfg(all(feature = "cache", feature = "http"))]
+use tokio::sync::OnceCell;
 
 /// An enum representing all possible fail conditions under which a command won't
 /// be executed.
@@ -67,6 +81,10 @@ pub enum DispatchError {
     LackingRole,
     /// When the command requester lacks specific required permissions.
     LackingPermissions(Permissions),
+    /// When the command requester's permissions or roles could not be
+    /// checked, because their guild is missing from the cache and could not
+    /// be retrieved over the REST API either.
+    PermissionsUnavailable,
     /// When there are too few arguments.
     NotEnoughArguments { min: u16, given: usize },
     /// When there are too many arguments.
@@ -76,6 +94,19 @@ pub enum DispatchError {
     IgnoredBot,
     /// When the bot ignores webhooks and a command was issued by one.
     WebhookAuthor,
+    /// When a command returned an error after one of its arguments failed to
+    /// parse through [`Args::single`] or [`Args::single_quoted`].
+    ///
+    /// `expected` is the name of the type the argument was parsed as, and
+    /// `got` is the argument, or `None` if there were no arguments left.
+    ///
+    /// [`Args::single`]: struct.Args.html#method.single
+    /// [`Args::single_quoted`]: struct.Args.html#method.single_quoted
+    InvalidArguments {
+        command: &'static str,
+        expected: &'static str,
+        got: Option<String>,
+    },
 }
 
 pub type DispatchHook = dyn Fn(&mut Context, &Message, DispatchError) + Send + Sync + 'static;
@@ -103,6 +134,8 @@ pub struct StandardFramework {
     prefix_only: Option<Arc<PrefixOnlyHook>>,
     config: Configuration,
     help: Option<&'static HelpCommand>,
+    prefix_command: Vec<String>,
+    check_results: CheckResultCache,
     /// Whether the framework has been "initialized".
     ///
     /// The framework is initialized once one of the following occurs:
@@ -163,6 +196,14 @@ impl StandardFramework {
     {
         f(&mut self.config);
 
+        for collision in prefixes::collisions(&self.config.prefixes) {
+            log::warn!(
+                "The prefix {:?} is never used, as {:?} is matched first",
+                collision.prefix,
+                collision.shadowed_by
+            );
+        }
+
         self
     }
 
@@ -227,6 +268,25 @@ impl StandardFramework {
         self
     }
 
+    /// Returns the name of the [built-in prefix command] if the message
+    /// invokes it.
+    ///
+    /// [built-in prefix command]: #method.prefix_command
+    fn prefix_command_name(&self, stream: &Stream<'_>) -> Option<String> {
+        let name = stream.peek_until(|s| s.is_ascii_whitespace());
+
+        self.prefix_command
+            .iter()
+            .find(|n| {
+                if self.config.case_insensitive {
+                    n.to_lowercase() == name.to_lowercase()
+                } else {
+                    n.as_str() == name
+                }
+            })
+            .cloned()
+    }
+
     fn should_fail_common(&self, msg: &Message) -> Option<DispatchError> {
         if self.config.ignore_bots && msg.author.bot {
             return Some(DispatchError::IgnoredBot);
@@ -278,17 +338,16 @@ impl StandardFramework {
 
             #[cfg(feature = "cache")]
             {
-                if let Some(Channel::Guild(chan)) =
-                    msg.channel_id.to_channel_cached(&ctx.cache).await
-                {
-                    let guild_id = chan.read().await.guild_id;
+                if let Some(Channel::Guild(chan)) = msg.channel_id.to_channel_cached(&ctx.cache) {
+                    let guild_id = chan.guild_id;
 
                     if self.config.blocked_guilds.contains(&guild_id) {
                         return Some(DispatchError::BlockedGuild);
                     }
 
-                    if let Some(guild) = guild_id.to_guild_cached(&ctx.cache).await {
-                        let owner_id = guild.read().await.owner_id;
+                    let owner_id = ctx.cache.guild_field(guild_id, |guild| guild.owner_id);
+
+                    if let Some(owner_id) = owner_id {
                         if self.config.blocked_users.contains(&owner_id) {
                             return Some(DispatchError::BlockedGuild);
                         }
@@ -319,7 +378,16 @@ impl StandardFramework {
             }
 
             for check in group.checks.iter().chain(command.checks.iter()) {
-                let res = check.function.check(ctx, msg, args, command).await;
+                let res = match self.check_results.get(check, msg.author.id, msg.guild_id) {
+                    Some(res) => res,
+                    None => {
+                        let res = check.function.check(ctx, msg, args, command).await;
+                        self.check_results
+                            .insert(check, msg.author.id, msg.guild_id, &res);
+
+                        res
+                    }
+                };
 
                 if let CheckResult::Failure(r) = res {
                     return Some(DispatchError::CheckFailed(check.name, r));
@@ -647,6 +715,47 @@ impl StandardFramework {
 
         self
     }
+
+    /// Sets the names of a built-in command which replies with the prefixes
+    /// commands can be invoked with in the channel, including the bot's
+    /// mention if [`Configuration::on_mention`] was set.
+    ///
+    /// This lets users of bots with [dynamic prefixes] find out how to invoke
+    /// them, e.g. with `@Bot prefix`.
+    ///
+    /// A command in a group with one of these names takes precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::StandardFramework;
+    /// use serenity::model::id::UserId;
+    ///
+    /// let framework = StandardFramework::new()
+    ///     .configure(|c| c.on_mention(Some(UserId(5))).prefix("~"))
+    ///     .prefix_command(&["prefix", "prefixes"]);
+    /// ```
+    ///
+    /// [`Configuration::on_mention`]: struct.Configuration.html#method.on_mention
+    /// [dynamic prefixes]: struct.Configuration.html#method.dynamic_prefix
+    pub fn prefix_command(mut self, names: &[&str]) -> Self {
+        self.prefix_command = names.iter().map(|n| n.to_string()).collect();
+
+        self
+    }
+
+    /// Returns the prefixes commands can be invoked with for a message, such as
+    /// those configured for the guild it was sent in through
+    /// [`Configuration::dynamic_prefix`].
+    ///
+    /// Use [`EffectivePrefixes::collisions`] to find prefixes which conflict
+    /// with each other.
+    ///
+    /// [`Configuration::dynamic_prefix`]: struct.Configuration.html#method.dynamic_prefix
+    /// [`EffectivePrefixes::collisions`]: struct.EffectivePrefixes.html#method.collisions
+    pub fn effective_prefixes(&self, ctx: &mut Context, msg: &Message) -> EffectivePrefixes {
+        EffectivePrefixes::new(ctx, msg, &self.config)
+    }
 }
 
 #[async_trait]
@@ -692,6 +801,8 @@ impl Framework for StandardFramework {
             return;
         }
 
+        let prefix_command = self.prefix_command_name(&stream);
+
         let invocation = parse::command(
             &ctx,
             &msg,
@@ -704,6 +815,34 @@ impl Framework for StandardFramework {
 
         let invoke = match invocation {
             Ok(i) => i,
+            Err(ParseError::UnrecognisedCommand(_)) if prefix_command.is_some() => {
+                let prefixes = self.effective_prefixes(&mut ctx, &msg);
+                let name = prefix_command.unwrap();
+                let before = self.before.clone();
+                let after = self.after.clone();
+                let msg = msg.clone();
+
+                tokio::spawn(async move {
+                    if let Some(before) = before {
+                        if !before(&mut ctx, &msg, &name) {
+                            return;
+                        }
+                    }
+
+                    let res = msg
+                        .channel_id
+                        .say(&ctx.http, prefixes.describe())
+                        .await
+                        .map(|_| ())
+                        .map_err(CommandError::from);
+
+                    if let Some(after) = after {
+                        after(&mut ctx, &msg, &name, res);
+                    }
+                });
+
+                return;
+            }
             Err(ParseError::UnrecognisedCommand(unreg)) => {
                 if let Some(unreg) = unreg {
                     if let Some(unrecognised_command) = &self.unrecognised_command {
@@ -807,6 +946,13 @@ impl Framework for StandardFramework {
 
                 let before = self.before.clone();
                 let after = self.after.clone();
+                let dispatch = self.dispatch.clone();
+                let timeout = self.config.command_timeout;
+                let timeout_message = self.config.timeout_message.clone();
+                let send_usage = self.config.usage_on_invalid_arguments;
+                let error_reply = self.config.error_reply;
+                let error_reply_formatter = self.config.error_reply_formatter.clone();
+                let failure = args.failure();
                 let msg = msg.clone();
                 let name = &command.options.names[0];
                 tokio::spawn(async move {
@@ -816,7 +962,60 @@ impl Framework for StandardFramework {
                         }
                     }
 
-                    let res = command.fun.command(&mut ctx, &msg, args).await;
+                    let res = match timeout {
+                        Some(duration) => {
+                            let fut = command.fun.command(&mut ctx, &msg, args);
+                            let res = tokio::time::timeout(duration, fut).await;
+
+                            match res {
+                                Ok(res) => res,
+                                Err(_) => {
+                                    if let Some(text) = timeout_message {
+                                        let _ = msg.channel_id.say(&ctx.http, text).await;
+                                    }
+
+                                    Err(CommandError::Timeout(duration))
+                                }
+                            }
+                        }
+                        None => command.fun.command(&mut ctx, &msg, args).await,
+                    };
+
+                    let failure = match res {
+                        Err(CommandError::Message(_)) => failure.write().unwrap().take(),
+                        _ => None,
+                    };
+
+                    let usage_sent = send_usage && failure.is_some();
+
+                    // Timeouts are covered by the `timeout_message` instead.
+                    if let (Some(style), Err(error)) = (error_reply, &res) {
+                        if let (CommandError::Message(why), false) = (error, usage_sent) {
+                            let text = match &error_reply_formatter {
+                                Some(formatter) => formatter(name, error),
+                                None => why.clone(),
+                            };
+
+                            let _ = send_error_reply(&ctx.http, &msg, style, text).await;
+                        }
+                    }
+
+                    if let Some(failure) = failure {
+                        if send_usage {
+                            let text = usage_message(name, command.options, &failure);
+                            let _ = msg.channel_id.say(&ctx.http, text).await;
+                        }
+
+                        if let Some(dispatch) = &dispatch {
+                            let error = DispatchError::InvalidArguments {
+                                command: name,
+                                expected: failure.expected,
+                                got: failure.got,
+                            };
+
+                            dispatch(&mut ctx, &msg, error);
+                        }
+                    }
 
                     if let Some(after) = after {
                         after(&mut ctx, &msg, name, res);
@@ -827,6 +1026,60 @@ impl Framework for StandardFramework {
     }
 }
 
+/// Builds the reply explaining which argument of a command failed to parse
+/// and how the command is used.
+fn usage_message(name: &str, options: &CommandOptions, failure: &ArgFailure) -> String {
+    let mut text = match &failure.got {
+        Some(got) => format!("Expected a {}, but got `{}`.", failure.expected, got),
+        None => format!(
+            "Expected a {}, but no argument was given.",
+            failure.expected
+        ),
+    };
+
+    if let Some(usage) = options.usage {
+        text.push_str(&format!("\nUsage: `{} {}`", name, usage));
+    }
+
+    if let Some(example) = options.examples.first() {
+        text.push_str(&format!("\nExample: `{} {}`", name, example));
+    }
+
+    text
+}
+
+/// Tells the user their command failed in the given style.
+///
+/// Self-deleting replies are deleted in the background, by the task whose
+/// handle is returned.
+async fn send_error_reply(
+    http: &Arc<Http>,
+    msg: &Message,
+    style: ErrorReplyStyle,
+    text: String,
+) -> crate::Result<Option<JoinHandle<()>>> {
+    match style {
+        ErrorReplyStyle::Message => {
+            msg.channel_id.say(http, text).await?;
+        }
+        ErrorReplyStyle::Reply => {
+            msg.reply(http, text).await?;
+        }
+        ErrorReplyStyle::SelfDeleting(delay) => {
+            let reply = msg.channel_id.say(http, text).await?;
+            let http = Arc::clone(http);
+
+            return Ok(Some(tokio::spawn(async move {
+                tokio::time::sleep(delay).await;
+
+                let _ = reply.channel_id.delete_message(&http, reply.id).await;
+            })));
+        }
+    }
+
+    Ok(None)
+}
+
 pub trait CommonOptions {
     fn required_permissions(&self) -> &Permissions;
     fn allowed_roles(&self) -> &'static [&'static str];
@@ -898,18 +1151,18 @@ impl CommonOptions for &CommandOptions {
 }
 
 #[cfg(feature = "cache")]
-pub(crate) async fn has_correct_permissions(
-    cache: impl AsRef<CacheRwLock>,
+pub(crate) fn has_correct_permissions(
+    cache: impl AsRef<Cache>,
     options: &impl CommonOptions,
     message: &Message,
 ) -> bool {
     if options.required_permissions().is_empty() {
         true
-    } else if let Some(guild) = message.guild(&cache).await {
-        let guild = guild.read().await;
-        let perms = guild
-            .user_permissions_in(message.channel_id, message.author.id)
-            .await;
+    } else if let Some(guild) = message
+        .guild_id
+        .and_then(|guild_id| cache.as_ref().guilds.get(&guild_id))
+    {
+        let perms = guild.user_permissions_in(message.channel_id, message.author.id);
 
         perms.contains(*options.required_permissions())
     } else {
@@ -920,7 +1173,7 @@ pub(crate) async fn has_correct_permissions(
 #[cfg(all(feature = "cache", feature = "http"))]
 pub(crate) fn has_correct_roles(
     options: &impl CommonOptions,
-    guild: &Guild,
+    roles: &HashMap<RoleId, Role>,
     member: &Member,
 ) -> bool {
     if options.allowed_roles().is_empty() {
@@ -929,7 +1182,299 @@ pub(crate) fn has_correct_roles(
         options
             .allowed_roles()
             .iter()
-            .flat_map(|r| guild.role_by_name(r))
+            .flat_map(|r| roles.values().find(|role| role.name == *r))
             .any(|g| member.roles.contains(&g.id))
     }
 }
+
+/// Whether the options restrict who may use a group or command by their
+/// permissions or roles.
+#[cfg(all(feature = "cache", feature = "http"))]
+pub(crate) fn has_permission_requirements(options: &impl CommonOptions) -> bool {
+    !options.required_permissions().is_empty() || !options.allowed_roles().is_empty()
+}
+
+/// The guild and member of a message's author along with the member's
+/// permissions in the message's channel, retrieved over the REST API.
+///
+/// This is used when the guild is missing from the cache, such as during
+/// partial outages, so that commands are not wrongly hidden or allowed. The
+/// requests are only sent once per message, however many groups and commands
+/// are checked.
+#[derive(Default)]
+pub(crate) struct FetchedPermissions {
+    #[cfg(all(feature = "cache", feature = "http"))]
+    fetched: OnceCell<Option<(PartialGuild, Member, Permissions)>>,
+}
+
+#[cfg(all(feature = "cache", feature = "http"))]
+impl FetchedPermissions {
+    /// Returns the guild, member and permissions of the message's author,
+    /// retrieving them if this is the first call.
+    ///
+    /// Returns `None` if the message was not sent in a guild or a request
+    /// fails.
+    pub(crate) async fn get(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+    ) -> Option<&(PartialGuild, Member, Permissions)> {
+        self.fetched
+            .get_or_init(|| fetch_member_permissions(ctx, msg))
+            .await
+            .as_ref()
+    }
+}
+
+#[cfg(all(feature = "cache", feature = "http"))]
+async fn fetch_member_permissions(
+    ctx: &Context,
+    msg: &Message,
+) -> Option<(PartialGuild, Member, Permissions)> {
+    let guild_id = msg.guild_id?;
+
+    let fetched = async {
+        let guild = ctx.http.get_guild(guild_id.0).await?;
+        let member = ctx.http.get_member(guild_id.0, msg.author.id.0).await?;
+        let channel = ctx.http.get_channel(msg.channel_id.0).await?;
+
+        Ok::<_, crate::Error>((guild, member, channel))
+    };
+
+    match fetched.await {
+        Ok((guild, member, Channel::Guild(channel))) => {
+            let permissions = guild.user_permissions_in(&channel, &member);
+
+            Some((guild, member, permissions))
+        }
+        Ok(_) => None,
+        Err(why) => {
+            warn!(
+                "Failed to fetch permissions for {}: {:?}",
+                msg.author.id, why
+            );
+
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{send_error_reply, ErrorReplyStyle};
+    use crate::http::mock::{MockResponse, MockServer};
+    use crate::model::channel::Message;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn test_fetched_permissions() {
+        use super::FetchedPermissions;
+        use crate::cache::Cache;
+        use crate::client::Context;
+        use crate::internal::AsyncRwLock;
+        use crate::model::{id::GuildId, permissions::Permissions};
+        use dashmap::DashMap;
+        use serde_json::json;
+        use typemap::ShareMap;
+
+        let json = include_str!("../../../tests/resources/message_create_1.json");
+        let mut message: Message = serde_json::from_str(json).unwrap();
+        message.guild_id = Some(GuildId(1));
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({
+                "id": "1",
+                "afk_channel_id": null,
+                "afk_timeout": 300,
+                "default_message_notifications": 0,
+                "embed_channel_id": null,
+                "embed_enabled": false,
+                "emojis": [],
+                "features": [],
+                "icon": null,
+                "mfa_level": 0,
+                "name": "guild",
+                "owner_id": "3",
+                "region": "us-west",
+                "roles": [{
+                    "id": "1",
+                    "color": 0,
+                    "hoist": false,
+                    "managed": false,
+                    "mentionable": false,
+                    "name": "@everyone",
+                    "permissions": 3072,
+                    "position": 0,
+                }],
+                "splash": null,
+                "verification_level": 0,
+                "description": null,
+                "premium_tier": 0,
+                "premium_subscription_count": 0,
+                "banner": null,
+                "vanity_url_code": null,
+            }),
+        ));
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({
+                "deaf": false,
+                "joined_at": "2020-01-01T00:00:00+00:00",
+                "mute": false,
+                "nick": null,
+                "roles": [],
+                "user": message.author,
+            }),
+        ));
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({
+                "id": "244567637332328449",
+                "guild_id": "1",
+                "type": 0,
+                "name": "general",
+                "permission_overwrites": [],
+                "position": 0,
+            }),
+        ));
+
+        let (tx, _rx) = futures::channel::mpsc::unbounded();
+        let ctx = Context::new(
+            Arc::new(AsyncRwLock::new(ShareMap::custom())),
+            tx,
+            Arc::new(DashMap::new()),
+            [0, 1],
+            Arc::new(server.http("Bot token")),
+            Arc::new(Cache::new()),
+        );
+
+        // The requests are only sent for the first check of a message.
+        let fetched = FetchedPermissions::default();
+        for _ in 0..3 {
+            let (_, _, permissions) = fetched.get(&ctx, &message).await.unwrap();
+            assert_eq!(
+                *permissions,
+                Permissions::READ_MESSAGES | Permissions::SEND_MESSAGES
+            );
+        }
+        assert_eq!(server.requests().len(), 3);
+
+        // Nothing is queued, so retrieving the guild fails.
+        let failed = FetchedPermissions::default();
+        assert!(failed.get(&ctx, &message).await.is_none());
+        assert!(failed.get(&ctx, &message).await.is_none());
+        assert_eq!(server.requests().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_error_reply() {
+        let json = include_str!("../../../tests/resources/message_create_1.json");
+        let message: Message = serde_json::from_str(json).unwrap();
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(
+            200,
+            &serde_json::from_str(json).unwrap(),
+        ));
+        server.enqueue(MockResponse::new(204));
+        let http = Arc::new(server.http("Bot token"));
+
+        let style = ErrorReplyStyle::SelfDeleting(Duration::from_millis(10));
+        let deletion = send_error_reply(&http, &message, style, "No such user.".to_string())
+            .await
+            .unwrap();
+        deletion
+            .expect("Expected the reply to be deleted")
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].path, "/channels/244567637332328449/messages");
+        assert!(String::from_utf8_lossy(&requests[0].body).contains("No such user."));
+        assert_eq!(requests[1].method, "DELETE");
+        assert_eq!(
+            requests[1].path,
+            "/channels/244567637332328449/messages/302917639565475840"
+        );
+    }
+    #[tokio::test]
+    async fn test_command_timeout() {
+        use super::{
+            macros::{command, group},
+            CommandError, CommandResult, StandardFramework,
+        };
+        use crate::client::Context;
+        use crate::framework::Framework;
+        use crate::internal::AsyncRwLock;
+        use dashmap::DashMap;
+        use futures::{channel::mpsc::unbounded, StreamExt};
+        use typemap::ShareMap;
+
+        #[command]
+        async fn slow(_ctx: &mut Context, _msg: &Message) -> CommandResult {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+
+            Ok(())
+        }
+
+        #[group]
+        #[commands(slow)]
+        struct General;
+
+        tokio::time::pause();
+
+        let json = include_str!("../../../tests/resources/message_create_1.json");
+        let mut message: Message = serde_json::from_str(json).unwrap();
+        message.content = "~slow".to_string();
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(
+            200,
+            &serde_json::from_str(json).unwrap(),
+        ));
+        let http = Arc::new(server.http("Bot token"));
+
+        let data = Arc::new(AsyncRwLock::new(ShareMap::custom()));
+        let (runner_tx, _runner_rx) = unbounded();
+        #[cfg(feature = "cache")]
+        let ctx = Context::new(
+            data,
+            runner_tx,
+            Arc::new(DashMap::new()),
+            [0, 1],
+            http,
+            Arc::new(crate::cache::Cache::new()),
+        );
+        #[cfg(not(feature = "cache"))]
+        let ctx = Context::new(data, runner_tx, Arc::new(DashMap::new()), [0, 1], http);
+
+        let (tx, mut results) = unbounded();
+        let mut framework = StandardFramework::new()
+            .configure(|c| {
+                c.prefix("~")
+                    .command_timeout(Duration::from_secs(1))
+                    .timeout_message("Took too long.")
+            })
+            .after(move |_, _, name, res| {
+                let _ = tx.unbounded_send((name.to_string(), res));
+            })
+            .group(&GENERAL_GROUP);
+
+        framework.dispatch(ctx, message).await;
+
+        // The command is cancelled once the clock passes the timeout, long
+        // before its own sleep would have finished.
+        let (name, res) = results.next().await.unwrap();
+        assert_eq!(name, "slow");
+        assert!(matches!(res, Err(CommandError::Timeout(d)) if d == Duration::from_secs(1)));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(String::from_utf8_lossy(&requests[0].body).contains("Took too long."));
+    }
+}
@@ -125,6 +125,7 @@ async fn check_discrepancy(
     msg: &Message,
     config: &Configuration,
     options: &impl CommonOptions,
+    fetched: &FetchedPermissions,
 ) -> Result<(), DispatchError> {
     if options.owners_only() && !config.owners.contains(&msg.author.id) {
         return Err(DispatchError::OnlyForOwners);
@@ -141,16 +142,31 @@ async fn check_discrepancy(
     #[cfg(feature = "cache")]
     {
         if let Some(guild_id) = msg.guild_id {
-            let guild = match guild_id.to_guild_cached(&ctx).await {
-                Some(g) => g,
-                None => return Ok(()),
-            };
-
-            let guild = guild.read().await;
+            if !has_permission_requirements(options) {
+                return Ok(());
+            }
 
-            let perms = guild
-                .user_permissions_in(msg.channel_id, msg.author.id)
-                .await;
+            let cached = ctx.cache.guilds.get(&guild_id).map(|guild| {
+                let perms = guild.user_permissions_in(msg.channel_id, msg.author.id);
+                let has_roles = match guild.members.get(&msg.author.id) {
+                    Some(member) => has_correct_roles(options, &guild.roles, member),
+                    None => true,
+                };
+
+                (perms, has_roles)
+            });
+
+            // Fall back to the REST API if the guild is missing from the
+            // cache, rather than skipping the checks.
+            let (perms, has_roles) = match cached {
+                Some(cached) => cached,
+                None => match fetched.get(ctx, msg).await {
+                    Some((guild, member, perms)) => {
+                        (*perms, has_correct_roles(options, &guild.roles, member))
+                    }
+                    None => return Err(DispatchError::PermissionsUnavailable),
+                },
+            };
 
             if !(perms.contains(*options.required_permissions())
                 || options.owner_privilege() && config.owners.contains(&msg.author.id))
@@ -160,10 +176,8 @@ async fn check_discrepancy(
                 ));
             }
 
-            if let Some(member) = guild.members.get(&msg.author.id) {
-                if !perms.administrator() && !has_correct_roles(options, &guild, &member) {
-                    return Err(DispatchError::LackingRole);
-                }
+            if !perms.administrator() && !has_roles {
+                return Err(DispatchError::LackingRole);
             }
         }
     }
@@ -207,6 +221,7 @@ fn parse_cmd<'a>(
     msg: &'a Message,
     config: &'a Configuration,
     map: &'a CommandMap,
+    fetched: &'a FetchedPermissions,
 ) -> BoxFuture<'a, Result<&'static Command, ParseError>> {
     async move {
         let (n, r) = try_parse(stream, map, config.by_space, |s| {
@@ -224,13 +239,13 @@ fn parse_cmd<'a>(
                 stream.take_while(|s| s.is_ascii_whitespace());
             }
 
-            check_discrepancy(ctx, msg, config, &cmd.options).await?;
+            check_discrepancy(ctx, msg, config, &cmd.options, fetched).await?;
 
             if map.is_empty() {
                 return Ok(cmd);
             }
 
-            return match parse_cmd(stream, ctx, msg, config, &map).await {
+            return match parse_cmd(stream, ctx, msg, config, &map, fetched).await {
                 Err(ParseError::UnrecognisedCommand(Some(_))) => Ok(cmd),
                 res => res,
             };
@@ -247,6 +262,7 @@ fn parse_group<'a>(
     msg: &'a Message,
     config: &'a Configuration,
     map: &'a GroupMap,
+    fetched: &'a FetchedPermissions,
 ) -> BoxFuture<'a, Result<(&'static CommandGroup, Arc<CommandMap>), ParseError>> {
     async move {
         let (n, o) = try_parse(stream, map, config.by_space, ToString::to_string);
@@ -258,13 +274,13 @@ fn parse_group<'a>(
                 stream.take_while(|s| s.is_ascii_whitespace());
             }
 
-            check_discrepancy(ctx, msg, config, &group.options).await?;
+            check_discrepancy(ctx, msg, config, &group.options, fetched).await?;
 
             if map.is_empty() {
                 return Ok((group, commands));
             }
 
-            return match parse_group(stream, ctx, msg, config, &map).await {
+            return match parse_group(stream, ctx, msg, config, &map, fetched).await {
                 Err(ParseError::UnrecognisedCommand(None)) => Ok((group, commands)),
                 res => res,
             };
@@ -283,8 +299,9 @@ async fn handle_command<'a>(
     config: &'a Configuration,
     map: &'a CommandMap,
     group: &'static CommandGroup,
+    fetched: &'a FetchedPermissions,
 ) -> Result<Invoke, ParseError> {
-    match parse_cmd(stream, ctx, msg, config, map).await {
+    match parse_cmd(stream, ctx, msg, config, map, fetched).await {
         Ok(command) => Ok(Invoke::Command { group, command }),
         Err(err) => match group.options.default_command {
             Some(command) => Ok(Invoke::Command { group, command }),
@@ -300,10 +317,11 @@ async fn handle_group(
     msg: &Message,
     config: &Configuration,
     map: &GroupMap,
+    fetched: &FetchedPermissions,
 ) -> Result<Invoke, ParseError> {
-    let (group, map) = parse_group(stream, ctx, msg, config, map).await?;
+    let (group, map) = parse_group(stream, ctx, msg, config, map, fetched).await?;
 
-    handle_command(stream, ctx, msg, config, &map, group).await
+    handle_command(stream, ctx, msg, config, &map, group, fetched).await
 }
 
 #[derive(Debug)]
@@ -351,13 +369,14 @@ pub async fn command(
         }
     }
 
+    let fetched = FetchedPermissions::default();
     let mut last = Err(ParseError::UnrecognisedCommand(None));
 
     for (group, map) in groups {
         match map {
             // Includes [group] itself.
             Map::WithPrefixes(map) => {
-                let res = handle_group(stream, ctx, msg, config, map).await;
+                let res = handle_group(stream, ctx, msg, config, map, &fetched).await;
 
                 if res.is_ok() {
                     return res;
@@ -366,18 +385,18 @@ pub async fn command(
                 last = res;
             }
             Map::Prefixless(subgroups, commands) => {
-                let res = handle_group(stream, ctx, msg, config, subgroups).await;
+                let res = handle_group(stream, ctx, msg, config, subgroups, &fetched).await;
 
                 if res.is_ok() {
-                    check_discrepancy(ctx, msg, config, &group.options).await?;
+                    check_discrepancy(ctx, msg, config, &group.options, &fetched).await?;
 
                     return res;
                 }
 
-                let res = handle_command(stream, ctx, msg, config, commands, group).await;
+                let res = handle_command(stream, ctx, msg, config, commands, group, &fetched).await;
 
                 if res.is_ok() {
-                    check_discrepancy(ctx, msg, config, &group.options).await?;
+                    check_discrepancy(ctx, msg, config, &group.options, &fetched).await?;
 
                     return res;
                 }
@@ -0,0 +1,170 @@
+use super::Configuration;
+use crate::client::Context;
+use crate::model::{channel::Message, id::UserId, misc::Mentionable};
+
+/// The prefixes a message can invoke commands with, as returned by
+/// [`StandardFramework::effective_prefixes`].
+///
+/// [`StandardFramework::effective_prefixes`]: struct.StandardFramework.html#method.effective_prefixes
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EffectivePrefixes {
+    /// The prefixes, in the order the framework tries them: [dynamic
+    /// prefixes] first, then [static prefixes].
+    ///
+    /// [dynamic prefixes]: struct.Configuration.html#method.dynamic_prefix
+    /// [static prefixes]: struct.Configuration.html#method.prefixes
+    pub prefixes: Vec<String>,
+    /// The user whose mention can be used in place of a prefix, if
+    /// [`Configuration::on_mention`] was set.
+    ///
+    /// [`Configuration::on_mention`]: struct.Configuration.html#method.on_mention
+    pub mention: Option<UserId>,
+}
+
+/// A prefix which can never be used, as the framework always matches an
+/// earlier prefix which it starts with instead.
+///
+/// For example, with the prefixes `!` and `!!`, a message starting with `!!`
+/// is parsed as the prefix `!` followed by a command name starting with `!`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PrefixCollision {
+    /// The prefix which can never be used.
+    pub prefix: String,
+    /// The earlier prefix which is matched instead.
+    pub shadowed_by: String,
+}
+
+impl EffectivePrefixes {
+    pub(crate) fn new(ctx: &mut Context, msg: &Message, config: &Configuration) -> Self {
+        let mut prefixes = config
+            .dynamic_prefixes
+            .iter()
+            .filter_map(|f| f(ctx, msg))
+            .collect::<Vec<_>>();
+        prefixes.extend(config.prefixes.iter().cloned());
+
+        EffectivePrefixes {
+            prefixes,
+            mention: on_mention(config),
+        }
+    }
+
+    /// Returns the prefixes which are shadowed by an earlier prefix, and can
+    /// thus never be used.
+    pub fn collisions(&self) -> Vec<PrefixCollision> {
+        collisions(&self.prefixes)
+    }
+
+    /// Describes how to invoke commands, suitable for replying to a user with.
+    ///
+    /// Prefixes which can never be used are left out.
+    pub fn describe(&self) -> String {
+        let usable = self
+            .prefixes
+            .iter()
+            .enumerate()
+            .filter(|(i, p)| !p.is_empty() && !is_shadowed(&self.prefixes[..*i], p))
+            .map(|(_, p)| format!("`{}`", p))
+            .collect::<Vec<_>>();
+
+        match (usable.len(), self.mention) {
+            (0, None) => String::from("I have no prefixes here."),
+            (0, Some(id)) => format!("Mention me to use my commands: {}", id.mention()),
+            (len, mention) => {
+                let mut text = if len == 1 {
+                    format!("My prefix here is {}.", usable[0])
+                } else {
+                    format!("My prefixes here are {}.", usable.join(", "))
+                };
+
+                if let Some(id) = mention {
+                    text.push_str(&format!(
+                        " You can also mention me instead: {}",
+                        id.mention()
+                    ));
+                }
+
+                text
+            }
+        }
+    }
+}
+
+fn on_mention(config: &Configuration) -> Option<UserId> {
+    config.on_mention.as_ref()?.parse().ok().map(UserId)
+}
+
+fn is_shadowed(earlier: &[String], prefix: &str) -> bool {
+    earlier.iter().any(|p| prefix.starts_with(p.as_str()))
+}
+
+pub(crate) fn collisions(prefixes: &[String]) -> Vec<PrefixCollision> {
+    let mut collisions = Vec::new();
+
+    for (i, prefix) in prefixes.iter().enumerate() {
+        let earlier = prefixes[..i]
+            .iter()
+            .find(|earlier| prefix.starts_with(earlier.as_str()));
+
+        if let Some(earlier) = earlier {
+            collisions.push(PrefixCollision {
+                prefix: prefix.clone(),
+                shadowed_by: earlier.clone(),
+            });
+        }
+    }
+
+    collisions
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EffectivePrefixes, PrefixCollision};
+    use crate::model::id::UserId;
+
+    fn prefixes(prefixes: &[&str], mention: Option<u64>) -> EffectivePrefixes {
+        EffectivePrefixes {
+            prefixes: prefixes.iter().map(|p| p.to_string()).collect(),
+            mention: mention.map(UserId),
+        }
+    }
+
+    #[test]
+    fn test_collisions() {
+        let effective = prefixes(&["!", "?", "!!", "?"], None);
+
+        assert_eq!(
+            effective.collisions(),
+            vec![
+                PrefixCollision {
+                    prefix: "!!".to_string(),
+                    shadowed_by: "!".to_string(),
+                },
+                PrefixCollision {
+                    prefix: "?".to_string(),
+                    shadowed_by: "?".to_string(),
+                },
+            ]
+        );
+        assert!(prefixes(&["!!", "!"], None).collisions().is_empty());
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(prefixes(&[], None).describe(), "I have no prefixes here.");
+        assert_eq!(
+            prefixes(&[], Some(5)).describe(),
+            "Mention me to use my commands: <@5>"
+        );
+        assert_eq!(
+            prefixes(&["!", "!!"], Some(5)).describe(),
+            "My prefix here is `!`. You can also mention me instead: <@5>"
+        );
+        assert_eq!(
+            prefixes(&["~", "?", "~"], None).describe(),
+            "My prefixes here are `~`, `?`."
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use crate::client::Context;
-use crate::model::id::{ChannelId, GuildId, UserId};
-use chrono::Utc;
+use crate::model::{
+    id::{ChannelId, GuildId, UserId},
+    Timestamp,
+};
 use std::collections::HashMap;
 
 type Check =
@@ -26,7 +28,7 @@ pub(crate) struct Bucket {
 
 impl Bucket {
     pub fn take(&mut self, user_id: u64) -> i64 {
-        let time = Utc::now().timestamp();
+        let time = Timestamp::now().unix_timestamp();
         let user = self
             .users
             .entry(user_id)
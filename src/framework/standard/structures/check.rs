@@ -1,8 +1,11 @@
 use crate::client::Context;
 use crate::framework::standard::{Args, CommandOptions};
 use crate::model::channel::Message;
+use crate::model::id::{GuildId, UserId};
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
+use std::time::{Duration, Instant};
 
 /// This type describes why a check has failed and occurs on
 /// [`CheckResult::Failure`].
@@ -152,6 +155,12 @@ pub struct Check {
     /// `false` won't affect whether the check will be evaluated help,
     /// solely `check_in_help` sets this.
     pub display_in_help: bool,
+    /// For how long the result of the check is reused for further messages
+    /// by the same user in the same guild, instead of running the check
+    /// again.
+    ///
+    /// `None` runs the check for every message.
+    pub cache_result: Option<Duration>,
 }
 
 impl Debug for Check {
@@ -161,6 +170,7 @@ impl Debug for Check {
             .field("function", &"<fn>")
             .field("check_in_help", &self.check_in_help)
             .field("display_in_help", &self.display_in_help)
+            .field("cache_result", &self.cache_result)
             .finish()
     }
 }
@@ -170,3 +180,135 @@ impl PartialEq for Check {
         self.name == other.name
     }
 }
+
+/// Results of checks with a [`cache_result`] duration, keyed by the check and
+/// the user and guild they were run for.
+///
+/// [`cache_result`]: struct.Check.html#structfield.cache_result
+#[derive(Debug, Default)]
+pub(crate) struct CheckResultCache {
+    results: HashMap<(usize, UserId, Option<GuildId>), (Instant, CheckResult)>,
+}
+
+impl CheckResultCache {
+    fn key(
+        check: &'static Check,
+        user_id: UserId,
+        guild_id: Option<GuildId>,
+    ) -> (usize, UserId, Option<GuildId>) {
+        // Checks are statics, and their names default to the same value, so
+        // they are told apart by address.
+        (check as *const Check as usize, user_id, guild_id)
+    }
+
+    /// Returns the result of the check for the user in the guild, if it is
+    /// cached and has not expired yet.
+    pub(crate) fn get(
+        &self,
+        check: &'static Check,
+        user_id: UserId,
+        guild_id: Option<GuildId>,
+    ) -> Option<CheckResult> {
+        let (expires_at, result) = self.results.get(&Self::key(check, user_id, guild_id))?;
+
+        if *expires_at > Instant::now() {
+            Some(result.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Stores the result of the check for the user in the guild, if the check
+    /// wants its results cached.
+    pub(crate) fn insert(
+        &mut self,
+        check: &'static Check,
+        user_id: UserId,
+        guild_id: Option<GuildId>,
+        result: &CheckResult,
+    ) {
+        let duration = match check.cache_result {
+            Some(duration) => duration,
+            None => return,
+        };
+
+        let now = Instant::now();
+        self.results.retain(|_, (expires_at, _)| *expires_at > now);
+        self.results.insert(
+            Self::key(check, user_id, guild_id),
+            (now + duration, result.clone()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::framework::standard::{Args, CommandOptions};
+
+    struct Fail;
+
+    impl AsyncCheckFunction for Fail {
+        fn check<'life0, 'life1, 'life2, 'life3, 'async_trait>(
+            &'life0 self,
+            _: &'life1 mut Context,
+            _: &'life2 Message,
+            _: &'life3 mut Args,
+            _: &'static CommandOptions,
+        ) -> core::pin::Pin<
+            Box<dyn core::future::Future<Output = CheckResult> + core::marker::Send + 'async_trait>,
+        >
+        where
+            'life0: 'async_trait,
+            'life1: 'async_trait,
+            'life2: 'async_trait,
+            'life3: 'async_trait,
+            Self: 'async_trait,
+        {
+            Box::pin(async { CheckResult::new_unknown() })
+        }
+    }
+
+    static CACHED: Check = Check {
+        name: "<fn>",
+        function: &Fail,
+        check_in_help: true,
+        display_in_help: true,
+        cache_result: Some(Duration::from_secs(60)),
+    };
+
+    static EXPIRED: Check = Check {
+        name: "<fn>",
+        function: &Fail,
+        check_in_help: true,
+        display_in_help: true,
+        cache_result: Some(Duration::from_secs(0)),
+    };
+
+    static UNCACHED: Check = Check {
+        name: "<fn>",
+        function: &Fail,
+        check_in_help: true,
+        display_in_help: true,
+        cache_result: None,
+    };
+
+    #[test]
+    fn test_check_result_cache() {
+        let mut cache = CheckResultCache::default();
+        let result = CheckResult::Success;
+
+        for check in &[&CACHED, &EXPIRED, &UNCACHED] {
+            cache.insert(check, UserId(1), Some(GuildId(2)), &result);
+        }
+
+        assert!(cache
+            .get(&CACHED, UserId(1), Some(GuildId(2)))
+            .unwrap()
+            .is_success());
+        assert!(cache.get(&CACHED, UserId(1), None).is_none());
+        assert!(cache.get(&CACHED, UserId(3), Some(GuildId(2))).is_none());
+        assert!(cache.get(&EXPIRED, UserId(1), Some(GuildId(2))).is_none());
+        assert!(cache.get(&UNCACHED, UserId(1), Some(GuildId(2))).is_none());
+    }
+}
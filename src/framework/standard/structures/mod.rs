@@ -2,7 +2,7 @@ use super::Args;
 use crate::client::Context;
 use crate::model::{channel::Message, id::UserId, permissions::Permissions};
 use crate::utils::Colour;
-use std::{collections::HashSet, fmt};
+use std::{collections::HashSet, fmt, time::Duration};
 
 pub mod buckets;
 mod check;
@@ -61,13 +61,33 @@ pub struct CommandOptions {
     pub sub_commands: &'static [&'static Command],
 }
 
+/// An error returned from a command's execution, passed on to the
+/// [`after`] hook.
+///
+/// Any error which implements `Display` converts into a
+/// [`CommandError::Message`], including [`serenity::Error`], `std::io::Error`
+/// and `Box<dyn Error + Send + Sync>`, so that `?` can be used on them inside
+/// commands.
+///
+/// [`CommandError::Message`]: #variant.Message
+/// [`serenity::Error`]: ../../enum.Error.html
+/// [`after`]: struct.StandardFramework.html#method.after
 #[derive(Debug, Clone)]
-pub struct CommandError(pub String);
+#[non_exhaustive]
+pub enum CommandError {
+    /// An error produced by the command itself.
+    Message(String),
+    /// The command did not finish within the [`command_timeout`] and was
+    /// cancelled. The attached value is the timeout that was exceeded.
+    ///
+    /// [`command_timeout`]: struct.Configuration.html#method.command_timeout
+    Timeout(Duration),
+}
 
 impl<T: fmt::Display> From<T> for CommandError {
     #[inline]
     fn from(d: T) -> Self {
-        CommandError(d.to_string())
+        CommandError::Message(d.to_string())
     }
 }
 
@@ -87,7 +107,8 @@ pub trait AsyncCommand: Send + Sync {
         Self: 'async_trait;
 }
 
-pub type CommandResult = ::std::result::Result<(), CommandError>;
+/// The result of a command's execution, or of a helper called by one.
+pub type CommandResult<T = ()> = ::std::result::Result<T, CommandError>;
 // TODO: remove pub type CommandFn = fn(Context, Message, Args) -> FutureCommandResult;
 
 pub struct Command {
@@ -1,3 +1,4 @@
+use super::ConnectionStage;
 use std::{
     error::Error as StdError,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -47,6 +48,18 @@ pub enum Error {
     OverloadedShard,
     /// Failed to reconnect after a number of attempts.
     ReconnectFailure,
+    /// An opcode which is only received, or which is managed by the shard
+    /// itself, was attempted to be sent as a raw command.
+    UnsendableOpCode(u8),
+    /// Failed to open a connection through the configured [`WsProxy`].
+    ///
+    /// [`WsProxy`]: enum.WsProxy.html
+    Proxy(String),
+    /// A stage of connecting took longer than its [`ConnectionTimeouts`]
+    /// allow.
+    ///
+    /// [`ConnectionTimeouts`]: struct.ConnectionTimeouts.html
+    Timeout(ConnectionStage),
 }
 
 impl Display for Error {
@@ -64,6 +77,9 @@ impl Display for Error {
             Error::NoSessionId => f.write_str("No Session Id present when required"),
             Error::OverloadedShard => f.write_str("Shard has too many guilds"),
             Error::ReconnectFailure => f.write_str("Failed to Reconnect"),
+            Error::UnsendableOpCode(op) => write!(f, "OpCode {} can not be sent", op),
+            Error::Proxy(why) => write!(f, "Failed to connect through proxy: {}", why),
+            Error::Timeout(stage) => write!(f, "Timed out while {}", stage),
         }
     }
 }
@@ -0,0 +1,112 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+/// Accumulated timings of a single stage of a shard's event pipeline.
+#[derive(Debug, Default)]
+pub struct StageTiming {
+    count: AtomicU64,
+    total_nanos: AtomicU64,
+    max_nanos: AtomicU64,
+}
+
+impl StageTiming {
+    /// Records one run of the stage.
+    pub fn record(&self, elapsed: Duration) {
+        let nanos = elapsed.as_nanos().min(u128::from(u64::MAX)) as u64;
+
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.total_nanos.fetch_add(nanos, Ordering::Relaxed);
+        self.max_nanos.fetch_max(nanos, Ordering::Relaxed);
+    }
+
+    /// The number of times the stage ran.
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// The total time spent in the stage.
+    pub fn total(&self) -> Duration {
+        Duration::from_nanos(self.total_nanos.load(Ordering::Relaxed))
+    }
+
+    /// The longest single run of the stage.
+    pub fn max(&self) -> Duration {
+        Duration::from_nanos(self.max_nanos.load(Ordering::Relaxed))
+    }
+
+    /// The average time of a run of the stage, if it ran at all.
+    pub fn mean(&self) -> Option<Duration> {
+        match self.count() {
+            0 => None,
+            count => Some(self.total() / count as u32),
+        }
+    }
+
+    /// Clears the recorded timings.
+    pub fn reset(&self) {
+        self.count.store(0, Ordering::Relaxed);
+        self.total_nanos.store(0, Ordering::Relaxed);
+        self.max_nanos.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Timings of each stage a shard's events go through, from the websocket to
+/// the event handlers.
+///
+/// Each shard runner records its own, which can be retrieved via
+/// [`ShardManager::stage_metrics`].
+///
+/// [`ShardManager::stage_metrics`]: ../client/bridge/gateway/struct.ShardManager.html#method.stage_metrics
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct StageMetrics {
+    /// Reading a message from the websocket.
+    pub ws_read: StageTiming,
+    /// Decompressing a binary message.
+    pub inflate: StageTiming,
+    /// Deserializing a message into a gateway event.
+    pub deserialize: StageTiming,
+    /// Updating the cache with an event.
+    pub cache_update: StageTiming,
+    /// Handing an event to the event handlers and framework.
+    ///
+    /// **Note**: As handlers are spawned as tasks, this does not include the
+    /// time the handlers themselves take.
+    pub dispatch: StageTiming,
+}
+
+impl StageMetrics {
+    /// Clears the timings of every stage.
+    pub fn reset(&self) {
+        self.ws_read.reset();
+        self.inflate.reset();
+        self.deserialize.reset();
+        self.cache_update.reset();
+        self.dispatch.reset();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::StageTiming;
+    use std::time::Duration;
+
+    #[test]
+    fn test_stage_timing() {
+        let timing = StageTiming::default();
+        assert_eq!(timing.mean(), None);
+
+        timing.record(Duration::from_millis(2));
+        timing.record(Duration::from_millis(4));
+
+        assert_eq!(timing.count(), 2);
+        assert_eq!(timing.total(), Duration::from_millis(6));
+        assert_eq!(timing.max(), Duration::from_millis(4));
+        assert_eq!(timing.mean(), Some(Duration::from_millis(3)));
+
+        timing.reset();
+        assert_eq!(timing.count(), 0);
+    }
+}
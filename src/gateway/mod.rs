@@ -47,11 +47,18 @@
 //! [docs]: https://discordapp.com/developers/docs/topics/gateway#sharding
 
 mod error;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod proxy;
 mod shard;
+mod timeouts;
 mod ws_client_ext;
 
+#[cfg(feature = "metrics")]
+pub use self::metrics::{StageMetrics, StageTiming};
 pub use self::{
-    error::Error as GatewayError, shard::Shard, ws_client_ext::WebSocketGatewayClientExt,
+    error::Error as GatewayError, proxy::WsProxy, shard::Shard, timeouts::ConnectionTimeouts,
+    ws_client_ext::WebSocketGatewayClientExt,
 };
 
 use crate::model::{gateway::Activity, user::OnlineStatus};
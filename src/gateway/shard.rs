@@ -1,9 +1,10 @@
 use super::{
-    ConnectionStage, CurrentPresence, GatewayError, ReconnectType, ShardAction,
-    WebSocketGatewayClientExt, WsClient,
+    ConnectionStage, ConnectionTimeouts, CurrentPresence, GatewayError, ReconnectType, ShardAction,
+    WebSocketGatewayClientExt, WsClient, WsProxy,
 };
 use crate::constants::{self, close_codes};
 use crate::internal::prelude::*;
+use crate::internal::ws_impl::create_proxied_client;
 use crate::model::{
     event::{Event, GatewayEvent},
     gateway::Activity,
@@ -13,6 +14,7 @@ use crate::model::{
 use futures::lock::Mutex;
 use log::{debug, error, info, trace, warn};
 use std::{
+    io::Error as IoError,
     sync::Arc,
     time::{Duration as StdDuration, Instant},
 };
@@ -85,8 +87,13 @@ pub struct Shard {
     // This acts as a timeout to determine if the shard has - for some reason -
     // not started within a decent amount of time.
     pub started: Instant,
+    /// Instant of when the shard entered its current stage, used to enforce
+    /// its connection timeouts.
+    stage_started: Instant,
+    timeouts: ConnectionTimeouts,
     pub token: String,
     ws_url: Arc<Mutex<String>>,
+    ws_proxy: Option<WsProxy>,
 }
 
 impl Shard {
@@ -130,7 +137,38 @@ impl Shard {
         shard_info: [u64; 2],
         guild_subscriptions: bool,
     ) -> Result<Shard> {
-        let mut client = connect(&*ws_url.lock().await)?;
+        Self::new_with_proxy(
+            ws_url,
+            token,
+            shard_info,
+            guild_subscriptions,
+            None,
+            ConnectionTimeouts::default(),
+        )
+        .await
+    }
+
+    /// Instantiates a new instance of a Shard like [`new`], opening its
+    /// websocket connections through the given proxy, if any, and giving up
+    /// on connecting after the given timeouts.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GatewayError::Timeout`] if the websocket connection could
+    /// not be opened in time.
+    ///
+    /// [`GatewayError::Timeout`]: enum.GatewayError.html#variant.Timeout
+    /// [`new`]: #method.new
+    pub async fn new_with_proxy(
+        ws_url: Arc<Mutex<String>>,
+        token: &str,
+        shard_info: [u64; 2],
+        guild_subscriptions: bool,
+        ws_proxy: Option<WsProxy>,
+        timeouts: ConnectionTimeouts,
+    ) -> Result<Shard> {
+        let url = ws_url.lock().await.clone();
+        let mut client = connect_with_timeout(url, ws_proxy.clone(), timeouts.connect).await?;
 
         // Configure timeout and buffer sizes. See the respective
         // methods for the reasoning behind changing the defaults.
@@ -155,11 +193,14 @@ impl Shard {
             seq,
             stage,
             started: Instant::now(),
+            stage_started: Instant::now(),
+            timeouts,
             token: token.to_string(),
             session_id,
             shard_info,
             guild_subscriptions,
             ws_url,
+            ws_proxy,
         })
     }
 
@@ -583,7 +624,7 @@ impl Shard {
             let heartbeat_interval = match self.heartbeat_interval {
                 Some(heartbeat_interval) => heartbeat_interval,
                 None => {
-                    return self.started.elapsed() < StdDuration::from_secs(15);
+                    return self.stage_started.elapsed() < self.timeouts.hello;
                 }
             };
 
@@ -621,6 +662,24 @@ impl Shard {
         }
     }
 
+    /// Checks whether the shard has spent longer in its current stage of
+    /// connecting than its [`ConnectionTimeouts`] allow, such as when the
+    /// gateway never sends a HELLO or READY.
+    ///
+    /// Returns the stage which timed out, if any, in which case the shard
+    /// should be restarted.
+    ///
+    /// [`ConnectionTimeouts`]: struct.ConnectionTimeouts.html
+    pub fn check_connection_timeout(&self) -> Option<ConnectionStage> {
+        let timeout = self.timeouts.for_stage(self.stage)?;
+
+        if self.stage_started.elapsed() > timeout {
+            Some(self.stage)
+        } else {
+            None
+        }
+    }
+
     /// Calculates the heartbeat latency between the shard and the gateway.
     // Shamelessly stolen from brayzure's commit in eris:
     // <https://github.com/abalabahaha/eris/commit/0ce296ae9a542bcec0edf1c999ee2d9986bed5a6>
@@ -749,11 +808,16 @@ impl Shard {
     // - the time that the last heartbeat sent as being now
     // - the `stage` to `Identifying`
     pub fn identify(&mut self) -> Result<()> {
-        self.client
-            .send_identify(&self.shard_info, &self.token, self.guild_subscriptions)?;
+        self.client.send_identify(
+            &self.shard_info,
+            &self.token,
+            self.guild_subscriptions,
+            &self.current_presence,
+        )?;
 
         self.heartbeat_instants.0 = Some(Instant::now());
         self.stage = ConnectionStage::Identifying;
+        self.stage_started = Instant::now();
 
         Ok(())
     }
@@ -775,8 +839,12 @@ impl Shard {
         // accurate when a Hello is received.
         self.stage = ConnectionStage::Connecting;
         self.started = Instant::now();
-        let mut client = connect(&self.ws_url.lock().await)?;
+        self.stage_started = self.started;
+        let url = self.ws_url.lock().await.clone();
+        let mut client =
+            connect_with_timeout(url, self.ws_proxy.clone(), self.timeouts.connect).await?;
         self.stage = ConnectionStage::Handshake;
+        self.stage_started = Instant::now();
 
         let _ = set_client_timeout(&mut client);
 
@@ -797,6 +865,7 @@ impl Shard {
 
         self.client = self.initialize().await?;
         self.stage = ConnectionStage::Resuming;
+        self.stage_started = Instant::now();
 
         match self.session_id.as_ref() {
             Some(session_id) => {
@@ -823,17 +892,45 @@ impl Shard {
 }
 
 #[cfg(not(feature = "native_tls_backend"))]
-fn connect(base_url: &str) -> Result<WsClient> {
+fn connect(base_url: &str, proxy: Option<&WsProxy>) -> Result<WsClient> {
     let url = build_gateway_url(base_url)?;
-    Ok(create_rustls_client(url)?)
+
+    match proxy {
+        Some(proxy) => create_proxied_client(url, proxy),
+        None => create_rustls_client(url),
+    }
 }
 
 #[cfg(feature = "native_tls_backend")]
-fn connect(base_url: &str) -> Result<WsClient> {
+fn connect(base_url: &str, proxy: Option<&WsProxy>) -> Result<WsClient> {
     let url = build_gateway_url(base_url)?;
-    let client = tungstenite::connect(url)?;
 
-    Ok(client.0)
+    match proxy {
+        Some(proxy) => create_proxied_client(url, proxy),
+        None => Ok(tungstenite::connect(url)?.0),
+    }
+}
+
+// The connection is opened with blocking IO, so it is made on a blocking
+// thread which is abandoned if it doesn't finish in time.
+async fn connect_with_timeout(
+    base_url: String,
+    proxy: Option<WsProxy>,
+    timeout: StdDuration,
+) -> Result<WsClient> {
+    let task = tokio::task::spawn_blocking(move || connect(&base_url, proxy.as_ref()));
+
+    match tokio::time::timeout(timeout, task).await {
+        Ok(Ok(result)) => result,
+        Ok(Err(why)) => Err(Error::Io(IoError::other(why))),
+        Err(_) => {
+            warn!("Timed out opening a gateway connection");
+
+            Err(Error::Gateway(GatewayError::Timeout(
+                ConnectionStage::Connecting,
+            )))
+        }
+    }
 }
 
 fn set_client_timeout(client: &mut WsClient) -> Result<()> {
@@ -875,3 +972,53 @@ fn build_gateway_url(base: &str) -> Result<Url> {
         Error::Gateway(GatewayError::BuildingUrl)
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_connect_timeout() {
+        // The listener never accepts, so the websocket handshake hangs.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        let mut timeouts = ConnectionTimeouts::default();
+        timeouts.connect = StdDuration::from_millis(100);
+
+        let started = Instant::now();
+        let result = Shard::new_with_proxy(
+            Arc::new(Mutex::new(url)),
+            "token",
+            [0, 1],
+            true,
+            None,
+            timeouts,
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Gateway(GatewayError::Timeout(
+                ConnectionStage::Connecting
+            )))
+        ));
+        assert!(started.elapsed() < StdDuration::from_secs(5));
+    }
+
+    #[test]
+    fn test_timeouts_for_stage() {
+        let timeouts = ConnectionTimeouts::default();
+
+        assert_eq!(
+            timeouts.for_stage(ConnectionStage::Handshake),
+            Some(timeouts.hello)
+        );
+        assert_eq!(
+            timeouts.for_stage(ConnectionStage::Resuming),
+            Some(timeouts.ready)
+        );
+        assert_eq!(timeouts.for_stage(ConnectionStage::Connected), None);
+    }
+}
@@ -0,0 +1,64 @@
+use super::ConnectionStage;
+use std::time::Duration;
+
+/// How long a [`Shard`] waits for each step of connecting to the gateway
+/// before giving up and being restarted.
+///
+/// Without these, a hung TLS handshake or a proxy which silently drops
+/// traffic would leave a shard stuck forever, receiving no events and
+/// reporting no error.
+///
+/// # Examples
+///
+/// Allow shards of a large bot more time to receive their READY:
+///
+/// ```rust
+/// use serenity::gateway::ConnectionTimeouts;
+/// use std::time::Duration;
+///
+/// let mut timeouts = ConnectionTimeouts::default();
+/// timeouts.ready = Duration::from_secs(120);
+/// ```
+///
+/// [`Shard`]: struct.Shard.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ConnectionTimeouts {
+    /// How long to wait for the websocket connection to be opened, including
+    /// the TLS handshake and connecting through a proxy.
+    ///
+    /// Defaults to 30 seconds.
+    pub connect: Duration,
+    /// How long to wait for a HELLO once connected.
+    ///
+    /// Defaults to 15 seconds.
+    pub hello: Duration,
+    /// How long to wait for a READY or RESUMED after sending an IDENTIFY or
+    /// RESUME.
+    ///
+    /// Defaults to 60 seconds.
+    pub ready: Duration,
+}
+
+impl ConnectionTimeouts {
+    /// Returns how long a shard may stay in a stage, if it is a stage of
+    /// connecting.
+    pub fn for_stage(&self, stage: ConnectionStage) -> Option<Duration> {
+        match stage {
+            ConnectionStage::Connecting => Some(self.connect),
+            ConnectionStage::Handshake => Some(self.hello),
+            ConnectionStage::Identifying | ConnectionStage::Resuming => Some(self.ready),
+            ConnectionStage::Connected | ConnectionStage::Disconnected => None,
+        }
+    }
+}
+
+impl Default for ConnectionTimeouts {
+    fn default() -> Self {
+        ConnectionTimeouts {
+            connect: Duration::from_secs(30),
+            hello: Duration::from_secs(15),
+            ready: Duration::from_secs(60),
+        }
+    }
+}
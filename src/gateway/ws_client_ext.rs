@@ -2,8 +2,7 @@ use crate::constants::{self, OpCode};
 use crate::gateway::{CurrentPresence, WsClient};
 use crate::internal::prelude::*;
 use crate::internal::ws_impl::SenderExt;
-use crate::model::id::GuildId;
-use chrono::Utc;
+use crate::model::{id::GuildId, Timestamp};
 use log::{debug, trace};
 use serde_json::json;
 use std::env::consts;
@@ -26,6 +25,7 @@ pub trait WebSocketGatewayClientExt {
         shard_info: &[u64; 2],
         token: &str,
         guild_subscriptions: bool,
+        current_presence: &CurrentPresence,
     ) -> Result<()>;
 
     fn send_presence_update(
@@ -82,6 +82,7 @@ impl WebSocketGatewayClientExt for WsClient {
         shard_info: &[u64; 2],
         token: &str,
         guild_subscriptions: bool,
+        current_presence: &CurrentPresence,
     ) -> Result<()> {
         debug!("[Shard {:?}] Identifying", shard_info);
 
@@ -91,6 +92,7 @@ impl WebSocketGatewayClientExt for WsClient {
                 "compress": true,
                 "large_threshold": constants::LARGE_THRESHOLD,
                 "guild_subscriptions": guild_subscriptions,
+                "presence": presence_json(current_presence),
                 "shard": shard_info,
                 "token": token,
                 "v": constants::GATEWAY_VERSION,
@@ -108,23 +110,11 @@ impl WebSocketGatewayClientExt for WsClient {
         shard_info: &[u64; 2],
         current_presence: &CurrentPresence,
     ) -> Result<()> {
-        let &(ref activity, ref status) = current_presence;
-        let now = Utc::now().timestamp() as u64;
-
         debug!("[Shard {:?}] Sending presence update", shard_info);
 
         self.send_json(&json!({
             "op": OpCode::StatusUpdate.num(),
-            "d": {
-                "afk": false,
-                "since": now,
-                "status": status.name(),
-                "game": activity.as_ref().map(|x| json!({
-                    "name": x.name,
-                    "type": x.kind,
-                    "url": x.url,
-                })),
-            },
+            "d": presence_json(current_presence),
         }))
     }
 
@@ -148,3 +138,19 @@ impl WebSocketGatewayClientExt for WsClient {
         .map_err(From::from)
     }
 }
+
+fn presence_json(current_presence: &CurrentPresence) -> Value {
+    let &(ref activity, ref status) = current_presence;
+    let now = Timestamp::now().unix_timestamp() as u64;
+
+    json!({
+        "afk": false,
+        "since": now,
+        "status": status.name(),
+        "game": activity.as_ref().map(|x| json!({
+            "name": x.name,
+            "type": x.kind,
+            "url": x.url,
+        })),
+    })
+}
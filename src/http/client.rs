@@ -1,28 +1,40 @@
+#[cfg(feature = "metrics")]
+use super::HttpMetrics;
 use super::{
+    middleware::RequestMiddleware,
     ratelimiting::{RatelimitedRequest, Ratelimiter},
     request::Request,
-    routing::RouteInfo,
-    AttachmentType, GuildPagination, HttpError,
+    retry::RetryPolicy,
+    routing::{Route, RouteInfo},
+    AttachmentType, GuildPagination, HttpError, HttpTimeouts, UserPagination,
 };
 use crate::constants;
 use crate::http::error::ErrorResponse;
+use crate::internal::json;
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
+use crate::SyncRwLock;
+use async_stream::try_stream;
+use futures::Stream;
 use log::{debug, trace};
 use reqwest::{
     header::{HeaderMap as Headers, HeaderValue, AUTHORIZATION, CONTENT_TYPE, USER_AGENT},
     multipart::Part,
-    Client, ClientBuilder, Response as ReqwestResponse, StatusCode, Url,
+    Body, Client, ClientBuilder, Response as ReqwestResponse, StatusCode, Url,
 };
 use serde::de::DeserializeOwned;
 use serde_json::json;
-use std::io::Read;
-use std::{borrow::Cow, collections::BTreeMap, sync::Arc};
+use std::io::{Error as IoError, Read};
+use std::{borrow::Cow, collections::BTreeMap, num::NonZeroU32, sync::Arc};
+use tokio::time::sleep;
+use tokio_util::io::ReaderStream;
 
 pub struct Http {
     client: Arc<Client>,
     pub ratelimiter: Ratelimiter,
     pub token: String,
+    middleware: SyncRwLock<Vec<Arc<dyn RequestMiddleware>>>,
+    retry_policy: SyncRwLock<Arc<RetryPolicy>>,
 }
 
 impl Http {
@@ -33,6 +45,8 @@ impl Http {
             client,
             ratelimiter: Ratelimiter::new(client2, token.to_string()),
             token: token.to_string(),
+            middleware: SyncRwLock::new(Vec::new()),
+            retry_policy: SyncRwLock::new(Arc::new(RetryPolicy::default())),
         }
     }
 
@@ -43,6 +57,177 @@ impl Http {
         Self::new(Arc::new(built), token)
     }
 
+    /// Creates a new `Http` which gives up on requests after the given
+    /// timeouts, failing them with [`HttpError::Timeout`].
+    ///
+    /// Refer to [`HttpTimeouts`] for an example.
+    ///
+    /// [`HttpError::Timeout`]: ../error/enum.Error.html#variant.Timeout
+    /// [`HttpTimeouts`]: ../struct.HttpTimeouts.html
+    pub fn new_with_timeouts(token: &str, timeouts: HttpTimeouts) -> Self {
+        let builder = timeouts.apply(configure_client_backend(Client::builder()));
+        let built = builder.build().expect("Cannot build reqwest::Client");
+
+        Self::new(Arc::new(built), token)
+    }
+
+    /// Creates a new `Http` sending requests through the given `reqwest`
+    /// client, allowing its TLS backend, connection pool and timeouts to be
+    /// configured.
+    ///
+    /// The token is used as is, so a bot token must be prefixed with `"Bot "`.
+    ///
+    /// **Note**: The `User-Agent` header is always set by the library, as
+    /// Discord requires it to follow a specific format.
+    ///
+    /// # Examples
+    ///
+    /// Use a client which times out requests after 10 seconds:
+    ///
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use serenity::http::Http;
+    /// use std::time::Duration;
+    ///
+    /// let client = Client::builder()
+    ///     .timeout(Duration::from_secs(10))
+    ///     .build()
+    ///     .expect("Failed to build the client");
+    ///
+    /// let http = Http::new_with_client(client, "Bot token");
+    /// ```
+    pub fn new_with_client(client: Client, token: &str) -> Self {
+        Self::new(Arc::new(client), token)
+    }
+
+    /// Sets the number of requests per second assumed to be allowed globally,
+    /// delaying requests which would exceed it.
+    ///
+    /// Discord allows 50 requests per second for most bots, but the limit is
+    /// never reported, so by default requests are only delayed once Discord
+    /// reports the global ratelimit was hit. Processes sharing a token can
+    /// each be given a part of the budget to avoid hitting it at all, as
+    /// repeatedly doing so may get the bot's IP temporarily banned.
+    ///
+    /// Pass `None` to remove the limit.
+    ///
+    /// # Examples
+    ///
+    /// Use half of the global budget, sharing the token with another process:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// use std::num::NonZeroU32;
+    ///
+    /// # let http = Http::default();
+    /// http.set_global_ratelimit(NonZeroU32::new(25));
+    /// ```
+    pub fn set_global_ratelimit(&self, requests_per_second: Option<NonZeroU32>) {
+        self.ratelimiter.set_global_rate(requests_per_second);
+    }
+
+    /// Sets the maximum number of requests awaiting a response at once,
+    /// making further requests wait for one to complete.
+    ///
+    /// Requests which are in flight while the limit is changed are not
+    /// counted towards the new limit.
+    ///
+    /// Pass `None` to remove the limit, which is the default.
+    pub fn set_max_in_flight(&self, max: Option<usize>) {
+        self.ratelimiter.set_max_in_flight(max);
+    }
+
+    /// Sets the version of Discord's API requests are sent to, replacing
+    /// the default of [`API_VERSION`].
+    ///
+    /// This allows opting into a newer version before the library's default
+    /// changes, or staying on an older one until its sunset. Routes are the
+    /// same across versions, but payloads may differ, so models may fail to
+    /// deserialize responses of versions the library does not support yet.
+    ///
+    /// **Note**: This does not affect the gateway, which uses
+    /// [`GATEWAY_VERSION`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// let http = Http::new_with_token("Bot token");
+    /// http.set_api_version(8);
+    /// ```
+    ///
+    /// [`API_VERSION`]: ../../constants/constant.API_VERSION.html
+    /// [`GATEWAY_VERSION`]: ../../constants/constant.GATEWAY_VERSION.html
+    pub fn set_api_version(&self, version: u8) {
+        self.ratelimiter.set_api_version(version);
+    }
+
+    /// Returns the version of Discord's API requests are sent to.
+    pub fn api_version(&self) -> u8 {
+        self.ratelimiter.api_version()
+    }
+
+    /// Sets the policy for retrying requests which spuriously failed,
+    /// replacing the default of retrying requests which could not be sent
+    /// once.
+    ///
+    /// Requests uploading files are never retried, as their bodies can not
+    /// be sent again.
+    ///
+    /// Refer to [`RetryPolicy`] for more information.
+    ///
+    /// [`RetryPolicy`]: ../retry/struct.RetryPolicy.html
+    pub fn set_retry_policy(&self, policy: RetryPolicy) {
+        *self.retry_policy.write() = Arc::new(policy);
+    }
+
+    /// Returns the policy for retrying requests.
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::clone(&self.retry_policy.read())
+    }
+
+    /// Returns the number, errors and latencies of the requests made to each
+    /// route.
+    ///
+    /// Refer to [`HttpMetrics`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Logging the error rate of every route:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # let http = Http::default();
+    /// for (route, metrics) in http.metrics().routes() {
+    ///     println!("{:?}: {}/{} failed", route, metrics.errors(), metrics.requests());
+    /// }
+    /// ```
+    ///
+    /// [`HttpMetrics`]: ../struct.HttpMetrics.html
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> &HttpMetrics {
+        &self.ratelimiter.metrics
+    }
+
+    /// Adds a middleware to run around every request, after any previously
+    /// added ones.
+    ///
+    /// Refer to [`RequestMiddleware`] for more information.
+    ///
+    /// [`RequestMiddleware`]: ../middleware/trait.RequestMiddleware.html
+    pub fn add_middleware<M: RequestMiddleware + 'static>(&self, middleware: M) {
+        self.middleware.write().push(Arc::new(middleware));
+    }
+
+    /// Removes all middleware added with [`add_middleware`].
+    ///
+    /// [`add_middleware`]: #method.add_middleware
+    pub fn clear_middleware(&self) {
+        self.middleware.write().clear();
+    }
+
     /// Adds a [`User`] as a recipient to a [`Group`].
     ///
     /// **Note**: Groups have a limit of 10 recipients, including the current user.
@@ -87,6 +272,24 @@ impl Http {
         .await
     }
 
+    /// Adds a user to a [`GuildThread`].
+    ///
+    /// [`GuildThread`]: ../../model/channel/struct.GuildThread.html
+    pub async fn add_thread_channel_member(&self, channel_id: u64, user_id: u64) -> Result<()> {
+        self.wind(
+            204,
+            Request {
+                body: None,
+                headers: None,
+                route: RouteInfo::AddThreadMember {
+                    channel_id,
+                    user_id,
+                },
+            },
+        )
+        .await
+    }
+
     /// Bans a [`User`] from a [`Guild`], removing their messages sent in the last
     /// X number of days.
     ///
@@ -109,7 +312,7 @@ impl Http {
             204,
             Request {
                 body: None,
-                headers: None,
+                headers: audit_log_reason_headers(reason)?,
                 route: RouteInfo::GuildBanUser {
                     delete_message_days: Some(delete_message_days),
                     reason: Some(reason),
@@ -153,11 +356,26 @@ impl Http {
     /// [docs]: https://discordapp.com/developers/docs/resources/guild#create-guild-channel
     /// [Manage Channels]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
     pub async fn create_channel(&self, guild_id: u64, map: &JsonMap) -> Result<GuildChannel> {
+        self.create_channel_with_reason(guild_id, map, "").await
+    }
+
+    /// Creates a channel in a guild, recording the provided reason in the
+    /// guild's audit log.
+    ///
+    /// Refer to [`create_channel`] for more information.
+    ///
+    /// [`create_channel`]: #method.create_channel
+    pub async fn create_channel_with_reason(
+        &self,
+        guild_id: u64,
+        map: &JsonMap,
+        reason: &str,
+    ) -> Result<GuildChannel> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason_headers(reason)?,
             route: RouteInfo::CreateChannel { guild_id },
         })
         .await
@@ -184,6 +402,77 @@ impl Http {
         .await
     }
 
+    /// Sends a follow-up message to an [`Interaction`], after it has been
+    /// responded to.
+    ///
+    /// Refer to Discord's [docs] for the fields the map can contain.
+    ///
+    /// [`Interaction`]: ../../model/interactions/struct.Interaction.html
+    /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#create-followup-message
+    pub async fn create_followup_message(
+        &self,
+        application_id: u64,
+        interaction_token: &str,
+        map: &Value,
+    ) -> Result<Message> {
+        let body = map.to_string();
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateFollowupMessage {
+                application_id,
+                interaction_token,
+            },
+        })
+        .await
+    }
+
+    /// Creates a global [`ApplicationCommand`], or replaces the existing one
+    /// of the same name.
+    ///
+    /// Refer to Discord's [docs] for the fields the map can contain.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#create-global-application-command
+    pub async fn create_global_application_command(
+        &self,
+        application_id: u64,
+        map: &Value,
+    ) -> Result<ApplicationCommand> {
+        let body = map.to_string();
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateGlobalApplicationCommand { application_id },
+        })
+        .await
+    }
+
+    /// Creates an [`ApplicationCommand`] in a guild, or replaces the existing
+    /// one of the same name.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn create_guild_application_command(
+        &self,
+        application_id: u64,
+        guild_id: u64,
+        map: &Value,
+    ) -> Result<ApplicationCommand> {
+        let body = map.to_string();
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateGuildApplicationCommand {
+                application_id,
+                guild_id,
+            },
+        })
+        .await
+    }
+
     /// Creates a guild with the data provided.
     ///
     /// Only a [`PartialGuild`] will be immediately returned, and a full [`Guild`]
@@ -229,6 +518,30 @@ impl Http {
         .await
     }
 
+    /// Creates a [`Guild`] from a [`GuildTemplate`], owned by the current user.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// **Note**: This can only be used by bots in less than 10 guilds.
+    ///
+    /// [`Guild`]: ../../model/guild/struct.Guild.html
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    /// [docs]: https://discord.com/developers/docs/resources/guild-template#create-guild-from-guild-template
+    pub async fn create_guild_from_template(
+        &self,
+        code: &str,
+        map: &Value,
+    ) -> Result<PartialGuild> {
+        let body = map.to_string();
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateGuildFromTemplate { code },
+        })
+        .await
+    }
+
     /// Creates an [`Integration`] for a [`Guild`].
     ///
     /// Refer to Discord's [docs] for field information.
@@ -261,6 +574,60 @@ impl Http {
         .await
     }
 
+    /// Creates a [`GuildTemplate`] from the current state of a [`Guild`].
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`Guild`]: ../../model/guild/struct.Guild.html
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    /// [docs]: https://discord.com/developers/docs/resources/guild-template#create-guild-template
+    pub async fn create_guild_template(&self, guild_id: u64, map: &Value) -> Result<GuildTemplate> {
+        let body = map.to_string();
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateGuildTemplate { guild_id },
+        })
+        .await
+    }
+
+    /// Responds to an [`Interaction`].
+    ///
+    /// An interaction must be responded to within 3 seconds, after which its
+    /// token is invalidated. Respond with a deferred message if more time is
+    /// needed, and edit it later with [`edit_original_interaction_response`].
+    ///
+    /// Refer to Discord's [docs] for the fields the map can contain.
+    ///
+    /// [`Interaction`]: ../../model/interactions/struct.Interaction.html
+    /// [`edit_original_interaction_response`]: #method.edit_original_interaction_response
+    /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#interaction-response
+    pub async fn create_interaction_response(
+        &self,
+        interaction_id: u64,
+        interaction_token: &str,
+        map: &Value,
+    ) -> Result<()> {
+        let body = map.to_string();
+
+        self.wind(
+            204,
+            Request {
+                body: Some(body.as_bytes()),
+                headers: None,
+                route: RouteInfo::CreateInteractionResponse {
+                    interaction_id,
+                    interaction_token,
+                },
+            },
+        )
+        .await
+    }
+
     /// Creates a [`RichInvite`] for the given [channel][`GuildChannel`].
     ///
     /// Refer to Discord's [docs] for field information.
@@ -345,16 +712,72 @@ impl Http {
 
     /// Creates a role.
     pub async fn create_role(&self, guild_id: u64, map: &JsonMap) -> Result<Role> {
+        self.create_role_with_reason(guild_id, map, "").await
+    }
+
+    /// Creates a role in a guild, recording the provided reason in the
+    /// guild's audit log.
+    pub async fn create_role_with_reason(
+        &self,
+        guild_id: u64,
+        map: &JsonMap,
+        reason: &str,
+    ) -> Result<Role> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason_headers(reason)?,
             route: RouteInfo::CreateRole { guild_id },
         })
         .await
     }
 
+    /// Starts a [`StageInstance`] in a stage channel.
+    ///
+    /// [`StageInstance`]: ../../model/channel/struct.StageInstance.html
+    pub async fn create_stage_instance(&self, map: &Value) -> Result<StageInstance> {
+        let body = map.to_string();
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateStageInstance,
+        })
+        .await
+    }
+
+    /// Uploads a [`Sticker`] to a guild.
+    ///
+    /// The map can contain the **name**, **description** and **tags** of the
+    /// sticker, which are sent alongside the file.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// [`Sticker`]: ../../model/sticker/struct.Sticker.html
+    /// [Manage Emojis]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    pub async fn create_sticker<'a>(
+        &self,
+        guild_id: u64,
+        map: JsonMap,
+        file: impl Into<AttachmentType<'a>>,
+    ) -> Result<Sticker> {
+        let mut multipart =
+            reqwest::multipart::Form::new().part("file", self.attachment_part(file.into()).await?);
+
+        for (k, v) in map {
+            if let Value::String(inner) = v {
+                multipart = multipart.text(k, inner);
+            }
+        }
+
+        let response = self
+            .multipart_request(&Route::guild_stickers(guild_id), multipart)
+            .await?;
+
+        decode(response).await
+    }
+
     /// Creates a webhook for the given [channel][`GuildChannel`]'s Id, passing in
     /// the given data.
     ///
@@ -383,16 +806,51 @@ impl Http {
     ///
     /// [`GuildChannel`]: ../../model/channel/struct.GuildChannel.html
     pub async fn create_webhook(&self, channel_id: u64, map: &Value) -> Result<Webhook> {
+        self.create_webhook_with_reason(channel_id, map, "").await
+    }
+
+    /// Creates a webhook for the given channel's Id, recording the provided
+    /// reason in the guild's audit log.
+    ///
+    /// Refer to [`create_webhook`] for more information.
+    ///
+    /// [`create_webhook`]: #method.create_webhook
+    pub async fn create_webhook_with_reason(
+        &self,
+        channel_id: u64,
+        map: &Value,
+        reason: &str,
+    ) -> Result<Webhook> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason_headers(reason)?,
             route: RouteInfo::CreateWebhook { channel_id },
         })
         .await
     }
 
+    /// Crossposts a message in a news channel, publishing it to the channels
+    /// following it.
+    ///
+    /// **Note**: Requires the [Send Messages] permission for messages sent by
+    /// the current user, or the [Manage Messages] permission otherwise.
+    ///
+    /// [Manage Messages]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    /// [Send Messages]: ../../model/permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    pub async fn crosspost_message(&self, channel_id: u64, message_id: u64) -> Result<Message> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::CrosspostMessage {
+                channel_id,
+                message_id,
+            },
+        })
+        .await
+    }
+
     /// Deletes a private channel or a channel in a guild.
     pub async fn delete_channel(&self, channel_id: u64) -> Result<Channel> {
         self.fire(Request {
@@ -416,16 +874,62 @@ impl Http {
         .await
     }
 
-    /// Deletes a guild, only if connected account owns it.
-    pub async fn delete_guild(&self, guild_id: u64) -> Result<PartialGuild> {
-        self.fire(Request {
-            body: None,
-            headers: None,
+    /// Deletes a global [`ApplicationCommand`].
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn delete_global_application_command(
+        &self,
+        application_id: u64,
+        command_id: u64,
+    ) -> Result<()> {
+        self.wind(
+            204,
+            Request {
+                body: None,
+                headers: None,
+                route: RouteInfo::DeleteGlobalApplicationCommand {
+                    application_id,
+                    command_id,
+                },
+            },
+        )
+        .await
+    }
+
+    /// Deletes a guild, only if connected account owns it.
+    pub async fn delete_guild(&self, guild_id: u64) -> Result<PartialGuild> {
+        self.fire(Request {
+            body: None,
+            headers: None,
             route: RouteInfo::DeleteGuild { guild_id },
         })
         .await
     }
 
+    /// Deletes an [`ApplicationCommand`] from a guild.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn delete_guild_application_command(
+        &self,
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    ) -> Result<()> {
+        self.wind(
+            204,
+            Request {
+                body: None,
+                headers: None,
+                route: RouteInfo::DeleteGuildApplicationCommand {
+                    application_id,
+                    guild_id,
+                    command_id,
+                },
+            },
+        )
+        .await
+    }
+
     /// Removes an integration from a guild.
     pub async fn delete_guild_integration(&self, guild_id: u64, integration_id: u64) -> Result<()> {
         self.wind(
@@ -442,6 +946,21 @@ impl Http {
         .await
     }
 
+    /// Deletes a guild's [`GuildTemplate`], returning it.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn delete_guild_template(&self, guild_id: u64, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteGuildTemplate { guild_id, code },
+        })
+        .await
+    }
+
     /// Deletes an invite by code.
     pub async fn delete_invite(&self, code: &str) -> Result<Invite> {
         self.fire(Request {
@@ -455,11 +974,23 @@ impl Http {
     /// Deletes a message if created by us or we have
     /// specific permissions.
     pub async fn delete_message(&self, channel_id: u64, message_id: u64) -> Result<()> {
+        self.delete_message_with_reason(channel_id, message_id, "")
+            .await
+    }
+
+    /// Deletes a message, recording the reason in the audit log when deleting
+    /// another user's message.
+    pub async fn delete_message_with_reason(
+        &self,
+        channel_id: u64,
+        message_id: u64,
+        reason: &str,
+    ) -> Result<()> {
         self.wind(
             204,
             Request {
                 body: None,
-                headers: None,
+                headers: audit_log_reason_headers(reason)?,
                 route: RouteInfo::DeleteMessage {
                     channel_id,
                     message_id,
@@ -471,13 +1002,24 @@ impl Http {
 
     /// Deletes a bunch of messages, only works for bots.
     pub async fn delete_messages(&self, channel_id: u64, map: &Value) -> Result<()> {
+        self.delete_messages_with_reason(channel_id, map, "").await
+    }
+
+    /// Deletes a bunch of messages with a provided reason, only works for
+    /// bots.
+    pub async fn delete_messages_with_reason(
+        &self,
+        channel_id: u64,
+        map: &Value,
+        reason: &str,
+    ) -> Result<()> {
         let body = map.to_string();
 
         self.wind(
             204,
             Request {
                 body: Some(body.as_bytes()),
-                headers: None,
+                headers: audit_log_reason_headers(reason)?,
                 route: RouteInfo::DeleteMessages { channel_id },
             },
         )
@@ -582,6 +1124,42 @@ impl Http {
         .await
     }
 
+    /// Ends the [`StageInstance`] running in the given stage channel.
+    ///
+    /// [`StageInstance`]: ../../model/channel/struct.StageInstance.html
+    pub async fn delete_stage_instance(&self, channel_id: u64) -> Result<()> {
+        self.wind(
+            204,
+            Request {
+                body: None,
+                headers: None,
+                route: RouteInfo::DeleteStageInstance { channel_id },
+            },
+        )
+        .await
+    }
+
+    /// Deletes a [`Sticker`] from a guild.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// [`Sticker`]: ../../model/sticker/struct.Sticker.html
+    /// [Manage Emojis]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    pub async fn delete_sticker(&self, guild_id: u64, sticker_id: u64) -> Result<()> {
+        self.wind(
+            204,
+            Request {
+                body: None,
+                headers: None,
+                route: RouteInfo::DeleteSticker {
+                    guild_id,
+                    sticker_id,
+                },
+            },
+        )
+        .await
+    }
+
     /// Deletes a [`Webhook`] given its Id.
     ///
     /// This method requires authentication, whereas [`delete_webhook_with_token`]
@@ -653,13 +1231,51 @@ impl Http {
         .await
     }
 
+    /// Downloads a file from Discord's CDN, such as an [`Attachment`], through
+    /// the client's connection pool.
+    ///
+    /// The `progress` callback is called with the number of bytes downloaded
+    /// so far each time a chunk of the file was received.
+    ///
+    /// [`Attachment`]: ../../model/channel/struct.Attachment.html
+    pub async fn download(&self, url: &str, mut progress: impl FnMut(u64)) -> Result<Vec<u8>> {
+        let mut response = self.client.get(url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(HttpError::UnsuccessfulRequest(
+                ErrorResponse::async_from_response(response).await,
+            )
+            .into());
+        }
+
+        let mut bytes = Vec::with_capacity(response.content_length().unwrap_or(0) as usize);
+
+        while let Some(chunk) = response.chunk().await? {
+            bytes.extend_from_slice(&chunk);
+            progress(bytes.len() as u64);
+        }
+
+        Ok(bytes)
+    }
+
     /// Changes channel information.
     pub async fn edit_channel(&self, channel_id: u64, map: &JsonMap) -> Result<GuildChannel> {
+        self.edit_channel_with_reason(channel_id, map, "").await
+    }
+
+    /// Changes channel information, recording the provided reason in the
+    /// guild's audit log.
+    pub async fn edit_channel_with_reason(
+        &self,
+        channel_id: u64,
+        map: &JsonMap,
+        reason: &str,
+    ) -> Result<GuildChannel> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason_headers(reason)?,
             route: RouteInfo::EditChannel { channel_id },
         })
         .await
@@ -677,6 +1293,28 @@ impl Http {
         .await
     }
 
+    /// Edits a global [`ApplicationCommand`].
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn edit_global_application_command(
+        &self,
+        application_id: u64,
+        command_id: u64,
+        map: &Value,
+    ) -> Result<ApplicationCommand> {
+        let body = map.to_string();
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: None,
+            route: RouteInfo::EditGlobalApplicationCommand {
+                application_id,
+                command_id,
+            },
+        })
+        .await
+    }
+
     /// Changes guild information.
     pub async fn edit_guild(&self, guild_id: u64, map: &JsonMap) -> Result<PartialGuild> {
         let body = serde_json::to_vec(map)?;
@@ -689,6 +1327,30 @@ impl Http {
         .await
     }
 
+    /// Edits an [`ApplicationCommand`] in a guild.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn edit_guild_application_command(
+        &self,
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+        map: &Value,
+    ) -> Result<ApplicationCommand> {
+        let body = map.to_string();
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: None,
+            route: RouteInfo::EditGuildApplicationCommand {
+                application_id,
+                guild_id,
+                command_id,
+            },
+        })
+        .await
+    }
+
     /// Edits the positions of a guild's channels.
     pub async fn edit_guild_channel_positions(&self, guild_id: u64, value: &Value) -> Result<()> {
         let body = serde_json::to_vec(value)?;
@@ -718,15 +1380,99 @@ impl Http {
         .await
     }
 
+    /// Edits the expiry settings of a guild's [`Integration`].
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`Integration`]: ../../model/guild/struct.Integration.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn edit_guild_integration(
+        &self,
+        guild_id: u64,
+        integration_id: u64,
+        map: &JsonMap,
+    ) -> Result<()> {
+        let body = serde_json::to_vec(map)?;
+
+        self.wind(
+            204,
+            Request {
+                body: Some(&body),
+                headers: None,
+                route: RouteInfo::EditGuildIntegration {
+                    guild_id,
+                    integration_id,
+                },
+            },
+        )
+        .await
+    }
+
+    /// Edits the name or description of a guild's [`GuildTemplate`].
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn edit_guild_template(
+        &self,
+        guild_id: u64,
+        code: &str,
+        map: &Value,
+    ) -> Result<GuildTemplate> {
+        let body = map.to_string();
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: None,
+            route: RouteInfo::EditGuildTemplate { guild_id, code },
+        })
+        .await
+    }
+
+    /// Edits a guild's [`WelcomeScreen`].
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`WelcomeScreen`]: ../../model/guild/struct.WelcomeScreen.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn edit_guild_welcome_screen(
+        &self,
+        guild_id: u64,
+        map: &JsonMap,
+    ) -> Result<WelcomeScreen> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGuildWelcomeScreen { guild_id },
+        })
+        .await
+    }
+
     /// Does specific actions to a member.
     pub async fn edit_member(&self, guild_id: u64, user_id: u64, map: &JsonMap) -> Result<()> {
+        self.edit_member_with_reason(guild_id, user_id, map, "")
+            .await
+    }
+
+    /// Does specific actions to a member, recording the provided reason in
+    /// the guild's audit log.
+    pub async fn edit_member_with_reason(
+        &self,
+        guild_id: u64,
+        user_id: u64,
+        map: &JsonMap,
+        reason: &str,
+    ) -> Result<()> {
         let body = serde_json::to_vec(map)?;
 
         self.wind(
             204,
             Request {
                 body: Some(&body),
-                headers: None,
+                headers: audit_log_reason_headers(reason)?,
                 route: RouteInfo::EditMember { guild_id, user_id },
             },
         )
@@ -775,6 +1521,31 @@ impl Http {
         .await
     }
 
+    /// Edits the initial response to an [`Interaction`].
+    ///
+    /// Refer to Discord's [docs] for the fields the map can contain.
+    ///
+    /// [`Interaction`]: ../../model/interactions/struct.Interaction.html
+    /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#edit-original-interaction-response
+    pub async fn edit_original_interaction_response(
+        &self,
+        application_id: u64,
+        interaction_token: &str,
+        map: &Value,
+    ) -> Result<Message> {
+        let body = map.to_string();
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: None,
+            route: RouteInfo::EditOriginalInteractionResponse {
+                application_id,
+                interaction_token,
+            },
+        })
+        .await
+    }
+
     /// Edits the current user's profile settings.
     pub async fn edit_profile(&self, map: &JsonMap) -> Result<CurrentUser> {
         let body = serde_json::to_vec(map)?;
@@ -785,20 +1556,30 @@ impl Http {
                 headers: None,
                 route: RouteInfo::EditProfile,
             })
-            .await?
-            .json::<CurrentUser>()
-            .await;
+            .await?;
 
-        response.map_err(From::from)
+        decode(response).await
     }
 
     /// Changes a role in a guild.
     pub async fn edit_role(&self, guild_id: u64, role_id: u64, map: &JsonMap) -> Result<Role> {
+        self.edit_role_with_reason(guild_id, role_id, map, "").await
+    }
+
+    /// Changes a role in a guild, recording the provided reason in the
+    /// guild's audit log.
+    pub async fn edit_role_with_reason(
+        &self,
+        guild_id: u64,
+        role_id: u64,
+        map: &JsonMap,
+        reason: &str,
+    ) -> Result<Role> {
         let body = serde_json::to_vec(&map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason_headers(reason)?,
             route: RouteInfo::EditRole { guild_id, role_id },
         })
         .await
@@ -824,23 +1605,62 @@ impl Http {
         .await
     }
 
-    /// Edits a the webhook with the given data.
-    ///
-    /// The Value is a map with optional values of:
-    ///
-    /// - **avatar**: base64-encoded 128x128 image for the webhook's default avatar
-    ///   (_optional_);
-    /// - **name**: the name of the webhook, limited to between 2 and 100 characters
-    ///   long.
+    /// Edits the [`StageInstance`] running in the given stage channel.
     ///
-    /// Note that, unlike with [`create_webhook`], _all_ values are optional.
-    ///
-    /// This method requires authentication, whereas [`edit_webhook_with_token`]
-    /// does not.
+    /// [`StageInstance`]: ../../model/channel/struct.StageInstance.html
+    pub async fn edit_stage_instance(&self, channel_id: u64, map: &Value) -> Result<StageInstance> {
+        let body = map.to_string();
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: None,
+            route: RouteInfo::EditStageInstance { channel_id },
+        })
+        .await
+    }
+
+    /// Edits a [`Sticker`] of a guild.
     ///
-    /// # Examples
+    /// **Note**: Requires the [Manage Emojis] permission.
     ///
-    /// Edit the image of a webhook given its Id and unique token:
+    /// [`Sticker`]: ../../model/sticker/struct.Sticker.html
+    /// [Manage Emojis]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    pub async fn edit_sticker(
+        &self,
+        guild_id: u64,
+        sticker_id: u64,
+        map: &JsonMap,
+    ) -> Result<Sticker> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditSticker {
+                guild_id,
+                sticker_id,
+            },
+        })
+        .await
+    }
+
+    /// Edits a the webhook with the given data.
+    ///
+    /// The Value is a map with optional values of:
+    ///
+    /// - **avatar**: base64-encoded 128x128 image for the webhook's default avatar
+    ///   (_optional_);
+    /// - **name**: the name of the webhook, limited to between 2 and 100 characters
+    ///   long.
+    ///
+    /// Note that, unlike with [`create_webhook`], _all_ values are optional.
+    ///
+    /// This method requires authentication, whereas [`edit_webhook_with_token`]
+    /// does not.
+    ///
+    /// # Examples
+    ///
+    /// Edit the image of a webhook given its Id and unique token:
     ///
     /// ```rust,ignore
     /// use serde_json::builder::ObjectBuilder;
@@ -996,11 +1816,72 @@ impl Http {
             return Ok(None);
         }
 
-        response
-            .json::<Message>()
-            .await
-            .map(Some)
-            .map_err(From::from)
+        decode(response).await.map(Some)
+    }
+
+    /// Executes a webhook, uploading files along with the message.
+    ///
+    /// Refer to [`execute_webhook`] for the meaning of `wait` and the fields
+    /// the map can contain.
+    ///
+    /// This method does _not_ require authentication.
+    ///
+    /// [`execute_webhook`]: #method.execute_webhook
+    pub async fn execute_webhook_with_files<'a, T, It: IntoIterator<Item = T>>(
+        &self,
+        webhook_id: u64,
+        token: &str,
+        wait: bool,
+        files: It,
+        map: JsonMap,
+    ) -> Result<Option<Message>>
+    where
+        T: Into<AttachmentType<'a>>,
+    {
+        let mut multipart = reqwest::multipart::Form::new();
+
+        for (num, file) in files.into_iter().enumerate() {
+            multipart = multipart.part(
+                format!("file{}", num),
+                self.attachment_part(file.into()).await?,
+            );
+        }
+
+        multipart = multipart.text("payload_json", serde_json::to_string(&map)?);
+
+        let path = Route::webhook_with_token_optioned(webhook_id, token, wait);
+        let response = self.multipart_request(&path, multipart).await?;
+
+        if response.status() == StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+
+        decode(response).await.map(Some)
+    }
+
+    /// Follows a news channel, creating a webhook in the target channel which
+    /// messages crossposted in the news channel are sent to.
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission in the target
+    /// channel.
+    ///
+    /// [Manage Webhooks]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_WEBHOOKS
+    pub async fn follow_news_channel(
+        &self,
+        news_channel_id: u64,
+        target_channel_id: u64,
+    ) -> Result<FollowedChannel> {
+        let map = json!({ "webhook_channel_id": target_channel_id.to_string() });
+        let body = serde_json::to_vec(&map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::FollowNewsChannel {
+                channel_id: news_channel_id,
+            },
+        })
+        .await
     }
 
     /// Gets the active maintenances from Discord's Status API.
@@ -1015,7 +1896,7 @@ impl Http {
             })
             .await?;
 
-        let mut map: BTreeMap<String, Value> = response.json().await?;
+        let mut map: BTreeMap<String, Value> = decode(response).await?;
 
         match map.remove("scheduled_maintenances") {
             Some(v) => serde_json::from_value::<Vec<Maintenance>>(v).map_err(From::from),
@@ -1023,16 +1904,88 @@ impl Http {
         }
     }
 
-    /// Gets all the users that are banned in specific guild.
-    pub async fn get_bans(&self, guild_id: u64) -> Result<Vec<Ban>> {
+    /// Gets a page of the users that are banned in specific guild, ordered by
+    /// Id.
+    ///
+    /// Discord returns at most 1000 bans per request, defaulting to all bans
+    /// if no `limit` is given. Use [`get_bans_iter`] to retrieve every ban
+    /// regardless of the limit.
+    ///
+    /// [`get_bans_iter`]: #method.get_bans_iter
+    pub async fn get_bans(
+        &self,
+        guild_id: u64,
+        target: Option<UserPagination>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Ban>> {
+        let (after, before) = match target {
+            Some(UserPagination::After(id)) => (Some(id.0), None),
+            Some(UserPagination::Before(id)) => (None, Some(id.0)),
+            None => (None, None),
+        };
+
         self.fire(Request {
             body: None,
             headers: None,
-            route: RouteInfo::GetBans { guild_id },
+            route: RouteInfo::GetBans {
+                guild_id,
+                after,
+                before,
+                limit,
+            },
         })
         .await
     }
 
+    /// Streams all of the users that are banned in a guild, ordered by Id.
+    ///
+    /// Bans are requested in pages of 1000 as the stream is polled, each page
+    /// starting after the last ban of the previous one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// use futures::{pin_mut, StreamExt};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// let bans = http.get_bans_iter(81384788765712384);
+    /// pin_mut!(bans);
+    ///
+    /// while let Some(ban) = bans.next().await {
+    ///     println!("{}", ban?.user.name);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn get_bans_iter(&self, guild_id: u64) -> impl Stream<Item = Result<Ban>> + '_ {
+        let grab_size = 1000;
+
+        try_stream! {
+            let mut after = UserId(0);
+
+            loop {
+                let page = self
+                    .get_bans(guild_id, Some(UserPagination::After(after)), Some(grab_size))
+                    .await?;
+                let done = page.len() < grab_size as usize;
+
+                if let Some(last) = page.last() {
+                    after = last.user.id;
+                }
+
+                for ban in page {
+                    yield ban;
+                }
+
+                if done {
+                    break;
+                }
+            }
+        }
+    }
+
     /// Gets all audit logs in a specific guild.
     pub async fn get_audit_logs(
         &self,
@@ -1109,6 +2062,52 @@ impl Http {
         .await
     }
 
+    /// Gets the archived private threads of a channel, most recently archived
+    /// first.
+    ///
+    /// `before` is an ISO8601 timestamp, to only return threads archived
+    /// before it.
+    pub async fn get_channel_archived_private_threads(
+        &self,
+        channel_id: u64,
+        before: Option<&str>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetArchivedPrivateThreads {
+                channel_id,
+                before,
+                limit,
+            },
+        })
+        .await
+    }
+
+    /// Gets the archived public threads of a channel, most recently archived
+    /// first.
+    ///
+    /// `before` is an ISO8601 timestamp, to only return threads archived
+    /// before it.
+    pub async fn get_channel_archived_public_threads(
+        &self,
+        channel_id: u64,
+        before: Option<&str>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetArchivedPublicThreads {
+                channel_id,
+                before,
+                limit,
+            },
+        })
+        .await
+    }
+
     /// Gets channel information.
     pub async fn get_channel(&self, channel_id: u64) -> Result<Channel> {
         self.fire(Request {
@@ -1161,6 +2160,40 @@ impl Http {
         .await
     }
 
+    /// Gets a global [`ApplicationCommand`].
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn get_global_application_command(
+        &self,
+        application_id: u64,
+        command_id: u64,
+    ) -> Result<ApplicationCommand> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGlobalApplicationCommand {
+                application_id,
+                command_id,
+            },
+        })
+        .await
+    }
+
+    /// Gets all of the application's global [`ApplicationCommand`]s.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn get_global_application_commands(
+        &self,
+        application_id: u64,
+    ) -> Result<Vec<ApplicationCommand>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGlobalApplicationCommands { application_id },
+        })
+        .await
+    }
+
     /// Gets guild information.
     pub async fn get_guild(&self, guild_id: u64) -> Result<PartialGuild> {
         self.fire(Request {
@@ -1171,6 +2204,46 @@ impl Http {
         .await
     }
 
+    /// Gets an [`ApplicationCommand`] of a guild.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn get_guild_application_command(
+        &self,
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    ) -> Result<ApplicationCommand> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildApplicationCommand {
+                application_id,
+                guild_id,
+                command_id,
+            },
+        })
+        .await
+    }
+
+    /// Gets all of the application's [`ApplicationCommand`]s in a guild.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn get_guild_application_commands(
+        &self,
+        application_id: u64,
+        guild_id: u64,
+    ) -> Result<Vec<ApplicationCommand>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildApplicationCommands {
+                application_id,
+                guild_id,
+            },
+        })
+        .await
+    }
+
     /// Gets a guild embed information.
     pub async fn get_guild_embed(&self, guild_id: u64) -> Result<GuildEmbed> {
         self.fire(Request {
@@ -1201,23 +2274,24 @@ impl Http {
         .await
     }
 
-    /// Gets a guild's vanity URL if it has one.
-    pub async fn get_guild_vanity_url(&self, guild_id: u64) -> Result<String> {
-        #[derive(Deserialize)]
-        struct GuildVanityUrl {
-            code: String,
-        }
+    /// Gets all active threads in a guild that the current user can see.
+    pub async fn get_guild_active_threads(&self, guild_id: u64) -> Result<ThreadsData> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetActiveThreads { guild_id },
+        })
+        .await
+    }
 
-        self.request(Request {
+    /// Gets a guild's vanity invite, along with how many times it was used.
+    pub async fn get_guild_vanity_url(&self, guild_id: u64) -> Result<VanityInvite> {
+        self.fire(Request {
             body: None,
             headers: None,
             route: RouteInfo::GetGuildVanityUrl { guild_id },
         })
-        .await?
-        .json::<GuildVanityUrl>()
         .await
-        .map(|x| x.code)
-        .map_err(From::from)
     }
 
     /// Gets the members of a guild. Optionally pass a `limit` and the Id of the
@@ -1240,7 +2314,7 @@ impl Http {
             })
             .await?;
 
-        let mut v = response.json::<Value>().await?;
+        let mut v = decode::<Value>(response).await?;
 
         if let Some(values) = v.as_array_mut() {
             let num = Value::Number(Number::from(guild_id));
@@ -1299,6 +2373,48 @@ impl Http {
         .await
     }
 
+    /// Gets a [`Sticker`] of a guild.
+    ///
+    /// [`Sticker`]: ../../model/sticker/struct.Sticker.html
+    pub async fn get_guild_sticker(&self, guild_id: u64, sticker_id: u64) -> Result<Sticker> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildSticker {
+                guild_id,
+                sticker_id,
+            },
+        })
+        .await
+    }
+
+    /// Gets the [`Sticker`]s of a guild.
+    ///
+    /// [`Sticker`]: ../../model/sticker/struct.Sticker.html
+    pub async fn get_guild_stickers(&self, guild_id: u64) -> Result<Vec<Sticker>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildStickers { guild_id },
+        })
+        .await
+    }
+
+    /// Gets the [`GuildTemplate`]s of a guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn get_guild_templates(&self, guild_id: u64) -> Result<Vec<GuildTemplate>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildTemplates { guild_id },
+        })
+        .await
+    }
+
     /// Retrieves the webhooks for the given [guild][`Guild`]'s Id.
     ///
     /// This method requires authentication.
@@ -1331,6 +2447,18 @@ impl Http {
         .await
     }
 
+    /// Gets a guild's [`WelcomeScreen`].
+    ///
+    /// [`WelcomeScreen`]: ../../model/guild/struct.WelcomeScreen.html
+    pub async fn get_guild_welcome_screen(&self, guild_id: u64) -> Result<WelcomeScreen> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildWelcomeScreen { guild_id },
+        })
+        .await
+    }
+
     /// Gets a paginated list of the current user's guilds.
     ///
     /// The `limit` has a maximum value of 100.
@@ -1375,6 +2503,55 @@ impl Http {
         .await
     }
 
+    /// Streams all of the guilds the current user is in, ordered by Id.
+    ///
+    /// Guilds are requested in pages of 100 as the stream is polled, each
+    /// page starting after the last guild of the previous one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// use futures::{pin_mut, StreamExt};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// let guilds = http.get_guilds_iter();
+    /// pin_mut!(guilds);
+    ///
+    /// while let Some(guild) = guilds.next().await {
+    ///     println!("{}", guild?.name);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn get_guilds_iter(&self) -> impl Stream<Item = Result<GuildInfo>> + '_ {
+        let grab_size = 100;
+
+        try_stream! {
+            let mut after = GuildId(0);
+
+            loop {
+                let page = self
+                    .get_guilds(&GuildPagination::After(after), grab_size)
+                    .await?;
+                let done = page.len() < grab_size as usize;
+
+                if let Some(last) = page.last() {
+                    after = last.id;
+                }
+
+                for guild in page {
+                    yield guild;
+                }
+
+                if done {
+                    break;
+                }
+            }
+        }
+    }
+
     /// Gets information about a specific invite.
     pub async fn get_invite(&self, mut code: &str, stats: bool) -> Result<Invite> {
         #[cfg(feature = "utils")]
@@ -1400,7 +2577,7 @@ impl Http {
             })
             .await?;
 
-        let mut v = response.json::<Value>().await?;
+        let mut v = decode::<Value>(response).await?;
 
         if let Some(map) = v.as_object_mut() {
             map.insert(
@@ -1473,19 +2650,56 @@ impl Http {
         .await
     }
 
-    /// Gets the current unresolved incidents from Discord's Status API.
+    /// Gets the [`StageInstance`] running in the given stage channel.
     ///
-    /// Does not require authentication.
-    pub async fn get_unresolved_incidents(&self) -> Result<Vec<Incident>> {
-        let response = self
-            .request(Request {
-                body: None,
-                headers: None,
+    /// [`StageInstance`]: ../../model/channel/struct.StageInstance.html
+    pub async fn get_stage_instance(&self, channel_id: u64) -> Result<StageInstance> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetStageInstance { channel_id },
+        })
+        .await
+    }
+
+    /// Gets a [`Sticker`] by its Id, such as one of Discord's standard
+    /// stickers.
+    ///
+    /// [`Sticker`]: ../../model/sticker/struct.Sticker.html
+    pub async fn get_sticker(&self, sticker_id: u64) -> Result<Sticker> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetSticker { sticker_id },
+        })
+        .await
+    }
+
+    /// Gets a [`GuildTemplate`] by its code.
+    ///
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    pub async fn get_template(&self, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetTemplate { code },
+        })
+        .await
+    }
+
+    /// Gets the current unresolved incidents from Discord's Status API.
+    ///
+    /// Does not require authentication.
+    pub async fn get_unresolved_incidents(&self) -> Result<Vec<Incident>> {
+        let response = self
+            .request(Request {
+                body: None,
+                headers: None,
                 route: RouteInfo::GetUnresolvedIncidents,
             })
             .await?;
 
-        let mut map: BTreeMap<String, Value> = response.json().await?;
+        let mut map: BTreeMap<String, Value> = decode(response).await?;
 
         match map.remove("incidents") {
             Some(v) => serde_json::from_value::<Vec<Incident>>(v).map_err(From::from),
@@ -1505,7 +2719,7 @@ impl Http {
             })
             .await?;
 
-        let mut map: BTreeMap<String, Value> = response.json().await?;
+        let mut map: BTreeMap<String, Value> = decode(response).await?;
 
         match map.remove("scheduled_maintenances") {
             Some(v) => serde_json::from_value::<Vec<Maintenance>>(v).map_err(From::from),
@@ -1605,6 +2819,105 @@ impl Http {
         .await
     }
 
+    /// Retrieves a webhook given its URL, as copied from Discord's client.
+    ///
+    /// This method does _not_ require authentication.
+    ///
+    /// # Examples
+    ///
+    /// Retrieve a webhook by its URL, with a client without a token:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let http = Http::default();
+    /// let url = "https://discord.com/api/webhooks/245037420704169985/ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
+    ///
+    /// let webhook = http.get_webhook_from_url(url)
+    ///     .await
+    ///     .expect("Error getting webhook");
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Url`] if the URL is not a webhook URL.
+    ///
+    /// [`Error::Url`]: ../../enum.Error.html#variant.Url
+    #[cfg(feature = "utils")]
+    pub async fn get_webhook_from_url(&self, url: &str) -> Result<Webhook> {
+        let (webhook_id, token) =
+            crate::utils::parse_webhook(url).ok_or_else(|| Error::Url(url.to_string()))?;
+
+        self.get_webhook_with_token(webhook_id, token).await
+    }
+
+    /// Reads an image into a base64-encoded data URI, as accepted for avatars
+    /// and icons, downloading it first if it is an image URL.
+    ///
+    /// The image's type is guessed from the extension of its filename.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// let avatar = http.image_data("./avatar.png".into()).await?;
+    /// assert!(avatar.starts_with("data:image/png;base64,"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "utils")]
+    pub async fn image_data(&self, image: AttachmentType<'_>) -> Result<String> {
+        let (data, filename) = match image {
+            AttachmentType::Bytes { data, filename } => (data.into_owned(), filename),
+            AttachmentType::File { file, filename } => {
+                let mut buf = Vec::new();
+                file.try_clone()?.read_to_end(&mut buf)?;
+
+                (buf, filename)
+            }
+            AttachmentType::Path(path) => {
+                let filename = path
+                    .file_name()
+                    .map(|filename| filename.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+
+                (std::fs::read(path)?, filename)
+            }
+            AttachmentType::Image(url) => {
+                let url = Url::parse(url).map_err(|_| Error::Url(url.to_string()))?;
+                let filename = url
+                    .path_segments()
+                    .and_then(|mut segments| segments.next_back().map(ToString::to_string))
+                    .unwrap_or_default();
+                let picture = self.client.get(url).send().await?.bytes().await?;
+
+                (picture.to_vec(), filename)
+            }
+            AttachmentType::Reader { reader, filename } => {
+                let mut reader = reader
+                    .take()
+                    .ok_or_else(|| IoError::other("attachment reader was already read"))?;
+                let mut buf = Vec::new();
+                tokio::io::AsyncReadExt::read_to_end(&mut reader, &mut buf).await?;
+
+                (buf, filename)
+            }
+        };
+
+        let ext = std::path::Path::new(&filename)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("");
+        let mime = mime_guess::from_ext(ext).first_or_octet_stream();
+
+        Ok(format!("data:{};base64,{}", mime, base64::encode(&data)))
+    }
+
     /// Kicks a member from a guild.
     pub async fn kick_member(&self, guild_id: u64, user_id: u64) -> Result<()> {
         self.kick_member_with_reason(guild_id, user_id, "").await
@@ -1621,7 +2934,7 @@ impl Http {
             204,
             Request {
                 body: None,
-                headers: None,
+                headers: audit_log_reason_headers(reason)?,
                 route: RouteInfo::KickMember {
                     guild_id,
                     user_id,
@@ -1632,6 +2945,21 @@ impl Http {
         .await
     }
 
+    /// Adds the current user to a [`GuildThread`].
+    ///
+    /// [`GuildThread`]: ../../model/channel/struct.GuildThread.html
+    pub async fn join_thread_channel(&self, channel_id: u64) -> Result<()> {
+        self.wind(
+            204,
+            Request {
+                body: None,
+                headers: None,
+                route: RouteInfo::JoinThread { channel_id },
+            },
+        )
+        .await
+    }
+
     /// Leaves a group DM.
     pub async fn leave_group(&self, group_id: u64) -> Result<Group> {
         self.fire(Request {
@@ -1655,6 +2983,63 @@ impl Http {
         .await
     }
 
+    /// Removes the current user from a [`GuildThread`].
+    ///
+    /// [`GuildThread`]: ../../model/channel/struct.GuildThread.html
+    pub async fn leave_thread_channel(&self, channel_id: u64) -> Result<()> {
+        self.wind(
+            204,
+            Request {
+                body: None,
+                headers: None,
+                route: RouteInfo::LeaveThread { channel_id },
+            },
+        )
+        .await
+    }
+
+    /// Replaces all of the application's global [`ApplicationCommand`]s with
+    /// the array of commands given, deleting any not in it.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn overwrite_global_application_commands(
+        &self,
+        application_id: u64,
+        map: &Value,
+    ) -> Result<Vec<ApplicationCommand>> {
+        let body = map.to_string();
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: None,
+            route: RouteInfo::OverwriteGlobalApplicationCommands { application_id },
+        })
+        .await
+    }
+
+    /// Replaces all of the application's [`ApplicationCommand`]s in a guild
+    /// with the array of commands given, deleting any not in it.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn overwrite_guild_application_commands(
+        &self,
+        application_id: u64,
+        guild_id: u64,
+        map: &Value,
+    ) -> Result<Vec<ApplicationCommand>> {
+        let body = map.to_string();
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: None,
+            route: RouteInfo::OverwriteGuildApplicationCommands {
+                application_id,
+                guild_id,
+            },
+        })
+        .await
+    }
+
     /// Deletes a user from group DM.
     pub async fn remove_group_recipient(&self, group_id: u64, user_id: u64) -> Result<()> {
         self.wind(
@@ -1686,70 +3071,14 @@ impl Http {
     where
         T: Into<AttachmentType<'a>>,
     {
-        let uri = api!("/channels/{}/messages", channel_id);
-        let url = match Url::parse(&uri) {
-            Ok(url) => url,
-            Err(_) => return Err(Error::Url(uri)),
-        };
-
         let mut multipart = reqwest::multipart::Form::new();
         let mut file_num = "0".to_string();
 
         for file in files {
-            match file.into() {
-                AttachmentType::Bytes { data, filename } => {
-                    multipart = multipart.part(
-                        file_num.to_string(),
-                        Part::bytes(data.into_owned()).file_name(filename),
-                    );
-                }
-                AttachmentType::File { file, filename } => {
-                    let mut f = file.try_clone()?;
-                    let mut buf = Vec::new();
-                    let _b = f.read_to_end(&mut buf)?;
-                    let part = Part::bytes(buf).file_name(filename.to_string());
-
-                    // TODO: investigate why this doesn't work
-                    // multipart = multipart
-                    //     .part(file_num.to_string(),
-                    //         Part::reader(file.try_clone()?)
-                    //             .file_name(filename));
-
-                    multipart = multipart.part(file_num.to_string(), part);
-                }
-                AttachmentType::Path(path) => {
-                    let file_name = path
-                        .file_name()
-                        .map(|filename| filename.to_string_lossy().into_owned());
-                    let ext = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
-
-                    let mime = mime_guess::from_ext(ext).first_or_octet_stream();
-                    let mut file = std::fs::File::open(path)?;
-                    let mut buf = Vec::new();
-                    file.read_to_end(&mut buf)?;
-
-                    let mut field = Part::bytes(buf).mime_str(mime.as_ref())?;
-
-                    if let Some(file_name) = file_name {
-                        field = field.file_name(file_name);
-                    }
-
-                    multipart = multipart.part(file_num.to_string(), field);
-                }
-                AttachmentType::Image(url) => {
-                    let url = Url::parse(url).map_err(|_| Error::Url(url.to_string()))?;
-                    let filename = url
-                        .path_segments()
-                        .and_then(|segments| segments.last().map(ToString::to_string))
-                        .ok_or_else(|| Error::Url(url.to_string()))?;
-                    let picture = self.client.get(url).send().await?.bytes().await?;
-                    multipart = multipart.part(
-                        file_num.to_string(),
-                        Part::bytes(Cow::Borrowed(&picture[..]).into_owned())
-                            .file_name(filename.to_string()),
-                    );
-                }
-            }
+            multipart = multipart.part(
+                file_num.to_string(),
+                self.attachment_part(file.into()).await?,
+            );
 
             unsafe {
                 let vec = file_num.as_mut_vec();
@@ -1771,22 +3100,10 @@ impl Http {
         }
 
         let response = self
-            .client
-            .post(url)
-            .header(AUTHORIZATION, HeaderValue::from_str(&self.token)?)
-            .header(USER_AGENT, HeaderValue::from_static(&constants::USER_AGENT))
-            .multipart(multipart)
-            .send()
+            .multipart_request(&api!("/channels/{}/messages", channel_id), multipart)
             .await?;
 
-        if !response.status().is_success() {
-            return Err(HttpError::UnsuccessfulRequest(
-                ErrorResponse::async_from_response(response).await,
-            )
-            .into());
-        }
-
-        response.json().await.map_err(From::from)
+        decode(response).await
     }
 
     /// Sends a message to a channel.
@@ -1819,11 +3136,21 @@ impl Http {
 
     /// Unbans a user from a guild.
     pub async fn remove_ban(&self, guild_id: u64, user_id: u64) -> Result<()> {
+        self.remove_ban_with_reason(guild_id, user_id, "").await
+    }
+
+    /// Unbans a user from a guild with a provided reason for the audit log.
+    pub async fn remove_ban_with_reason(
+        &self,
+        guild_id: u64,
+        user_id: u64,
+        reason: &str,
+    ) -> Result<()> {
         self.wind(
             204,
             Request {
                 body: None,
-                headers: None,
+                headers: audit_log_reason_headers(reason)?,
                 route: RouteInfo::RemoveBan { guild_id, user_id },
             },
         )
@@ -1860,6 +3187,24 @@ impl Http {
         .await
     }
 
+    /// Removes a user from a [`GuildThread`].
+    ///
+    /// [`GuildThread`]: ../../model/channel/struct.GuildThread.html
+    pub async fn remove_thread_channel_member(&self, channel_id: u64, user_id: u64) -> Result<()> {
+        self.wind(
+            204,
+            Request {
+                body: None,
+                headers: None,
+                route: RouteInfo::RemoveThreadMember {
+                    channel_id,
+                    user_id,
+                },
+            },
+        )
+        .await
+    }
+
     /// Starts removing some members from a guild based on the last time they've been online.
     pub async fn start_guild_prune(&self, guild_id: u64, map: &Value) -> Result<GuildPrune> {
         // Note for 0.6.x: turn this into a function parameter.
@@ -1897,6 +3242,63 @@ impl Http {
         .await
     }
 
+    /// Starts a private [`GuildThread`] in a channel, which is not attached to
+    /// a message.
+    ///
+    /// [`GuildThread`]: ../../model/channel/struct.GuildThread.html
+    pub async fn start_private_thread(
+        &self,
+        channel_id: u64,
+        map: &JsonMap,
+    ) -> Result<GuildThread> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::StartPrivateThread { channel_id },
+        })
+        .await
+    }
+
+    /// Starts a public [`GuildThread`] from a message.
+    ///
+    /// [`GuildThread`]: ../../model/channel/struct.GuildThread.html
+    pub async fn start_thread_with_message(
+        &self,
+        channel_id: u64,
+        message_id: u64,
+        map: &JsonMap,
+    ) -> Result<GuildThread> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::StartThreadWithMessage {
+                channel_id,
+                message_id,
+            },
+        })
+        .await
+    }
+
+    /// Updates a guild's [`GuildTemplate`] to match the current state of the
+    /// guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn sync_guild_template(&self, guild_id: u64, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::SyncGuildTemplate { guild_id, code },
+        })
+        .await
+    }
+
     /// Unpins a message from a channel.
     pub async fn unpin_message(&self, channel_id: u64, message_id: u64) -> Result<()> {
         self.wind(
@@ -1966,7 +3368,7 @@ impl Http {
     pub async fn fire<T: DeserializeOwned>(&self, req: Request<'_>) -> Result<T> {
         let response = self.request(req).await?;
 
-        response.json::<T>().await.map_err(From::from)
+        decode(response).await
     }
 
     /// Performs a request, ratelimiting it if necessary.
@@ -2013,9 +3415,36 @@ impl Http {
     /// ```
     ///
     /// [`fire`]: fn.fire.html
-    pub async fn request(&self, req: Request<'_>) -> Result<ReqwestResponse> {
-        let ratelimiting_req = RatelimitedRequest::from(req);
-        let response = self.ratelimiter.perform(ratelimiting_req).await?;
+    pub async fn request(&self, mut req: Request<'_>) -> Result<ReqwestResponse> {
+        let middleware = self.middleware.read().clone();
+
+        for layer in &middleware {
+            layer.before(&mut req).await;
+        }
+
+        let policy = Arc::clone(&self.retry_policy.read());
+        let method = req.route.deconstruct().0;
+        let mut attempt = 0;
+
+        let response = loop {
+            attempt += 1;
+
+            let ratelimiting_req = RatelimitedRequest::from(req.clone());
+            let result = self.ratelimiter.perform(ratelimiting_req).await;
+
+            if !policy.should_retry(attempt, method, &result, ReqwestResponse::status) {
+                break result?;
+            }
+
+            let delay = policy.delay(attempt);
+            debug!("Retrying request (attempt {}) in {:?}", attempt + 1, delay);
+
+            sleep(delay).await;
+        };
+
+        for layer in middleware.iter().rev() {
+            layer.after(&response).await;
+        }
 
         if response.status().is_success() {
             Ok(response)
@@ -2026,6 +3455,107 @@ impl Http {
         }
     }
 
+    /// Reads an attachment into a part of a multipart request, downloading it
+    /// first if it is an image URL.
+    async fn attachment_part(&self, file: AttachmentType<'_>) -> Result<Part> {
+        let part = match file {
+            AttachmentType::Bytes { data, filename } => {
+                Part::bytes(data.into_owned()).file_name(filename)
+            }
+            AttachmentType::File { file, filename } => {
+                let mut f = file.try_clone()?;
+                let mut buf = Vec::new();
+                let _b = f.read_to_end(&mut buf)?;
+
+                // TODO: investigate why `Part::reader(file.try_clone()?)`
+                // doesn't work
+                Part::bytes(buf).file_name(filename.to_string())
+            }
+            AttachmentType::Path(path) => {
+                let file_name = path
+                    .file_name()
+                    .map(|filename| filename.to_string_lossy().into_owned());
+                let ext = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
+
+                let mime = mime_guess::from_ext(ext).first_or_octet_stream();
+                let mut file = std::fs::File::open(path)?;
+                let mut buf = Vec::new();
+                file.read_to_end(&mut buf)?;
+
+                let mut field = Part::bytes(buf).mime_str(mime.as_ref())?;
+
+                if let Some(file_name) = file_name {
+                    field = field.file_name(file_name);
+                }
+
+                field
+            }
+            AttachmentType::Image(url) => {
+                let url = Url::parse(url).map_err(|_| Error::Url(url.to_string()))?;
+                let filename = url
+                    .path_segments()
+                    .and_then(|segments| segments.last().map(ToString::to_string))
+                    .ok_or_else(|| Error::Url(url.to_string()))?;
+                let picture = self.client.get(url).send().await?.bytes().await?;
+
+                Part::bytes(Cow::Borrowed(&picture[..]).into_owned()).file_name(filename)
+            }
+            AttachmentType::Reader { reader, filename } => {
+                let reader = reader
+                    .take()
+                    .ok_or_else(|| IoError::other("attachment reader was already read"))?;
+
+                Part::stream(Body::wrap_stream(ReaderStream::new(reader))).file_name(filename)
+            }
+        };
+
+        Ok(part)
+    }
+
+    /// Performs a multipart request to a path of Discord's API, such as one
+    /// uploading files.
+    ///
+    /// These bypass the ratelimiter, as their bodies can not be retried.
+    async fn multipart_request(
+        &self,
+        path: &str,
+        multipart: reqwest::multipart::Form,
+    ) -> Result<ReqwestResponse> {
+        let uri = match self.ratelimiter.api_base() {
+            Some(base_url) => path.replacen(api!(""), &base_url, 1),
+            None => path.to_string(),
+        };
+        let url = Url::parse(&uri).map_err(|_| Error::Url(uri))?;
+
+        let mut request = self
+            .client
+            .post(url)
+            .header(USER_AGENT, HeaderValue::from_static(&constants::USER_AGENT))
+            .multipart(multipart);
+
+        // Webhooks can be executed without a token.
+        if !self.token.is_empty() {
+            request = request.header(AUTHORIZATION, HeaderValue::from_str(&self.token)?);
+        }
+
+        let response = request.send().await?;
+
+        let middleware = self.middleware.read().clone();
+
+        for layer in middleware.iter().rev() {
+            layer.after(&response).await;
+        }
+
+        if !response.status().is_success() {
+            return Err(HttpError::UnsuccessfulRequest(
+                ErrorResponse::async_from_response(response).await,
+            )
+            .into());
+        }
+
+        Ok(response)
+    }
+
     /// Performs a request and then verifies that the response status code is equal
     /// to the expected value.
     ///
@@ -2047,13 +3577,45 @@ impl Http {
     }
 }
 
+/// Builds the headers recording `reason` in a guild's audit log, or `None` if
+/// the reason is empty.
+///
+/// Discord requires the reason to be URL-encoded, so any non-printable or
+/// non-ASCII bytes are percent-encoded.
+fn audit_log_reason_headers(reason: &str) -> Result<Option<Headers>> {
+    if reason.is_empty() {
+        return Ok(None);
+    }
+
+    let mut encoded = String::with_capacity(reason.len());
+
+    for byte in reason.bytes() {
+        match byte {
+            b' '..=b'~' if byte != b'%' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    let mut headers = Headers::with_capacity(1);
+    headers.insert("X-Audit-Log-Reason", HeaderValue::from_str(&encoded)?);
+
+    Ok(Some(headers))
+}
+
+/// Deserializes the JSON body of a response.
+async fn decode<T: DeserializeOwned>(response: ReqwestResponse) -> Result<T> {
+    let mut bytes = response.bytes().await?.to_vec();
+
+    json::from_slice(&mut bytes)
+}
+
 #[cfg(not(feature = "native_tls_backend"))]
-fn configure_client_backend(builder: ClientBuilder) -> ClientBuilder {
+pub(super) fn configure_client_backend(builder: ClientBuilder) -> ClientBuilder {
     builder.use_rustls_tls()
 }
 
 #[cfg(feature = "native_tls_backend")]
-fn configure_client_backend(builder: ClientBuilder) -> ClientBuilder {
+pub(super) fn configure_client_backend(builder: ClientBuilder) -> ClientBuilder {
     builder.use_native_tls()
 }
 
@@ -2075,6 +3637,1155 @@ impl Default for Http {
             client,
             ratelimiter: Ratelimiter::new(client2, ""),
             token: "".to_string(),
+            middleware: SyncRwLock::new(Vec::new()),
+            retry_policy: SyncRwLock::new(Arc::new(RetryPolicy::default())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::http::mock::{MockResponse, MockServer};
+    use crate::model::id::{ChannelId, GuildId};
+
+    #[tokio::test]
+    async fn test_audit_log_reason_header() {
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::new(204));
+        server.enqueue(MockResponse::new(204));
+
+        let http = server.http("Bot token");
+        GuildId(1)
+            .edit_member(&http, 2, |m| {
+                m.mute(true).audit_log_reason("Spam 100% café")
+            })
+            .await
+            .unwrap();
+        GuildId(1)
+            .edit_member(&http, 2, |m| m.mute(false))
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0]
+                .headers
+                .get("x-audit-log-reason")
+                .map(String::as_str),
+            Some("Spam 100%25 caf%C3%A9"),
+        );
+        assert!(!String::from_utf8_lossy(&requests[0].body).contains("Spam"));
+        assert!(!requests[1].headers.contains_key("x-audit-log-reason"));
+    }
+
+    #[tokio::test]
+    async fn test_get_guilds_iter() {
+        use futures::{pin_mut, StreamExt};
+        use serde_json::json;
+
+        let guild = |id: u64| {
+            json!({
+                "id": id.to_string(),
+                "icon": null,
+                "name": format!("guild {}", id),
+                "owner": false,
+                "permissions": 0,
+            })
+        };
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(
+            200,
+            &json!((1..=100).map(guild).collect::<Vec<_>>()),
+        ));
+        server.enqueue(MockResponse::json(200, &json!([guild(101)])));
+
+        let http = server.http("Bot token");
+        let guilds = http.get_guilds_iter();
+        pin_mut!(guilds);
+
+        let mut ids = Vec::new();
+
+        while let Some(guild) = guilds.next().await {
+            ids.push(guild.unwrap().id.0);
+        }
+
+        assert_eq!(ids, (1..=101).collect::<Vec<_>>());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].path.contains("after=0"));
+        assert!(requests[1].path.contains("after=100"));
+    }
+
+    #[tokio::test]
+    async fn test_bans_iter() {
+        use futures::{pin_mut, StreamExt};
+        use serde_json::json;
+
+        let ban = |id: u64| {
+            json!({
+                "reason": null,
+                "user": {"id": id.to_string(), "username": "user", "discriminator": "0001", "avatar": null},
+            })
+        };
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(
+            200,
+            &json!((1..=1000).map(ban).collect::<Vec<_>>()),
+        ));
+        server.enqueue(MockResponse::json(200, &json!([ban(1001)])));
+
+        let http = server.http("Bot token");
+        let bans = GuildId(1).bans_iter(&http);
+        pin_mut!(bans);
+
+        let mut ids = Vec::new();
+
+        while let Some(ban) = bans.next().await {
+            ids.push(ban.unwrap().user.id.0);
+        }
+
+        assert_eq!(ids, (1..=1001).collect::<Vec<_>>());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].path, "/guilds/1/bans?after=0&limit=1000");
+        assert_eq!(requests[1].path, "/guilds/1/bans?after=1000&limit=1000");
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_reason_on_ban_and_delete() {
+        let server = MockServer::start().await;
+        for _ in 0..4 {
+            server.enqueue(MockResponse::new(204));
+        }
+
+        let http = server.http("Bot token");
+        GuildId(1).ban(&http, 2, &(0, "raiding")).await.unwrap();
+        GuildId(1)
+            .unban_with_reason(&http, 2, "appealed")
+            .await
+            .unwrap();
+        ChannelId(3)
+            .delete_message_with_reason(&http, 4, "off topic")
+            .await
+            .unwrap();
+        ChannelId(3).delete_message(&http, 5).await.unwrap();
+
+        let reasons = server
+            .requests()
+            .into_iter()
+            .map(|request| request.headers.get("x-audit-log-reason").cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reasons,
+            vec![
+                Some("raiding".to_string()),
+                Some("appealed".to_string()),
+                Some("off topic".to_string()),
+                None,
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_interaction_responses() {
+        use crate::model::interactions::Interaction;
+        use serde_json::{json, Value};
+
+        let message = json!({
+            "id": "5",
+            "channel_id": "6",
+            "author": {"id": "2", "username": "bot", "discriminator": "0001", "avatar": null},
+            "content": "Done",
+            "timestamp": "2021-01-01T00:00:00+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+        });
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::new(204));
+        server.enqueue(MockResponse::json(200, &message));
+        server.enqueue(MockResponse::json(200, &message));
+
+        let interaction: Interaction = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 2,
+            "token": "abc",
+            "version": 1,
+        }))
+        .unwrap();
+
+        let http = server.http("Bot token");
+        interaction
+            .create_interaction_response(&http, |r| {
+                r.interaction_response_data(|d| d.content("Working").ephemeral(true))
+            })
+            .await
+            .unwrap();
+        interaction
+            .edit_original_interaction_response(&http, |d| d.content("Done"))
+            .await
+            .unwrap();
+        interaction
+            .create_followup_message(&http, |d| d.content("Done"))
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        let paths = requests
+            .iter()
+            .map(|request| request.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "/interactions/1/abc/callback",
+                "/webhooks/2/abc/messages/@original",
+                "/webhooks/2/abc?wait=true",
+            ],
+        );
+
+        let body: Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(
+            body,
+            json!({"type": 4, "data": {"content": "Working", "flags": 64}}),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_guild_templates() {
+        use serde_json::{json, Value};
+
+        let template = json!({
+            "code": "abc",
+            "name": "Template",
+            "description": null,
+            "usage_count": 0,
+            "creator_id": "2",
+            "creator": {"id": "2", "username": "bot", "discriminator": "0001", "avatar": null},
+            "created_at": "2021-01-01T00:00:00+00:00",
+            "updated_at": "2021-01-01T00:00:00+00:00",
+            "source_guild_id": "1",
+            "serialized_source_guild": {"name": "Guild", "roles": [], "channels": []},
+            "is_dirty": null,
+        });
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(200, &template));
+        server.enqueue(MockResponse::json(200, &json!([template])));
+        server.enqueue(MockResponse::json(200, &template));
+        server.enqueue(MockResponse::json(200, &template));
+
+        let http = server.http("Bot token");
+        let mut created = GuildId(1)
+            .create_template(&http, "Template", None)
+            .await
+            .unwrap();
+        assert_eq!(created.code, "abc");
+        assert_eq!(GuildId(1).templates(&http).await.unwrap().len(), 1);
+        created.sync(&http).await.unwrap();
+        let template = http.get_template("abc").await.unwrap();
+        assert_eq!(template.source_guild_id, GuildId(1));
+
+        let requests = server.requests();
+        let routes = requests
+            .iter()
+            .map(|request| (request.method.as_str(), request.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            routes,
+            vec![
+                ("POST", "/guilds/1/templates"),
+                ("GET", "/guilds/1/templates"),
+                ("PUT", "/guilds/1/templates/abc"),
+                ("GET", "/guilds/templates/abc"),
+            ],
+        );
+
+        let body: Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body, json!({"description": null, "name": "Template"}));
+    }
+
+    #[tokio::test]
+    async fn test_guild_vanity_url() {
+        use serde_json::json;
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(200, &json!({"code": "abc", "uses": 12})));
+        server.enqueue(MockResponse::json(200, &json!({"code": null, "uses": 0})));
+
+        let http = server.http("Bot token");
+        let vanity = GuildId(1).vanity_url(&http).await.unwrap();
+        assert_eq!(vanity.code.as_deref(), Some("abc"));
+        assert_eq!(vanity.uses, 12);
+        assert_eq!(vanity.url().as_deref(), Some("https://discord.gg/abc"));
+
+        let vanity = GuildId(1).vanity_url(&http).await.unwrap();
+        assert_eq!(vanity.url(), None);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/guilds/1/vanity-url");
+    }
+
+    #[tokio::test]
+    async fn test_guild_welcome_screen() {
+        use serde_json::{json, Value};
+
+        let screen = json!({
+            "description": "A place to talk about crabs",
+            "welcome_channels": [
+                {"channel_id": "8", "description": "Read me first", "emoji_id": null, "emoji_name": "📜"},
+            ],
+        });
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(200, &screen));
+        server.enqueue(MockResponse::json(200, &screen));
+
+        let http = server.http("Bot token");
+        let fetched = GuildId(1).welcome_screen(&http).await.unwrap();
+        assert_eq!(fetched.welcome_channels[0].channel_id, ChannelId(8));
+        assert_eq!(
+            fetched.welcome_channels[0].emoji_name.as_deref(),
+            Some("📜")
+        );
+
+        GuildId(1)
+            .edit_welcome_screen(&http, |w| {
+                w.enabled(true)
+                    .add_welcome_channel(|c| c.id(8).description("Read me first").emoji('📜'))
+            })
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        let routes = requests
+            .iter()
+            .map(|request| (request.method.as_str(), request.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            routes,
+            vec![
+                ("GET", "/guilds/1/welcome-screen"),
+                ("PATCH", "/guilds/1/welcome-screen"),
+            ],
+        );
+
+        let body: Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "enabled": true,
+                "welcome_channels": [
+                    {"channel_id": "8", "description": "Read me first", "emoji_id": null, "emoji_name": "📜"},
+                ],
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_webhook_lifecycle() {
+        use crate::http::AttachmentType;
+        use serde_json::{json, Value};
+
+        let webhook = json!({
+            "id": "3",
+            "type": 1,
+            "avatar": null,
+            "channel_id": "2",
+            "guild_id": "1",
+            "name": "hook",
+            "token": "abc",
+        });
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(200, &webhook));
+        server.enqueue(MockResponse::json(200, &webhook));
+        server.enqueue(MockResponse::json(200, &webhook));
+
+        let http = server.http("Bot token");
+        let image = AttachmentType::Bytes {
+            data: b"\x01\x02\x03"[..].into(),
+            filename: "avatar.png".to_string(),
+        };
+        let mut created = ChannelId(2)
+            .create_webhook_with_avatar(&http, "hook", image)
+            .await
+            .unwrap();
+        created.edit_name(&http, "renamed").await.unwrap();
+        created.edit_channel(&http, 4).await.unwrap();
+
+        let requests = server.requests();
+        let routes = requests
+            .iter()
+            .map(|request| (request.method.as_str(), request.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            routes,
+            vec![
+                ("POST", "/channels/2/webhooks"),
+                ("PATCH", "/webhooks/3/abc"),
+                ("PATCH", "/webhooks/3"),
+            ],
+        );
+
+        let body: Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(
+            body,
+            json!({"name": "hook", "avatar": "data:image/png;base64,AQID"}),
+        );
+        let body: Value = serde_json::from_slice(&requests[2].body).unwrap();
+        assert_eq!(body, json!({"channel_id": 4}));
+    }
+
+    #[tokio::test]
+    async fn test_stage_instance_lifecycle() {
+        use crate::model::channel::StagePrivacyLevel;
+        use serde_json::{json, Value};
+
+        let stage = json!({
+            "id": "3",
+            "guild_id": "1",
+            "channel_id": "2",
+            "topic": "town hall",
+            "privacy_level": 2,
+            "discoverable_disabled": false,
+        });
+        let mut edited = stage.clone();
+        edited["topic"] = json!("q&a");
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(200, &stage));
+        server.enqueue(MockResponse::json(200, &edited));
+        server.enqueue(MockResponse::new(204));
+
+        let http = server.http("Bot token");
+        let mut instance = ChannelId(2)
+            .create_stage_instance(&http, "town hall")
+            .await
+            .unwrap();
+        assert_eq!(instance.id.0, 3);
+        assert_eq!(instance.privacy_level, StagePrivacyLevel::GuildOnly);
+        instance.edit(&http, "q&a").await.unwrap();
+        assert_eq!(instance.topic, "q&a");
+        instance.delete(&http).await.unwrap();
+
+        let requests = server.requests();
+        let routes = requests
+            .iter()
+            .map(|request| (request.method.as_str(), request.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            routes,
+            vec![
+                ("POST", "/stage-instances"),
+                ("PATCH", "/stage-instances/2"),
+                ("DELETE", "/stage-instances/2"),
+            ],
+        );
+
+        let body: Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body, json!({"channel_id": 2, "topic": "town hall"}));
+        let body: Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(body, json!({"topic": "q&a"}));
+    }
+
+    #[tokio::test]
+    async fn test_threads() {
+        use crate::model::channel::ChannelType;
+        use serde_json::{json, Value};
+
+        let thread = |id: u64, kind: u64| {
+            json!({
+                "id": id.to_string(),
+                "guild_id": "1",
+                "parent_id": "2",
+                "owner_id": "5",
+                "type": kind,
+                "name": "discussion",
+                "last_message_id": null,
+                "message_count": 0,
+                "member_count": 1,
+                "rate_limit_per_user": 0,
+                "thread_metadata": {
+                    "archived": false,
+                    "auto_archive_duration": 1440,
+                    "archive_timestamp": "2021-01-01T00:00:00+00:00",
+                    "locked": false,
+                },
+            })
+        };
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(200, &thread(4, 11)));
+        server.enqueue(MockResponse::json(200, &thread(6, 12)));
+        server.enqueue(MockResponse::new(204));
+        server.enqueue(MockResponse::new(204));
+        server.enqueue(MockResponse::new(204));
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({"threads": [thread(4, 11)], "members": [], "has_more": true}),
+        ));
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({"threads": [thread(4, 11)], "members": []}),
+        ));
+
+        let http = server.http("Bot token");
+        let public = ChannelId(2)
+            .start_thread_with_message(&http, 3, |t| {
+                t.name("discussion").auto_archive_duration(1440)
+            })
+            .await
+            .unwrap();
+        assert_eq!(public.kind, ChannelType::PublicThread);
+        assert_eq!(public.thread_metadata.auto_archive_duration, 1440);
+        let private = ChannelId(2)
+            .start_private_thread(&http, |t| t.name("secret").invitable(false))
+            .await
+            .unwrap();
+        assert_eq!(private.kind, ChannelType::PrivateThread);
+        public.join(&http).await.unwrap();
+        public.add_member(&http, 7).await.unwrap();
+        public.leave(&http).await.unwrap();
+
+        let before = "2021-02-01T00:00:00+00:00".parse().unwrap();
+        let archived = ChannelId(2)
+            .archived_public_threads(&http, Some(before), Some(10))
+            .await
+            .unwrap();
+        assert!(archived.has_more);
+        let active = GuildId(1).active_threads(&http).await.unwrap();
+        assert_eq!(active.threads[0].id, ChannelId(4));
+        assert!(!active.has_more);
+
+        let requests = server.requests();
+        let routes = requests
+            .iter()
+            .map(|request| (request.method.as_str(), request.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            routes,
+            vec![
+                ("POST", "/channels/2/messages/3/threads"),
+                ("POST", "/channels/2/threads"),
+                ("PUT", "/channels/4/thread-members/@me"),
+                ("PUT", "/channels/4/thread-members/7"),
+                ("DELETE", "/channels/4/thread-members/@me"),
+                (
+                    "GET",
+                    "/channels/2/threads/archived/public?&before=2021-02-01T00:00:00%2B00:00&limit=10",
+                ),
+                ("GET", "/guilds/1/threads/active"),
+            ],
+        );
+
+        let body: Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(
+            body,
+            json!({"name": "discussion", "auto_archive_duration": 1440})
+        );
+        let body: Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(
+            body,
+            json!({"name": "secret", "invitable": false, "type": 12})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stickers() {
+        use crate::http::AttachmentType;
+        use crate::model::sticker::{StickerFormatType, StickerType};
+        use serde_json::{json, Value};
+
+        let sticker = json!({
+            "id": "3",
+            "name": "ferris",
+            "description": "A friendly crab",
+            "tags": "crab",
+            "type": 2,
+            "format_type": 1,
+            "available": true,
+            "guild_id": "1",
+        });
+        let mut message: Value =
+            serde_json::from_str(include_str!("../../tests/resources/message_create_1.json"))
+                .unwrap();
+        message["sticker_items"] = json!([{"id": "3", "name": "ferris", "format_type": 1}]);
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(200, &sticker));
+        server.enqueue(MockResponse::json(200, &sticker));
+        server.enqueue(MockResponse::json(200, &json!([sticker])));
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({
+                "id": "4",
+                "pack_id": "5",
+                "name": "wave",
+                "description": null,
+                "tags": "wave, hello",
+                "type": 1,
+                "format_type": 3,
+                "sort_value": 0,
+            }),
+        ));
+        server.enqueue(MockResponse::new(204));
+        server.enqueue(MockResponse::json(200, &message));
+
+        let http = server.http("Bot token");
+        let image = AttachmentType::Bytes {
+            data: b"png"[..].into(),
+            filename: "ferris.png".to_string(),
+        };
+        let created = GuildId(1)
+            .create_sticker(&http, image, |s| {
+                s.name("ferris").description("A friendly crab").tags("crab")
+            })
+            .await
+            .unwrap();
+        assert_eq!(created.kind, StickerType::Guild);
+        assert_eq!(created.guild_id, Some(GuildId(1)));
+        GuildId(1)
+            .edit_sticker(&http, 3, |s| s.name("crab"))
+            .await
+            .unwrap();
+        assert_eq!(GuildId(1).stickers(&http).await.unwrap().len(), 1);
+
+        let standard = http.get_sticker(4).await.unwrap();
+        assert_eq!(standard.format_type, StickerFormatType::Lottie);
+        assert_eq!(
+            standard.image_url(),
+            "https://cdn.discordapp.com/stickers/4.json"
+        );
+        GuildId(1).delete_sticker(&http, 3).await.unwrap();
+
+        let sent = ChannelId(2)
+            .send_message(&http, |m| m.sticker_ids(vec![3]))
+            .await
+            .unwrap();
+        assert_eq!(
+            sent.sticker_items[0].image_url(),
+            "https://cdn.discordapp.com/stickers/3.png"
+        );
+
+        let requests = server.requests();
+        let routes = requests
+            .iter()
+            .map(|request| (request.method.as_str(), request.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            routes,
+            vec![
+                ("POST", "/guilds/1/stickers"),
+                ("PATCH", "/guilds/1/stickers/3"),
+                ("GET", "/guilds/1/stickers"),
+                ("GET", "/stickers/4"),
+                ("DELETE", "/guilds/1/stickers/3"),
+                ("POST", "/channels/2/messages"),
+            ],
+        );
+
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert!(body.contains("filename=\"ferris.png\""));
+        assert!(body.contains("name=\"tags\"\r\n\r\ncrab"));
+        let body: Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(body, json!({"name": "crab"}));
+        let body: Value = serde_json::from_slice(&requests[5].body).unwrap();
+        assert_eq!(body["sticker_ids"], json!([3]));
+    }
+
+    #[tokio::test]
+    async fn test_guild_integrations() {
+        use crate::model::guild::IntegrationExpireBehaviour;
+        use serde_json::{json, Value};
+
+        let integrations = json!([
+            {
+                "id": "3",
+                "name": "streamer",
+                "type": "twitch",
+                "enabled": true,
+                "syncing": false,
+                "role_id": "4",
+                "enable_emoticons": true,
+                "expire_behavior": 0,
+                "expire_grace_period": 1,
+                "user": {"id": "5", "username": "streamer", "discriminator": "0001", "avatar": null},
+                "account": {"id": "6", "name": "streamer"},
+                "synced_at": "2021-01-01T00:00:00+00:00",
+            },
+            {
+                "id": "7",
+                "name": "bot",
+                "type": "discord",
+                "enabled": true,
+                "account": {"id": "8", "name": "bot"},
+            },
+        ]);
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(200, &integrations));
+        server.enqueue(MockResponse::new(204));
+        server.enqueue(MockResponse::new(204));
+        server.enqueue(MockResponse::new(204));
+
+        let http = server.http("Bot token");
+        let integrations = GuildId(1).integrations(&http).await.unwrap();
+        assert_eq!(integrations.len(), 2);
+        assert_eq!(integrations[0].kind, "twitch");
+        assert_eq!(
+            integrations[0].expire_behaviour,
+            Some(IntegrationExpireBehaviour::RemoveRole),
+        );
+        assert!(integrations[1].role_id.is_none());
+
+        GuildId(1)
+            .edit_integration(&http, 3, |i| {
+                i.expire_behaviour(IntegrationExpireBehaviour::Kick)
+                    .expire_grace_period(7)
+            })
+            .await
+            .unwrap();
+        GuildId(1).start_integration_sync(&http, 3).await.unwrap();
+        GuildId(1).delete_integration(&http, 3).await.unwrap();
+
+        let requests = server.requests();
+        let routes = requests
+            .iter()
+            .map(|request| (request.method.as_str(), request.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            routes,
+            vec![
+                ("GET", "/guilds/1/integrations"),
+                ("PATCH", "/guilds/1/integrations/3"),
+                ("POST", "/guilds/1/integrations/3/sync"),
+                ("DELETE", "/guilds/1/integrations/3"),
+            ],
+        );
+
+        let body: Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(
+            body,
+            json!({"expire_behavior": 1, "expire_grace_period": 7}),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_webhook_without_token() {
+        use crate::http::AttachmentType;
+        use serde_json::json;
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({
+                "id": "1",
+                "type": 1,
+                "guild_id": null,
+                "channel_id": "2",
+                "user": null,
+                "name": "hook",
+                "avatar": null,
+                "token": "abc",
+            }),
+        ));
+        server.enqueue(MockResponse::new(204));
+        server.enqueue(MockResponse::new(204));
+
+        let http = server.http("");
+        let webhook = http
+            .get_webhook_from_url("https://discord.com/api/webhooks/1/abc")
+            .await
+            .unwrap();
+        webhook
+            .execute(&http, false, |w| w.content("hello"))
+            .await
+            .unwrap();
+        webhook
+            .execute(&http, false, |w| {
+                w.content("report").add_file(AttachmentType::Bytes {
+                    data: b"data"[..].into(),
+                    filename: "report.txt".to_string(),
+                })
+            })
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests
+            .iter()
+            .all(|request| !request.headers.contains_key("authorization")));
+        assert_eq!(requests[0].path, "/webhooks/1/abc");
+        assert_eq!(requests[2].path, "/webhooks/1/abc?wait=false");
+
+        let body = String::from_utf8_lossy(&requests[2].body);
+        assert!(body.contains("name=\"payload_json\""));
+        assert!(body.contains("filename=\"report.txt\""));
+        assert!(body.contains("\"content\":\"report\""));
+    }
+
+    #[tokio::test]
+    async fn test_send_files_reader() {
+        use crate::http::{AttachmentReader, AttachmentType};
+
+        let json = include_str!("../../tests/resources/message_create_1.json");
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(
+            200,
+            &serde_json::from_str(json).unwrap(),
+        ));
+        let http = server.http("Bot token");
+
+        let attachment = AttachmentType::Reader {
+            reader: AttachmentReader::new(&b"streamed data"[..]),
+            filename: "stream.txt".to_string(),
+        };
+
+        http.send_files(7, vec![attachment.clone()], serde_json::Map::new())
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert!(body.contains("filename=\"stream.txt\""));
+        assert!(body.contains("streamed data"));
+
+        // The reader is shared between clones and was consumed.
+        assert!(http
+            .send_files(7, vec![attachment], serde_json::Map::new())
+            .await
+            .is_err());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_middleware() {
+        use crate::http::middleware::RequestMiddleware;
+        use crate::http::request::Request;
+        use async_trait::async_trait;
+        use parking_lot::Mutex;
+        use reqwest::header::{HeaderMap, HeaderValue};
+        use reqwest::Response;
+        use std::sync::Arc;
+
+        struct Layer(&'static str, Arc<Mutex<Vec<String>>>);
+
+        #[async_trait]
+        impl RequestMiddleware for Layer {
+            async fn before(&self, request: &mut Request<'_>) {
+                self.1.lock().push(format!("before {}", self.0));
+                request
+                    .headers_mut()
+                    .get_or_insert_with(HeaderMap::new)
+                    .insert("x-layer", HeaderValue::from_static(self.0));
+            }
+
+            async fn after(&self, response: &Response) {
+                self.1
+                    .lock()
+                    .push(format!("after {} {}", self.0, response.status().as_u16()));
+            }
+        }
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::new(204));
+        server.enqueue(MockResponse::new(404));
+        server.enqueue(MockResponse::new(204));
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let http = server.http("Bot token");
+        http.add_middleware(Layer("a", Arc::clone(&log)));
+        http.add_middleware(Layer("b", Arc::clone(&log)));
+
+        ChannelId(1).delete_message(&http, 2).await.unwrap();
+        assert!(ChannelId(1).delete_message(&http, 3).await.is_err());
+
+        http.clear_middleware();
+        ChannelId(1).delete_message(&http, 4).await.unwrap();
+
+        assert_eq!(
+            *log.lock(),
+            vec![
+                "before a",
+                "before b",
+                "after b 204",
+                "after a 204",
+                "before a",
+                "before b",
+                "after b 404",
+                "after a 404",
+            ],
+        );
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].headers.get("x-layer").map(String::as_str),
+            Some("b")
+        );
+        assert!(!requests[2].headers.contains_key("x-layer"));
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy() {
+        use crate::http::{RetryPolicy, StatusCode};
+        use std::time::Duration;
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::new(503));
+        server.enqueue(MockResponse::new(502));
+        server.enqueue(MockResponse::new(204));
+        server.enqueue(MockResponse::new(503));
+
+        let mut policy = RetryPolicy::new();
+        policy
+            .max_attempts(3)
+            .backoff(Duration::from_millis(1), Duration::from_millis(1))
+            .status(StatusCode::BAD_GATEWAY)
+            .status(StatusCode::SERVICE_UNAVAILABLE);
+
+        let http = server.http("Bot token");
+        http.set_retry_policy(policy);
+
+        ChannelId(1).delete_message(&http, 2).await.unwrap();
+        assert_eq!(server.requests().len(), 3);
+
+        assert!(ChannelId(1).say(&http, "hello").await.is_err());
+        assert_eq!(server.requests().len(), 4);
+
+        server.enqueue(MockResponse::new(503));
+        http.set_retry_policy(RetryPolicy::never());
+
+        assert!(ChannelId(1).delete_message(&http, 3).await.is_err());
+        assert_eq!(server.requests().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_get_bot_gateway() {
+        use serde_json::json;
+
+        let server = MockServer::start().await;
+        let gateway = |limit: serde_json::Value| {
+            json!({
+                "url": "wss://gateway.discord.gg",
+                "shards": 16,
+                "session_start_limit": limit,
+            })
+        };
+        server.enqueue(MockResponse::json(
+            200,
+            &gateway(json!({
+                "total": 1000,
+                "remaining": 999,
+                "reset_after": 14400000,
+                "max_concurrency": 16,
+            })),
+        ));
+        server.enqueue(MockResponse::json(
+            200,
+            &gateway(json!({"total": 1000, "remaining": 999, "reset_after": 0})),
+        ));
+
+        let http = server.http("Bot token");
+        let gateway = http.get_bot_gateway().await.unwrap();
+        assert_eq!(gateway.shards, 16);
+        assert_eq!(gateway.session_start_limit.remaining, 999);
+        assert_eq!(gateway.session_start_limit.reset_after, 14400000);
+        assert_eq!(gateway.session_start_limit.max_concurrency, 16);
+
+        let gateway = http.get_bot_gateway().await.unwrap();
+        assert_eq!(gateway.session_start_limit.max_concurrency, 1);
+
+        assert_eq!(server.requests()[0].path, "/gateway/bot");
+    }
+
+    #[tokio::test]
+    async fn test_crosspost_message() {
+        use serde_json::json;
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({
+                "id": "2",
+                "channel_id": "1",
+                "author": {"id": "3", "username": "a", "discriminator": "0001", "avatar": null},
+                "content": "news",
+                "timestamp": "2021-01-01T00:00:00+00:00",
+                "edited_timestamp": null,
+                "tts": false,
+                "mention_everyone": false,
+                "mentions": [],
+                "mention_roles": [],
+                "attachments": [],
+                "embeds": [],
+                "pinned": false,
+                "type": 0,
+                "flags": 1,
+            }),
+        ));
+
+        let http = server.http("Bot token");
+        let message = ChannelId(1).crosspost(&http, 2).await.unwrap();
+        assert_eq!(
+            message.flags,
+            Some(crate::model::channel::MessageFlags::CROSSPOSTED)
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/channels/1/messages/2/crosspost");
+    }
+
+    #[tokio::test]
+    async fn test_follow_news_channel() {
+        use serde_json::json;
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({"channel_id": "1", "webhook_id": "3"}),
+        ));
+
+        let http = server.http("Bot token");
+        let followed = ChannelId(1).follow(&http, 2).await.unwrap();
+        assert_eq!(followed.channel_id, ChannelId(1));
+        assert_eq!(followed.webhook_id.0, 3);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/channels/1/followers");
+
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body, json!({"webhook_channel_id": "2"}));
+    }
+
+    #[tokio::test]
+    async fn test_ban_many() {
+        use crate::model::id::UserId;
+        use futures::StreamExt;
+        use serde_json::json;
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::new(204));
+        server.enqueue(MockResponse::json(
+            403,
+            &json!({"code": 50013, "message": "Missing Permissions"}),
+        ));
+        server.enqueue(MockResponse::new(204));
+
+        let http = server.http("Bot token");
+        let results = GuildId(1)
+            .ban_many(&http, vec![2, 3, 4], 1, "Raid")
+            .collect::<Vec<_>>()
+            .await;
+
+        let users = results.iter().map(|(user, _)| *user).collect::<Vec<_>>();
+        assert_eq!(users, [UserId(2), UserId(3), UserId(4)]);
+        assert!(results[0].1.is_ok());
+        assert!(results[1].1.is_err());
+        assert!(results[2].1.is_ok());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[2].method, "PUT");
+        assert!(requests[2].path.starts_with("/guilds/1/bans/4"));
+
+        let invalid = GuildId(1)
+            .ban_many(&http, vec![2], 8, "")
+            .collect::<Vec<_>>()
+            .await;
+        assert!(invalid[0].1.is_err());
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_download_attachment() {
+        use crate::model::channel::Attachment;
+        use serde_json::json;
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::new(200).body(&b"attached file"[..]));
+        server.enqueue(MockResponse::new(404));
+
+        let attachment: Attachment = serde_json::from_value(json!({
+            "id": "1",
+            "filename": "file.txt",
+            "height": null,
+            "proxy_url": "",
+            "size": 13,
+            "url": format!("{}/attachments/2/1/file.txt", server.url()),
+            "width": null,
+        }))
+        .unwrap();
+
+        let http = server.http("Bot token");
+        let mut reported = Vec::new();
+        let content = attachment
+            .download_with_progress(&http, |downloaded, size| reported.push((downloaded, size)))
+            .await
+            .unwrap();
+
+        assert_eq!(content, b"attached file");
+        assert_eq!(reported.last(), Some(&(13, 13)));
+        assert_eq!(server.requests()[0].path, "/attachments/2/1/file.txt");
+
+        assert!(attachment.download(&http).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout() {
+        use crate::http::{Http, HttpError, HttpTimeouts, RetryPolicy};
+        use crate::Error;
+        use serde_json::json;
+        use std::time::Duration;
+        use tokio::time::sleep;
+
+        let server = MockServer::start().await;
+        server.enqueue(
+            MockResponse::json(200, &json!({"url": "wss://gateway.discord.gg"}))
+                .delay(Duration::from_secs(5)),
+        );
+
+        let mut timeouts = HttpTimeouts::default();
+        timeouts.request = Some(Duration::from_millis(100));
+
+        let mut http = Http::new_with_timeouts("Bot token", timeouts);
+        http.ratelimiter.base_url = Some(server.url());
+        http.set_retry_policy(RetryPolicy::never());
+
+        match http.get_gateway().await {
+            Err(Error::Http(why)) => assert!(matches!(*why, HttpError::Timeout(_))),
+            other => panic!(
+                "expected a timeout, got {:?}",
+                other.map(|gateway| gateway.url)
+            ),
+        }
+
+        // A lost connection is not reported as a timeout.
+        drop(server);
+        sleep(Duration::from_millis(100)).await;
+
+        match http.get_gateway().await {
+            Err(Error::Http(why)) => assert!(matches!(*why, HttpError::Request(_))),
+            other => panic!(
+                "expected a request error, got {:?}",
+                other.map(|gateway| gateway.url)
+            ),
         }
     }
 }
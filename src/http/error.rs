@@ -58,11 +58,20 @@ pub enum Error {
     InvalidHeader(InvalidHeaderValue),
     /// Reqwest's Error contain information on why sending a request failed.
     Request(ReqwestError),
+    /// When Discord did not respond within the timeouts the [`Http`] client
+    /// was configured with.
+    ///
+    /// [`Http`]: ../client/struct.Http.html
+    Timeout(ReqwestError),
 }
 
 impl From<ReqwestError> for Error {
     fn from(error: ReqwestError) -> Error {
-        Error::Request(error)
+        if error.is_timeout() {
+            Error::Timeout(error)
+        } else {
+            Error::Request(error)
+        }
     }
 }
 
@@ -87,6 +96,7 @@ impl Display for Error {
             Error::Url(_) => f.write_str("Provided URL is incorrect."),
             Error::InvalidHeader(_) => f.write_str("Provided value is an invalid header value."),
             Error::Request(_) => f.write_str("Error while sending HTTP request."),
+            Error::Timeout(_) => f.write_str("Timed out waiting for Discord to respond."),
         }
     }
 }
@@ -95,41 +105,59 @@ impl StdError for Error {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match self {
             Error::Url(inner) => Some(inner),
-            Error::Request(inner) => Some(inner),
+            Error::Request(inner) | Error::Timeout(inner) => Some(inner),
             _ => None,
         }
     }
 }
 
-//#[cfg(test)]
-//mod test {
-//    use super::*;
-//    use http_crate::response::Builder;
-//    use reqwest::ResponseBuilderExt;
-//
-//    #[test]
-//    fn test_error_response_into() {
-//        let error = DiscordJsonError {
-//            code: 43121215,
-//            message: String::from("This is a Ferris error"),
-//            non_exhaustive: (),
-//        };
-//
-//        let mut builder = Builder::new();
-//        builder = builder.status(403);
-//        builder = builder.url(String::from("https://ferris.crab").parse().unwrap());
-//        let body_string = serde_json::to_string(&error).unwrap();
-//        let response = builder.body(body_string.into_bytes()).unwrap();
-//
-//        let reqwest_response: reqwest::Response = response.into();
-//        let error_response: ErrorResponse = ErrorResponse::async_from_response(reqwest_response).await;
-//
-//        let known = ErrorResponse {
-//            status_code: reqwest::StatusCode::from_u16(403).unwrap(),
-//            url: String::from("https://ferris.crab").parse().unwrap(),
-//            error,
-//        };
-//
-//        assert_eq!(error_response, known);
-//    }
-//}
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        http::mock::{MockResponse, MockServer},
+        Error as SerenityError,
+    };
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_error_response_into() {
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(
+            403,
+            &json!({"code": 50013, "message": "Missing Permissions"}),
+        ));
+
+        let error = match server.http("Bot token").get_gateway().await {
+            Err(SerenityError::Http(error)) => *error,
+            other => panic!("Expected an HTTP error, got {:?}", other),
+        };
+
+        match error {
+            Error::UnsuccessfulRequest(response) => {
+                assert_eq!(response.status_code, StatusCode::FORBIDDEN);
+                assert_eq!(response.url.path(), "/gateway");
+                assert_eq!(response.error.code, 50013);
+                assert_eq!(response.error.message, "Missing Permissions");
+            }
+            other => panic!("Expected an unsuccessful request, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_response_invalid_json() {
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::new(502).body("Bad Gateway"));
+
+        match server.http("Bot token").get_gateway().await {
+            Err(SerenityError::Http(error)) => match *error {
+                Error::UnsuccessfulRequest(response) => {
+                    assert_eq!(response.status_code, StatusCode::BAD_GATEWAY);
+                    assert_eq!(response.error.code, -1);
+                }
+                other => panic!("Expected an unsuccessful request, got {:?}", other),
+            },
+            other => panic!("Expected an HTTP error, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use super::routing::Route;
+use crate::SyncRwLock;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+// Latencies are recorded in microseconds into buckets which each cover a
+// quarter of a power of two, so that percentiles are accurate to within 25%
+// while taking a fixed amount of memory.
+const SUB_BUCKETS: usize = 4;
+const BUCKETS: usize = SUB_BUCKETS + (64 - 2) * SUB_BUCKETS;
+
+fn bucket(micros: u64) -> usize {
+    if micros < SUB_BUCKETS as u64 {
+        return micros as usize;
+    }
+
+    let exponent = 63 - micros.leading_zeros() as usize;
+    let sub = (micros >> (exponent - 2)) as usize & (SUB_BUCKETS - 1);
+
+    SUB_BUCKETS + (exponent - 2) * SUB_BUCKETS + sub
+}
+
+/// The largest latency, in microseconds, which falls into the bucket.
+fn bucket_max(index: usize) -> u64 {
+    if index < SUB_BUCKETS {
+        return index as u64;
+    }
+
+    let exponent = (index - SUB_BUCKETS) / SUB_BUCKETS + 2;
+    let sub = ((index - SUB_BUCKETS) % SUB_BUCKETS) as u128;
+    let max = ((SUB_BUCKETS as u128 + sub + 1) << (exponent - 2)) - 1;
+
+    max.min(u128::from(u64::MAX)) as u64
+}
+
+/// Accumulated statistics of the requests made to a single [`Route`].
+///
+/// [`Route`]: routing/enum.Route.html
+#[derive(Debug)]
+pub struct RouteMetrics {
+    requests: AtomicU64,
+    errors: AtomicU64,
+    total_micros: AtomicU64,
+    latencies: Vec<AtomicU64>,
+}
+
+impl Default for RouteMetrics {
+    fn default() -> Self {
+        RouteMetrics {
+            requests: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+            total_micros: AtomicU64::new(0),
+            latencies: (0..BUCKETS).map(|_| AtomicU64::new(0)).collect(),
+        }
+    }
+}
+
+impl RouteMetrics {
+    /// Records one request, which took `elapsed` until a response was
+    /// received or sending it failed.
+    pub(crate) fn record(&self, elapsed: Duration, success: bool) {
+        let micros = elapsed.as_micros().min(u128::from(u64::MAX)) as u64;
+
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        self.total_micros.fetch_add(micros, Ordering::Relaxed);
+        self.latencies[bucket(micros)].fetch_add(1, Ordering::Relaxed);
+
+        if !success {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// The number of requests sent, including failed ones.
+    pub fn requests(&self) -> u64 {
+        self.requests.load(Ordering::Relaxed)
+    }
+
+    /// The number of requests which could not be sent, or received an
+    /// unsuccessful response, including ratelimited ones.
+    pub fn errors(&self) -> u64 {
+        self.errors.load(Ordering::Relaxed)
+    }
+
+    /// The average latency of a request, if any were sent.
+    pub fn mean(&self) -> Option<Duration> {
+        match self.requests() {
+            0 => None,
+            count => Some(Duration::from_micros(
+                self.total_micros.load(Ordering::Relaxed) / count,
+            )),
+        }
+    }
+
+    /// The latency which the given percentage of requests did not exceed, if
+    /// any were sent.
+    ///
+    /// The result is an upper bound, accurate to within 25%.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// use serenity::http::routing::Route;
+    ///
+    /// # let http = Http::default();
+    /// if let Some(metrics) = http.metrics().route(Route::Gateway) {
+    ///     println!("p50: {:?}", metrics.percentile(50.0));
+    ///     println!("p99: {:?}", metrics.percentile(99.0));
+    /// }
+    /// ```
+    pub fn percentile(&self, percent: f64) -> Option<Duration> {
+        let counts = self
+            .latencies
+            .iter()
+            .map(|count| count.load(Ordering::Relaxed))
+            .collect::<Vec<_>>();
+        let total = counts.iter().sum::<u64>();
+
+        if total == 0 {
+            return None;
+        }
+
+        let target = ((percent.clamp(0.0, 100.0) / 100.0) * total as f64).ceil() as u64;
+        let mut seen = 0;
+
+        for (index, count) in counts.into_iter().enumerate() {
+            seen += count;
+
+            if seen >= target.max(1) {
+                return Some(Duration::from_micros(bucket_max(index)));
+            }
+        }
+
+        None
+    }
+
+    /// Clears the recorded statistics.
+    pub fn reset(&self) {
+        self.requests.store(0, Ordering::Relaxed);
+        self.errors.store(0, Ordering::Relaxed);
+        self.total_micros.store(0, Ordering::Relaxed);
+
+        for count in &self.latencies {
+            count.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Statistics of the requests made by an [`Http`] client, per [`Route`].
+///
+/// Only requests sent through the [`Ratelimiter`] are recorded, which
+/// excludes requests uploading files. Each attempt at sending a request is
+/// recorded separately, so retried requests are counted more than once.
+///
+/// This can be retrieved via [`Http::metrics`].
+///
+/// [`Http`]: client/struct.Http.html
+/// [`Http::metrics`]: client/struct.Http.html#method.metrics
+/// [`Ratelimiter`]: ratelimiting/struct.Ratelimiter.html
+/// [`Route`]: routing/enum.Route.html
+#[derive(Debug, Default)]
+pub struct HttpMetrics {
+    routes: SyncRwLock<HashMap<Route, Arc<RouteMetrics>>>,
+}
+
+impl HttpMetrics {
+    pub(crate) fn record(&self, route: Route, elapsed: Duration, success: bool) {
+        let existing = self.routes.read().get(&route).cloned();
+        let metrics = match existing {
+            Some(metrics) => metrics,
+            None => Arc::clone(self.routes.write().entry(route).or_default()),
+        };
+
+        metrics.record(elapsed, success);
+    }
+
+    /// Returns the statistics of a route, if any requests were made to it.
+    pub fn route(&self, route: Route) -> Option<Arc<RouteMetrics>> {
+        self.routes.read().get(&route).cloned()
+    }
+
+    /// Returns the statistics of every route requests were made to.
+    pub fn routes(&self) -> HashMap<Route, Arc<RouteMetrics>> {
+        self.routes.read().clone()
+    }
+
+    /// Clears the statistics of every route.
+    pub fn reset(&self) {
+        self.routes.write().clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{bucket, bucket_max, RouteMetrics, BUCKETS};
+    use std::time::Duration;
+
+    #[test]
+    fn test_buckets() {
+        for micros in (0..10_000).chain(vec![u64::MAX / 2, u64::MAX]) {
+            let index = bucket(micros);
+
+            assert!(index < BUCKETS);
+            assert!(bucket_max(index) >= micros);
+            assert!(index == 0 || bucket_max(index - 1) < micros);
+        }
+    }
+
+    #[test]
+    fn test_route_metrics() {
+        let metrics = RouteMetrics::default();
+        assert_eq!(metrics.mean(), None);
+        assert_eq!(metrics.percentile(50.0), None);
+
+        for millis in 1..=100 {
+            metrics.record(Duration::from_millis(millis), millis != 100);
+        }
+
+        assert_eq!(metrics.requests(), 100);
+        assert_eq!(metrics.errors(), 1);
+        assert_eq!(metrics.mean(), Some(Duration::from_micros(50_500)));
+
+        let p50 = metrics.percentile(50.0).unwrap();
+        assert!(p50 >= Duration::from_millis(50) && p50 < Duration::from_millis(63));
+        let p100 = metrics.percentile(100.0).unwrap();
+        assert!(p100 >= Duration::from_millis(100) && p100 < Duration::from_millis(125));
+
+        metrics.reset();
+        assert_eq!(metrics.requests(), 0);
+        assert_eq!(metrics.percentile(99.0), None);
+    }
+}
@@ -0,0 +1,64 @@
+//! Hooks into the requests sent by [`Http`], allowing them to be logged,
+//! instrumented or given extra headers.
+//!
+//! [`Http`]: ../client/struct.Http.html
+
+use super::request::Request;
+use async_trait::async_trait;
+use reqwest::Response;
+
+/// A layer which is run around every request sent by an [`Http`] client.
+///
+/// Middleware is added with [`Http::add_middleware`]. Multiple middlewares
+/// can be stacked: their [`before`] methods are called in the order they were
+/// added, and their [`after`] methods in reverse order.
+///
+/// Both methods do nothing by default, so only the needed one has to be
+/// implemented.
+///
+/// **Note**: Requests uploading files are not built from a [`Request`], so
+/// only [`after`] is called for them. Requests retried after hitting a
+/// ratelimit are only passed through the middleware once.
+///
+/// # Examples
+///
+/// Log the time taken by every request, and add a header to them:
+///
+/// ```rust,no_run
+/// use async_trait::async_trait;
+/// use reqwest::{header::{HeaderMap, HeaderValue}, Response};
+/// use serenity::http::{middleware::RequestMiddleware, request::Request, Http};
+///
+/// struct Logger;
+///
+/// #[async_trait]
+/// impl RequestMiddleware for Logger {
+///     async fn before(&self, request: &mut Request<'_>) {
+///         request
+///             .headers_mut()
+///             .get_or_insert_with(HeaderMap::new)
+///             .insert("X-Bot-Instance", HeaderValue::from_static("1"));
+///     }
+///
+///     async fn after(&self, response: &Response) {
+///         println!("{} {}", response.status(), response.url());
+///     }
+/// }
+///
+/// let http = Http::new_with_token("Bot token");
+/// http.add_middleware(Logger);
+/// ```
+///
+/// [`Http`]: ../client/struct.Http.html
+/// [`Http::add_middleware`]: ../client/struct.Http.html#method.add_middleware
+/// [`Request`]: ../request/struct.Request.html
+/// [`after`]: #method.after
+/// [`before`]: #method.before
+#[async_trait]
+pub trait RequestMiddleware: Send + Sync {
+    /// Called before a request is sent, and may modify it.
+    async fn before(&self, _request: &mut Request<'_>) {}
+
+    /// Called once a response was received, whether or not it was successful.
+    async fn after(&self, _response: &Response) {}
+}
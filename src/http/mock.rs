@@ -0,0 +1,286 @@
+//! A mock Discord API server for testing code which makes HTTP requests.
+//!
+//! The [`MockServer`] listens on a local port and replies to requests with
+//! queued [`MockResponse`]s, in the order they were queued. This allows
+//! simulating Discord's responses, including errors and ratelimits, without
+//! making any requests to Discord.
+//!
+//! This is only available with the `http_test` feature.
+//!
+//! # Examples
+//!
+//! Simulate a ratelimited request which succeeds once retried:
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "http_test")]
+//! # #[tokio::main]
+//! # async fn main() {
+//! use serde_json::json;
+//! use serenity::http::mock::{MockResponse, MockServer};
+//!
+//! let server = MockServer::start().await;
+//! server.enqueue(MockResponse::json(429, &json!({"retry_after": 10})).header("retry-after", "10"));
+//! server.enqueue(MockResponse::json(200, &json!({"url": "wss://gateway.discord.gg"})));
+//!
+//! let http = server.http("Bot token");
+//! let gateway = http.get_gateway().await.unwrap();
+//!
+//! assert_eq!(gateway.url, "wss://gateway.discord.gg");
+//! assert_eq!(server.requests().len(), 2);
+//! # }
+//! #
+//! # #[cfg(not(feature = "http_test"))]
+//! # fn main() { }
+//! ```
+//!
+//! [`MockResponse`]: struct.MockResponse.html
+//! [`MockServer`]: struct.MockServer.html
+
+use super::{oauth2::OAuth2Client, Http};
+use futures::channel::oneshot;
+use hyper::{
+    body::to_bytes,
+    service::{make_service_fn, service_fn},
+    Body, Request as HyperRequest, Response as HyperResponse, Server,
+};
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use std::{
+    collections::{HashMap, VecDeque},
+    convert::Infallible,
+    net::SocketAddr,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::time::sleep;
+
+/// A response which the [`MockServer`] replies to a request with.
+///
+/// [`MockServer`]: struct.MockServer.html
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    delay: Option<Duration>,
+}
+
+impl MockResponse {
+    /// Creates a response with the given status code and an empty body.
+    pub fn new(status: u16) -> Self {
+        MockResponse {
+            status,
+            headers: Vec::new(),
+            body: Vec::new(),
+            delay: None,
+        }
+    }
+
+    /// Creates a response with the given status code and a JSON body.
+    pub fn json(status: u16, body: &Value) -> Self {
+        MockResponse::new(status)
+            .header("content-type", "application/json")
+            .body(body.to_string())
+    }
+
+    /// Sets the body of the response.
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+
+        self
+    }
+
+    /// Adds a header to the response.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+
+        self
+    }
+
+    /// Delays sending the response, simulating a slow response from Discord.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+
+        self
+    }
+
+    /// Adds the headers describing the ratelimit of a route to the response.
+    ///
+    /// `reset_after` is the number of seconds until the ratelimit resets.
+    pub fn ratelimit(self, limit: i64, remaining: i64, reset_after: f64) -> Self {
+        self.header("x-ratelimit-limit", limit.to_string())
+            .header("x-ratelimit-remaining", remaining.to_string())
+            .header("x-ratelimit-reset-after", reset_after.to_string())
+    }
+}
+
+/// A request received by the [`MockServer`].
+///
+/// [`MockServer`]: struct.MockServer.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ReceivedRequest {
+    /// The method of the request, such as `GET`.
+    pub method: String,
+    /// The path and query of the request, relative to the API's base URL.
+    pub path: String,
+    /// The headers of the request, with lowercase names.
+    pub headers: HashMap<String, String>,
+    /// The body of the request.
+    pub body: Vec<u8>,
+}
+
+#[derive(Default)]
+struct State {
+    responses: VecDeque<MockResponse>,
+    requests: Vec<ReceivedRequest>,
+}
+
+/// A local server imitating Discord's API.
+///
+/// Requests are answered with the queued responses in order. If no response
+/// is queued, a `404` is returned.
+///
+/// The server shuts down when dropped.
+///
+/// Refer to the [module-level documentation][`mock`] for an example.
+///
+/// [`mock`]: index.html
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// Starts a server on a free local port.
+    ///
+    /// This must be called from within a Tokio runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no local port could be bound.
+    pub async fn start() -> Self {
+        let state = Arc::new(Mutex::new(State::default()));
+        let service_state = Arc::clone(&state);
+
+        let make_service = make_service_fn(move |_| {
+            let state = Arc::clone(&service_state);
+            let service = service_fn(move |req| respond(Arc::clone(&state), req));
+
+            async move { Ok::<_, Infallible>(service) }
+        });
+
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+        let addr = server.local_addr();
+        let (tx, rx) = oneshot::channel::<()>();
+
+        tokio::spawn(server.with_graceful_shutdown(async {
+            let _ = rx.await;
+        }));
+
+        MockServer {
+            addr,
+            state,
+            shutdown: Some(tx),
+        }
+    }
+
+    /// The base URL of the server, to be used in place of Discord's API URL.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Queues a response to reply to the next unanswered request with.
+    pub fn enqueue(&self, response: MockResponse) {
+        self.state.lock().responses.push_back(response);
+    }
+
+    /// Returns all requests the server has received so far, oldest first.
+    pub fn requests(&self) -> Vec<ReceivedRequest> {
+        self.state.lock().requests.clone()
+    }
+
+    /// Creates an [`Http`] client which sends all of its requests to this
+    /// server.
+    ///
+    /// [`Http`]: ../client/struct.Http.html
+    pub fn http(&self, token: &str) -> Http {
+        let mut http = Http::new_with_token(token);
+        http.ratelimiter.base_url = Some(self.url());
+
+        http
+    }
+
+    /// Creates an [`OAuth2Client`] which sends all of its requests to this
+    /// server.
+    ///
+    /// [`OAuth2Client`]: ../oauth2/struct.OAuth2Client.html
+    pub fn oauth2(&self, client_id: u64, client_secret: &str, redirect_uri: &str) -> OAuth2Client {
+        let mut oauth2 = OAuth2Client::new(client_id, client_secret, redirect_uri);
+        oauth2.base_url = Some(self.url());
+
+        oauth2
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+async fn respond(
+    state: Arc<Mutex<State>>,
+    req: HyperRequest<Body>,
+) -> Result<HyperResponse<Body>, Infallible> {
+    let (parts, body) = req.into_parts();
+    let body = to_bytes(body)
+        .await
+        .map(|body| body.to_vec())
+        .unwrap_or_default();
+    let headers = parts
+        .headers
+        .iter()
+        .filter_map(|(name, value)| {
+            Some((name.as_str().to_owned(), value.to_str().ok()?.to_owned()))
+        })
+        .collect();
+
+    let response = {
+        let mut state = state.lock();
+
+        state.requests.push(ReceivedRequest {
+            method: parts.method.to_string(),
+            path: parts
+                .uri
+                .path_and_query()
+                .map_or_else(String::new, |path| path.as_str().to_owned()),
+            headers,
+            body,
+        });
+
+        state.responses.pop_front().unwrap_or_else(|| {
+            MockResponse::json(
+                404,
+                &json!({"code": 0, "message": "No mock response was queued"}),
+            )
+        })
+    };
+
+    if let Some(delay) = response.delay {
+        sleep(delay).await;
+    }
+
+    let mut builder = HyperResponse::builder().status(response.status);
+
+    for (name, value) in &response.headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+
+    Ok(builder
+        .body(Body::from(response.body))
+        .expect("Invalid mock response"))
+}
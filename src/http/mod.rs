@@ -14,48 +14,66 @@
 //! The former require a [`Client`] to have logged in, while the latter may be
 //! made regardless of any other usage of the library.
 //!
-//! If a request spuriously fails, it will be retried once.
+//! If a request spuriously fails, it will be retried according to the
+//! [`RetryPolicy`] of its [`Http`] client, which retries it once by default.
 //!
 //! Note that you may want to perform requests through a [model]s'
 //! instance methods where possible, as they each offer different
 //! levels of a high-level interface to the HTTP module.
 //!
 //! [`Client`]: ../client/struct.Client.html
+//! [`Http`]: client/struct.Http.html
+//! [`RetryPolicy`]: retry/struct.RetryPolicy.html
 //! [model]: ../model/index.html
 
 pub mod client;
 pub mod error;
+#[cfg(feature = "metrics")]
+mod metrics;
+pub mod middleware;
+#[cfg(any(test, feature = "http_test"))]
+pub mod mock;
+pub mod oauth2;
 pub mod ratelimiting;
 pub mod request;
+pub mod retry;
 pub mod routing;
+mod timeouts;
 
 pub use self::client::*;
 pub use self::error::Error as HttpError;
+#[cfg(feature = "metrics")]
+pub use self::metrics::{HttpMetrics, RouteMetrics};
+pub use self::retry::RetryPolicy;
+pub use self::timeouts::HttpTimeouts;
 pub use reqwest::StatusCode;
 
 use self::request::Request;
 use crate::model::prelude::*;
+use parking_lot::Mutex;
 use reqwest::Method;
 use std::{
     borrow::Cow,
+    fmt,
     fs::File,
     path::{Path, PathBuf},
+    pin::Pin,
 };
+use tokio::io::AsyncRead;
 
-#[cfg(any(feature = "client", feature = "http"))]
+#[cfg(any(feature = "cache", feature = "client", feature = "http"))]
 use std::sync::Arc;
 
 #[cfg(feature = "cache")]
-use crate::cache::CacheRwLock;
+use crate::cache::Cache;
 #[cfg(feature = "client")]
 use crate::client::Context;
-#[cfg(feature = "client")]
 use crate::CacheAndHttp;
 
 /// This trait will be required by functions that need [`Http`] and can
-/// optionally use a [`CacheRwLock`] to potentially avoid REST-requests.
+/// optionally use a [`Cache`] to potentially avoid REST-requests.
 ///
-/// The types [`Context`], [`CacheRwLock`], and [`Http`] implement this trait
+/// The types [`Context`] and [`Http`] implement this trait
 /// and thus passing these to functions expecting `impl CacheHttp` is possible.
 ///
 /// In a situation where you have the `cache`-feature enabled but you do not
@@ -63,16 +81,16 @@ use crate::CacheAndHttp;
 ///
 /// If you are calling a function that expects `impl CacheHttp` as argument
 /// and you wish to utilise the `cache`-feature but you got no access to a
-/// [`Context`], you can pass a tuple of `(CacheRwLock, Http)`.
+/// [`Context`], you can pass a tuple of `(&Arc<Cache>, &Http)`.
 ///
-/// [`CacheRwLock`]: ../cache/struct.CacheRwLock.html
+/// [`Cache`]: ../cache/struct.Cache.html
 /// [`Http`]: client/struct.Http.html
 /// [`Context`]: ../client/struct.Context.html
 pub trait CacheHttp {
     #[cfg(feature = "http")]
     fn http(&self) -> &Http;
     #[cfg(feature = "cache")]
-    fn cache(&self) -> Option<&CacheRwLock> {
+    fn cache(&self) -> Option<&Arc<Cache>> {
         None
     }
 }
@@ -84,7 +102,7 @@ impl CacheHttp for Context {
         &self.http
     }
     #[cfg(feature = "cache")]
-    fn cache(&self) -> Option<&CacheRwLock> {
+    fn cache(&self) -> Option<&Arc<Cache>> {
         Some(&self.cache)
     }
 }
@@ -96,7 +114,7 @@ impl CacheHttp for &Context {
         &self.http
     }
     #[cfg(feature = "cache")]
-    fn cache(&self) -> Option<&CacheRwLock> {
+    fn cache(&self) -> Option<&Arc<Cache>> {
         Some(&self.cache)
     }
 }
@@ -108,7 +126,7 @@ impl CacheHttp for &mut Context {
         &self.http
     }
     #[cfg(feature = "cache")]
-    fn cache(&self) -> Option<&CacheRwLock> {
+    fn cache(&self) -> Option<&Arc<Cache>> {
         Some(&self.cache)
     }
 }
@@ -120,43 +138,40 @@ impl CacheHttp for &&mut Context {
         &self.http
     }
     #[cfg(feature = "cache")]
-    fn cache(&self) -> Option<&CacheRwLock> {
+    fn cache(&self) -> Option<&Arc<Cache>> {
         Some(&self.cache)
     }
 }
 
-#[cfg(feature = "client")]
 impl CacheHttp for CacheAndHttp {
     #[cfg(feature = "http")]
     fn http(&self) -> &Http {
         &self.http
     }
     #[cfg(feature = "cache")]
-    fn cache(&self) -> Option<&CacheRwLock> {
+    fn cache(&self) -> Option<&Arc<Cache>> {
         Some(&self.cache)
     }
 }
 
-#[cfg(feature = "client")]
 impl CacheHttp for &CacheAndHttp {
     #[cfg(feature = "http")]
     fn http(&self) -> &Http {
         &self.http
     }
     #[cfg(feature = "cache")]
-    fn cache(&self) -> Option<&CacheRwLock> {
+    fn cache(&self) -> Option<&Arc<Cache>> {
         Some(&self.cache)
     }
 }
 
-#[cfg(feature = "client")]
 impl CacheHttp for Arc<CacheAndHttp> {
     #[cfg(feature = "http")]
     fn http(&self) -> &Http {
         &self.http
     }
     #[cfg(feature = "cache")]
-    fn cache(&self) -> Option<&CacheRwLock> {
+    fn cache(&self) -> Option<&Arc<Cache>> {
         Some(&self.cache)
     }
 }
@@ -168,14 +183,14 @@ impl CacheHttp for &Arc<CacheAndHttp> {
         &self.http
     }
     #[cfg(feature = "cache")]
-    fn cache(&self) -> Option<&CacheRwLock> {
+    fn cache(&self) -> Option<&Arc<Cache>> {
         Some(&self.cache)
     }
 }
 
 #[cfg(all(feature = "cache", feature = "http"))]
-impl CacheHttp for (&CacheRwLock, &Http) {
-    fn cache(&self) -> Option<&CacheRwLock> {
+impl CacheHttp for (&Arc<Cache>, &Http) {
+    fn cache(&self) -> Option<&Arc<Cache>> {
         Some(&self.0)
     }
     fn http(&self) -> &Http {
@@ -205,14 +220,14 @@ impl CacheHttp for &Arc<Http> {
 }
 
 #[cfg(all(feature = "cache", feature = "http"))]
-impl AsRef<CacheRwLock> for (&CacheRwLock, &Http) {
-    fn as_ref(&self) -> &CacheRwLock {
+impl AsRef<Cache> for (&Arc<Cache>, &Http) {
+    fn as_ref(&self) -> &Cache {
         self.0
     }
 }
 
-#[cfg(feature = "cache")]
-impl AsRef<Http> for (&CacheRwLock, &Http) {
+#[cfg(all(feature = "cache", feature = "http"))]
+impl AsRef<Http> for (&Arc<Cache>, &Http) {
     fn as_ref(&self) -> &Http {
         self.1
     }
@@ -262,6 +277,87 @@ pub enum AttachmentType<'a> {
     Path(&'a Path),
     /// Indicates that the `AttachmentType` is an image URL.
     Image(&'a str),
+    /// Indicates that the `AttachmentType` is streamed from a reader, such as
+    /// a `tokio::fs::File`, rather than being read into memory first.
+    Reader {
+        reader: AttachmentReader,
+        filename: String,
+    },
+}
+
+impl<'a> AttachmentType<'a> {
+    /// Returns the filename the attachment is uploaded with, which embeds can
+    /// reference it by as `attachment://filename`.
+    ///
+    /// Returns `None` if the path or URL does not end in a filename.
+    pub fn filename(&self) -> Option<String> {
+        match self {
+            AttachmentType::Bytes { filename, .. }
+            | AttachmentType::File { filename, .. }
+            | AttachmentType::Reader { filename, .. } => Some(filename.clone()),
+            AttachmentType::Path(path) => path
+                .file_name()
+                .map(|filename| filename.to_string_lossy().into_owned()),
+            AttachmentType::Image(url) => reqwest::Url::parse(url)
+                .ok()?
+                .path_segments()?
+                .next_back()
+                .map(ToString::to_string),
+        }
+    }
+}
+
+/// A reader which an attachment is streamed from when it is uploaded.
+///
+/// Clones of an `AttachmentReader` share the same reader, which can only be
+/// read once: attempting to upload it again returns an [`Error::Io`].
+///
+/// # Examples
+///
+/// Upload a large file without reading it into memory:
+///
+/// ```rust,no_run
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # use serenity::http::Http;
+/// # let http = Http::default();
+/// use serenity::http::{AttachmentReader, AttachmentType};
+/// use serenity::model::id::ChannelId;
+///
+/// let file = tokio::fs::File::open("recording.ogg").await?;
+/// let attachment = AttachmentType::Reader {
+///     reader: AttachmentReader::new(file),
+///     filename: "recording.ogg".to_string(),
+/// };
+///
+/// ChannelId(7).send_files(&http, vec![attachment], |m| m).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Error::Io`]: ../enum.Error.html#variant.Io
+#[derive(Clone)]
+pub struct AttachmentReader(Arc<Mutex<Option<BoxedReader>>>);
+
+type BoxedReader = Pin<Box<dyn AsyncRead + Send>>;
+
+impl AttachmentReader {
+    /// Wraps a reader to be streamed into an upload.
+    pub fn new<R: AsyncRead + Send + 'static>(reader: R) -> Self {
+        AttachmentReader(Arc::new(Mutex::new(Some(Box::pin(reader)))))
+    }
+
+    /// Takes the reader out, if it was not read yet.
+    pub(crate) fn take(&self) -> Option<BoxedReader> {
+        self.0.lock().take()
+    }
+}
+
+impl fmt::Debug for AttachmentReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AttachmentReader")
+            .field("consumed", &self.0.lock().is_none())
+            .finish()
+    }
 }
 
 impl<'a> From<(&'a [u8], &str)> for AttachmentType<'a> {
@@ -318,6 +414,18 @@ pub enum GuildPagination {
     Before(GuildId),
 }
 
+/// Representation of the method of a query to send for the [`get_bans`]
+/// function.
+///
+/// [`get_bans`]: client/struct.Http.html#method.get_bans
+#[non_exhaustive]
+pub enum UserPagination {
+    /// The Id to get the users after.
+    After(UserId),
+    /// The Id to get the users before.
+    Before(UserId),
+}
+
 #[cfg(test)]
 mod test {
     use super::AttachmentType;
@@ -336,4 +444,16 @@ mod test {
             _ => false,
         });
     }
+
+    #[test]
+    fn test_attachment_filename() {
+        assert_eq!(
+            AttachmentType::Path(Path::new("cards/rank.png")).filename(),
+            Some("rank.png".to_string())
+        );
+        assert_eq!(
+            AttachmentType::Image("https://example.com/a/b.gif?size=2").filename(),
+            Some("b.gif".to_string())
+        );
+    }
 }
@@ -0,0 +1,318 @@
+//! A client for the OAuth2 authorization code flow, which lets users log in to
+//! e.g. a bot's web dashboard with their Discord account.
+//!
+//! Unlike [`Http`], the [`OAuth2Client`] acts on behalf of users who
+//! authorized an application, rather than on behalf of a bot user. Requests
+//! made with it are not ratelimited by the library.
+//!
+//! # Examples
+//!
+//! Exchange the code a user was redirected with for an access token, and
+//! fetch the user:
+//!
+//! ```rust,no_run
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! use serenity::http::oauth2::OAuth2Client;
+//!
+//! let oauth2 = OAuth2Client::new(
+//!     381880193251409931,
+//!     "client secret",
+//!     "https://example.com/callback",
+//! );
+//!
+//! // Send the user here to authorize the application.
+//! let url = oauth2.authorize_url(&["identify", "guilds"], Some("random state"));
+//!
+//! // Discord redirects the user to the callback with a `code` to exchange.
+//! let token = oauth2.exchange_code("code").await?;
+//! let user = oauth2.current_user(&token.access_token).await?;
+//!
+//! println!("{} logged in", user.name);
+//!
+//! // Access tokens expire after `token.expires_in` seconds.
+//! let token = oauth2.refresh_token(&token.refresh_token).await?;
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! [`Http`]: ../client/struct.Http.html
+//! [`OAuth2Client`]: struct.OAuth2Client.html
+
+use super::{client::configure_client_backend, routing::Route, HttpError};
+use crate::constants;
+use crate::http::error::ErrorResponse;
+use crate::internal::json;
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+use reqwest::{
+    header::{HeaderValue, USER_AGENT},
+    Client, RequestBuilder, Url,
+};
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+
+/// A token granting access to a user's account, within the scopes the user
+/// authorized.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AccessToken {
+    /// The token used to make requests on behalf of the user.
+    pub access_token: String,
+    /// The type of the token, which is always `Bearer`.
+    pub token_type: String,
+    /// The number of seconds until the access token expires.
+    pub expires_in: u64,
+    /// The token used to get a new access token once this one expires.
+    pub refresh_token: String,
+    /// A space-separated list of the scopes the user authorized.
+    pub scope: String,
+}
+
+impl AccessToken {
+    /// Returns an iterator over the scopes the user authorized.
+    pub fn scopes(&self) -> impl Iterator<Item = &str> {
+        self.scope.split_whitespace()
+    }
+}
+
+/// A client for the OAuth2 authorization code flow of an application.
+///
+/// Refer to the [module-level documentation][`oauth2`] for an example.
+///
+/// [`oauth2`]: index.html
+#[derive(Clone, Debug)]
+pub struct OAuth2Client {
+    client: Arc<Client>,
+    client_id: ApplicationId,
+    client_secret: String,
+    redirect_uri: String,
+    pub(crate) base_url: Option<String>,
+}
+
+impl OAuth2Client {
+    /// Creates a client for the application with the given Id and secret.
+    ///
+    /// The `redirect_uri` must be one of the redirect URIs of the application.
+    pub fn new(
+        client_id: impl Into<ApplicationId>,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> Self {
+        let builder = configure_client_backend(Client::builder());
+        let built = builder.build().expect("Cannot build reqwest::Client");
+
+        Self::new_with_client(Arc::new(built), client_id, client_secret, redirect_uri)
+    }
+
+    /// Creates a client like [`new`], but sending requests through the given
+    /// `reqwest` client.
+    ///
+    /// [`new`]: #method.new
+    pub fn new_with_client(
+        client: Arc<Client>,
+        client_id: impl Into<ApplicationId>,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> Self {
+        OAuth2Client {
+            client,
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            redirect_uri: redirect_uri.into(),
+            base_url: None,
+        }
+    }
+
+    /// Generates the URL to send users to in order to authorize the
+    /// application for the given scopes.
+    ///
+    /// The `state` is passed back with the code once the user was redirected,
+    /// and should be used to prevent cross-site request forgery.
+    pub fn authorize_url(&self, scopes: &[&str], state: Option<&str>) -> String {
+        let mut url = Url::parse(Route::oauth2_authorize()).expect("The URL is valid");
+
+        {
+            let mut query = url.query_pairs_mut();
+            query
+                .append_pair("response_type", "code")
+                .append_pair("client_id", &self.client_id.0.to_string())
+                .append_pair("scope", &scopes.join(" "))
+                .append_pair("redirect_uri", &self.redirect_uri);
+
+            if let Some(state) = state {
+                query.append_pair("state", state);
+            }
+        }
+
+        url.into()
+    }
+
+    /// Exchanges the code a user was redirected with for an access token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`HttpError::UnsuccessfulRequest`] if the code is invalid,
+    /// expired, or was already exchanged.
+    ///
+    /// [`HttpError::UnsuccessfulRequest`]: ../error/enum.Error.html#variant.UnsuccessfulRequest
+    pub async fn exchange_code(&self, code: &str) -> Result<AccessToken> {
+        self.token(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", &self.redirect_uri),
+        ])
+        .await
+    }
+
+    /// Exchanges a refresh token for a new access token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`HttpError::UnsuccessfulRequest`] if the refresh token is
+    /// invalid or was revoked.
+    ///
+    /// [`HttpError::UnsuccessfulRequest`]: ../error/enum.Error.html#variant.UnsuccessfulRequest
+    pub async fn refresh_token(&self, refresh_token: &str) -> Result<AccessToken> {
+        self.token(&[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ])
+        .await
+    }
+
+    /// Gets the user who authorized the application.
+    ///
+    /// **Note**: Requires the `identify` scope.
+    pub async fn current_user(&self, access_token: &str) -> Result<CurrentUser> {
+        let request = self
+            .client
+            .get(self.url(&Route::user("@me")))
+            .bearer_auth(access_token);
+
+        self.send(request).await
+    }
+
+    /// Gets information about the current authorization, such as its scopes
+    /// and the application which was authorized.
+    pub async fn current_authorization(
+        &self,
+        access_token: &str,
+    ) -> Result<CurrentAuthorizationInfo> {
+        let request = self
+            .client
+            .get(self.url(Route::oauth2_authorization_current()))
+            .bearer_auth(access_token);
+
+        self.send(request).await
+    }
+
+    async fn token(&self, params: &[(&str, &str)]) -> Result<AccessToken> {
+        let client_id = self.client_id.0.to_string();
+        let mut form = vec![
+            ("client_id", client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+        ];
+        form.extend_from_slice(params);
+
+        let request = self
+            .client
+            .post(self.url(Route::oauth2_token()))
+            .form(&form);
+
+        self.send(request).await
+    }
+
+    /// Resolves a path of Discord's API, sending it to the base URL instead if
+    /// one is set.
+    fn url(&self, path: &str) -> Url {
+        let uri = match self.base_url {
+            Some(ref base_url) => path.replacen(api!(""), base_url, 1),
+            None => path.to_string(),
+        };
+
+        Url::parse(&uri).expect("The URL is valid")
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        let response = request
+            .header(USER_AGENT, HeaderValue::from_static(constants::USER_AGENT))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(HttpError::UnsuccessfulRequest(
+                ErrorResponse::async_from_response(response).await,
+            )
+            .into());
+        }
+
+        let mut bytes = response.bytes().await?.to_vec();
+
+        json::from_slice(&mut bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::http::mock::{MockResponse, MockServer};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_authorization_code_flow() {
+        let server = MockServer::start().await;
+        let oauth2 = server.oauth2(1, "secret", "https://example.com/callback");
+
+        let token = json!({
+            "access_token": "access",
+            "token_type": "Bearer",
+            "expires_in": 604800,
+            "refresh_token": "refresh",
+            "scope": "identify guilds",
+        });
+        server.enqueue(MockResponse::json(200, &token));
+        server.enqueue(MockResponse::json(200, &token));
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({"id": "2", "username": "a", "discriminator": "0001", "avatar": null,
+                "mfa_enabled": false, "verified": true}),
+        ));
+        server.enqueue(MockResponse::json(400, &json!({"error": "invalid_grant"})));
+
+        let token = oauth2.exchange_code("code").await.unwrap();
+        assert_eq!(token.access_token, "access");
+        assert_eq!(token.scopes().collect::<Vec<_>>(), ["identify", "guilds"]);
+
+        oauth2.refresh_token(&token.refresh_token).await.unwrap();
+
+        let user = oauth2.current_user(&token.access_token).await.unwrap();
+        assert_eq!(user.id.0, 2);
+
+        assert!(oauth2.exchange_code("code").await.is_err());
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/oauth2/token");
+        assert_eq!(
+            String::from_utf8_lossy(&requests[0].body),
+            "client_id=1&client_secret=secret&grant_type=authorization_code&code=code\
+             &redirect_uri=https%3A%2F%2Fexample.com%2Fcallback"
+        );
+        assert_eq!(
+            String::from_utf8_lossy(&requests[1].body),
+            "client_id=1&client_secret=secret&grant_type=refresh_token&refresh_token=refresh"
+        );
+        assert_eq!(requests[2].path, "/users/@me");
+        assert_eq!(requests[2].headers["authorization"], "Bearer access");
+    }
+
+    #[test]
+    fn test_authorize_url() {
+        let oauth2 = super::OAuth2Client::new(1, "secret", "https://example.com/callback");
+
+        assert_eq!(
+            oauth2.authorize_url(&["identify", "guilds"], Some("state")),
+            "https://discordapp.com/api/v6/oauth2/authorize?response_type=code&client_id=1\
+             &scope=identify+guilds&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback&state=state"
+        );
+    }
+}
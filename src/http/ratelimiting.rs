@@ -41,44 +41,111 @@
 
 pub use super::routing::Route;
 
-use super::{HttpError, Request};
+#[cfg(feature = "metrics")]
+use super::HttpMetrics;
+use super::{HttpError, LightMethod, Request};
+use crate::constants;
 use crate::internal::prelude::*;
+use crate::model::Timestamp;
 use crate::SyncRwLock;
 use futures::lock::Mutex;
 use log::debug;
-use reqwest::{header::HeaderMap, Client, Response, StatusCode};
+use reqwest::{
+    header::{HeaderMap, DATE},
+    Client, Response, StatusCode, Url,
+};
 use std::{
     collections::HashMap,
     i64,
+    num::NonZeroU32,
     str::{self, FromStr},
-    sync::Arc,
-    time::Duration,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
     u64,
 };
-use tokio::time::sleep;
+use tokio::{sync::Semaphore, time::sleep};
+
+/// A callback invoked by the [`Ratelimiter`] whenever a request is delayed by
+/// a ratelimit.
+///
+/// [`Ratelimiter`]: struct.Ratelimiter.html
+pub type RatelimitCallback = dyn Fn(&RatelimitInfo) + Send + Sync + 'static;
+
+/// Information about a request being delayed by a ratelimit, passed to the
+/// [`Ratelimiter`]'s callback.
+///
+/// [`Ratelimiter`]: struct.Ratelimiter.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RatelimitInfo {
+    /// The route of the delayed request.
+    pub route: Route,
+    /// How long the request is delayed for.
+    pub delay: Duration,
+    /// Whether the global ratelimit was hit, rather than the route's.
+    pub global: bool,
+    /// Whether the request was delayed pre-emptively, before being sent,
+    /// rather than because Discord responded with a 429.
+    pub preemptive: bool,
+}
 
 /// Ratelimiter for requests to the Discord API.
 ///
-/// This keeps track of ratelimit data for known routes through the
-/// [`Ratelimit`] implementation for each route: how many tickets are
+/// This keeps track of ratelimit data for known buckets through the
+/// [`Ratelimit`] implementation for each bucket: how many tickets are
 /// [`remaining`] until the user needs to wait for the known [`reset`] time, and
 /// the [`limit`] of requests that can be made within that time.
 ///
 /// When no tickets are available for some time, then the thread sleeps until
 /// that time passes. The mechanism is known as "pre-emptive ratelimiting".
 ///
+/// Requests are told apart by their method, the template of their path, and
+/// their major parameter, so endpoints without a [`Route`] of their own are
+/// ratelimited all the same. Each kind of request starts out with a bucket of
+/// its own, and is moved to the bucket named by the `X-RateLimit-Bucket`
+/// header of its responses, which it then shares with all other requests
+/// Discord counts towards the same bucket.
+///
 /// Occasionally for very high traffic bots, a global ratelimit may be reached
 /// which blocks all future requests until the global ratelimit is over,
 /// regardless of route. The value of this global ratelimit is never given
 /// through the API, so it can't be pre-emptively ratelimited. This only affects
-/// the largest of bots.
+/// the largest of bots. Such bots can instead configure an assumed global rate
+/// and a cap on in-flight requests through [`Http::set_global_ratelimit`] and
+/// [`Http::set_max_in_flight`].
+///
+/// [`Http::set_global_ratelimit`]: ../client/struct.Http.html#method.set_global_ratelimit
+/// [`Http::set_max_in_flight`]: ../client/struct.Http.html#method.set_max_in_flight
+/// [`Route`]: ../routing/enum.Route.html
 pub struct Ratelimiter {
     client: Arc<Client>,
     global: Arc<Mutex<()>>,
+    callback: SyncRwLock<Option<Arc<RatelimitCallback>>>,
+    // The assumed number of requests per second allowed globally, and the
+    // requests sent in the current second.
+    global_rate: SyncRwLock<Option<NonZeroU32>>,
+    global_window: Mutex<GlobalWindow>,
+    // Limits the number of requests awaiting a response at once.
+    in_flight: SyncRwLock<Option<Arc<Semaphore>>>,
     // When futures is implemented, make tasks clear out their respective entry
     // when the 'reset' passes.
     routes: Arc<SyncRwLock<HashMap<Route, Arc<Mutex<Ratelimit>>>>>,
+    // The buckets of each kind of request, keyed by its method, path template
+    // and major parameter, see `bucket_key`.
+    keys: SyncRwLock<HashMap<String, Arc<Mutex<Ratelimit>>>>,
+    // The buckets reported by Discord, keyed by their hash and the major
+    // parameter of the requests counting towards them.
+    buckets: SyncRwLock<HashMap<String, Arc<Mutex<Ratelimit>>>>,
     token: String,
+    // The version of Discord's API requests are sent to.
+    api_version: AtomicU8,
+    // Overrides the base URL of Discord's API, used to talk to a mock server.
+    pub(crate) base_url: Option<String>,
+    #[cfg(feature = "metrics")]
+    pub(crate) metrics: HttpMetrics,
 }
 
 impl Ratelimiter {
@@ -95,13 +162,31 @@ impl Ratelimiter {
         Self {
             client,
             global: Default::default(),
+            callback: SyncRwLock::new(None),
+            global_rate: SyncRwLock::new(None),
+            global_window: Mutex::new(GlobalWindow {
+                start: Instant::now(),
+                sent: 0,
+            }),
+            in_flight: SyncRwLock::new(None),
             routes: Default::default(),
+            keys: Default::default(),
+            buckets: Default::default(),
             token,
+            api_version: AtomicU8::new(constants::API_VERSION),
+            base_url: None,
+            #[cfg(feature = "metrics")]
+            metrics: HttpMetrics::default(),
         }
     }
 
-    /// The routes mutex is a HashMap of each [`Route`] and their respective
-    /// ratelimit information.
+    /// The routes mutex is a HashMap of each [`Route`] and the ratelimit
+    /// information of the bucket it last used.
+    ///
+    /// Requests are ratelimited by their method and path rather than by their
+    /// route, so a route covering several methods refers to the bucket of the
+    /// most recent one. Routes which share a bucket share the same ratelimit
+    /// information, so locking one locks all of them.
     ///
     /// See the documentation for [`Ratelimit`] for more information on how the
     /// library handles ratelimiting.
@@ -132,6 +217,179 @@ impl Ratelimiter {
         Arc::clone(&self.routes)
     }
 
+    /// Sets a callback to invoke whenever a request is pre-emptively delayed
+    /// or a 429 is received, replacing any previously set callback.
+    ///
+    /// The callback is invoked before the request is delayed, so it should
+    /// return quickly.
+    ///
+    /// # Examples
+    ///
+    /// Log every time the global ratelimit is hit:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # let http = Http::default();
+    /// http.ratelimiter.set_ratelimit_callback(|info| {
+    ///     if info.global {
+    ///         println!("Globally ratelimited for {:?}", info.delay);
+    ///     }
+    /// });
+    /// ```
+    pub fn set_ratelimit_callback<F>(&self, callback: F)
+    where
+        F: Fn(&RatelimitInfo) + Send + Sync + 'static,
+    {
+        *self.callback.write() = Some(Arc::new(callback));
+    }
+
+    /// Removes the callback set with [`set_ratelimit_callback`], if any.
+    ///
+    /// [`set_ratelimit_callback`]: #method.set_ratelimit_callback
+    pub fn clear_ratelimit_callback(&self) {
+        *self.callback.write() = None;
+    }
+
+    pub(crate) fn set_global_rate(&self, requests_per_second: Option<NonZeroU32>) {
+        *self.global_rate.write() = requests_per_second;
+    }
+
+    pub(crate) fn set_max_in_flight(&self, max: Option<usize>) {
+        *self.in_flight.write() = max.map(|max| Arc::new(Semaphore::new(max)));
+    }
+
+    pub(crate) fn api_version(&self) -> u8 {
+        self.api_version.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_api_version(&self, version: u8) {
+        self.api_version.store(version, Ordering::Relaxed);
+    }
+
+    /// The base URL to send requests to instead of the one the routes are
+    /// built with, if it differs.
+    pub(crate) fn api_base(&self) -> Option<String> {
+        if let Some(ref base_url) = self.base_url {
+            return Some(base_url.clone());
+        }
+
+        match self.api_version() {
+            constants::API_VERSION => None,
+            version => {
+                // Routes are built with the default version, which is swapped
+                // for the configured one.
+                let unversioned = api!("").trim_end_matches(|c: char| c.is_ascii_digit());
+
+                Some(format!("{}{}", unversioned, version))
+            }
+        }
+    }
+
+    /// Waits until a request can be sent without exceeding the configured
+    /// global rate, if any.
+    async fn acquire_global(&self, route: Route, callback: Option<&RatelimitCallback>) {
+        let rate = match *self.global_rate.read() {
+            Some(rate) => rate.get(),
+            None => return,
+        };
+
+        let mut window = self.global_window.lock().await;
+        let now = Instant::now();
+
+        if now.duration_since(window.start) >= Duration::from_secs(1) {
+            window.start = now;
+            window.sent = 0;
+        }
+
+        if window.sent >= rate {
+            let delay = Duration::from_secs(1) - now.duration_since(window.start);
+
+            debug!("Pre-emptive global ratelimit for {:?}", delay);
+
+            if let Some(callback) = callback {
+                callback(&RatelimitInfo {
+                    route,
+                    delay,
+                    global: true,
+                    preemptive: true,
+                });
+            }
+
+            sleep(delay).await;
+
+            window.start = Instant::now();
+            window.sent = 0;
+        }
+
+        window.sent += 1;
+    }
+
+    /// Returns the bucket of a request, looked up by its [`bucket_key`], or
+    /// by its route if its URL could not be parsed.
+    ///
+    /// [`bucket_key`]: fn.bucket_key.html
+    fn bucket(&self, route: Route, key: Option<&str>) -> Arc<Mutex<Ratelimit>> {
+        let key = match key {
+            Some(key) => key,
+            None => return Arc::clone(self.routes.write().entry(route).or_default()),
+        };
+
+        let bucket = Arc::clone(self.keys.write().entry(key.to_string()).or_default());
+
+        if route != Route::None {
+            self.routes.write().insert(route, Arc::clone(&bucket));
+        }
+
+        bucket
+    }
+
+    /// Moves the request to the bucket its response was counted towards, if
+    /// it is not already in it, and returns the bucket.
+    ///
+    /// The first request reporting a bucket brings its ratelimit information
+    /// with it, which all requests moved to the bucket later on share.
+    fn shared_bucket(
+        &self,
+        response: &Response,
+        route: Route,
+        key: Option<&str>,
+        major: Option<&str>,
+        bucket: &Arc<Mutex<Ratelimit>>,
+    ) -> Option<Arc<Mutex<Ratelimit>>> {
+        let hash = response
+            .headers()
+            .get("x-ratelimit-bucket")?
+            .to_str()
+            .ok()?;
+        let shared_key = format!("{}:{}", hash, major.unwrap_or_default());
+
+        let shared = Arc::clone(
+            self.buckets
+                .write()
+                .entry(shared_key)
+                .or_insert_with(|| Arc::clone(bucket)),
+        );
+
+        if Arc::ptr_eq(&shared, bucket) {
+            return None;
+        }
+
+        debug!("Request {:?} shares the ratelimit bucket {}", key, hash);
+
+        if let Some(key) = key {
+            self.keys
+                .write()
+                .insert(key.to_string(), Arc::clone(&shared));
+        }
+
+        if key.is_none() || route != Route::None {
+            self.routes.write().insert(route, Arc::clone(&shared));
+        }
+
+        Some(shared)
+    }
+
     pub async fn perform(&self, req: RatelimitedRequest<'_>) -> Result<Response> {
         let RatelimitedRequest { req } = req;
 
@@ -148,7 +406,10 @@ impl Ratelimiter {
             // amount.
             //
             // This isn't normally important, but might be for ratelimiting.
-            let (_, route, _) = req.route.deconstruct();
+            let (method, route, path) = req.route.deconstruct();
+            let url = Url::parse(&path).ok();
+            let key = url.as_ref().and_then(|url| bucket_key(method, url));
+            let major = url.as_ref().and_then(major_parameter);
 
             // Perform pre-checking here:
             //
@@ -158,12 +419,41 @@ impl Ratelimiter {
             // - get the global rate;
             // - sleep if there is 0 remaining
             // - then, perform the request
-            let bucket = Arc::clone(&self.routes.write().entry(route).or_default());
+            let mut bucket = self.bucket(route, key.as_deref());
+            let callback = self.callback.read().clone();
+
+            bucket
+                .lock()
+                .await
+                .pre_hook(&route, callback.as_deref())
+                .await;
 
-            bucket.lock().await.pre_hook(&route).await;
+            self.acquire_global(route, callback.as_deref()).await;
 
-            let request = req.build(&self.client, &self.token)?;
-            let response = request.send().await?;
+            let base_url = self.api_base();
+            let request =
+                req.build_with_base_url(&self.client, &self.token, base_url.as_deref())?;
+
+            let semaphore = self.in_flight.read().clone();
+            let permit = match &semaphore {
+                Some(semaphore) => semaphore.acquire().await.ok(),
+                None => None,
+            };
+
+            #[cfg(feature = "metrics")]
+            let started = Instant::now();
+
+            let response = request.send().await;
+            drop(permit);
+
+            #[cfg(feature = "metrics")]
+            self.metrics.record(
+                route,
+                started.elapsed(),
+                matches!(&response, Ok(response) if response.status().is_success()),
+            );
+
+            let response = response?;
 
             // Check if the request got ratelimited by checking for status 429,
             // and if so, sleep for the value of the header 'retry-after' -
@@ -178,36 +468,62 @@ impl Ratelimiter {
             // It _may_ be possible for the limit to be raised at any time,
             // so check if it did from the value of the 'x-ratelimit-limit'
             // header. If the limit was 5 and is now 7, add 2 to the 'remaining'
-            if route == Route::None {
-                return Ok(response);
+            let redo = if response.headers().get("x-ratelimit-global").is_some() {
+                let _ = self.global.lock();
+
+                Ok(
+                    if let Some(retry_after) =
+                        parse_header::<u64>(&response.headers(), "retry-after")?
+                    {
+                        debug!("Ratelimited on route {:?} for {:?}ms", route, retry_after);
+
+                        let delay = Duration::from_millis(retry_after);
+
+                        if let Some(callback) = &callback {
+                            callback(&RatelimitInfo {
+                                route,
+                                delay,
+                                global: true,
+                                preemptive: false,
+                            });
+                        }
+
+                        sleep(delay).await;
+
+                        true
+                    } else {
+                        false
+                    },
+                )
             } else {
-                let redo = if response.headers().get("x-ratelimit-global").is_some() {
-                    let _ = self.global.lock();
-
-                    Ok(
-                        if let Some(retry_after) =
-                            parse_header::<u64>(&response.headers(), "retry-after")?
-                        {
-                            debug!("Ratelimited on route {:?} for {:?}ms", route, retry_after);
-                            sleep(Duration::from_millis(retry_after)).await;
-
-                            true
-                        } else {
-                            false
-                        },
-                    )
-                } else {
-                    bucket.lock().await.post_hook(&response, &route).await
-                };
-
-                if !redo.unwrap_or(true) {
-                    return Ok(response);
+                if let Some(shared) =
+                    self.shared_bucket(&response, route, key.as_deref(), major.as_deref(), &bucket)
+                {
+                    bucket = shared;
                 }
+
+                bucket
+                    .lock()
+                    .await
+                    .post_hook(&response, &route, callback.as_deref())
+                    .await
+            };
+
+            if !redo.unwrap_or(true) {
+                return Ok(response);
             }
         }
     }
 }
 
+/// The requests sent within a one second window, for enforcing the configured
+/// global rate.
+#[derive(Debug)]
+struct GlobalWindow {
+    start: Instant,
+    sent: u32,
+}
+
 /// A set of data containing information about the ratelimits for a particular
 /// [`Route`], which is stored in [`Http`].
 ///
@@ -229,15 +545,25 @@ pub struct Ratelimit {
     reset: i64,
     /// The total time in milliseconds when the interval resets.
     reset_after: i64,
+    /// The local time in milliseconds when the interval resets, derived from
+    /// `reset_after` so that it does not depend on the local clock matching
+    /// Discord's.
+    reset_at: Option<i64>,
+    /// The difference in milliseconds between Discord's clock and the local
+    /// clock, as estimated from the `Date` header.
+    offset: i64,
 }
 
 impl Ratelimit {
     #[cfg(feature = "absolute_ratelimits")]
     fn get_delay(&self) -> i64 {
-        use chrono::Utc;
+        let now = Timestamp::now().unix_timestamp_millis();
 
-        let now = Utc::now().timestamp_millis();
-        self.reset - now
+        match self.reset_at {
+            Some(reset_at) => reset_at - now,
+            // Translate Discord's reset time to the local clock.
+            None => self.reset.saturating_sub(self.offset) - now,
+        }
     }
 
     #[cfg(not(feature = "absolute_ratelimits"))]
@@ -245,7 +571,7 @@ impl Ratelimit {
         self.reset_after
     }
 
-    pub async fn pre_hook(&mut self, route: &Route) {
+    pub async fn pre_hook(&mut self, route: &Route, callback: Option<&RatelimitCallback>) {
         if self.limit() == 0 {
             return;
         }
@@ -267,7 +593,18 @@ impl Ratelimit {
                 route, delay,
             );
 
-            sleep(Duration::from_millis(delay)).await;
+            let delay = Duration::from_millis(delay);
+
+            if let Some(callback) = callback {
+                callback(&RatelimitInfo {
+                    route: *route,
+                    delay,
+                    global: false,
+                    preemptive: true,
+                });
+            }
+
+            sleep(delay).await;
 
             return;
         }
@@ -275,7 +612,18 @@ impl Ratelimit {
         self.remaining -= 1;
     }
 
-    pub async fn post_hook(&mut self, response: &Response, route: &Route) -> Result<bool> {
+    pub async fn post_hook(
+        &mut self,
+        response: &Response,
+        route: &Route,
+        callback: Option<&RatelimitCallback>,
+    ) -> Result<bool> {
+        let now = Timestamp::now().unix_timestamp_millis();
+
+        if let Some(offset) = clock_offset(response.headers(), now) {
+            self.offset = offset;
+        }
+
         if let Some(limit) = parse_header(&response.headers(), "x-ratelimit-limit")? {
             self.limit = limit;
         }
@@ -292,13 +640,30 @@ impl Ratelimit {
             parse_header::<f64>(&response.headers(), "x-ratelimit-reset-after")?
         {
             self.reset_after = (reset_after * 1000f64) as i64;
+            self.reset_at = Some(now + self.reset_after);
+        } else {
+            // Fall back to `reset` rather than a deadline from a previous
+            // response.
+            self.reset_at = None;
         }
 
         Ok(if response.status() != StatusCode::TOO_MANY_REQUESTS {
             false
         } else if let Some(retry_after) = parse_header::<u64>(&response.headers(), "retry-after")? {
             debug!("Ratelimited on route {:?} for {:?}ms", route, retry_after);
-            sleep(Duration::from_millis(retry_after as u64)).await;
+
+            let delay = Duration::from_millis(retry_after);
+
+            if let Some(callback) = callback {
+                callback(&RatelimitInfo {
+                    route: *route,
+                    delay,
+                    global: false,
+                    preemptive: false,
+                });
+            }
+
+            sleep(delay).await;
 
             true
         } else {
@@ -319,6 +684,11 @@ impl Ratelimit {
     }
 
     /// The absolute time in milliseconds when the interval resets.
+    ///
+    /// This is according to Discord's clock, which may differ from the local
+    /// clock by [`offset`].
+    ///
+    /// [`offset`]: #method.offset
     #[inline]
     pub fn reset(&self) -> i64 {
         self.reset
@@ -329,6 +699,16 @@ impl Ratelimit {
     pub fn reset_after(&self) -> i64 {
         self.reset_after
     }
+
+    /// The estimated difference in milliseconds between Discord's clock and
+    /// the local clock, positive if the local clock is behind.
+    ///
+    /// This is derived from the `Date` header of responses, which only has a
+    /// precision of one second; smaller differences are reported as `0`.
+    #[inline]
+    pub fn offset(&self) -> i64 {
+        self.offset
+    }
 }
 
 impl Default for Ratelimit {
@@ -338,6 +718,8 @@ impl Default for Ratelimit {
             remaining: i64::MAX,
             reset: i64::MAX,
             reset_after: i64::MAX,
+            reset_at: None,
+            offset: 0,
         }
     }
 }
@@ -374,12 +756,109 @@ fn parse_header<T: FromStr>(headers: &HeaderMap, header: &str) -> Result<Option<
     Ok(Some(num))
 }
 
+/// The major parameter of a request's URL, which Discord ratelimits separately
+/// even within a bucket: the Id of the channel or guild, or the Id and token of
+/// the webhook or interaction the path starts with.
+fn major_parameter(url: &Url) -> Option<String> {
+    let mut segments = url
+        .path_segments()?
+        .skip_while(|segment| *segment == "api" || is_api_version(segment));
+
+    match segments.next()? {
+        "channels" | "guilds" => segments.next().map(ToString::to_string),
+        "webhooks" | "interactions" => {
+            let id = segments.next()?;
+
+            Some(match segments.next() {
+                Some(token) => format!("{}/{}", id, token),
+                None => id.to_string(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// The key a request's bucket is looked up by: its method and path, with the
+/// Ids and emojis other than the major parameter replaced by placeholders, so
+/// that all requests to an endpoint start out in the same bucket.
+fn bucket_key(method: LightMethod, url: &Url) -> Option<String> {
+    let mut segments = url
+        .path_segments()?
+        .skip_while(|segment| *segment == "api" || is_api_version(segment));
+
+    let resource = segments.next()?;
+    let major = match resource {
+        "channels" | "guilds" => 1,
+        "webhooks" | "interactions" => 2,
+        _ => 0,
+    };
+
+    let mut key = format!("{:?} /{}", method, resource);
+    let mut previous = resource;
+
+    for (index, segment) in segments.enumerate() {
+        let segment = if index < major {
+            segment
+        } else if previous == "reactions" {
+            ":emoji"
+        } else if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+            ":id"
+        } else {
+            segment
+        };
+
+        key.push('/');
+        key.push_str(segment);
+        previous = segment;
+    }
+
+    Some(key)
+}
+
+fn is_api_version(segment: &str) -> bool {
+    segment.len() > 1
+        && segment.starts_with('v')
+        && segment[1..].bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Estimates the difference between Discord's clock and the local clock from
+/// the `Date` header, given the local time the response was received at.
+fn clock_offset(headers: &HeaderMap, now: i64) -> Option<i64> {
+    let date = headers.get(DATE)?.to_str().ok()?;
+    let date = Timestamp::parse_rfc2822(date).ok()?.unix_timestamp_millis();
+
+    // The header is truncated to whole seconds, so anything below that is
+    // indistinguishable from latency.
+    let offset = date - now;
+
+    Some(if offset.abs() < 1000 { 0 } else { offset })
+}
+
 #[cfg(test)]
 mod tests {
-    use super::parse_header;
-    use crate::{error::Error, http::HttpError};
-    use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
-    use std::{error::Error as StdError, result::Result as StdResult};
+    use super::{
+        bucket_key, clock_offset, major_parameter, parse_header, LightMethod, Ratelimit,
+        RatelimitInfo, Route,
+    };
+    use crate::{
+        error::Error,
+        http::{
+            mock::{MockResponse, MockServer},
+            Http, HttpError,
+        },
+    };
+    use reqwest::{
+        header::{HeaderMap, HeaderName, HeaderValue},
+        Url,
+    };
+    use serde_json::json;
+    use std::{
+        error::Error as StdError,
+        num::NonZeroU32,
+        result::Result as StdResult,
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    };
 
     type Result<T> = StdResult<T, Box<dyn StdError>>;
 
@@ -458,4 +937,346 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_api_base() {
+        use crate::{constants::API_VERSION, http::Http};
+
+        assert_eq!(
+            api!(""),
+            format!("https://discordapp.com/api/v{}", API_VERSION)
+        );
+
+        let http = Http::default();
+        assert_eq!(http.api_version(), API_VERSION);
+        assert_eq!(http.ratelimiter.api_base(), None);
+
+        http.set_api_version(9);
+        assert_eq!(
+            http.ratelimiter.api_base().as_deref(),
+            Some("https://discordapp.com/api/v9")
+        );
+    }
+
+    #[test]
+    fn test_clock_offset() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(clock_offset(&headers, 0), None);
+
+        headers.insert(
+            HeaderName::from_static("date"),
+            HeaderValue::from_static("Sun, 16 Jun 2019 17:08:00 GMT"),
+        );
+        let date = 1_560_704_880_000;
+
+        // Within the header's precision.
+        assert_eq!(clock_offset(&headers, date + 400), Some(0));
+        // The local clock is ahead by 5 seconds.
+        assert_eq!(clock_offset(&headers, date + 5000), Some(-5000));
+        // The local clock is behind by 3 seconds.
+        assert_eq!(clock_offset(&headers, date - 3000), Some(3000));
+
+        headers.insert(
+            HeaderName::from_static("date"),
+            HeaderValue::from_static("not a date"),
+        );
+        assert_eq!(clock_offset(&headers, date), None);
+    }
+
+    fn gateway() -> MockResponse {
+        MockResponse::json(200, &json!({"url": "wss://gateway.discord.gg"}))
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_ratelimit() {
+        let server = MockServer::start().await;
+        server.enqueue(
+            MockResponse::json(429, &json!({"message": "You are being rate limited."}))
+                .ratelimit(5, 0, 0.01)
+                .header("retry-after", "10"),
+        );
+        server.enqueue(gateway().ratelimit(5, 4, 1.0));
+
+        let http = server.http("Bot token");
+        let gateway = http.get_gateway().await.unwrap();
+
+        assert_eq!(gateway.url, "wss://gateway.discord.gg");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, "GET");
+        assert_eq!(requests[1].path, "/gateway");
+        assert_eq!(requests[1].headers["authorization"], "Bot token");
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_global_ratelimit() {
+        let server = MockServer::start().await;
+        server.enqueue(
+            MockResponse::json(429, &json!({"global": true}))
+                .header("x-ratelimit-global", "true")
+                .header("retry-after", "10"),
+        );
+        server.enqueue(gateway());
+
+        let http = server.http("Bot token");
+
+        assert!(http.get_gateway().await.is_ok());
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_ratelimit_callback() {
+        let server = MockServer::start().await;
+        let http = server.http("Bot token");
+
+        let delays = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&delays);
+        http.ratelimiter.set_ratelimit_callback(move |info| {
+            recorded.lock().unwrap().push(info.clone());
+        });
+
+        server.enqueue(
+            MockResponse::json(429, &json!({"global": true}))
+                .header("x-ratelimit-global", "true")
+                .header("retry-after", "10"),
+        );
+        server.enqueue(gateway().ratelimit(1, 0, 0.05));
+        server.enqueue(gateway());
+        http.get_gateway().await.unwrap();
+        http.get_gateway().await.unwrap();
+
+        let delays = delays.lock().unwrap();
+        assert_eq!(delays.len(), 2);
+        assert_eq!(delays[0].route, Route::Gateway);
+        assert_eq!(delays[0].delay, Duration::from_millis(10));
+        assert!(delays[0].global && !delays[0].preemptive);
+        assert_eq!(delays[1].route, Route::Gateway);
+        assert!(!delays[1].global && delays[1].preemptive);
+    }
+
+    #[tokio::test]
+    async fn test_global_rate() {
+        let server = MockServer::start().await;
+        let http = server.http("Bot token");
+        http.set_global_ratelimit(NonZeroU32::new(2));
+        http.set_max_in_flight(Some(1));
+
+        for _ in 0..3 {
+            server.enqueue(gateway());
+        }
+
+        let start = Instant::now();
+        let (first, second) = tokio::join!(http.get_gateway(), http.get_gateway());
+        assert!(first.is_ok() && second.is_ok());
+        assert!(start.elapsed() < Duration::from_millis(500));
+
+        // The third request in the same second waits for the next one.
+        http.get_gateway().await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(900));
+    }
+
+    #[tokio::test]
+    async fn test_preemptive_ratelimit_per_route() {
+        let server = MockServer::start().await;
+        let http = server.http("Bot token");
+
+        // Exhaust the gateway route's bucket.
+        server.enqueue(gateway().ratelimit(1, 0, 0.3));
+        http.get_gateway().await.unwrap();
+
+        // Other routes are not affected.
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({
+                "url": "wss://gateway.discord.gg",
+                "shards": 1,
+                "session_start_limit": {"remaining": 1000, "reset_after": 0, "total": 1000},
+            }),
+        ));
+        let start = Instant::now();
+        http.get_bot_gateway().await.unwrap();
+        assert!(start.elapsed() < Duration::from_millis(250));
+
+        // The gateway route waits for its bucket to reset.
+        server.enqueue(gateway());
+        let start = Instant::now();
+        http.get_gateway().await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(250));
+    }
+
+    #[tokio::test]
+    async fn test_reset_without_reset_after() {
+        let server = MockServer::start().await;
+        let client = reqwest::Client::new();
+        let mut ratelimit = Ratelimit::default();
+
+        server.enqueue(gateway().ratelimit(5, 4, 1.0));
+        let response = client.get(&server.url()).send().await.unwrap();
+        ratelimit
+            .post_hook(&response, &Route::Gateway, None)
+            .await
+            .unwrap();
+        assert!(ratelimit.reset_at.is_some());
+
+        // A later response without `x-ratelimit-reset-after` uses its own
+        // `x-ratelimit-reset` instead of the previous deadline.
+        server.enqueue(
+            gateway()
+                .header("x-ratelimit-remaining", "3")
+                .header("x-ratelimit-reset", "1470173023.123"),
+        );
+        let response = client.get(&server.url()).send().await.unwrap();
+        ratelimit
+            .post_hook(&response, &Route::Gateway, None)
+            .await
+            .unwrap();
+        assert_eq!(ratelimit.reset_at, None);
+        assert_eq!(ratelimit.reset(), 1_470_173_023_123);
+        assert_eq!(ratelimit.remaining(), 3);
+    }
+
+    #[test]
+    fn test_major_parameter() {
+        let major = |url: &str| major_parameter(&Url::parse(url).unwrap());
+
+        assert_eq!(
+            major("https://discordapp.com/api/v6/channels/1/messages/2").as_deref(),
+            Some("1")
+        );
+        assert_eq!(
+            major("http://127.0.0.1:80/guilds/3/members?limit=1000").as_deref(),
+            Some("3")
+        );
+        assert_eq!(
+            major("https://discordapp.com/api/v6/webhooks/4/token/messages/5").as_deref(),
+            Some("4/token")
+        );
+        assert_eq!(
+            major("https://discordapp.com/api/v6/users/@me/guilds/6"),
+            None
+        );
+        assert_eq!(major("https://discordapp.com/api/v6/gateway"), None);
+    }
+
+    #[test]
+    fn test_bucket_key() {
+        let key = |method, url: &str| bucket_key(method, &Url::parse(url).unwrap());
+
+        assert_eq!(
+            key(
+                LightMethod::Get,
+                "https://discordapp.com/api/v6/channels/1/messages/2"
+            )
+            .as_deref(),
+            Some("Get /channels/1/messages/:id")
+        );
+        assert_eq!(
+            key(
+                LightMethod::Put,
+                "https://discordapp.com/api/v6/channels/1/messages/2/reactions/%F0%9F%91%8D/@me"
+            )
+            .as_deref(),
+            Some("Put /channels/1/messages/:id/reactions/:emoji/@me")
+        );
+        assert_eq!(
+            key(
+                LightMethod::Post,
+                "https://discordapp.com/api/v6/webhooks/3/token/messages/4"
+            )
+            .as_deref(),
+            Some("Post /webhooks/3/token/messages/:id")
+        );
+        assert_eq!(
+            key(
+                LightMethod::Delete,
+                "http://127.0.0.1:80/users/@me/guilds/5"
+            )
+            .as_deref(),
+            Some("Delete /users/@me/guilds/:id")
+        );
+        assert_ne!(
+            key(LightMethod::Get, "http://127.0.0.1:80/guilds/6"),
+            key(LightMethod::Patch, "http://127.0.0.1:80/guilds/6")
+        );
+    }
+
+    fn record_delays(http: &Http) -> Arc<Mutex<Vec<RatelimitInfo>>> {
+        let delays = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&delays);
+        http.ratelimiter.set_ratelimit_callback(move |info| {
+            recorded.lock().unwrap().push(info.clone());
+        });
+
+        delays
+    }
+
+    #[tokio::test]
+    async fn test_shared_bucket() {
+        tokio::time::pause();
+
+        let server = MockServer::start().await;
+        let http = server.http("Bot token");
+        let delays = record_delays(&http);
+        let empty = || MockResponse::json(200, &json!([]));
+
+        // Two routes of a channel report the same bucket, the second one
+        // exhausting it.
+        server.enqueue(
+            empty()
+                .ratelimit(2, 1, 0.3)
+                .header("x-ratelimit-bucket", "shared"),
+        );
+        http.get_pins(1).await.unwrap();
+        server.enqueue(
+            empty()
+                .ratelimit(2, 0, 0.3)
+                .header("x-ratelimit-bucket", "shared"),
+        );
+        http.get_channel_webhooks(1).await.unwrap();
+
+        let routes = http.ratelimiter.routes();
+        assert!(Arc::ptr_eq(
+            &routes.read()[&Route::ChannelsIdPins(1)],
+            &routes.read()[&Route::ChannelsIdWebhooks(1)],
+        ));
+
+        // The bucket is separate for other channels.
+        server.enqueue(empty().header("x-ratelimit-bucket", "shared"));
+        http.get_pins(2).await.unwrap();
+        assert!(delays.lock().unwrap().is_empty());
+
+        // The first route waits for the bucket exhausted by the second.
+        server.enqueue(empty());
+        http.get_pins(1).await.unwrap();
+
+        let delays = delays.lock().unwrap();
+        assert_eq!(delays.len(), 1);
+        assert_eq!(delays[0].route, Route::ChannelsIdPins(1));
+        assert!(!delays[0].global && delays[0].preemptive);
+    }
+
+    #[tokio::test]
+    async fn test_ratelimit_without_route() {
+        tokio::time::pause();
+
+        let server = MockServer::start().await;
+        let http = server.http("Bot token");
+        let delays = record_delays(&http);
+
+        // Endpoints without a route of their own are still ratelimited.
+        server.enqueue(MockResponse::new(204).ratelimit(1, 0, 0.3));
+        server.enqueue(MockResponse::new(204));
+        http.remove_group_recipient(1, 2).await.unwrap();
+        http.remove_group_recipient(1, 2).await.unwrap();
+
+        // The bucket is separate for other groups.
+        server.enqueue(MockResponse::new(204));
+        http.remove_group_recipient(4, 2).await.unwrap();
+
+        let delays = delays.lock().unwrap();
+        assert_eq!(delays.len(), 1);
+        assert_eq!(delays[0].route, Route::None);
+        assert!(delays[0].preemptive);
+    }
 }
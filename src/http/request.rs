@@ -71,6 +71,19 @@ impl<'a> Request<'a> {
         &'a self,
         client: &Client,
         token: &str,
+    ) -> Result<ReqwestRequestBuilder, HttpError> {
+        self.build_with_base_url(client, token, None)
+    }
+
+    /// Builds the request like [`build`], but sends it to `base_url` instead
+    /// of Discord's API if one is given.
+    ///
+    /// [`build`]: #method.build
+    pub(crate) fn build_with_base_url(
+        &'a self,
+        client: &Client,
+        token: &str,
+        base_url: Option<&str>,
     ) -> Result<ReqwestRequestBuilder, HttpError> {
         let Request {
             body,
@@ -78,7 +91,11 @@ impl<'a> Request<'a> {
             route: ref route_info,
         } = *self;
 
-        let (method, _, path) = route_info.deconstruct();
+        let (method, _, mut path) = route_info.deconstruct();
+
+        if let Some(base_url) = base_url {
+            path = path.replacen(api!(""), base_url, 1).into();
+        }
 
         let mut builder = client.request(method.reqwest_method(), Url::parse(&path)?);
 
@@ -91,10 +108,16 @@ impl<'a> Request<'a> {
 
         let mut headers = Headers::with_capacity(4);
         headers.insert(USER_AGENT, HeaderValue::from_static(&constants::USER_AGENT));
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&token).map_err(HttpError::InvalidHeader)?,
-        );
+
+        // Some routes, such as those executing webhooks, are usable without a
+        // token.
+        if !token.is_empty() {
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(&token).map_err(HttpError::InvalidHeader)?,
+            );
+        }
+
         headers.insert(
             CONTENT_LENGTH,
             HeaderValue::from_str(&content_length.to_string())?,
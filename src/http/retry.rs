@@ -0,0 +1,282 @@
+use super::{HttpError, LightMethod};
+use crate::internal::prelude::*;
+use reqwest::StatusCode;
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// Determines whether and when requests which spuriously failed are retried.
+///
+/// By default, a request is only retried if no connection to Discord could
+/// be established, in which case Discord can not have received it. Requests
+/// which timed out or failed otherwise, and responses with one of the
+/// [`statuses`], are only retried for idempotent requests (`GET`, `PUT` and
+/// `DELETE`), unless [`retry_non_idempotent`] is enabled. Each retry waits for
+/// twice as long as the previous one, starting at [`base_delay`].
+///
+/// Ratelimited requests are always retried by the [`Ratelimiter`], regardless
+/// of the policy.
+///
+/// # Examples
+///
+/// Retry requests up to 4 times, also retrying on a 503 or on a 429 from e.g.
+/// a proxy:
+///
+/// ```rust,no_run
+/// use serenity::http::{Http, RetryPolicy, StatusCode};
+///
+/// let mut policy = RetryPolicy::new();
+/// policy
+///     .max_attempts(5)
+///     .status(StatusCode::SERVICE_UNAVAILABLE)
+///     .status(StatusCode::TOO_MANY_REQUESTS);
+///
+/// let http = Http::new_with_token("Bot token");
+/// http.set_retry_policy(policy);
+/// ```
+///
+/// [`Ratelimiter`]: ../ratelimiting/struct.Ratelimiter.html
+/// [`base_delay`]: #structfield.base_delay
+/// [`retry_non_idempotent`]: #structfield.retry_non_idempotent
+/// [`statuses`]: #structfield.statuses
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RetryPolicy {
+    /// The maximum number of times a request is sent, including the first
+    /// attempt.
+    ///
+    /// Defaults to 2, retrying requests once.
+    pub max_attempts: u32,
+    /// How long to wait before the first retry.
+    ///
+    /// Defaults to 500 milliseconds.
+    pub base_delay: Duration,
+    /// The longest to wait before any retry.
+    ///
+    /// Defaults to 10 seconds.
+    pub max_delay: Duration,
+    /// Whether to wait for a random duration between half of and the full
+    /// delay, to avoid many failed requests being retried at once.
+    ///
+    /// Defaults to `true`.
+    pub jitter: bool,
+    /// The response statuses to retry on.
+    ///
+    /// Defaults to none.
+    pub statuses: Vec<StatusCode>,
+    /// Whether to retry requests which could not be sent, or which timed out.
+    ///
+    /// Defaults to `true`.
+    pub retry_io_errors: bool,
+    /// Whether to retry `POST` and `PATCH` requests which may have been
+    /// received by Discord, because they timed out or Discord responded with
+    /// one of the [`statuses`].
+    ///
+    /// **Note**: Retrying such a request may, for example, send a message
+    /// twice.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`statuses`]: #structfield.statuses
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 2,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            statuses: Vec::new(),
+            retry_io_errors: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates the default retry policy.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a policy which never retries requests.
+    pub fn never() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the maximum number of times a request is sent.
+    ///
+    /// Refer to [`max_attempts`] for more information.
+    ///
+    /// [`max_attempts`]: #structfield.max_attempts
+    pub fn max_attempts(&mut self, max_attempts: u32) -> &mut Self {
+        self.max_attempts = max_attempts;
+
+        self
+    }
+
+    /// Sets the delays before retrying, the first one being `base` and the
+    /// others doubling up to `max`.
+    pub fn backoff(&mut self, base: Duration, max: Duration) -> &mut Self {
+        self.base_delay = base;
+        self.max_delay = max;
+
+        self
+    }
+
+    /// Sets whether to randomise delays.
+    ///
+    /// Refer to [`jitter`] for more information.
+    ///
+    /// [`jitter`]: #structfield.jitter
+    pub fn jitter(&mut self, jitter: bool) -> &mut Self {
+        self.jitter = jitter;
+
+        self
+    }
+
+    /// Adds a response status to retry on.
+    pub fn status(&mut self, status: StatusCode) -> &mut Self {
+        if !self.statuses.contains(&status) {
+            self.statuses.push(status);
+        }
+
+        self
+    }
+
+    /// Sets whether to retry requests which could not be sent.
+    ///
+    /// Refer to [`retry_io_errors`] for more information.
+    ///
+    /// [`retry_io_errors`]: #structfield.retry_io_errors
+    pub fn retry_io_errors(&mut self, retry: bool) -> &mut Self {
+        self.retry_io_errors = retry;
+
+        self
+    }
+
+    /// Sets whether to retry `POST` and `PATCH` requests which may have been
+    /// received by Discord.
+    ///
+    /// Refer to [`retry_non_idempotent`] for more information.
+    ///
+    /// [`retry_non_idempotent`]: #structfield.retry_non_idempotent
+    pub fn retry_non_idempotent(&mut self, retry: bool) -> &mut Self {
+        self.retry_non_idempotent = retry;
+
+        self
+    }
+
+    /// Whether a `method` request which was sent `attempt` times and resulted
+    /// in `result` should be sent again.
+    pub(crate) fn should_retry<T>(
+        &self,
+        attempt: u32,
+        method: LightMethod,
+        result: &Result<T>,
+        status: impl FnOnce(&T) -> StatusCode,
+    ) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+
+        let idempotent = match method {
+            LightMethod::Get | LightMethod::Put | LightMethod::Delete => true,
+            LightMethod::Post | LightMethod::Patch => self.retry_non_idempotent,
+        };
+
+        match result {
+            Ok(response) => idempotent && self.statuses.contains(&status(response)),
+            Err(Error::Http(why)) => match **why {
+                HttpError::Request(ref why) if why.is_connect() => self.retry_io_errors,
+                HttpError::Request(ref why) => {
+                    self.retry_io_errors && idempotent && (why.is_timeout() || why.is_request())
+                }
+                HttpError::Timeout(_) => self.retry_io_errors && idempotent,
+                _ => false,
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// The delay before sending a request for the `attempt + 1`th time.
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .checked_mul(1 << exponent)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay));
+
+        if self.jitter {
+            let random = RandomState::new().build_hasher().finish();
+            let half = delay / 2;
+
+            half + half.mul_f64(random as f64 / u64::MAX as f64)
+        } else {
+            delay
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RetryPolicy;
+    use crate::http::LightMethod;
+    use reqwest::StatusCode;
+    use std::time::Duration;
+
+    #[test]
+    fn test_retry_methods() {
+        let unavailable = || Ok(StatusCode::SERVICE_UNAVAILABLE);
+        let mut policy = RetryPolicy::new();
+
+        assert!(!policy.should_retry(1, LightMethod::Get, &unavailable(), |s| *s));
+
+        policy.status(StatusCode::SERVICE_UNAVAILABLE);
+
+        assert!(policy.should_retry(1, LightMethod::Get, &unavailable(), |s| *s));
+        assert!(policy.should_retry(1, LightMethod::Put, &unavailable(), |s| *s));
+        assert!(policy.should_retry(1, LightMethod::Delete, &unavailable(), |s| *s));
+        assert!(!policy.should_retry(1, LightMethod::Post, &unavailable(), |s| *s));
+        assert!(!policy.should_retry(1, LightMethod::Patch, &unavailable(), |s| *s));
+        assert!(!policy.should_retry(2, LightMethod::Get, &unavailable(), |s| *s));
+
+        policy.retry_non_idempotent(true);
+
+        assert!(policy.should_retry(1, LightMethod::Post, &unavailable(), |s| *s));
+        assert!(policy.should_retry(1, LightMethod::Patch, &unavailable(), |s| *s));
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        let mut policy = RetryPolicy::new();
+        policy
+            .jitter(false)
+            .backoff(Duration::from_millis(100), Duration::from_millis(1000));
+
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(4), Duration::from_millis(800));
+        assert_eq!(policy.delay(5), Duration::from_millis(1000));
+        assert_eq!(policy.delay(100), Duration::from_millis(1000));
+
+        policy.jitter(true);
+
+        for attempt in 1..10 {
+            let delay = policy.delay(attempt);
+            policy.jitter(false);
+            let max = policy.delay(attempt);
+            policy.jitter(true);
+
+            assert!(delay >= max / 2 && delay <= max);
+        }
+    }
+}
@@ -12,12 +12,45 @@ use std::{
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Route {
+    /// Route for the `/applications/:application_id/commands` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: ../../model/id/struct.ApplicationId.html
+    ApplicationsIdCommands(u64),
+    /// Route for the `/applications/:application_id/commands/:command_id` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: ../../model/id/struct.ApplicationId.html
+    ApplicationsIdCommandsId(u64),
+    /// Route for the `/applications/:application_id/guilds/:guild_id/commands`
+    /// path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    ApplicationsIdGuildsIdCommands(u64),
+    /// Route for the
+    /// `/applications/:application_id/guilds/:guild_id/commands/:command_id`
+    /// path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    ApplicationsIdGuildsIdCommandsId(u64),
     /// Route for the `/channels/:channel_id` path.
     ///
     /// The data is the relevant [`ChannelId`].
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsId(u64),
+    /// Route for the `/channels/:channel_id/followers` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdFollowers(u64),
     /// Route for the `/channels/:channel_id/invites` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -57,6 +90,13 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsIdMessagesIdAck(u64),
+    /// Route for the `/channels/:channel_id/messages/:message_id/crosspost`
+    /// path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdMessagesIdCrosspost(u64),
     /// Route for the `/channels/:channel_id/messages/:message_id/reactions`
     /// path.
     ///
@@ -72,6 +112,13 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsIdMessagesIdReactionsUserIdType(u64),
+    /// Route for the `/channels/:channel_id/messages/:message_id/threads`
+    /// path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdMessagesIdThreads(u64),
     /// Route for the `/channels/:channel_id/permissions/:target_id` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -90,6 +137,30 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsIdPinsMessageId(u64),
+    /// Route for the `/channels/:channel_id/thread-members/:user_id` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreadMembersUserId(u64),
+    /// Route for the `/channels/:channel_id/threads` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreads(u64),
+    /// Route for the `/channels/:channel_id/threads/archived/private` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreadsArchivedPrivate(u64),
+    /// Route for the `/channels/:channel_id/threads/archived/public` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreadsArchivedPublic(u64),
     /// Route for the `/channels/:channel_id/typing` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -228,6 +299,36 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdRolesId(u64),
+    /// Route for the `/guilds/:guild_id/stickers` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdStickers(u64),
+    /// Route for the `/guilds/:guild_id/stickers/:sticker_id` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdStickersId(u64),
+    /// Route for the `/guilds/:guild_id/templates` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdTemplates(u64),
+    /// Route for the `/guilds/:guild_id/templates/:code` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdTemplatesCode(u64),
+    /// Route for the `/guilds/:guild_id/threads/active` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdThreadsActive(u64),
     /// Route for the `/guilds/:guild_id/vanity-url` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -240,8 +341,33 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdWebhooks(u64),
+    /// Route for the `/guilds/:guild_id/welcome-screen` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdWelcomeScreen(u64),
+    /// Route for the `/guilds/templates/:code` path.
+    GuildsTemplatesCode,
+    /// Route for the `/interactions/:interaction_id/:interaction_token/callback`
+    /// path.
+    ///
+    /// The data is the relevant [`InteractionId`].
+    ///
+    /// [`InteractionId`]: ../../model/id/struct.InteractionId.html
+    InteractionsIdTokenCallback(u64),
     /// Route for the `/invites/:code` path.
     InvitesCode,
+    /// Route for the `/stage-instances` path.
+    StageInstances,
+    /// Route for the `/stage-instances/:channel_id` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    StageInstancesChannelId(u64),
+    /// Route for the `/stickers/:sticker_id` path.
+    StickersId,
     /// Route for the `/users/:user_id` path.
     UsersId,
     /// Route for the `/users/@me` path.
@@ -256,6 +382,13 @@ pub enum Route {
     VoiceRegions,
     /// Route for the `/webhooks/:webhook_id` path.
     WebhooksId(u64),
+    /// Route for the `/webhooks/:application_id/:token/messages/@original`
+    /// path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: ../../model/id/struct.ApplicationId.html
+    WebhooksIdTokenMessagesOriginal(u64),
     /// Route where no ratelimit headers are in place (i.e. user account-only
     /// routes).
     ///
@@ -265,10 +398,43 @@ pub enum Route {
 }
 
 impl Route {
+    pub fn application_command(application_id: u64, command_id: u64) -> String {
+        format!(
+            api!("/applications/{}/commands/{}"),
+            application_id, command_id
+        )
+    }
+
+    pub fn application_commands(application_id: u64) -> String {
+        format!(api!("/applications/{}/commands"), application_id)
+    }
+
+    pub fn application_guild_command(
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    ) -> String {
+        format!(
+            api!("/applications/{}/guilds/{}/commands/{}"),
+            application_id, guild_id, command_id
+        )
+    }
+
+    pub fn application_guild_commands(application_id: u64, guild_id: u64) -> String {
+        format!(
+            api!("/applications/{}/guilds/{}/commands"),
+            application_id, guild_id
+        )
+    }
+
     pub fn channel(channel_id: u64) -> String {
         format!(api!("/channels/{}"), channel_id)
     }
 
+    pub fn channel_followers(channel_id: u64) -> String {
+        format!(api!("/channels/{}/followers"), channel_id)
+    }
+
     pub fn channel_invites(channel_id: u64) -> String {
         format!(api!("/channels/{}/invites"), channel_id)
     }
@@ -277,6 +443,13 @@ impl Route {
         format!(api!("/channels/{}/messages/{}"), channel_id, message_id)
     }
 
+    pub fn channel_message_crosspost(channel_id: u64, message_id: u64) -> String {
+        format!(
+            api!("/channels/{}/messages/{}/crosspost"),
+            channel_id, message_id
+        )
+    }
+
     pub fn channel_message_reaction<D, T>(
         channel_id: u64,
         message_id: u64,
@@ -340,6 +513,45 @@ impl Route {
         format!(api!("/channels/{}/pins"), channel_id)
     }
 
+    pub fn channel_message_threads(channel_id: u64, message_id: u64) -> String {
+        format!(
+            api!("/channels/{}/messages/{}/threads"),
+            channel_id, message_id
+        )
+    }
+
+    pub fn channel_thread_member<D: Display>(channel_id: u64, user_id: D) -> String {
+        format!(api!("/channels/{}/thread-members/{}"), channel_id, user_id)
+    }
+
+    pub fn channel_threads(channel_id: u64) -> String {
+        format!(api!("/channels/{}/threads"), channel_id)
+    }
+
+    pub fn channel_archived_threads(
+        channel_id: u64,
+        visibility: &str,
+        before: Option<&str>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = format!(
+            api!("/channels/{}/threads/archived/{}?"),
+            channel_id, visibility
+        );
+
+        if let Some(before) = before {
+            // Timestamps may contain a `+` in their offset, which would
+            // otherwise be read as a space.
+            let _ = write!(s, "&before={}", before.replace('+', "%2B"));
+        }
+
+        if let Some(limit) = limit {
+            let _ = write!(s, "&limit={}", limit);
+        }
+
+        s
+    }
+
     pub fn channel_typing(channel_id: u64) -> String {
         format!(api!("/channels/{}/typing"), channel_id)
     }
@@ -419,6 +631,25 @@ impl Route {
         format!(api!("/guilds/{}/bans"), guild_id)
     }
 
+    pub fn guild_bans_optioned(
+        guild_id: u64,
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = Route::guild_bans(guild_id);
+        let mut separator = '?';
+
+        for (name, value) in &[("after", after), ("before", before), ("limit", limit)] {
+            if let Some(value) = value {
+                let _ = write!(s, "{}{}={}", separator, name, value);
+                separator = '&';
+            }
+        }
+
+        s
+    }
+
     pub fn guild_channels(guild_id: u64) -> String {
         format!(api!("/guilds/{}/channels"), guild_id)
     }
@@ -503,6 +734,26 @@ impl Route {
         format!(api!("/guilds/{}/roles"), guild_id)
     }
 
+    pub fn guild_sticker(guild_id: u64, sticker_id: u64) -> String {
+        format!(api!("/guilds/{}/stickers/{}"), guild_id, sticker_id)
+    }
+
+    pub fn guild_stickers(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/stickers"), guild_id)
+    }
+
+    pub fn guild_template(guild_id: u64, code: &str) -> String {
+        format!(api!("/guilds/{}/templates/{}"), guild_id, code)
+    }
+
+    pub fn guild_templates(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/templates"), guild_id)
+    }
+
+    pub fn guild_active_threads(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/threads/active"), guild_id)
+    }
+
     pub fn guild_vanity_url(guild_id: u64) -> String {
         format!(api!("/guilds/{}/vanity-url"), guild_id)
     }
@@ -511,10 +762,25 @@ impl Route {
         format!(api!("/guilds/{}/webhooks"), guild_id)
     }
 
+    pub fn guild_welcome_screen(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/welcome-screen"), guild_id)
+    }
+
     pub fn guilds() -> &'static str {
         api!("/guilds")
     }
 
+    pub fn guilds_template(code: &str) -> String {
+        format!(api!("/guilds/templates/{}"), code)
+    }
+
+    pub fn interaction_response<D>(interaction_id: u64, token: D) -> String
+    where
+        D: Display,
+    {
+        format!(api!("/interactions/{}/{}/callback"), interaction_id, token)
+    }
+
     pub fn invite(code: &str) -> String {
         format!(api!("/invites/{}"), code)
     }
@@ -527,10 +793,34 @@ impl Route {
         api!("/oauth2/applications/@me")
     }
 
+    pub fn oauth2_authorization_current() -> &'static str {
+        api!("/oauth2/@me")
+    }
+
+    pub fn oauth2_authorize() -> &'static str {
+        api!("/oauth2/authorize")
+    }
+
+    pub fn oauth2_token() -> &'static str {
+        api!("/oauth2/token")
+    }
+
     pub fn private_channel() -> &'static str {
         api!("/users/@me/channels")
     }
 
+    pub fn stage_instances() -> &'static str {
+        api!("/stage-instances")
+    }
+
+    pub fn stage_instance(channel_id: u64) -> String {
+        format!(api!("/stage-instances/{}"), channel_id)
+    }
+
+    pub fn sticker(sticker_id: u64) -> String {
+        format!(api!("/stickers/{}"), sticker_id)
+    }
+
     pub fn status_incidents_unresolved() -> &'static str {
         status!("/incidents/unresolved.json")
     }
@@ -599,6 +889,16 @@ impl Route {
     {
         format!(api!("/webhooks/{}/{}?wait={}"), webhook_id, token, wait)
     }
+
+    pub fn webhook_original_interaction_response<D>(application_id: u64, token: D) -> String
+    where
+        D: Display,
+    {
+        format!(
+            api!("/webhooks/{}/{}/messages/@original"),
+            application_id, token
+        )
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -613,6 +913,10 @@ pub enum RouteInfo<'a> {
         role_id: u64,
         user_id: u64,
     },
+    AddThreadMember {
+        channel_id: u64,
+        user_id: u64,
+    },
     GuildBanUser {
         guild_id: u64,
         user_id: u64,
@@ -628,11 +932,32 @@ pub enum RouteInfo<'a> {
     CreateEmoji {
         guild_id: u64,
     },
+    CreateFollowupMessage {
+        application_id: u64,
+        interaction_token: &'a str,
+    },
+    CreateGlobalApplicationCommand {
+        application_id: u64,
+    },
     CreateGuild,
+    CreateGuildApplicationCommand {
+        application_id: u64,
+        guild_id: u64,
+    },
+    CreateGuildFromTemplate {
+        code: &'a str,
+    },
     CreateGuildIntegration {
         guild_id: u64,
         integration_id: u64,
     },
+    CreateGuildTemplate {
+        guild_id: u64,
+    },
+    CreateInteractionResponse {
+        interaction_id: u64,
+        interaction_token: &'a str,
+    },
     CreateInvite {
         channel_id: u64,
     },
@@ -652,9 +977,14 @@ pub enum RouteInfo<'a> {
     CreateRole {
         guild_id: u64,
     },
+    CreateStageInstance,
     CreateWebhook {
         channel_id: u64,
     },
+    CrosspostMessage {
+        channel_id: u64,
+        message_id: u64,
+    },
     DeleteChannel {
         channel_id: u64,
     },
@@ -662,13 +992,26 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         emoji_id: u64,
     },
+    DeleteGlobalApplicationCommand {
+        application_id: u64,
+        command_id: u64,
+    },
     DeleteGuild {
         guild_id: u64,
     },
+    DeleteGuildApplicationCommand {
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    },
     DeleteGuildIntegration {
         guild_id: u64,
         integration_id: u64,
     },
+    DeleteGuildTemplate {
+        guild_id: u64,
+        code: &'a str,
+    },
     DeleteInvite {
         code: &'a str,
     },
@@ -697,6 +1040,13 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         role_id: u64,
     },
+    DeleteStageInstance {
+        channel_id: u64,
+    },
+    DeleteSticker {
+        guild_id: u64,
+        sticker_id: u64,
+    },
     DeleteWebhook {
         webhook_id: u64,
     },
@@ -711,15 +1061,35 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         emoji_id: u64,
     },
+    EditGlobalApplicationCommand {
+        application_id: u64,
+        command_id: u64,
+    },
     EditGuild {
         guild_id: u64,
     },
+    EditGuildApplicationCommand {
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    },
     EditGuildChannels {
         guild_id: u64,
     },
     EditGuildEmbed {
         guild_id: u64,
     },
+    EditGuildIntegration {
+        guild_id: u64,
+        integration_id: u64,
+    },
+    EditGuildTemplate {
+        guild_id: u64,
+        code: &'a str,
+    },
+    EditGuildWelcomeScreen {
+        guild_id: u64,
+    },
     EditMember {
         guild_id: u64,
         user_id: u64,
@@ -731,6 +1101,10 @@ pub enum RouteInfo<'a> {
     EditNickname {
         guild_id: u64,
     },
+    EditOriginalInteractionResponse {
+        application_id: u64,
+        interaction_token: &'a str,
+    },
     EditProfile,
     EditRole {
         guild_id: u64,
@@ -739,6 +1113,13 @@ pub enum RouteInfo<'a> {
     EditRolePosition {
         guild_id: u64,
     },
+    EditStageInstance {
+        channel_id: u64,
+    },
+    EditSticker {
+        guild_id: u64,
+        sticker_id: u64,
+    },
     EditWebhook {
         webhook_id: u64,
     },
@@ -751,6 +1132,9 @@ pub enum RouteInfo<'a> {
         wait: bool,
         webhook_id: u64,
     },
+    FollowNewsChannel {
+        channel_id: u64,
+    },
     GetActiveMaintenance,
     GetAuditLogs {
         action_type: Option<u8>,
@@ -759,8 +1143,24 @@ pub enum RouteInfo<'a> {
         limit: Option<u8>,
         user_id: Option<u64>,
     },
+    GetActiveThreads {
+        guild_id: u64,
+    },
+    GetArchivedPrivateThreads {
+        channel_id: u64,
+        before: Option<&'a str>,
+        limit: Option<u64>,
+    },
+    GetArchivedPublicThreads {
+        channel_id: u64,
+        before: Option<&'a str>,
+        limit: Option<u64>,
+    },
     GetBans {
         guild_id: u64,
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: Option<u64>,
     },
     GetBotGateway,
     GetChannel {
@@ -778,9 +1178,25 @@ pub enum RouteInfo<'a> {
     GetCurrentApplicationInfo,
     GetCurrentUser,
     GetGateway,
+    GetGlobalApplicationCommand {
+        application_id: u64,
+        command_id: u64,
+    },
+    GetGlobalApplicationCommands {
+        application_id: u64,
+    },
     GetGuild {
         guild_id: u64,
     },
+    GetGuildApplicationCommand {
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    },
+    GetGuildApplicationCommands {
+        application_id: u64,
+        guild_id: u64,
+    },
     GetGuildEmbed {
         guild_id: u64,
     },
@@ -805,12 +1221,25 @@ pub enum RouteInfo<'a> {
     GetGuildRoles {
         guild_id: u64,
     },
+    GetGuildSticker {
+        guild_id: u64,
+        sticker_id: u64,
+    },
+    GetGuildStickers {
+        guild_id: u64,
+    },
+    GetGuildTemplates {
+        guild_id: u64,
+    },
     GetGuildVanityUrl {
         guild_id: u64,
     },
     GetGuildWebhooks {
         guild_id: u64,
     },
+    GetGuildWelcomeScreen {
+        guild_id: u64,
+    },
     GetGuilds {
         after: Option<u64>,
         before: Option<u64>,
@@ -842,6 +1271,15 @@ pub enum RouteInfo<'a> {
         message_id: u64,
         reaction: String,
     },
+    GetStageInstance {
+        channel_id: u64,
+    },
+    GetSticker {
+        sticker_id: u64,
+    },
+    GetTemplate {
+        code: &'a str,
+    },
     GetUnresolvedIncidents,
     GetUpcomingMaintenances,
     GetUser {
@@ -861,12 +1299,25 @@ pub enum RouteInfo<'a> {
         user_id: u64,
         reason: &'a str,
     },
+    JoinThread {
+        channel_id: u64,
+    },
     LeaveGroup {
         group_id: u64,
     },
     LeaveGuild {
         guild_id: u64,
     },
+    LeaveThread {
+        channel_id: u64,
+    },
+    OverwriteGlobalApplicationCommands {
+        application_id: u64,
+    },
+    OverwriteGuildApplicationCommands {
+        application_id: u64,
+        guild_id: u64,
+    },
     RemoveGroupRecipient {
         group_id: u64,
         user_id: u64,
@@ -884,6 +1335,10 @@ pub enum RouteInfo<'a> {
         role_id: u64,
         user_id: u64,
     },
+    RemoveThreadMember {
+        channel_id: u64,
+        user_id: u64,
+    },
     StartGuildPrune {
         days: u64,
         guild_id: u64,
@@ -892,6 +1347,17 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         integration_id: u64,
     },
+    StartPrivateThread {
+        channel_id: u64,
+    },
+    StartThreadWithMessage {
+        channel_id: u64,
+        message_id: u64,
+    },
+    SyncGuildTemplate {
+        guild_id: u64,
+        code: &'a str,
+    },
     StatusIncidentsUnresolved,
     StatusMaintenancesActive,
     StatusMaintenancesUpcoming,
@@ -909,6 +1375,14 @@ impl<'a> RouteInfo<'a> {
                 Route::None,
                 Cow::from(Route::group_recipient(group_id, user_id)),
             ),
+            RouteInfo::AddThreadMember {
+                channel_id,
+                user_id,
+            } => (
+                LightMethod::Put,
+                Route::ChannelsIdThreadMembersUserId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, user_id)),
+            ),
             RouteInfo::AddMemberRole {
                 guild_id,
                 role_id,
@@ -949,9 +1423,39 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmojis(guild_id),
                 Cow::from(Route::guild_emojis(guild_id)),
             ),
+            RouteInfo::CreateFollowupMessage {
+                application_id,
+                interaction_token,
+            } => (
+                LightMethod::Post,
+                Route::WebhooksId(application_id),
+                Cow::from(Route::webhook_with_token_optioned(
+                    application_id,
+                    interaction_token,
+                    true,
+                )),
+            ),
+            RouteInfo::CreateGlobalApplicationCommand { application_id } => (
+                LightMethod::Post,
+                Route::ApplicationsIdCommands(application_id),
+                Cow::from(Route::application_commands(application_id)),
+            ),
             RouteInfo::CreateGuild => {
                 (LightMethod::Post, Route::Guilds, Cow::from(Route::guilds()))
             }
+            RouteInfo::CreateGuildApplicationCommand {
+                application_id,
+                guild_id,
+            } => (
+                LightMethod::Post,
+                Route::ApplicationsIdGuildsIdCommands(guild_id),
+                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+            ),
+            RouteInfo::CreateGuildFromTemplate { code } => (
+                LightMethod::Post,
+                Route::GuildsTemplatesCode,
+                Cow::from(Route::guilds_template(code)),
+            ),
             RouteInfo::CreateGuildIntegration {
                 guild_id,
                 integration_id,
@@ -960,6 +1464,22 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdIntegrationsId(guild_id),
                 Cow::from(Route::guild_integration(guild_id, integration_id)),
             ),
+            RouteInfo::CreateGuildTemplate { guild_id } => (
+                LightMethod::Post,
+                Route::GuildsIdTemplates(guild_id),
+                Cow::from(Route::guild_templates(guild_id)),
+            ),
+            RouteInfo::CreateInteractionResponse {
+                interaction_id,
+                interaction_token,
+            } => (
+                LightMethod::Post,
+                Route::InteractionsIdTokenCallback(interaction_id),
+                Cow::from(Route::interaction_response(
+                    interaction_id,
+                    interaction_token,
+                )),
+            ),
             RouteInfo::CreateInvite { channel_id } => (
                 LightMethod::Post,
                 Route::ChannelsIdInvites(channel_id),
@@ -999,11 +1519,24 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRoles(guild_id),
                 Cow::from(Route::guild_roles(guild_id)),
             ),
+            RouteInfo::CreateStageInstance => (
+                LightMethod::Post,
+                Route::StageInstances,
+                Cow::from(Route::stage_instances()),
+            ),
             RouteInfo::CreateWebhook { channel_id } => (
                 LightMethod::Post,
                 Route::ChannelsIdWebhooks(channel_id),
                 Cow::from(Route::channel_webhooks(channel_id)),
             ),
+            RouteInfo::CrosspostMessage {
+                channel_id,
+                message_id,
+            } => (
+                LightMethod::Post,
+                Route::ChannelsIdMessagesIdCrosspost(channel_id),
+                Cow::from(Route::channel_message_crosspost(channel_id, message_id)),
+            ),
             RouteInfo::DeleteChannel { channel_id } => (
                 LightMethod::Delete,
                 Route::ChannelsId(channel_id),
@@ -1014,11 +1547,32 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmojisId(guild_id),
                 Cow::from(Route::guild_emoji(guild_id, emoji_id)),
             ),
+            RouteInfo::DeleteGlobalApplicationCommand {
+                application_id,
+                command_id,
+            } => (
+                LightMethod::Delete,
+                Route::ApplicationsIdCommandsId(application_id),
+                Cow::from(Route::application_command(application_id, command_id)),
+            ),
             RouteInfo::DeleteGuild { guild_id } => (
                 LightMethod::Delete,
                 Route::GuildsId(guild_id),
                 Cow::from(Route::guild(guild_id)),
             ),
+            RouteInfo::DeleteGuildApplicationCommand {
+                application_id,
+                guild_id,
+                command_id,
+            } => (
+                LightMethod::Delete,
+                Route::ApplicationsIdGuildsIdCommandsId(guild_id),
+                Cow::from(Route::application_guild_command(
+                    application_id,
+                    guild_id,
+                    command_id,
+                )),
+            ),
             RouteInfo::DeleteGuildIntegration {
                 guild_id,
                 integration_id,
@@ -1027,6 +1581,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdIntegrationsId(guild_id),
                 Cow::from(Route::guild_integration(guild_id, integration_id)),
             ),
+            RouteInfo::DeleteGuildTemplate { guild_id, code } => (
+                LightMethod::Delete,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
             RouteInfo::DeleteInvite { code } => (
                 LightMethod::Delete,
                 Route::InvitesCode,
@@ -1078,6 +1637,19 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRolesId(guild_id),
                 Cow::from(Route::guild_role(guild_id, role_id)),
             ),
+            RouteInfo::DeleteStageInstance { channel_id } => (
+                LightMethod::Delete,
+                Route::StageInstancesChannelId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
+            RouteInfo::DeleteSticker {
+                guild_id,
+                sticker_id,
+            } => (
+                LightMethod::Delete,
+                Route::GuildsIdStickersId(guild_id),
+                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+            ),
             RouteInfo::DeleteWebhook { webhook_id } => (
                 LightMethod::Delete,
                 Route::WebhooksId(webhook_id),
@@ -1098,11 +1670,32 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmojisId(guild_id),
                 Cow::from(Route::guild_emoji(guild_id, emoji_id)),
             ),
+            RouteInfo::EditGlobalApplicationCommand {
+                application_id,
+                command_id,
+            } => (
+                LightMethod::Patch,
+                Route::ApplicationsIdCommandsId(application_id),
+                Cow::from(Route::application_command(application_id, command_id)),
+            ),
             RouteInfo::EditGuild { guild_id } => (
                 LightMethod::Patch,
                 Route::GuildsId(guild_id),
                 Cow::from(Route::guild(guild_id)),
             ),
+            RouteInfo::EditGuildApplicationCommand {
+                application_id,
+                guild_id,
+                command_id,
+            } => (
+                LightMethod::Patch,
+                Route::ApplicationsIdGuildsIdCommandsId(guild_id),
+                Cow::from(Route::application_guild_command(
+                    application_id,
+                    guild_id,
+                    command_id,
+                )),
+            ),
             RouteInfo::EditGuildChannels { guild_id } => (
                 LightMethod::Patch,
                 Route::GuildsIdChannels(guild_id),
@@ -1113,6 +1706,24 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmbed(guild_id),
                 Cow::from(Route::guild_embed(guild_id)),
             ),
+            RouteInfo::EditGuildIntegration {
+                guild_id,
+                integration_id,
+            } => (
+                LightMethod::Patch,
+                Route::GuildsIdIntegrationsId(guild_id),
+                Cow::from(Route::guild_integration(guild_id, integration_id)),
+            ),
+            RouteInfo::EditGuildTemplate { guild_id, code } => (
+                LightMethod::Patch,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
+            RouteInfo::EditGuildWelcomeScreen { guild_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdWelcomeScreen(guild_id),
+                Cow::from(Route::guild_welcome_screen(guild_id)),
+            ),
             RouteInfo::EditMember { guild_id, user_id } => (
                 LightMethod::Patch,
                 Route::GuildsIdMembersId(guild_id),
@@ -1131,6 +1742,17 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersMeNick(guild_id),
                 Cow::from(Route::guild_nickname(guild_id)),
             ),
+            RouteInfo::EditOriginalInteractionResponse {
+                application_id,
+                interaction_token,
+            } => (
+                LightMethod::Patch,
+                Route::WebhooksIdTokenMessagesOriginal(application_id),
+                Cow::from(Route::webhook_original_interaction_response(
+                    application_id,
+                    interaction_token,
+                )),
+            ),
             RouteInfo::EditProfile => (
                 LightMethod::Patch,
                 Route::UsersMe,
@@ -1146,6 +1768,19 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRolesId(guild_id),
                 Cow::from(Route::guild_roles(guild_id)),
             ),
+            RouteInfo::EditStageInstance { channel_id } => (
+                LightMethod::Patch,
+                Route::StageInstancesChannelId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
+            RouteInfo::EditSticker {
+                guild_id,
+                sticker_id,
+            } => (
+                LightMethod::Patch,
+                Route::GuildsIdStickersId(guild_id),
+                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+            ),
             RouteInfo::EditWebhook { webhook_id } => (
                 LightMethod::Patch,
                 Route::WebhooksId(webhook_id),
@@ -1165,6 +1800,11 @@ impl<'a> RouteInfo<'a> {
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook_with_token_optioned(webhook_id, token, wait)),
             ),
+            RouteInfo::FollowNewsChannel { channel_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdFollowers(channel_id),
+                Cow::from(Route::channel_followers(channel_id)),
+            ),
             RouteInfo::GetActiveMaintenance => (
                 LightMethod::Get,
                 Route::None,
@@ -1187,10 +1827,42 @@ impl<'a> RouteInfo<'a> {
                     limit,
                 )),
             ),
-            RouteInfo::GetBans { guild_id } => (
+            RouteInfo::GetActiveThreads { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdThreadsActive(guild_id),
+                Cow::from(Route::guild_active_threads(guild_id)),
+            ),
+            RouteInfo::GetArchivedPrivateThreads {
+                channel_id,
+                before,
+                limit,
+            } => (
+                LightMethod::Get,
+                Route::ChannelsIdThreadsArchivedPrivate(channel_id),
+                Cow::from(Route::channel_archived_threads(
+                    channel_id, "private", before, limit,
+                )),
+            ),
+            RouteInfo::GetArchivedPublicThreads {
+                channel_id,
+                before,
+                limit,
+            } => (
+                LightMethod::Get,
+                Route::ChannelsIdThreadsArchivedPublic(channel_id),
+                Cow::from(Route::channel_archived_threads(
+                    channel_id, "public", before, limit,
+                )),
+            ),
+            RouteInfo::GetBans {
+                guild_id,
+                after,
+                before,
+                limit,
+            } => (
                 LightMethod::Get,
                 Route::GuildsIdBans(guild_id),
-                Cow::from(Route::guild_bans(guild_id)),
+                Cow::from(Route::guild_bans_optioned(guild_id, after, before, limit)),
             ),
             RouteInfo::GetBotGateway => (
                 LightMethod::Get,
@@ -1232,11 +1904,45 @@ impl<'a> RouteInfo<'a> {
                 Route::Gateway,
                 Cow::from(Route::gateway()),
             ),
+            RouteInfo::GetGlobalApplicationCommand {
+                application_id,
+                command_id,
+            } => (
+                LightMethod::Get,
+                Route::ApplicationsIdCommandsId(application_id),
+                Cow::from(Route::application_command(application_id, command_id)),
+            ),
+            RouteInfo::GetGlobalApplicationCommands { application_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdCommands(application_id),
+                Cow::from(Route::application_commands(application_id)),
+            ),
             RouteInfo::GetGuild { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsId(guild_id),
                 Cow::from(Route::guild(guild_id)),
             ),
+            RouteInfo::GetGuildApplicationCommand {
+                application_id,
+                guild_id,
+                command_id,
+            } => (
+                LightMethod::Get,
+                Route::ApplicationsIdGuildsIdCommandsId(guild_id),
+                Cow::from(Route::application_guild_command(
+                    application_id,
+                    guild_id,
+                    command_id,
+                )),
+            ),
+            RouteInfo::GetGuildApplicationCommands {
+                application_id,
+                guild_id,
+            } => (
+                LightMethod::Get,
+                Route::ApplicationsIdGuildsIdCommands(guild_id),
+                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+            ),
             RouteInfo::GetGuildEmbed { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdEmbed(guild_id),
@@ -1276,6 +1982,24 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRoles(guild_id),
                 Cow::from(Route::guild_roles(guild_id)),
             ),
+            RouteInfo::GetGuildSticker {
+                guild_id,
+                sticker_id,
+            } => (
+                LightMethod::Get,
+                Route::GuildsIdStickersId(guild_id),
+                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+            ),
+            RouteInfo::GetGuildStickers { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdStickers(guild_id),
+                Cow::from(Route::guild_stickers(guild_id)),
+            ),
+            RouteInfo::GetGuildTemplates { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdTemplates(guild_id),
+                Cow::from(Route::guild_templates(guild_id)),
+            ),
             RouteInfo::GetGuildVanityUrl { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdVanityUrl(guild_id),
@@ -1286,6 +2010,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdWebhooks(guild_id),
                 Cow::from(Route::guild_webhooks(guild_id)),
             ),
+            RouteInfo::GetGuildWelcomeScreen { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdWelcomeScreen(guild_id),
+                Cow::from(Route::guild_welcome_screen(guild_id)),
+            ),
             RouteInfo::GetGuilds {
                 after,
                 before,
@@ -1339,6 +2068,21 @@ impl<'a> RouteInfo<'a> {
                     channel_id, message_id, reaction, limit, after,
                 )),
             ),
+            RouteInfo::GetStageInstance { channel_id } => (
+                LightMethod::Get,
+                Route::StageInstancesChannelId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
+            RouteInfo::GetSticker { sticker_id } => (
+                LightMethod::Get,
+                Route::StickersId,
+                Cow::from(Route::sticker(sticker_id)),
+            ),
+            RouteInfo::GetTemplate { code } => (
+                LightMethod::Get,
+                Route::GuildsTemplatesCode,
+                Cow::from(Route::guilds_template(code)),
+            ),
             RouteInfo::GetUnresolvedIncidents => (
                 LightMethod::Get,
                 Route::None,
@@ -1383,6 +2127,16 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersId(guild_id),
                 Cow::from(Route::guild_kick_optioned(guild_id, user_id, reason)),
             ),
+            RouteInfo::JoinThread { channel_id } => (
+                LightMethod::Put,
+                Route::ChannelsIdThreadMembersUserId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, "@me")),
+            ),
+            RouteInfo::LeaveThread { channel_id } => (
+                LightMethod::Delete,
+                Route::ChannelsIdThreadMembersUserId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, "@me")),
+            ),
             RouteInfo::LeaveGroup { group_id } => (
                 LightMethod::Delete,
                 Route::ChannelsId(group_id),
@@ -1393,6 +2147,19 @@ impl<'a> RouteInfo<'a> {
                 Route::UsersMeGuildsId,
                 Cow::from(Route::user_guild("@me", guild_id)),
             ),
+            RouteInfo::OverwriteGlobalApplicationCommands { application_id } => (
+                LightMethod::Put,
+                Route::ApplicationsIdCommands(application_id),
+                Cow::from(Route::application_commands(application_id)),
+            ),
+            RouteInfo::OverwriteGuildApplicationCommands {
+                application_id,
+                guild_id,
+            } => (
+                LightMethod::Put,
+                Route::ApplicationsIdGuildsIdCommands(guild_id),
+                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+            ),
             RouteInfo::RemoveGroupRecipient { group_id, user_id } => (
                 LightMethod::Delete,
                 Route::None,
@@ -1420,6 +2187,27 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersIdRolesId(guild_id),
                 Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
             ),
+            RouteInfo::RemoveThreadMember {
+                channel_id,
+                user_id,
+            } => (
+                LightMethod::Delete,
+                Route::ChannelsIdThreadMembersUserId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, user_id)),
+            ),
+            RouteInfo::StartPrivateThread { channel_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdThreads(channel_id),
+                Cow::from(Route::channel_threads(channel_id)),
+            ),
+            RouteInfo::StartThreadWithMessage {
+                channel_id,
+                message_id,
+            } => (
+                LightMethod::Post,
+                Route::ChannelsIdMessagesIdThreads(channel_id),
+                Cow::from(Route::channel_message_threads(channel_id, message_id)),
+            ),
             RouteInfo::StartGuildPrune { days, guild_id } => (
                 LightMethod::Post,
                 Route::GuildsIdPrune(guild_id),
@@ -1433,6 +2221,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdIntegrationsId(guild_id),
                 Cow::from(Route::guild_integration_sync(guild_id, integration_id)),
             ),
+            RouteInfo::SyncGuildTemplate { guild_id, code } => (
+                LightMethod::Put,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
             RouteInfo::StatusIncidentsUnresolved => (
                 LightMethod::Get,
                 Route::None,
@@ -0,0 +1,55 @@
+use reqwest::ClientBuilder;
+use std::time::Duration;
+
+/// How long an [`Http`] client waits for Discord before giving up on a
+/// request.
+///
+/// A request which times out fails with [`HttpError::Timeout`], allowing a
+/// slow response from Discord to be told apart from a lost connection, which
+/// fails with [`HttpError::Request`].
+///
+/// # Examples
+///
+/// Give up on requests which were not answered within 10 seconds:
+///
+/// ```rust,no_run
+/// use serenity::http::{Http, HttpTimeouts};
+/// use std::time::Duration;
+///
+/// let mut timeouts = HttpTimeouts::default();
+/// timeouts.request = Some(Duration::from_secs(10));
+///
+/// let http = Http::new_with_timeouts("Bot token", timeouts);
+/// ```
+///
+/// [`Http`]: client/struct.Http.html
+/// [`HttpError::Request`]: error/enum.Error.html#variant.Request
+/// [`HttpError::Timeout`]: error/enum.Error.html#variant.Timeout
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct HttpTimeouts {
+    /// How long to wait for a connection to be established, including the
+    /// TLS handshake.
+    ///
+    /// Defaults to `None`, waiting as long as the operating system allows.
+    pub connect: Option<Duration>,
+    /// How long to wait for a whole request, from connecting until the body
+    /// of the response was received.
+    ///
+    /// Defaults to `None`, waiting indefinitely.
+    pub request: Option<Duration>,
+}
+
+impl HttpTimeouts {
+    pub(super) fn apply(self, mut builder: ClientBuilder) -> ClientBuilder {
+        if let Some(connect) = self.connect {
+            builder = builder.connect_timeout(connect);
+        }
+
+        if let Some(request) = self.request {
+            builder = builder.timeout(request);
+        }
+
+        builder
+    }
+}
@@ -0,0 +1,33 @@
+use crate::internal::prelude::*;
+use serde::de::DeserializeOwned;
+
+/// Deserializes JSON from a buffer, using simd-json if the `simd_json`
+/// feature is enabled.
+///
+/// The buffer may be modified in place, so its contents should not be used
+/// afterwards.
+pub fn from_slice<T: DeserializeOwned>(bytes: &mut [u8]) -> Result<T> {
+    #[cfg(feature = "simd_json")]
+    let value = simd_json::from_slice(bytes)?;
+    #[cfg(not(feature = "simd_json"))]
+    let value = serde_json::from_slice(bytes)?;
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::from_slice;
+    use crate::model::event::GuildCreateEvent;
+    use serde_json::Value;
+
+    #[test]
+    fn test_from_slice() {
+        let mut bytes = include_bytes!("../../tests/resources/guild_create_1.json").to_vec();
+        let event = from_slice::<GuildCreateEvent>(&mut bytes).unwrap();
+        assert_eq!(event.guild.name, "Discord API");
+
+        let mut bytes = b"{\"a\": [1, 2".to_vec();
+        assert!(from_slice::<Value>(&mut bytes).is_err());
+    }
+}
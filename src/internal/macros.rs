@@ -10,6 +10,8 @@ macro_rules! cdn {
     };
 }
 
+// The version must match `constants::API_VERSION`, as requests to other
+// versions are sent by replacing this base URL.
 #[cfg(feature = "http")]
 macro_rules! api {
     ($e:expr) => {
@@ -42,14 +44,63 @@ macro_rules! feature_cache {
     }};
 }
 
+/// Defines an enum whose variants map to integer values, as used by many of
+/// Discord's fields.
+///
+/// An `Unknown(u64)` variant is appended to the enum, so that values which
+/// are not known to the library are retained instead of failing to
+/// deserialize. The enum also implements `From<u64>`, `From<Enum> for u64`,
+/// a `num` method, and (de)serializes as an integer.
 macro_rules! enum_number {
-    ($name:ident { $($variant:ident, )* }) => {
+    (
+        $(#[$outer:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$attr:meta])*
+                $variant:ident = $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$outer])*
+        $vis enum $name {
+            $(
+                $(#[$attr])*
+                $variant,
+            )*
+            /// A value which is not known to the library.
+            Unknown(u64),
+        }
+
+        impl $name {
+            /// Returns the integer value of the variant.
+            pub fn num(self) -> u64 {
+                u64::from(self)
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(value: u64) -> Self {
+                match value {
+                    $( $value => $name::$variant, )*
+                    other => $name::Unknown(other),
+                }
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(value: $name) -> Self {
+                match value {
+                    $( $name::$variant => $value, )*
+                    $name::Unknown(other) => other,
+                }
+            }
+        }
+
         impl ::serde::Serialize for $name {
             fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
                 where S: ::serde::Serializer
             {
-                // Serialize the enum as a u64.
-                serializer.serialize_u64(*self as u64)
+                serializer.serialize_u64(self.num())
             }
         }
 
@@ -57,32 +108,7 @@ macro_rules! enum_number {
             fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
                 where D: ::serde::Deserializer<'de>
             {
-                struct Visitor;
-
-                impl<'de> ::serde::de::Visitor<'de> for Visitor {
-                    type Value = $name;
-
-                    fn expecting(&self, formatter: &mut ::std::fmt::Formatter<'_>)
-                        -> ::std::fmt::Result {
-                        formatter.write_str("positive integer")
-                    }
-
-                    fn visit_u64<E>(self, value: u64) -> ::std::result::Result<$name, E>
-                        where E: ::serde::de::Error
-                    {
-                        // Rust does not come with a simple way of converting a
-                        // number to an enum, so use a big `match`.
-                        match value {
-                            $( v if v == $name::$variant as u64 => Ok($name::$variant), )*
-                            _ => Err(E::custom(
-                                format!("unknown {} value: {}",
-                                stringify!($name), value))),
-                        }
-                    }
-                }
-
-                // Deserialize the enum from a u64.
-                deserializer.deserialize_u64(Visitor)
+                <u64 as ::serde::Deserialize>::deserialize(deserializer).map($name::from)
             }
         }
     }
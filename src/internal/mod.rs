@@ -10,6 +10,9 @@ pub use self::rwlock_ext::RwLockExt;
 pub use async_std::sync::RwLock as AsyncRwLock;
 pub use parking_lot::RwLock as SyncRwLock;
 
+#[cfg(feature = "http")]
+pub mod json;
+
 #[cfg(feature = "gateway")]
 pub mod ws_impl;
 
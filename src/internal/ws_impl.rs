@@ -1,8 +1,16 @@
-use crate::gateway::WsClient;
-use crate::internal::prelude::*;
+use crate::gateway::{WsClient, WsProxy};
+use crate::internal::{json, prelude::*};
 use flate2::read::ZlibDecoder;
 use log::warn;
 use tungstenite::{util::NonBlockingResult, Message};
+use url::Url;
+
+#[cfg(feature = "metrics")]
+use crate::gateway::StageMetrics;
+use std::io::Read;
+
+#[cfg(feature = "metrics")]
+use std::time::Instant;
 
 #[cfg(not(feature = "native_tls_backend"))]
 use std::{
@@ -13,9 +21,17 @@ use std::{
     sync::Arc,
 };
 
+// With metrics, the shard runner receives raw payloads and only voice parses
+// them here.
+#[cfg_attr(all(feature = "metrics", not(feature = "voice")), allow(dead_code))]
 pub trait ReceiverExt {
     fn recv_json(&mut self) -> Result<Option<Value>>;
     fn try_recv_json(&mut self) -> Result<Option<Value>>;
+
+    /// Receives a message and decompresses it if needed, without parsing it,
+    /// recording the time spent on each step.
+    #[cfg(feature = "metrics")]
+    fn recv_inflated(&mut self, metrics: &StageMetrics) -> Result<Option<Vec<u8>>>;
 }
 
 pub trait SenderExt {
@@ -30,6 +46,27 @@ impl ReceiverExt for WsClient {
     fn try_recv_json(&mut self) -> Result<Option<Value>> {
         convert_ws_message(self.read_message().no_block()?)
     }
+
+    #[cfg(feature = "metrics")]
+    fn recv_inflated(&mut self, metrics: &StageMetrics) -> Result<Option<Vec<u8>>> {
+        let start = Instant::now();
+        let message = self.read_message()?;
+        metrics.ws_read.record(start.elapsed());
+
+        Ok(match message {
+            Message::Binary(bytes) => {
+                let start = Instant::now();
+                let mut inflated = Vec::with_capacity(bytes.len() * 4);
+                ZlibDecoder::new(&bytes[..]).read_to_end(&mut inflated)?;
+                metrics.inflate.record(start.elapsed());
+
+                Some(inflated)
+            }
+            Message::Text(payload) => Some(payload.into_bytes()),
+            // Ping/Pong message behaviour is internally handled by tungstenite.
+            _ => None,
+        })
+    }
 }
 
 impl SenderExt for WsClient {
@@ -42,18 +79,24 @@ impl SenderExt for WsClient {
 }
 
 #[inline]
+#[cfg_attr(all(feature = "metrics", not(feature = "voice")), allow(dead_code))]
 fn convert_ws_message(message: Option<Message>) -> Result<Option<Value>> {
     Ok(match message {
-        Some(Message::Binary(bytes)) => serde_json::from_reader(ZlibDecoder::new(&bytes[..]))
-            .map(Some)
-            .map_err(|why| {
+        Some(Message::Binary(bytes)) => {
+            let mut inflated = Vec::with_capacity(bytes.len() * 4);
+            ZlibDecoder::new(&bytes[..]).read_to_end(&mut inflated)?;
+
+            json::from_slice(&mut inflated).map(Some).map_err(|why| {
                 warn!("Err deserializing bytes: {:?}; bytes: {:?}", why, bytes);
 
                 why
-            })?,
+            })?
+        }
         Some(Message::Text(payload)) => {
-            serde_json::from_str(&payload).map(Some).map_err(|why| {
-                warn!("Err deserializing text: {:?}; text: {}", why, payload,);
+            let mut bytes = payload.clone().into_bytes();
+
+            json::from_slice(&mut bytes).map(Some).map_err(|why| {
+                warn!("Err deserializing text: {:?}; text: {}", why, payload);
 
                 why
             })?
@@ -109,6 +152,19 @@ impl StdError for RustlsError {
 // Create a tungstenite client with a rustls stream.
 #[cfg(not(feature = "native_tls_backend"))]
 pub(crate) fn create_rustls_client(url: Url) -> Result<WsClient> {
+    let port = url
+        .port_or_known_default()
+        .ok_or_else(|| Error::Url("No port number in the URL.".into()))?;
+
+    let addrs = url.socket_addrs(|| Some(port))?;
+
+    let socket = TcpStream::connect(addrs.as_slice())?;
+
+    wrap_rustls_client(url, socket)
+}
+
+#[cfg(not(feature = "native_tls_backend"))]
+fn wrap_rustls_client(url: Url, socket: TcpStream) -> Result<WsClient> {
     let mut config = rustls::ClientConfig::new();
     config
         .root_store
@@ -130,16 +186,56 @@ pub(crate) fn create_rustls_client(url: Url) -> Result<WsClient> {
 
     let session = rustls::ClientSession::new(&Arc::new(config), dns_name);
 
+    let tls = rustls::StreamOwned::new(session, socket);
+
+    let client = tungstenite::client(url, tls).map_err(|_| RustlsError::HandshakeError)?;
+
+    Ok(client.0)
+}
+
+// Create a tungstenite client whose connection is tunnelled through a proxy.
+pub(crate) fn create_proxied_client(url: Url, proxy: &WsProxy) -> Result<WsClient> {
+    let host = url
+        .host_str()
+        .ok_or_else(|| Error::Url("No host in the URL.".into()))?;
+
     let port = url
         .port_or_known_default()
         .ok_or_else(|| Error::Url("No port number in the URL.".into()))?;
 
-    let addrs = url.socket_addrs(|| Some(port))?;
+    let socket = proxy.connect(host, port)?;
 
-    let socket = TcpStream::connect(addrs.as_slice())?;
-    let tls = rustls::StreamOwned::new(session, socket);
+    #[cfg(not(feature = "native_tls_backend"))]
+    {
+        wrap_rustls_client(url, socket)
+    }
 
-    let client = tungstenite::client(url, tls).map_err(|_| RustlsError::HandshakeError)?;
+    #[cfg(feature = "native_tls_backend")]
+    {
+        use native_tls::{HandshakeError as TlsHandshakeError, TlsConnector};
+        use tungstenite::{handshake::HandshakeError, stream::Stream, Error as TungsteniteError};
+
+        let stream = if url.scheme() == "wss" {
+            let connector = TlsConnector::new().map_err(TungsteniteError::from)?;
+            let tls = connector.connect(host, socket).map_err(|why| match why {
+                TlsHandshakeError::Failure(why) => TungsteniteError::from(why),
+                TlsHandshakeError::WouldBlock(_) => {
+                    TungsteniteError::Io(std::io::ErrorKind::WouldBlock.into())
+                }
+            })?;
+
+            Stream::Tls(tls)
+        } else {
+            Stream::Plain(socket)
+        };
+
+        let client = tungstenite::client(url, stream).map_err(|why| match why {
+            HandshakeError::Failure(why) => why,
+            HandshakeError::Interrupted(_) => {
+                TungsteniteError::Io(std::io::ErrorKind::WouldBlock.into())
+            }
+        })?;
 
-    Ok(client.0)
+        Ok(client.0)
+    }
 }
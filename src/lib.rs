@@ -61,6 +61,7 @@ extern crate serde;
 #[macro_use]
 mod internal;
 
+pub mod all;
 pub mod constants;
 pub mod model;
 pub mod prelude;
@@ -91,25 +92,98 @@ pub use crate::internal::{AsyncRwLock, SyncRwLock};
 pub use crate::client::Client;
 
 #[cfg(feature = "cache")]
-use crate::cache::CacheRwLock;
-#[cfg(feature = "client")]
+use crate::cache::{Cache, Settings as CacheSettings};
+#[cfg(feature = "http")]
 use crate::http::Http;
-#[cfg(feature = "client")]
+#[cfg(feature = "http")]
 use std::sync::Arc;
-#[cfg(feature = "cache")]
-use std::time::Duration;
 
-#[cfg(feature = "client")]
+/// A container for the cache and HTTP client, as used by the [`Client`].
+///
+/// This implements [`CacheHttp`], so it can be used with model methods
+/// outside of a client, such as in a web dashboard or a REST-only tool which
+/// does not connect to the gateway.
+///
+/// [`CacheHttp`]: http/trait.CacheHttp.html
+/// [`Client`]: client/struct.Client.html
+#[cfg(feature = "http")]
 #[derive(Default)]
 #[non_exhaustive]
 pub struct CacheAndHttp {
     #[cfg(feature = "cache")]
-    pub cache: CacheRwLock,
-    #[cfg(feature = "cache")]
-    pub update_cache_timeout: Option<Duration>,
+    pub cache: Arc<Cache>,
     pub http: Arc<Http>,
 }
 
+#[cfg(feature = "http")]
+impl CacheAndHttp {
+    /// Creates a new cache with the given settings, and an HTTP client using
+    /// the given token.
+    ///
+    /// The token is prefixed with `"Bot "` if it is not already.
+    ///
+    /// # Examples
+    ///
+    /// Look up a member from a web service, using the cache if possible:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{cache::Settings, model::id::{GuildId, UserId}, CacheAndHttp};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let cache_and_http = CacheAndHttp::new(Settings::default(), "token");
+    ///
+    /// let member = GuildId(7).member(&cache_and_http, UserId(8)).await?;
+    ///
+    /// println!("{} joined at {:?}", member.display_name(), member.joined_at);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "cache")]
+    pub fn new(cache_settings: CacheSettings, token: &str) -> Self {
+        CacheAndHttp {
+            cache: Arc::new(Cache::new_with_settings(cache_settings)),
+            http: Arc::new(Http::new_with_token(&bot_token(token))),
+        }
+    }
+
+    /// Creates a new HTTP client using the given token.
+    ///
+    /// The token is prefixed with `"Bot "` if it is not already.
+    #[cfg(not(feature = "cache"))]
+    pub fn new(token: &str) -> Self {
+        CacheAndHttp {
+            http: Arc::new(Http::new_with_token(&bot_token(token))),
+        }
+    }
+}
+
+/// Trims the token, and prefixes it with `"Bot "` if it is not already.
+#[cfg(feature = "http")]
+pub(crate) fn bot_token(token: &str) -> String {
+    let token = token.trim();
+
+    if token.starts_with("Bot ") {
+        token.to_string()
+    } else {
+        format!("Bot {}", token)
+    }
+}
+
+#[cfg(all(feature = "cache", feature = "http"))]
+impl AsRef<Cache> for CacheAndHttp {
+    fn as_ref(&self) -> &Cache {
+        &self.cache
+    }
+}
+
+#[cfg(feature = "http")]
+impl AsRef<Http> for CacheAndHttp {
+    fn as_ref(&self) -> &Http {
+        &self.http
+    }
+}
+
 // For the procedural macros defined in `command_attr`; do not remove!
 #[allow(clippy::useless_attribute)]
 #[allow(unused_attributes)]
@@ -1,6 +1,11 @@
 //! Models about OAuth2 applications.
 
-use super::{id::UserId, user::User, utils::default_true};
+use super::{
+    id::{ApplicationId, UserId},
+    user::User,
+    utils::default_true,
+    Timestamp,
+};
 
 /// Information about a user's application. An application does not necessarily
 /// have an associated bot user.
@@ -86,3 +91,44 @@ pub struct CurrentApplicationInfo {
     pub bot_public: bool,
     pub bot_require_code_grant: bool,
 }
+
+/// Information about the application a user authorized through OAuth2, as
+/// seen by that user.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct OAuth2Application {
+    /// The unique Id of the application.
+    pub id: ApplicationId,
+    /// The name assigned to the application by the application owner.
+    pub name: String,
+    /// A hash pointing to the application's icon.
+    pub icon: Option<String>,
+    /// A description of the application, assigned by the application owner.
+    pub description: String,
+    /// Indicator of whether the application's bot is public.
+    #[serde(default = "default_true")]
+    pub bot_public: bool,
+    /// Indicator of whether the application's bot requires an OAuth2 code
+    /// grant.
+    #[serde(default)]
+    pub bot_require_code_grant: bool,
+    /// The hex encoded key used to verify interactions and GameSDK tokens.
+    pub verify_key: String,
+}
+
+/// Information about the current authorization of a user through OAuth2.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct CurrentAuthorizationInfo {
+    /// The application the user authorized.
+    pub application: OAuth2Application,
+    /// The scopes the user authorized the application for.
+    pub scopes: Vec<String>,
+    /// When the access token expires.
+    pub expires: Timestamp,
+    /// The user who authorized the application.
+    ///
+    /// This is only sent if the application was authorized with the
+    /// `identify` scope.
+    pub user: Option<User>,
+}
@@ -1,11 +1,9 @@
 use super::super::id::AttachmentId;
 
 #[cfg(feature = "model")]
-use crate::internal::prelude::*;
+use crate::http::Http;
 #[cfg(feature = "model")]
-use reqwest::Client as ReqwestClient;
-//#[cfg(feature = "model")]
-//use std::io::Read;
+use crate::internal::prelude::*;
 
 /// A file uploaded with a message. Not to be confused with [`Embed`]s.
 ///
@@ -41,6 +39,9 @@ impl Attachment {
 
     /// Downloads the attachment, returning back a vector of bytes.
     ///
+    /// The download reuses the connection pool and configuration of the
+    /// given [`Http`] client.
+    ///
     /// # Examples
     ///
     /// Download all of the attachments associated with a [`Message`]:
@@ -63,7 +64,7 @@ impl Attachment {
     /// impl EventHandler for Handler {
     ///     async fn message(&self, context: Context, mut message: Message) {
     ///         for attachment in message.attachments {
-    ///             let content = match attachment.download().await {
+    ///             let content = match attachment.download(&context.http).await {
     ///                 Ok(content) => content,
     ///                 Err(why) => {
     ///                     println!("Error downloading attachment: {:?}", why);
@@ -118,11 +119,46 @@ impl Attachment {
     ///
     /// [`Error::Http`]: ../../enum.Error.html#variant.Http
     /// [`Error::Io`]: ../../enum.Error.html#variant.Io
+    /// [`Http`]: ../../http/client/struct.Http.html
     /// [`Message`]: struct.Message.html
-    pub async fn download(&self) -> Result<Vec<u8>> {
-        let reqwest = ReqwestClient::new();
-        let response = reqwest.get(&self.url).send().await?.bytes().await?;
+    #[inline]
+    pub async fn download(&self, http: impl AsRef<Http>) -> Result<Vec<u8>> {
+        http.as_ref().download(&self.url, |_| {}).await
+    }
+
+    /// Downloads the attachment like [`download`], calling `progress` with the
+    /// number of bytes downloaded so far and the [`size`] of the attachment
+    /// each time a chunk of it was received.
+    ///
+    /// # Examples
+    ///
+    /// Report the progress of a large download:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::channel::Attachment;
+    /// #
+    /// # async fn run(http: &Http, attachment: &Attachment) -> serenity::Result<()> {
+    /// let content = attachment
+    ///     .download_with_progress(http, |downloaded, size| {
+    ///         println!("{}%", downloaded * 100 / size.max(1));
+    ///     })
+    ///     .await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`download`]: #method.download
+    /// [`size`]: #structfield.size
+    pub async fn download_with_progress(
+        &self,
+        http: impl AsRef<Http>,
+        mut progress: impl FnMut(u64, u64),
+    ) -> Result<Vec<u8>> {
+        let size = self.size;
 
-        Ok(response.into_iter().collect())
+        http.as_ref()
+            .download(&self.url, |downloaded| progress(downloaded, size))
+            .await
     }
 }
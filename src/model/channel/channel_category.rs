@@ -4,6 +4,8 @@ use crate::model::prelude::*;
 
 #[cfg(all(feature = "builder", feature = "model"))]
 use crate::builder::EditChannel;
+#[cfg(all(feature = "cache", feature = "model"))]
+use crate::cache::Cache;
 #[cfg(feature = "http")]
 use crate::http::Http;
 #[cfg(all(feature = "model", feature = "utils"))]
@@ -106,10 +108,11 @@ impl ChannelCategory {
         let mut edit_channel = EditChannel::default();
         f(&mut edit_channel);
         let map = serenity_utils::hashmap_to_json_map(edit_channel.0);
+        let reason = edit_channel.1.as_deref().unwrap_or("");
 
         cache_http
             .http()
-            .edit_channel(self.id.0, &map)
+            .edit_channel_with_reason(self.id.0, &map, reason)
             .await
             .map(|channel| {
                 let GuildChannel {
@@ -146,4 +149,16 @@ impl ChannelCategory {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// Retrieves the channels in the category from the Cache, ordered by
+    /// their position.
+    ///
+    /// Refer to [`Cache::category_channels`] for more information.
+    ///
+    /// [`Cache::category_channels`]: ../../cache/struct.Cache.html#method.category_channels
+    #[cfg(feature = "cache")]
+    #[inline]
+    pub fn channels(&self, cache: impl AsRef<Cache>) -> Vec<GuildChannel> {
+        cache.as_ref().category_channels(self.id)
+    }
 }
@@ -3,17 +3,21 @@ use crate::http::CacheHttp;
 use crate::model::prelude::*;
 
 #[cfg(feature = "model")]
-use crate::builder::{CreateMessage, EditChannel, EditMessage, GetMessages};
+use crate::builder::{
+    CreateMessage, CreateThread, CreateWebhook, EditChannel, EditMessage, GetMessages,
+};
 #[cfg(all(feature = "cache", feature = "model"))]
-use crate::cache::{Cache, CacheRwLock};
+use crate::cache::Cache;
 #[cfg(feature = "model")]
 use crate::http::AttachmentType;
 #[cfg(feature = "http")]
 use crate::http::Http;
-#[cfg(all(feature = "cache", feature = "model"))]
-use crate::internal::AsyncRwLock;
 #[cfg(feature = "model")]
 use crate::utils;
+#[cfg(feature = "http")]
+use async_stream::try_stream;
+#[cfg(feature = "http")]
+use futures::Stream;
 #[cfg(all(feature = "http", feature = "model"))]
 use serde_json::json;
 #[cfg(feature = "model")]
@@ -21,6 +25,20 @@ use std::borrow::Cow;
 #[cfg(feature = "model")]
 use std::fmt::Write as FmtWrite;
 
+#[cfg(feature = "model")]
+fn channel_name(channel: Channel) -> String {
+    match channel {
+        Channel::Guild(channel) => channel.name().to_string(),
+        Channel::Group(channel) => match channel.name() {
+            Cow::Borrowed(name) => name.to_string(),
+            Cow::Owned(name) => name,
+        },
+        Channel::Category(category) => category.name().to_string(),
+        Channel::Private(channel) => channel.name(),
+        Channel::Thread(thread) => thread.name,
+    }
+}
+
 #[cfg(feature = "model")]
 impl ChannelId {
     /// Broadcasts that the current user is typing to a channel for the next 5
@@ -125,6 +143,108 @@ impl ChannelId {
             .await
     }
 
+    /// Creates a [`Webhook`] in the channel.
+    ///
+    /// Refer to [`CreateWebhook`] for the fields which may be set.
+    ///
+    /// Requires the [Manage Webhooks] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{http::Http, model::id::ChannelId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// let webhook = ChannelId(7)
+    ///     .create_webhook(&http, |w| w.name("announcements").audit_log_reason("Release feed"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`CreateWebhook`]: ../../builder/struct.CreateWebhook.html
+    /// [`Webhook`]: ../webhook/struct.Webhook.html
+    /// [Manage Webhooks]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_WEBHOOKS
+    #[cfg(feature = "http")]
+    pub async fn create_webhook<F>(self, http: impl AsRef<Http>, f: F) -> Result<Webhook>
+    where
+        F: FnOnce(&mut CreateWebhook) -> &mut CreateWebhook,
+    {
+        let mut builder = CreateWebhook::default();
+        f(&mut builder);
+
+        let map = Value::Object(utils::hashmap_to_json_map(builder.0));
+        let reason = builder.1.as_deref().unwrap_or("");
+
+        http.as_ref()
+            .create_webhook_with_reason(self.0, &map, reason)
+            .await
+    }
+
+    /// Creates a [`Webhook`] with the given name and avatar in the channel.
+    ///
+    /// The avatar can be given as any [`AttachmentType`], such as a path to an
+    /// image file or an image URL, and is encoded with [`Http::image_data`].
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{http::Http, model::id::ChannelId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// let webhook = ChannelId(7)
+    ///     .create_webhook_with_avatar(&http, "announcements", "./avatar.png")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`AttachmentType`]: ../../http/enum.AttachmentType.html
+    /// [`Http::image_data`]: ../../http/client/struct.Http.html#method.image_data
+    /// [`Webhook`]: ../webhook/struct.Webhook.html
+    /// [Manage Webhooks]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_WEBHOOKS
+    #[cfg(all(feature = "http", feature = "utils"))]
+    pub async fn create_webhook_with_avatar<'a>(
+        self,
+        http: impl AsRef<Http>,
+        name: &str,
+        avatar: impl Into<AttachmentType<'a>>,
+    ) -> Result<Webhook> {
+        let http = http.as_ref();
+        let avatar = http.image_data(avatar.into()).await?;
+
+        self.create_webhook(http, |w| w.name(name).avatar(avatar))
+            .await
+    }
+
+    /// Starts a [`StageInstance`] with the given topic in the stage channel.
+    ///
+    /// **Note**: Requires the current user to be a moderator of the stage,
+    /// through the [Manage Channels], [Mute Members] and [Move Members]
+    /// permissions.
+    ///
+    /// [`StageInstance`]: ../channel/struct.StageInstance.html
+    /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    /// [Move Members]: ../permissions/struct.Permissions.html#associatedconstant.MOVE_MEMBERS
+    /// [Mute Members]: ../permissions/struct.Permissions.html#associatedconstant.MUTE_MEMBERS
+    #[cfg(feature = "http")]
+    pub async fn create_stage_instance(
+        self,
+        http: impl AsRef<Http>,
+        topic: &str,
+    ) -> Result<StageInstance> {
+        let map = json!({
+            "channel_id": self.0,
+            "topic": topic,
+        });
+
+        http.as_ref().create_stage_instance(&map).await
+    }
+
     /// Deletes this channel, returning the channel on a successful deletion.
     #[cfg(feature = "http")]
     #[inline]
@@ -149,12 +269,37 @@ impl ChannelId {
         http: impl AsRef<Http>,
         message_id: M,
     ) -> Result<()> {
-        self._delete_message(&http, message_id.into()).await
+        self._delete_message(&http, message_id.into(), "").await
+    }
+
+    /// Deletes a [`Message`] given its Id, recording a reason in the guild's
+    /// audit log.
+    ///
+    /// Refer to [`delete_message`] for more information.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    /// [`delete_message`]: #method.delete_message
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn delete_message_with_reason<M: Into<MessageId>>(
+        self,
+        http: impl AsRef<Http>,
+        message_id: M,
+        reason: &str,
+    ) -> Result<()> {
+        self._delete_message(&http, message_id.into(), reason).await
     }
 
     #[cfg(feature = "http")]
-    async fn _delete_message(self, http: impl AsRef<Http>, message_id: MessageId) -> Result<()> {
-        http.as_ref().delete_message(self.0, message_id.0).await
+    async fn _delete_message(
+        self,
+        http: impl AsRef<Http>,
+        message_id: MessageId,
+        reason: &str,
+    ) -> Result<()> {
+        http.as_ref()
+            .delete_message_with_reason(self.0, message_id.0, reason)
+            .await
     }
 
     /// Deletes all messages by Ids from the given vector in the given channel.
@@ -186,21 +331,49 @@ impl ChannelId {
             .map(|message_id| message_id.as_ref().0)
             .collect::<Vec<u64>>();
 
-        self._delete_messages(&http, &ids).await
+        self._delete_messages(&http, &ids, "").await
+    }
+
+    /// Deletes all messages by Ids from the given vector in the given channel,
+    /// recording a reason in the guild's audit log.
+    ///
+    /// Refer to [`delete_messages`] for more information.
+    ///
+    /// [`delete_messages`]: #method.delete_messages
+    #[cfg(feature = "http")]
+    pub async fn delete_messages_with_reason<T: AsRef<MessageId>, It: IntoIterator<Item = T>>(
+        self,
+        http: impl AsRef<Http>,
+        message_ids: It,
+        reason: &str,
+    ) -> Result<()> {
+        let ids = message_ids
+            .into_iter()
+            .map(|message_id| message_id.as_ref().0)
+            .collect::<Vec<u64>>();
+
+        self._delete_messages(&http, &ids, reason).await
     }
 
     #[cfg(feature = "http")]
-    async fn _delete_messages(self, http: impl AsRef<Http>, ids: &[u64]) -> Result<()> {
+    async fn _delete_messages(
+        self,
+        http: impl AsRef<Http>,
+        ids: &[u64],
+        reason: &str,
+    ) -> Result<()> {
         let len = ids.len();
 
         if len == 0 || len > 100 {
             Err(Error::Model(ModelError::BulkDeleteAmount))
         } else if ids.len() == 1 {
-            self.delete_message(&http, ids[0]).await
+            self._delete_message(&http, MessageId(ids[0]), reason).await
         } else {
             let map = json!({ "messages": ids });
 
-            http.as_ref().delete_messages(self.0, &map).await
+            http.as_ref()
+                .delete_messages_with_reason(self.0, &map, reason)
+                .await
         }
     }
 
@@ -270,6 +443,22 @@ impl ChannelId {
             .await
     }
 
+    /// Ends the [`StageInstance`] running in the stage channel.
+    ///
+    /// **Note**: Requires the current user to be a moderator of the stage,
+    /// through the [Manage Channels], [Mute Members] and [Move Members]
+    /// permissions.
+    ///
+    /// [`StageInstance`]: ../channel/struct.StageInstance.html
+    /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    /// [Move Members]: ../permissions/struct.Permissions.html#associatedconstant.MOVE_MEMBERS
+    /// [Mute Members]: ../permissions/struct.Permissions.html#associatedconstant.MUTE_MEMBERS
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn delete_stage_instance(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().delete_stage_instance(self.0).await
+    }
+
     /// Edits the settings of a [`Channel`], optionally setting new values.
     ///
     /// Refer to `EditChannel`'s documentation for its methods.
@@ -299,8 +488,11 @@ impl ChannelId {
         f(&mut channel);
 
         let map = utils::hashmap_to_json_map(channel.0);
+        let reason = channel.1.as_deref().unwrap_or("");
 
-        http.as_ref().edit_channel(self.0, &map).await
+        http.as_ref()
+            .edit_channel_with_reason(self.0, &map, reason)
+            .await
     }
 
     /// Edits a [`Message`] in the channel given its Id.
@@ -363,20 +555,36 @@ impl ChannelId {
         http.as_ref().edit_message(self.0, message_id.0, &obj).await
     }
 
-    /// Attempts to find a [`Channel`] by its Id in the cache.
+    /// Changes the topic of the [`StageInstance`] running in the stage channel.
     ///
-    /// [`Channel`]: ../channel/enum.Channel.html
-    #[cfg(feature = "cache")]
-    #[inline]
-    pub async fn to_channel_cached(self, cache: impl AsRef<CacheRwLock>) -> Option<Channel> {
-        self._to_channel_cached(&cache.as_ref()).await
+    /// **Note**: Requires the current user to be a moderator of the stage,
+    /// through the [Manage Channels], [Mute Members] and [Move Members]
+    /// permissions.
+    ///
+    /// [`StageInstance`]: ../channel/struct.StageInstance.html
+    /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    /// [Move Members]: ../permissions/struct.Permissions.html#associatedconstant.MOVE_MEMBERS
+    /// [Mute Members]: ../permissions/struct.Permissions.html#associatedconstant.MUTE_MEMBERS
+    #[cfg(feature = "http")]
+    pub async fn edit_stage_instance(
+        self,
+        http: impl AsRef<Http>,
+        topic: &str,
+    ) -> Result<StageInstance> {
+        let map = json!({
+            "topic": topic,
+        });
+
+        http.as_ref().edit_stage_instance(self.0, &map).await
     }
 
-    /// To allow testing pass their own cache instead of using the globale one.
+    /// Attempts to find a [`Channel`] by its Id in the cache.
+    ///
+    /// [`Channel`]: ../channel/enum.Channel.html
     #[cfg(feature = "cache")]
     #[inline]
-    pub(crate) async fn _to_channel_cached(self, cache: &AsyncRwLock<Cache>) -> Option<Channel> {
-        cache.read().await.channel(self)
+    pub fn to_channel_cached(self, cache: impl AsRef<Cache>) -> Option<Channel> {
+        cache.as_ref().channel(self)
     }
 
     /// First attempts to find a [`Channel`] by its Id in the cache,
@@ -392,7 +600,7 @@ impl ChannelId {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                if let Some(channel) = cache.read().await.channel(self) {
+                if let Some(channel) = cache.channel(self) {
                     return Ok(channel);
                 }
             }
@@ -473,28 +681,121 @@ impl ChannelId {
         Ok(_msg)
     }
 
-    /// Returns the name of whatever channel this id holds.
-    #[cfg(all(feature = "model", feature = "cache"))]
-    pub async fn name(self, cache: impl AsRef<CacheRwLock>) -> Option<String> {
-        let channel = self.to_channel_cached(&cache).await?;
-
-        Some(match channel {
-            Channel::Guild(channel) => channel.read().await.name().to_string(),
-            Channel::Group(channel) => {
-                let guard = channel.read().await;
-                let res = guard.name();
-
-                match res {
-                    Cow::Borrowed(name) => name.to_string(),
-                    Cow::Owned(name) => name,
+    /// Streams all of the channel's messages, from newest to oldest.
+    ///
+    /// Messages are requested in pages of 100 as the stream is polled, each
+    /// page starting before the oldest message of the previous one.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// # Examples
+    ///
+    /// Counting the messages sent in a channel by bots:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::ChannelId;
+    /// use futures::{pin_mut, StreamExt};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// # let channel_id = ChannelId(7);
+    /// let messages = channel_id.messages_iter(&http);
+    /// pin_mut!(messages);
+    ///
+    /// let mut count = 0;
+    ///
+    /// while let Some(message) = messages.next().await {
+    ///     if message?.author.bot {
+    ///         count += 1;
+    ///     }
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    #[cfg(feature = "http")]
+    pub fn messages_iter<H: AsRef<Http>>(self, http: H) -> impl Stream<Item = Result<Message>> {
+        let grab_size: usize = 100;
+
+        try_stream! {
+            let mut before: Option<MessageId> = None;
+
+            loop {
+                let query = match before {
+                    Some(before) => format!("?limit={}&before={}", grab_size, before),
+                    None => format!("?limit={}", grab_size),
+                };
+
+                let page = http.as_ref().get_messages(self.0, &query).await?;
+                let done = page.len() < grab_size;
+                before = page.last().map(|message| message.id);
+
+                for mut message in page {
+                    message.transform_content().await;
+
+                    yield message;
+                }
+
+                if done {
+                    break;
                 }
             }
-            Channel::Category(category) => category.read().await.name().to_string(),
-            Channel::Private(channel) => {
-                let guard = channel.read().await;
-                guard.name()
-            }
-        })
+        }
+    }
+
+    /// Returns the name of whatever channel this id holds.
+    #[cfg(all(feature = "model", feature = "cache"))]
+    pub fn name(self, cache: impl AsRef<Cache>) -> Option<String> {
+        self.to_channel_cached(cache).map(channel_name)
+    }
+
+    /// Returns the name of whatever channel this id holds, requesting the
+    /// channel over the REST API if it is not in the cache.
+    ///
+    /// This is useful during partial outages, where guilds may be missing
+    /// from the cache.
+    #[cfg(feature = "model")]
+    pub async fn name_or_fetch(self, cache_http: impl CacheHttp) -> Result<String> {
+        self.to_channel(cache_http).await.map(channel_name)
+    }
+
+    /// Follows the news channel, sending messages crossposted in it to the
+    /// `target` channel through a newly created webhook.
+    ///
+    /// Refer to [`Http::follow_news_channel`] for more information.
+    ///
+    /// [`Http::follow_news_channel`]: ../../http/client/struct.Http.html#method.follow_news_channel
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn follow(
+        self,
+        http: impl AsRef<Http>,
+        target: impl Into<ChannelId>,
+    ) -> Result<FollowedChannel> {
+        http.as_ref()
+            .follow_news_channel(self.0, target.into().0)
+            .await
+    }
+
+    /// Crossposts a [`Message`] in a news channel, publishing it to the
+    /// channels following it.
+    ///
+    /// Refer to [`Http::crosspost_message`] for more information.
+    ///
+    /// [`Http::crosspost_message`]: ../../http/client/struct.Http.html#method.crosspost_message
+    /// [`Message`]: ../channel/struct.Message.html
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn crosspost<M: Into<MessageId>>(
+        self,
+        http: impl AsRef<Http>,
+        message_id: M,
+    ) -> Result<Message> {
+        http.as_ref()
+            .crosspost_message(self.0, message_id.into().0)
+            .await
     }
 
     /// Pins a [`Message`] to the channel.
@@ -601,7 +902,8 @@ impl ChannelId {
     /// be specified.
     ///
     /// Message contents may be passed by using the [`CreateMessage::content`]
-    /// method.
+    /// method. Files attached to the builder via [`CreateMessage::add_file`]
+    /// are sent after the given `files`.
     ///
     /// The [Attach Files] and [Send Messages] permissions are required.
     ///
@@ -661,6 +963,7 @@ impl ChannelId {
     ///
     /// [`ClientError::MessageTooLong`]: ../../client/enum.ClientError.html#variant.MessageTooLong
     /// [`HttpError::InvalidRequest`]: ../../http/enum.HttpError.html#variant.InvalidRequest
+    /// [`CreateMessage::add_file`]: ../../builder/struct.CreateMessage.html#method.add_file
     /// [`CreateMessage::content`]: ../../builder/struct.CreateMessage.html#method.content
     /// [`GuildChannel`]: struct.GuildChannel.html
     /// [Attach Files]: ../permissions/struct.Permissions.html#associatedconstant.ATTACH_FILES
@@ -677,23 +980,15 @@ impl ChannelId {
         T: Into<AttachmentType<'a>>,
         It: IntoIterator<Item = T>,
     {
-        let mut create_message = CreateMessage::default();
-        let msg = f(&mut create_message);
-
-        if let Some(content) = msg.0.get(&"content") {
-            if let Value::String(ref content) = *content {
-                if let Some(length_over) = Message::overflow_length(content) {
-                    return Err(Error::Model(ModelError::MessageTooLong(length_over)));
-                }
-            }
-        }
+        let files = files.into_iter().map(Into::into).collect::<Vec<_>>();
 
-        if let Some(e) = msg.0.remove(&"embed") {
-            msg.0.insert("payload_json", json!({ "embed": e }));
-        }
+        self.send_message(http, |m| {
+            let m = f(m);
+            m.2.splice(0..0, files);
 
-        let map = utils::hashmap_to_json_map(msg.0.clone());
-        http.as_ref().send_files(self.0, files, map).await
+            m
+        })
+        .await
     }
 
     /// Sends a message to the channel.
@@ -701,7 +996,8 @@ impl ChannelId {
     /// Refer to the documentation for [`CreateMessage`] for more information
     /// regarding message restrictions and requirements.
     ///
-    /// Requires the [Send Messages] permission.
+    /// Requires the [Send Messages] permission, and the [Attach Files]
+    /// permission if files were attached via [`CreateMessage::add_file`].
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
     ///
@@ -711,9 +1007,15 @@ impl ChannelId {
     /// is over the above limit, containing the number of unicode code points
     /// over the limit.
     ///
+    /// Returns a [`ModelError::MissingAttachment`] if the embed references an
+    /// `attachment://filename` which is not among the attached files.
+    ///
     /// [`Channel`]: ../channel/enum.Channel.html
     /// [`ModelError::MessageTooLong`]: ../error/enum.Error.html#variant.MessageTooLong
+    /// [`ModelError::MissingAttachment`]: ../error/enum.Error.html#variant.MissingAttachment
     /// [`CreateMessage`]: ../../builder/struct.CreateMessage.html
+    /// [`CreateMessage::add_file`]: ../../builder/struct.CreateMessage.html#method.add_file
+    /// [Attach Files]: ../permissions/struct.Permissions.html#associatedconstant.ATTACH_FILES
     /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
     #[cfg(all(feature = "utils", feature = "http"))]
     pub async fn send_message<'a, F>(self, http: impl AsRef<Http>, f: F) -> Result<Message>
@@ -723,15 +1025,23 @@ impl ChannelId {
         let mut create_message = CreateMessage::default();
         let msg = f(&mut create_message);
 
-        if !msg.2.is_empty() {
-            if let Some(e) = msg.0.remove(&"embed") {
-                if let Some(c) = msg.0.remove(&"content") {
-                    msg.0
-                        .insert("payload_json", json!({ "content": c, "embed": e }));
-                } else {
-                    msg.0.insert("payload_json", json!({ "embed": e }));
-                }
-            }
+        if let Some(filename) = Message::missing_attachment(msg.0.get("embed"), &msg.2) {
+            return Err(Error::Model(ModelError::MissingAttachment(filename)));
+        }
+
+        // Objects and arrays can only be sent alongside files as part of the
+        // JSON payload.
+        if !msg.2.is_empty()
+            && ["embed", "allowed_mentions", "sticker_ids"]
+                .iter()
+                .any(|key| msg.0.contains_key(key))
+        {
+            let payload = ["content", "embed", "allowed_mentions", "sticker_ids"]
+                .iter()
+                .filter_map(|key| msg.0.remove(key).map(|value| (key.to_string(), value)))
+                .collect::<JsonMap>();
+
+            msg.0.insert("payload_json", Value::Object(payload));
         }
 
         let map = utils::hashmap_to_json_map(msg.0.clone());
@@ -755,6 +1065,153 @@ impl ChannelId {
         Ok(message)
     }
 
+    /// Gets the [`StageInstance`] running in the stage channel, if any.
+    ///
+    /// [`StageInstance`]: ../channel/struct.StageInstance.html
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn stage_instance(self, http: impl AsRef<Http>) -> Result<StageInstance> {
+        http.as_ref().get_stage_instance(self.0).await
+    }
+
+    /// Starts a public [`GuildThread`] from a message in the channel.
+    ///
+    /// Refer to [`CreateThread`] for the fields which may be set. A name is
+    /// required.
+    ///
+    /// **Note**: Requires the Create Public Threads permission.
+    ///
+    /// [`CreateThread`]: ../../builder/struct.CreateThread.html
+    /// [`GuildThread`]: ../channel/struct.GuildThread.html
+    #[cfg(feature = "http")]
+    pub async fn start_thread_with_message<M, F>(
+        self,
+        http: impl AsRef<Http>,
+        message_id: M,
+        f: F,
+    ) -> Result<GuildThread>
+    where
+        M: Into<MessageId>,
+        F: FnOnce(&mut CreateThread) -> &mut CreateThread,
+    {
+        let mut builder = CreateThread::default();
+        f(&mut builder);
+
+        let map = utils::hashmap_to_json_map(builder.0);
+
+        http.as_ref()
+            .start_thread_with_message(self.0, message_id.into().0, &map)
+            .await
+    }
+
+    /// Starts a private [`GuildThread`] in the channel, which only members
+    /// who are added to it can see.
+    ///
+    /// Refer to [`CreateThread`] for the fields which may be set. A name is
+    /// required.
+    ///
+    /// **Note**: Requires the Create Private Threads permission.
+    ///
+    /// [`CreateThread`]: ../../builder/struct.CreateThread.html
+    /// [`GuildThread`]: ../channel/struct.GuildThread.html
+    #[cfg(feature = "http")]
+    pub async fn start_private_thread<F>(self, http: impl AsRef<Http>, f: F) -> Result<GuildThread>
+    where
+        F: FnOnce(&mut CreateThread) -> &mut CreateThread,
+    {
+        let mut builder = CreateThread::default();
+        f(&mut builder);
+
+        let mut map = utils::hashmap_to_json_map(builder.0);
+        map.insert(
+            "type".to_string(),
+            Value::from(ChannelType::PrivateThread.num()),
+        );
+
+        http.as_ref().start_private_thread(self.0, &map).await
+    }
+
+    /// Adds the current user to the thread.
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn join_thread(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().join_thread_channel(self.0).await
+    }
+
+    /// Removes the current user from the thread.
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn leave_thread(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().leave_thread_channel(self.0).await
+    }
+
+    /// Adds a user to the thread.
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn add_thread_member(
+        self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+    ) -> Result<()> {
+        http.as_ref()
+            .add_thread_channel_member(self.0, user_id.into().0)
+            .await
+    }
+
+    /// Removes a user from the thread.
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn remove_thread_member(
+        self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+    ) -> Result<()> {
+        http.as_ref()
+            .remove_thread_channel_member(self.0, user_id.into().0)
+            .await
+    }
+
+    /// Gets the archived public threads of the channel, most recently
+    /// archived first, optionally only those archived before a given time.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    #[cfg(feature = "http")]
+    pub async fn archived_public_threads(
+        self,
+        http: impl AsRef<Http>,
+        before: Option<Timestamp>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        let before = before.map(|before| before.to_rfc3339());
+
+        http.as_ref()
+            .get_channel_archived_public_threads(self.0, before.as_deref(), limit)
+            .await
+    }
+
+    /// Gets the archived private threads of the channel, most recently
+    /// archived first, optionally only those archived before a given time.
+    ///
+    /// **Note**: Requires the [Read Message History] permission, as well as
+    /// the Manage Threads permission.
+    ///
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    #[cfg(feature = "http")]
+    pub async fn archived_private_threads(
+        self,
+        http: impl AsRef<Http>,
+        before: Option<Timestamp>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        let before = before.map(|before| before.to_rfc3339());
+
+        http.as_ref()
+            .get_channel_archived_private_threads(self.0, before.as_deref(), limit)
+            .await
+    }
+
     /// Unpins a [`Message`] in the channel given by its Id.
     ///
     /// Requires the [Manage Messages] permission.
@@ -786,6 +1243,41 @@ impl ChannelId {
     pub async fn webhooks(self, http: impl AsRef<Http>) -> Result<Vec<Webhook>> {
         http.as_ref().get_channel_webhooks(self.0).await
     }
+
+    /// Retrieves the channel's webhooks of the given kind.
+    ///
+    /// This is useful to only touch the [`Incoming`] webhooks a bot created,
+    /// leaving the ones following news channels alone.
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission.
+    ///
+    /// [`Incoming`]: ../webhook/enum.WebhookType.html#variant.Incoming
+    /// [Manage Webhooks]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_WEBHOOKS
+    #[cfg(feature = "http")]
+    pub async fn webhooks_of_kind(
+        self,
+        http: impl AsRef<Http>,
+        kind: WebhookType,
+    ) -> Result<Vec<Webhook>> {
+        let mut webhooks = self.webhooks(http).await?;
+        webhooks.retain(|w| w.kind == kind);
+
+        Ok(webhooks)
+    }
+}
+
+impl From<Channel> for ChannelId {
+    /// Gets the Id of a `Channel`.
+    fn from(channel: Channel) -> ChannelId {
+        channel.id()
+    }
+}
+
+impl From<&Channel> for ChannelId {
+    /// Gets the Id of a `Channel`.
+    fn from(channel: &Channel) -> ChannelId {
+        channel.id()
+    }
 }
 
 impl From<PrivateChannel> for ChannelId {
@@ -815,3 +1307,70 @@ impl<'a> From<&'a GuildChannel> for ChannelId {
         public_channel.id
     }
 }
+
+#[cfg(all(test, feature = "utils", feature = "http"))]
+mod test {
+    use crate::http::mock::{MockResponse, MockServer};
+    use crate::http::AttachmentType;
+    use crate::model::id::ChannelId;
+
+    #[tokio::test]
+    async fn test_send_message_with_files() {
+        let json = include_str!("../../../tests/resources/message_create_1.json");
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(
+            200,
+            &serde_json::from_str(json).unwrap(),
+        ));
+        server.enqueue(MockResponse::json(
+            200,
+            &serde_json::from_str(json).unwrap(),
+        ));
+        let http = server.http("Bot token");
+
+        ChannelId(7)
+            .send_message(&http, |m| {
+                m.content("report")
+                    .embed(|e| e.title("Report"))
+                    .allowed_mentions(|am| am.replied_user(false))
+                    .add_file(AttachmentType::Bytes {
+                        data: b"data"[..].into(),
+                        filename: "report.txt".to_string(),
+                    })
+            })
+            .await
+            .unwrap();
+
+        let files = vec![AttachmentType::Bytes {
+            data: b"first"[..].into(),
+            filename: "first.txt".to_string(),
+        }];
+        ChannelId(7)
+            .send_files(&http, files, |m| {
+                m.add_file(AttachmentType::Bytes {
+                    data: b"second"[..].into(),
+                    filename: "second.txt".to_string(),
+                })
+            })
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests
+            .iter()
+            .all(|request| request.path == "/channels/7/messages"));
+
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert!(body.contains("filename=\"report.txt\""));
+        assert!(body.contains("\"content\":\"report\""));
+        assert!(body.contains("\"title\":\"Report\""));
+        assert!(body.contains("\"allowed_mentions\":{"));
+
+        let body = String::from_utf8_lossy(&requests[1].body);
+        let first = body.find("filename=\"first.txt\"").unwrap();
+        let second = body.find("filename=\"second.txt\"").unwrap();
+        assert!(first < second);
+    }
+}
@@ -70,6 +70,27 @@ pub struct Embed {
     pub video: Option<EmbedVideo>,
 }
 
+/// An empty embed of the `"rich"` kind, to fill in field by field.
+impl Default for Embed {
+    fn default() -> Embed {
+        Embed {
+            author: None,
+            colour: Default::default(),
+            description: None,
+            fields: Vec::new(),
+            footer: None,
+            image: None,
+            kind: "rich".to_string(),
+            provider: None,
+            thumbnail: None,
+            timestamp: None,
+            title: None,
+            url: None,
+            video: None,
+        }
+    }
+}
+
 #[cfg(feature = "model")]
 impl Embed {
     /// Creates a fake Embed, giving back a `serde_json` map.
@@ -105,7 +126,7 @@ impl Embed {
 }
 
 /// An author object in an embed.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct EmbedAuthor {
     /// The URL of the author icon.
@@ -121,7 +142,7 @@ pub struct EmbedAuthor {
 }
 
 /// A field object in an embed.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct EmbedField {
     /// Indicator of whether the field should display as inline.
@@ -162,7 +183,7 @@ impl EmbedField {
 }
 
 /// Footer information for an embed.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct EmbedFooter {
     /// The URL of the footer icon.
@@ -176,7 +197,7 @@ pub struct EmbedFooter {
 }
 
 /// An image object in an embed.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct EmbedImage {
     /// The height of the image.
@@ -192,7 +213,7 @@ pub struct EmbedImage {
 }
 
 /// The provider of an embed.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct EmbedProvider {
     /// The name of the provider.
@@ -202,7 +223,7 @@ pub struct EmbedProvider {
 }
 
 /// The dimensions and URL of an embed thumbnail.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct EmbedThumbnail {
     /// The height of the thumbnail in pixels.
@@ -218,7 +239,7 @@ pub struct EmbedThumbnail {
 }
 
 /// Video information for an embed.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct EmbedVideo {
     /// The height of the video in pixels.
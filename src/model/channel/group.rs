@@ -1,6 +1,4 @@
-use crate::internal::SyncRwLock;
 use crate::model::prelude::*;
-use chrono::{DateTime, FixedOffset};
 
 #[cfg(feature = "model")]
 use crate::builder::{CreateMessage, EditMessage, GetMessages};
@@ -27,7 +25,7 @@ pub struct Group {
     /// The Id of the last message sent.
     pub last_message_id: Option<MessageId>,
     /// Timestamp of the latest pinned message.
-    pub last_pin_timestamp: Option<DateTime<FixedOffset>>,
+    pub last_pin_timestamp: Option<Timestamp>,
     /// The name of the group channel.
     pub name: Option<String>,
     /// The Id of the group owner.
@@ -37,7 +35,7 @@ pub struct Group {
         deserialize_with = "deserialize_users",
         serialize_with = "serialize_users"
     )]
-    pub recipients: HashMap<UserId, Arc<SyncRwLock<User>>>,
+    pub recipients: HashMap<UserId, User>,
 }
 
 #[cfg(feature = "model")]
@@ -280,12 +278,12 @@ impl Group {
             Some(ref name) => Cow::Borrowed(name.as_str()),
             None => {
                 let mut name = match self.recipients.values().next() {
-                    Some(recipient) => recipient.read().name.clone(),
+                    Some(recipient) => recipient.name.clone(),
                     None => return Cow::Borrowed("Empty Group"),
                 };
 
                 for recipient in self.recipients.values().skip(1) {
-                    let _ = write!(name, ", {}", recipient.read().name.clone());
+                    let _ = write!(name, ", {}", recipient.name.clone());
                 }
 
                 Cow::Owned(name) as Cow<'_, str>
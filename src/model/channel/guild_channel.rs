@@ -1,26 +1,21 @@
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
 use crate::model::prelude::*;
-use chrono::{DateTime, FixedOffset};
 
 #[cfg(all(feature = "model", feature = "builder"))]
 use crate::builder::EditChannel;
 #[cfg(feature = "model")]
 use crate::builder::{CreateInvite, CreateMessage, EditMessage, GetMessages};
 #[cfg(all(feature = "cache", feature = "model"))]
-use crate::cache::CacheRwLock;
+use crate::cache::Cache;
 #[cfg(feature = "model")]
 use crate::http::AttachmentType;
 #[cfg(feature = "http")]
 use crate::http::Http;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::internal::prelude::*;
-#[cfg(feature = "cache")]
-use crate::internal::AsyncRwLock;
 #[cfg(all(feature = "model", feature = "utils"))]
 use crate::utils as serenity_utils;
-#[cfg(feature = "cache")]
-use std::sync::Arc;
 
 /// Represents a guild's text, news, or voice channel. Some methods are available
 /// only for voice channels and some are only available for text channels.
@@ -57,7 +52,7 @@ pub struct GuildChannel {
     /// The timestamp of the time a pin was most recently made.
     ///
     /// **Note**: This is only available for text channels.
-    pub last_pin_timestamp: Option<DateTime<FixedOffset>>,
+    pub last_pin_timestamp: Option<Timestamp>,
     /// The name of the channel.
     pub name: String,
     /// Permission overwrites for [`Member`]s and for [`Role`]s.
@@ -133,7 +128,7 @@ impl GuildChannel {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::CREATE_INVITE;
 
-                if !utils::user_has_perms(&cache, self.id, Some(self.guild_id), req).await? {
+                if !utils::user_has_perms(cache, self.id, Some(self.guild_id), req)? {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -168,12 +163,11 @@ impl GuildChannel {
     /// # #[cfg(feature = "cache")]
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn Error>> {
-    /// # use serenity::{cache::{Cache, CacheRwLock}, http::Http, model::id::{ChannelId, UserId}};
-    /// # use async_std::sync::RwLock;
+    /// # use serenity::{cache::Cache, http::Http, model::id::{ChannelId, UserId}};
     /// # use std::sync::Arc;
     /// #
     /// #     let http = Arc::new(Http::default());
-    /// #     let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
+    /// #     let cache = Arc::new(Cache::default());
     /// #     let (channel_id, user_id) = (ChannelId(0), UserId(0));
     /// #
     /// use serenity::model::channel::{
@@ -188,14 +182,11 @@ impl GuildChannel {
     ///     deny: deny,
     ///     kind: PermissionOverwriteType::Member(user_id),
     /// };
-    /// # let cache = cache.read().await;
-    /// // assuming the cache has been unlocked
     /// let channel = cache
     ///     .guild_channel(channel_id)
     ///     .ok_or(ModelError::ItemMissing)?;
     ///
-    /// let read = channel.read().await;
-    /// read.create_permission(&http, &overwrite).await?;
+    /// channel.create_permission(&http, &overwrite).await?;
     /// # Ok(())
     /// # }
     /// #
@@ -214,12 +205,11 @@ impl GuildChannel {
     /// # #[cfg(feature = "cache")]
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn Error>> {
-    /// # use serenity::{cache::{Cache, CacheRwLock}, http::Http, model::id::{ChannelId, UserId}};
-    /// # use async_std::sync::RwLock;
+    /// # use serenity::{cache::Cache, http::Http, model::id::{ChannelId, UserId}};
     /// # use std::sync::Arc;
     /// #
     /// #   let http = Arc::new(Http::default());
-    /// #   let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
+    /// #   let cache = Arc::new(Cache::default());
     /// #   let (channel_id, user_id) = (ChannelId(0), UserId(0));
     /// #
     /// use serenity::model::channel::{
@@ -236,13 +226,11 @@ impl GuildChannel {
     ///     kind: PermissionOverwriteType::Member(user_id),
     /// };
     ///
-    /// let cache = cache.read().await;
     /// let channel = cache
     ///     .guild_channel(channel_id)
     ///     .ok_or(ModelError::ItemMissing)?;
     ///
-    /// let read = channel.read().await;
-    /// read.create_permission(&http, &overwrite).await?;
+    /// channel.create_permission(&http, &overwrite).await?;
     /// #     Ok(())
     /// # }
     /// #
@@ -283,7 +271,7 @@ impl GuildChannel {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::MANAGE_CHANNELS;
 
-                if !utils::user_has_perms(&cache, self.id, Some(self.guild_id), req).await? {
+                if !utils::user_has_perms(cache, self.id, Some(self.guild_id), req)? {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -382,7 +370,7 @@ impl GuildChannel {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::MANAGE_CHANNELS;
 
-                if !utils::user_has_perms(&cache, self.id, Some(self.guild_id), req).await? {
+                if !utils::user_has_perms(cache, self.id, Some(self.guild_id), req)? {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -395,8 +383,13 @@ impl GuildChannel {
         let mut edit_channel = EditChannel::default();
         f(&mut edit_channel);
         let edited = serenity_utils::hashmap_to_json_map(edit_channel.0);
+        let reason = edit_channel.1.as_deref().unwrap_or("");
 
-        match cache_http.http().edit_channel(self.id.0, &edited).await {
+        match cache_http
+            .http()
+            .edit_channel_with_reason(self.id.0, &edited, reason)
+            .await
+        {
             Ok(channel) => {
                 *self = channel;
 
@@ -445,9 +438,33 @@ impl GuildChannel {
     /// **Note**: Right now this performs a clone of the guild. This will be
     /// optimized in the future.
     #[cfg(feature = "cache")]
-    pub async fn guild(&self, cache: impl AsRef<CacheRwLock>) -> Option<Arc<AsyncRwLock<Guild>>> {
-        let guard = cache.as_ref().read().await;
-        guard.guild(self.guild_id)
+    pub fn guild(&self, cache: impl AsRef<Cache>) -> Option<Guild> {
+        cache.as_ref().guild(self.guild_id)
+    }
+
+    /// Attempts to find the category this channel is in in the Cache.
+    ///
+    /// Returns `None` if the channel is not in a category, or if the category
+    /// is not cached.
+    #[cfg(feature = "cache")]
+    pub fn category(&self, cache: impl AsRef<Cache>) -> Option<ChannelCategory> {
+        let cache = cache.as_ref();
+        let category_id = self.category_id?;
+
+        // Categories received as part of a guild are stored among its
+        // channels rather than in the categories map.
+        cache.categories(category_id).or_else(|| {
+            cache.guild_channel_field(category_id, |channel| ChannelCategory {
+                id: channel.id,
+                guild_id: channel.guild_id,
+                category_id: channel.category_id,
+                position: channel.position,
+                kind: channel.kind,
+                name: channel.name.clone(),
+                nsfw: channel.nsfw,
+                permission_overwrites: channel.permission_overwrites.clone(),
+            })
+        })
     }
 
     /// Gets all of the channel's invites.
@@ -487,6 +504,40 @@ impl GuildChannel {
         self.id.message(&http, message_id).await
     }
 
+    /// Gets the last message sent in the channel, if any.
+    ///
+    /// If the `cache` is enabled, the message is first looked up in the
+    /// message cache, upon failure it is requested via the REST API.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// **Note**: This relies on [`last_message_id`], which the cache keeps
+    /// up to date as messages are received.
+    ///
+    /// [`last_message_id`]: #structfield.last_message_id
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    #[cfg(feature = "http")]
+    pub async fn last_message(&self, cache_http: impl CacheHttp) -> Result<Option<Message>> {
+        let message_id = match self.last_message_id {
+            Some(message_id) => message_id,
+            None => return Ok(None),
+        };
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(message) = cache.message(self.id, message_id) {
+                    return Ok(Some(message));
+                }
+            }
+        }
+
+        self.id
+            .message(cache_http.http(), message_id)
+            .await
+            .map(Some)
+    }
+
     /// Gets messages from the channel.
     ///
     /// Refer to the [`GetMessages`]-builder for more information on how to
@@ -509,6 +560,14 @@ impl GuildChannel {
         &self.name
     }
 
+    /// Returns the channel's permission overwrite for a member or role, if it
+    /// has one.
+    pub fn overwrite_for(&self, kind: PermissionOverwriteType) -> Option<&PermissionOverwrite> {
+        self.permission_overwrites
+            .iter()
+            .find(|overwrite| overwrite.kind == kind)
+    }
+
     /// Calculates the permissions of a member.
     ///
     /// The Id of the argument must be a [`Member`] of the [`Guild`] that the
@@ -529,13 +588,12 @@ impl GuildChannel {
     /// #[async_trait]
     /// impl EventHandler for Handler {
     ///     async fn message(&self, context: Context, msg: Message) {
-    ///         let channel = match context.cache.read().await.guild_channel(msg.channel_id) {
+    ///         let channel = match context.cache.guild_channel(msg.channel_id) {
     ///             Some(channel) => channel,
     ///             None => return,
     ///         };
     ///
-    ///         let guard = channel.read().await;
-    ///         let permissions = guard.permissions_for(&context.cache, &msg.author).await.unwrap();
+    ///         let permissions = channel.permissions_for_user(&context.cache, &msg.author).unwrap();
     ///
     ///         println!("The user's permissions: {:?}", permissions);
     ///     }
@@ -564,16 +622,14 @@ impl GuildChannel {
     /// #[async_trait]
     /// impl EventHandler for Handler {
     ///     async fn message(&self, context: Context, mut msg: Message) {
-    ///         let channel = match context.cache.read().await.guild_channel(msg.channel_id) {
+    ///         let channel = match context.cache.guild_channel(msg.channel_id) {
     ///             Some(channel) => channel,
     ///             None => return,
     ///         };
     ///
-    ///         let guard = context.cache.read().await;
-    ///         let current_user_id = guard.user.id;
-    ///         let guard = channel.read().await;
+    ///         let current_user_id = context.cache.current_user_id();
     ///         let permissions =
-    ///             guard.permissions_for(&context.cache, current_user_id).await.unwrap();
+    ///             channel.permissions_for_user(&context.cache, current_user_id).unwrap();
     ///
     ///             if !permissions.contains(Permissions::ATTACH_FILES | Permissions::SEND_MESSAGES) {
     ///                 return;
@@ -620,12 +676,12 @@ impl GuildChannel {
     #[cfg(feature = "cache")]
     #[inline]
     #[deprecated(since = "0.6.4", note = "Please use `permissions_for_user` instead.")]
-    pub async fn permissions_for<U: Into<UserId>>(
+    pub fn permissions_for<U: Into<UserId>>(
         &self,
-        cache: impl AsRef<CacheRwLock>,
+        cache: impl AsRef<Cache>,
         user_id: U,
     ) -> Result<Permissions> {
-        self.permissions_for_user(&cache, user_id.into()).await
+        self.permissions_for_user(cache, user_id)
     }
 
     /// Calculates the permissions of a role.
@@ -648,28 +704,21 @@ impl GuildChannel {
     /// [`Role`]: ../guild/struct.Role.html
     #[cfg(feature = "cache")]
     #[inline]
-    pub async fn permissions_for_user<U: Into<UserId>>(
+    pub fn permissions_for_user<U: Into<UserId>>(
         &self,
-        cache: impl AsRef<CacheRwLock>,
+        cache: impl AsRef<Cache>,
         user_id: U,
     ) -> Result<Permissions> {
-        self._permissions_for_user(&cache, user_id.into()).await
+        self._permissions_for_user(cache.as_ref(), user_id.into())
     }
 
     #[cfg(feature = "cache")]
-    async fn _permissions_for_user(
-        &self,
-        cache: impl AsRef<CacheRwLock>,
-        user_id: UserId,
-    ) -> Result<Permissions> {
-        match self.guild(&cache).await {
-            Some(guild) => {
-                let guard = guild.read().await;
-
-                Ok(guard.user_permissions_in(self.id, user_id).await)
-            }
-            None => Err(Error::Model(ModelError::GuildNotFound)),
-        }
+    fn _permissions_for_user(&self, cache: &Cache, user_id: UserId) -> Result<Permissions> {
+        cache
+            .guilds
+            .get(&self.guild_id)
+            .map(|guild| guild.user_permissions_in(self.id, user_id))
+            .ok_or(Error::Model(ModelError::GuildNotFound))
     }
 
     /// Calculates the permissions of a member.
@@ -692,13 +741,12 @@ impl GuildChannel {
     /// #[async_trait]
     /// impl EventHandler for Handler {
     ///     async fn message(&self, context: Context, msg: Message) {
-    ///         let channel = match context.cache.read().await.guild_channel(msg.channel_id) {
+    ///         let channel = match context.cache.guild_channel(msg.channel_id) {
     ///             Some(channel) => channel,
     ///             None => return,
     ///         };
     ///
-    ///         let guard = channel.read().await;
-    ///         let permissions = guard.permissions_for(&context.cache, &msg.author).await.unwrap();
+    ///         let permissions = channel.permissions_for_user(&context.cache, &msg.author).unwrap();
     ///
     ///         println!("The user's permissions: {:?}", permissions);
     ///     }
@@ -728,15 +776,14 @@ impl GuildChannel {
     /// #[async_trait]
     /// impl EventHandler for Handler {
     ///     async fn message(&self, context: Context, mut msg: Message) {
-    ///         let channel = match context.cache.read().await.guild_channel(msg.channel_id) {
+    ///         let channel = match context.cache.guild_channel(msg.channel_id) {
     ///             Some(channel) => channel,
     ///             None => return,
     ///         };
     ///
-    ///         let current_user_id = context.cache.read().await.user.id;
-    ///         let guard = channel.read().await;
+    ///         let current_user_id = context.cache.current_user_id();
     ///         let permissions =
-    ///             guard.permissions_for(&context.cache, current_user_id).await.unwrap();
+    ///             channel.permissions_for_user(&context.cache, current_user_id).unwrap();
     ///
     ///             if !permissions.contains(Permissions::ATTACH_FILES | Permissions::SEND_MESSAGES) {
     ///                 return;
@@ -782,33 +829,24 @@ impl GuildChannel {
     /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
     #[cfg(feature = "cache")]
     #[inline]
-    pub async fn permissions_for_role<R: Into<RoleId>>(
+    pub fn permissions_for_role<R: Into<RoleId>>(
         &self,
-        cache: impl AsRef<CacheRwLock>,
+        cache: impl AsRef<Cache>,
         role_id: R,
     ) -> Result<Permissions> {
-        self._permissions_for_role(&cache, role_id.into()).await
+        self._permissions_for_role(cache.as_ref(), role_id.into())
     }
 
     #[cfg(feature = "cache")]
-    async fn _permissions_for_role(
-        &self,
-        cache: impl AsRef<CacheRwLock>,
-        role_id: RoleId,
-    ) -> Result<Permissions> {
-        // The weird definitions here are to deal with rustc's processing of async borrows
-        let guard = self.guild(&cache).await;
-
-        let res = guard.ok_or(Error::Model(ModelError::GuildNotFound))?;
+    fn _permissions_for_role(&self, cache: &Cache, role_id: RoleId) -> Result<Permissions> {
+        let guild = cache
+            .guilds
+            .get(&self.guild_id)
+            .ok_or(Error::Model(ModelError::GuildNotFound))?;
 
-        let res2 = res.read().await;
-
-        let res3 = res2
+        guild
             .role_permissions_in(self.id, role_id)
-            .await
-            .ok_or(Error::Model(ModelError::GuildNotFound));
-
-        res3
+            .ok_or(Error::Model(ModelError::RoleNotFound))
     }
 
     /// Pins a [`Message`] to the channel.
@@ -945,7 +983,7 @@ impl GuildChannel {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::SEND_MESSAGES;
 
-                if !utils::user_has_perms(&cache, self.id, Some(self.guild_id), req).await? {
+                if !utils::user_has_perms(cache, self.id, Some(self.guild_id), req)? {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -955,6 +993,53 @@ impl GuildChannel {
         self.id.send_message(&h, f).await
     }
 
+    /// Replaces the channel's permission overwrites with the given ones.
+    ///
+    /// Only the overwrites which differ are created, updated or deleted, as
+    /// computed by [`diff_overwrites`]. The channel's
+    /// [`permission_overwrites`] are updated as each change is made.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`Error::Http`] encountered. Changes made before it
+    /// are not reverted.
+    ///
+    /// [`Error::Http`]: ../../enum.Error.html#variant.Http
+    /// [`diff_overwrites`]: fn.diff_overwrites.html
+    /// [`permission_overwrites`]: #structfield.permission_overwrites
+    /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    #[cfg(feature = "http")]
+    pub async fn set_overwrites(
+        &mut self,
+        http: impl AsRef<Http>,
+        overwrites: Vec<PermissionOverwrite>,
+    ) -> Result<()> {
+        let diff = diff_overwrites(&self.permission_overwrites, &overwrites);
+
+        for kind in diff.delete {
+            self.id.delete_permission(&http, kind).await?;
+            self.permission_overwrites
+                .retain(|overwrite| overwrite.kind != kind);
+        }
+
+        for overwrite in diff.upsert {
+            self.id.create_permission(&http, &overwrite).await?;
+
+            match self
+                .permission_overwrites
+                .iter_mut()
+                .find(|existing| existing.kind == overwrite.kind)
+            {
+                Some(existing) => *existing = overwrite,
+                None => self.permission_overwrites.push(overwrite),
+            }
+        }
+
+        Ok(())
+    }
+
     /// Unpins a [`Message`] in the channel given by its Id.
     ///
     /// Requires the [Manage Messages] permission.
@@ -984,9 +1069,9 @@ impl GuildChannel {
 
     /// Retrieves [`Member`]s from the current channel.
     ///
-    /// [`ChannelType::Voice`] returns [`Member`]s using the channel.
-    /// [`ChannelType::Text`] and [`ChannelType::News`] return [`Member`]s
-    /// that can read the channel.
+    /// [`ChannelType::Voice`] and [`ChannelType::Stage`] return [`Member`]s
+    /// using the channel. [`ChannelType::Text`] and [`ChannelType::News`]
+    /// return [`Member`]s that can read the channel.
     ///
     /// Other [`ChannelType`]s lack the concept of [`Member`]s and
     /// will return: [`ModelError::InvalidChannelType`].
@@ -994,68 +1079,103 @@ impl GuildChannel {
     /// [`Member`]: ../guild/struct.Member.html
     /// [`ChannelType`]: enum.ChannelType.html
     /// [`ChannelType::Voice`]: enum.ChannelType.html#variant.Voice
+    /// [`ChannelType::Stage`]: enum.ChannelType.html#variant.Stage
     /// [`ChannelType::Text`]: enum.ChannelType.html#variant.Text
     /// [`ChannelType::News`]: enum.ChannelType.html#variant.News
     /// [`ModelError::InvalidChannelType`]: ../error/enum.Error.html#variant.InvalidChannelType
     #[cfg(feature = "cache")]
     #[inline]
-    pub async fn members(&self, cache: impl AsRef<CacheRwLock>) -> Result<Vec<Member>> {
+    pub fn members(&self, cache: impl AsRef<Cache>) -> Result<Vec<Member>> {
         let cache = cache.as_ref();
-        let guild = cache.read().await.guild(self.guild_id).unwrap();
+        let guild = cache
+            .guilds
+            .get(&self.guild_id)
+            .ok_or(Error::Model(ModelError::GuildNotFound))?;
 
         match self.kind {
-            ChannelType::Voice => Ok({
-                let guard = guild.read().await;
-
-                let mut output = Vec::new();
-
-                for v in guard.voice_states.values() {
-                    if let Some(channel_id) = v.channel_id {
-                        if channel_id == self.id {
-                            if let Some(member) =
-                                guild.read().await.members.get(&v.user_id).cloned()
-                            {
-                                output.push(member);
-                            }
-                        }
-                    }
-                }
-
-                output
-            }),
-            ChannelType::News | ChannelType::Text => Ok({
-                let guard = guild.read().await;
-
-                let mut output = Vec::new();
-                for e in guard.members.iter() {
-                    if self
-                        .permissions_for_user(&cache, e.0)
-                        .await
-                        .map(|p| p.contains(Permissions::READ_MESSAGES))
-                        .unwrap_or(false)
-                    {
-                        output.push(e.1.clone());
-                    }
-                }
-
-                output
-            }),
+            ChannelType::Voice | ChannelType::Stage => Ok(guild
+                .voice_states
+                .values()
+                .filter(|v| v.channel_id == Some(self.id))
+                .filter_map(|v| guild.members.get(&v.user_id).cloned())
+                .collect()),
+            ChannelType::News | ChannelType::Text => Ok(guild
+                .members
+                .iter()
+                .filter(|(user_id, _)| {
+                    guild
+                        .user_permissions_in(self.id, **user_id)
+                        .contains(Permissions::READ_MESSAGES)
+                })
+                .map(|(_, member)| member.clone())
+                .collect()),
             _ => Err(Error::from(ModelError::InvalidChannelType)),
         }
     }
 }
 
-/*
-TODO: refactor this
-#[cfg(feature = "model")]
-use std::fmt::{
-    Display,
-    Formatter,
-    Result as FmtResult
-};
-
 #[cfg(feature = "model")]
 impl Display for GuildChannel {
     /// Formats the channel, creating a mention of it.
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult { Display::fmt(&futures::executor::block_on(self.id.mention()), f) }
-}*/
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.id.mention(), f)
+    }
+}
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::GuildChannel;
+    use crate::http::mock::{MockResponse, MockServer};
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_set_overwrites() {
+        let mut channel: GuildChannel = serde_json::from_value(json!({
+            "id": "1",
+            "guild_id": "2",
+            "type": 0,
+            "name": "general",
+            "position": 0,
+            "permission_overwrites": [
+                {"id": "3", "type": "role", "allow": 0, "deny": 2048},
+                {"id": "4", "type": "member", "allow": 2048, "deny": 0},
+            ],
+        }))
+        .unwrap();
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::new(204));
+        server.enqueue(MockResponse::new(204));
+        let http = server.http("Bot token");
+
+        let kept = channel.permission_overwrites[0].clone();
+        let added = PermissionOverwrite {
+            allow: Permissions::ATTACH_FILES,
+            deny: Permissions::empty(),
+            kind: PermissionOverwriteType::Role(RoleId(5)),
+        };
+        channel
+            .set_overwrites(&http, vec![kept.clone(), added.clone()])
+            .await
+            .unwrap();
+
+        let requests = server
+            .requests()
+            .into_iter()
+            .map(|request| (request.method, request.path))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            requests,
+            vec![
+                ("DELETE".to_owned(), "/channels/1/permissions/4".to_owned()),
+                ("PUT".to_owned(), "/channels/1/permissions/5".to_owned()),
+            ]
+        );
+        assert_eq!(channel.permission_overwrites, vec![kept, added.clone()]);
+        assert_eq!(
+            channel.overwrite_for(PermissionOverwriteType::Role(RoleId(5))),
+            Some(&added)
+        );
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(feature = "http")]
+use crate::http::Http;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A thread within a [`GuildChannel`], which is either a [public], [private]
+/// or [news] thread.
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/threads).
+///
+/// [`GuildChannel`]: struct.GuildChannel.html
+/// [news]: enum.ChannelType.html#variant.NewsThread
+/// [private]: enum.ChannelType.html#variant.PrivateThread
+/// [public]: enum.ChannelType.html#variant.PublicThread
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildThread {
+    /// The unique Id of the thread.
+    pub id: ChannelId,
+    /// The Id of the guild the thread is located in.
+    pub guild_id: GuildId,
+    /// The Id of the channel the thread was started in.
+    pub parent_id: ChannelId,
+    /// The Id of the user who started the thread.
+    pub owner_id: Option<UserId>,
+    /// The type of the thread.
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    /// The name of the thread.
+    pub name: String,
+    /// The Id of the last message sent in the thread.
+    pub last_message_id: Option<MessageId>,
+    /// An approximate number of messages in the thread, which stops counting
+    /// at 50.
+    #[serde(default)]
+    pub message_count: u64,
+    /// An approximate number of users in the thread, which stops counting
+    /// at 50.
+    #[serde(default)]
+    pub member_count: u64,
+    /// The number of seconds a user has to wait before sending another
+    /// message in the thread.
+    #[serde(default, rename = "rate_limit_per_user")]
+    pub slow_mode_rate: Option<u64>,
+    /// Thread-specific data, such as whether it is archived.
+    pub thread_metadata: ThreadMetadata,
+    /// The current user's membership of the thread, if they have joined it.
+    ///
+    /// This is only sent by some endpoints.
+    pub member: Option<ThreadMember>,
+}
+
+/// Thread-specific data of a [`GuildThread`].
+///
+/// [`GuildThread`]: struct.GuildThread.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ThreadMetadata {
+    /// Whether the thread is archived.
+    pub archived: bool,
+    /// The number of minutes of inactivity after which the thread is
+    /// automatically archived: one of `60`, `1440`, `4320` or `10080`.
+    pub auto_archive_duration: u64,
+    /// When the thread was last archived or unarchived.
+    pub archive_timestamp: Timestamp,
+    /// Whether the thread can only be unarchived by members with the Manage
+    /// Threads permission.
+    #[serde(default)]
+    pub locked: bool,
+    /// Whether members who aren't moderators can add other members to a
+    /// private thread.
+    pub invitable: Option<bool>,
+}
+
+/// A user's membership of a [`GuildThread`].
+///
+/// [`GuildThread`]: struct.GuildThread.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ThreadMember {
+    /// The Id of the thread.
+    ///
+    /// This is not sent when the membership is part of a [`GuildThread`].
+    ///
+    /// [`GuildThread`]: struct.GuildThread.html
+    pub id: Option<ChannelId>,
+    /// The Id of the user.
+    ///
+    /// This is not sent when the membership is part of a [`GuildThread`].
+    ///
+    /// [`GuildThread`]: struct.GuildThread.html
+    pub user_id: Option<UserId>,
+    /// When the user last joined the thread.
+    pub join_timestamp: Timestamp,
+    /// Flags used for notification settings.
+    pub flags: u64,
+}
+
+/// A list of threads, as returned when listing the active threads of a guild,
+/// or the archived threads of a channel.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ThreadsData {
+    /// The threads.
+    pub threads: Vec<GuildThread>,
+    /// The current user's memberships of the threads they have joined.
+    pub members: Vec<ThreadMember>,
+    /// Whether there are more threads which can be retrieved.
+    ///
+    /// This is always `false` for active threads.
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+#[cfg(feature = "model")]
+impl GuildThread {
+    /// Whether the thread is archived.
+    #[inline]
+    pub fn is_archived(&self) -> bool {
+        self.thread_metadata.archived
+    }
+
+    /// Adds the current user to the thread.
+    ///
+    /// **Note**: The thread must not be archived.
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn join(&self, http: impl AsRef<Http>) -> Result<()> {
+        self.id.join_thread(http).await
+    }
+
+    /// Removes the current user from the thread.
+    ///
+    /// **Note**: The thread must not be archived.
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn leave(&self, http: impl AsRef<Http>) -> Result<()> {
+        self.id.leave_thread(http).await
+    }
+
+    /// Adds a user to the thread.
+    ///
+    /// **Note**: The current user must be able to send messages in the
+    /// thread, and the thread must not be archived.
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn add_member(
+        &self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+    ) -> Result<()> {
+        self.id.add_thread_member(http, user_id).await
+    }
+
+    /// Removes a user from the thread.
+    ///
+    /// **Note**: Requires the Manage Threads permission, or the current user
+    /// to be the creator of a private thread. The thread must not be
+    /// archived.
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn remove_member(
+        &self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+    ) -> Result<()> {
+        self.id.remove_thread_member(http, user_id).await
+    }
+}
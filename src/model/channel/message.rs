@@ -3,7 +3,6 @@
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
 use crate::model::prelude::*;
-use chrono::{DateTime, FixedOffset};
 use serde_json::Value;
 
 #[cfg(feature = "model")]
@@ -11,11 +10,9 @@ use super::utils::U64Visitor;
 #[cfg(feature = "model")]
 use crate::builder::{CreateEmbed, EditMessage};
 #[cfg(all(feature = "cache", feature = "model"))]
-use crate::cache::CacheRwLock;
+use crate::cache::Cache;
 #[cfg(feature = "http")]
-use crate::http::Http;
-#[cfg(all(feature = "cache", feature = "model"))]
-use crate::internal::AsyncRwLock;
+use crate::http::{AttachmentType, Http};
 #[cfg(feature = "model")]
 use crate::{
     constants,
@@ -35,8 +32,6 @@ use serde_json::json;
 use std::fmt::Write;
 #[cfg(feature = "model")]
 use std::result::Result as StdResult;
-#[cfg(all(feature = "cache", feature = "model"))]
-use std::sync::Arc;
 
 /// A representation of a message over a guild's text channel, a group, or a
 /// private channel.
@@ -57,7 +52,7 @@ pub struct Message {
     /// The content of the message.
     pub content: String,
     /// The timestamp of the last time the message was updated, if it was.
-    pub edited_timestamp: Option<DateTime<FixedOffset>>,
+    pub edited_timestamp: Option<Timestamp>,
     /// Array of embeds sent with the message.
     pub embeds: Vec<Embed>,
     /// The Id of the [`Guild`] that the message was sent in. This value will
@@ -91,7 +86,7 @@ pub struct Message {
     #[serde(default)]
     pub reactions: Vec<MessageReaction>,
     /// Initial message creation timestamp, calculated from its Id.
-    pub timestamp: DateTime<FixedOffset>,
+    pub timestamp: Timestamp,
     /// Indicator of whether the command is to be played back via
     /// text-to-speech.
     ///
@@ -107,6 +102,9 @@ pub struct Message {
     pub message_reference: Option<MessageReference>,
     /// Bit flags describing extra features of the message.
     pub flags: Option<MessageFlags>,
+    /// The stickers sent with the message.
+    #[serde(default)]
+    pub sticker_items: Vec<StickerItem>,
 }
 
 #[cfg(feature = "model")]
@@ -116,18 +114,47 @@ impl Message {
     /// Returns `None` if the channel is not in the cache.
     #[cfg(feature = "cache")]
     #[inline]
-    pub async fn channel(&self, cache: impl AsRef<CacheRwLock>) -> Option<Channel> {
-        let guard = cache.as_ref().read().await;
-        guard.channel(self.channel_id)
+    pub fn channel(&self, cache: impl AsRef<Cache>) -> Option<Channel> {
+        cache.as_ref().channel(self.channel_id)
     }
 
     /// A util function for determining whether this message was sent by someone else, or the
     /// bot.
     #[cfg(all(feature = "cache", feature = "utils"))]
-    pub async fn is_own(&self, cache: impl AsRef<CacheRwLock>) -> bool {
-        let guard = cache.as_ref().read().await;
+    pub fn is_own(&self, cache: impl AsRef<Cache>) -> bool {
+        self.author.id == cache.as_ref().current_user_id()
+    }
 
-        self.author.id == guard.user.id
+    /// Crossposts the message in its news channel, publishing it to the
+    /// channels following it.
+    ///
+    /// **Note**: The logged in user must either be the author of the message or
+    /// have the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` feature is enabled, then returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
+    /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    #[cfg(feature = "http")]
+    pub async fn crosspost(&self, cache_http: impl CacheHttp) -> Result<Message> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                let req = Permissions::MANAGE_MESSAGES;
+                let is_author = self.author.id == cache.current_user_id();
+
+                if !is_author && !utils::user_has_perms(cache, self.channel_id, self.guild_id, req)?
+                {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.channel_id.crosspost(cache_http.http(), self.id).await
     }
 
     /// Deletes the message.
@@ -145,14 +172,24 @@ impl Message {
     /// [`ModelError::InvalidUser`]: ../error/enum.Error.html#variant.InvalidUser
     /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
     #[cfg(feature = "http")]
+    #[inline]
     pub async fn delete(&self, cache_http: impl CacheHttp) -> Result<()> {
+        self.delete_with_reason(cache_http, "").await
+    }
+
+    /// Deletes the message, recording a reason in the guild's audit log.
+    ///
+    /// Refer to [`delete`] for more information.
+    ///
+    /// [`delete`]: #method.delete
+    #[cfg(feature = "http")]
+    pub async fn delete_with_reason(&self, cache_http: impl CacheHttp, reason: &str) -> Result<()> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::MANAGE_MESSAGES;
-                let is_author = self.author.id == cache.read().await.user.id;
-                let has_perms =
-                    utils::user_has_perms(&cache, self.channel_id, self.guild_id, req).await?;
+                let is_author = self.author.id == cache.current_user_id();
+                let has_perms = utils::user_has_perms(cache, self.channel_id, self.guild_id, req)?;
 
                 if !is_author && !has_perms {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
@@ -161,7 +198,9 @@ impl Message {
         }
 
         let h = cache_http.http();
-        self.channel_id.delete_message(&h, self.id).await
+        self.channel_id
+            .delete_message_with_reason(&h, self.id, reason)
+            .await
     }
 
     /// Deletes all of the [`Reaction`]s associated with the message.
@@ -184,7 +223,7 @@ impl Message {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::MANAGE_MESSAGES;
 
-                if !utils::user_has_perms(cache, self.channel_id, self.guild_id, req).await? {
+                if !utils::user_has_perms(cache, self.channel_id, self.guild_id, req)? {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -237,7 +276,7 @@ impl Message {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                if self.author.id != cache.read().await.user.id {
+                if self.author.id != cache.current_user_id() {
                     return Err(Error::Model(ModelError::InvalidUser));
                 }
             }
@@ -276,20 +315,100 @@ impl Message {
         }
     }
 
+    /// Sends a copy of the message to another channel, such as for a
+    /// starboard.
+    ///
+    /// The content and the first rich embed are copied, and the attachments
+    /// are downloaded and uploaded again. Attachments which would exceed the
+    /// [upload limit] are linked in the content instead. Mentions in the copy
+    /// do not notify anyone.
+    ///
+    /// **Note**: Requires the [Send Messages] and [Attach Files] permissions
+    /// in the target channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if linking the attachments
+    /// makes the content exceed the limit of 2000 unicode code points.
+    ///
+    /// Returns an [`Error::Http`] if an attachment could not be downloaded.
+    ///
+    /// [`Error::Http`]: ../../enum.Error.html#variant.Http
+    /// [`ModelError::MessageTooLong`]: ../error/enum.Error.html#variant.MessageTooLong
+    /// [upload limit]: ../../constants/constant.UPLOAD_MAX_SIZE.html
+    /// [Attach Files]: ../permissions/struct.Permissions.html#associatedconstant.ATTACH_FILES
+    /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    #[cfg(feature = "http")]
+    pub async fn forward_to(
+        &self,
+        cache_http: impl CacheHttp,
+        channel_id: impl Into<ChannelId>,
+    ) -> Result<Message> {
+        let http = cache_http.http();
+        let mut content = self.content.clone();
+        let mut files = Vec::with_capacity(self.attachments.len());
+        let mut uploaded = 0;
+
+        for attachment in &self.attachments {
+            if uploaded + attachment.size > constants::UPLOAD_MAX_SIZE {
+                if !content.is_empty() {
+                    content.push('\n');
+                }
+
+                content.push_str(&attachment.url);
+
+                continue;
+            }
+
+            let data = attachment.download(http).await?;
+            uploaded += attachment.size;
+
+            files.push(AttachmentType::Bytes {
+                data: data.into(),
+                filename: attachment.filename.clone(),
+            });
+        }
+
+        let embed = self
+            .embeds
+            .iter()
+            .find(|embed| embed.kind == "rich")
+            .cloned()
+            .map(CreateEmbed::from);
+
+        channel_id
+            .into()
+            .send_message(http, |m| {
+                if !content.is_empty() {
+                    m.content(content);
+                }
+
+                if let Some(embed) = embed {
+                    m.embed(|e| {
+                        *e = embed;
+                        e
+                    });
+                }
+
+                m.allowed_mentions(|am| am.empty_parse()).add_files(files)
+            })
+            .await
+    }
+
     pub(crate) async fn transform_content(&mut self) {
         match self.kind {
             MessageType::PinsAdd => {
                 self.content = format!(
                     "{} pinned a message to this channel. See all the pins.",
-                    self.author.async_to_string().await
+                    self.author
                 );
             }
             MessageType::MemberJoin => {
-                let sec = self.timestamp.timestamp() as usize;
+                let sec = self.timestamp.unix_timestamp() as usize;
                 let chosen = constants::JOIN_MESSAGES[sec % constants::JOIN_MESSAGES.len()];
 
                 self.content = if chosen.contains("$user") {
-                    chosen.replace("$user", &self.author.mention().await)
+                    chosen.replace("$user", &self.author.mention())
                 } else {
                     chosen.to_string()
                 };
@@ -301,7 +420,7 @@ impl Message {
     /// Returns message content, but with user and role mentions replaced with
     /// names and everyone/here mentions cancelled.
     #[cfg(feature = "cache")]
-    pub async fn content_safe(&self, cache: impl AsRef<CacheRwLock>) -> String {
+    pub fn content_safe(&self, cache: impl AsRef<Cache>) -> String {
         let mut result = self.content.clone();
 
         // First replace all user mentions.
@@ -311,14 +430,14 @@ impl Message {
             at_distinct.push_str(&u.name);
             at_distinct.push('#');
             let _ = write!(at_distinct, "{:04}", u.discriminator);
-            result = result.replace(&u.mention().await, &at_distinct);
+            result = result.replace(&u.mention(), &at_distinct);
         }
 
         // Then replace all role mentions.
         for id in &self.mention_roles {
-            let mention = id.mention().await;
+            let mention = id.mention();
 
-            if let Some(role) = id.to_role_cached(&cache).await {
+            if let Some(role) = id.to_role_cached(&cache) {
                 result = result.replace(&mention, &format!("@{}", role.name));
             } else {
                 result = result.replace(&mention, "@deleted-role");
@@ -374,9 +493,8 @@ impl Message {
     ///
     /// [`guild_id`]: #method.guild_id
     #[cfg(feature = "cache")]
-    pub async fn guild(&self, cache: impl AsRef<CacheRwLock>) -> Option<Arc<AsyncRwLock<Guild>>> {
-        let guard = cache.as_ref().read().await;
-        guard.guild(self.guild_id?)
+    pub fn guild(&self, cache: impl AsRef<Cache>) -> Option<Guild> {
+        cache.as_ref().guild(self.guild_id?)
     }
 
     /// True if message was sent using direct messages.
@@ -393,11 +511,14 @@ impl Message {
     ///
     /// [`Guild::members`]: ../guild/struct.Guild.html#structfield.members
     #[cfg(feature = "cache")]
-    pub async fn member(&self, cache: impl AsRef<CacheRwLock>) -> Option<Member> {
-        match self.guild(&cache).await {
-            Some(entry) => entry.read().await.members.get(&self.author.id).cloned(),
-            None => None,
-        }
+    pub fn member(&self, cache: impl AsRef<Cache>) -> Option<Member> {
+        cache
+            .as_ref()
+            .guilds
+            .get(&self.guild_id?)?
+            .members
+            .get(&self.author.id)
+            .cloned()
     }
 
     /// Checks the length of a string to ensure that it is within Discord's
@@ -439,7 +560,7 @@ impl Message {
                 if self.guild_id.is_some() {
                     let req = Permissions::MANAGE_MESSAGES;
 
-                    if !utils::user_has_perms(&cache, self.channel_id, self.guild_id, req).await? {
+                    if !utils::user_has_perms(cache, self.channel_id, self.guild_id, req)? {
                         return Err(Error::Model(ModelError::InvalidPermissions(req)));
                     }
                 }
@@ -483,7 +604,7 @@ impl Message {
                 if self.guild_id.is_some() {
                     let req = Permissions::ADD_REACTIONS;
 
-                    if !utils::user_has_perms(cache, self.channel_id, self.guild_id, req).await? {
+                    if !utils::user_has_perms(cache, self.channel_id, self.guild_id, req)? {
                         return Err(Error::Model(ModelError::InvalidPermissions(req)));
                     }
                 }
@@ -536,14 +657,14 @@ impl Message {
                 if self.guild_id.is_some() {
                     let req = Permissions::SEND_MESSAGES;
 
-                    if !utils::user_has_perms(cache, self.channel_id, self.guild_id, req).await? {
+                    if !utils::user_has_perms(cache, self.channel_id, self.guild_id, req)? {
                         return Err(Error::Model(ModelError::InvalidPermissions(req)));
                     }
                 }
             }
         }
 
-        let mut gen = self.author.mention().await;
+        let mut gen = self.author.mention();
         gen.push_str(": ");
         gen.push_str(content);
 
@@ -558,6 +679,77 @@ impl Message {
             .await
     }
 
+    /// Replies to the message using Discord's reply feature, without
+    /// mentioning its author.
+    ///
+    /// Unlike [`reply`], the content is sent as is. Any other mentions in the
+    /// content still notify as usual.
+    ///
+    /// **Note**: Requires the [Send Messages] permission, and the [Read
+    /// Message History] permission in guilds.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if the content of the message
+    /// is over the above limit, containing the number of unicode code points
+    /// over the limit.
+    ///
+    /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
+    /// [`ModelError::MessageTooLong`]: ../error/enum.Error.html#variant.MessageTooLong
+    /// [`reply`]: #method.reply
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    #[cfg(feature = "client")]
+    pub async fn reply_without_ping(
+        &self,
+        cache_http: impl CacheHttp,
+        content: impl AsRef<str>,
+    ) -> Result<Message> {
+        let content = content.as_ref();
+
+        if let Some(length_over) = Message::overflow_length(content) {
+            return Err(Error::Model(ModelError::MessageTooLong(length_over)));
+        }
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if self.guild_id.is_some() {
+                    let req = Permissions::SEND_MESSAGES | Permissions::READ_MESSAGE_HISTORY;
+
+                    if !utils::user_has_perms(cache, self.channel_id, self.guild_id, req)? {
+                        return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                    }
+                }
+            }
+        }
+
+        let map = json!({
+            "content": content,
+            "tts": false,
+            "message_reference": {
+                "message_id": self.id,
+                "channel_id": self.channel_id,
+                "guild_id": self.guild_id,
+            },
+            "allowed_mentions": {
+                "parse": ["users", "roles", "everyone"],
+                "replied_user": false,
+            },
+        });
+
+        cache_http
+            .http()
+            .send_message(self.channel_id.0, &map)
+            .await
+    }
+
     /// Checks whether the message mentions passed [`UserId`].
     ///
     /// [`UserId`]: ../id/struct.UserId.html
@@ -579,6 +771,111 @@ impl Message {
         self.mentions_user_id(user.id)
     }
 
+    /// Scans the content of the message for user, role and channel mentions,
+    /// returning them in the order they appear along with their positions.
+    ///
+    /// Unlike [`mentions`] and [`mention_roles`], mentions are not
+    /// deduplicated, and a user mentioned in a reply without appearing in the
+    /// content is not included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::prelude::*;
+    /// # fn example(message: &Message) {
+    /// // For the content `<@&3> <@1> hello <#2>`:
+    /// let mentions = message.mentions_ordered();
+    ///
+    /// assert_eq!(mentions[0].mention, Mention::Role(RoleId(3)));
+    /// assert_eq!(mentions[1].mention, Mention::User(UserId(1)));
+    /// assert_eq!((mentions[1].start, mentions[1].end), (6, 10));
+    /// assert_eq!(mentions[2].mention, Mention::Channel(ChannelId(2)));
+    /// # }
+    /// ```
+    ///
+    /// [`mention_roles`]: #structfield.mention_roles
+    /// [`mentions`]: #structfield.mentions
+    pub fn mentions_ordered(&self) -> Vec<ContentMention> {
+        let content = &self.content;
+        let mut mentions = Vec::new();
+        let mut offset = 0;
+
+        while let Some(index) = content[offset..].find('<') {
+            let start = offset + index;
+
+            let end = match content[start..].find('>') {
+                Some(index) => start + index + 1,
+                None => break,
+            };
+
+            let candidate = &content[start..end];
+
+            let mention = if candidate.starts_with("<@&") {
+                serenity_utils::parse_role(candidate).map(|id| Mention::Role(RoleId(id)))
+            } else if candidate.starts_with("<@") {
+                serenity_utils::parse_username(candidate).map(|id| Mention::User(UserId(id)))
+            } else if candidate.starts_with("<#") {
+                serenity_utils::parse_channel(candidate).map(|id| Mention::Channel(ChannelId(id)))
+            } else {
+                None
+            };
+
+            match mention {
+                Some(mention) => {
+                    mentions.push(ContentMention {
+                        mention,
+                        start,
+                        end,
+                    });
+
+                    offset = end;
+                }
+                // The candidate may contain the start of a mention, as in
+                // `<<@1>`, so continue right after the `<`.
+                None => offset = start + 1,
+            }
+        }
+
+        mentions
+    }
+
+    /// Retrieves the roles mentioned by the message from the cache.
+    ///
+    /// Roles which are not in the cache, or mentioned outside of a guild, are
+    /// skipped.
+    #[cfg(feature = "cache")]
+    pub fn role_mention_objects(&self, cache: impl AsRef<Cache>) -> Vec<Role> {
+        let guild_id = match self.guild_id {
+            Some(guild_id) => guild_id,
+            None => return Vec::new(),
+        };
+
+        let cache = cache.as_ref();
+
+        self.mention_roles
+            .iter()
+            .filter_map(|&role_id| cache.role(guild_id, role_id))
+            .collect()
+    }
+
+    /// Retrieves the first user mentioned in the content of the message,
+    /// skipping mentions of the current user.
+    ///
+    /// This is useful for commands taking a user as an argument when the bot
+    /// can be mentioned as a prefix, as in `@Bot ban @User`.
+    #[cfg(feature = "cache")]
+    pub fn first_user_mention_excluding_bot(&self, cache: impl AsRef<Cache>) -> Option<&User> {
+        let current_user_id = cache.as_ref().current_user_id();
+
+        self.mentions_ordered()
+            .into_iter()
+            .filter_map(|content_mention| match content_mention.mention {
+                Mention::User(user_id) if user_id != current_user_id => Some(user_id),
+                _ => None,
+            })
+            .find_map(|user_id| self.mentions.iter().find(|user| user.id == user_id))
+    }
+
     /// Unpins the message from its channel.
     ///
     /// **Note**: Requires the [Manage Messages] permission.
@@ -599,7 +896,7 @@ impl Message {
                 if self.guild_id.is_some() {
                     let req = Permissions::MANAGE_MESSAGES;
 
-                    if !utils::user_has_perms(cache, self.channel_id, self.guild_id, req).await? {
+                    if !utils::user_has_perms(cache, self.channel_id, self.guild_id, req)? {
                         return Err(Error::Model(ModelError::InvalidPermissions(req)));
                     }
                 }
@@ -690,6 +987,32 @@ impl Message {
             Err(Error::Model(ModelError::EmbedTooLarge(overflow)))
         }
     }
+
+    /// Returns the first `attachment://filename` the embed references which is
+    /// not among the attached files.
+    #[cfg(feature = "http")]
+    pub(crate) fn missing_attachment(
+        embed: Option<&Value>,
+        files: &[AttachmentType<'_>],
+    ) -> Option<String> {
+        let embed = embed?.as_object()?;
+
+        ["author", "footer", "image", "thumbnail"]
+            .iter()
+            .filter_map(|key| embed.get(*key)?.as_object())
+            .flat_map(|object| {
+                ["url", "icon_url"]
+                    .iter()
+                    .filter_map(move |key| object.get(*key))
+            })
+            .filter_map(|url| url.as_str()?.strip_prefix("attachment://"))
+            .find(|filename| {
+                !files
+                    .iter()
+                    .any(|file| file.filename().as_deref() == Some(*filename))
+            })
+            .map(ToString::to_string)
+    }
 }
 
 impl AsRef<MessageId> for Message {
@@ -732,99 +1055,47 @@ pub struct MessageReaction {
     pub reaction_type: ReactionType,
 }
 
-/// Differentiates between regular and different types of system messages.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
-#[non_exhaustive]
-pub enum MessageType {
-    /// A regular message.
-    Regular = 0,
-    /// An indicator that a recipient was added by the author.
-    GroupRecipientAddition = 1,
-    /// An indicator that a recipient was removed by the author.
-    GroupRecipientRemoval = 2,
-    /// An indicator that a call was started by the author.
-    GroupCallCreation = 3,
-    /// An indicator that the group name was modified by the author.
-    GroupNameUpdate = 4,
-    /// An indicator that the group icon was modified by the author.
-    GroupIconUpdate = 5,
-    /// An indicator that a message was pinned by the author.
-    PinsAdd = 6,
-    /// An indicator that a member joined the guild.
-    MemberJoin = 7,
-    /// An indicator that someone has boosted the guild.
-    NitroBoost = 8,
-    /// An indicator that the guild has reached nitro tier 1
-    NitroTier1 = 9,
-    /// An indicator that the guild has reached nitro tier 2
-    NitroTier2 = 10,
-    /// An indicator that the guild has reached nitro tier 3
-    NitroTier3 = 11,
-}
-
-enum_number!(MessageType {
-    Regular,
-    GroupRecipientAddition,
-    GroupRecipientRemoval,
-    GroupCallCreation,
-    GroupNameUpdate,
-    GroupIconUpdate,
-    PinsAdd,
-    MemberJoin,
-    NitroBoost,
-    NitroTier1,
-    NitroTier2,
-    NitroTier3,
-});
-
-impl MessageType {
-    pub fn num(self) -> u64 {
-        use self::MessageType::*;
-
-        match self {
-            Regular => 0,
-            GroupRecipientAddition => 1,
-            GroupRecipientRemoval => 2,
-            GroupCallCreation => 3,
-            GroupNameUpdate => 4,
-            GroupIconUpdate => 5,
-            PinsAdd => 6,
-            MemberJoin => 7,
-            NitroBoost => 8,
-            NitroTier1 => 9,
-            NitroTier2 => 10,
-            NitroTier3 => 11,
-        }
+enum_number! {
+    /// Differentiates between regular and different types of system messages.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum MessageType {
+        /// A regular message.
+        Regular = 0,
+        /// An indicator that a recipient was added by the author.
+        GroupRecipientAddition = 1,
+        /// An indicator that a recipient was removed by the author.
+        GroupRecipientRemoval = 2,
+        /// An indicator that a call was started by the author.
+        GroupCallCreation = 3,
+        /// An indicator that the group name was modified by the author.
+        GroupNameUpdate = 4,
+        /// An indicator that the group icon was modified by the author.
+        GroupIconUpdate = 5,
+        /// An indicator that a message was pinned by the author.
+        PinsAdd = 6,
+        /// An indicator that a member joined the guild.
+        MemberJoin = 7,
+        /// An indicator that someone has boosted the guild.
+        NitroBoost = 8,
+        /// An indicator that the guild has reached nitro tier 1
+        NitroTier1 = 9,
+        /// An indicator that the guild has reached nitro tier 2
+        NitroTier2 = 10,
+        /// An indicator that the guild has reached nitro tier 3
+        NitroTier3 = 11,
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
-#[non_exhaustive]
-pub enum MessageActivityKind {
-    JOIN = 1,
-    SPECTATE = 2,
-    LISTEN = 3,
-    #[allow(non_camel_case_types)]
-    JOIN_REQUEST = 5,
-}
-
-enum_number!(MessageActivityKind {
-    JOIN,
-    SPECTATE,
-    LISTEN,
-    JOIN_REQUEST,
-});
-
-impl MessageActivityKind {
-    pub fn num(self) -> u64 {
-        use self::MessageActivityKind::*;
-
-        match self {
-            JOIN => 1,
-            SPECTATE => 2,
-            LISTEN => 3,
-            JOIN_REQUEST => 5,
-        }
+enum_number! {
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum MessageActivityKind {
+        JOIN = 1,
+        SPECTATE = 2,
+        LISTEN = 3,
+        #[allow(non_camel_case_types)]
+        JOIN_REQUEST = 5,
     }
 }
 
@@ -921,3 +1192,125 @@ impl Serialize for MessageFlags {
         serializer.serialize_u64(self.bits())
     }
 }
+
+#[cfg(all(test, feature = "client"))]
+mod test {
+    use super::Message;
+    use crate::http::mock::{MockResponse, MockServer};
+    use serde_json::Value;
+
+    #[tokio::test]
+    async fn test_reply_without_ping() {
+        let json = include_str!("../../../tests/resources/message_create_1.json");
+        let message: Message = serde_json::from_str(json).unwrap();
+
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(
+            200,
+            &serde_json::from_str(json).unwrap(),
+        ));
+        let http = server.http("Bot token");
+
+        message.reply_without_ping(&http, "pong").await.unwrap();
+
+        let requests = server.requests();
+        let body: Value = serde_json::from_slice(&requests[0].body).unwrap();
+
+        assert_eq!(body["content"], "pong");
+        assert_eq!(body["message_reference"]["message_id"], message.id.0);
+        assert_eq!(body["allowed_mentions"]["replied_user"], false);
+    }
+
+    #[tokio::test]
+    async fn test_attachment_references() {
+        use crate::http::AttachmentType;
+        use crate::model::{error::Error as ModelError, id::ChannelId};
+        use crate::Error;
+
+        let json = include_str!("../../../tests/resources/message_create_1.json");
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::json(
+            200,
+            &serde_json::from_str(json).unwrap(),
+        ));
+        let http = server.http("Bot token");
+
+        let chart = || AttachmentType::Bytes {
+            data: b"png"[..].into(),
+            filename: "chart.png".to_string(),
+        };
+
+        let result = ChannelId(7)
+            .send_message(&http, |m| {
+                m.add_file(chart());
+                m.embed(|e| e.attachment("chart.png").attachment_thumbnail("avatar.png"))
+            })
+            .await;
+
+        match result {
+            Err(Error::Model(ModelError::MissingAttachment(filename))) => {
+                assert_eq!(filename, "avatar.png")
+            }
+            other => panic!("expected a missing attachment, got {:?}", other),
+        }
+        assert!(server.requests().is_empty());
+
+        ChannelId(7)
+            .send_message(&http, |m| {
+                m.add_file(chart());
+                m.embed(|e| e.attachment("chart.png"))
+            })
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        let body = String::from_utf8_lossy(&requests[0].body);
+
+        assert!(body.contains("attachment://chart.png"));
+        assert!(body.contains("filename=\"chart.png\""));
+    }
+
+    #[tokio::test]
+    async fn test_forward_to() {
+        use crate::constants::UPLOAD_MAX_SIZE;
+        use crate::model::id::ChannelId;
+        use serde_json::json;
+
+        let json = include_str!("../../../tests/resources/message_create_1.json");
+        let server = MockServer::start().await;
+        server.enqueue(MockResponse::new(200).body(&b"png"[..]));
+        server.enqueue(MockResponse::json(
+            200,
+            &serde_json::from_str(json).unwrap(),
+        ));
+        let http = server.http("Bot token");
+
+        let mut message: Message = serde_json::from_str(json).unwrap();
+        message.content = "<@1> look".to_string();
+        message.attachments = serde_json::from_value(json!([
+            {"id": "1", "filename": "small.png", "height": null, "proxy_url": "", "size": 3,
+                "url": format!("{}/attachments/1/small.png", server.url()), "width": null},
+            {"id": "2", "filename": "large.mp4", "height": null, "proxy_url": "",
+                "size": UPLOAD_MAX_SIZE, "url": "https://cdn.example.com/large.mp4", "width": null},
+        ]))
+        .unwrap();
+        message.embeds = serde_json::from_value(json!([
+            {"type": "link", "url": "https://example.com"},
+            {"type": "rich", "title": "Starred"},
+        ]))
+        .unwrap();
+
+        message.forward_to(&http, ChannelId(7)).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/attachments/1/small.png");
+        assert_eq!(requests[1].path, "/channels/7/messages");
+
+        let body = String::from_utf8_lossy(&requests[1].body);
+        assert!(body.contains("filename=\"small.png\""));
+        assert!(!body.contains("filename=\"large.mp4\""));
+        assert!(body.contains(r#""content":"<@1> look\nhttps://cdn.example.com/large.mp4""#));
+        assert!(body.contains(r#""title":"Starred""#));
+        assert!(body.contains(r#""parse":[]"#));
+    }
+}
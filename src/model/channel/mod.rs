@@ -6,9 +6,11 @@ mod channel_id;
 mod embed;
 mod group;
 mod guild_channel;
+mod guild_thread;
 mod message;
 mod private_channel;
 mod reaction;
+mod stage_instance;
 
 pub use self::attachment::*;
 pub use self::channel_category::*;
@@ -16,9 +18,11 @@ pub use self::channel_id::*;
 pub use self::embed::*;
 pub use self::group::*;
 pub use self::guild_channel::*;
+pub use self::guild_thread::*;
 pub use self::message::*;
 pub use self::private_channel::*;
 pub use self::reaction::*;
+pub use self::stage_instance::*;
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
 
@@ -28,47 +32,45 @@ use serde::de::Error as DeError;
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 #[cfg(feature = "cache")]
-use crate::cache::CacheRwLock;
+use crate::cache::Cache;
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
 use crate::cache::FromStrAndCache;
-#[cfg(feature = "cache")]
-use crate::internal::AsyncRwLock;
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
 use crate::model::misc::ChannelParseError;
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
 use crate::utils::parse_channel;
-#[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
-use async_trait::async_trait;
-#[cfg(feature = "cache")]
-use std::sync::Arc;
 
 /// A container for any channel.
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub enum Channel {
     /// A group. A group comprises of only one channel.
-    Group(Arc<AsyncRwLock<Group>>),
+    Group(Group),
     /// A [text] or [voice] channel within a [`Guild`].
     ///
     /// [`Guild`]: ../guild/struct.Guild.html
     /// [text]: enum.ChannelType.html#variant.Text
     /// [voice]: enum.ChannelType.html#variant.Voice
-    Guild(Arc<AsyncRwLock<GuildChannel>>),
+    Guild(GuildChannel),
     /// A private channel to another [`User`]. No other users may access the
     /// channel. For multi-user "private channels", use a group.
     ///
     /// [`User`]: ../user/struct.User.html
-    Private(Arc<AsyncRwLock<PrivateChannel>>),
+    Private(PrivateChannel),
     /// A category of [`GuildChannel`]s
     ///
     /// [`GuildChannel`]: struct.GuildChannel.html
-    Category(Arc<AsyncRwLock<ChannelCategory>>),
+    Category(ChannelCategory),
+    /// A thread within a [`GuildChannel`].
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    Thread(GuildThread),
 }
 
 impl Channel {
-    /// Converts from `Channel` to `Option<Arc<RwLock<Group>>>`.
+    /// Converts from `Channel` to `Option<Group>`.
     ///
-    /// Converts `self` into an `Option<Arc<RwLock<Group>>>`, consuming `self`,
+    /// Converts `self` into an `Option<Group>`, consuming `self`,
     /// and discarding a `GuildChannel`, `PrivateChannel`, or `ChannelCategory`,
     /// if any.
     ///
@@ -78,18 +80,15 @@ impl Channel {
     ///
     /// ```rust,no_run
     /// # #[cfg(all(feature = "model", feature = "cache"))]
-    /// # #[tokio::main]
-    /// # async fn main() {
-    /// # use serenity::{cache::{Cache, CacheRwLock}, model::id::ChannelId};
-    /// # use async_std::sync::RwLock;
-    /// # use std::sync::Arc;
+    /// # fn main() {
+    /// # use serenity::{cache::Cache, model::id::ChannelId};
     /// #
-    /// #     let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// #     let channel = ChannelId(0).to_channel_cached(&cache).await.unwrap();
+    /// #   let cache = Cache::default();
+    /// #   let channel = ChannelId(0).to_channel_cached(&cache).unwrap();
     /// #
     /// match channel.group() {
-    ///     Some(group_lock) => {
-    ///         if let Some(ref name) = group_lock.read().await.name {
+    ///     Some(group) => {
+    ///         if let Some(name) = group.name {
     ///             println!("It's a group named {}!", name);
     ///         } else {
     ///              println!("It's an unnamed group!");
@@ -103,16 +102,16 @@ impl Channel {
     /// # #[cfg(not(all(feature = "model", feature = "cache")))]
     /// fn main() {}
     /// ```
-    pub fn group(self) -> Option<Arc<AsyncRwLock<Group>>> {
+    pub fn group(self) -> Option<Group> {
         match self {
-            Channel::Group(lock) => Some(lock),
+            Channel::Group(channel) => Some(channel),
             _ => None,
         }
     }
 
-    /// Converts from `Channel` to `Option<Arc<RwLock<GuildChannel>>>`.
+    /// Converts from `Channel` to `Option<GuildChannel>`.
     ///
-    /// Converts `self` into an `Option<Arc<RwLock<GuildChannel>>>`, consuming
+    /// Converts `self` into an `Option<GuildChannel>`, consuming
     /// `self`, and discarding a `Group`, `PrivateChannel`, or
     /// `ChannelCategory`, if any.
     ///
@@ -122,18 +121,15 @@ impl Channel {
     ///
     /// ```rust,no_run
     /// # #[cfg(all(feature = "model", feature = "cache"))]
-    /// # #[tokio::main]
-    /// # async fn main() {
-    /// # use serenity::{cache::{Cache, CacheRwLock}, model::id::ChannelId};
-    /// # use async_std::sync::RwLock;
-    /// # use std::sync::Arc;
+    /// # fn main() {
+    /// # use serenity::{cache::Cache, model::id::ChannelId};
     /// #
-    /// #   let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// #   let channel = ChannelId(0).to_channel_cached(&cache).await.unwrap();
+    /// #   let cache = Cache::default();
+    /// #   let channel = ChannelId(0).to_channel_cached(&cache).unwrap();
     /// #
     /// match channel.guild() {
-    ///     Some(guild_lock) => {
-    ///         println!("It's a guild named {}!", guild_lock.read().await.name);
+    ///     Some(guild) => {
+    ///         println!("It's a guild named {}!", guild.name);
     ///     },
     ///     None => { println!("It's not a guild!"); },
     /// }
@@ -143,16 +139,16 @@ impl Channel {
     /// # #[cfg(not(all(feature = "model", feature = "cache")))]
     /// fn main() {}
     /// ```
-    pub fn guild(self) -> Option<Arc<AsyncRwLock<GuildChannel>>> {
+    pub fn guild(self) -> Option<GuildChannel> {
         match self {
-            Channel::Guild(lock) => Some(lock),
+            Channel::Guild(channel) => Some(channel),
             _ => None,
         }
     }
 
-    /// Converts from `Channel` to `Option<Arc<RwLock<PrivateChannel>>>`.
+    /// Converts from `Channel` to `Option<PrivateChannel>`.
     ///
-    /// Converts `self` into an `Option<Arc<RwLock<PrivateChannel>>>`, consuming
+    /// Converts `self` into an `Option<PrivateChannel>`, consuming
     /// `self`, and discarding a `Group`, `GuildChannel`, or `ChannelCategory`,
     /// if any.
     ///
@@ -162,19 +158,15 @@ impl Channel {
     ///
     /// ```rust,no_run
     /// # #[cfg(all(feature = "model", feature = "cache"))]
-    /// # #[tokio::main]
-    /// # async fn main() {
-    /// # use serenity::{cache::{Cache, CacheRwLock}, model::id::ChannelId};
-    /// # use async_std::sync::RwLock;
-    /// # use std::sync::Arc;
+    /// # fn main() {
+    /// # use serenity::{cache::Cache, model::id::ChannelId};
     /// #
-    /// #   let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// #   let channel = ChannelId(0).to_channel_cached(&cache).await.unwrap();
+    /// #   let cache = Cache::default();
+    /// #   let channel = ChannelId(0).to_channel_cached(&cache).unwrap();
     /// #
     /// match channel.private() {
-    ///     Some(private_lock) => {
-    ///         let private = private_lock.read().await;
-    ///         println!("It's a private channel with {}!", &private.recipient.read().name);
+    ///     Some(private) => {
+    ///         println!("It's a private channel with {}!", &private.recipient.name);
     ///     },
     ///     None => { println!("It's not a private channel!"); },
     /// }
@@ -184,16 +176,16 @@ impl Channel {
     /// # #[cfg(not(all(feature = "model", feature = "cache")))]
     /// fn main() {}
     /// ```
-    pub fn private(self) -> Option<Arc<AsyncRwLock<PrivateChannel>>> {
+    pub fn private(self) -> Option<PrivateChannel> {
         match self {
-            Channel::Private(lock) => Some(lock),
+            Channel::Private(channel) => Some(channel),
             _ => None,
         }
     }
 
-    /// Converts from `Channel` to `Option<Arc<RwLock<ChannelCategory>>>`.
+    /// Converts from `Channel` to `Option<ChannelCategory>`.
     ///
-    /// Converts `self` into an `Option<Arc<RwLock<ChannelCategory>>>`,
+    /// Converts `self` into an `Option<ChannelCategory>`,
     /// consuming `self`, and discarding a `Group`, `GuildChannel`, or
     /// `PrivateChannel`, if any.
     ///
@@ -203,18 +195,15 @@ impl Channel {
     ///
     /// ```rust,no_run
     /// # #[cfg(all(feature = "model", feature = "cache"))]
-    /// # #[tokio::main]
-    /// # async fn main() {
-    /// # use serenity::{cache::{Cache, CacheRwLock}, model::id::ChannelId};
-    /// # use async_std::sync::RwLock;
-    /// # use std::sync::Arc;
+    /// # fn main() {
+    /// # use serenity::{cache::Cache, model::id::ChannelId};
     /// #
-    /// #   let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// #   let channel = ChannelId(0).to_channel_cached(&cache).await.unwrap();
+    /// #   let cache = Cache::default();
+    /// #   let channel = ChannelId(0).to_channel_cached(&cache).unwrap();
     /// #
     /// match channel.category() {
-    ///     Some(category_lock) => {
-    ///         println!("It's a category named {}!", category_lock.read().await.name);
+    ///     Some(category) => {
+    ///         println!("It's a category named {}!", category.name);
     ///     },
     ///     None => { println!("It's not a category!"); },
     /// }
@@ -224,9 +213,20 @@ impl Channel {
     /// # #[cfg(not(all(feature = "model", feature = "cache")))]
     /// fn main() {}
     /// ```
-    pub fn category(self) -> Option<Arc<AsyncRwLock<ChannelCategory>>> {
+    pub fn category(self) -> Option<ChannelCategory> {
+        match self {
+            Channel::Category(channel) => Some(channel),
+            _ => None,
+        }
+    }
+
+    /// Converts from `Channel` to `Option<GuildThread>`.
+    ///
+    /// Converts `self` into an `Option<GuildThread>`, consuming `self`, and
+    /// discarding any other kind of channel.
+    pub fn thread(self) -> Option<GuildThread> {
         match self {
-            Channel::Category(lock) => Some(lock),
+            Channel::Thread(thread) => Some(thread),
             _ => None,
         }
     }
@@ -244,20 +244,19 @@ impl Channel {
     pub async fn delete(&self, cache_http: impl CacheHttp) -> Result<()> {
         match *self {
             Channel::Group(ref group) => {
-                let g = group.read().await;
-                let _ = g.leave(cache_http.http()).await?;
+                let _ = group.leave(cache_http.http()).await?;
             }
             Channel::Guild(ref public_channel) => {
-                let g = public_channel.read().await;
-                let _ = g.delete(cache_http).await?;
+                let _ = public_channel.delete(cache_http).await?;
             }
             Channel::Private(ref private_channel) => {
-                let g = private_channel.read().await;
-                let _ = g.delete(cache_http.http()).await?;
+                let _ = private_channel.delete(cache_http.http()).await?;
             }
             Channel::Category(ref category) => {
-                let g = category.read().await;
-                g.delete(cache_http).await?;
+                category.delete(cache_http).await?;
+            }
+            Channel::Thread(ref thread) => {
+                cache_http.http().delete_channel(thread.id.0).await?;
             }
         }
 
@@ -267,11 +266,11 @@ impl Channel {
     /// Determines if the channel is NSFW.
     #[cfg(feature = "model")]
     #[inline]
-    pub async fn is_nsfw(&self) -> bool {
+    pub fn is_nsfw(&self) -> bool {
         match *self {
-            Channel::Guild(ref channel) => channel.read().await.is_nsfw(),
-            Channel::Category(ref category) => category.read().await.is_nsfw(),
-            Channel::Group(_) | Channel::Private(_) => false,
+            Channel::Guild(ref channel) => channel.is_nsfw(),
+            Channel::Category(ref category) => category.is_nsfw(),
+            Channel::Group(_) | Channel::Private(_) | Channel::Thread(_) => false,
         }
     }
 
@@ -281,12 +280,58 @@ impl Channel {
     /// [`Group`]: struct.Group.html
     /// [`GuildChannel`]: struct.GuildChannel.html
     /// [`PrivateChannel`]: struct.PrivateChannel.html
-    pub async fn id(&self) -> ChannelId {
+    pub fn id(&self) -> ChannelId {
         match *self {
-            Channel::Group(ref group) => group.read().await.channel_id,
-            Channel::Guild(ref ch) => ch.read().await.id,
-            Channel::Private(ref ch) => ch.read().await.id,
-            Channel::Category(ref category) => category.read().await.id,
+            Channel::Group(ref group) => group.channel_id,
+            Channel::Guild(ref ch) => ch.id,
+            Channel::Private(ref ch) => ch.id,
+            Channel::Category(ref category) => category.id,
+            Channel::Thread(ref thread) => thread.id,
+        }
+    }
+
+    /// Retrieves the type of the inner channel.
+    pub fn kind(&self) -> ChannelType {
+        match *self {
+            Channel::Group(_) => ChannelType::Group,
+            Channel::Guild(ref channel) => channel.kind,
+            Channel::Private(ref channel) => channel.kind,
+            Channel::Category(ref category) => category.kind,
+            Channel::Thread(ref thread) => thread.kind,
+        }
+    }
+
+    /// Whether messages can be sent in the channel, such as a text channel,
+    /// a thread, or a private channel.
+    ///
+    /// Refer to [`ChannelType::is_text_based`] for the types this includes.
+    ///
+    /// [`ChannelType::is_text_based`]: enum.ChannelType.html#method.is_text_based
+    #[inline]
+    pub fn is_text_based(&self) -> bool {
+        self.kind().is_text_based()
+    }
+
+    /// Whether users can connect to the channel to talk, such as a voice or
+    /// stage channel.
+    #[inline]
+    pub fn is_voice_based(&self) -> bool {
+        self.kind().is_voice_based()
+    }
+
+    /// Whether the channel is a thread.
+    #[inline]
+    pub fn is_thread(&self) -> bool {
+        self.kind().is_thread()
+    }
+
+    /// Retrieves the Id of the guild the inner channel is in, if it is in one.
+    pub fn guild_id(&self) -> Option<GuildId> {
+        match *self {
+            Channel::Guild(ref channel) => Some(channel.guild_id),
+            Channel::Category(ref category) => Some(category.guild_id),
+            Channel::Thread(ref thread) => Some(thread.guild_id),
+            Channel::Group(_) | Channel::Private(_) => None,
         }
     }
 
@@ -297,29 +342,13 @@ impl Channel {
     ///
     /// [`GuildChannel`]: struct.GuildChannel.html
     /// [`CategoryChannel`]: struct.ChannelCategory.html
-    pub async fn position(&self) -> Option<i64> {
+    pub fn position(&self) -> Option<i64> {
         match *self {
-            Channel::Guild(ref channel) => Some(channel.read().await.position),
-            Channel::Category(ref catagory) => Some(catagory.read().await.position),
+            Channel::Guild(ref channel) => Some(channel.position),
+            Channel::Category(ref catagory) => Some(catagory.position),
             _ => None,
         }
     }
-
-    pub async fn async_to_string(&self) -> String {
-        match *self {
-            Channel::Group(ref group) => group.read().await.name().to_string(),
-            Channel::Guild(ref ch) => {
-                let guard = ch.read().await;
-                guard.mention().await
-            }
-            Channel::Private(ref ch) => {
-                let ch = ch.read().await;
-                let guard = ch.recipient.read();
-                guard.name.to_string()
-            }
-            Channel::Category(ref category) => category.read().await.name.to_string(),
-        }
-    }
 }
 
 impl<'de> Deserialize<'de> for Channel {
@@ -334,17 +363,20 @@ impl<'de> Deserialize<'de> for Channel {
         };
 
         match kind {
-            0 | 2 | 5 | 6 => serde_json::from_value::<GuildChannel>(Value::Object(v))
-                .map(|x| Channel::Guild(Arc::new(AsyncRwLock::new(x))))
+            0 | 2 | 5 | 6 | 13 => serde_json::from_value::<GuildChannel>(Value::Object(v))
+                .map(Channel::Guild)
                 .map_err(DeError::custom),
             1 => serde_json::from_value::<PrivateChannel>(Value::Object(v))
-                .map(|x| Channel::Private(Arc::new(AsyncRwLock::new(x))))
+                .map(Channel::Private)
                 .map_err(DeError::custom),
             3 => serde_json::from_value::<Group>(Value::Object(v))
-                .map(|x| Channel::Group(Arc::new(AsyncRwLock::new(x))))
+                .map(Channel::Group)
                 .map_err(DeError::custom),
             4 => serde_json::from_value::<ChannelCategory>(Value::Object(v))
-                .map(|x| Channel::Category(Arc::new(AsyncRwLock::new(x))))
+                .map(Channel::Category)
+                .map_err(DeError::custom),
+            10..=12 => serde_json::from_value::<GuildThread>(Value::Object(v))
+                .map(Channel::Thread)
                 .map_err(DeError::custom),
             _ => Err(DeError::custom("Unknown channel type")),
         }
@@ -357,23 +389,15 @@ impl Serialize for Channel {
         S: Serializer,
     {
         match *self {
-            Channel::Category(ref c) => {
-                ChannelCategory::serialize(&*futures::executor::block_on(c.read()), serializer)
-            }
-            Channel::Group(ref c) => {
-                Group::serialize(&*futures::executor::block_on(c.read()), serializer)
-            }
-            Channel::Guild(ref c) => {
-                GuildChannel::serialize(&*futures::executor::block_on(c.read()), serializer)
-            }
-            Channel::Private(ref c) => {
-                PrivateChannel::serialize(&*futures::executor::block_on(c.read()), serializer)
-            }
+            Channel::Category(ref c) => ChannelCategory::serialize(c, serializer),
+            Channel::Group(ref c) => Group::serialize(c, serializer),
+            Channel::Guild(ref c) => GuildChannel::serialize(c, serializer),
+            Channel::Private(ref c) => PrivateChannel::serialize(c, serializer),
+            Channel::Thread(ref c) => GuildThread::serialize(c, serializer),
         }
     }
 }
 
-/*
 #[cfg(feature = "model")]
 impl Display for Channel {
     /// Formats the channel into a "mentioned" string.
@@ -383,7 +407,7 @@ impl Display for Channel {
     /// - [`Group`]s: the generated name retrievable via [`Group::name`];
     /// - [`PrivateChannel`]s: the recipient's name;
     /// - [`GuildChannel`]s: a string mentioning the channel that users who can
-    /// see the channel can click on.
+    ///   see the channel can click on.
     ///
     /// [`Group`]: struct.Group.html
     /// [`Group::name`]: struct.Group.html#method.name
@@ -391,71 +415,73 @@ impl Display for Channel {
     /// [`PrivateChannel`]: struct.PrivateChannel.html
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match *self {
-            Channel::Group(ref group) => futures::executor::block_on(async move {
-                let guard = group.read().await;
-                Display::fmt(&guard.name(), f)
-            }),
-            Channel::Guild(ref ch) => Display::fmt(&futures::executor::block_on(async {
-                let guard = ch.read().await;
-                guard.mention().await
-            }), f),
-            Channel::Private(ref ch) => {
-                let channel = futures::executor::block_on(ch.read());
-
-                Display::fmt(&channel.recipient.name, f)
-            },
-            Channel::Category(ref category) => Display::fmt(&futures::executor::block_on(category.read()).name, f),
+            Channel::Group(ref group) => Display::fmt(&group.name(), f),
+            Channel::Guild(ref ch) => Display::fmt(&ch.mention(), f),
+            Channel::Private(ref ch) => Display::fmt(&ch.recipient.name, f),
+            Channel::Category(ref category) => Display::fmt(&category.name, f),
+            Channel::Thread(ref thread) => Display::fmt(&thread.id.mention(), f),
         }
     }
-}*/
-
-/// A representation of a type of channel.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
-#[non_exhaustive]
-pub enum ChannelType {
-    /// An indicator that the channel is a text [`GuildChannel`].
-    ///
-    /// [`GuildChannel`]: struct.GuildChannel.html
-    Text = 0,
-    /// An indicator that the channel is a [`PrivateChannel`].
-    ///
-    /// [`PrivateChannel`]: struct.PrivateChannel.html
-    Private = 1,
-    /// An indicator that the channel is a voice [`GuildChannel`].
-    ///
-    /// [`GuildChannel`]: struct.GuildChannel.html
-    Voice = 2,
-    /// An indicator that the channel is the channel of a [`Group`].
-    ///
-    /// [`Group`]: struct.Group.html
-    Group = 3,
-    /// An indicator that the channel is the channel of a [`ChannelCategory`].
-    ///
-    /// [`ChannelCategory`]: struct.ChannelCategory.html
-    Category = 4,
-    /// An indicator that the channel is a `NewsChannel`.
-    ///
-    /// Note: `NewsChannel` is serialized into a [`GuildChannel`]
-    ///
-    /// [`GuildChannel`]: struct.GuildChannel.html
-    News = 5,
-    /// An indicator that the channel is a `StoreChannel`
-    ///
-    /// Note: `StoreChannel` is serialized into a [`GuildChannel`]
-    ///
-    /// [`GuildChannel`]: struct.GuildChannel.html
-    Store = 6,
 }
 
-enum_number!(ChannelType {
-    Text,
-    Private,
-    Voice,
-    Group,
-    Category,
-    News,
-    Store,
-});
+enum_number! {
+    /// A representation of a type of channel.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum ChannelType {
+        /// An indicator that the channel is a text [`GuildChannel`].
+        ///
+        /// [`GuildChannel`]: struct.GuildChannel.html
+        Text = 0,
+        /// An indicator that the channel is a [`PrivateChannel`].
+        ///
+        /// [`PrivateChannel`]: struct.PrivateChannel.html
+        Private = 1,
+        /// An indicator that the channel is a voice [`GuildChannel`].
+        ///
+        /// [`GuildChannel`]: struct.GuildChannel.html
+        Voice = 2,
+        /// An indicator that the channel is the channel of a [`Group`].
+        ///
+        /// [`Group`]: struct.Group.html
+        Group = 3,
+        /// An indicator that the channel is the channel of a [`ChannelCategory`].
+        ///
+        /// [`ChannelCategory`]: struct.ChannelCategory.html
+        Category = 4,
+        /// An indicator that the channel is a `NewsChannel`.
+        ///
+        /// Note: `NewsChannel` is serialized into a [`GuildChannel`]
+        ///
+        /// [`GuildChannel`]: struct.GuildChannel.html
+        News = 5,
+        /// An indicator that the channel is a `StoreChannel`
+        ///
+        /// Note: `StoreChannel` is serialized into a [`GuildChannel`]
+        ///
+        /// [`GuildChannel`]: struct.GuildChannel.html
+        Store = 6,
+        /// An indicator that the channel is a [`GuildThread`] in a news
+        /// channel.
+        ///
+        /// [`GuildThread`]: struct.GuildThread.html
+        NewsThread = 10,
+        /// An indicator that the channel is a public [`GuildThread`].
+        ///
+        /// [`GuildThread`]: struct.GuildThread.html
+        PublicThread = 11,
+        /// An indicator that the channel is a private [`GuildThread`], which
+        /// can only be seen by members who were invited to it.
+        ///
+        /// [`GuildThread`]: struct.GuildThread.html
+        PrivateThread = 12,
+        /// An indicator that the channel is a stage [`GuildChannel`], a voice
+        /// channel for hosting events with an audience.
+        ///
+        /// [`GuildChannel`]: struct.GuildChannel.html
+        Stage = 13,
+    }
+}
 
 impl ChannelType {
     pub fn name(&self) -> &str {
@@ -467,19 +493,39 @@ impl ChannelType {
             ChannelType::Category => "category",
             ChannelType::News => "news",
             ChannelType::Store => "store",
+            ChannelType::NewsThread => "news_thread",
+            ChannelType::PublicThread => "public_thread",
+            ChannelType::PrivateThread => "private_thread",
+            ChannelType::Stage => "stage",
+            ChannelType::Unknown(_) => "unknown",
         }
     }
 
-    pub fn num(self) -> u64 {
-        match self {
-            ChannelType::Text => 0,
-            ChannelType::Private => 1,
-            ChannelType::Voice => 2,
-            ChannelType::Group => 3,
-            ChannelType::Category => 4,
-            ChannelType::News => 5,
-            ChannelType::Store => 6,
-        }
+    /// Whether messages can be sent in channels of this type.
+    pub fn is_text_based(self) -> bool {
+        matches!(
+            self,
+            ChannelType::Text
+                | ChannelType::Private
+                | ChannelType::Group
+                | ChannelType::News
+                | ChannelType::NewsThread
+                | ChannelType::PublicThread
+                | ChannelType::PrivateThread
+        )
+    }
+
+    /// Whether users can connect to channels of this type to talk.
+    pub fn is_voice_based(self) -> bool {
+        matches!(self, ChannelType::Voice | ChannelType::Stage)
+    }
+
+    /// Whether channels of this type are threads.
+    pub fn is_thread(self) -> bool {
+        matches!(
+            self,
+            ChannelType::NewsThread | ChannelType::PublicThread | ChannelType::PrivateThread
+        )
     }
 }
 
@@ -494,7 +540,7 @@ struct PermissionOverwriteData {
 }
 
 /// A channel-specific permission overwrite for a member or role.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PermissionOverwrite {
     pub allow: Permissions,
     pub deny: Permissions,
@@ -555,15 +601,175 @@ pub enum PermissionOverwriteType {
     Role(RoleId),
 }
 
+/// The changes needed to turn one set of permission overwrites into another,
+/// as computed by [`diff_overwrites`].
+///
+/// [`diff_overwrites`]: fn.diff_overwrites.html
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct OverwriteDiff {
+    /// The overwrites to create, or to update if one for the same member or
+    /// role already exists.
+    pub upsert: Vec<PermissionOverwrite>,
+    /// The members and roles whose overwrites are to be deleted.
+    pub delete: Vec<PermissionOverwriteType>,
+}
+
+impl OverwriteDiff {
+    /// Whether the two sets of overwrites were equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.upsert.is_empty() && self.delete.is_empty()
+    }
+}
+
+/// Computes the changes needed to turn the `old` permission overwrites into
+/// the `new` ones.
+///
+/// Overwrites which are unchanged are left out. If `new` contains several
+/// overwrites for the same member or role, the last one is used.
+///
+/// Refer to [`GuildChannel::set_overwrites`] for applying the changes to a
+/// channel.
+///
+/// [`GuildChannel::set_overwrites`]: struct.GuildChannel.html#method.set_overwrites
+pub fn diff_overwrites(old: &[PermissionOverwrite], new: &[PermissionOverwrite]) -> OverwriteDiff {
+    let mut diff = OverwriteDiff::default();
+
+    for (index, overwrite) in new.iter().enumerate() {
+        let superseded = new[index + 1..]
+            .iter()
+            .any(|later| later.kind == overwrite.kind);
+
+        if !superseded && !old.contains(overwrite) {
+            diff.upsert.push(overwrite.clone());
+        }
+    }
+
+    for overwrite in old {
+        if !new.iter().any(|new| new.kind == overwrite.kind) {
+            diff.delete.push(overwrite.kind);
+        }
+    }
+
+    diff
+}
+
+/// A news channel followed by another channel, as returned when following it.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#followed-channel-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct FollowedChannel {
+    /// The Id of the news channel which was followed.
+    pub channel_id: ChannelId,
+    /// The Id of the webhook created in the target channel, which crossposted
+    /// messages are sent through.
+    pub webhook_id: WebhookId,
+}
+
 #[cfg(test)]
 mod test {
+    use super::ChannelType;
+
+    #[test]
+    fn test_channel_type_numbers() {
+        assert_eq!(ChannelType::from(2), ChannelType::Voice);
+        assert_eq!(u64::from(ChannelType::Store), 6);
+        assert_eq!(ChannelType::from(13), ChannelType::Stage);
+        assert_eq!(ChannelType::from(11), ChannelType::PublicThread);
+        assert_eq!(ChannelType::from(99), ChannelType::Unknown(99));
+        assert_eq!(ChannelType::Unknown(99).num(), 99);
+
+        let kind: ChannelType = serde_json::from_str("99").unwrap();
+        assert_eq!(kind, ChannelType::Unknown(99));
+        assert_eq!(serde_json::to_string(&kind).unwrap(), "99");
+        assert_eq!(serde_json::to_string(&ChannelType::News).unwrap(), "5");
+    }
+
+    #[test]
+    fn test_channel_capabilities() {
+        use super::Channel;
+        use serde_json::json;
+
+        let thread = json!({
+            "id": "2",
+            "guild_id": "1",
+            "parent_id": "3",
+            "type": 11,
+            "name": "thread",
+            "thread_metadata": {
+                "archived": false,
+                "auto_archive_duration": 60,
+                "archive_timestamp": "2021-01-01T00:00:00+00:00",
+            },
+        });
+        let thread: Channel = serde_json::from_value(thread).unwrap();
+
+        assert!(thread.is_text_based());
+        assert!(thread.is_thread());
+        assert!(!thread.is_voice_based());
+        assert_eq!(thread.guild_id().map(|id| id.0), Some(1));
+
+        let private = json!({
+            "id": "4",
+            "type": 1,
+            "recipients": [{"id": "5", "username": "a", "discriminator": "0001", "avatar": null}],
+        });
+        let private: Channel = serde_json::from_value(private).unwrap();
+
+        assert!(private.is_text_based());
+        assert_eq!(private.kind(), ChannelType::Private);
+        assert_eq!(private.guild_id(), None);
+
+        assert!(ChannelType::Stage.is_voice_based());
+        assert!(!ChannelType::Stage.is_text_based());
+        assert!(!ChannelType::Unknown(99).is_text_based());
+    }
+
+    #[test]
+    fn test_diff_overwrites() {
+        use super::{diff_overwrites, PermissionOverwrite, PermissionOverwriteType};
+        use crate::model::prelude::*;
+
+        fn overwrite(kind: PermissionOverwriteType, allow: Permissions) -> PermissionOverwrite {
+            PermissionOverwrite {
+                allow,
+                deny: Permissions::empty(),
+                kind,
+            }
+        }
+
+        let (member, role, other) = (
+            PermissionOverwriteType::Member(UserId(1)),
+            PermissionOverwriteType::Role(RoleId(2)),
+            PermissionOverwriteType::Role(RoleId(3)),
+        );
+        let old = vec![
+            overwrite(member, Permissions::SEND_MESSAGES),
+            overwrite(role, Permissions::SEND_MESSAGES),
+            overwrite(other, Permissions::SEND_MESSAGES),
+        ];
+
+        assert!(diff_overwrites(&old, &old).is_empty());
+
+        let new = vec![
+            overwrite(member, Permissions::SEND_MESSAGES),
+            overwrite(role, Permissions::ADD_REACTIONS),
+            overwrite(role, Permissions::ATTACH_FILES),
+        ];
+        let diff = diff_overwrites(&old, &new);
+
+        assert_eq!(
+            diff.upsert,
+            vec![overwrite(role, Permissions::ATTACH_FILES)]
+        );
+        assert_eq!(diff.delete, vec![other]);
+    }
+
     #[cfg(all(feature = "model", feature = "utils"))]
     mod model_utils {
-        use crate::internal::{AsyncRwLock, SyncRwLock};
         use crate::model::prelude::*;
-        use crate::utils::run_async_test;
         use std::collections::HashMap;
-        use std::sync::Arc;
 
         fn group() -> Group {
             Group {
@@ -602,61 +808,58 @@ mod test {
                 last_message_id: None,
                 last_pin_timestamp: None,
                 kind: ChannelType::Private,
-                recipient: Arc::new(SyncRwLock::new(User {
+                recipient: User {
                     id: UserId(2),
                     avatar: None,
                     bot: false,
                     discriminator: 1,
                     name: "ab".to_string(),
-                })),
+                },
             }
         }
 
         #[test]
         fn nsfw_checks() {
-            run_async_test(async move {
-                let mut channel = guild_channel();
-                assert!(!channel.is_nsfw());
-                channel.kind = ChannelType::Voice;
-                assert!(!channel.is_nsfw());
-
-                channel.kind = ChannelType::Text;
-                channel.name = "nsfw-".to_string();
-                assert!(!channel.is_nsfw());
-
-                channel.name = "nsfw".to_string();
-                assert!(!channel.is_nsfw());
-                channel.kind = ChannelType::Voice;
-                assert!(!channel.is_nsfw());
-                channel.kind = ChannelType::Text;
-
-                channel.name = "nsf".to_string();
-                channel.nsfw = true;
-                assert!(channel.is_nsfw());
-                channel.nsfw = false;
-                assert!(!channel.is_nsfw());
-
-                let channel = Channel::Guild(Arc::new(AsyncRwLock::new(channel)));
-                assert!(!channel.is_nsfw().await);
-
-                let group = group();
-                assert!(!group.is_nsfw());
-
-                let private_channel = private_channel();
-                assert!(!private_channel.is_nsfw());
-            });
+            let mut channel = guild_channel();
+            assert!(!channel.is_nsfw());
+            channel.kind = ChannelType::Voice;
+            assert!(!channel.is_nsfw());
+
+            channel.kind = ChannelType::Text;
+            channel.name = "nsfw-".to_string();
+            assert!(!channel.is_nsfw());
+
+            channel.name = "nsfw".to_string();
+            assert!(!channel.is_nsfw());
+            channel.kind = ChannelType::Voice;
+            assert!(!channel.is_nsfw());
+            channel.kind = ChannelType::Text;
+
+            channel.name = "nsf".to_string();
+            channel.nsfw = true;
+            assert!(channel.is_nsfw());
+            channel.nsfw = false;
+            assert!(!channel.is_nsfw());
+
+            let channel = Channel::Guild(channel);
+            assert!(!channel.is_nsfw());
+
+            let group = group();
+            assert!(!group.is_nsfw());
+
+            let private_channel = private_channel();
+            assert!(!private_channel.is_nsfw());
         }
     }
 }
 
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
-#[async_trait]
 impl FromStrAndCache for Channel {
     type Err = ChannelParseError;
 
-    async fn from_str(cache: &CacheRwLock, s: &str) -> StdResult<Self, Self::Err> {
+    fn from_str(cache: &Cache, s: &str) -> StdResult<Self, Self::Err> {
         match parse_channel(s) {
-            Some(x) => match ChannelId(x).to_channel_cached(&cache).await {
+            Some(x) => match ChannelId(x).to_channel_cached(cache) {
                 Some(channel) => Ok(channel),
                 _ => Err(ChannelParseError::NotPresentInCache),
             },
@@ -1,7 +1,5 @@
 use super::{deserialize_single_recipient, serialize_single_recipient};
-use crate::internal::SyncRwLock;
 use crate::model::prelude::*;
-use chrono::{DateTime, FixedOffset};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 #[cfg(feature = "model")]
@@ -24,7 +22,7 @@ pub struct PrivateChannel {
     /// Timestamp of the last time a [`Message`] was pinned.
     ///
     /// [`Message`]: struct.Message.html
-    pub last_pin_timestamp: Option<DateTime<FixedOffset>>,
+    pub last_pin_timestamp: Option<Timestamp>,
     /// Indicator of the type of channel this is.
     ///
     /// This should always be [`ChannelType::Private`].
@@ -38,7 +36,7 @@ pub struct PrivateChannel {
         serialize_with = "serialize_single_recipient",
         rename = "recipients"
     )]
-    pub recipient: Arc<SyncRwLock<User>>,
+    pub recipient: User,
 }
 
 #[cfg(feature = "model")]
@@ -231,7 +229,7 @@ impl PrivateChannel {
 
     /// Returns "DM with $username#discriminator".
     pub fn name(&self) -> String {
-        format!("DM with {}", self.recipient.read().tag())
+        format!("DM with {}", self.recipient.tag())
     }
 
     /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
@@ -384,6 +382,6 @@ impl PrivateChannel {
 impl Display for PrivateChannel {
     /// Formats the private channel, displaying the recipient's username.
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.write_str(&self.recipient.read().name)
+        f.write_str(&self.recipient.name)
     }
 }
@@ -80,17 +80,14 @@ impl Reaction {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                if self.user_id == cache.read().await.user.id {
+                if self.user_id == cache.current_user_id() {
                     user_id = None;
                 }
 
                 if user_id.is_some() {
                     let req = Permissions::MANAGE_MESSAGES;
 
-                    if !utils::user_has_perms(cache, self.channel_id, None, req)
-                        .await
-                        .unwrap_or(true)
-                    {
+                    if !utils::user_has_perms(cache, self.channel_id, None, req).unwrap_or(true) {
                         return Err(Error::Model(ModelError::InvalidPermissions(req)));
                     }
                 }
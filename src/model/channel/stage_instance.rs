@@ -0,0 +1,75 @@
+#[cfg(all(feature = "model", feature = "http"))]
+use crate::http::Http;
+#[cfg(all(feature = "model", feature = "http"))]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A live stage in a [stage channel], holding information about the event
+/// currently taking place in it.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/stage-instance#stage-instance-object).
+///
+/// [stage channel]: enum.ChannelType.html#variant.Stage
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct StageInstance {
+    /// The Id of the stage instance.
+    pub id: StageInstanceId,
+    /// The Id of the guild of the stage channel.
+    pub guild_id: GuildId,
+    /// The Id of the stage channel.
+    pub channel_id: ChannelId,
+    /// The topic of the stage, between 1 and 120 characters long.
+    pub topic: String,
+    /// Who can see the stage.
+    pub privacy_level: StagePrivacyLevel,
+    /// Whether the stage is hidden from stage discovery.
+    #[serde(default)]
+    pub discoverable_disabled: bool,
+}
+
+enum_number! {
+    /// Who can see a [`StageInstance`].
+    ///
+    /// [`StageInstance`]: struct.StageInstance.html
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    #[non_exhaustive]
+    pub enum StagePrivacyLevel {
+        /// The stage is visible publicly, such as in stage discovery.
+        Public = 1,
+        /// The stage is only visible to members of the guild.
+        GuildOnly = 2,
+    }
+}
+
+#[cfg(all(feature = "model", feature = "http"))]
+impl StageInstance {
+    /// Changes the topic of the stage in-place.
+    ///
+    /// **Note**: Requires the current user to be a moderator of the stage,
+    /// through the [Manage Channels], [Mute Members] and [Move Members]
+    /// permissions.
+    ///
+    /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    /// [Move Members]: ../permissions/struct.Permissions.html#associatedconstant.MOVE_MEMBERS
+    /// [Mute Members]: ../permissions/struct.Permissions.html#associatedconstant.MUTE_MEMBERS
+    pub async fn edit(&mut self, http: impl AsRef<Http>, topic: &str) -> Result<()> {
+        *self = self.channel_id.edit_stage_instance(http, topic).await?;
+
+        Ok(())
+    }
+
+    /// Ends the stage.
+    ///
+    /// **Note**: Requires the current user to be a moderator of the stage,
+    /// through the [Manage Channels], [Mute Members] and [Move Members]
+    /// permissions.
+    ///
+    /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    /// [Move Members]: ../permissions/struct.Permissions.html#associatedconstant.MOVE_MEMBERS
+    /// [Mute Members]: ../permissions/struct.Permissions.html#associatedconstant.MUTE_MEMBERS
+    #[inline]
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<()> {
+        self.channel_id.delete_stage_instance(http).await
+    }
+}
@@ -134,6 +134,11 @@ pub enum Error {
     /// Indicates that the current user is attempting to Direct Message another
     /// bot user, which is disallowed by the API.
     MessagingBot,
+    /// Indicates that an embed references an image as `attachment://filename`,
+    /// but no file with that filename is attached to the message.
+    ///
+    /// The referenced filename is provided.
+    MissingAttachment(String),
     /// An indicator that the [`ChannelType`] cannot perform an action.
     ///
     /// [`ChannelType`]: ../channel/enum.ChannelType.html
@@ -155,6 +160,9 @@ impl Display for Error {
             Error::ItemMissing => f.write_str("The required item is missing from the cache."),
             Error::MessageTooLong(_) => f.write_str("Message too large."),
             Error::MessagingBot => f.write_str("Attempted to message another bot user."),
+            Error::MissingAttachment(_) => {
+                f.write_str("An embed references a file which is not attached.")
+            }
         }
     }
 }
@@ -5,18 +5,20 @@ use super::utils::{deserialize_emojis, deserialize_u64};
 use crate::constants::{OpCode, VoiceOpCode};
 use crate::internal::prelude::*;
 use async_trait::async_trait;
-use chrono::{DateTime, FixedOffset};
 use serde::de::Error as DeError;
 use serde::ser::{Serialize, SerializeSeq, Serializer};
 use std::collections::HashMap;
 
 #[cfg(feature = "cache")]
 use crate::cache::{Cache, CacheUpdate};
-use crate::internal::{AsyncRwLock, SyncRwLock};
 #[cfg(feature = "cache")]
-use std::collections::hash_map::Entry;
+use dashmap::mapref::entry::Entry;
+#[cfg(feature = "cache")]
+use std::collections::HashSet;
 #[cfg(feature = "cache")]
 use std::mem;
+#[cfg(feature = "cache")]
+use std::sync::atomic::Ordering;
 
 /// Event data for the channel creation event.
 ///
@@ -59,75 +61,48 @@ impl Serialize for ChannelCreateEvent {
 impl CacheUpdate for ChannelCreateEvent {
     type Output = Channel;
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<Self::Output> {
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         match self.channel {
             Channel::Group(ref group) => {
-                let group = Arc::clone(group);
-
-                let channel_id = {
-                    let mut writer = group.write().await;
-
-                    for (recipient_id, recipient) in &mut writer.recipients {
-                        cache.update_user_entry(&*recipient.read());
-
-                        *recipient = Arc::clone(&cache.users[recipient_id]);
-                    }
-
-                    writer.channel_id
-                };
-
-                let ch = cache.groups.insert(channel_id, group);
+                for recipient in group.recipients.values() {
+                    cache.update_user_entry(recipient);
+                }
 
-                ch.map(Channel::Group)
+                cache
+                    .groups
+                    .insert(group.channel_id, group.clone())
+                    .map(Channel::Group)
             }
             Channel::Guild(ref channel) => {
-                let (guild_id, channel_id) = {
-                    let guard = channel.read().await;
-                    (guard.guild_id, guard.id)
-                };
-
-                cache.channels.insert(channel_id, Arc::clone(channel));
-
-                if let Some(entry) = cache.guilds.get_mut(&guild_id) {
-                    entry
-                        .write()
-                        .await
-                        .channels
-                        .insert(channel_id, Arc::clone(channel))
-                        .map(Channel::Guild)
-                } else {
-                    None
-                }
+                cache.insert_channel(channel.clone());
+
+                cache
+                    .guilds
+                    .get_mut(&channel.guild_id)
+                    .and_then(|mut guild| {
+                        guild
+                            .channels
+                            .insert(channel.id, channel.clone())
+                            .map(Channel::Guild)
+                    })
             }
             Channel::Private(ref channel) => {
-                let tmp_id = channel.read().await.id;
-
-                if let Some(channel) = cache.private_channels.get(&tmp_id) {
-                    return Some(Channel::Private(Arc::clone(&(*channel))));
+                if let Some(channel) = cache.private_channels.get(&channel.id) {
+                    return Some(Channel::Private(channel.clone()));
                 }
 
-                let channel = Arc::clone(channel);
-
-                let id = {
-                    let mut guard = channel.write().await;
-                    let user_id = {
-                        let c_user = guard.recipient.read();
-                        cache.update_user_entry(&*c_user);
-
-                        c_user.id
-                    };
+                cache.update_user_entry(&channel.recipient);
 
-                    guard.recipient = Arc::clone(&cache.users[&user_id]);
-                    guard.id
-                };
-
-                let ch = cache.private_channels.insert(id, Arc::clone(&channel));
-                ch.map(Channel::Private)
+                cache
+                    .private_channels
+                    .insert(channel.id, channel.clone())
+                    .map(Channel::Private)
             }
             Channel::Category(ref category) => cache
                 .categories
-                .insert(category.read().await.id, Arc::clone(category))
+                .insert(category.id, category.clone())
                 .map(Channel::Category),
+            Channel::Thread(ref thread) => cache.insert_thread(thread.clone()).map(Channel::Thread),
         }
     }
 }
@@ -143,29 +118,23 @@ pub struct ChannelDeleteEvent {
 impl CacheUpdate for ChannelDeleteEvent {
     type Output = ();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
         match self.channel {
             Channel::Guild(ref channel) => {
-                let (guild_id, channel_id) = {
-                    let guard = channel.read().await;
-                    (guard.guild_id, guard.id)
-                };
-
-                cache.channels.remove(&channel_id);
+                cache.remove_channel(channel.id);
 
-                if let Some(entry) = cache.guilds.get_mut(&guild_id) {
-                    entry.write().await.channels.remove(&channel_id);
+                if let Some(mut guild) = cache.guilds.get_mut(&channel.guild_id) {
+                    guild.channels.remove(&channel.id);
                 }
             }
             Channel::Category(ref category) => {
-                let channel_id = category.read().await.id;
-
-                cache.categories.remove(&channel_id);
+                cache.categories.remove(&category.id);
             }
             Channel::Private(ref channel) => {
-                let id = { channel.read().await.id };
-
-                cache.private_channels.remove(&id);
+                cache.private_channels.remove(&channel.id);
+            }
+            Channel::Thread(ref thread) => {
+                cache.remove_thread(thread.guild_id, thread.id);
             }
 
             // We ignore these because the delete event does not fire for these.
@@ -173,7 +142,9 @@ impl CacheUpdate for ChannelDeleteEvent {
         };
 
         // Remove the cached messages for the channel.
-        cache.messages.remove(&self.channel.id().await);
+        let channel_id = self.channel.id();
+        cache.messages.remove(&channel_id);
+        cache.message_queue.remove(&channel_id);
 
         None
     }
@@ -201,7 +172,7 @@ impl Serialize for ChannelDeleteEvent {
 pub struct ChannelPinsUpdateEvent {
     pub guild_id: Option<GuildId>,
     pub channel_id: ChannelId,
-    pub last_pin_timestamp: Option<DateTime<FixedOffset>>,
+    pub last_pin_timestamp: Option<Timestamp>,
 }
 
 #[cfg(feature = "cache")]
@@ -209,21 +180,32 @@ pub struct ChannelPinsUpdateEvent {
 impl CacheUpdate for ChannelPinsUpdateEvent {
     type Output = ();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
-        if let Some(channel) = cache.channels.get(&self.channel_id) {
-            channel.write().await.last_pin_timestamp = self.last_pin_timestamp;
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
+        // The reference into one map is released before another map is
+        // locked, so that concurrent updates can not deadlock.
+        let guild_id = cache.channels.get_mut(&self.channel_id).map(|mut channel| {
+            channel.last_pin_timestamp = self.last_pin_timestamp;
+            channel.guild_id
+        });
+
+        if let Some(guild_id) = guild_id {
+            if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
+                if let Some(channel) = guild.channels.get_mut(&self.channel_id) {
+                    channel.last_pin_timestamp = self.last_pin_timestamp;
+                }
+            }
 
             return None;
         }
 
-        if let Some(channel) = cache.private_channels.get_mut(&self.channel_id) {
-            channel.write().await.last_pin_timestamp = self.last_pin_timestamp;
+        if let Some(mut channel) = cache.private_channels.get_mut(&self.channel_id) {
+            channel.last_pin_timestamp = self.last_pin_timestamp;
 
             return None;
         }
 
-        if let Some(group) = cache.groups.get_mut(&self.channel_id) {
-            group.write().await.last_pin_timestamp = self.last_pin_timestamp;
+        if let Some(mut group) = cache.groups.get_mut(&self.channel_id) {
+            group.last_pin_timestamp = self.last_pin_timestamp;
 
             return None;
         }
@@ -244,12 +226,11 @@ pub struct ChannelRecipientAddEvent {
 impl CacheUpdate for ChannelRecipientAddEvent {
     type Output = ();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
         cache.update_user_entry(&self.user);
-        let user = Arc::clone(&cache.users[&self.user.id]);
 
-        if let Some(group) = cache.groups.get_mut(&self.channel_id) {
-            group.write().await.recipients.insert(self.user.id, user);
+        if let Some(mut group) = cache.groups.get_mut(&self.channel_id) {
+            group.recipients.insert(self.user.id, self.user.clone());
         }
 
         None
@@ -268,14 +249,10 @@ pub struct ChannelRecipientRemoveEvent {
 impl CacheUpdate for ChannelRecipientRemoveEvent {
     type Output = ();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
-        match cache.groups.get_mut(&self.channel_id) {
-            Some(group) => {
-                let mut group = group.write().await;
-                group.recipients.remove(&self.user.id)
-            }
-            None => None,
-        };
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
+        if let Some(mut group) = cache.groups.get_mut(&self.channel_id) {
+            group.recipients.remove(&self.user.id);
+        }
 
         None
     }
@@ -290,61 +267,52 @@ pub struct ChannelUpdateEvent {
 #[cfg(feature = "cache")]
 #[async_trait]
 impl CacheUpdate for ChannelUpdateEvent {
-    type Output = ();
+    type Output = Channel;
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
+    async fn update(&mut self, cache: &Cache) -> Option<Channel> {
         match self.channel {
-            Channel::Group(ref group) => {
-                let (ch_id, no_recipients) = {
-                    let guard = group.read().await;
-                    (guard.channel_id, guard.recipients.is_empty())
-                };
-
-                match cache.groups.entry(ch_id) {
-                    Entry::Vacant(e) => {
-                        e.insert(Arc::clone(group));
-                    }
-                    Entry::Occupied(mut e) => {
-                        let mut dest = e.get_mut().write().await;
+            Channel::Group(ref group) => match cache.groups.entry(group.channel_id) {
+                Entry::Vacant(e) => {
+                    e.insert(group.clone());
+
+                    None
+                }
+                Entry::Occupied(mut e) => {
+                    let dest = e.get_mut();
+                    let old = dest.clone();
 
-                        if no_recipients {
-                            let recipients = mem::replace(&mut dest.recipients, HashMap::new());
+                    if group.recipients.is_empty() {
+                        let recipients = mem::replace(&mut dest.recipients, HashMap::new());
 
-                            dest.clone_from(&group.read().await.clone());
+                        dest.clone_from(group);
 
-                            dest.recipients = recipients;
-                        } else {
-                            dest.clone_from(&group.read().await.clone());
-                        }
+                        dest.recipients = recipients;
+                    } else {
+                        dest.clone_from(group);
                     }
+
+                    Some(Channel::Group(old))
                 }
-            }
+            },
             Channel::Guild(ref channel) => {
-                let (guild_id, channel_id) = {
-                    let guard = channel.read().await;
-                    (guard.guild_id, guard.id)
-                };
+                let old = cache.insert_channel(channel.clone());
 
-                cache.channels.insert(channel_id, Arc::clone(channel));
-
-                if let Some(guild) = cache.guilds.get_mut(&guild_id) {
-                    let mut guild = guild.write().await;
-                    guild.channels.insert(channel_id, Arc::clone(channel));
-                }
-            }
-            Channel::Private(ref channel) => {
-                if let Some(private) = cache.private_channels.get_mut(&channel.read().await.id) {
-                    private.clone_from(channel);
-                }
-            }
-            Channel::Category(ref category) => {
-                if let Some(c) = cache.categories.get_mut(&category.read().await.id) {
-                    c.clone_from(category)
+                if let Some(mut guild) = cache.guilds.get_mut(&channel.guild_id) {
+                    guild.channels.insert(channel.id, channel.clone());
                 }
+
+                old.map(Channel::Guild)
             }
+            Channel::Private(ref channel) => cache
+                .private_channels
+                .get_mut(&channel.id)
+                .map(|mut private| Channel::Private(mem::replace(&mut *private, channel.clone()))),
+            Channel::Category(ref category) => cache
+                .categories
+                .get_mut(&category.id)
+                .map(|mut c| Channel::Category(mem::replace(&mut *c, category.clone()))),
+            Channel::Thread(ref thread) => cache.insert_thread(thread.clone()).map(Channel::Thread),
         }
-
-        None
     }
 }
 
@@ -390,22 +358,9 @@ pub struct GuildCreateEvent {
 impl CacheUpdate for GuildCreateEvent {
     type Output = ();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
-        cache.unavailable_guilds.remove(&self.guild.id);
-
-        let mut guild = self.guild.clone();
-
-        for (user_id, member) in &mut guild.members {
-            cache.update_user_entry(&*member.user.read());
-            let user = Arc::clone(&cache.users[user_id]);
-
-            member.user = Arc::clone(&user);
-        }
-
-        cache.channels.extend(guild.channels.clone());
-        cache
-            .guilds
-            .insert(self.guild.id, Arc::new(AsyncRwLock::new(guild)));
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
+        cache.insert_guild(self.guild.clone());
+        cache.check_startup();
 
         None
     }
@@ -437,18 +392,46 @@ pub struct GuildDeleteEvent {
 #[cfg(feature = "cache")]
 #[async_trait]
 impl CacheUpdate for GuildDeleteEvent {
-    type Output = Arc<AsyncRwLock<Guild>>;
+    type Output = Guild;
+
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        // The bot may have been removed from a guild listed in the Ready while
+        // offline, in which case this is received instead of its GuildCreate.
+        cache.unavailable_guilds.remove(&self.guild.id);
+        cache.check_startup();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<Self::Output> {
         // Remove channel entries for the guild if the guild is found.
-        match cache.guilds.remove(&self.guild.id) {
+        match cache.remove_guild(self.guild.id) {
             Some(guild) => {
-                for channel_id in guild.write().await.channels.keys() {
+                for channel_id in guild.channels.keys() {
                     // Remove the channel from the cache.
-                    cache.channels.remove(channel_id);
+                    cache.remove_channel(*channel_id);
 
                     // Remove the channel's cached messages.
                     cache.messages.remove(channel_id);
+                    cache.message_queue.remove(channel_id);
+                }
+
+                for thread_id in guild.threads.keys() {
+                    cache.remove_thread(guild.id, *thread_id);
+                }
+
+                // Drop the presence timestamps of the guild's members, unless
+                // their presence is still cached elsewhere.
+                let mut user_ids = guild
+                    .members
+                    .keys()
+                    .chain(guild.presences.keys())
+                    .filter(|user_id| !cache.presences.contains_key(user_id))
+                    .copied()
+                    .collect::<HashSet<_>>();
+
+                for other in cache.guilds.iter() {
+                    user_ids.retain(|user_id| !other.presences.contains_key(user_id));
+                }
+
+                for user_id in &user_ids {
+                    cache.presence_timestamps.remove(user_id);
                 }
 
                 Some(guild)
@@ -491,10 +474,13 @@ pub struct GuildEmojisUpdateEvent {
 impl CacheUpdate for GuildEmojisUpdateEvent {
     type Output = ();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
-        if let Some(guild) = cache.guilds.get_mut(&self.guild_id) {
-            let mut guild = guild.write().await;
-            guild.emojis.clone_from(&self.emojis)
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings().cache_emojis {
+            return None;
+        }
+
+        if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
+            guild.emojis.clone_from(&self.emojis);
         }
 
         None
@@ -519,15 +505,11 @@ pub struct GuildMemberAddEvent {
 impl CacheUpdate for GuildMemberAddEvent {
     type Output = ();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
-        let user_id = self.member.user.read().id;
-        cache.update_user_entry(&*self.member.user.read());
-
-        // Always safe due to being inserted above.
-        self.member.user = Arc::clone(&cache.users[&user_id]);
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
+        let user_id = self.member.user.id;
+        cache.update_user_entry(&self.member.user);
 
-        if let Some(guild) = cache.guilds.get_mut(&self.guild_id) {
-            let mut guild = guild.write().await;
+        if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
             guild.member_count += 1;
             guild.members.insert(user_id, self.member.clone());
         }
@@ -582,15 +564,11 @@ pub struct GuildMemberRemoveEvent {
 impl CacheUpdate for GuildMemberRemoveEvent {
     type Output = Member;
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<Self::Output> {
-        match cache.guilds.get_mut(&self.guild_id) {
-            Some(guild) => {
-                let mut guild = guild.write().await;
-                guild.member_count -= 1;
-                guild.members.remove(&self.user.id)
-            }
-            None => None,
-        }
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache.guilds.get_mut(&self.guild_id).and_then(|mut guild| {
+            guild.member_count -= 1;
+            guild.members.remove(&self.user.id)
+        })
     }
 }
 
@@ -608,47 +586,35 @@ pub struct GuildMemberUpdateEvent {
 impl CacheUpdate for GuildMemberUpdateEvent {
     type Output = Member;
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<Self::Output> {
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         cache.update_user_entry(&self.user);
 
-        if let Some(guild) = cache.guilds.get_mut(&self.guild_id) {
-            let mut guild = guild.write().await;
+        let mut guild = cache.guilds.get_mut(&self.guild_id)?;
 
-            let mut found = false;
+        if let Some(member) = guild.members.get_mut(&self.user.id) {
+            let item = member.clone();
 
-            let item = if let Some(member) = guild.members.get_mut(&self.user.id) {
-                let item = Some(member.clone());
+            member.nick.clone_from(&self.nick);
+            member.roles.clone_from(&self.roles);
+            member.user.clone_from(&self.user);
 
-                member.nick.clone_from(&self.nick);
-                member.roles.clone_from(&self.roles);
-                member.user = Arc::new(SyncRwLock::new(self.user.clone()));
+            return Some(item);
+        }
 
-                found = true;
+        guild.members.insert(
+            self.user.id,
+            Member {
+                deaf: false,
+                guild_id: self.guild_id,
+                joined_at: None,
+                mute: false,
+                nick: self.nick.clone(),
+                roles: self.roles.clone(),
+                user: self.user.clone(),
+            },
+        );
 
-                item
-            } else {
-                None
-            };
-
-            if !found {
-                guild.members.insert(
-                    self.user.id,
-                    Member {
-                        deaf: false,
-                        guild_id: self.guild_id,
-                        joined_at: None,
-                        mute: false,
-                        nick: self.nick.clone(),
-                        roles: self.roles.clone(),
-                        user: Arc::new(SyncRwLock::new(self.user.clone())),
-                    },
-                );
-            }
-
-            item
-        } else {
-            None
-        }
+        None
     }
 }
 
@@ -664,14 +630,13 @@ pub struct GuildMembersChunkEvent {
 impl CacheUpdate for GuildMembersChunkEvent {
     type Output = ();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
         for member in self.members.values() {
-            cache.update_user_entry(&*member.user.read());
+            cache.update_user_entry(&member.user);
         }
 
-        if let Some(guild) = cache.guilds.get_mut(&self.guild_id) {
-            let mut guild = guild.write().await;
-            guild.members.extend(self.members.clone())
+        if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
+            guild.members.extend(self.members.clone());
         }
 
         None
@@ -705,7 +670,7 @@ impl<'de> Deserialize<'de> for GuildMembersChunkEvent {
         let members = serde_json::from_value::<Vec<Member>>(members)
             .map(|members| {
                 members.into_iter().fold(HashMap::new(), |mut acc, member| {
-                    let id = member.user.read().id;
+                    let id = member.user.id;
 
                     acc.insert(id, member);
 
@@ -730,13 +695,10 @@ pub struct GuildRoleCreateEvent {
 impl CacheUpdate for GuildRoleCreateEvent {
     type Output = ();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
-        if let Some(guild) = cache.guilds.get_mut(&self.guild_id) {
-            guild
-                .write()
-                .await
-                .roles
-                .insert(self.role.id, self.role.clone());
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
+        if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
+            let old = guild.roles.insert(self.role.id, self.role.clone());
+            cache.index_role(self.guild_id, &self.role, old.as_ref());
         }
 
         None
@@ -755,11 +717,14 @@ pub struct GuildRoleDeleteEvent {
 impl CacheUpdate for GuildRoleDeleteEvent {
     type Output = Role;
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<Self::Output> {
-        match cache.guilds.get_mut(&self.guild_id) {
-            Some(entry) => entry.write().await.roles.remove(&self.role_id),
-            None => None,
-        }
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let role = cache
+            .guilds
+            .get_mut(&self.guild_id)
+            .and_then(|mut guild| guild.roles.remove(&self.role_id))?;
+        cache.unindex_role(self.guild_id, &role);
+
+        Some(role)
     }
 }
 
@@ -775,17 +740,16 @@ pub struct GuildRoleUpdateEvent {
 impl CacheUpdate for GuildRoleUpdateEvent {
     type Output = Role;
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<Self::Output> {
-        match cache.guilds.get_mut(&self.guild_id) {
-            Some(guild) => {
-                let mut guild = guild.write().await;
-                guild
-                    .roles
-                    .get_mut(&self.role.id)
-                    .map(|role| mem::replace(role, self.role.clone()))
-            }
-            None => None,
-        }
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let old = cache.guilds.get_mut(&self.guild_id).and_then(|mut guild| {
+            guild
+                .roles
+                .get_mut(&self.role.id)
+                .map(|role| mem::replace(role, self.role.clone()))
+        })?;
+        cache.index_role(self.guild_id, &self.role, Some(&old));
+
+        Some(old)
     }
 }
 
@@ -801,9 +765,9 @@ pub struct GuildUnavailableEvent {
 impl CacheUpdate for GuildUnavailableEvent {
     type Output = ();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
         cache.unavailable_guilds.insert(self.guild_id);
-        cache.guilds.remove(&self.guild_id);
+        cache.remove_guild(self.guild_id);
 
         None
     }
@@ -818,23 +782,16 @@ pub struct GuildUpdateEvent {
 #[cfg(feature = "cache")]
 #[async_trait]
 impl CacheUpdate for GuildUpdateEvent {
-    type Output = ();
+    type Output = Guild;
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
-        if let Some(guild) = cache.guilds.get_mut(&self.guild.id) {
-            let mut guild = guild.write().await;
-
-            guild.afk_timeout = self.guild.afk_timeout;
-            guild.afk_channel_id.clone_from(&self.guild.afk_channel_id);
-            guild.icon.clone_from(&self.guild.icon);
-            guild.name.clone_from(&self.guild.name);
-            guild.owner_id.clone_from(&self.guild.owner_id);
-            guild.region.clone_from(&self.guild.region);
-            guild.roles.clone_from(&self.guild.roles);
-            guild.verification_level = self.guild.verification_level;
-        }
+    async fn update(&mut self, cache: &Cache) -> Option<Guild> {
+        let mut guild = cache.guilds.get_mut(&self.guild.id)?;
+        let old = guild.clone();
 
-        None
+        guild.merge_partial(&self.guild);
+        cache.index_roles(guild.id, &guild.roles);
+
+        Some(old)
     }
 }
 
@@ -867,27 +824,45 @@ impl CacheUpdate for MessageCreateEvent {
     /// The oldest message, if the channel's message cache was already full.
     type Output = Message;
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<Self::Output> {
-        let max = cache.settings().max_messages;
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let channel_id = self.message.channel_id;
+        let message_id = Some(self.message.id);
+
+        let guild_id = cache.channels.get_mut(&channel_id).map(|mut channel| {
+            channel.last_message_id = message_id;
+            channel.guild_id
+        });
+
+        if let Some(guild_id) = guild_id {
+            if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
+                if let Some(channel) = guild.channels.get_mut(&channel_id) {
+                    channel.last_message_id = message_id;
+                }
+            }
+        } else if let Some(mut channel) = cache.private_channels.get_mut(&channel_id) {
+            channel.last_message_id = message_id;
+        } else if let Some(mut group) = cache.groups.get_mut(&channel_id) {
+            group.last_message_id = message_id;
+        }
+
+        let max = cache.settings().max_messages_for(channel_id);
 
         if max == 0 {
             return None;
         }
 
-        let messages = cache
-            .messages
-            .entry(self.message.channel_id)
-            .or_insert_with(Default::default);
-        let queue = cache
-            .message_queue
-            .entry(self.message.channel_id)
-            .or_insert_with(Default::default);
+        // The queue is locked first, keeping it in sync with the messages.
+        let mut queue = cache.message_queue.entry(channel_id).or_default();
+        let mut messages = cache.messages.entry(channel_id).or_default();
 
         let mut removed_msg = None;
 
-        if messages.len() == max {
-            if let Some(id) = queue.pop_front() {
-                removed_msg = messages.remove(&id);
+        // The limit for a channel may have been lowered since messages were
+        // cached, so evict until there is room for the new message.
+        while messages.len() >= max {
+            match queue.pop_front() {
+                Some(id) => removed_msg = messages.remove(&id),
+                None => break,
             }
         }
 
@@ -923,6 +898,29 @@ pub struct MessageDeleteBulkEvent {
     pub ids: Vec<MessageId>,
 }
 
+#[cfg(feature = "cache")]
+#[async_trait]
+impl CacheUpdate for MessageDeleteBulkEvent {
+    /// The deleted messages which were in the cache.
+    type Output = Vec<Message>;
+
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        // The queue is locked first, keeping it in sync with the messages.
+        let mut queue = cache.message_queue.get_mut(&self.channel_id)?;
+        let mut messages = cache.messages.get_mut(&self.channel_id)?;
+
+        let removed = self
+            .ids
+            .iter()
+            .filter_map(|id| messages.remove(id))
+            .collect::<Vec<_>>();
+
+        queue.retain(|id| messages.contains_key(id));
+
+        Some(removed)
+    }
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct MessageDeleteEvent {
@@ -943,8 +941,8 @@ pub struct MessageUpdateEvent {
     pub nonce: Option<String>,
     pub tts: Option<bool>,
     pub pinned: Option<bool>,
-    pub timestamp: Option<DateTime<FixedOffset>>,
-    pub edited_timestamp: Option<DateTime<FixedOffset>>,
+    pub timestamp: Option<Timestamp>,
+    pub edited_timestamp: Option<Timestamp>,
     pub author: Option<User>,
     pub mention_everyone: Option<bool>,
     pub mentions: Option<Vec<User>>,
@@ -958,8 +956,8 @@ pub struct MessageUpdateEvent {
 impl CacheUpdate for MessageUpdateEvent {
     type Output = Message;
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<Self::Output> {
-        if let Some(messages) = cache.messages.get_mut(&self.channel_id) {
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if let Some(mut messages) = cache.messages.get_mut(&self.channel_id) {
             if let Some(message) = messages.get_mut(&self.id) {
                 let item = message.clone();
 
@@ -1012,25 +1010,24 @@ pub struct PresenceUpdateEvent {
 impl CacheUpdate for PresenceUpdateEvent {
     type Output = ();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
-        let user_id = self.presence.user_id;
-
-        if let Some(user) = self.presence.user.as_mut() {
-            cache.update_user_entry(&*user.read());
-            *user = Arc::clone(&cache.users[&user_id]);
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
+        if let Some(user) = self.presence.user.as_ref() {
+            cache.update_user_entry(user);
         }
 
-        if let Some(guild_id) = self.guild_id {
-            if let Some(guild) = cache.guilds.get_mut(&guild_id) {
-                let mut guild = guild.write().await;
+        let cache_presences = cache.settings().cache_presences;
 
+        if let Some(guild_id) = self.guild_id {
+            if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
                 // If the member went offline, remove them from the presence list.
-                if self.presence.status == OnlineStatus::Offline {
-                    guild.presences.remove(&self.presence.user_id);
-                } else {
-                    guild
-                        .presences
-                        .insert(self.presence.user_id, self.presence.clone());
+                if cache_presences {
+                    if self.presence.status == OnlineStatus::Offline {
+                        guild.presences.remove(&self.presence.user_id);
+                    } else {
+                        let presence = cache.prepare_presence(&self.presence);
+
+                        guild.presences.insert(self.presence.user_id, presence);
+                    }
                 }
 
                 // Create a partial member instance out of the presence update
@@ -1048,19 +1045,22 @@ impl CacheUpdate for PresenceUpdateEvent {
                                 joined_at: None,
                                 mute: false,
                                 nick: self.presence.nick.clone(),
-                                user: Arc::clone(user),
+                                user: user.clone(),
                                 roles,
                             },
                         );
                     }
                 }
             }
-        } else if self.presence.status == OnlineStatus::Offline {
-            cache.presences.remove(&self.presence.user_id);
-        } else {
-            cache
-                .presences
-                .insert(self.presence.user_id, self.presence.clone());
+        } else if cache_presences {
+            if self.presence.status == OnlineStatus::Offline {
+                cache.presences.remove(&self.presence.user_id);
+                cache.presence_timestamps.remove(&self.presence.user_id);
+            } else {
+                let presence = cache.prepare_presence(&self.presence);
+
+                cache.presences.insert(self.presence.user_id, presence);
+            }
         }
 
         None
@@ -1100,16 +1100,16 @@ pub struct PresencesReplaceEvent {
 impl CacheUpdate for PresencesReplaceEvent {
     type Output = ();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
-        cache.presences.extend({
-            let mut p: HashMap<UserId, Presence> = HashMap::default();
-
-            for presence in &self.presences {
-                p.insert(presence.user_id, presence.clone());
-            }
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings().cache_presences {
+            return None;
+        }
 
-            p
-        });
+        for presence in &self.presences {
+            cache
+                .presences
+                .insert(presence.user_id, cache.prepare_presence(presence));
+        }
 
         None
     }
@@ -1204,20 +1204,19 @@ pub struct ReadyEvent {
 impl CacheUpdate for ReadyEvent {
     type Output = ();
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<()> {
-        let mut ready = self.ready.clone();
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
+        let ready = self.ready.clone();
 
         for guild in ready.guilds {
             match guild {
                 GuildStatus::Offline(unavailable) => {
-                    cache.guilds.remove(&unavailable.id);
+                    cache.remove_guild(unavailable.id);
                     cache.unavailable_guilds.insert(unavailable.id);
                 }
-                GuildStatus::OnlineGuild(guild) => {
+                GuildStatus::OnlineGuild(mut guild) => {
+                    cache.strip_guild(&mut guild);
                     cache.unavailable_guilds.remove(&guild.id);
-                    cache
-                        .guilds
-                        .insert(guild.id, Arc::new(AsyncRwLock::new(guild)));
+                    cache.replace_guild(guild);
                 }
                 GuildStatus::OnlinePartialGuild(_) => {}
             }
@@ -1226,16 +1225,32 @@ impl CacheUpdate for ReadyEvent {
         // `ready.private_channels` will always be empty, and possibly be removed in the future.
         // So don't handle it at all.
 
-        for (user_id, presence) in &mut ready.presences {
-            if let Some(ref user) = presence.user {
-                cache.update_user_entry(&*user.read());
+        for presence in ready.presences.values() {
+            if let Some(user) = presence.user.as_ref() {
+                cache.update_user_entry(user);
             }
-            presence.user = cache.user(user_id);
         }
 
-        cache.presences.extend(ready.presences);
-        cache.shard_count = ready.shard.map_or(1, |s| s[1]);
-        cache.user = ready.user;
+        if cache.settings().cache_presences {
+            let compact = cache.settings().compact_presences;
+
+            for (user_id, mut presence) in ready.presences {
+                if compact {
+                    presence.compact();
+                }
+
+                cache.touch_presence(user_id);
+                cache.presences.insert(user_id, presence);
+            }
+        }
+
+        cache
+            .shard_count
+            .store(ready.shard.map_or(1, |s| s[1]), Ordering::Relaxed);
+        cache.ready_shards.insert(ready.shard.map_or(0, |s| s[0]));
+        *cache.user.write() = ready.user;
+        cache.restart_startup_timeout();
+        cache.check_startup();
 
         None
     }
@@ -1265,6 +1280,108 @@ pub struct ResumedEvent {
     pub trace: Vec<Option<String>>,
 }
 
+/// Event data for the thread create event.
+///
+/// This is fired when a thread is created, or when the current user is added
+/// to a private thread.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ThreadCreateEvent {
+    /// The thread that was created.
+    pub thread: GuildThread,
+}
+
+#[cfg(feature = "cache")]
+#[async_trait]
+impl CacheUpdate for ThreadCreateEvent {
+    type Output = GuildThread;
+
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache.insert_thread(self.thread.clone())
+    }
+}
+
+impl<'de> Deserialize<'de> for ThreadCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            thread: GuildThread::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for ThreadCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        GuildThread::serialize(&self.thread, serializer)
+    }
+}
+
+/// Event data for the thread update event.
+///
+/// This is also fired when a thread is archived or unarchived.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ThreadUpdateEvent {
+    /// The updated thread.
+    pub thread: GuildThread,
+}
+
+#[cfg(feature = "cache")]
+#[async_trait]
+impl CacheUpdate for ThreadUpdateEvent {
+    type Output = GuildThread;
+
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache.insert_thread(self.thread.clone())
+    }
+}
+
+impl<'de> Deserialize<'de> for ThreadUpdateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            thread: GuildThread::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for ThreadUpdateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        GuildThread::serialize(&self.thread, serializer)
+    }
+}
+
+/// Event data for the thread delete event.
+///
+/// Only the Ids and type of the deleted thread are sent.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ThreadDeleteEvent {
+    /// The Id of the deleted thread.
+    pub id: ChannelId,
+    /// The Id of the guild the thread was in.
+    pub guild_id: GuildId,
+    /// The Id of the channel the thread was started in.
+    pub parent_id: ChannelId,
+    /// The type of the thread.
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+}
+
+#[cfg(feature = "cache")]
+#[async_trait]
+impl CacheUpdate for ThreadDeleteEvent {
+    type Output = GuildThread;
+
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache.remove_thread(self.guild_id, self.id)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct TypingStartEvent {
@@ -1292,8 +1409,11 @@ pub struct UserUpdateEvent {
 impl CacheUpdate for UserUpdateEvent {
     type Output = CurrentUser;
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<Self::Output> {
-        Some(mem::replace(&mut cache.user, self.current_user.clone()))
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        Some(mem::replace(
+            &mut *cache.user.write(),
+            self.current_user.clone(),
+        ))
     }
 }
 
@@ -1335,11 +1455,13 @@ pub struct VoiceStateUpdateEvent {
 impl CacheUpdate for VoiceStateUpdateEvent {
     type Output = VoiceState;
 
-    async fn update(&mut self, cache: &mut Cache) -> Option<VoiceState> {
-        if let Some(guild_id) = self.guild_id {
-            if let Some(guild) = cache.guilds.get_mut(&guild_id) {
-                let mut guild = guild.write().await;
+    async fn update(&mut self, cache: &Cache) -> Option<VoiceState> {
+        if !cache.settings().cache_voice_states {
+            return None;
+        }
 
+        if let Some(guild_id) = self.guild_id {
+            if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
                 if self.voice_state.channel_id.is_some() {
                     // Update or add to the voice state list
                     guild
@@ -1586,6 +1708,13 @@ pub enum Event {
     Ready(ReadyEvent),
     /// The connection has successfully resumed after a disconnect.
     Resumed(ResumedEvent),
+    /// A thread was created, or the current user was added to a private
+    /// thread.
+    ThreadCreate(ThreadCreateEvent),
+    /// A thread was deleted.
+    ThreadDelete(ThreadDeleteEvent),
+    /// A thread was updated.
+    ThreadUpdate(ThreadUpdateEvent),
     /// A user is typing; considered to last 5 seconds
     TypingStart(TypingStartEvent),
     /// Update to the logged-in user's information
@@ -1603,6 +1732,233 @@ pub enum Event {
     Unknown(UnknownEvent),
 }
 
+impl Event {
+    /// The type of the event.
+    pub fn event_type(&self) -> EventType {
+        match self {
+            Event::ChannelCreate(_) => EventType::ChannelCreate,
+            Event::ChannelDelete(_) => EventType::ChannelDelete,
+            Event::ChannelPinsUpdate(_) => EventType::ChannelPinsUpdate,
+            Event::ChannelRecipientAdd(_) => EventType::ChannelRecipientAdd,
+            Event::ChannelRecipientRemove(_) => EventType::ChannelRecipientRemove,
+            Event::ChannelUpdate(_) => EventType::ChannelUpdate,
+            Event::GuildBanAdd(_) => EventType::GuildBanAdd,
+            Event::GuildBanRemove(_) => EventType::GuildBanRemove,
+            Event::GuildCreate(_) => EventType::GuildCreate,
+            Event::GuildDelete(_) => EventType::GuildDelete,
+            Event::GuildEmojisUpdate(_) => EventType::GuildEmojisUpdate,
+            Event::GuildIntegrationsUpdate(_) => EventType::GuildIntegrationsUpdate,
+            Event::GuildMemberAdd(_) => EventType::GuildMemberAdd,
+            Event::GuildMemberRemove(_) => EventType::GuildMemberRemove,
+            Event::GuildMemberUpdate(_) => EventType::GuildMemberUpdate,
+            Event::GuildMembersChunk(_) => EventType::GuildMembersChunk,
+            Event::GuildRoleCreate(_) => EventType::GuildRoleCreate,
+            Event::GuildRoleDelete(_) => EventType::GuildRoleDelete,
+            Event::GuildRoleUpdate(_) => EventType::GuildRoleUpdate,
+            Event::GuildUnavailable(_) => EventType::GuildUnavailable,
+            Event::GuildUpdate(_) => EventType::GuildUpdate,
+            Event::MessageCreate(_) => EventType::MessageCreate,
+            Event::MessageDelete(_) => EventType::MessageDelete,
+            Event::MessageDeleteBulk(_) => EventType::MessageDeleteBulk,
+            Event::MessageUpdate(_) => EventType::MessageUpdate,
+            Event::PresenceUpdate(_) => EventType::PresenceUpdate,
+            Event::PresencesReplace(_) => EventType::PresencesReplace,
+            Event::ReactionAdd(_) => EventType::ReactionAdd,
+            Event::ReactionRemove(_) => EventType::ReactionRemove,
+            Event::ReactionRemoveAll(_) => EventType::ReactionRemoveAll,
+            Event::Ready(_) => EventType::Ready,
+            Event::Resumed(_) => EventType::Resumed,
+            Event::ThreadCreate(_) => EventType::ThreadCreate,
+            Event::ThreadDelete(_) => EventType::ThreadDelete,
+            Event::ThreadUpdate(_) => EventType::ThreadUpdate,
+            Event::TypingStart(_) => EventType::TypingStart,
+            Event::UserUpdate(_) => EventType::UserUpdate,
+            Event::VoiceStateUpdate(_) => EventType::VoiceStateUpdate,
+            Event::VoiceServerUpdate(_) => EventType::VoiceServerUpdate,
+            Event::WebhookUpdate(_) => EventType::WebhookUpdate,
+            Event::Unknown(event) => EventType::Other(event.kind.clone()),
+        }
+    }
+}
+
+/// Updates the cache with whichever event is contained, discarding the output
+/// of the update.
+///
+/// Events which do not affect the cache are ignored.
+#[cfg(feature = "cache")]
+#[async_trait]
+impl CacheUpdate for Event {
+    type Output = ();
+
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
+        if self.update_unrecorded(cache).await {
+            cache.record_event(self);
+        }
+
+        None
+    }
+}
+
+#[cfg(feature = "cache")]
+impl Event {
+    /// Updates the cache with whichever event is contained, without recording
+    /// it for [`Cache::recent_events`].
+    ///
+    /// Returns whether the event affects the cache.
+    ///
+    /// [`Cache::recent_events`]: ../../cache/struct.Cache.html#method.recent_events
+    pub(crate) async fn update_unrecorded(&mut self, cache: &Cache) -> bool {
+        match *self {
+            Event::ChannelCreate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::ChannelDelete(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::ChannelPinsUpdate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::ChannelRecipientAdd(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::ChannelRecipientRemove(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::ChannelUpdate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::GuildCreate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::GuildDelete(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::GuildEmojisUpdate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::GuildMemberAdd(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::GuildMemberRemove(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::GuildMemberUpdate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::GuildMembersChunk(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::GuildRoleCreate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::GuildRoleDelete(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::GuildRoleUpdate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::GuildUnavailable(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::GuildUpdate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::MessageCreate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::MessageDeleteBulk(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::MessageUpdate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::PresenceUpdate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::PresencesReplace(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::Ready(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::ThreadCreate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::ThreadDelete(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::ThreadUpdate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::UserUpdate(ref mut event) => {
+                cache.update(event).await;
+            }
+            Event::VoiceStateUpdate(ref mut event) => {
+                cache.update(event).await;
+            }
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+macro_rules! impl_from_event {
+    ($($variant:ident($event:ident),)*) => {
+        $(
+            impl From<$event> for Event {
+                fn from(event: $event) -> Event {
+                    Event::$variant(event)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_event! {
+    ChannelCreate(ChannelCreateEvent),
+    ChannelDelete(ChannelDeleteEvent),
+    ChannelPinsUpdate(ChannelPinsUpdateEvent),
+    ChannelRecipientAdd(ChannelRecipientAddEvent),
+    ChannelRecipientRemove(ChannelRecipientRemoveEvent),
+    ChannelUpdate(ChannelUpdateEvent),
+    GuildBanAdd(GuildBanAddEvent),
+    GuildBanRemove(GuildBanRemoveEvent),
+    GuildCreate(GuildCreateEvent),
+    GuildDelete(GuildDeleteEvent),
+    GuildEmojisUpdate(GuildEmojisUpdateEvent),
+    GuildIntegrationsUpdate(GuildIntegrationsUpdateEvent),
+    GuildMemberAdd(GuildMemberAddEvent),
+    GuildMemberRemove(GuildMemberRemoveEvent),
+    GuildMemberUpdate(GuildMemberUpdateEvent),
+    GuildMembersChunk(GuildMembersChunkEvent),
+    GuildRoleCreate(GuildRoleCreateEvent),
+    GuildRoleDelete(GuildRoleDeleteEvent),
+    GuildRoleUpdate(GuildRoleUpdateEvent),
+    GuildUnavailable(GuildUnavailableEvent),
+    GuildUpdate(GuildUpdateEvent),
+    MessageCreate(MessageCreateEvent),
+    MessageDelete(MessageDeleteEvent),
+    MessageDeleteBulk(MessageDeleteBulkEvent),
+    MessageUpdate(MessageUpdateEvent),
+    PresenceUpdate(PresenceUpdateEvent),
+    PresencesReplace(PresencesReplaceEvent),
+    ReactionAdd(ReactionAddEvent),
+    ReactionRemove(ReactionRemoveEvent),
+    ReactionRemoveAll(ReactionRemoveAllEvent),
+    Ready(ReadyEvent),
+    Resumed(ResumedEvent),
+    ThreadCreate(ThreadCreateEvent),
+    ThreadDelete(ThreadDeleteEvent),
+    ThreadUpdate(ThreadUpdateEvent),
+    TypingStart(TypingStartEvent),
+    UserUpdate(UserUpdateEvent),
+    VoiceStateUpdate(VoiceStateUpdateEvent),
+    VoiceServerUpdate(VoiceServerUpdateEvent),
+    WebhookUpdate(WebhookUpdateEvent),
+    Unknown(UnknownEvent),
+}
+
 /// Deserializes a `serde_json::Value` into an `Event`.
 ///
 /// The given `EventType` is used to determine what event to deserialize into.
@@ -1688,6 +2044,9 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
         EventType::PresencesReplace => Event::PresencesReplace(serde_json::from_value(v)?),
         EventType::Ready => Event::Ready(serde_json::from_value(v)?),
         EventType::Resumed => Event::Resumed(serde_json::from_value(v)?),
+        EventType::ThreadCreate => Event::ThreadCreate(serde_json::from_value(v)?),
+        EventType::ThreadDelete => Event::ThreadDelete(serde_json::from_value(v)?),
+        EventType::ThreadUpdate => Event::ThreadUpdate(serde_json::from_value(v)?),
         EventType::TypingStart => Event::TypingStart(serde_json::from_value(v)?),
         EventType::UserUpdate => Event::UserUpdate(serde_json::from_value(v)?),
         EventType::VoiceServerUpdate => Event::VoiceServerUpdate(serde_json::from_value(v)?),
@@ -1706,7 +2065,7 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
 /// [`EventType::ChannelCreate`].
 ///
 /// [`EventType::ChannelCreate`]: enum.EventType.html#variant.ChannelCreate
-#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[non_exhaustive]
 pub enum EventType {
     /// Indicator that a channel create payload was received.
@@ -1901,6 +2260,24 @@ pub enum EventType {
     ///
     /// [`ResumedEvent`]: struct.ResumedEvent.html
     Resumed,
+    /// Indicator that a thread create payload was received.
+    ///
+    /// This maps to [`ThreadCreateEvent`].
+    ///
+    /// [`ThreadCreateEvent`]: struct.ThreadCreateEvent.html
+    ThreadCreate,
+    /// Indicator that a thread delete payload was received.
+    ///
+    /// This maps to [`ThreadDeleteEvent`].
+    ///
+    /// [`ThreadDeleteEvent`]: struct.ThreadDeleteEvent.html
+    ThreadDelete,
+    /// Indicator that a thread update payload was received.
+    ///
+    /// This maps to [`ThreadUpdateEvent`].
+    ///
+    /// [`ThreadUpdateEvent`]: struct.ThreadUpdateEvent.html
+    ThreadUpdate,
     /// Indicator that a typing start payload was received.
     ///
     /// This maps to [`TypingStartEvent`].
@@ -1988,6 +2365,9 @@ impl<'de> Deserialize<'de> for EventType {
                     "PRESENCES_REPLACE" => EventType::PresencesReplace,
                     "READY" => EventType::Ready,
                     "RESUMED" => EventType::Resumed,
+                    "THREAD_CREATE" => EventType::ThreadCreate,
+                    "THREAD_DELETE" => EventType::ThreadDelete,
+                    "THREAD_UPDATE" => EventType::ThreadUpdate,
                     "TYPING_START" => EventType::TypingStart,
                     "USER_UPDATE" => EventType::UserUpdate,
                     "VOICE_SERVER_UPDATE" => EventType::VoiceServerUpdate,
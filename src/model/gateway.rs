@@ -2,11 +2,9 @@
 
 use super::prelude::*;
 use super::utils::*;
-use crate::internal::SyncRwLock;
 use bitflags::bitflags;
 use serde::de::Error as DeError;
 use serde::ser::{Serialize, SerializeStruct, Serializer};
-use std::sync::Arc;
 
 /// A representation of the data retrieved from the bot gateway endpoint.
 ///
@@ -346,36 +344,18 @@ pub struct ActivityEmoji {
     pub animated: Option<bool>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
-#[non_exhaustive]
-pub enum ActivityType {
-    /// An indicator that the user is playing a game.
-    Playing = 0,
-    /// An indicator that the user is streaming to a service.
-    Streaming = 1,
-    /// An indicator that the user is listening to something.
-    Listening = 2,
-    /// An indicator that the user uses custum statuses
-    Custom = 4,
-}
-
-enum_number!(ActivityType {
-    Playing,
-    Streaming,
-    Listening,
-    Custom,
-});
-
-impl ActivityType {
-    pub fn num(self) -> u64 {
-        use self::ActivityType::*;
-
-        match self {
-            Playing => 0,
-            Streaming => 1,
-            Listening => 2,
-            Custom => 4,
-        }
+enum_number! {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[non_exhaustive]
+    pub enum ActivityType {
+        /// An indicator that the user is playing a game.
+        Playing = 0,
+        /// An indicator that the user is streaming to a service.
+        Streaming = 1,
+        /// An indicator that the user is listening to something.
+        Listening = 2,
+        /// An indicator that the user uses custum statuses
+        Custom = 4,
     }
 }
 
@@ -429,7 +409,37 @@ pub struct Presence {
     /// date.
     pub user_id: UserId,
     /// The associated user instance.
-    pub user: Option<Arc<SyncRwLock<User>>>,
+    pub user: Option<User>,
+}
+
+impl Presence {
+    /// Discards all data of the presence except for the user's status,
+    /// nickname and a summary of their activity, being the activity's kind,
+    /// name and stream URL.
+    ///
+    /// The cache does this to every stored presence when
+    /// [`Settings::compact_presences`] is enabled.
+    ///
+    /// [`Settings::compact_presences`]: ../../cache/struct.Settings.html#structfield.compact_presences
+    pub fn compact(&mut self) {
+        self.activity = self.activity.take().map(|activity| Activity {
+            application_id: None,
+            assets: None,
+            details: None,
+            flags: None,
+            instance: None,
+            kind: activity.kind,
+            name: activity.name,
+            party: None,
+            secrets: None,
+            state: None,
+            emoji: None,
+            timestamps: None,
+            url: activity.url,
+        });
+        self.client_status = None;
+        self.user = None;
+    }
 }
 
 impl<'de> Deserialize<'de> for Presence {
@@ -444,7 +454,7 @@ impl<'de> Deserialize<'de> for Presence {
         let (user_id, user) = if user_map.len() > 1 {
             let user = User::deserialize(Value::Object(user_map)).map_err(DeError::custom)?;
 
-            (user.id, Some(Arc::new(SyncRwLock::new(user))))
+            (user.id, Some(user))
         } else {
             let user_id = user_map
                 .remove("id")
@@ -513,7 +523,7 @@ impl Serialize for Presence {
         state.serialize_field("status", &self.status)?;
 
         if let Some(ref user) = self.user {
-            state.serialize_field("user", &*user.read())?;
+            state.serialize_field("user", user)?;
         } else {
             state.serialize_field(
                 "user",
@@ -565,6 +575,14 @@ pub struct SessionStartLimit {
     pub reset_after: u64,
     /// The total number of session starts within the ratelimit period allowed.
     pub total: u64,
+    /// The number of shards which may identify at the same time, every 5
+    /// seconds.
+    #[serde(default = "default_max_concurrency")]
+    pub max_concurrency: u64,
+}
+
+fn default_max_concurrency() -> u64 {
+    1
 }
 /// Timestamps of when a user started and/or is ending their activity.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -6,7 +6,7 @@ use super::super::id::GuildId;
 #[cfg(all(feature = "cache", feature = "model"))]
 use super::super::ModelError;
 #[cfg(all(feature = "cache", feature = "model"))]
-use crate::cache::CacheRwLock;
+use crate::cache::Cache;
 #[cfg(all(feature = "cache", feature = "http"))]
 use crate::http::client::Http;
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -90,9 +90,9 @@ impl Emoji {
     #[cfg(all(feature = "cache", feature = "http"))]
     pub async fn delete<T>(&self, cache_and_http: T) -> Result<()>
     where
-        T: AsRef<CacheRwLock> + AsRef<Http>,
+        T: AsRef<Cache> + AsRef<Http>,
     {
-        match self.find_guild_id(&cache_and_http).await {
+        match self.find_guild_id(&cache_and_http) {
             Some(guild_id) => {
                 AsRef::<Http>::as_ref(&cache_and_http)
                     .delete_emoji(guild_id.0, self.id.0)
@@ -112,9 +112,9 @@ impl Emoji {
     #[cfg(all(feature = "cache", feature = "http"))]
     pub async fn edit<T>(&mut self, cache_and_http: T, name: &str) -> Result<()>
     where
-        T: AsRef<CacheRwLock> + AsRef<Http>,
+        T: AsRef<Cache> + AsRef<Http>,
     {
-        match self.find_guild_id(&cache_and_http).await {
+        match self.find_guild_id(&cache_and_http) {
             Some(guild_id) => {
                 let map = json!({
                     "name": name,
@@ -149,12 +149,11 @@ impl Emoji {
     /// # extern crate serenity;
     /// #
     /// # use serde_json::json;
-    /// # use serenity::{cache::{Cache, CacheRwLock}, model::{guild::{Emoji, Role}, id::EmojiId}};
-    /// # use async_std::sync::RwLock;
+    /// # use serenity::{cache::Cache, model::{guild::{Emoji, Role}, id::EmojiId}};
     /// # use std::sync::Arc;
     /// #
-    /// # async fn try_main() {
-    /// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
+    /// # fn try_main() {
+    /// # let cache = Arc::new(Cache::default());
     /// #
     /// # let mut emoji = serde_json::from_value::<Emoji>(json!({
     /// #     "animated": false,
@@ -166,22 +165,19 @@ impl Emoji {
     /// # })).unwrap();
     /// #
     /// // assuming emoji has been set already
-    /// if let Some(guild_id) = emoji.find_guild_id(&cache).await {
+    /// if let Some(guild_id) = emoji.find_guild_id(&cache) {
     ///     println!("{} is owned by {}", emoji.name, guild_id);
     /// }
     /// # }
     /// ```
     #[cfg(feature = "cache")]
-    pub async fn find_guild_id(&self, cache: impl AsRef<CacheRwLock>) -> Option<GuildId> {
-        for guild in cache.as_ref().read().await.guilds.values() {
-            let guild = guild.read().await;
-
-            if guild.emojis.contains_key(&self.id) {
-                return Some(guild.id);
-            }
-        }
-
-        None
+    pub fn find_guild_id(&self, cache: impl AsRef<Cache>) -> Option<GuildId> {
+        cache
+            .as_ref()
+            .guilds
+            .iter()
+            .find(|guild| guild.emojis.contains_key(&self.id))
+            .map(|guild| guild.id)
     }
 
     /// Generates a URL to the emoji's image.
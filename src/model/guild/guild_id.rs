@@ -5,19 +5,22 @@ use crate::model::prelude::*;
 #[cfg(feature = "model")]
 use crate::builder::CreateChannel;
 #[cfg(feature = "model")]
-use crate::builder::{EditGuild, EditMember, EditRole};
+use crate::builder::{
+    CreateSticker, EditGuild, EditGuildWelcomeScreen, EditIntegration, EditMember, EditRole,
+    EditSticker,
+};
 #[cfg(all(feature = "cache", feature = "model"))]
-use crate::cache::CacheRwLock;
+use crate::cache::Cache;
 #[cfg(feature = "http")]
-use crate::http::Http;
+use crate::http::{AttachmentType, Http};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
-#[cfg(feature = "cache")]
-use crate::internal::AsyncRwLock;
 #[cfg(feature = "model")]
 use crate::model::guild::BanOptions;
 #[cfg(feature = "model")]
 use crate::utils;
+#[cfg(feature = "http")]
+use async_stream::stream;
 use futures::Stream;
 #[cfg(feature = "model")]
 use serde_json::json;
@@ -86,6 +89,74 @@ impl GuildId {
         http.as_ref().ban_user(self.0, user.0, dmd, reason).await
     }
 
+    /// Bans several [`User`]s from the guild one after another, as the
+    /// returned stream is polled.
+    ///
+    /// Each ban goes through the [`Http`] ratelimiter, so raids can be
+    /// responded to without running into ratelimits. The stream yields the
+    /// result of each ban as it completes, which can be used to report
+    /// progress. A failed ban does not stop the others from being attempted;
+    /// drop the stream to stop banning.
+    ///
+    /// Refer to [`ban`] for the restrictions on `dmd` and `reason`.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Examples
+    ///
+    /// Ban the members who joined during a raid, reporting progress:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::UserId;
+    /// use futures::{pin_mut, StreamExt};
+    /// use serenity::model::id::GuildId;
+    ///
+    /// # async fn run() {
+    /// # let http = Http::default();
+    /// # let raiders: Vec<UserId> = vec![];
+    /// let total = raiders.len();
+    /// let bans = GuildId(81384788765712384).ban_many(&http, raiders, 1, "Raid");
+    /// pin_mut!(bans);
+    ///
+    /// let mut done = 0;
+    ///
+    /// while let Some((user_id, result)) = bans.next().await {
+    ///     done += 1;
+    ///
+    ///     if let Err(why) = result {
+    ///         println!("Could not ban {}: {:?}", user_id, why);
+    ///     }
+    ///
+    ///     println!("Banned {}/{}", done, total);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`ban`]: #method.ban
+    /// [`Http`]: ../../http/client/struct.Http.html
+    /// [`User`]: ../user/struct.User.html
+    /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
+    #[cfg(feature = "http")]
+    pub fn ban_many<'a, U>(
+        self,
+        http: &'a Http,
+        users: impl IntoIterator<Item = U>,
+        dmd: u8,
+        reason: &'a str,
+    ) -> impl Stream<Item = (UserId, Result<()>)> + 'a
+    where
+        U: Into<UserId>,
+    {
+        let users = users.into_iter().map(Into::into).collect::<Vec<UserId>>();
+
+        stream! {
+            for user in users {
+                yield (user, self._ban(http, user, (dmd, reason)).await);
+            }
+        }
+    }
+
     /// Gets a list of the guild's bans.
     ///
     /// Requires the [Ban Members] permission.
@@ -94,7 +165,29 @@ impl GuildId {
     #[cfg(feature = "http")]
     #[inline]
     pub async fn bans(self, http: impl AsRef<Http>) -> Result<Vec<Ban>> {
-        http.as_ref().get_bans(self.0).await
+        http.as_ref().get_bans(self.0, None, None).await
+    }
+
+    /// Streams all of the guild's bans, ordered by user Id, requesting them
+    /// in pages as the stream is polled.
+    ///
+    /// Refer to [`Http::get_bans_iter`] for more information.
+    ///
+    /// Requires the [Ban Members] permission.
+    ///
+    /// [`Http::get_bans_iter`]: ../../http/client/struct.Http.html#method.get_bans_iter
+    /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
+    #[cfg(feature = "http")]
+    #[inline]
+    pub fn bans_iter(self, http: &Http) -> impl Stream<Item = Result<Ban>> + '_ {
+        http.get_bans_iter(self.0)
+    }
+
+    /// Gets all active threads in the guild that the current user can see.
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn active_threads(self, http: impl AsRef<Http>) -> Result<ThreadsData> {
+        http.as_ref().get_guild_active_threads(self.0).await
     }
 
     /// Gets a list of the guild's audit log entries
@@ -172,8 +265,11 @@ impl GuildId {
         f(&mut builder);
 
         let map = utils::hashmap_to_json_map(builder.0);
+        let reason = builder.1.as_deref().unwrap_or("");
 
-        http.as_ref().create_channel(self.0, &map).await
+        http.as_ref()
+            .create_channel_with_reason(self.0, &map, reason)
+            .await
     }
 
     /// Creates an emoji in the guild with a name and base64-encoded image.
@@ -263,8 +359,12 @@ impl GuildId {
         let mut edit_role = EditRole::default();
         f(&mut edit_role);
         let map = utils::hashmap_to_json_map(edit_role.0);
+        let reason = edit_role.1.as_deref().unwrap_or("");
 
-        let role = http.as_ref().create_role(self.0, &map).await?;
+        let role = http
+            .as_ref()
+            .create_role_with_reason(self.0, &map, reason)
+            .await?;
 
         if let Some(position) = map.get("position").and_then(Value::as_u64) {
             self.edit_role_position(&http, role.id, position).await?;
@@ -273,6 +373,54 @@ impl GuildId {
         Ok(role)
     }
 
+    /// Uploads a [`Sticker`] to the guild from a file.
+    ///
+    /// Refer to [`CreateSticker`] for the fields which need to be set.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// [`CreateSticker`]: ../../builder/struct.CreateSticker.html
+    /// [`Sticker`]: ../sticker/struct.Sticker.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[cfg(feature = "http")]
+    pub async fn create_sticker<'a, F, T>(
+        self,
+        http: impl AsRef<Http>,
+        file: T,
+        f: F,
+    ) -> Result<Sticker>
+    where
+        F: FnOnce(&mut CreateSticker) -> &mut CreateSticker,
+        T: Into<AttachmentType<'a>>,
+    {
+        let mut create_sticker = CreateSticker::default();
+        f(&mut create_sticker);
+        let map = utils::hashmap_to_json_map(create_sticker.0);
+
+        http.as_ref().create_sticker(self.0, map, file).await
+    }
+
+    /// Creates a template of the guild's current settings, channels and
+    /// roles, which new guilds can be created from.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[cfg(feature = "http")]
+    pub async fn create_template(
+        self,
+        http: impl AsRef<Http>,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GuildTemplate> {
+        let map = json!({
+            "description": description,
+            "name": name,
+        });
+
+        http.as_ref().create_guild_template(self.0, &map).await
+    }
+
     /// Deletes the current guild if the current account is the owner of the
     /// guild.
     ///
@@ -358,6 +506,24 @@ impl GuildId {
         http.as_ref().delete_role(self.0, role_id.0).await
     }
 
+    /// Deletes a [`Sticker`] from the guild.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// [`Sticker`]: ../sticker/struct.Sticker.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn delete_sticker<S: Into<StickerId>>(
+        self,
+        http: impl AsRef<Http>,
+        sticker_id: S,
+    ) -> Result<()> {
+        http.as_ref()
+            .delete_sticker(self.0, sticker_id.into().0)
+            .await
+    }
+
     /// Edits the current guild with new data where specified.
     ///
     /// Refer to [`Guild::edit`] for more information.
@@ -414,6 +580,37 @@ impl GuildId {
         http.as_ref().edit_emoji(self.0, emoji_id.0, &map).await
     }
 
+    /// Edits the expiry settings of an [`Integration`] of the guild.
+    ///
+    /// Refer to [`EditIntegration`]'s documentation for a full list of
+    /// settings.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`EditIntegration`]: ../../builder/struct.EditIntegration.html
+    /// [`Integration`]: ../guild/struct.Integration.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn edit_integration<F, I>(
+        self,
+        http: impl AsRef<Http>,
+        integration_id: I,
+        f: F,
+    ) -> Result<()>
+    where
+        F: FnOnce(&mut EditIntegration) -> &mut EditIntegration,
+        I: Into<IntegrationId>,
+    {
+        let mut edit_integration = EditIntegration::default();
+        f(&mut edit_integration);
+        let map = utils::hashmap_to_json_map(edit_integration.0);
+
+        http.as_ref()
+            .edit_guild_integration(self.0, integration_id.into().0, &map)
+            .await
+    }
+
     /// Edits the properties of member of the guild, such as muting or
     /// nicknaming them.
     ///
@@ -445,8 +642,11 @@ impl GuildId {
         let mut edit_member = EditMember::default();
         f(&mut edit_member);
         let map = utils::hashmap_to_json_map(edit_member.0);
+        let reason = edit_member.1.as_deref().unwrap_or("");
 
-        http.as_ref().edit_member(self.0, user_id.0, &map).await
+        http.as_ref()
+            .edit_member_with_reason(self.0, user_id.0, &map, reason)
+            .await
     }
 
     /// Edits the current user's nickname for the guild.
@@ -500,8 +700,11 @@ impl GuildId {
         let mut edit_role = EditRole::default();
         f(&mut edit_role);
         let map = utils::hashmap_to_json_map(edit_role.0);
+        let reason = edit_role.1.as_deref().unwrap_or("");
 
-        http.as_ref().edit_role(self.0, role_id.0, &map).await
+        http.as_ref()
+            .edit_role_with_reason(self.0, role_id.0, &map, reason)
+            .await
     }
 
     /// Edits the order of [`Role`]s
@@ -545,17 +748,61 @@ impl GuildId {
             .await
     }
 
+    /// Edits a [`Sticker`] of the guild.
+    ///
+    /// Refer to [`EditSticker`] for the fields which can be changed.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// [`EditSticker`]: ../../builder/struct.EditSticker.html
+    /// [`Sticker`]: ../sticker/struct.Sticker.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[cfg(feature = "http")]
+    pub async fn edit_sticker<F, S>(
+        self,
+        http: impl AsRef<Http>,
+        sticker_id: S,
+        f: F,
+    ) -> Result<Sticker>
+    where
+        F: FnOnce(&mut EditSticker) -> &mut EditSticker,
+        S: Into<StickerId>,
+    {
+        let mut edit_sticker = EditSticker::default();
+        f(&mut edit_sticker);
+        let map = utils::hashmap_to_json_map(edit_sticker.0);
+
+        http.as_ref()
+            .edit_sticker(self.0, sticker_id.into().0, &map)
+            .await
+    }
+
     /// Tries to find the [`Guild`] by its Id in the cache.
     ///
     /// [`Guild`]: ../guild/struct.Guild.html
     #[cfg(feature = "cache")]
     #[inline]
-    pub async fn to_guild_cached(
-        self,
-        cache: impl AsRef<CacheRwLock>,
-    ) -> Option<Arc<AsyncRwLock<Guild>>> {
-        let guard = cache.as_ref().read().await;
-        guard.guild(self)
+    pub fn to_guild_cached(self, cache: impl AsRef<Cache>) -> Option<Guild> {
+        cache.as_ref().guild(self)
+    }
+
+    /// Returns the name of the guild, requesting the guild over the REST API
+    /// if it is not in the cache.
+    ///
+    /// This is useful during partial outages, where guilds may be missing
+    /// from the cache.
+    #[cfg(feature = "http")]
+    pub async fn name_or_fetch(self, cache_http: impl CacheHttp) -> Result<String> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(name) = cache.guild_field(self, |guild| guild.name.clone()) {
+                    return Ok(name);
+                }
+            }
+        }
+
+        Ok(self.to_partial_guild(cache_http.http()).await?.name)
     }
 
     /// Requests [`PartialGuild`] over REST API.
@@ -645,8 +892,7 @@ impl GuildId {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                let guard = cache.read().await;
-                if let Some(member) = guard.member(self.0, user_id).await {
+                if let Some(member) = cache.member(self.0, user_id) {
                     return Ok(member);
                 }
             }
@@ -710,7 +956,7 @@ impl GuildId {
     ///     match member_result {
     ///         Ok(member) => println!(
     ///             "{} is {}",
-    ///             member.async_to_string().await,
+    ///             member,
     ///             member.display_name()
     ///         ),
     ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
@@ -826,9 +1072,8 @@ impl GuildId {
     /// [`utils::shard_id`]: ../../utils/fn.shard_id.html
     #[cfg(all(feature = "cache", feature = "utils"))]
     #[inline]
-    pub async fn shard_id(self, cache: impl AsRef<CacheRwLock>) -> u64 {
-        let guard = cache.as_ref().read().await;
-        crate::utils::shard_id(self.0, guard.shard_count)
+    pub fn shard_id(self, cache: impl AsRef<Cache>) -> u64 {
+        crate::utils::shard_id(self.0, cache.as_ref().shard_count())
     }
 
     /// Returns the Id of the shard associated with the guild.
@@ -904,6 +1149,41 @@ impl GuildId {
         http.as_ref().start_guild_prune(self.0, &map).await
     }
 
+    /// Gets a [`Sticker`] of the guild.
+    ///
+    /// [`Sticker`]: ../sticker/struct.Sticker.html
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn sticker<S: Into<StickerId>>(
+        self,
+        http: impl AsRef<Http>,
+        sticker_id: S,
+    ) -> Result<Sticker> {
+        http.as_ref()
+            .get_guild_sticker(self.0, sticker_id.into().0)
+            .await
+    }
+
+    /// Gets the guild's [`Sticker`]s.
+    ///
+    /// [`Sticker`]: ../sticker/struct.Sticker.html
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn stickers(self, http: impl AsRef<Http>) -> Result<Vec<Sticker>> {
+        http.as_ref().get_guild_stickers(self.0).await
+    }
+
+    /// Retrieves the guild's templates.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn templates(self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        http.as_ref().get_guild_templates(self.0).await
+    }
+
     /// Unbans a [`User`] from the guild.
     ///
     /// Requires the [Ban Members] permission.
@@ -913,22 +1193,42 @@ impl GuildId {
     #[cfg(feature = "http")]
     #[inline]
     pub async fn unban<U: Into<UserId>>(self, http: impl AsRef<Http>, user_id: U) -> Result<()> {
-        self._unban(&http, user_id.into()).await
+        self._unban(&http, user_id.into(), "").await
+    }
+
+    /// Unbans a [`User`] from the guild, recording a reason in the audit log.
+    ///
+    /// Requires the [Ban Members] permission.
+    ///
+    /// [`User`]: ../user/struct.User.html
+    /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn unban_with_reason<U: Into<UserId>>(
+        self,
+        http: impl AsRef<Http>,
+        user_id: U,
+        reason: &str,
+    ) -> Result<()> {
+        self._unban(&http, user_id.into(), reason).await
     }
 
     #[cfg(feature = "http")]
-    async fn _unban(self, http: impl AsRef<Http>, user_id: UserId) -> Result<()> {
-        http.as_ref().remove_ban(self.0, user_id.0).await
+    async fn _unban(self, http: impl AsRef<Http>, user_id: UserId, reason: &str) -> Result<()> {
+        http.as_ref()
+            .remove_ban_with_reason(self.0, user_id.0, reason)
+            .await
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Retrieves the guild's vanity invite, including its code and how many
+    /// times it was used.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     #[cfg(feature = "http")]
     #[inline]
-    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<VanityInvite> {
         http.as_ref().get_guild_vanity_url(self.0).await
     }
 
@@ -941,6 +1241,38 @@ impl GuildId {
     pub async fn webhooks(self, http: impl AsRef<Http>) -> Result<Vec<Webhook>> {
         http.as_ref().get_guild_webhooks(self.0).await
     }
+
+    /// Retrieves the guild's [`WelcomeScreen`].
+    ///
+    /// [`WelcomeScreen`]: ../guild/struct.WelcomeScreen.html
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn welcome_screen(self, http: impl AsRef<Http>) -> Result<WelcomeScreen> {
+        http.as_ref().get_guild_welcome_screen(self.0).await
+    }
+
+    /// Edits the guild's [`WelcomeScreen`], returning the updated version.
+    ///
+    /// Refer to [`EditGuildWelcomeScreen`]'s documentation for a full list of
+    /// settings.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`EditGuildWelcomeScreen`]: ../../builder/struct.EditGuildWelcomeScreen.html
+    /// [`WelcomeScreen`]: ../guild/struct.WelcomeScreen.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn edit_welcome_screen<F>(self, http: impl AsRef<Http>, f: F) -> Result<WelcomeScreen>
+    where
+        F: FnOnce(&mut EditGuildWelcomeScreen) -> &mut EditGuildWelcomeScreen,
+    {
+        let mut edit_welcome_screen = EditGuildWelcomeScreen::default();
+        f(&mut edit_welcome_screen);
+        let map = utils::hashmap_to_json_map(edit_welcome_screen.0);
+
+        http.as_ref().edit_guild_welcome_screen(self.0, &map).await
+    }
 }
 
 impl From<PartialGuild> for GuildId {
@@ -1003,7 +1335,7 @@ impl<'a> From<&'a Guild> for GuildId {
 ///
 /// [`GuildId.members_iter()`]: #method.members_iter
 #[derive(Clone, Debug)]
-#[cfg(all(feature = "http", feature = "cache"))]
+#[cfg(all(feature = "model", feature = "http", feature = "cache"))]
 pub struct MembersIter<H: AsRef<Http>> {
     guild_id: GuildId,
     http: H,
@@ -1012,7 +1344,7 @@ pub struct MembersIter<H: AsRef<Http>> {
     tried_fetch: bool,
 }
 
-#[cfg(all(feature = "http", feature = "cache"))]
+#[cfg(all(feature = "model", feature = "http", feature = "cache"))]
 impl<H: AsRef<Http>> MembersIter<H> {
     fn new(guild_id: GuildId, http: H) -> MembersIter<H> {
         MembersIter {
@@ -1056,10 +1388,10 @@ impl<H: AsRef<Http>> MembersIter<H> {
     }
 }
 
-#[cfg(all(feature = "http", feature = "cache"))]
+#[cfg(all(feature = "model", feature = "http", feature = "cache"))]
 use async_stream::try_stream;
 
-#[cfg(all(feature = "http", feature = "cache"))]
+#[cfg(all(feature = "model", feature = "http", feature = "cache"))]
 pub fn members_iter_to_stream<H: AsRef<Http>>(
     mut iter: MembersIter<H>,
 ) -> impl Stream<Item = Result<Member>> {
@@ -1078,7 +1410,7 @@ pub fn members_iter_to_stream<H: AsRef<Http>>(
 }
 
 /*
-#[cfg(all(feature = "http", feature = "cache"))]
+#[cfg(all(feature = "model", feature = "http", feature = "cache"))]
 impl<H: AsRef<Http>> Iterator for MembersIter<H> {
     type Item = Result<Member>;
 
@@ -1103,6 +1435,6 @@ impl<H: AsRef<Http>> Iterator for MembersIter<H> {
     }
 }
 
-#[cfg(all(feature = "http", feature = "cache"))]
+#[cfg(all(feature = "model", feature = "http", feature = "cache"))]
 impl<H: AsRef<Http>> std::iter::FusedIterator for MembersIter<H> {}
 */
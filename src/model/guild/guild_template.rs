@@ -0,0 +1,88 @@
+use super::*;
+
+/// A snapshot of a guild's settings, channels and roles, which new guilds can
+/// be created from.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#guild-template-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildTemplate {
+    /// The unique code of the template, as used in `discord.new` links.
+    pub code: String,
+    /// The name of the template.
+    pub name: String,
+    /// The description of the template, if any.
+    pub description: Option<String>,
+    /// The number of guilds created from the template.
+    pub usage_count: u64,
+    /// The Id of the user who created the template.
+    pub creator_id: UserId,
+    /// The user who created the template.
+    pub creator: User,
+    /// When the template was created.
+    pub created_at: Timestamp,
+    /// When the template was last synced with its guild.
+    pub updated_at: Timestamp,
+    /// The Id of the guild the template was created from.
+    pub source_guild_id: GuildId,
+    /// The snapshot of the guild the template was created from.
+    ///
+    /// This only contains the subset of a guild's fields which are part of
+    /// templates.
+    pub serialized_source_guild: Value,
+    /// Whether the guild changed since the template was last synced.
+    pub is_dirty: Option<bool>,
+}
+
+#[cfg(feature = "http")]
+impl GuildTemplate {
+    /// Creates a new guild, owned by the current user, from the template.
+    ///
+    /// **Note**: This can only be used by bots in less than 10 guilds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user is in too many guilds.
+    ///
+    /// [`Error::Http`]: ../../enum.Error.html#variant.Http
+    pub async fn create_guild(
+        &self,
+        http: impl AsRef<Http>,
+        name: &str,
+        icon: Option<&str>,
+    ) -> Result<PartialGuild> {
+        let map = json!({
+            "icon": icon,
+            "name": name,
+        });
+
+        http.as_ref()
+            .create_guild_from_template(&self.code, &map)
+            .await
+    }
+
+    /// Updates the template to match the current state of its guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn sync(&mut self, http: impl AsRef<Http>) -> Result<()> {
+        *self = http
+            .as_ref()
+            .sync_guild_template(self.source_guild_id.0, &self.code)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Deletes the template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<GuildTemplate> {
+        http.as_ref()
+            .delete_guild_template(self.source_guild_id.0, &self.code)
+            .await
+    }
+}
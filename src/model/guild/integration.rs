@@ -7,15 +7,29 @@ pub struct Integration {
     pub id: IntegrationId,
     pub account: IntegrationAccount,
     pub enabled: bool,
-    #[serde(rename = "expire_behaviour")]
-    pub expire_behaviour: u64,
-    pub expire_grace_period: u64,
+    /// Whether emoticons of the integration are synced.
+    ///
+    /// This is only present for Twitch integrations.
+    pub enable_emoticons: Option<bool>,
+    /// What happens to subscribers once their subscription expires.
+    ///
+    /// This is not present for bot integrations.
+    #[serde(rename = "expire_behavior", alias = "expire_behaviour")]
+    pub expire_behaviour: Option<IntegrationExpireBehaviour>,
+    /// The number of days to wait before applying the
+    /// [`expire_behaviour`].
+    ///
+    /// [`expire_behaviour`]: #structfield.expire_behaviour
+    pub expire_grace_period: Option<u64>,
+    #[serde(rename = "type")]
     pub kind: String,
     pub name: String,
-    pub role_id: RoleId,
-    pub synced_at: u64,
-    pub syncing: bool,
-    pub user: User,
+    /// The role given to subscribers.
+    pub role_id: Option<RoleId>,
+    pub synced_at: Option<Timestamp>,
+    pub syncing: Option<bool>,
+    /// The user the integration belongs to.
+    pub user: Option<User>,
 }
 
 impl From<Integration> for IntegrationId {
@@ -32,3 +46,18 @@ pub struct IntegrationAccount {
     pub id: String,
     pub name: String,
 }
+
+enum_number! {
+    /// What happens to the subscribers of an [`Integration`] once their
+    /// subscription expires.
+    ///
+    /// [`Integration`]: struct.Integration.html
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    #[non_exhaustive]
+    pub enum IntegrationExpireBehaviour {
+        /// The subscriber role is removed.
+        RemoveRole = 0,
+        /// The subscriber is kicked.
+        Kick = 1,
+    }
+}
@@ -1,8 +1,6 @@
-use super::deserialize_sync_user;
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
 use crate::model::prelude::*;
-use chrono::{DateTime, FixedOffset};
 
 #[cfg(all(feature = "builder", feature = "cache", feature = "model"))]
 use crate::builder::EditMember;
@@ -10,16 +8,14 @@ use crate::builder::EditMember;
 use crate::http::Http;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::internal::prelude::*;
-#[cfg(feature = "cache")]
-use crate::internal::AsyncRwLock;
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
 use crate::utils::Colour;
 #[cfg(all(feature = "cache", feature = "model"))]
-use crate::{cache::CacheRwLock, utils};
+use crate::{cache::Cache, utils};
 #[cfg(feature = "model")]
 use std::borrow::Cow;
-
-use crate::internal::SyncRwLock;
+#[cfg(feature = "model")]
+use std::time::Duration;
 
 /// A trait for allowing both u8 or &str or (u8, &str) to be passed into the `ban` methods in `Guild` and `Member`.
 pub trait BanOptions {
@@ -76,7 +72,7 @@ impl BanOptions for (u8, String) {
 }
 
 /// Information about a member of a guild.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Member {
     /// Indicator of whether the member can hear in voice channels.
@@ -84,7 +80,7 @@ pub struct Member {
     /// The unique Id of the guild that the member is a part of.
     pub guild_id: GuildId,
     /// Timestamp representing the date when the member joined.
-    pub joined_at: Option<DateTime<FixedOffset>>,
+    pub joined_at: Option<Timestamp>,
     /// Indicator of whether the member can speak in voice channels.
     pub mute: bool,
     /// The member's nickname, if present.
@@ -94,11 +90,7 @@ pub struct Member {
     /// Vector of Ids of [`Role`](struct.Role.html)s given to the member.
     pub roles: Vec<RoleId>,
     /// Attached User struct.
-    #[serde(
-        deserialize_with = "deserialize_sync_user",
-        serialize_with = "serialize_sync_user"
-    )]
-    pub user: Arc<SyncRwLock<User>>,
+    pub user: User,
 }
 
 #[cfg(feature = "model")]
@@ -128,7 +120,7 @@ impl Member {
 
         match http
             .as_ref()
-            .add_member_role(self.guild_id.0, self.user.read().id.0, role_id.0)
+            .add_member_role(self.guild_id.0, self.user.id.0, role_id.0)
             .await
         {
             Ok(()) => {
@@ -157,7 +149,7 @@ impl Member {
 
         match http
             .as_ref()
-            .edit_member(self.guild_id.0, self.user.read().id.0, &map)
+            .edit_member(self.guild_id.0, self.user.id.0, &map)
             .await
         {
             Ok(()) => Ok(()),
@@ -203,15 +195,14 @@ impl Member {
         }
 
         http.as_ref()
-            .ban_user(self.guild_id.0, self.user.read().id.0, dmd, &*reason)
+            .ban_user(self.guild_id.0, self.user.id.0, dmd, &*reason)
             .await
     }
 
     /// Determines the member's colour.
     #[cfg(all(feature = "cache", feature = "utils"))]
-    pub async fn colour(&self, cache: impl AsRef<CacheRwLock>) -> Option<Colour> {
-        let cache = cache.as_ref().read().await;
-        let guild = cache.guilds.get(&self.guild_id)?.read().await;
+    pub fn colour(&self, cache: impl AsRef<Cache>) -> Option<Colour> {
+        let guild = cache.as_ref().guilds.get(&self.guild_id)?;
 
         let mut roles = self
             .roles
@@ -232,28 +223,10 @@ impl Member {
     /// (This returns the first channel that can be read by the member, if there isn't
     /// one returns `None`)
     #[cfg(feature = "cache")]
-    pub async fn default_channel(
-        &self,
-        cache: impl AsRef<CacheRwLock>,
-    ) -> Option<Arc<AsyncRwLock<GuildChannel>>> {
-        let guild = match self.guild_id.to_guild_cached(&cache).await {
-            Some(guild) => guild,
-            None => return None,
-        };
-
-        let reader = guild.read().await;
-
-        for (cid, channel) in reader.channels.iter() {
-            if reader
-                .user_permissions_in(*cid, self.user.read().id)
-                .await
-                .read_messages()
-            {
-                return Some(Arc::clone(channel));
-            }
-        }
+    pub fn default_channel(&self, cache: impl AsRef<Cache>) -> Option<GuildChannel> {
+        let guild = cache.as_ref().guilds.get(&self.guild_id)?;
 
-        None
+        guild.default_channel(self.user.id).cloned()
     }
 
     /// Calculates the member's display name.
@@ -263,18 +236,14 @@ impl Member {
     pub fn display_name(&self) -> Cow<'_, String> {
         match self.nick.as_ref().map(Cow::Borrowed) {
             Some(x) => x,
-            None => Cow::Owned(self.user.read().name.clone()),
+            None => Cow::Owned(self.user.name.clone()),
         }
     }
 
     /// Returns the DiscordTag of a Member, taking possible nickname into account.
     #[inline]
     pub fn distinct(&self) -> String {
-        format!(
-            "{}#{:04}",
-            self.display_name(),
-            self.user.read().discriminator
-        )
+        format!("{}#{:04}", self.display_name(), self.user.discriminator)
     }
 
     /// Edits the member with the given data. See [`Guild::edit_member`] for
@@ -294,9 +263,10 @@ impl Member {
         let mut edit_member = EditMember::default();
         f(&mut edit_member);
         let map = utils::hashmap_to_json_map(edit_member.0);
+        let reason = edit_member.1.as_deref().unwrap_or("");
 
         http.as_ref()
-            .edit_member(self.guild_id.0, self.user.read().id.0, &map)
+            .edit_member_with_reason(self.guild_id.0, self.user.id.0, &map, reason)
             .await
     }
 
@@ -307,15 +277,13 @@ impl Member {
     ///
     /// - the user has roles, but they are not present in the cache for cache
     /// inconsistency reasons
-    /// - you already have a write lock to the member's guild
     ///
     /// The "highest role in hierarchy" is defined as the role with the highest
     /// position. If two or more roles have the same highest position, then the
     /// role with the lowest ID is the highest.
     #[cfg(feature = "cache")]
-    pub async fn highest_role_info(&self, cache: impl AsRef<CacheRwLock>) -> Option<(RoleId, i64)> {
-        let guild = self.guild_id.to_guild_cached(&cache).await?;
-        let reader = guild.try_read()?;
+    pub fn highest_role_info(&self, cache: impl AsRef<Cache>) -> Option<(RoleId, i64)> {
+        let reader = cache.as_ref().guilds.get(&self.guild_id)?;
 
         let mut highest = None;
 
@@ -407,22 +375,19 @@ impl Member {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                let locked_cache = cache.read().await;
-
-                if let Some(guild) = locked_cache.guilds.get(&self.guild_id) {
+                if let Some(guild) = cache.guilds.get(&self.guild_id) {
                     let req = Permissions::KICK_MEMBERS;
-                    let reader = guild.read().await;
 
-                    if !reader.has_perms(cache, req).await {
+                    if !guild.has_perms(cache, req) {
                         return Err(Error::Model(ModelError::InvalidPermissions(req)));
                     }
 
-                    reader.check_hierarchy(cache, self.user.read().id).await?;
+                    guild.check_hierarchy(cache, self.user.id)?;
                 }
             }
         }
         self.guild_id
-            .kick_with_reason(cache_http.http(), self.user.read().id, reason)
+            .kick_with_reason(cache_http.http(), self.user.id, reason)
             .await
     }
 
@@ -447,15 +412,13 @@ impl Member {
     /// [`ModelError::GuildNotFound`]: ../error/enum.Error.html#variant.GuildNotFound
     /// [`ModelError::ItemMissing`]: ../error/enum.Error.html#variant.ItemMissing
     #[cfg(feature = "cache")]
-    pub async fn permissions(&self, cache: impl AsRef<CacheRwLock>) -> Result<Permissions> {
-        let guild = match self.guild_id.to_guild_cached(&cache).await {
+    pub fn permissions(&self, cache: impl AsRef<Cache>) -> Result<Permissions> {
+        let guild = match cache.as_ref().guilds.get(&self.guild_id) {
             Some(guild) => guild,
             None => return Err(From::from(ModelError::GuildNotFound)),
         };
 
-        let reader = guild.read().await;
-
-        Ok(reader.member_permissions(self.user.read().id))
+        Ok(guild.member_permissions(self.user.id))
     }
 
     /// Removes a [`Role`] from the member, editing its roles in-place if the
@@ -483,7 +446,7 @@ impl Member {
 
         match http
             .as_ref()
-            .remove_member_role(self.guild_id.0, self.user.read().id.0, role_id.0)
+            .remove_member_role(self.guild_id.0, self.user.id.0, role_id.0)
             .await
         {
             Ok(()) => {
@@ -515,7 +478,7 @@ impl Member {
 
         match http
             .as_ref()
-            .edit_member(self.guild_id.0, self.user.read().id.0, &map)
+            .edit_member(self.guild_id.0, self.user.id.0, &map)
             .await
         {
             Ok(()) => Ok(()),
@@ -533,20 +496,15 @@ impl Member {
     ///
     /// If role data can not be found for the member, then `None` is returned.
     #[cfg(feature = "cache")]
-    pub async fn roles(&self, cache: impl AsRef<CacheRwLock>) -> Option<Vec<Role>> {
-        match self.guild_id.to_guild_cached(&cache).await {
-            Some(guild) => Some(
-                guild
-                    .read()
-                    .await
-                    .roles
-                    .values()
-                    .filter(|role| self.roles.contains(&role.id))
-                    .cloned()
-                    .collect(),
-            ),
-            None => None,
-        }
+    pub fn roles(&self, cache: impl AsRef<Cache>) -> Option<Vec<Role>> {
+        cache.as_ref().guilds.get(&self.guild_id).map(|guild| {
+            guild
+                .roles
+                .values()
+                .filter(|role| self.roles.contains(&role.id))
+                .cloned()
+                .collect()
+        })
     }
 
     /// Unbans the [`User`] from the guild.
@@ -564,32 +522,30 @@ impl Member {
     #[cfg(all(feature = "cache", feature = "http"))]
     pub async fn unban(&self, http: impl AsRef<Http>) -> Result<()> {
         http.as_ref()
-            .remove_ban(self.guild_id.0, self.user.read().id.0)
+            .remove_ban(self.guild_id.0, self.user.id.0)
             .await
     }
 
-    /// Retrieves the member's user ID.
+    /// How long ago the member joined the guild, if known.
     ///
-    /// This is a shortcut for accessing the [`user`] structfield, retrieving a
-    /// reader guard, and then copying its ID.
+    /// This is `None` if Discord did not send the [`joined_at`] time along
+    /// with the member.
     ///
-    /// # Deadlocking
+    /// [`joined_at`]: #structfield.joined_at
+    pub fn time_in_guild(&self) -> Option<Duration> {
+        self.joined_at.map(|joined_at| joined_at.elapsed())
+    }
+
+    /// Retrieves the member's user ID.
     ///
-    /// This function can deadlock while retrieving a read guard to the user
-    /// object if your application infinitely holds a write lock elsewhere.
+    /// This is a shortcut for accessing the [`user`] structfield and copying
+    /// its ID.
     #[cfg(feature = "cache")]
     pub fn user_id(&self) -> UserId {
-        self.user.read().id
-    }
-
-    /// Retrieve a string representation of this user
-    pub async fn async_to_string(&self) -> String {
-        self.user.read().mention().await
+        self.user.id
     }
 }
 
-/*
-TODO: async to_string
 impl std::fmt::Display for Member {
     /// Mentions the user so that they receive a notification.
     ///
@@ -602,9 +558,9 @@ impl std::fmt::Display for Member {
     ///
     // This is in the format of `<@USER_ID>`.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> FmtResult {
-        std::fmt::Display::fmt(&self.user.read().mention(), f)
+        std::fmt::Display::fmt(&self.user.mention(), f)
     }
-}*/
+}
 
 /// A partial amount of data for a member.
 ///
@@ -618,7 +574,7 @@ pub struct PartialMember {
     /// Indicator of whether the member can hear in voice channels.
     pub deaf: bool,
     /// Timestamp representing the date when the member joined.
-    pub joined_at: Option<DateTime<FixedOffset>>,
+    pub joined_at: Option<Timestamp>,
     /// Indicator of whether the member can speak in voice channels.
     pub mute: bool,
     /// Vector of Ids of [`Role`]s given to the member.
@@ -3,46 +3,47 @@
 mod audit_log;
 mod emoji;
 mod guild_id;
+mod guild_template;
 mod integration;
 mod member;
 mod partial_guild;
 mod premium_tier;
 mod role;
+mod welcome_screen;
 
 pub use self::audit_log::*;
 pub use self::emoji::*;
 pub use self::guild_id::*;
+pub use self::guild_template::*;
 pub use self::integration::*;
 pub use self::member::*;
 pub use self::partial_guild::*;
 pub use self::premium_tier::*;
 pub use self::role::*;
+pub use self::welcome_screen::*;
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
 
 use super::utils::*;
 use crate::model::prelude::*;
-use chrono::{DateTime, FixedOffset};
 use serde::de::Error as DeError;
 
 #[cfg(feature = "model")]
-use crate::builder::{CreateChannel, EditGuild, EditMember, EditRole};
+use crate::builder::{
+    CreateChannel, EditGuild, EditGuildWelcomeScreen, EditIntegration, EditMember, EditRole,
+};
 #[cfg(all(feature = "cache", feature = "model"))]
-use crate::cache::CacheRwLock;
+use crate::cache::Cache;
 #[cfg(feature = "model")]
 use crate::constants::LARGE_THRESHOLD;
 #[cfg(feature = "http")]
 use crate::http::Http;
-#[cfg(all(feature = "cache", feature = "model"))]
-use crate::internal::AsyncRwLock;
 #[cfg(feature = "model")]
 use log::{error, warn};
-#[cfg(all(feature = "http", feature = "model"))]
+#[cfg(feature = "http")]
 use serde_json::json;
 #[cfg(feature = "model")]
 use std::borrow::Cow;
-#[cfg(all(feature = "cache", feature = "model"))]
-use std::sync::Arc;
 
 /// A representation of a banning of a user.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Hash, Serialize)]
@@ -68,8 +69,8 @@ pub struct Guild {
     ///
     /// This contains all channels regardless of permissions (i.e. the ability
     /// of the bot to read from or connect to them).
-    #[serde(serialize_with = "serialize_gen_locked_map")]
-    pub channels: HashMap<ChannelId, Arc<AsyncRwLock<GuildChannel>>>,
+    #[serde(serialize_with = "serialize_gen_map")]
+    pub channels: HashMap<ChannelId, GuildChannel>,
     /// Indicator of whether notifications for all messages are enabled by
     /// default in the guild.
     pub default_message_notifications: DefaultMessageNotificationLevel,
@@ -100,7 +101,7 @@ pub struct Guild {
     /// that of the default channel (typically `#general`).
     pub id: GuildId,
     /// The date that the current user joined the guild.
-    pub joined_at: DateTime<FixedOffset>,
+    pub joined_at: Timestamp,
     /// Indicator of whether the guild is considered "large" by Discord.
     pub large: bool,
     /// The number of members in the guild.
@@ -151,6 +152,10 @@ pub struct Guild {
     /// [`User`]: ../user/struct.User.html
     #[serde(serialize_with = "serialize_gen_map")]
     pub voice_states: HashMap<UserId, VoiceState>,
+    /// All active threads in the guild that the current user has permission
+    /// to view.
+    #[serde(serialize_with = "serialize_gen_map")]
+    pub threads: HashMap<ChannelId, GuildThread>,
     /// The server's description
     pub description: Option<String>,
     /// The server's premium boosting level.
@@ -171,17 +176,10 @@ pub struct Guild {
 #[cfg(feature = "model")]
 impl Guild {
     #[cfg(feature = "cache")]
-    async fn check_hierarchy(
-        &self,
-        cache: impl AsRef<CacheRwLock>,
-        other_user: UserId,
-    ) -> Result<()> {
-        let current_id = cache.as_ref().read().await.user.id;
+    fn check_hierarchy(&self, cache: impl AsRef<Cache>, other_user: UserId) -> Result<()> {
+        let current_id = cache.as_ref().current_user_id();
 
-        if let Some(higher) = self
-            .greater_member_hierarchy(&cache, other_user, current_id)
-            .await
-        {
+        if let Some(higher) = self.greater_member_hierarchy(&cache, other_user, current_id) {
             if higher != current_id {
                 return Err(Error::Model(ModelError::Hierarchy));
             }
@@ -194,10 +192,10 @@ impl Guild {
     /// (This returns the first channel that can be read by the user, if there isn't one,
     /// returns `None`)
     #[cfg(feature = "http")]
-    pub async fn default_channel(&self, uid: UserId) -> Option<Arc<AsyncRwLock<GuildChannel>>> {
+    pub fn default_channel(&self, uid: UserId) -> Option<&GuildChannel> {
         for (cid, channel) in self.channels.iter() {
-            if self.user_permissions_in(*cid, uid).await.read_messages() {
-                return Some(Arc::clone(channel));
+            if self.user_permissions_in(*cid, uid).read_messages() {
+                return Some(channel);
             }
         }
 
@@ -209,11 +207,11 @@ impl Guild {
     /// returns `None`)
     /// Note however that this is very costy if used in a server with lots of channels,
     /// members, or both.
-    pub async fn default_channel_guaranteed(&self) -> Option<Arc<AsyncRwLock<GuildChannel>>> {
+    pub fn default_channel_guaranteed(&self) -> Option<&GuildChannel> {
         for (cid, channel) in self.channels.iter() {
             for memid in self.members.keys() {
-                if self.user_permissions_in(*cid, *memid).await.read_messages() {
-                    return Some(Arc::clone(channel));
+                if self.user_permissions_in(*cid, *memid).read_messages() {
+                    return Some(channel);
                 }
             }
         }
@@ -222,12 +220,8 @@ impl Guild {
     }
 
     #[cfg(feature = "cache")]
-    async fn has_perms(
-        &self,
-        cache: impl AsRef<CacheRwLock>,
-        mut permissions: Permissions,
-    ) -> bool {
-        let user_id = cache.as_ref().read().await.user.id;
+    fn has_perms(&self, cache: impl AsRef<Cache>, mut permissions: Permissions) -> bool {
+        let user_id = cache.as_ref().current_user_id();
 
         let perms = self.member_permissions(user_id);
         permissions.remove(perms);
@@ -236,22 +230,15 @@ impl Guild {
     }
 
     #[cfg(feature = "cache")]
-    pub async fn channel_id_from_name(
+    pub fn channel_id_from_name(
         &self,
-        cache: impl AsRef<CacheRwLock>,
+        cache: impl AsRef<Cache>,
         name: impl AsRef<str>,
     ) -> Option<ChannelId> {
-        let name = name.as_ref();
-        let cache = cache.as_ref().read().await;
-        let guild = cache.guilds.get(&self.id)?.read().await;
-
-        for (cid, channel) in guild.channels.iter() {
-            let channel = channel.read().await;
-            if channel.name == name {
-                return Some(*cid);
-            }
-        }
-        None
+        cache
+            .as_ref()
+            .channel_by_name(self.id, name.as_ref())
+            .map(|channel| channel.id)
     }
 
     /// Ban a [`User`] from the guild. All messages by the
@@ -306,11 +293,11 @@ impl Guild {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::BAN_MEMBERS;
 
-                if !self.has_perms(cache, req).await {
+                if !self.has_perms(cache, req) {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
 
-                self.check_hierarchy(cache, user).await?;
+                self.check_hierarchy(cache, user)?;
             }
         }
 
@@ -336,7 +323,7 @@ impl Guild {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::BAN_MEMBERS;
 
-                if !self.has_perms(cache, req).await {
+                if !self.has_perms(cache, req) {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -345,6 +332,17 @@ impl Guild {
         self.id.bans(cache_http.http()).await
     }
 
+    /// Gets all active threads in the guild that the current user can see.
+    ///
+    /// The threads received through the gateway are cached in [`threads`].
+    ///
+    /// [`threads`]: #structfield.threads
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn active_threads(&self, http: impl AsRef<Http>) -> Result<ThreadsData> {
+        self.id.active_threads(&http).await
+    }
+
     /// Retrieves a list of [`AuditLogs`] for the guild.
     ///
     /// [`AuditLogs`]: audit_log/struct.AuditLogs.html
@@ -449,7 +447,7 @@ impl Guild {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::MANAGE_CHANNELS;
 
-                if !self.has_perms(cache, req).await {
+                if !self.has_perms(cache, req) {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -509,6 +507,26 @@ impl Guild {
             .await
     }
 
+    /// Creates a template of the guild's current settings, channels and
+    /// roles, which new guilds can be created from.
+    ///
+    /// Refer to [`GuildId::create_template`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildId::create_template`]: ../id/struct.GuildId.html#method.create_template
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn create_template(
+        &self,
+        http: impl AsRef<Http>,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GuildTemplate> {
+        self.id.create_template(&http, name, description).await
+    }
+
     /// Creates a new role in the guild with the data set, if any.
     ///
     /// **Note**: Requires the [Manage Roles] permission.
@@ -541,7 +559,7 @@ impl Guild {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::MANAGE_ROLES;
 
-                if !self.has_perms(cache, req).await {
+                if !self.has_perms(cache, req) {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -566,7 +584,7 @@ impl Guild {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                if self.owner_id != cache.read().await.user.id {
+                if self.owner_id != cache.current_user_id() {
                     let req = Permissions::MANAGE_GUILD;
 
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
@@ -666,7 +684,7 @@ impl Guild {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::MANAGE_GUILD;
 
-                if !self.has_perms(cache, req).await {
+                if !self.has_perms(cache, req) {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -715,6 +733,30 @@ impl Guild {
         self.id.edit_emoji(&http, emoji_id, name).await
     }
 
+    /// Edits the expiry settings of an [`Integration`] of the guild.
+    ///
+    /// Refer to [`GuildId::edit_integration`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildId::edit_integration`]: ../id/struct.GuildId.html#method.edit_integration
+    /// [`Integration`]: struct.Integration.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn edit_integration<F, I>(
+        &self,
+        http: impl AsRef<Http>,
+        integration_id: I,
+        f: F,
+    ) -> Result<()>
+    where
+        F: FnOnce(&mut EditIntegration) -> &mut EditIntegration,
+        I: Into<IntegrationId>,
+    {
+        self.id.edit_integration(&http, integration_id, f).await
+    }
+
     /// Edits the properties of member of the guild, such as muting or
     /// nicknaming them.
     ///
@@ -763,7 +805,7 @@ impl Guild {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::CHANGE_NICKNAME;
 
-                if !self.has_perms(cache, req).await {
+                if !self.has_perms(cache, req) {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -850,9 +892,9 @@ impl Guild {
     /// [`position`]: struct.Role.html#structfield.position
     #[cfg(feature = "cache")]
     #[inline]
-    pub async fn greater_member_hierarchy<T, U>(
+    pub fn greater_member_hierarchy<T, U>(
         &self,
-        cache: impl AsRef<CacheRwLock>,
+        cache: impl AsRef<Cache>,
         lhs_id: T,
         rhs_id: U,
     ) -> Option<UserId>
@@ -861,13 +903,12 @@ impl Guild {
         U: Into<UserId>,
     {
         self._greater_member_hierarchy(&cache, lhs_id.into(), rhs_id.into())
-            .await
     }
 
     #[cfg(feature = "cache")]
-    async fn _greater_member_hierarchy(
+    fn _greater_member_hierarchy(
         &self,
-        cache: impl AsRef<CacheRwLock>,
+        cache: impl AsRef<Cache>,
         lhs_id: UserId,
         rhs_id: UserId,
     ) -> Option<UserId> {
@@ -887,13 +928,11 @@ impl Guild {
             .members
             .get(&lhs_id)?
             .highest_role_info(&cache)
-            .await
             .unwrap_or((RoleId(0), 0));
         let rhs = self
             .members
             .get(&rhs_id)?
             .highest_role_info(&cache)
-            .await
             .unwrap_or((RoleId(0), 0));
 
         // If LHS and RHS both have no top position or have the same role ID,
@@ -957,7 +996,7 @@ impl Guild {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::MANAGE_GUILD;
 
-                if !self.has_perms(cache, req).await {
+                if !self.has_perms(cache, req) {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -1089,9 +1128,9 @@ impl Guild {
         self.members
             .values()
             .find(|member| {
-                let name_matches = member.user.read().name == name;
+                let name_matches = member.user.name == name;
                 let discrim_matches = match discrim {
-                    Some(discrim) => member.user.read().discriminator == discrim,
+                    Some(discrim) => member.user.discriminator == discrim,
                     None => true,
                 };
 
@@ -1124,9 +1163,9 @@ impl Guild {
             .values()
             .filter(|member|
                 if case_sensitive {
-                    member.user.read().name.starts_with(prefix)
+                    member.user.name.starts_with(prefix)
                 } else {
-                    contains_case_insensitive(&member.user.read().name, prefix)
+                    contains_case_insensitive(&member.user.name, prefix)
                 }
                 || member.nick.as_ref()
                     .map_or(false, |nick|
@@ -1141,24 +1180,24 @@ impl Guild {
             members.sort_by(|a, b| {
                 let name_a = match a.nick {
                     Some(ref nick) => {
-                        if contains_case_insensitive(&a.user.read().name[..], prefix) {
-                            Cow::Owned(a.user.read().name.clone())
+                        if contains_case_insensitive(&a.user.name[..], prefix) {
+                            Cow::Owned(a.user.name.clone())
                         } else {
                             Cow::Borrowed(nick)
                         }
                     }
-                    None => Cow::Owned(a.user.read().name.clone()),
+                    None => Cow::Owned(a.user.name.clone()),
                 };
 
                 let name_b = match b.nick {
                     Some(ref nick) => {
-                        if contains_case_insensitive(&b.user.read().name[..], prefix) {
-                            Cow::Owned(b.user.read().name.clone())
+                        if contains_case_insensitive(&b.user.name[..], prefix) {
+                            Cow::Owned(b.user.name.clone())
                         } else {
                             Cow::Borrowed(nick)
                         }
                     }
-                    None => Cow::Owned(b.user.read().name.clone()),
+                    None => Cow::Owned(b.user.name.clone()),
                 };
                 closest_to_origin(prefix, &name_a[..], &name_b[..])
             });
@@ -1200,9 +1239,9 @@ impl Guild {
             .values()
             .filter(|member|
                 if case_sensitive {
-                    member.user.read().name.contains(substring)
+                    member.user.name.contains(substring)
                 } else {
-                    contains_case_insensitive(&member.user.read().name, substring)
+                    contains_case_insensitive(&member.user.name, substring)
                 }
                 || member.nick.as_ref()
                     .map_or(false, |nick| {
@@ -1218,24 +1257,24 @@ impl Guild {
             members.sort_by(|a, b| {
                 let name_a = match a.nick {
                     Some(ref nick) => {
-                        if contains_case_insensitive(&a.user.read().name[..], substring) {
-                            Cow::Owned(a.user.read().name.clone())
+                        if contains_case_insensitive(&a.user.name[..], substring) {
+                            Cow::Owned(a.user.name.clone())
                         } else {
                             Cow::Borrowed(nick)
                         }
                     }
-                    None => Cow::Owned(a.user.read().name.clone()),
+                    None => Cow::Owned(a.user.name.clone()),
                 };
 
                 let name_b = match b.nick {
                     Some(ref nick) => {
-                        if contains_case_insensitive(&b.user.read().name[..], substring) {
-                            Cow::Owned(b.user.read().name.clone())
+                        if contains_case_insensitive(&b.user.name[..], substring) {
+                            Cow::Owned(b.user.name.clone())
                         } else {
                             Cow::Borrowed(nick)
                         }
                     }
-                    None => Cow::Owned(b.user.read().name.clone()),
+                    None => Cow::Owned(b.user.name.clone()),
                 };
 
                 closest_to_origin(substring, &name_a[..], &name_b[..])
@@ -1273,16 +1312,16 @@ impl Guild {
             .values()
             .filter(|member| {
                 if case_sensitive {
-                    member.user.read().name.contains(substring)
+                    member.user.name.contains(substring)
                 } else {
-                    contains_case_insensitive(&member.user.read().name, substring)
+                    contains_case_insensitive(&member.user.name, substring)
                 }
             })
             .collect();
         if sorted {
             members.sort_by(|a, b| {
-                let name_a = &a.user.read().name;
-                let name_b = &b.user.read().name;
+                let name_a = &a.user.name;
+                let name_b = &b.user.name;
                 closest_to_origin(substring, &name_a[..], &name_b[..])
             });
             members
@@ -1334,12 +1373,12 @@ impl Guild {
             members.sort_by(|a, b| {
                 let name_a = match a.nick {
                     Some(ref nick) => Cow::Borrowed(nick),
-                    None => Cow::Owned(a.user.read().name.clone()),
+                    None => Cow::Owned(a.user.name.clone()),
                 };
 
                 let name_b = match b.nick {
                     Some(ref nick) => Cow::Borrowed(nick),
-                    None => Cow::Owned(b.user.read().name.clone()),
+                    None => Cow::Owned(b.user.name.clone()),
                 };
 
                 closest_to_origin(substring, &name_a[..], &name_b[..])
@@ -1395,9 +1434,7 @@ impl Guild {
             } else {
                 warn!(
                     "(╯°□°）╯︵ ┻━┻ {} on {} has non-existent role {:?}",
-                    member.user.read().id,
-                    self.id,
-                    role,
+                    member.user.id, self.id, role,
                 );
             }
         }
@@ -1430,136 +1467,36 @@ impl Guild {
     /// [`User`]: ../user/struct.User.html
     #[inline]
     #[deprecated(since = "0.6.4", note = "Please use `user_permissions_in` instead.")]
-    pub async fn permissions_in<C, U>(&self, channel_id: C, user_id: U) -> Permissions
+    pub fn permissions_in<C, U>(&self, channel_id: C, user_id: U) -> Permissions
     where
         C: Into<ChannelId>,
         U: Into<UserId>,
     {
         self.user_permissions_in(channel_id.into(), user_id.into())
-            .await
     }
 
     /// Calculate a [`User`]'s permissions in a given channel in the guild.
     ///
     /// [`User`]: ../user/struct.User.html
     #[inline]
-    pub async fn user_permissions_in<C, U>(&self, channel_id: C, user_id: U) -> Permissions
+    pub fn user_permissions_in<C, U>(&self, channel_id: C, user_id: U) -> Permissions
     where
         C: Into<ChannelId>,
         U: Into<UserId>,
     {
         self._user_permissions_in(channel_id.into(), user_id.into())
-            .await
     }
 
-    async fn _user_permissions_in(&self, channel_id: ChannelId, user_id: UserId) -> Permissions {
-        // The owner has all permissions in all cases.
-        if user_id == self.owner_id {
-            return Permissions::all();
-        }
-
-        // Start by retrieving the @everyone role's permissions.
-        let everyone = match self.roles.get(&RoleId(self.id.0)) {
-            Some(everyone) => everyone,
-            None => {
-                error!(
-                    "(╯°□°）╯︵ ┻━┻ @everyone role ({}) missing in '{}'",
-                    self.id, self.name
-                );
-
-                return Permissions::empty();
-            }
-        };
-
-        // Create a base set of permissions, starting with `@everyone`s.
-        let mut permissions = everyone.permissions;
-
-        let member = match self.members.get(&user_id) {
-            Some(member) => member,
-            None => return everyone.permissions,
-        };
-
-        for &role in &member.roles {
-            if let Some(role) = self.roles.get(&role) {
-                permissions |= role.permissions;
-            } else {
-                warn!(
-                    "(╯°□°）╯︵ ┻━┻ {} on {} has non-existent role {:?}",
-                    member.user.read().id,
-                    self.id,
-                    role
-                );
-            }
-        }
-
-        // Administrators have all permissions in any channel.
-        if permissions.contains(Permissions::ADMINISTRATOR) {
-            return Permissions::all();
-        }
-
-        if let Some(channel) = self.channels.get(&channel_id) {
-            let channel = channel.read().await;
-            // If this is a text channel, then throw out voice permissions.
-            if channel.kind == ChannelType::Text {
-                permissions &= !(Permissions::CONNECT
-                    | Permissions::SPEAK
-                    | Permissions::MUTE_MEMBERS
-                    | Permissions::DEAFEN_MEMBERS
-                    | Permissions::MOVE_MEMBERS
-                    | Permissions::USE_VAD);
-            }
-
-            // Apply the permission overwrites for the channel for each of the
-            // overwrites that - first - applies to the member's roles, and then
-            // the member itself.
-            //
-            // First apply the denied permission overwrites for each, then apply
-            // the allowed.
-
-            let mut data = Vec::with_capacity(member.roles.len());
-
-            // Roles
-            for overwrite in &channel.permission_overwrites {
-                if let PermissionOverwriteType::Role(role) = overwrite.kind {
-                    if role.0 != self.id.0 && !member.roles.contains(&role) {
-                        continue;
-                    }
-
-                    if let Some(role) = self.roles.get(&role) {
-                        data.push((role.position, overwrite.deny, overwrite.allow));
-                    }
-                }
-            }
-
-            data.sort_by(|a, b| a.0.cmp(&b.0));
-
-            for overwrite in data {
-                permissions = (permissions & !overwrite.1) | overwrite.2;
-            }
-
-            // Member
-            for overwrite in &channel.permission_overwrites {
-                if PermissionOverwriteType::Member(user_id) != overwrite.kind {
-                    continue;
-                }
-
-                permissions = (permissions & !overwrite.deny) | overwrite.allow;
-            }
-        } else {
-            warn!(
-                "(╯°□°）╯︵ ┻━┻ Guild {} does not contain channel {}",
-                self.id, channel_id
-            );
-        }
-
-        // The default channel is always readable.
-        if channel_id.0 == self.id.0 {
-            permissions |= Permissions::READ_MESSAGES;
-        }
-
-        self.remove_unusable_permissions(&mut permissions);
-
-        permissions
+    fn _user_permissions_in(&self, channel_id: ChannelId, user_id: UserId) -> Permissions {
+        user_permissions_in(
+            self.id,
+            self.owner_id,
+            &self.roles,
+            self.members.get(&user_id),
+            user_id,
+            channel_id,
+            self.channels.get(&channel_id),
+        )
     }
 
     /// Calculate a [`Role`]'s permissions in a given channel in the guild.
@@ -1567,20 +1504,15 @@ impl Guild {
     ///
     /// [`Role`]: ../guild/struct.Role.html
     #[inline]
-    pub async fn role_permissions_in<C, R>(&self, channel_id: C, role_id: R) -> Option<Permissions>
+    pub fn role_permissions_in<C, R>(&self, channel_id: C, role_id: R) -> Option<Permissions>
     where
         C: Into<ChannelId>,
         R: Into<RoleId>,
     {
         self._role_permissions_in(channel_id.into(), role_id.into())
-            .await
     }
 
-    async fn _role_permissions_in(
-        &self,
-        channel_id: ChannelId,
-        role_id: RoleId,
-    ) -> Option<Permissions> {
+    fn _role_permissions_in(&self, channel_id: ChannelId, role_id: RoleId) -> Option<Permissions> {
         let mut permissions = match self.roles.get(&role_id) {
             Some(role) => role.permissions,
             None => return None,
@@ -1591,7 +1523,6 @@ impl Guild {
         }
 
         if let Some(channel) = self.channels.get(&channel_id) {
-            let channel = channel.read().await;
             for overwrite in &channel.permission_overwrites {
                 if let PermissionOverwriteType::Role(permissions_role_id) = overwrite.kind {
                     if permissions_role_id == role_id {
@@ -1610,7 +1541,7 @@ impl Guild {
             return None;
         }
 
-        self.remove_unusable_permissions(&mut permissions);
+        remove_unusable_permissions(&mut permissions);
 
         Some(permissions)
     }
@@ -1638,7 +1569,7 @@ impl Guild {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::KICK_MEMBERS;
 
-                if !self.has_perms(cache, req).await {
+                if !self.has_perms(cache, req) {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -1647,29 +1578,6 @@ impl Guild {
         self.id.prune_count(cache_http.http(), days).await
     }
 
-    fn remove_unusable_permissions(&self, permissions: &mut Permissions) {
-        // No SEND_MESSAGES => no message-sending-related actions
-        // If the member does not have the `SEND_MESSAGES` permission, then
-        // throw out message-able permissions.
-        if !permissions.contains(Permissions::SEND_MESSAGES) {
-            *permissions &= !(Permissions::SEND_TTS_MESSAGES
-                | Permissions::MENTION_EVERYONE
-                | Permissions::EMBED_LINKS
-                | Permissions::ATTACH_FILES);
-        }
-
-        // If the permission does not have the `READ_MESSAGES` permission, then
-        // throw out actionable permissions.
-        if !permissions.contains(Permissions::READ_MESSAGES) {
-            *permissions &= Permissions::KICK_MEMBERS
-                | Permissions::BAN_MEMBERS
-                | Permissions::ADMINISTRATOR
-                | Permissions::MANAGE_GUILD
-                | Permissions::CHANGE_NICKNAME
-                | Permissions::MANAGE_NICKNAMES;
-        }
-    }
-
     /// Re-orders the channels of the guild.
     ///
     /// Although not required, you should specify all channels' positions,
@@ -1695,8 +1603,8 @@ impl Guild {
     /// [`utils::shard_id`]: ../../utils/fn.shard_id.html
     #[cfg(all(feature = "cache", feature = "utils"))]
     #[inline]
-    pub async fn shard_id(&self, cache: impl AsRef<CacheRwLock>) -> u64 {
-        self.id.shard_id(&cache).await
+    pub fn shard_id(&self, cache: impl AsRef<Cache>) -> u64 {
+        self.id.shard_id(&cache)
     }
 
     /// Returns the Id of the shard associated with the guild.
@@ -1769,7 +1677,7 @@ impl Guild {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::KICK_MEMBERS;
 
-                if !self.has_perms(cache, req).await {
+                if !self.has_perms(cache, req) {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -1778,6 +1686,67 @@ impl Guild {
         self.id.start_prune(cache_http.http(), days).await
     }
 
+    /// Sends a welcome message for a member to the guild's system channel,
+    /// filling in the given template with [`utils::template`].
+    ///
+    /// Returns `None` if the guild has no system channel.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// # Examples
+    ///
+    /// Welcoming members as they join:
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(all(feature = "cache", feature = "client"))]
+    /// # mod run {
+    /// use async_trait::async_trait;
+    /// use serenity::model::prelude::*;
+    /// use serenity::prelude::*;
+    ///
+    /// struct Handler;
+    ///
+    /// #[async_trait]
+    /// impl EventHandler for Handler {
+    ///     async fn guild_member_addition(&self, ctx: Context, guild_id: GuildId, member: Member) {
+    ///         if let Some(guild) = ctx.cache.guild(guild_id) {
+    ///             let template = "Welcome to {guild}, {user.mention}!";
+    ///             let _ = guild.send_welcome_message(&ctx.http, &member, template).await;
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`utils::template`]: ../../utils/fn.template.html
+    /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    pub async fn send_welcome_message(
+        &self,
+        http: impl AsRef<Http>,
+        member: &Member,
+        template: &str,
+    ) -> Result<Option<Message>> {
+        let channel_id = match self.system_channel_id {
+            Some(channel_id) => channel_id,
+            None => return Ok(None),
+        };
+
+        let content = crate::utils::template(template, member, self);
+
+        channel_id.say(http, content).await.map(Some)
+    }
+
+    /// Retrieves the guild's templates.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn templates(&self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        self.id.templates(&http).await
+    }
+
     /// Unbans the given [`User`] from the guild.
     ///
     /// **Note**: Requires the [Ban Members] permission.
@@ -1801,7 +1770,7 @@ impl Guild {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::BAN_MEMBERS;
 
-                if !self.has_perms(cache, req).await {
+                if !self.has_perms(cache, req) {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -1811,14 +1780,15 @@ impl Guild {
         self.id.unban(&h, user_id).await
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Retrieves the guild's vanity invite, including its code and how many
+    /// times it was used.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     #[cfg(feature = "http")]
     #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<VanityInvite> {
         self.id.vanity_url(&http).await
     }
 
@@ -1833,6 +1803,37 @@ impl Guild {
         self.id.webhooks(&http).await
     }
 
+    /// Retrieves the guild's [`WelcomeScreen`].
+    ///
+    /// [`WelcomeScreen`]: struct.WelcomeScreen.html
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn welcome_screen(&self, http: impl AsRef<Http>) -> Result<WelcomeScreen> {
+        self.id.welcome_screen(&http).await
+    }
+
+    /// Edits the guild's [`WelcomeScreen`], returning the updated version.
+    ///
+    /// Refer to [`GuildId::edit_welcome_screen`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildId::edit_welcome_screen`]: ../id/struct.GuildId.html#method.edit_welcome_screen
+    /// [`WelcomeScreen`]: struct.WelcomeScreen.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[cfg(feature = "http")]
+    #[inline]
+    pub async fn edit_welcome_screen<F>(
+        &self,
+        http: impl AsRef<Http>,
+        f: F,
+    ) -> Result<WelcomeScreen>
+    where
+        F: FnOnce(&mut EditGuildWelcomeScreen) -> &mut EditGuildWelcomeScreen,
+    {
+        self.id.edit_welcome_screen(&http, f).await
+    }
+
     /// Obtain a reference to a role by its name.
     ///
     /// **Note**: If two or more roles have the same name, obtained reference will be one of
@@ -1855,8 +1856,8 @@ impl Guild {
     /// #[async_trait]
     /// impl EventHandler for Handler {
     ///     async fn message(&self, ctx: Context, msg: Message) {
-    ///         if let Some(arc) = msg.guild_id.unwrap().to_guild_cached(&ctx.cache).await {
-    ///             if let Some(role) = arc.read().await.role_by_name("role_name") {
+    ///         if let Some(guild) = msg.guild_id.unwrap().to_guild_cached(&ctx.cache) {
+    ///             if let Some(role) = guild.role_by_name("role_name") {
     ///                 println!("{:?}", role);
     ///             }
     ///         }
@@ -1878,6 +1879,38 @@ impl Guild {
     }
 }
 
+impl Guild {
+    /// Updates the guild with the data of a [`PartialGuild`], such as the one
+    /// received in a [`GuildUpdateEvent`].
+    ///
+    /// Only the fields present on the partial guild are replaced, so data
+    /// only a full guild carries - like its channels, members, presences and
+    /// voice states - is kept as-is.
+    ///
+    /// [`GuildUpdateEvent`]: ../event/struct.GuildUpdateEvent.html
+    /// [`PartialGuild`]: struct.PartialGuild.html
+    pub fn merge_partial(&mut self, partial: &PartialGuild) {
+        self.afk_channel_id = partial.afk_channel_id;
+        self.afk_timeout = partial.afk_timeout;
+        self.default_message_notifications = partial.default_message_notifications;
+        self.emojis.clone_from(&partial.emojis);
+        self.features.clone_from(&partial.features);
+        self.icon.clone_from(&partial.icon);
+        self.mfa_level = partial.mfa_level;
+        self.name.clone_from(&partial.name);
+        self.owner_id = partial.owner_id;
+        self.region.clone_from(&partial.region);
+        self.roles.clone_from(&partial.roles);
+        self.splash.clone_from(&partial.splash);
+        self.verification_level = partial.verification_level;
+        self.description.clone_from(&partial.description);
+        self.premium_tier = partial.premium_tier;
+        self.premium_subscription_count = partial.premium_subscription_count;
+        self.banner.clone_from(&partial.banner);
+        self.vanity_url_code.clone_from(&partial.vanity_url_code);
+    }
+}
+
 impl<'de> Deserialize<'de> for Guild {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
         let mut map = JsonMap::deserialize(deserializer)?;
@@ -1899,6 +1932,17 @@ impl<'de> Deserialize<'de> for Guild {
                 }
             }
 
+            if let Some(array) = map.get_mut("threads").and_then(|x| x.as_array_mut()) {
+                for value in array {
+                    if let Some(thread) = value.as_object_mut() {
+                        thread.insert(
+                            "guild_id".to_string(),
+                            Value::Number(Number::from(guild_id)),
+                        );
+                    }
+                }
+            }
+
             if let Some(array) = map.get_mut("members").and_then(|x| x.as_array_mut()) {
                 for value in array {
                     if let Some(member) = value.as_object_mut() {
@@ -1963,7 +2007,7 @@ impl<'de> Deserialize<'de> for Guild {
         let joined_at = map
             .remove("joined_at")
             .ok_or_else(|| DeError::custom("expected guild joined_at"))
-            .and_then(DateTime::deserialize)
+            .and_then(Timestamp::deserialize)
             .map_err(DeError::custom)?;
         let large = map
             .remove("large")
@@ -2028,6 +2072,10 @@ impl<'de> Deserialize<'de> for Guild {
             .ok_or_else(|| DeError::custom("expected guild voice_states"))
             .and_then(deserialize_voice_states)
             .map_err(DeError::custom)?;
+        let threads = match map.remove("threads") {
+            Some(v) => deserialize_guild_threads(v).map_err(DeError::custom)?,
+            None => HashMap::new(),
+        };
         let description = match map.remove("description") {
             Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
             None => None,
@@ -2079,6 +2127,7 @@ impl<'de> Deserialize<'de> for Guild {
             system_channel_id,
             verification_level,
             voice_states,
+            threads,
             description,
             premium_tier,
             premium_subscription_count,
@@ -2089,6 +2138,150 @@ impl<'de> Deserialize<'de> for Guild {
     }
 }
 
+/// Calculates a member's permissions in a channel of a guild.
+///
+/// This is shared by [`Guild`] and [`PartialGuild`], so that permissions can
+/// also be calculated from data retrieved over the REST API.
+///
+/// [`Guild`]: struct.Guild.html
+/// [`PartialGuild`]: struct.PartialGuild.html
+#[cfg(feature = "model")]
+pub(crate) fn user_permissions_in(
+    guild_id: GuildId,
+    owner_id: UserId,
+    roles: &HashMap<RoleId, Role>,
+    member: Option<&Member>,
+    user_id: UserId,
+    channel_id: ChannelId,
+    channel: Option<&GuildChannel>,
+) -> Permissions {
+    // The owner has all permissions in all cases.
+    if user_id == owner_id {
+        return Permissions::all();
+    }
+
+    // Start by retrieving the @everyone role's permissions.
+    let everyone = match roles.get(&RoleId(guild_id.0)) {
+        Some(everyone) => everyone,
+        None => {
+            error!("(╯°□°）╯︵ ┻━┻ @everyone role missing in {}", guild_id);
+
+            return Permissions::empty();
+        }
+    };
+
+    // Create a base set of permissions, starting with `@everyone`s.
+    let mut permissions = everyone.permissions;
+
+    let member = match member {
+        Some(member) => member,
+        None => return everyone.permissions,
+    };
+
+    for &role in &member.roles {
+        if let Some(role) = roles.get(&role) {
+            permissions |= role.permissions;
+        } else {
+            warn!(
+                "(╯°□°）╯︵ ┻━┻ {} on {} has non-existent role {:?}",
+                member.user.id, guild_id, role
+            );
+        }
+    }
+
+    // Administrators have all permissions in any channel.
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        return Permissions::all();
+    }
+
+    if let Some(channel) = channel {
+        // If this is a text channel, then throw out voice permissions.
+        if channel.kind == ChannelType::Text {
+            permissions &= !(Permissions::CONNECT
+                | Permissions::SPEAK
+                | Permissions::MUTE_MEMBERS
+                | Permissions::DEAFEN_MEMBERS
+                | Permissions::MOVE_MEMBERS
+                | Permissions::USE_VAD);
+        }
+
+        // Apply the permission overwrites for the channel for each of the
+        // overwrites that - first - applies to the member's roles, and then
+        // the member itself.
+        //
+        // First apply the denied permission overwrites for each, then apply
+        // the allowed.
+
+        let mut data = Vec::with_capacity(member.roles.len());
+
+        // Roles
+        for overwrite in &channel.permission_overwrites {
+            if let PermissionOverwriteType::Role(role) = overwrite.kind {
+                if role.0 != guild_id.0 && !member.roles.contains(&role) {
+                    continue;
+                }
+
+                if let Some(role) = roles.get(&role) {
+                    data.push((role.position, overwrite.deny, overwrite.allow));
+                }
+            }
+        }
+
+        data.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for overwrite in data {
+            permissions = (permissions & !overwrite.1) | overwrite.2;
+        }
+
+        // Member
+        for overwrite in &channel.permission_overwrites {
+            if PermissionOverwriteType::Member(user_id) != overwrite.kind {
+                continue;
+            }
+
+            permissions = (permissions & !overwrite.deny) | overwrite.allow;
+        }
+    } else {
+        warn!(
+            "(╯°□°）╯︵ ┻━┻ Guild {} does not contain channel {}",
+            guild_id, channel_id
+        );
+    }
+
+    // The default channel is always readable.
+    if channel_id.0 == guild_id.0 {
+        permissions |= Permissions::READ_MESSAGES;
+    }
+
+    remove_unusable_permissions(&mut permissions);
+
+    permissions
+}
+
+#[cfg(feature = "model")]
+fn remove_unusable_permissions(permissions: &mut Permissions) {
+    // No SEND_MESSAGES => no message-sending-related actions
+    // If the member does not have the `SEND_MESSAGES` permission, then
+    // throw out message-able permissions.
+    if !permissions.contains(Permissions::SEND_MESSAGES) {
+        *permissions &= !(Permissions::SEND_TTS_MESSAGES
+            | Permissions::MENTION_EVERYONE
+            | Permissions::EMBED_LINKS
+            | Permissions::ATTACH_FILES);
+    }
+
+    // If the permission does not have the `READ_MESSAGES` permission, then
+    // throw out actionable permissions.
+    if !permissions.contains(Permissions::READ_MESSAGES) {
+        *permissions &= Permissions::KICK_MEMBERS
+            | Permissions::BAN_MEMBERS
+            | Permissions::ADMINISTRATOR
+            | Permissions::MANAGE_GUILD
+            | Permissions::CHANGE_NICKNAME
+            | Permissions::MANAGE_NICKNAMES;
+    }
+}
+
 /// Checks if a `&str` contains another `&str`.
 #[cfg(feature = "model")]
 fn contains_case_insensitive(to_look_at: &str, to_find: &str) -> bool {
@@ -2236,7 +2429,6 @@ pub enum GuildStatus {
     Offline(GuildUnavailable),
 }
 
-#[cfg(feature = "model")]
 impl GuildStatus {
     /// Retrieves the Id of the inner [`Guild`].
     ///
@@ -2250,88 +2442,41 @@ impl GuildStatus {
     }
 }
 
-/// Default message notification level for a guild.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
-#[non_exhaustive]
-pub enum DefaultMessageNotificationLevel {
-    /// Receive notifications for everything.
-    All = 0,
-    /// Receive only mentions.
-    Mentions = 1,
-}
-
-#[rustfmt::skip]
-enum_number!(
-    DefaultMessageNotificationLevel {
-        All,
-        Mentions,
-    }
-);
-
-impl DefaultMessageNotificationLevel {
-    pub fn num(self) -> u64 {
-        match self {
-            DefaultMessageNotificationLevel::All => 0,
-            DefaultMessageNotificationLevel::Mentions => 1,
-        }
+enum_number! {
+    /// Default message notification level for a guild.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum DefaultMessageNotificationLevel {
+        /// Receive notifications for everything.
+        All = 0,
+        /// Receive only mentions.
+        Mentions = 1,
     }
 }
 
-/// Setting used to filter explicit messages from members.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
-#[non_exhaustive]
-pub enum ExplicitContentFilter {
-    /// Don't scan any messages.
-    None = 0,
-    /// Scan messages from members without a role.
-    WithoutRole = 1,
-    /// Scan messages sent by all members.
-    All = 2,
-}
-
-#[rustfmt::skip]
-enum_number!(
-    ExplicitContentFilter {
-        None,
-        WithoutRole,
-        All,
-    }
-);
-
-impl ExplicitContentFilter {
-    pub fn num(self) -> u64 {
-        match self {
-            ExplicitContentFilter::None => 0,
-            ExplicitContentFilter::WithoutRole => 1,
-            ExplicitContentFilter::All => 2,
-        }
+enum_number! {
+    /// Setting used to filter explicit messages from members.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum ExplicitContentFilter {
+        /// Don't scan any messages.
+        None = 0,
+        /// Scan messages from members without a role.
+        WithoutRole = 1,
+        /// Scan messages sent by all members.
+        All = 2,
     }
 }
 
-/// Multi-Factor Authentication level for guild moderators.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
-#[non_exhaustive]
-pub enum MfaLevel {
-    /// MFA is disabled.
-    None = 0,
-    /// MFA is enabled.
-    Elevated = 1,
-}
-
-#[rustfmt::skip]
-enum_number!(
-    MfaLevel {
-        None,
-        Elevated,
-    }
-);
-
-impl MfaLevel {
-    pub fn num(self) -> u64 {
-        match self {
-            MfaLevel::None => 0,
-            MfaLevel::Elevated => 1,
-        }
+enum_number! {
+    /// Multi-Factor Authentication level for guild moderators.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum MfaLevel {
+        /// MFA is disabled.
+        None = 0,
+        /// MFA is enabled.
+        Elevated = 1,
     }
 }
 
@@ -2402,42 +2547,24 @@ impl Region {
     }
 }
 
-/// The level to set as criteria prior to a user being able to send
-/// messages in a [`Guild`].
-///
-/// [`Guild`]: struct.Guild.html
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
-#[non_exhaustive]
-pub enum VerificationLevel {
-    /// Does not require any verification.
-    None = 0,
-    /// Must have a verified email on the user's Discord account.
-    Low = 1,
-    /// Must also be a registered user on Discord for longer than 5 minutes.
-    Medium = 2,
-    /// Must also be a member of the guild for longer than 10 minutes.
-    High = 3,
-    /// Must have a verified phone on the user's Discord account.
-    Higher = 4,
-}
-
-enum_number!(VerificationLevel {
-    None,
-    Low,
-    Medium,
-    High,
-    Higher,
-});
-
-impl VerificationLevel {
-    pub fn num(self) -> u64 {
-        match self {
-            VerificationLevel::None => 0,
-            VerificationLevel::Low => 1,
-            VerificationLevel::Medium => 2,
-            VerificationLevel::High => 3,
-            VerificationLevel::Higher => 4,
-        }
+enum_number! {
+    /// The level to set as criteria prior to a user being able to send
+    /// messages in a [`Guild`].
+    ///
+    /// [`Guild`]: struct.Guild.html
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum VerificationLevel {
+        /// Does not require any verification.
+        None = 0,
+        /// Must have a verified email on the user's Discord account.
+        Low = 1,
+        /// Must also be a registered user on Discord for longer than 5 minutes.
+        Medium = 2,
+        /// Must also be a member of the guild for longer than 10 minutes.
+        High = 3,
+        /// Must have a verified phone on the user's Discord account.
+        Higher = 4,
     }
 }
 
@@ -2445,12 +2572,9 @@ impl VerificationLevel {
 mod test {
     #[cfg(feature = "model")]
     mod model {
-        use crate::internal::SyncRwLock;
         use crate::model::prelude::*;
         use crate::utils::run_async_test;
-        use chrono::prelude::*;
         use std::collections::*;
-        use std::sync::Arc;
 
         fn gen_user() -> User {
             User {
@@ -2463,11 +2587,9 @@ mod test {
         }
 
         fn gen_member() -> Member {
-            let dt: DateTime<FixedOffset> = FixedOffset::east(5 * 3600)
-                .ymd(2016, 11, 08)
-                .and_hms(0, 0, 0);
+            let dt = Timestamp::parse("2016-11-08T00:00:00+05:00").unwrap();
             let vec1 = Vec::new();
-            let u = Arc::new(SyncRwLock::new(gen_user()));
+            let u = gen_user();
 
             Member {
                 deaf: false,
@@ -2487,9 +2609,7 @@ mod test {
             let hm1 = HashMap::new();
             let hm2 = HashMap::new();
             let vec1 = Vec::new();
-            let dt: DateTime<FixedOffset> = FixedOffset::east(5 * 3600)
-                .ymd(2016, 11, 08)
-                .and_hms(0, 0, 0);
+            let dt = Timestamp::parse("2016-11-08T00:00:00+05:00").unwrap();
             let mut hm3 = HashMap::new();
             let hm4 = HashMap::new();
             let hm5 = HashMap::new();
@@ -2521,6 +2641,7 @@ mod test {
                 splash: Some("asdf".to_string()),
                 verification_level: VerificationLevel::None,
                 voice_states: hm6,
+                threads: HashMap::new(),
                 description: None,
                 premium_tier: PremiumTier::Tier1,
                 application_id: Some(ApplicationId(0)),
@@ -2554,5 +2675,145 @@ mod test {
                 assert_eq!(lhs, rhs.display_name());
             });
         }
+
+        #[cfg(feature = "cache")]
+        #[tokio::test]
+        async fn name_or_fetch() {
+            use crate::cache::Cache;
+            use crate::http::mock::{MockResponse, MockServer};
+            use serde_json::json;
+            use std::sync::Arc;
+
+            let server = MockServer::start().await;
+            server.enqueue(MockResponse::json(
+                200,
+                &json!({
+                    "id": "2",
+                    "afk_channel_id": null,
+                    "afk_timeout": 300,
+                    "default_message_notifications": 0,
+                    "embed_channel_id": null,
+                    "embed_enabled": false,
+                    "emojis": [],
+                    "features": [],
+                    "icon": null,
+                    "mfa_level": 0,
+                    "name": "Fetched",
+                    "owner_id": "210",
+                    "region": "us-west",
+                    "roles": [],
+                    "splash": null,
+                    "verification_level": 0,
+                    "description": null,
+                    "premium_tier": 0,
+                    "premium_subscription_count": 0,
+                    "banner": null,
+                    "vanity_url_code": null,
+                }),
+            ));
+
+            let http = server.http("Bot token");
+            let cache = Arc::new(Cache::new());
+            cache.guilds.insert(GuildId(1), gen());
+
+            let cached = GuildId(1).name_or_fetch((&cache, &http)).await.unwrap();
+            let fetched = GuildId(2).name_or_fetch((&cache, &http)).await.unwrap();
+
+            assert_eq!(cached, "Spaghetti");
+            assert_eq!(fetched, "Fetched");
+            assert_eq!(server.requests().len(), 1);
+        }
+
+        fn gen_partial() -> PartialGuild {
+            serde_json::from_value(serde_json::json!({
+                "id": "1",
+                "afk_channel_id": null,
+                "afk_timeout": 300,
+                "default_message_notifications": 1,
+                "embed_channel_id": null,
+                "embed_enabled": false,
+                "emojis": [],
+                "features": ["BANNER"],
+                "icon": null,
+                "mfa_level": 0,
+                "name": "Renamed",
+                "owner_id": "211",
+                "region": "us-west",
+                "roles": [],
+                "splash": null,
+                "verification_level": 2,
+                "description": "desc",
+                "premium_tier": 2,
+                "premium_subscription_count": 15,
+                "banner": "banner",
+                "vanity_url_code": null,
+            }))
+            .unwrap()
+        }
+
+        #[test]
+        fn merge_partial() {
+            let mut guild = gen();
+            guild.merge_partial(&gen_partial());
+
+            assert_eq!(guild.name, "Renamed");
+            assert_eq!(guild.owner_id, UserId(211));
+            assert_eq!(guild.afk_channel_id, None);
+            assert_eq!(guild.premium_subscription_count, 15);
+            assert_eq!(guild.features, vec!["BANNER".to_string()]);
+            assert_eq!(guild.members.len(), 1);
+            assert_eq!(guild.system_channel_id, Some(ChannelId(0)));
+        }
+
+        #[tokio::test]
+        async fn fetch_full_over_rest() {
+            use crate::http::mock::{MockResponse, MockServer};
+            use serde_json::json;
+
+            let server = MockServer::start().await;
+            server.enqueue(MockResponse::json(200, &json!([])));
+            server.enqueue(MockResponse::json(
+                200,
+                &json!([{
+                    "deaf": false,
+                    "joined_at": "2017-01-01T00:00:00+00:00",
+                    "mute": false,
+                    "nick": null,
+                    "roles": [],
+                    "user": {
+                        "id": "210",
+                        "avatar": null,
+                        "bot": true,
+                        "discriminator": "1432",
+                        "username": "test",
+                    },
+                }]),
+            ));
+            server.enqueue(MockResponse::json(
+                200,
+                &json!({
+                    "id": "210",
+                    "avatar": null,
+                    "bot": true,
+                    "discriminator": "1432",
+                    "username": "test",
+                    "mfa_enabled": false,
+                    "verified": true,
+                    "email": null,
+                }),
+            ));
+
+            let http = server.http("Bot token");
+            let guild = gen_partial().fetch_full(&http).await.unwrap();
+
+            assert_eq!(guild.name, "Renamed");
+            assert_eq!(guild.member_count, 1);
+            assert!(guild.members.contains_key(&UserId(210)));
+            assert_eq!(
+                guild.joined_at,
+                Timestamp::parse("2017-01-01T00:00:00+00:00").unwrap()
+            );
+            assert_eq!(server.requests().len(), 3);
+        }
     }
 }
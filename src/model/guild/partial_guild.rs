@@ -6,7 +6,7 @@ use crate::model::prelude::*;
 #[cfg(feature = "model")]
 use crate::builder::{CreateChannel, EditGuild, EditMember, EditRole};
 #[cfg(all(feature = "cache", feature = "utils", feature = "client"))]
-use crate::cache::CacheRwLock;
+use crate::cache::Cache;
 #[cfg(feature = "http")]
 use crate::http::Http;
 
@@ -383,6 +383,88 @@ impl PartialGuild {
         self.id.edit_nickname(&http, new_nickname).await
     }
 
+    /// Upgrades the partial guild into a full [`Guild`].
+    ///
+    /// If the `cache` feature is enabled and the guild is cached, the cached
+    /// guild is returned. Otherwise, the guild's channels and members are
+    /// fetched over the REST API and combined with the partial guild.
+    ///
+    /// **Note**: Presences and voice states are only sent over the gateway,
+    /// so they are left empty on a guild built over the REST API. If the
+    /// current user's join date can not be found, the guild's creation date
+    /// is used in its place.
+    ///
+    /// [`Guild`]: struct.Guild.html
+    #[cfg(feature = "http")]
+    pub async fn fetch_full(&self, cache_http: impl CacheHttp) -> Result<Guild> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(guild) = cache.guild(self.id) {
+                    return Ok(guild);
+                }
+            }
+        }
+
+        let http = cache_http.http();
+        let channels = self.id.channels(http).await?;
+
+        let grab_size = 1000;
+        let mut members = HashMap::new();
+        let mut after = None;
+
+        loop {
+            let chunk = self.id.members(http, Some(grab_size), after).await?;
+            let done = chunk.len() < grab_size as usize;
+            after = chunk.last().map(|member| member.user.id);
+            members.extend(chunk.into_iter().map(|member| (member.user.id, member)));
+
+            if done {
+                break;
+            }
+        }
+
+        let current_user_id = http.get_current_user().await?.id;
+        let joined_at = members
+            .get(&current_user_id)
+            .and_then(|member| member.joined_at)
+            .unwrap_or_else(|| self.id.created_at());
+
+        Ok(Guild {
+            id: self.id,
+            afk_channel_id: self.afk_channel_id,
+            afk_timeout: self.afk_timeout,
+            application_id: None,
+            channels,
+            default_message_notifications: self.default_message_notifications,
+            emojis: self.emojis.clone(),
+            explicit_content_filter: ExplicitContentFilter::None,
+            features: self.features.clone(),
+            icon: self.icon.clone(),
+            joined_at,
+            large: members.len() > crate::constants::LARGE_THRESHOLD as usize,
+            member_count: members.len() as u64,
+            members,
+            mfa_level: self.mfa_level,
+            name: self.name.clone(),
+            owner_id: self.owner_id,
+            presences: HashMap::new(),
+            region: self.region.clone(),
+            roles: self.roles.clone(),
+            splash: self.splash.clone(),
+            system_channel_id: None,
+            verification_level: self.verification_level,
+            voice_states: HashMap::new(),
+            threads: HashMap::new(),
+            description: self.description.clone(),
+            premium_tier: self.premium_tier,
+            premium_subscription_count: self.premium_subscription_count,
+            banner: self.banner.clone(),
+            vanity_url_code: self.vanity_url_code.clone(),
+            preferred_locale: "en-US".to_string(),
+        })
+    }
+
     /// Gets a partial amount of guild data by its Id.
     ///
     /// Requires that the current user be in the guild.
@@ -530,8 +612,8 @@ impl PartialGuild {
     /// [`utils::shard_id`]: ../../utils/fn.shard_id.html
     #[cfg(all(feature = "cache", feature = "utils", feature = "client"))]
     #[inline]
-    pub async fn shard_id(&self, cache: impl AsRef<CacheRwLock>) -> u64 {
-        self.id.shard_id(cache).await
+    pub fn shard_id(&self, cache: impl AsRef<Cache>) -> u64 {
+        self.id.shard_id(cache)
     }
 
     /// Returns the Id of the shard associated with the guild.
@@ -594,14 +676,15 @@ impl PartialGuild {
         self.id.unban(&http, user_id).await
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Retrieves the guild's vanity invite, including its code and how many
+    /// times it was used.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     #[cfg(feature = "http")]
     #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<VanityInvite> {
         self.id.vanity_url(&http).await
     }
 
@@ -660,4 +743,24 @@ impl PartialGuild {
     pub fn role_by_name(&self, role_name: &str) -> Option<&Role> {
         self.roles.values().find(|role| role_name == role.name)
     }
+
+    /// Calculate a [`Member`]'s permissions in a given channel in the guild.
+    ///
+    /// Unlike a cached [`Guild`], a partial guild contains neither members
+    /// nor channels, so both must be provided. This allows permissions to be
+    /// calculated from data retrieved over the REST API.
+    ///
+    /// [`Guild`]: struct.Guild.html
+    /// [`Member`]: struct.Member.html
+    pub fn user_permissions_in(&self, channel: &GuildChannel, member: &Member) -> Permissions {
+        super::user_permissions_in(
+            self.id,
+            self.owner_id,
+            &self.roles,
+            Some(member),
+            member.user.id,
+            channel.id,
+            Some(channel),
+        )
+    }
 }
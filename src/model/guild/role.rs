@@ -4,11 +4,9 @@ use std::cmp::Ordering;
 #[cfg(all(feature = "builder", feature = "cache", feature = "model"))]
 use crate::builder::EditRole;
 #[cfg(feature = "cache")]
-use crate::cache::CacheRwLock;
+use crate::cache::Cache;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::internal::prelude::*;
-#[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
-use async_trait::async_trait;
 
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
 use crate::cache::FromStrAndCache;
@@ -25,7 +23,7 @@ use crate::utils::parse_role;
 /// are unique per guild and do not cross over to other guilds in any way, and
 /// can have channel-specific permission overrides in addition to guild-level
 /// permissions.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Role {
     /// The Id of the role. Can be used to calculate the role's creation date.
@@ -82,10 +80,10 @@ impl Role {
     #[inline]
     pub async fn delete<T>(&mut self, cache_and_http: T) -> Result<()>
     where
-        T: AsRef<CacheRwLock> + AsRef<Http>,
+        T: AsRef<Cache> + AsRef<Http>,
     {
         AsRef::<Http>::as_ref(&cache_and_http)
-            .delete_role(self.find_guild(&cache_and_http).await?.0, self.id.0)
+            .delete_role(self.find_guild(&cache_and_http)?.0, self.id.0)
             .await
     }
 
@@ -118,9 +116,9 @@ impl Role {
         f: F,
     ) -> Result<Role>
     where
-        T: AsRef<CacheRwLock> + AsRef<Http>,
+        T: AsRef<Cache> + AsRef<Http>,
     {
-        match self.find_guild(&cache_and_http).await {
+        match self.find_guild(&cache_and_http) {
             Ok(guild_id) => guild_id.edit_role(&cache_and_http, self.id, f).await,
             Err(e) => Err(e),
         }
@@ -134,16 +132,14 @@ impl Role {
     ///
     /// [`ModelError::GuildNotFound`]: ../error/enum.Error.html#variant.GuildNotFound
     #[cfg(feature = "cache")]
-    pub async fn find_guild(&self, cache: impl AsRef<CacheRwLock>) -> Result<GuildId> {
-        for guild in cache.as_ref().read().await.guilds.values() {
-            let guild = guild.read().await;
-
-            if guild.roles.contains_key(&RoleId(self.id.0)) {
-                return Ok(guild.id);
-            }
-        }
-
-        Err(Error::Model(ModelError::GuildNotFound))
+    pub fn find_guild(&self, cache: impl AsRef<Cache>) -> Result<GuildId> {
+        cache
+            .as_ref()
+            .guilds
+            .iter()
+            .find(|guild| guild.roles.contains_key(&self.id))
+            .map(|guild| guild.id)
+            .ok_or(Error::Model(ModelError::GuildNotFound))
     }
 
     /// Check that the role has the given permission.
@@ -164,19 +160,15 @@ impl Role {
             self.permissions.contains(permissions)
         }
     }
-
-    pub async fn async_to_string(&self) -> String {
-        self.mention().await
-    }
 }
 
-/*
-TODO: async display
 impl Display for Role {
     /// Format a mention for the role, pinging its members.
     // This is in the format of: `<@&ROLE_ID>`.
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult { Display::fmt(&futures::executor::block_on(self.mention()), f) }
-}*/
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.mention(), f)
+    }
+}
 
 impl Eq for Role {}
 
@@ -208,25 +200,12 @@ impl RoleId {
     ///
     /// [`Role`]: ../guild/struct.Role.html
     #[cfg(feature = "cache")]
-    pub async fn to_role_cached(self, cache: impl AsRef<CacheRwLock>) -> Option<Role> {
-        self._to_role_cached(&cache).await
-    }
-
-    #[cfg(feature = "cache")]
-    pub(crate) async fn _to_role_cached(self, cache: impl AsRef<CacheRwLock>) -> Option<Role> {
-        for guild in cache.as_ref().read().await.guilds.values() {
-            let guild = guild.read().await;
-
-            if !guild.roles.contains_key(&self) {
-                continue;
-            }
-
-            if let Some(role) = guild.roles.get(&self) {
-                return Some(role.clone());
-            }
-        }
-
-        None
+    pub fn to_role_cached(self, cache: impl AsRef<Cache>) -> Option<Role> {
+        cache
+            .as_ref()
+            .guilds
+            .iter()
+            .find_map(|guild| guild.roles.get(&self).cloned())
     }
 }
 
@@ -245,13 +224,12 @@ impl<'a> From<&'a Role> for RoleId {
 }
 
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
-#[async_trait]
 impl FromStrAndCache for Role {
     type Err = RoleParseError;
 
-    async fn from_str(cache: &CacheRwLock, s: &str) -> StdResult<Self, Self::Err> {
+    fn from_str(cache: &Cache, s: &str) -> StdResult<Self, Self::Err> {
         match parse_role(s) {
-            Some(x) => match RoleId(x).to_role_cached(&cache).await {
+            Some(x) => match RoleId(x).to_role_cached(cache) {
                 Some(role) => Ok(role),
                 _ => Err(RoleParseError::NotPresentInCache),
             },
@@ -0,0 +1,32 @@
+use super::*;
+
+/// The screen shown to new members of a community guild, highlighting some of
+/// its channels.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#welcome-screen-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct WelcomeScreen {
+    /// The description of the guild shown on the welcome screen, if any.
+    pub description: Option<String>,
+    /// The channels shown on the welcome screen, up to 5.
+    #[serde(default)]
+    pub welcome_channels: Vec<WelcomeChannel>,
+}
+
+/// A channel shown on a guild's [`WelcomeScreen`].
+///
+/// [`WelcomeScreen`]: struct.WelcomeScreen.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct WelcomeChannel {
+    /// The Id of the channel.
+    pub channel_id: ChannelId,
+    /// The description shown for the channel.
+    pub description: String,
+    /// The Id of the channel's custom emoji, if it uses one.
+    pub emoji_id: Option<EmojiId>,
+    /// The name of the channel's emoji, or the emoji itself if it is a
+    /// unicode emoji.
+    pub emoji_name: Option<String>,
+}
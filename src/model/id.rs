@@ -1,9 +1,8 @@
 //! A collection of newtypes defining type-strong IDs.
 
 use super::utils::U64Visitor;
+use super::Timestamp;
 use crate::internal::prelude::*;
-use crate::model::channel::Channel;
-use chrono::{DateTime, FixedOffset, NaiveDateTime};
 use serde::de::{Deserialize, Deserializer};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
@@ -12,13 +11,13 @@ macro_rules! id_u64 {
         $(
             impl $name {
                 /// Retrieves the time that the Id was created at.
-                pub fn created_at(&self) -> DateTime<FixedOffset> {
-                    let offset = self.0 >> 22;
-                    let secs = offset / 1000;
-                    let millis = (offset % 1000) * 1_000_000; // 1 million nanoseconds in a millisecond
+                pub fn created_at(&self) -> Timestamp {
+                    // The top 42 bits are milliseconds since Discord's epoch,
+                    // so this is always in range.
+                    let millis = (self.0 >> 22) as i64;
 
-                    let tm = NaiveDateTime::from_timestamp(1_420_070_400 + secs as i64, millis as u32);
-                    DateTime::from_utc(tm, FixedOffset::east(0))
+                    Timestamp::from_unix_timestamp_millis(1_420_070_400_000 + millis)
+                        .expect("Id timestamp out of range")
                 }
 
                 /// Immutably borrow inner Id.
@@ -96,17 +95,11 @@ pub struct ApplicationId(pub u64);
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct ChannelId(pub u64);
 
-impl ChannelId {
-    /// Gets the Id of a `Channel`.
-    pub async fn async_from<T: AsRef<Channel>>(channel: T) -> ChannelId {
-        match channel.as_ref() {
-            Channel::Group(group) => group.read().await.channel_id,
-            Channel::Guild(ch) => ch.read().await.id,
-            Channel::Private(ch) => ch.read().await.id,
-            Channel::Category(ch) => ch.read().await.id,
-        }
-    }
-}
+/// An identifier for an [`ApplicationCommand`].
+///
+/// [`ApplicationCommand`]: ../interactions/struct.ApplicationCommand.html
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct CommandId(pub u64);
 
 /// An identifier for an Emoji
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
@@ -120,6 +113,12 @@ pub struct GuildId(pub u64);
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct IntegrationId(pub u64);
 
+/// An identifier for an [`Interaction`].
+///
+/// [`Interaction`]: ../interactions/struct.Interaction.html
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct InteractionId(pub u64);
+
 /// An identifier for a Message
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct MessageId(pub u64);
@@ -128,6 +127,24 @@ pub struct MessageId(pub u64);
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct RoleId(pub u64);
 
+/// An identifier for a [`StageInstance`].
+///
+/// [`StageInstance`]: ../channel/struct.StageInstance.html
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct StageInstanceId(pub u64);
+
+/// An identifier for a [`Sticker`].
+///
+/// [`Sticker`]: ../sticker/struct.Sticker.html
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct StickerId(pub u64);
+
+/// An identifier for a pack of standard [`Sticker`]s.
+///
+/// [`Sticker`]: ../sticker/struct.Sticker.html
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct StickerPackId(pub u64);
+
 /// An identifier for a User
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct UserId(pub u64);
@@ -148,11 +165,16 @@ id_u64! {
     AttachmentId;
     ApplicationId;
     ChannelId;
+    CommandId;
     EmojiId;
     GuildId;
     IntegrationId;
+    InteractionId;
     MessageId;
     RoleId;
+    StageInstanceId;
+    StickerId;
+    StickerPackId;
     UserId;
     WebhookId;
     AuditLogEntryId;
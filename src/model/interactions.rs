@@ -0,0 +1,547 @@
+//! Models about interactions with the application, such as slash commands.
+
+use super::guild::Member;
+use super::id::{ApplicationId, ChannelId, CommandId, GuildId, InteractionId};
+use super::user::User;
+use serde::de::{DeserializeOwned, Error as DeError};
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+use std::result::Result as StdResult;
+
+#[cfg(feature = "http")]
+use super::channel::Message;
+#[cfg(feature = "model")]
+use crate::builder::{
+    CreateApplicationCommand, CreateInteractionResponse, CreateInteractionResponseData,
+};
+#[cfg(feature = "http")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
+#[cfg(feature = "model")]
+use crate::utils;
+
+/// A command registered by an application, which users can invoke by typing
+/// `/` followed by its name.
+///
+/// Commands are either global, available in every guild the application is
+/// in and in direct messages, or specific to a single guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/slash-commands#applicationcommand).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommand {
+    /// The Id of the command.
+    pub id: CommandId,
+    /// The Id of the application the command belongs to.
+    pub application_id: ApplicationId,
+    /// The guild the command is registered in, if it is not global.
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
+    /// The name of the command, 1 to 32 lowercase characters.
+    pub name: String,
+    /// The description of the command, 1 to 100 characters.
+    pub description: String,
+    /// The parameters of the command.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandOption>,
+}
+
+#[cfg(feature = "model")]
+impl ApplicationCommand {
+    /// Creates a global command for the application, or replaces the existing
+    /// one of the same name.
+    ///
+    /// **Note**: Global commands may take up to an hour to be available in
+    /// every guild. Use [`create_guild_command`] while testing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// use serenity::model::interactions::{ApplicationCommand, ApplicationCommandOptionType};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// let command = ApplicationCommand::create_global_command(&http, 7, |c| {
+    ///     c.name("ping")
+    ///         .description("Checks that the bot is alive")
+    ///         .create_option(|o| {
+    ///             o.name("message")
+    ///                 .description("A message to echo back")
+    ///                 .kind(ApplicationCommandOptionType::String)
+    ///         })
+    /// })
+    /// .await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`create_guild_command`]: #method.create_guild_command
+    #[cfg(feature = "http")]
+    pub async fn create_global_command<A, F>(
+        http: impl AsRef<Http>,
+        application_id: A,
+        f: F,
+    ) -> Result<ApplicationCommand>
+    where
+        A: Into<ApplicationId>,
+        F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand,
+    {
+        let map = build(f);
+
+        http.as_ref()
+            .create_global_application_command(application_id.into().0, &map)
+            .await
+    }
+
+    /// Creates a command for the application in a guild, or replaces the
+    /// existing one of the same name.
+    ///
+    /// Refer to [`create_global_command`] for an example.
+    ///
+    /// [`create_global_command`]: #method.create_global_command
+    #[cfg(feature = "http")]
+    pub async fn create_guild_command<A, G, F>(
+        http: impl AsRef<Http>,
+        application_id: A,
+        guild_id: G,
+        f: F,
+    ) -> Result<ApplicationCommand>
+    where
+        A: Into<ApplicationId>,
+        G: Into<GuildId>,
+        F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand,
+    {
+        let map = build(f);
+
+        http.as_ref()
+            .create_guild_application_command(application_id.into().0, guild_id.into().0, &map)
+            .await
+    }
+
+    /// Edits the command.
+    ///
+    /// Only the fields set on the builder are changed.
+    #[cfg(feature = "http")]
+    pub async fn edit<F>(&mut self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand,
+    {
+        let map = build(f);
+        let http = http.as_ref();
+
+        *self = match self.guild_id {
+            Some(guild_id) => {
+                http.edit_guild_application_command(
+                    self.application_id.0,
+                    guild_id.0,
+                    self.id.0,
+                    &map,
+                )
+                .await?
+            }
+            None => {
+                http.edit_global_application_command(self.application_id.0, self.id.0, &map)
+                    .await?
+            }
+        };
+
+        Ok(())
+    }
+
+    /// Deletes the command.
+    #[cfg(feature = "http")]
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<()> {
+        let http = http.as_ref();
+
+        match self.guild_id {
+            Some(guild_id) => {
+                http.delete_guild_application_command(self.application_id.0, guild_id.0, self.id.0)
+                    .await
+            }
+            None => {
+                http.delete_global_application_command(self.application_id.0, self.id.0)
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(feature = "model")]
+fn build<F>(f: F) -> Value
+where
+    F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand,
+{
+    let mut command = CreateApplicationCommand::default();
+    f(&mut command);
+
+    Value::Object(utils::hashmap_to_json_map(command.0))
+}
+
+/// A parameter of an [`ApplicationCommand`], or a sub-command of it.
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandOption {
+    /// The type of the option.
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandOptionType,
+    /// The name of the option, 1 to 32 lowercase characters.
+    pub name: String,
+    /// The description of the option, 1 to 100 characters.
+    pub description: String,
+    /// Whether the option must be given.
+    #[serde(default)]
+    pub required: bool,
+    /// The values the user can pick from, if the option is limited to them.
+    #[serde(default)]
+    pub choices: Vec<ApplicationCommandOptionChoice>,
+    /// The options of a sub-command or sub-command group.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandOption>,
+}
+
+/// A value the user can pick for an [`ApplicationCommandOption`].
+///
+/// [`ApplicationCommandOption`]: struct.ApplicationCommandOption.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandOptionChoice {
+    /// The name of the choice shown to the user.
+    pub name: String,
+    /// The value sent when the choice is picked, either a string or an
+    /// integer.
+    pub value: Value,
+}
+
+enum_number! {
+    /// The type of an [`ApplicationCommandOption`].
+    ///
+    /// [`ApplicationCommandOption`]: struct.ApplicationCommandOption.html
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum ApplicationCommandOptionType {
+        /// A sub-command, with options of its own.
+        SubCommand = 1,
+        /// A group of sub-commands.
+        SubCommandGroup = 2,
+        /// A string.
+        String = 3,
+        /// An integer.
+        Integer = 4,
+        /// A boolean.
+        Boolean = 5,
+        /// A user, given by its Id.
+        User = 6,
+        /// A channel, given by its Id.
+        Channel = 7,
+        /// A role, given by its Id.
+        Role = 8,
+    }
+}
+
+/// An interaction of a user with the application, such as invoking one of
+/// its [`ApplicationCommand`]s.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/slash-commands#interaction).
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+#[derive(Clone, Debug, Serialize)]
+#[non_exhaustive]
+pub struct Interaction {
+    /// The Id of the interaction.
+    pub id: InteractionId,
+    /// The Id of the application the interaction is for.
+    pub application_id: ApplicationId,
+    /// The type of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// The invoked command, for [`ApplicationCommand`] interactions.
+    ///
+    /// [`ApplicationCommand`]: enum.InteractionType.html#variant.ApplicationCommand
+    pub data: Option<ApplicationCommandInteractionData>,
+    /// The guild the interaction was sent from, if any.
+    pub guild_id: Option<GuildId>,
+    /// The channel the interaction was sent from.
+    pub channel_id: Option<ChannelId>,
+    /// The member who invoked the interaction, if it was sent from a guild.
+    pub member: Option<Member>,
+    /// The user who invoked the interaction, if it was sent from a direct
+    /// message.
+    pub user: Option<User>,
+    /// The token to respond to the interaction with.
+    pub token: String,
+    /// The version of the interaction, always `1`.
+    pub version: u8,
+    /// The locale the invoking user's client is set to.
+    pub locale: Option<String>,
+    /// The preferred locale of the guild the interaction was sent from.
+    pub guild_locale: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for Interaction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = Map::deserialize(deserializer)?;
+
+        // The member does not carry the Id of its guild, so copy it over.
+        if let Some(guild_id) = map.get("guild_id").cloned() {
+            if let Some(Value::Object(member)) = map.get_mut("member") {
+                member.insert("guild_id".to_string(), guild_id);
+            }
+        }
+
+        Ok(Interaction {
+            id: remove(&mut map, "id")?,
+            application_id: remove(&mut map, "application_id")?,
+            kind: remove(&mut map, "type")?,
+            data: remove(&mut map, "data")?,
+            guild_id: remove(&mut map, "guild_id")?,
+            channel_id: remove(&mut map, "channel_id")?,
+            member: remove(&mut map, "member")?,
+            user: remove(&mut map, "user")?,
+            token: remove(&mut map, "token")?,
+            version: remove(&mut map, "version")?,
+            locale: remove(&mut map, "locale")?,
+            guild_locale: remove(&mut map, "guild_locale")?,
+        })
+    }
+}
+
+#[cfg(feature = "model")]
+impl Interaction {
+    /// Responds to the interaction.
+    ///
+    /// An interaction must be responded to within 3 seconds. If more time is
+    /// needed, respond with [`DeferredChannelMessageWithSource`] and edit the
+    /// response later with [`edit_original_interaction_response`].
+    ///
+    /// # Examples
+    ///
+    /// Responding with a message only the invoking user can see:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::interactions::Interaction;
+    /// #
+    /// # async fn run(http: Http, interaction: Interaction) -> Result<(), Box<dyn std::error::Error>> {
+    /// interaction
+    ///     .create_interaction_response(&http, |r| {
+    ///         r.interaction_response_data(|d| d.content("Pong!").ephemeral(true))
+    ///     })
+    ///     .await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`DeferredChannelMessageWithSource`]: enum.InteractionResponseType.html#variant.DeferredChannelMessageWithSource
+    /// [`edit_original_interaction_response`]: #method.edit_original_interaction_response
+    #[cfg(feature = "http")]
+    pub async fn create_interaction_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut CreateInteractionResponse) -> &mut CreateInteractionResponse,
+    {
+        let mut response = CreateInteractionResponse::default();
+        f(&mut response);
+        let map = Value::Object(utils::hashmap_to_json_map(response.0));
+
+        http.as_ref()
+            .create_interaction_response(self.id.0, &self.token, &map)
+            .await
+    }
+
+    /// Edits the initial response to the interaction.
+    #[cfg(feature = "http")]
+    pub async fn edit_original_interaction_response<F>(
+        &self,
+        http: impl AsRef<Http>,
+        f: F,
+    ) -> Result<Message>
+    where
+        F: FnOnce(&mut CreateInteractionResponseData) -> &mut CreateInteractionResponseData,
+    {
+        let map = build_data(f);
+
+        http.as_ref()
+            .edit_original_interaction_response(self.application_id.0, &self.token, &map)
+            .await
+    }
+
+    /// Sends a follow-up message to the interaction, after it has been
+    /// responded to.
+    #[cfg(feature = "http")]
+    pub async fn create_followup_message<F>(&self, http: impl AsRef<Http>, f: F) -> Result<Message>
+    where
+        F: FnOnce(&mut CreateInteractionResponseData) -> &mut CreateInteractionResponseData,
+    {
+        let map = build_data(f);
+
+        http.as_ref()
+            .create_followup_message(self.application_id.0, &self.token, &map)
+            .await
+    }
+}
+
+#[cfg(feature = "model")]
+fn build_data<F>(f: F) -> Value
+where
+    F: FnOnce(&mut CreateInteractionResponseData) -> &mut CreateInteractionResponseData,
+{
+    let mut data = CreateInteractionResponseData::default();
+    f(&mut data);
+
+    Value::Object(utils::hashmap_to_json_map(data.0))
+}
+
+/// Removes a field from the map, treating a missing field as `null`.
+fn remove<T: DeserializeOwned, E: DeError>(
+    map: &mut Map<String, Value>,
+    key: &str,
+) -> StdResult<T, E> {
+    let value = map.remove(key).unwrap_or(Value::Null);
+
+    T::deserialize(value).map_err(|why| E::custom(format!("invalid `{}`: {}", key, why)))
+}
+
+enum_number! {
+    /// The type of an [`Interaction`].
+    ///
+    /// [`Interaction`]: struct.Interaction.html
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum InteractionType {
+        /// A ping, sent to check that an outgoing webhook is alive.
+        Ping = 1,
+        /// An invocation of an [`ApplicationCommand`].
+        ///
+        /// [`ApplicationCommand`]: struct.ApplicationCommand.html
+        ApplicationCommand = 2,
+    }
+}
+
+enum_number! {
+    /// The type of a response to an [`Interaction`].
+    ///
+    /// [`Interaction`]: struct.Interaction.html
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum InteractionResponseType {
+        /// Acknowledges a [`Ping`].
+        ///
+        /// [`Ping`]: enum.InteractionType.html#variant.Ping
+        Pong = 1,
+        /// Responds with a message, showing the user's invocation.
+        ChannelMessageWithSource = 4,
+        /// Acknowledges the interaction, showing a loading state until the
+        /// response is edited in.
+        DeferredChannelMessageWithSource = 5,
+    }
+}
+
+/// The command invoked by an [`Interaction`].
+///
+/// [`Interaction`]: struct.Interaction.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandInteractionData {
+    /// The Id of the invoked command.
+    pub id: CommandId,
+    /// The name of the invoked command.
+    pub name: String,
+    /// The options given to the command.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandInteractionDataOption>,
+}
+
+/// An option given to the command invoked by an [`Interaction`].
+///
+/// [`Interaction`]: struct.Interaction.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandInteractionDataOption {
+    /// The name of the option.
+    pub name: String,
+    /// The value of the option, absent for sub-commands and sub-command
+    /// groups.
+    #[serde(default)]
+    pub value: Option<Value>,
+    /// The options of a sub-command or sub-command group.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandInteractionDataOption>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_deserialize_command() {
+        let command: ApplicationCommand = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "name": "roll",
+            "description": "Rolls a die",
+            "options": [{
+                "type": 4,
+                "name": "sides",
+                "description": "The number of sides",
+                "choices": [
+                    {"name": "six", "value": 6},
+                    {"name": "twenty", "value": 20},
+                ],
+            }],
+        }))
+        .unwrap();
+
+        assert_eq!(command.id, CommandId(1));
+        assert_eq!(command.guild_id, None);
+        assert_eq!(
+            command.options[0].kind,
+            ApplicationCommandOptionType::Integer
+        );
+        assert!(!command.options[0].required);
+        assert_eq!(command.options[0].choices[1].value, json!(20));
+    }
+
+    #[test]
+    fn test_deserialize_interaction() {
+        let interaction: Interaction = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 2,
+            "data": {
+                "id": "3",
+                "name": "roll",
+                "options": [{"name": "sides", "value": 20}],
+            },
+            "guild_id": "4",
+            "channel_id": "5",
+            "member": {
+                "deaf": false,
+                "mute": false,
+                "joined_at": "2021-01-01T00:00:00+00:00",
+                "roles": [],
+                "user": {
+                    "id": "6",
+                    "username": "user",
+                    "discriminator": "0001",
+                    "avatar": null,
+                },
+            },
+            "token": "token",
+            "version": 1,
+            "locale": "de",
+            "guild_locale": "en-GB",
+        }))
+        .unwrap();
+
+        assert_eq!(interaction.kind, InteractionType::ApplicationCommand);
+        assert_eq!(interaction.member.unwrap().guild_id, GuildId(4));
+        assert!(interaction.user.is_none());
+        assert_eq!(interaction.data.unwrap().options[0].value, Some(json!(20)));
+        assert_eq!(interaction.locale.as_deref(), Some("de"));
+        assert_eq!(interaction.guild_locale.as_deref(), Some("en-GB"));
+    }
+}
@@ -3,14 +3,13 @@
 use super::prelude::*;
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
-use chrono::{DateTime, FixedOffset};
 
 #[cfg(all(feature = "cache", feature = "model"))]
 use super::{utils as model_utils, Permissions};
 #[cfg(feature = "model")]
 use crate::builder::CreateInvite;
 #[cfg(feature = "cache")]
-use crate::cache::CacheRwLock;
+use crate::cache::Cache;
 #[cfg(feature = "http")]
 use crate::http::Http;
 #[cfg(feature = "model")]
@@ -109,7 +108,7 @@ impl Invite {
             if let Some(cache) = cache_http.cache() {
                 let req = Permissions::CREATE_INVITE;
 
-                if !model_utils::user_has_perms(cache, channel_id, None, req).await? {
+                if !model_utils::user_has_perms(cache, channel_id, None, req)? {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -140,7 +139,7 @@ impl Invite {
                 let req = Permissions::MANAGE_GUILD;
 
                 let guild_id = self.guild.as_ref().map(|g| g.id);
-                if !model_utils::user_has_perms(cache, self.channel.id, guild_id, req).await? {
+                if !model_utils::user_has_perms(cache, self.channel.id, guild_id, req)? {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -269,8 +268,8 @@ impl InviteGuild {
     /// [`utils::shard_id`]: ../../utils/fn.shard_id.html
     #[cfg(all(feature = "cache", feature = "utils"))]
     #[inline]
-    pub async fn shard_id(&self, cache: impl AsRef<CacheRwLock>) -> u64 {
-        self.id.shard_id(&cache).await
+    pub fn shard_id(&self, cache: impl AsRef<Cache>) -> u64 {
+        self.id.shard_id(cache)
     }
 
     /// Returns the Id of the shard associated with the guild.
@@ -316,7 +315,7 @@ pub struct RichInvite {
     /// The unique code for the invite.
     pub code: String,
     /// When the invite was created.
-    pub created_at: DateTime<FixedOffset>,
+    pub created_at: Timestamp,
     /// A representation of the minimal amount of information needed about the
     /// [`Guild`] being invited to.
     ///
@@ -375,7 +374,7 @@ impl RichInvite {
                 let req = Permissions::MANAGE_GUILD;
 
                 let guild_id = self.guild.as_ref().map(|g| g.id);
-                if !model_utils::user_has_perms(cache, self.channel.id, guild_id, req).await? {
+                if !model_utils::user_has_perms(cache, self.channel.id, guild_id, req)? {
                     return Err(Error::Model(ModelError::InvalidPermissions(req)));
                 }
             }
@@ -434,3 +433,28 @@ impl RichInvite {
         format!("https://discord.gg/{}", self.code)
     }
 }
+
+/// A guild's vanity invite, available to partnered and sufficiently boosted
+/// guilds with the `VANITY_URL` feature.
+///
+/// This can only be retrieved by those with the [Manage Guild] permission.
+///
+/// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct VanityInvite {
+    /// The vanity invite code, or `None` if the guild has not set one.
+    pub code: Option<String>,
+    /// The amount of times that the vanity invite has been used.
+    #[serde(default)]
+    pub uses: u64,
+}
+
+impl VanityInvite {
+    /// Returns a URL to use for the vanity invite, if the guild has set one.
+    pub fn url(&self) -> Option<String> {
+        self.code
+            .as_ref()
+            .map(|code| format!("https://discord.gg/{}", code))
+    }
+}
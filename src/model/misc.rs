@@ -13,124 +13,126 @@ use std::result::Result as StdResult;
 #[cfg(all(feature = "model", feature = "utils"))]
 use std::str::FromStr;
 
-use async_trait::async_trait;
-
 /// Allows something - such as a channel or role - to be mentioned in a message.
-#[async_trait]
 pub trait Mentionable {
     /// Creates a mentionable string, that will be able to notify and/or create
     /// a link to the item.
-    async fn mention(&self) -> String;
+    fn mention(&self) -> String;
 }
 
-#[async_trait]
 impl Mentionable for ChannelId {
-    async fn mention(&self) -> String {
+    fn mention(&self) -> String {
         format!("<#{}>", self.0)
     }
 }
 
-#[async_trait]
 impl Mentionable for Channel {
-    async fn mention(&self) -> String {
+    fn mention(&self) -> String {
         match *self {
-            Channel::Guild(ref x) => {
-                let guard = x.read().await;
-                guard.mention().await
-            }
-            Channel::Private(ref x) => {
-                let guard = x.read().await;
-                guard.mention().await
-            }
-            Channel::Group(ref x) => {
-                let guard = x.read().await;
-                guard.mention().await
-            }
-            Channel::Category(ref x) => {
-                let guard = x.read().await;
-                guard.mention().await
-            }
+            Channel::Guild(ref x) => x.mention(),
+            Channel::Private(ref x) => x.mention(),
+            Channel::Group(ref x) => x.mention(),
+            Channel::Category(ref x) => x.mention(),
+            Channel::Thread(ref x) => x.id.mention(),
         }
     }
 }
 
-#[async_trait]
 impl Mentionable for ChannelCategory {
-    async fn mention(&self) -> String {
+    fn mention(&self) -> String {
         format!("<#{}>", self.name)
     }
 }
 
-#[async_trait]
 impl Mentionable for CurrentUser {
-    async fn mention(&self) -> String {
+    fn mention(&self) -> String {
         format!("<@{}>", self.id.0)
     }
 }
 
-#[async_trait]
 impl Mentionable for Emoji {
-    async fn mention(&self) -> String {
+    fn mention(&self) -> String {
         format!("<:{}:{}>", self.name, self.id.0)
     }
 }
 
-#[async_trait]
 impl Mentionable for Group {
-    async fn mention(&self) -> String {
+    fn mention(&self) -> String {
         format!("<#{}>", self.channel_id.0)
     }
 }
 
-#[async_trait]
 impl Mentionable for Member {
-    async fn mention(&self) -> String {
-        format!("<@{}>", self.user.read().id.0)
+    fn mention(&self) -> String {
+        format!("<@{}>", self.user.id.0)
     }
 }
 
-#[async_trait]
 impl Mentionable for PrivateChannel {
-    async fn mention(&self) -> String {
+    fn mention(&self) -> String {
         format!("<#{}>", self.id.0)
     }
 }
 
-#[async_trait]
 impl Mentionable for RoleId {
-    async fn mention(&self) -> String {
+    fn mention(&self) -> String {
         format!("<@&{}>", self.0)
     }
 }
 
-#[async_trait]
 impl Mentionable for Role {
-    async fn mention(&self) -> String {
+    fn mention(&self) -> String {
         format!("<@&{}>", self.id.0)
     }
 }
 
-#[async_trait]
 impl Mentionable for UserId {
-    async fn mention(&self) -> String {
+    fn mention(&self) -> String {
         format!("<@{}>", self.0)
     }
 }
 
-#[async_trait]
 impl Mentionable for User {
-    async fn mention(&self) -> String {
+    fn mention(&self) -> String {
         format!("<@{}>", self.id.0)
     }
 }
 
-#[async_trait]
 impl Mentionable for GuildChannel {
-    async fn mention(&self) -> String {
+    fn mention(&self) -> String {
         format!("<#{}>", self.id.0)
     }
 }
 
+/// The target of a mention in the content of a message.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Mention {
+    /// A mention of a channel, as in `<#81384788765712384>`.
+    Channel(ChannelId),
+    /// A mention of a role, as in `<@&136107769680887808>`.
+    Role(RoleId),
+    /// A mention of a user, as in `<@114941315417899012>` or
+    /// `<@!114941315417899012>`.
+    User(UserId),
+}
+
+/// A [`Mention`] along with where it appears in the content of a message, as
+/// returned by [`Message::mentions_ordered`].
+///
+/// [`Mention`]: enum.Mention.html
+/// [`Message::mentions_ordered`]: ../channel/struct.Message.html#method.mentions_ordered
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct ContentMention {
+    /// What is mentioned.
+    pub mention: Mention,
+    /// The byte index in the content at which the mention starts.
+    pub start: usize,
+    /// The byte index in the content just after the end of the mention.
+    pub end: usize,
+}
+
 #[cfg(all(feature = "model", feature = "utils"))]
 #[derive(Debug)]
 #[non_exhaustive]
@@ -335,74 +337,70 @@ mod test {
 
     #[cfg(feature = "utils")]
     mod utils {
-        use crate::internal::{AsyncRwLock, SyncRwLock};
         use crate::model::prelude::*;
-        use crate::utils::{run_async_test, Colour};
-        use std::sync::Arc;
+        use crate::utils::Colour;
 
         #[test]
         fn test_mention() {
-            run_async_test(async move {
-                let channel = Channel::Guild(Arc::new(AsyncRwLock::new(GuildChannel {
-                    bitrate: None,
-                    category_id: None,
-                    guild_id: GuildId(1),
-                    kind: ChannelType::Text,
-                    id: ChannelId(4),
-                    last_message_id: None,
-                    last_pin_timestamp: None,
-                    name: "a".to_string(),
-                    permission_overwrites: vec![],
-                    position: 1,
-                    topic: None,
-                    user_limit: None,
-                    nsfw: false,
-                    slow_mode_rate: Some(0),
-                })));
-                let emoji = Emoji {
-                    animated: false,
-                    id: EmojiId(5),
-                    name: "a".to_string(),
-                    managed: true,
-                    require_colons: true,
-                    roles: vec![],
-                };
-                let role = Role {
-                    id: RoleId(2),
-                    colour: Colour::ROSEWATER,
-                    hoist: false,
-                    managed: false,
-                    mentionable: false,
-                    name: "fake role".to_string(),
-                    permissions: Permissions::empty(),
-                    position: 1,
-                };
-                let user = User {
-                    id: UserId(6),
-                    avatar: None,
-                    bot: false,
-                    discriminator: 4132,
-                    name: "fake".to_string(),
-                };
-                let member = Member {
-                    deaf: false,
-                    guild_id: GuildId(2),
-                    joined_at: None,
-                    mute: false,
-                    nick: None,
-                    roles: vec![],
-                    user: Arc::new(SyncRwLock::new(user.clone())),
-                };
-
-                assert_eq!(ChannelId(1).mention().await, "<#1>");
-                assert_eq!(channel.mention().await, "<#4>");
-                assert_eq!(emoji.mention().await, "<:a:5>");
-                assert_eq!(member.mention().await, "<@6>");
-                assert_eq!(role.mention().await, "<@&2>");
-                assert_eq!(role.id.mention().await, "<@&2>");
-                assert_eq!(user.mention().await, "<@6>");
-                assert_eq!(user.id.mention().await, "<@6>");
+            let channel = Channel::Guild(GuildChannel {
+                bitrate: None,
+                category_id: None,
+                guild_id: GuildId(1),
+                kind: ChannelType::Text,
+                id: ChannelId(4),
+                last_message_id: None,
+                last_pin_timestamp: None,
+                name: "a".to_string(),
+                permission_overwrites: vec![],
+                position: 1,
+                topic: None,
+                user_limit: None,
+                nsfw: false,
+                slow_mode_rate: Some(0),
             });
+            let emoji = Emoji {
+                animated: false,
+                id: EmojiId(5),
+                name: "a".to_string(),
+                managed: true,
+                require_colons: true,
+                roles: vec![],
+            };
+            let role = Role {
+                id: RoleId(2),
+                colour: Colour::ROSEWATER,
+                hoist: false,
+                managed: false,
+                mentionable: false,
+                name: "fake role".to_string(),
+                permissions: Permissions::empty(),
+                position: 1,
+            };
+            let user = User {
+                id: UserId(6),
+                avatar: None,
+                bot: false,
+                discriminator: 4132,
+                name: "fake".to_string(),
+            };
+            let member = Member {
+                deaf: false,
+                guild_id: GuildId(2),
+                joined_at: None,
+                mute: false,
+                nick: None,
+                roles: vec![],
+                user: user.clone(),
+            };
+
+            assert_eq!(ChannelId(1).mention(), "<#1>");
+            assert_eq!(channel.mention(), "<#4>");
+            assert_eq!(emoji.mention(), "<:a:5>");
+            assert_eq!(member.mention(), "<@6>");
+            assert_eq!(role.mention(), "<@&2>");
+            assert_eq!(role.id.mention(), "<@&2>");
+            assert_eq!(user.mention(), "<@6>");
+            assert_eq!(user.id.mention(), "<@6>");
         }
     }
 }
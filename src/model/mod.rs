@@ -18,6 +18,27 @@
 //! ```rust,no_run
 //! use serenity::model::prelude::*;
 //! ```
+//!
+//! Most models are `#[non_exhaustive]`, so that new fields sent by Discord can
+//! be added without breaking changes, which means they can not be built with a
+//! struct expression outside of this crate. Models that are commonly built by
+//! hand, such as in tests, implement `Default` instead, after which their
+//! fields can be set one by one:
+//!
+//! ```rust
+//! use serenity::model::prelude::*;
+//!
+//! let mut user = User::default();
+//! user.id = UserId(7);
+//! user.name = "ferris".to_string();
+//!
+//! let mut embed = Embed::default();
+//! embed.title = Some("Hello".to_string());
+//! embed.fields.push(EmbedField::new("A field", "Has some content.", false));
+//!
+//! assert_eq!(user.tag(), "ferris#0000");
+//! assert_eq!(embed.kind, "rich");
+//! ```
 
 #[macro_use]
 mod utils;
@@ -29,25 +50,28 @@ pub mod event;
 pub mod gateway;
 pub mod guild;
 pub mod id;
+pub mod interactions;
 pub mod invite;
 pub mod misc;
 pub mod permissions;
 pub mod prelude;
+pub mod sticker;
+pub mod timestamp;
 pub mod user;
 pub mod voice;
 pub mod webhook;
 
 pub use self::error::Error as ModelError;
 pub use self::permissions::Permissions;
+pub use self::timestamp::Timestamp;
 
 use self::utils::*;
 use crate::internal::prelude::*;
 use serde::de::Visitor;
 use std::{
     collections::HashMap,
-    fmt::{Formatter, Result as FmtResult},
+    fmt::{Display, Formatter, Result as FmtResult},
     result::Result as StdResult,
-    sync::Arc,
 };
 
 #[cfg(feature = "utils")]
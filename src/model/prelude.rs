@@ -16,9 +16,11 @@ pub use super::event::*;
 pub use super::gateway::*;
 pub use super::guild::*;
 pub use super::id::*;
+pub use super::interactions::*;
 pub use super::invite::*;
 pub use super::misc::*;
 pub use super::permissions::*;
+pub use super::sticker::*;
 pub use super::user::*;
 pub use super::voice::*;
 pub use super::webhook::*;
@@ -0,0 +1,117 @@
+//! Models for stickers, which can be sent in messages.
+
+use crate::model::prelude::*;
+
+/// A sticker, either one of Discord's standard stickers or one uploaded to a
+/// guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/sticker#sticker-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Sticker {
+    /// The Id of the sticker.
+    pub id: StickerId,
+    /// The Id of the pack the sticker is from, for standard stickers.
+    pub pack_id: Option<StickerPackId>,
+    /// The name of the sticker.
+    pub name: String,
+    /// The description of the sticker.
+    pub description: Option<String>,
+    /// A comma-separated list of keywords for the sticker, which are used for
+    /// autocomplete.
+    ///
+    /// For guild stickers, this is the name of a unicode emoji.
+    pub tags: String,
+    /// The type of the sticker.
+    #[serde(rename = "type")]
+    pub kind: StickerType,
+    /// The format of the sticker's image.
+    pub format_type: StickerFormatType,
+    /// Whether the guild sticker can be used, which may be `false` when the
+    /// guild has lost premium subscriptions.
+    pub available: Option<bool>,
+    /// The Id of the guild which owns the sticker, for guild stickers.
+    pub guild_id: Option<GuildId>,
+    /// The user who uploaded the guild sticker.
+    ///
+    /// **Note**: This is only sent to users with the Manage Emojis
+    /// permission.
+    pub user: Option<User>,
+    /// The sticker's sort order within its pack.
+    pub sort_value: Option<u64>,
+}
+
+/// The smallest amount of data needed to render a [`Sticker`], as sent in a
+/// [`Message`].
+///
+/// [`Message`]: ../channel/struct.Message.html
+/// [`Sticker`]: struct.Sticker.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct StickerItem {
+    /// The Id of the sticker.
+    pub id: StickerId,
+    /// The name of the sticker.
+    pub name: String,
+    /// The format of the sticker's image.
+    pub format_type: StickerFormatType,
+}
+
+enum_number! {
+    /// The type of a [`Sticker`].
+    ///
+    /// [`Sticker`]: struct.Sticker.html
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    #[non_exhaustive]
+    pub enum StickerType {
+        /// An official sticker in a pack, part of Nitro or in a removed
+        /// purchasable pack.
+        Standard = 1,
+        /// A sticker uploaded to a boosted guild for the guild's members.
+        Guild = 2,
+    }
+}
+
+enum_number! {
+    /// The format of a [`Sticker`]'s image.
+    ///
+    /// [`Sticker`]: struct.Sticker.html
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    #[non_exhaustive]
+    pub enum StickerFormatType {
+        /// A PNG image.
+        Png = 1,
+        /// An animated PNG image.
+        Apng = 2,
+        /// A Lottie animation, encoded as JSON.
+        Lottie = 3,
+    }
+}
+
+#[cfg(feature = "model")]
+impl Sticker {
+    /// Generates a URL to the sticker's image.
+    #[inline]
+    pub fn image_url(&self) -> String {
+        sticker_url(self.id, self.format_type)
+    }
+}
+
+#[cfg(feature = "model")]
+impl StickerItem {
+    /// Generates a URL to the sticker's image.
+    #[inline]
+    pub fn image_url(&self) -> String {
+        sticker_url(self.id, self.format_type)
+    }
+}
+
+#[cfg(feature = "model")]
+fn sticker_url(id: StickerId, format_type: StickerFormatType) -> String {
+    let extension = match format_type {
+        StickerFormatType::Lottie => "json",
+        _ => "png",
+    };
+
+    format!(cdn!("/stickers/{}.{}"), id, extension)
+}
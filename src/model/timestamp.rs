@@ -0,0 +1,340 @@
+//! A point in time, as used by Discord's API.
+//!
+//! Timestamps are backed by [`chrono`] by default, and by [`time`] when the
+//! `time` feature is enabled. Either way, they can be converted to and from
+//! `chrono`'s types, and also to and from `time`'s types with the `time`
+//! feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serenity::model::Timestamp;
+//!
+//! let timestamp: Timestamp = "2016-04-30T11:18:25.796000+00:00".parse().unwrap();
+//!
+//! assert_eq!(timestamp.unix_timestamp(), 1_462_015_105);
+//! ```
+//!
+//! [`chrono`]: https://docs.rs/chrono
+//! [`time`]: https://docs.rs/time
+
+use serde::de::{Error as DeError, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::Deref,
+    str::FromStr,
+    time::Duration,
+};
+
+use chrono::{DateTime, TimeZone, Utc};
+#[cfg(feature = "time")]
+use time::format_description::well_known::{Rfc2822, Rfc3339};
+#[cfg(feature = "time")]
+use time::OffsetDateTime;
+
+#[cfg(not(feature = "time"))]
+type Inner = DateTime<Utc>;
+#[cfg(feature = "time")]
+type Inner = OffsetDateTime;
+
+/// A point in time, in UTC.
+///
+/// This dereferences to a `chrono::DateTime<Utc>` by default, and to a
+/// `time::OffsetDateTime` when the `time` feature is enabled.
+///
+/// Refer to the [module-level documentation] for more information.
+///
+/// [module-level documentation]: index.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Timestamp(Inner);
+
+impl Timestamp {
+    /// Returns the current time.
+    pub fn now() -> Self {
+        #[cfg(not(feature = "time"))]
+        let now = Utc::now();
+        #[cfg(feature = "time")]
+        let now = OffsetDateTime::now_utc();
+
+        Timestamp(now)
+    }
+
+    /// Creates a timestamp from the number of seconds since the Unix epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidTimestamp`] if the timestamp is out of range.
+    ///
+    /// [`InvalidTimestamp`]: struct.InvalidTimestamp.html
+    pub fn from_unix_timestamp(secs: i64) -> Result<Self, InvalidTimestamp> {
+        Self::from_unix_timestamp_millis(secs.checked_mul(1000).ok_or(InvalidTimestamp)?)
+    }
+
+    /// Creates a timestamp from the number of milliseconds since the Unix
+    /// epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidTimestamp`] if the timestamp is out of range.
+    ///
+    /// [`InvalidTimestamp`]: struct.InvalidTimestamp.html
+    pub fn from_unix_timestamp_millis(millis: i64) -> Result<Self, InvalidTimestamp> {
+        #[cfg(not(feature = "time"))]
+        let inner = Utc.timestamp_millis_opt(millis).single();
+        #[cfg(feature = "time")]
+        let inner = OffsetDateTime::from_unix_timestamp_nanos(i128::from(millis) * 1_000_000).ok();
+
+        inner.map(Timestamp).ok_or(InvalidTimestamp)
+    }
+
+    /// Returns the number of seconds since the Unix epoch.
+    pub fn unix_timestamp(&self) -> i64 {
+        #[cfg(not(feature = "time"))]
+        let secs = self.0.timestamp();
+        #[cfg(feature = "time")]
+        let secs = self.0.unix_timestamp();
+
+        secs
+    }
+
+    /// Returns the number of milliseconds since the Unix epoch.
+    pub fn unix_timestamp_millis(&self) -> i64 {
+        #[cfg(not(feature = "time"))]
+        let millis = self.0.timestamp_millis();
+        #[cfg(feature = "time")]
+        let millis = (self.0.unix_timestamp_nanos() / 1_000_000) as i64;
+
+        millis
+    }
+
+    /// The time since the timestamp, or zero if it is in the future.
+    pub(crate) fn elapsed(&self) -> Duration {
+        let millis = Timestamp::now().unix_timestamp_millis() - self.unix_timestamp_millis();
+
+        Duration::from_millis(millis.max(0) as u64)
+    }
+
+    /// Parses an [RFC 3339] timestamp, such as the ones sent by Discord.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidTimestamp`] if the input is not a valid timestamp.
+    ///
+    /// [`InvalidTimestamp`]: struct.InvalidTimestamp.html
+    /// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+    pub fn parse(input: &str) -> Result<Self, InvalidTimestamp> {
+        #[cfg(not(feature = "time"))]
+        let inner = DateTime::parse_from_rfc3339(input).map(|dt| dt.with_timezone(&Utc));
+        #[cfg(feature = "time")]
+        let inner =
+            OffsetDateTime::parse(input, &Rfc3339).map(|dt| dt.to_offset(time::UtcOffset::UTC));
+
+        inner.map(Timestamp).map_err(|_| InvalidTimestamp)
+    }
+
+    /// Parses an [RFC 2822] timestamp, as used by the `Date` HTTP header.
+    ///
+    /// [RFC 2822]: https://tools.ietf.org/html/rfc2822
+    pub(crate) fn parse_rfc2822(input: &str) -> Result<Self, InvalidTimestamp> {
+        #[cfg(not(feature = "time"))]
+        let inner = DateTime::parse_from_rfc2822(input).map(|dt| dt.with_timezone(&Utc));
+        #[cfg(feature = "time")]
+        let inner =
+            OffsetDateTime::parse(input, &Rfc2822).map(|dt| dt.to_offset(time::UtcOffset::UTC));
+
+        inner.map(Timestamp).map_err(|_| InvalidTimestamp)
+    }
+
+    /// Formats the timestamp as [RFC 3339], as expected by Discord.
+    ///
+    /// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+    pub fn to_rfc3339(&self) -> String {
+        #[cfg(not(feature = "time"))]
+        let formatted = self.0.to_rfc3339();
+
+        // Timestamps are always in UTC and their year is limited to four
+        // digits when parsed, so this can only fail for timestamps
+        // constructed from out of range `OffsetDateTime`s.
+        #[cfg(feature = "time")]
+        let formatted = self.0.format(&Rfc3339).unwrap_or_default();
+
+        formatted
+    }
+}
+
+impl Deref for Timestamp {
+    type Target = Inner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.to_rfc3339())
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = InvalidTimestamp;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_rfc3339())
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TimestampVisitor;
+
+        impl<'de> Visitor<'de> for TimestampVisitor {
+            type Value = Timestamp;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+                f.write_str("an RFC 3339 timestamp")
+            }
+
+            fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+                Timestamp::parse(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(TimestampVisitor)
+    }
+}
+
+/// # Panics
+///
+/// When the `time` feature is enabled, panics if the date's year is outside of
+/// the range supported by `time`.
+impl<Tz: TimeZone> From<DateTime<Tz>> for Timestamp {
+    fn from(dt: DateTime<Tz>) -> Self {
+        #[cfg(not(feature = "time"))]
+        let inner = dt.with_timezone(&Utc);
+        #[cfg(feature = "time")]
+        let inner = {
+            let nanos = i128::from(dt.timestamp()) * 1_000_000_000
+                + i128::from(dt.timestamp_subsec_nanos());
+
+            OffsetDateTime::from_unix_timestamp_nanos(nanos).expect("Timestamp out of range")
+        };
+
+        Timestamp(inner)
+    }
+}
+
+impl From<Timestamp> for DateTime<Utc> {
+    fn from(timestamp: Timestamp) -> Self {
+        #[cfg(not(feature = "time"))]
+        let dt = timestamp.0;
+        // `time` supports a smaller range of years than `chrono`, so this
+        // can not fail.
+        #[cfg(feature = "time")]
+        let dt = Utc
+            .timestamp_opt(timestamp.0.unix_timestamp(), timestamp.0.nanosecond())
+            .unwrap();
+
+        dt
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<OffsetDateTime> for Timestamp {
+    fn from(dt: OffsetDateTime) -> Self {
+        Timestamp(dt.to_offset(time::UtcOffset::UTC))
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Timestamp> for OffsetDateTime {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0
+    }
+}
+
+/// An error returned when a [`Timestamp`] could not be parsed or constructed.
+///
+/// [`Timestamp`]: struct.Timestamp.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidTimestamp;
+
+impl Display for InvalidTimestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("invalid timestamp")
+    }
+}
+
+impl StdError for InvalidTimestamp {}
+
+#[cfg(test)]
+mod test {
+    use super::Timestamp;
+
+    #[test]
+    fn test_timestamp() {
+        let timestamp = Timestamp::parse("2016-04-30T11:18:25.796+00:00").unwrap();
+
+        assert_eq!(timestamp.unix_timestamp(), 1_462_015_105);
+        assert_eq!(timestamp.unix_timestamp_millis(), 1_462_015_105_796);
+        assert_eq!(
+            Timestamp::from_unix_timestamp_millis(1_462_015_105_796).unwrap(),
+            timestamp
+        );
+
+        // Offsets are converted to UTC.
+        let offset = Timestamp::parse("2016-04-30T13:18:25.796+02:00").unwrap();
+        assert_eq!(offset, timestamp);
+
+        let json = serde_json::to_value(&timestamp).unwrap();
+        assert_eq!(
+            serde_json::from_value::<Timestamp>(json).unwrap(),
+            timestamp
+        );
+
+        assert!(Timestamp::parse("yesterday").is_err());
+        assert_eq!(
+            Timestamp::parse_rfc2822("Sat, 30 Apr 2016 11:18:25 GMT")
+                .unwrap()
+                .unix_timestamp(),
+            1_462_015_105,
+        );
+    }
+
+    #[test]
+    fn test_timestamp_elapsed() {
+        use std::time::Duration;
+
+        let now = Timestamp::now().unix_timestamp_millis();
+        let day_ago = Timestamp::from_unix_timestamp_millis(now - 24 * 60 * 60 * 1000).unwrap();
+        let future = Timestamp::from_unix_timestamp_millis(now + 60 * 1000).unwrap();
+
+        assert!(day_ago.elapsed() >= Duration::from_secs(24 * 60 * 60));
+        assert!(day_ago.elapsed() < Duration::from_secs(25 * 60 * 60));
+        assert_eq!(future.elapsed(), Duration::from_secs(0));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_timestamp_conversions() {
+        use chrono::{DateTime, Utc};
+        use time::OffsetDateTime;
+
+        let timestamp = Timestamp::parse("2016-04-30T11:18:25.796+00:00").unwrap();
+
+        let dt: OffsetDateTime = timestamp.into();
+        assert_eq!(dt.unix_timestamp(), 1_462_015_105);
+        assert_eq!(Timestamp::from(dt), timestamp);
+
+        let dt: DateTime<Utc> = timestamp.into();
+        assert_eq!(Timestamp::from(dt), timestamp);
+    }
+}
@@ -4,16 +4,14 @@ use super::prelude::*;
 use super::utils::deserialize_u16;
 #[cfg(feature = "http")]
 use crate::http::CacheHttp;
-use crate::{internal::prelude::*, internal::SyncRwLock, model::misc::Mentionable};
+use crate::{internal::prelude::*, model::misc::Mentionable};
 
 #[cfg(feature = "model")]
 use crate::builder::{CreateMessage, EditProfile};
+#[cfg(all(feature = "cache", feature = "model"))]
+use crate::cache::Cache;
 #[cfg(feature = "model")]
 use crate::http::GuildPagination;
-//#[cfg(all(feature = "cache", feature = "model"))]
-//use async_std::sync::RwLock;
-#[cfg(all(feature = "cache", feature = "model"))]
-use crate::cache::CacheRwLock;
 #[cfg(feature = "http")]
 use crate::http::Http;
 #[cfg(feature = "model")]
@@ -27,8 +25,6 @@ use serde_json::json;
 use std::fmt::Write;
 #[cfg(feature = "model")]
 use std::mem;
-#[cfg(all(feature = "cache", feature = "model"))]
-use std::sync::Arc;
 
 /// Information about the current user.
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
@@ -41,6 +37,8 @@ pub struct CurrentUser {
     #[serde(deserialize_with = "deserialize_u16")]
     pub discriminator: u16,
     pub email: Option<String>,
+    #[serde(default)]
+    pub locale: Option<String>,
     pub mfa_enabled: bool,
     #[serde(rename = "username")]
     pub name: String,
@@ -61,14 +59,11 @@ impl CurrentUser {
     /// # #[cfg(feature = "cache")]
     /// # #[tokio::main]
     /// # async fn main() {
-    /// # use serenity::{cache::{Cache, CacheRwLock}, model::prelude::*, prelude::*};
-    /// # use async_std::sync::RwLock;
+    /// # use serenity::{cache::Cache, model::prelude::*, prelude::*};
     /// # use std::sync::Arc;
     /// #
-    /// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// # let cache = cache.read().await;
-    /// // assuming the cache has been unlocked
-    /// let user = &cache.user;
+    /// # let cache = Arc::new(Cache::default());
+    /// let user = cache.current_user();
     ///
     /// match user.avatar_url() {
     ///     Some(url) => println!("{}'s avatar can be found at {}", user.name, url),
@@ -107,7 +102,7 @@ impl CurrentUser {
     /// # fn main() {
     /// let avatar = serenity::utils::read_image("./avatar.png").unwrap();
     ///
-    /// context.cache.write().user.edit(|p| p.avatar(Some(&avatar)));
+    /// context.cache.current_user().edit(&context, |p| p.avatar(Some(&avatar)));
     /// # }
     /// #
     /// # #[cfg(not(feature = "cache"))]
@@ -164,15 +159,12 @@ impl CurrentUser {
     /// # #[cfg(feature = "cache")]
     /// # #[tokio::main]
     /// # async fn main() {
-    /// # use serenity::{cache::{Cache, CacheRwLock}, http::Http, model::prelude::*, prelude::*};
-    /// # use async_std::sync::RwLock;
+    /// # use serenity::{cache::Cache, http::Http, model::prelude::*, prelude::*};
     /// # use std::sync::Arc;
     /// #
-    /// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// # let cache = cache.read().await;
+    /// # let cache = Arc::new(Cache::default());
     /// # let http = Arc::new(Http::default());
-    /// // assuming the cache has been unlocked
-    /// let user = &cache.user;
+    /// let user = cache.current_user();
     ///
     /// if let Ok(guilds) = user.guilds(&http).await {
     ///     for (index, guild) in guilds.into_iter().enumerate() {
@@ -205,18 +197,15 @@ impl CurrentUser {
     /// # #[cfg(feature = "cache")]
     /// # #[tokio::main]
     /// # async fn main() {
-    /// # use serenity::{cache::{Cache, CacheRwLock}, http::Http, model::prelude::*, prelude::*};
-    /// # use async_std::sync::RwLock;
+    /// # use serenity::{cache::Cache, http::Http, model::prelude::*, prelude::*};
     /// # use std::sync::Arc;
     /// #
-    /// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// # let mut cache = cache.write().await;
+    /// # let cache = Arc::new(Cache::default());
     /// # let http = Arc::new(Http::default());
     ///
     /// use serenity::model::Permissions;
     ///
-    /// // assuming the cache has been unlocked
-    /// let url = match cache.user.invite_url(&http, Permissions::empty()).await {
+    /// let url = match cache.current_user().invite_url(&http, Permissions::empty()).await {
     ///     Ok(v) => v,
     ///     Err(why) => {
     ///         println!("Error getting invite url: {:?}", why);
@@ -239,17 +228,14 @@ impl CurrentUser {
     /// # #[cfg(feature = "cache")]
     /// # #[tokio::main]
     /// # async fn main() {
-    /// # use serenity::{cache::{Cache, CacheRwLock}, http::Http, model::prelude::*, prelude::*};
-    /// # use async_std::sync::RwLock;
+    /// # use serenity::{cache::Cache, http::Http, model::prelude::*, prelude::*};
     /// # use std::sync::Arc;
     /// #
-    /// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// # let mut cache = cache.write().await;
+    /// # let cache = Arc::new(Cache::default());
     /// # let http = Arc::new(Http::default());
     /// use serenity::model::Permissions;
     ///
-    /// // assuming the cache has been unlocked
-    /// let url = match cache.user.invite_url(&http, Permissions::READ_MESSAGES | Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS).await {
+    /// let url = match cache.current_user().invite_url(&http, Permissions::READ_MESSAGES | Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS).await {
     ///     Ok(v) => v,
     ///     Err(why) => {
     ///         println!("Error getting invite url: {:?}", why);
@@ -314,14 +300,11 @@ impl CurrentUser {
     /// # #[cfg(feature = "cache")]
     /// # #[tokio::main]
     /// # async fn main() {
-    /// # use serenity::{cache::{Cache, CacheRwLock}, model::prelude::*, prelude::*};
-    /// # use async_std::sync::RwLock;
+    /// # use serenity::{cache::Cache, model::prelude::*, prelude::*};
     /// # use std::sync::Arc;
     /// #
-    /// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// # let cache = cache.read().await;
-    /// // assuming the cache has been unlocked
-    /// let user = &cache.user;
+    /// # let cache = Arc::new(Cache::default());
+    /// let user = cache.current_user();
     ///
     /// match user.static_avatar_url() {
     ///     Some(url) => println!("{}'s static avatar can be found at {}", user.name, url),
@@ -347,14 +330,11 @@ impl CurrentUser {
     /// # #[cfg(feature = "cache")]
     /// # #[tokio::main]
     /// # async fn main() {
-    /// # use serenity::{cache::{Cache, CacheRwLock}, model::prelude::*, prelude::*};
-    /// # use async_std::sync::RwLock;
+    /// # use serenity::{cache::Cache, model::prelude::*, prelude::*};
     /// # use std::sync::Arc;
     /// #
-    /// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-    /// # let cache = cache.read().await;
-    /// // assuming the cache has been unlocked
-    /// println!("The current user's distinct identifier is {}", cache.user.tag());
+    /// # let cache = Arc::new(Cache::default());
+    /// println!("The current user's distinct identifier is {}", cache.current_user().tag());
     /// # }
     /// #
     /// # #[cfg(not(feature = "cache"))]
@@ -443,7 +423,7 @@ impl Default for OnlineStatus {
 }
 
 /// Information about a user.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct User {
     /// The unique Id of the user. Can be used to calculate the account's
@@ -466,8 +446,6 @@ pub struct User {
     pub name: String,
 }
 
-#[cfg(feature = "model")]
-use chrono::{DateTime, FixedOffset};
 use std::hash::{Hash, Hasher};
 
 impl PartialEq for User {
@@ -506,7 +484,7 @@ impl User {
 
     /// Retrieves the time that this user was created at.
     #[inline]
-    pub fn created_at(&self) -> DateTime<FixedOffset> {
+    pub fn created_at(&self) -> Timestamp {
         self.id.created_at()
     }
 
@@ -541,8 +519,8 @@ impl User {
     /// #   #[cfg(feature = "cache")]
     ///     async fn message(&self, ctx: Context, msg: Message) {
     ///         if msg.content == "~help" {
-    ///             let read = ctx.cache.read().await;
-    ///             let url = match read.user.invite_url(&ctx, Permissions::empty()).await {
+    ///             let user = ctx.cache.current_user();
+    ///             let url = match user.invite_url(&ctx, Permissions::empty()).await {
     ///                 Ok(v) => v,
     ///                 Err(why) => {
     ///                     println!("Error creating invite url: {:?}", why);
@@ -618,15 +596,11 @@ impl User {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                let guard = &cache.read().await.private_channels;
-
-                for channel in guard.values() {
-                    let guard = channel.read().await;
-                    if guard.recipient.read().id == self.id {
-                        private_channel_id = Some(guard.id);
-                        break;
-                    }
-                }
+                private_channel_id = cache
+                    .private_channels
+                    .iter()
+                    .find(|channel| channel.recipient.id == self.id)
+                    .map(|channel| channel.id);
             }
         }
 
@@ -741,11 +715,9 @@ impl User {
                     #[cfg(feature = "cache")]
                     {
                         if let Some(cache) = cache_http.cache() {
-                            if let Some(guild) = cache.read().await.guilds.get(&guild_id) {
-                                if let Some(member) = guild.read().await.members.get(&self.id) {
-                                    has_role = Some(member.roles.contains(&role));
-                                }
-                            }
+                            has_role = cache.member_field(guild_id, self.id, |member| {
+                                member.roles.contains(&role)
+                            });
                         }
                     }
 
@@ -784,11 +756,8 @@ impl User {
                 #[cfg(feature = "cache")]
                     {
                         if let Some(cache) = cache_http.cache() {
-                            if let Some(guild) = cache.read().await.guilds.get(&guild_id) {
-                                if let Some(member) = guild.read().await.members.get(&self.id) {
-                                    has_role = Some(member.roles.contains(&role));
-                                }
-                            }
+                            has_role = cache
+                                .member_field(guild_id, self.id, |member| member.roles.contains(&role));
                         }
                     }
 
@@ -892,12 +861,10 @@ impl User {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                if let Some(guild) = guild_id.to_guild_cached(cache).await {
-                    let guild = guild.read().await;
-                    return guild
-                        .members
-                        .get(&self.id)
-                        .and_then(|member| member.nick.clone());
+                if let Some(nick) =
+                    cache.member_field(guild_id, self.id, |member| member.nick.clone())
+                {
+                    return nick;
                 }
             }
         }
@@ -908,23 +875,15 @@ impl User {
             .ok()
             .and_then(|member| member.nick)
     }
-
-    /// Returns a string representing the user.
-    /// This is in the format of: `<@USER_ID>`
-    pub async fn async_to_string(&self) -> String {
-        self.id.mention().await
-    }
 }
 
-/*
-impl fmt::Display for User {
+impl Display for User {
     /// Formats a string which will mention the user.
     // This is in the format of: `<@USER_ID>`
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&futures::executor::block_on(self.id.mention()), f)
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.id.mention(), f)
     }
 }
-*/
 
 #[cfg(feature = "model")]
 impl UserId {
@@ -946,12 +905,8 @@ impl UserId {
     /// [`User`]: ../user/struct.User.html
     #[cfg(feature = "cache")]
     #[inline]
-    pub async fn to_user_cached(
-        self,
-        cache: impl AsRef<CacheRwLock>,
-    ) -> Option<Arc<SyncRwLock<User>>> {
-        let guard = cache.as_ref().read().await;
-        guard.user(self)
+    pub fn to_user_cached(self, cache: impl AsRef<Cache>) -> Option<User> {
+        cache.as_ref().user(self)
     }
 
     /// First attempts to find a [`User`] by its Id in the cache,
@@ -967,8 +922,8 @@ impl UserId {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                if let Some(user) = cache.read().await.user(self) {
-                    return Ok(user.read().clone());
+                if let Some(user) = cache.user(self) {
+                    return Ok(user);
                 }
             }
         }
@@ -1018,14 +973,14 @@ impl<'a> From<&'a CurrentUser> for UserId {
 impl From<Member> for UserId {
     /// Gets the Id of a `Member`.
     fn from(member: Member) -> UserId {
-        member.user.read().id
+        member.user.id
     }
 }
 
 impl<'a> From<&'a Member> for UserId {
     /// Gets the Id of a `Member`.
     fn from(member: &Member) -> UserId {
-        member.user.read().id
+        member.user.id
     }
 }
 
@@ -2,7 +2,7 @@ use super::prelude::*;
 use serde::de::Error as DeError;
 use serde::de::MapAccess;
 use serde::ser::{Serialize, SerializeSeq, Serializer};
-use std::{collections::HashMap, hash::Hash, sync::Arc};
+use std::{collections::HashMap, hash::Hash};
 
 #[cfg(feature = "cache")]
 use crate::internal::prelude::*;
@@ -10,8 +10,7 @@ use crate::internal::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
 use super::permissions::Permissions;
 #[cfg(all(feature = "cache", feature = "model"))]
-use crate::cache::CacheRwLock;
-use crate::internal::{AsyncRwLock, SyncRwLock};
+use crate::cache::Cache;
 
 pub fn default_true() -> bool {
     true
@@ -45,12 +44,25 @@ pub fn serialize_emojis<S: Serializer>(
 
 pub fn deserialize_guild_channels<'de, D: Deserializer<'de>>(
     deserializer: D,
-) -> StdResult<HashMap<ChannelId, Arc<AsyncRwLock<GuildChannel>>>, D::Error> {
+) -> StdResult<HashMap<ChannelId, GuildChannel>, D::Error> {
     let vec: Vec<GuildChannel> = Deserialize::deserialize(deserializer)?;
     let mut map = HashMap::new();
 
     for channel in vec {
-        map.insert(channel.id, Arc::new(AsyncRwLock::new(channel)));
+        map.insert(channel.id, channel);
+    }
+
+    Ok(map)
+}
+
+pub fn deserialize_guild_threads<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> StdResult<HashMap<ChannelId, GuildThread>, D::Error> {
+    let vec: Vec<GuildThread> = Deserialize::deserialize(deserializer)?;
+    let mut map = HashMap::new();
+
+    for thread in vec {
+        map.insert(thread.id, thread);
     }
 
     Ok(map)
@@ -64,7 +76,7 @@ pub fn deserialize_members<'de, D: Deserializer<'de>>(
     let mut members = HashMap::new();
 
     for member in vec {
-        let user_id = member.user.read().id;
+        let user_id = member.user.id;
 
         members.insert(user_id, member);
     }
@@ -106,10 +118,11 @@ pub fn deserialize_private_channels<'de, D: Deserializer<'de>>(
 
     for private_channel in vec {
         let id = match private_channel {
-            Channel::Group(ref group) => futures::executor::block_on(group.read()).channel_id,
-            Channel::Private(ref channel) => futures::executor::block_on(channel.read()).id,
+            Channel::Group(ref group) => group.channel_id,
+            Channel::Private(ref channel) => channel.id,
             Channel::Guild(_) => unreachable!("Guild private channel decode"),
             Channel::Category(_) => unreachable!("Channel category private channel decode"),
+            Channel::Thread(_) => unreachable!("Thread private channel decode"),
         };
 
         private_channels.insert(id, private_channel);
@@ -159,7 +172,7 @@ pub fn serialize_roles<S: Serializer>(
 
 pub fn deserialize_single_recipient<'de, D: Deserializer<'de>>(
     deserializer: D,
-) -> StdResult<Arc<SyncRwLock<User>>, D::Error> {
+) -> StdResult<User, D::Error> {
     let mut users: Vec<User> = Deserialize::deserialize(deserializer)?;
     let user = if users.is_empty() {
         return Err(DeError::custom("Expected a single recipient"));
@@ -167,55 +180,41 @@ pub fn deserialize_single_recipient<'de, D: Deserializer<'de>>(
         users.remove(0)
     };
 
-    Ok(Arc::new(SyncRwLock::new(user)))
+    Ok(user)
 }
 
 pub fn serialize_single_recipient<S: Serializer>(
-    user: &Arc<SyncRwLock<User>>,
+    user: &User,
     serializer: S,
 ) -> StdResult<S::Ok, S::Error> {
     let mut seq = serializer.serialize_seq(Some(1))?;
 
-    seq.serialize_element(&*user.read())?;
+    seq.serialize_element(user)?;
 
     seq.end()
 }
 
-pub fn deserialize_sync_user<'de, D>(deserializer: D) -> StdResult<Arc<SyncRwLock<User>>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    Ok(Arc::new(SyncRwLock::new(User::deserialize(deserializer)?)))
-}
-
-pub fn serialize_sync_user<S: Serializer>(
-    user: &Arc<SyncRwLock<User>>,
-    serializer: S,
-) -> StdResult<S::Ok, S::Error> {
-    User::serialize(&*user.read(), serializer)
-}
-
 pub fn deserialize_users<'de, D: Deserializer<'de>>(
     deserializer: D,
-) -> StdResult<HashMap<UserId, Arc<SyncRwLock<User>>>, D::Error> {
+) -> StdResult<HashMap<UserId, User>, D::Error> {
     let vec: Vec<User> = Deserialize::deserialize(deserializer)?;
     let mut users = HashMap::new();
 
     for user in vec {
-        users.insert(user.id, Arc::new(SyncRwLock::new(user)));
+        users.insert(user.id, user);
     }
 
     Ok(users)
 }
 
 pub fn serialize_users<S: Serializer>(
-    users: &HashMap<UserId, Arc<SyncRwLock<User>>>,
+    users: &HashMap<UserId, User>,
     serializer: S,
 ) -> StdResult<S::Ok, S::Error> {
     let mut seq = serializer.serialize_seq(Some(users.len()))?;
 
     for user in users.values() {
-        seq.serialize_element(&*user.read())?;
+        seq.serialize_element(user)?;
     }
 
     seq.end()
@@ -267,28 +266,14 @@ pub fn serialize_gen_map<K: Eq + Hash, S: Serializer, V: Serialize>(
     seq.end()
 }
 
-pub fn serialize_gen_locked_map<K: Eq + Hash, S: Serializer, V: Serialize>(
-    map: &HashMap<K, Arc<AsyncRwLock<V>>>,
-    serializer: S,
-) -> StdResult<S::Ok, S::Error> {
-    let mut seq = serializer.serialize_seq(Some(map.len()))?;
-
-    for value in map.values() {
-        seq.serialize_element(&*futures::executor::block_on(value.read()))?;
-    }
-
-    seq.end()
-}
-
 #[cfg(all(feature = "cache", feature = "model"))]
-pub async fn user_has_perms(
-    cache: impl AsRef<CacheRwLock>,
+pub fn user_has_perms(
+    cache: impl AsRef<Cache>,
     channel_id: ChannelId,
     guild_id: Option<GuildId>,
     mut permissions: Permissions,
 ) -> Result<bool> {
-    let cache = cache.as_ref().read().await;
-    let current_user = &cache.user;
+    let cache = cache.as_ref();
 
     let guild_id = match guild_id {
         Some(id) => id,
@@ -299,7 +284,8 @@ pub async fn user_has_perms(
             };
 
             match channel {
-                Channel::Guild(channel) => channel.read().await.guild_id,
+                Channel::Guild(channel) => channel.guild_id,
+                Channel::Thread(thread) => thread.guild_id,
                 Channel::Group(_) | Channel::Private(_) | Channel::Category(_) => {
                     // Both users in DMs, all users in groups, and maybe all channels in categories
                     // will have the same permissions.
@@ -316,15 +302,12 @@ pub async fn user_has_perms(
         }
     };
 
-    let guild = match cache.guild(guild_id) {
+    let guild = match cache.guilds.get(&guild_id) {
         Some(guild) => guild,
         None => return Err(Error::Model(ModelError::ItemMissing)),
     };
 
-    let perms = {
-        let tmp = guild.read().await;
-        tmp.user_permissions_in(channel_id, current_user.id).await
-    };
+    let perms = guild.user_permissions_in(channel_id, cache.current_user_id());
 
     permissions.remove(perms);
 
@@ -1,7 +1,7 @@
 //! Webhook model and implementations.
 
 use super::{
-    id::{ChannelId, GuildId, WebhookId},
+    id::{ApplicationId, ChannelId, GuildId, WebhookId},
     user::User,
 };
 
@@ -9,6 +9,8 @@ use super::{
 use super::channel::Message;
 #[cfg(feature = "model")]
 use crate::builder::ExecuteWebhook;
+#[cfg(feature = "model")]
+use crate::http::AttachmentType;
 #[cfg(feature = "http")]
 use crate::http::Http;
 #[cfg(feature = "model")]
@@ -16,8 +18,65 @@ use crate::internal::prelude::*;
 #[cfg(feature = "model")]
 use crate::utils;
 #[cfg(feature = "model")]
+use serde_json::json;
+#[cfg(feature = "model")]
 use std::mem;
 
+enum_number! {
+    /// The kind of a [`Webhook`].
+    ///
+    /// [`Webhook`]: struct.Webhook.html
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum WebhookType {
+        /// A webhook which posts messages to a channel using its token.
+        #[default]
+        Incoming = 1,
+        /// A webhook managed by Discord, which crossposts the messages of a
+        /// followed news channel.
+        ChannelFollower = 2,
+        /// A webhook used by an application, for interactions.
+        Application = 3,
+    }
+}
+
+impl WebhookType {
+    pub fn name(&self) -> &str {
+        match *self {
+            WebhookType::Incoming => "incoming",
+            WebhookType::ChannelFollower => "channel follower",
+            WebhookType::Application => "application",
+            WebhookType::Unknown(_) => "unknown",
+        }
+    }
+}
+
+/// The guild of the news channel followed by a channel follower [`Webhook`].
+///
+/// [`Webhook`]: struct.Webhook.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct WebhookGuild {
+    /// The Id of the guild.
+    pub id: GuildId,
+    /// The name of the guild.
+    pub name: String,
+    /// The hash of the guild's icon.
+    pub icon: Option<String>,
+}
+
+/// The news channel followed by a channel follower [`Webhook`].
+///
+/// [`Webhook`]: struct.Webhook.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct WebhookChannel {
+    /// The Id of the channel.
+    pub id: ChannelId,
+    /// The name of the channel.
+    pub name: String,
+}
+
 /// A representation of a webhook, which is a low-effort way to post messages to
 /// channels. They do not necessarily require a bot user or authentication to
 /// use.
@@ -28,6 +87,9 @@ pub struct Webhook {
     ///
     /// Can be used to calculate the creation date of the webhook.
     pub id: WebhookId,
+    /// The kind of the webhook.
+    #[serde(rename = "type", default)]
+    pub kind: WebhookType,
     /// The default avatar.
     ///
     /// This can be modified via [`ExecuteWebhook::avatar`].
@@ -45,11 +107,49 @@ pub struct Webhook {
     /// [`ExecuteWebhook::username`]: ../../builder/struct.ExecuteWebhook.html#method.username
     pub name: Option<String>,
     /// The webhook's secure token.
+    ///
+    /// **Note**: This is empty for webhooks which are not
+    /// [`WebhookType::Incoming`].
+    ///
+    /// [`WebhookType::Incoming`]: enum.WebhookType.html#variant.Incoming
+    #[serde(default)]
     pub token: String,
     /// The user that created the webhook.
     ///
     /// **Note**: This is not received when getting a webhook by its token.
     pub user: Option<User>,
+    /// The Id of the application that created the webhook, if any.
+    #[serde(default)]
+    pub application_id: Option<ApplicationId>,
+    /// The guild of the followed channel, for channel follower webhooks.
+    #[serde(default)]
+    pub source_guild: Option<WebhookGuild>,
+    /// The followed channel, for channel follower webhooks.
+    #[serde(default)]
+    pub source_channel: Option<WebhookChannel>,
+}
+
+impl Webhook {
+    /// Whether the webhook is an [`Incoming`] one, which can be executed
+    /// with its token.
+    ///
+    /// [`Incoming`]: enum.WebhookType.html#variant.Incoming
+    #[inline]
+    pub fn is_incoming(&self) -> bool {
+        self.kind == WebhookType::Incoming
+    }
+
+    /// Whether the webhook crossposts messages from a followed news channel.
+    #[inline]
+    pub fn is_channel_follower(&self) -> bool {
+        self.kind == WebhookType::ChannelFollower
+    }
+
+    /// Whether the webhook belongs to an application.
+    #[inline]
+    pub fn is_application(&self) -> bool {
+        self.kind == WebhookType::Application
+    }
 }
 
 #[cfg(feature = "model")]
@@ -59,9 +159,19 @@ impl Webhook {
     /// As this calls the [`http::delete_webhook_with_token`] function,
     /// authentication is not required.
     ///
+    /// **Note**: Webhooks without a token, such as channel follower ones, are
+    /// deleted with [`http::delete_webhook`] instead, which requires the
+    /// [Manage Webhooks] permission.
+    ///
+    /// [`http::delete_webhook`]: ../../http/fn.delete_webhook.html
     /// [`http::delete_webhook_with_token`]: ../../http/fn.delete_webhook_with_token.html
+    /// [Manage Webhooks]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_WEBHOOKS
     #[inline]
     pub async fn delete(&self, http: impl AsRef<Http>) -> Result<()> {
+        if self.token.is_empty() {
+            return http.as_ref().delete_webhook(self.id.0).await;
+        }
+
         http.as_ref()
             .delete_webhook_with_token(self.id.0, &self.token)
             .await
@@ -165,11 +275,80 @@ impl Webhook {
         }
     }
 
+    /// Renames the webhook in-place.
+    ///
+    /// Refer to [`edit`] for more information.
+    ///
+    /// [`edit`]: #method.edit
+    #[inline]
+    pub async fn edit_name(&mut self, http: impl AsRef<Http>, name: &str) -> Result<()> {
+        self.edit(http, Some(name), None).await
+    }
+
+    /// Sets the webhook's avatar in-place.
+    ///
+    /// The avatar can be given as any [`AttachmentType`], such as a path to an
+    /// image file or an image URL, and is encoded with [`Http::image_data`].
+    ///
+    /// Refer to [`edit`] for more information.
+    ///
+    /// [`AttachmentType`]: ../../http/enum.AttachmentType.html
+    /// [`Http::image_data`]: ../../http/client/struct.Http.html#method.image_data
+    /// [`edit`]: #method.edit
+    #[cfg(feature = "utils")]
+    pub async fn edit_avatar<'a>(
+        &mut self,
+        http: impl AsRef<Http>,
+        avatar: impl Into<AttachmentType<'a>>,
+    ) -> Result<()> {
+        let http = http.as_ref();
+        let avatar = http.image_data(avatar.into()).await?;
+
+        self.edit(http, None, Some(&avatar)).await
+    }
+
+    /// Removes the webhook's avatar in-place.
+    ///
+    /// Refer to [`edit`] for more information.
+    ///
+    /// [`edit`]: #method.edit
+    #[inline]
+    pub async fn delete_avatar(&mut self, http: impl AsRef<Http>) -> Result<()> {
+        self.edit(http, None, Some("")).await
+    }
+
+    /// Moves the webhook to another channel of the same guild in-place.
+    ///
+    /// Unlike the other edits, this can not be done with the webhook's token.
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission.
+    ///
+    /// [Manage Webhooks]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_WEBHOOKS
+    pub async fn edit_channel<C: Into<ChannelId>>(
+        &mut self,
+        http: impl AsRef<Http>,
+        channel_id: C,
+    ) -> Result<()> {
+        let map = json!({
+            "channel_id": channel_id.into(),
+        });
+
+        *self = http.as_ref().edit_webhook(self.id.0, &map).await?;
+
+        Ok(())
+    }
+
     /// Executes a webhook with the fields set via the given builder.
     ///
     /// The builder provides a method of setting only the fields you need,
     /// without needing to pass a long set of arguments.
     ///
+    /// If `wait` is `true`, the created [`Message`] is returned once Discord
+    /// has sent it.
+    ///
+    /// As a webhook is executed with its token, `http` does not need a bot
+    /// token; a client made with `Http::default()` suffices.
+    ///
     /// # Examples
     ///
     /// Execute a webhook with message content of `test`:
@@ -232,23 +411,54 @@ impl Webhook {
     /// }).await;
     /// # }
     /// ```
-    #[inline]
-    pub async fn execute<F>(
+    ///
+    /// Execute a webhook from its URL without a bot token, uploading a file
+    /// and waiting for the message to be sent:
+    ///
+    /// ```rust,no_run
+    /// use serenity::http::{AttachmentType, Http};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let http = Http::default();
+    /// let url = "https://discord.com/api/webhooks/245037420704169985/ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
+    /// let webhook = http.get_webhook_from_url(url).await?;
+    ///
+    /// let message = webhook.execute(&http, true, |w| {
+    ///     w.content("Today's report")
+    ///         .avatar_url("https://i.imgur.com/KTs6whd.jpg")
+    ///         .embed(|e| e.title("Report").description("All systems operational"))
+    ///         .add_file(AttachmentType::Path(std::path::Path::new("./report.txt")))
+    /// }).await?;
+    ///
+    /// println!("Sent message {:?}", message.map(|m| m.id));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    pub async fn execute<'a, F>(
         &self,
         http: impl AsRef<Http>,
         wait: bool,
         f: F,
     ) -> Result<Option<Message>>
     where
-        F: FnOnce(&mut ExecuteWebhook) -> &mut ExecuteWebhook,
+        for<'b> F: FnOnce(&'b mut ExecuteWebhook<'a>) -> &'b mut ExecuteWebhook<'a>,
     {
         let mut execute_webhook = ExecuteWebhook::default();
         f(&mut execute_webhook);
-        let map = utils::hashmap_to_json_map(execute_webhook.0);
+        let ExecuteWebhook(map, files) = execute_webhook;
+        let map = utils::hashmap_to_json_map(map);
 
-        http.as_ref()
-            .execute_webhook(self.id.0, &self.token, wait, &map)
-            .await
+        if files.is_empty() {
+            http.as_ref()
+                .execute_webhook(self.id.0, &self.token, wait, &map)
+                .await
+        } else {
+            http.as_ref()
+                .execute_webhook_with_files(self.id.0, &self.token, wait, files, map)
+                .await
+        }
     }
 
     /// Retrieves the latest information about the webhook, editing the
@@ -287,3 +497,42 @@ impl WebhookId {
         http.as_ref().get_webhook(self.0).await
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Webhook, WebhookType};
+    use crate::model::id::{ChannelId, GuildId};
+    use serde_json::json;
+
+    #[test]
+    fn test_webhook_kind() {
+        let incoming: Webhook = serde_json::from_value(json!({
+            "id": "1",
+            "channel_id": "2",
+            "guild_id": "3",
+            "name": "hook",
+            "avatar": null,
+            "token": "abc",
+        }))
+        .unwrap();
+
+        assert!(incoming.is_incoming());
+
+        let follower: Webhook = serde_json::from_value(json!({
+            "id": "1",
+            "type": 2,
+            "channel_id": "2",
+            "guild_id": "3",
+            "name": "announcements",
+            "avatar": null,
+            "source_guild": {"id": "4", "name": "upstream", "icon": null},
+            "source_channel": {"id": "5", "name": "news"},
+        }))
+        .unwrap();
+
+        assert_eq!(follower.kind, WebhookType::ChannelFollower);
+        assert!(follower.token.is_empty());
+        assert_eq!(follower.source_guild.unwrap().id, GuildId(4));
+        assert_eq!(follower.source_channel.unwrap().id, ChannelId(5));
+    }
+}
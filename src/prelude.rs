@@ -12,13 +12,23 @@
 //! use serenity::prelude::*;
 //! ```
 //!
+//! To import every public item of the library instead, use [`serenity::all`].
+//!
 //! [`serenity::Error`]: ../enum.Error.html
+//! [`serenity::all`]: ../all/index.html
 
 pub use crate::error::Error as SerenityError;
+pub use crate::model::id::*;
 pub use crate::model::misc::Mentionable;
 
+#[cfg(feature = "builder")]
+pub use crate::builder::{CreateEmbed, CreateMessage, EditMessage};
+#[cfg(feature = "client")]
+pub use crate::client::bridge::gateway::ReactionFilter;
 #[cfg(feature = "client")]
 pub use crate::client::{Client, ClientError, Context, EventHandler, RawEventHandler};
+#[cfg(feature = "standard_framework")]
+pub use crate::framework::standard::{Args, CommandError, CommandResult};
 #[cfg(feature = "gateway")]
 pub use crate::gateway::GatewayError;
 #[cfg(feature = "http")]
@@ -0,0 +1,120 @@
+use crate::client::{bridge::gateway::ReactionFilter, Context};
+use crate::internal::prelude::*;
+use crate::model::{
+    channel::ReactionType,
+    id::{ChannelId, UserId},
+};
+use futures::StreamExt;
+use std::time::Duration;
+use tokio::time::timeout;
+
+const CONFIRM: &str = "✅";
+const CANCEL: &str = "❌";
+
+/// The outcome of a prompt sent via [`confirm`].
+///
+/// [`confirm`]: fn.confirm.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ConfirmResult {
+    /// The user reacted with ✅.
+    Confirmed,
+    /// The user reacted with ❌.
+    Cancelled,
+    /// The user did not react in time.
+    TimedOut,
+}
+
+impl ConfirmResult {
+    /// Whether the user confirmed the prompt.
+    pub fn is_confirmed(self) -> bool {
+        self == ConfirmResult::Confirmed
+    }
+}
+
+/// Asks a user to confirm an action, such as a destructive moderation
+/// command.
+///
+/// The prompt is sent to the channel and reacted to with ✅ and ❌, and the
+/// first of these reactions added by the user decides the result. Reactions
+/// by anyone else are ignored. Once the user reacted or the timeout passed,
+/// the reactions are removed from the prompt.
+///
+/// **Note**: Requires the [Add Reactions] permission. Without the
+/// [Manage Messages] permission, only the current user's reactions are
+/// removed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::client::Context;
+/// # use serenity::model::channel::Message;
+/// #
+/// # async fn run(ctx: &Context, msg: &Message) -> serenity::Result<()> {
+/// use serenity::utils::confirm;
+/// use std::time::Duration;
+///
+/// let prompt = "Really delete the last 100 messages?";
+/// let result = confirm(ctx, msg.channel_id, msg.author.id, prompt, Duration::from_secs(30)).await?;
+///
+/// if result.is_confirmed() {
+///     // Delete the messages.
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error::Http`] if the prompt could not be sent or reacted to.
+///
+/// [`Error::Http`]: ../enum.Error.html#variant.Http
+/// [Add Reactions]: ../model/permissions/struct.Permissions.html#associatedconstant.ADD_REACTIONS
+/// [Manage Messages]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+pub async fn confirm(
+    ctx: &Context,
+    channel_id: ChannelId,
+    user_id: UserId,
+    prompt: &str,
+    timeout_after: Duration,
+) -> Result<ConfirmResult> {
+    let message = channel_id.say(&ctx.http, prompt).await?;
+    let message_id = message.id;
+
+    let confirm = ReactionType::Unicode(CONFIRM.to_string());
+    let cancel = ReactionType::Unicode(CANCEL.to_string());
+
+    let (filter, mut reactions) = {
+        let (confirm, cancel) = (confirm.clone(), cancel.clone());
+
+        ReactionFilter::new(move |reaction| {
+            reaction.message_id == message_id
+                && reaction.user_id == user_id
+                && (reaction.emoji == confirm || reaction.emoji == cancel)
+        })
+    };
+    ctx.shard.clone().set_reaction_filter(filter).await;
+
+    message.react(ctx, confirm.clone()).await?;
+    message.react(ctx, cancel.clone()).await?;
+
+    let result = match timeout(timeout_after, reactions.next()).await {
+        Ok(Some(reaction)) if reaction.emoji == confirm => ConfirmResult::Confirmed,
+        Ok(Some(_)) => ConfirmResult::Cancelled,
+        Ok(None) | Err(_) => ConfirmResult::TimedOut,
+    };
+
+    if ctx
+        .http
+        .delete_message_reactions(channel_id.0, message_id.0)
+        .await
+        .is_err()
+    {
+        for reaction_type in [confirm, cancel] {
+            let _ = channel_id
+                .delete_reaction(&ctx.http, message_id, None, reaction_type)
+                .await;
+        }
+    }
+
+    Ok(result)
+}
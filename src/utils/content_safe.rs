@@ -0,0 +1,394 @@
+use crate::cache::Cache;
+use crate::model::id::{ChannelId, GuildId, RoleId, UserId};
+use std::collections::{HashMap, HashSet};
+
+/// Struct that allows to alter [`content_safe`]'s behaviour.
+///
+/// [`content_safe`]: fn.content_safe.html
+#[derive(Clone, Debug)]
+pub struct ContentSafeOptions {
+    clean_role: bool,
+    clean_user: bool,
+    clean_channel: bool,
+    clean_here: bool,
+    clean_everyone: bool,
+    show_discriminator: bool,
+    guild_reference: Option<GuildId>,
+}
+
+impl ContentSafeOptions {
+    pub fn new() -> Self {
+        ContentSafeOptions::default()
+    }
+
+    /// [`content_safe`] will replace role mentions (`<@&{id}>`) with its name
+    /// prefixed with `@` (`@rolename`) or with `@deleted-role` if the
+    /// identifier is invalid.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn clean_role(mut self, b: bool) -> Self {
+        self.clean_role = b;
+
+        self
+    }
+
+    /// If set to true, [`content_safe`] will replace user mentions
+    /// (`<@!{id}>` or `<@{id}>`) with the user's name prefixed with `@`
+    /// (`@username`) or with `@invalid-user` if the identifier is invalid.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn clean_user(mut self, b: bool) -> Self {
+        self.clean_user = b;
+
+        self
+    }
+
+    /// If set to true, [`content_safe`] will replace channel mentions
+    /// (`<#{id}>`) with the channel's name prefixed with `#`
+    /// (`#channelname`) or with `#deleted-channel` if the identifier is
+    /// invalid.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn clean_channel(mut self, b: bool) -> Self {
+        self.clean_channel = b;
+
+        self
+    }
+
+    /// If set to true, if [`content_safe`] replaces a user mention it will
+    /// add their four digit discriminator with a preceeding `#`,
+    /// turning `@username` to `@username#discriminator`.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn show_discriminator(mut self, b: bool) -> Self {
+        self.show_discriminator = b;
+
+        self
+    }
+
+    /// If set, [`content_safe`] will replace a user mention with the user's
+    /// display name in passed `guild`.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn display_as_member_from<G: Into<GuildId>>(mut self, guild: G) -> Self {
+        self.guild_reference = Some(guild.into());
+
+        self
+    }
+
+    /// If set, [`content_safe`] will replace `@here` with a non-pinging
+    /// alternative.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn clean_here(mut self, b: bool) -> Self {
+        self.clean_here = b;
+
+        self
+    }
+
+    /// If set, [`content_safe`] will replace `@everyone` with a non-pinging
+    /// alternative.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn clean_everyone(mut self, b: bool) -> Self {
+        self.clean_everyone = b;
+
+        self
+    }
+}
+
+impl Default for ContentSafeOptions {
+    /// Instantiates with all options set to `true`.
+    fn default() -> Self {
+        ContentSafeOptions {
+            clean_role: true,
+            clean_user: true,
+            clean_channel: true,
+            clean_here: true,
+            clean_everyone: true,
+            show_discriminator: true,
+            guild_reference: None,
+        }
+    }
+}
+
+/// A mention found while scanning the content.
+///
+/// The Id is `None` if it has too many digits to be valid.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Mention {
+    Channel(Option<ChannelId>),
+    Role(Option<RoleId>),
+    User(Option<UserId>),
+}
+
+/// A piece of the content, borrowed from it unless it is a mention to be
+/// replaced.
+enum Segment<'a> {
+    Text(&'a str),
+    Mention(Mention),
+}
+
+/// Splits the content into text and the mentions which should be replaced.
+///
+/// `@everyone` and `@here` are escaped as part of the text.
+fn scan<'a>(s: &'a str, options: &ContentSafeOptions) -> Vec<Segment<'a>> {
+    let mut segments = Vec::new();
+    let mut text_start = 0;
+    let mut i = 0;
+
+    while let Some(offset) = s[i..].find(['<', '@']) {
+        i += offset;
+
+        let rest = &s[i..];
+        let found = if rest.starts_with('@') {
+            escaped_ping(rest, options).map(|(len, escaped)| (len, Segment::Text(escaped)))
+        } else {
+            mention(rest, options).map(|(len, mention)| (len, Segment::Mention(mention)))
+        };
+
+        match found {
+            Some((len, segment)) => {
+                if text_start < i {
+                    segments.push(Segment::Text(&s[text_start..i]));
+                }
+
+                segments.push(segment);
+                i += len;
+                text_start = i;
+            }
+            None => i += 1,
+        }
+    }
+
+    if text_start < s.len() {
+        segments.push(Segment::Text(&s[text_start..]));
+    }
+
+    segments
+}
+
+/// Parses an `@everyone` or `@here` at the start of `s`, returning its length
+/// and its non-pinging alternative.
+fn escaped_ping(s: &str, options: &ContentSafeOptions) -> Option<(usize, &'static str)> {
+    if options.clean_everyone && s.starts_with("@everyone") {
+        Some(("@everyone".len(), "@\u{200B}everyone"))
+    } else if options.clean_here && s.starts_with("@here") {
+        Some(("@here".len(), "@\u{200B}here"))
+    } else {
+        None
+    }
+}
+
+/// Parses a mention at the start of `s`, returning its length.
+///
+/// Only mentions of the kinds being cleaned are parsed.
+fn mention(s: &str, options: &ContentSafeOptions) -> Option<(usize, Mention)> {
+    let (prefix, kind): (&str, fn(Option<u64>) -> Mention) =
+        if options.clean_role && s.starts_with("<@&") {
+            ("<@&", |id| Mention::Role(id.map(RoleId)))
+        } else if options.clean_user && s.starts_with("<@!") {
+            ("<@!", |id| Mention::User(id.map(UserId)))
+        } else if options.clean_user && s.starts_with("<@") {
+            ("<@", |id| Mention::User(id.map(UserId)))
+        } else if options.clean_channel && s.starts_with("<#") {
+            ("<#", |id| Mention::Channel(id.map(ChannelId)))
+        } else {
+            return None;
+        };
+
+    let digits = s[prefix.len()..]
+        .bytes()
+        .take_while(u8::is_ascii_digit)
+        .count();
+    let end = prefix.len() + digits;
+
+    if digits == 0 || s.as_bytes().get(end) != Some(&b'>') {
+        return None;
+    }
+
+    Some((end + 1, kind(s[prefix.len()..end].parse().ok())))
+}
+
+/// Looks up the replacements of every distinct mention at once.
+fn resolve(
+    cache: &Cache,
+    mentions: HashSet<Mention>,
+    options: &ContentSafeOptions,
+) -> HashMap<Mention, String> {
+    let mut replacements = HashMap::with_capacity(mentions.len());
+    let mut roles = Vec::new();
+
+    for mention in mentions {
+        let replacement = match mention {
+            Mention::Channel(id) => id.and_then(|id| cache.channels.get(&id)).map_or_else(
+                || "#deleted-channel".to_string(),
+                |c| format!("#{}", c.name),
+            ),
+            Mention::Role(Some(id)) => {
+                roles.push(id);
+
+                continue;
+            }
+            Mention::Role(None) => "@deleted-role".to_string(),
+            Mention::User(id) => id
+                .and_then(|id| user_name(cache, id, options))
+                .unwrap_or_else(|| "@invalid-user".to_string()),
+        };
+
+        replacements.insert(mention, replacement);
+    }
+
+    if !roles.is_empty() {
+        for guild in cache.guilds.iter() {
+            roles.retain(|id| match guild.roles.get(id) {
+                Some(role) => {
+                    let replacement = format!("@{}", role.name);
+                    replacements.insert(Mention::Role(Some(*id)), replacement);
+
+                    false
+                }
+                None => true,
+            });
+
+            if roles.is_empty() {
+                break;
+            }
+        }
+
+        for id in roles {
+            replacements.insert(Mention::Role(Some(id)), "@deleted-role".to_string());
+        }
+    }
+
+    replacements
+}
+
+fn user_name(cache: &Cache, id: UserId, options: &ContentSafeOptions) -> Option<String> {
+    if let Some(guild_id) = options.guild_reference {
+        let guild = cache.guilds.get(&guild_id)?;
+        let member = guild.members.get(&id)?;
+        let name = member.nick.as_ref().unwrap_or(&member.user.name);
+
+        return Some(if options.show_discriminator {
+            format!("@{}#{:04}", name, member.user.discriminator)
+        } else {
+            format!("@{}", name)
+        });
+    }
+
+    let user = cache.users.get(&id)?;
+
+    Some(if options.show_discriminator {
+        format!("@{}#{:04}", user.name, user.discriminator)
+    } else {
+        format!("@{}", user.name)
+    })
+}
+
+/// Appends a replacement to the output, escaping `@everyone` and `@here` in
+/// names such as a role called `everyone`.
+fn push_replacement(out: &mut String, replacement: &str, options: &ContentSafeOptions) {
+    let mut rest = replacement;
+
+    while let Some(i) = rest.find('@') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+
+        match escaped_ping(rest, options) {
+            Some((len, escaped)) => {
+                out.push_str(escaped);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('@');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+}
+
+/// Transforms role, channel, user, `@everyone` and `@here` mentions
+/// into raw text by using the [`Cache`] only.
+///
+/// [`ContentSafeOptions`] decides what kind of mentions should be filtered
+/// and how the raw-text will be displayed.
+///
+/// The content is scanned once, and each distinct mention is looked up in the
+/// cache once.
+///
+/// # Examples
+///
+/// Sanitise an `@everyone` mention.
+///
+/// ```rust
+/// # use serenity::client::Cache;
+/// #
+/// # let cache = Cache::default();
+/// use serenity::utils::{
+///     content_safe,
+///     ContentSafeOptions,
+/// };
+///
+/// let with_mention = "@everyone";
+/// let options = ContentSafeOptions::default();
+/// let without_mention = content_safe(&cache, &with_mention, &options);
+///
+/// assert_eq!("@\u{200B}everyone".to_string(), without_mention);
+/// ```
+/// [`ContentSafeOptions`]: struct.ContentSafeOptions.html
+/// [`Cache`]: ../cache/struct.Cache.html
+pub fn content_safe(
+    cache: impl AsRef<Cache>,
+    s: impl AsRef<str>,
+    options: &ContentSafeOptions,
+) -> String {
+    let s = s.as_ref();
+    let segments = scan(s, options);
+
+    let mentions = segments
+        .iter()
+        .filter_map(|segment| match segment {
+            Segment::Mention(mention) => Some(*mention),
+            Segment::Text(_) => None,
+        })
+        .collect::<HashSet<_>>();
+
+    let replacements = resolve(cache.as_ref(), mentions, options);
+    let mut out = String::with_capacity(s.len());
+
+    for segment in segments {
+        match segment {
+            Segment::Text(text) => out.push_str(text),
+            Segment::Mention(mention) => {
+                push_replacement(&mut out, &replacements[&mention], options)
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::{content_safe, ContentSafeOptions};
+    use crate::cache::Cache;
+
+    #[test]
+    fn test_adjacent_mentions() {
+        let cache = Cache::default();
+        let options = ContentSafeOptions::default();
+
+        assert_eq!(
+            content_safe(&cache, "<@1><@!1>@here<#2><@&3>@@everyone<<@4>>", &options),
+            "@invalid-user@invalid-user@\u{200B}here#deleted-channel@deleted-role\
+             @@\u{200B}everyone<@invalid-user>"
+        );
+        assert_eq!(
+            content_safe(&cache, "日本語 <@1> 한국어", &options),
+            "日本語 @invalid-user 한국어"
+        );
+    }
+}
@@ -1,5 +1,4 @@
 use crate::model::prelude::*;
-use chrono::{DateTime, FixedOffset, Local, TimeZone};
 use serde_json::Value;
 
 /// A builder for constructing a personal [`Message`] instance.
@@ -81,8 +80,8 @@ impl CustomMessage {
     ///
     /// If not used, the default value is `None` (not all messages are edited).
     #[inline]
-    pub fn edited_timestamp(&mut self, timestamp: DateTime<FixedOffset>) -> &mut Self {
-        self.msg.edited_timestamp = Some(timestamp);
+    pub fn edited_timestamp<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
+        self.msg.edited_timestamp = Some(timestamp.into());
 
         self
     }
@@ -195,10 +194,10 @@ impl CustomMessage {
 
     /// Assign the dummy message the timestamp it was created at.
     ///
-    /// If not used, the default value is the current local time.
+    /// If not used, the default value is the current time.
     #[inline]
-    pub fn timestamp(&mut self, timestamp: DateTime<FixedOffset>) -> &mut Self {
-        self.msg.timestamp = timestamp;
+    pub fn timestamp<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
+        self.msg.timestamp = timestamp.into();
 
         self
     }
@@ -267,14 +266,11 @@ fn dummy_message() -> Message {
         reactions: Vec::new(),
         tts: false,
         webhook_id: None,
-        timestamp: {
-            let now = Local::now();
-
-            FixedOffset::east(0).timestamp(now.timestamp(), 0)
-        },
+        timestamp: Timestamp::now(),
         activity: None,
         application: None,
         message_reference: None,
         flags: None,
+        sticker_items: Vec::new(),
     }
 }
@@ -0,0 +1,271 @@
+//! A client for [Discord's status page], reporting incidents affecting the
+//! platform.
+//!
+//! Gaps in received events or failing requests are not always the bot's
+//! fault; checking for ongoing incidents allows alerting operators about
+//! platform outages rather than debugging the bot's code.
+//!
+//! This is only available with the `discord_status` feature.
+//!
+//! # Examples
+//!
+//! Log incidents as they are reported, checking every five minutes:
+//!
+//! ```rust,no_run
+//! use serenity::utils::discord_status::DiscordStatus;
+//! use std::time::Duration;
+//!
+//! # async fn run() {
+//! tokio::spawn(async {
+//!     DiscordStatus::new()
+//!         .watch_incidents(Duration::from_secs(300), |incident| {
+//!             println!("Discord is having issues: {}", incident.name);
+//!         })
+//!         .await;
+//! });
+//! # }
+//! ```
+//!
+//! [Discord's status page]: https://discordstatus.com
+
+use crate::http::{error::ErrorResponse, HttpError};
+use crate::internal::prelude::*;
+use crate::model::Timestamp;
+use log::warn;
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use std::collections::HashSet;
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// The base URL of the status page's API.
+pub const STATUS_API_URL: &str = "https://discordstatus.com/api/v2";
+
+/// An incident reported on Discord's status page.
+#[derive(Clone, Debug, Deserialize)]
+#[non_exhaustive]
+pub struct Incident {
+    /// The Id of the incident.
+    pub id: String,
+    /// The title of the incident.
+    pub name: String,
+    /// The state of the incident, one of `investigating`, `identified`,
+    /// `monitoring`, `resolved` or `postmortem`.
+    pub status: String,
+    /// How severe the incident is, one of `none`, `minor`, `major` or
+    /// `critical`.
+    pub impact: String,
+    /// A link to the incident on the status page.
+    pub shortlink: Option<String>,
+    /// When the incident was created.
+    pub created_at: Timestamp,
+    /// When the incident was last updated.
+    pub updated_at: Option<Timestamp>,
+    /// When the incident was resolved, if it was.
+    pub resolved_at: Option<Timestamp>,
+    /// The updates posted about the incident, newest first.
+    #[serde(default)]
+    pub incident_updates: Vec<IncidentUpdate>,
+}
+
+impl Incident {
+    /// Whether the incident is still ongoing.
+    pub fn is_unresolved(&self) -> bool {
+        self.resolved_at.is_none()
+    }
+}
+
+/// An update posted about an [`Incident`].
+///
+/// [`Incident`]: struct.Incident.html
+#[derive(Clone, Debug, Deserialize)]
+#[non_exhaustive]
+pub struct IncidentUpdate {
+    /// The Id of the update.
+    pub id: String,
+    /// The state of the incident as of the update.
+    pub status: String,
+    /// The text of the update.
+    pub body: String,
+    /// When the update was posted.
+    pub created_at: Timestamp,
+}
+
+#[derive(Deserialize)]
+struct Incidents {
+    incidents: Vec<Incident>,
+}
+
+/// A client for Discord's status page.
+///
+/// Refer to the [module-level documentation] for an example.
+///
+/// [module-level documentation]: index.html
+#[derive(Clone, Debug)]
+pub struct DiscordStatus {
+    client: Client,
+    base_url: String,
+}
+
+impl DiscordStatus {
+    /// Creates a client for Discord's status page.
+    pub fn new() -> Self {
+        Self::new_with_client(Client::new())
+    }
+
+    /// Creates a client for Discord's status page sending its requests
+    /// through the given `reqwest` client, such as one also used for
+    /// [`Http`].
+    ///
+    /// [`Http`]: ../../http/client/struct.Http.html
+    pub fn new_with_client(client: Client) -> Self {
+        DiscordStatus {
+            client,
+            base_url: STATUS_API_URL.to_string(),
+        }
+    }
+
+    /// Retrieves the incidents which are currently ongoing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the status page could not be reached or
+    /// responded with an error.
+    ///
+    /// [`Error::Http`]: ../../enum.Error.html#variant.Http
+    pub async fn unresolved_incidents(&self) -> Result<Vec<Incident>> {
+        self.get::<Incidents>("/incidents/unresolved.json")
+            .await
+            .map(|body| body.incidents)
+    }
+
+    /// Retrieves the 50 most recent incidents, including resolved ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the status page could not be reached or
+    /// responded with an error.
+    ///
+    /// [`Error::Http`]: ../../enum.Error.html#variant.Http
+    pub async fn incidents(&self) -> Result<Vec<Incident>> {
+        self.get::<Incidents>("/incidents.json")
+            .await
+            .map(|body| body.incidents)
+    }
+
+    /// Checks for unresolved incidents every `interval`, calling the callback
+    /// once for each incident which was not reported before.
+    ///
+    /// Incidents which are ongoing when this is called are reported on the
+    /// first check. Failing checks are logged and retried at the next
+    /// interval.
+    ///
+    /// This never returns, so it should be spawned as a task of its own.
+    pub async fn watch_incidents<F>(&self, interval: Duration, mut callback: F)
+    where
+        F: FnMut(&Incident) + Send,
+    {
+        let mut seen = HashSet::new();
+
+        loop {
+            match self.unresolved_incidents().await {
+                Ok(incidents) => {
+                    for incident in incidents {
+                        if seen.insert(incident.id.clone()) {
+                            callback(&incident);
+                        }
+                    }
+                }
+                Err(why) => warn!("[DiscordStatus] Failed to check for incidents: {:?}", why),
+            }
+
+            sleep(interval).await;
+        }
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self.client.get(&url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(HttpError::UnsuccessfulRequest(
+                ErrorResponse::async_from_response(response).await,
+            )
+            .into());
+        }
+
+        Ok(response.json().await?)
+    }
+}
+
+impl Default for DiscordStatus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DiscordStatus;
+    use crate::http::mock::{MockResponse, MockServer};
+    use serde_json::{json, Value};
+    use std::time::Duration;
+
+    fn incident(id: &str, name: &str) -> Value {
+        json!({
+            "id": id,
+            "name": name,
+            "status": "investigating",
+            "impact": "major",
+            "shortlink": "https://stspg.io/1",
+            "created_at": "2021-01-01T00:00:00.000-08:00",
+            "updated_at": "2021-01-01T00:10:00.000-08:00",
+            "resolved_at": null,
+            "incident_updates": [{
+                "id": "update",
+                "status": "investigating",
+                "body": "We are investigating.",
+                "created_at": "2021-01-01T00:10:00.000-08:00",
+            }],
+        })
+    }
+
+    #[tokio::test]
+    async fn test_watch_incidents() {
+        let server = MockServer::start().await;
+        let mut status = DiscordStatus::new();
+        status.base_url = server.url();
+
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({"incidents": [incident("1", "API errors")]}),
+        ));
+
+        let incidents = status.unresolved_incidents().await.unwrap();
+        assert_eq!(incidents.len(), 1);
+        assert!(incidents[0].is_unresolved());
+        assert_eq!(
+            incidents[0].incident_updates[0].body,
+            "We are investigating."
+        );
+
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({"incidents": [incident("1", "API errors")]}),
+        ));
+        server.enqueue(MockResponse::new(503));
+        server.enqueue(MockResponse::json(
+            200,
+            &json!({"incidents": [incident("1", "API errors"), incident("2", "Gateway outage")]}),
+        ));
+
+        let mut reported = Vec::new();
+        let watch = status.watch_incidents(Duration::from_millis(50), |incident| {
+            reported.push(incident.name.clone())
+        });
+        let _ = tokio::time::timeout(Duration::from_millis(400), watch).await;
+
+        assert_eq!(reported, vec!["API errors", "Gateway outage"]);
+        assert_eq!(server.requests()[1].path, "/incidents/unresolved.json");
+    }
+}
@@ -161,7 +161,7 @@ impl MessageBuilder {
     }
 
     async fn _channel(&mut self, channel: ChannelId) -> &mut Self {
-        let _ = write!(self.0, "{}", channel.mention().await);
+        let _ = write!(self.0, "{}", channel.mention());
 
         self
     }
@@ -218,7 +218,7 @@ impl MessageBuilder {
     ///
     /// [`Mentionable`]: ../model/misc/trait.Mentionable.html
     pub async fn mention<M: Mentionable>(&mut self, item: &M) -> &mut Self {
-        let _ = write!(self.0, "{}", item.mention().await);
+        let _ = write!(self.0, "{}", item.mention());
 
         self
     }
@@ -894,7 +894,7 @@ impl MessageBuilder {
     /// [Display implementation]: ../model/id/struct.RoleId.html#method.fmt-1
     pub async fn role<R: Into<RoleId>>(&mut self, role: R) -> &mut Self {
         let guard = role.into();
-        let _ = write!(self.0, "{}", guard.mention().await);
+        let _ = write!(self.0, "{}", guard.mention());
 
         self
     }
@@ -912,7 +912,7 @@ impl MessageBuilder {
     /// [Display implementation]: ../model/id/struct.UserId.html#method.fmt-1
     pub async fn user<U: Into<UserId>>(&mut self, user: U) -> &mut Self {
         let guard = user.into();
-        let _ = write!(self.0, "{}", guard.mention().await);
+        let _ = write!(self.0, "{}", guard.mention());
 
         self
     }
@@ -3,22 +3,31 @@
 
 mod async_test;
 mod colour;
+#[cfg(all(feature = "client", feature = "model"))]
+mod confirm;
+#[cfg(feature = "cache")]
+mod content_safe;
 mod custom_message;
+#[cfg(feature = "discord_status")]
+pub mod discord_status;
 mod message_builder;
+mod template;
 
+#[cfg(all(feature = "client", feature = "model"))]
+pub use self::confirm::{confirm, ConfirmResult};
+#[cfg(feature = "cache")]
+pub use self::content_safe::{content_safe, ContentSafeOptions};
 pub use self::{
     async_test::run_async_test,
     colour::Colour,
     custom_message::CustomMessage,
     message_builder::{Content, ContentModifier, EmbedMessageBuilding, MessageBuilder},
+    template::template,
 };
 pub use futures::lock::Mutex;
 pub type Color = Colour;
 
 use crate::internal::prelude::*;
-use crate::internal::AsyncRwLock;
-#[cfg(feature = "cache")]
-use crate::model::id::{ChannelId, GuildId, RoleId, UserId};
 use crate::model::{id::EmojiId, misc::EmojiIdentifier};
 use std::{
     collections::HashMap,
@@ -30,11 +39,7 @@ use std::{
 };
 
 #[cfg(feature = "cache")]
-use crate::cache::{Cache, CacheRwLock};
-#[cfg(feature = "cache")]
-use crate::model::channel::Channel;
-#[cfg(feature = "cache")]
-use std::str::FromStr;
+use crate::cache::Cache;
 
 /// Converts a HashMap into a final `serde_json::Map` representation.
 pub fn hashmap_to_json_map<H, T>(map: HashMap<T, Value, H>) -> Map<String, Value>
@@ -93,6 +98,69 @@ pub fn parse_invite(code: &str) -> &str {
     }
 }
 
+/// Retrieves the Id and token of a [`Webhook`] from its URL, such as
+/// `https://discord.com/api/webhooks/{id}/{token}`.
+///
+/// URLs of both `discord.com` and `discordapp.com`, optionally with an API
+/// version, are accepted. If the URL is not a webhook URL, then `None` is
+/// returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::parse_webhook;
+///
+/// let url = "https://discord.com/api/webhooks/245037420704169985/ig5AO-wdVWpCBtUUMxmgsW";
+///
+/// assert_eq!(parse_webhook(url), Some((245037420704169985, "ig5AO-wdVWpCBtUUMxmgsW")));
+/// assert_eq!(parse_webhook("https://discord.com/channels/1/2"), None);
+/// ```
+///
+/// [`Webhook`]: ../model/webhook/struct.Webhook.html
+pub fn parse_webhook(url: &str) -> Option<(u64, &str)> {
+    let url = url
+        .trim_start_matches("http://")
+        .trim_start_matches("https://");
+    let url = url.split(['?', '#']).next()?;
+    let mut segments = url.split('/');
+
+    let host = segments.next()?.to_lowercase();
+    let host = host
+        .trim_start_matches("canary.")
+        .trim_start_matches("ptb.");
+
+    if host != "discord.com" && host != "discordapp.com" {
+        return None;
+    }
+
+    if segments.next()? != "api" {
+        return None;
+    }
+
+    let mut segment = segments.next()?;
+
+    // Skip an API version, such as `v8`.
+    if segment.starts_with('v') && segment[1..].parse::<u8>().is_ok() {
+        segment = segments.next()?;
+    }
+
+    if segment != "webhooks" {
+        return None;
+    }
+
+    let id = segments.next()?.parse().ok()?;
+    let token = segments.next()?;
+
+    if token.is_empty() {
+        return None;
+    }
+
+    match segments.next() {
+        None | Some("") => Some((id, token)),
+        Some(_) => None,
+    }
+}
+
 /// Retrieves an Id from a user mention.
 ///
 /// If the mention is invalid, then `None` is returned.
@@ -446,9 +514,9 @@ pub fn shard_id(guild_id: u64, shard_count: u64) -> u64 {
     (guild_id >> 22) % shard_count
 }
 
-/// A function for doing automatic `read`ing (and the releasing of the guard as well)
-/// This is particularly useful if you just want to use the cache for this one time,
-/// or don't want to be messing with the `RwLock` directly.
+/// A function for running a closure against the cache.
+/// This is particularly useful if you just want to use the cache for this one
+/// time and want to avoid juggling references to it.
 ///
 /// # Examples
 ///
@@ -458,386 +526,22 @@ pub fn shard_id(guild_id: u64, shard_count: u64) -> u64 {
 /// use serenity::utils;
 ///
 /// // assuming that the id is `1234`:
-/// assert_eq!(1234, utils::with_cache(|cache|cache.as_ref().user.id));
+/// assert_eq!(1234, utils::with_cache(&cache, |cache| cache.current_user_id()));
 /// ```
 #[cfg(feature = "cache")]
-pub async fn with_cache<T, F>(cache: impl AsRef<CacheRwLock>, f: F) -> T
+pub fn with_cache<T, F>(cache: impl AsRef<Cache>, f: F) -> T
 where
     F: Fn(&Cache) -> T,
 {
-    let cache = cache.as_ref().read().await;
-    f(&cache)
-}
-
-/// Like [`with_cache`] but as the name says, allows for modifications to be done.
-///
-/// # Examples
-///
-/// Return the bot's id, and changes the shard count
-///
-/// ```rust,ignore
-/// use serenity::utils;
-///
-/// // assuming that the id is `1234`:
-/// assert_eq!(1234, utils::with_cache_mut(|cache| { cache.shard_count = 8;cache.as_ref().user.id }));
-/// ```
-///
-/// [`with_cache`]: #fn.with_cache
-#[cfg(feature = "cache")]
-pub async fn with_cache_mut<T, F>(cache: impl AsRef<CacheRwLock>, mut f: F) -> T
-where
-    F: FnMut(&mut Cache) -> T,
-{
-    let mut cache = cache.as_ref().write().await;
-    f(&mut cache)
-}
-
-/// Struct that allows to alter [`content_safe`]'s behaviour.
-///
-/// [`content_safe`]: fn.content_safe.html
-#[cfg(feature = "cache")]
-#[derive(Clone, Debug)]
-pub struct ContentSafeOptions {
-    clean_role: bool,
-    clean_user: bool,
-    clean_channel: bool,
-    clean_here: bool,
-    clean_everyone: bool,
-    show_discriminator: bool,
-    guild_reference: Option<GuildId>,
-}
-
-#[cfg(feature = "cache")]
-impl ContentSafeOptions {
-    pub fn new() -> Self {
-        ContentSafeOptions::default()
-    }
-
-    /// [`content_safe`] will replace role mentions (`<@&{id}>`) with its name
-    /// prefixed with `@` (`@rolename`) or with `@deleted-role` if the
-    /// identifier is invalid.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn clean_role(mut self, b: bool) -> Self {
-        self.clean_role = b;
-
-        self
-    }
-
-    /// If set to true, [`content_safe`] will replace user mentions
-    /// (`<@!{id}>` or `<@{id}>`) with the user's name prefixed with `@`
-    /// (`@username`) or with `@invalid-user` if the identifier is invalid.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn clean_user(mut self, b: bool) -> Self {
-        self.clean_user = b;
-
-        self
-    }
-
-    /// If set to true, [`content_safe`] will replace channel mentions
-    /// (`<#{id}>`) with the channel's name prefixed with `#`
-    /// (`#channelname`) or with `#deleted-channel` if the identifier is
-    /// invalid.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn clean_channel(mut self, b: bool) -> Self {
-        self.clean_channel = b;
-
-        self
-    }
-
-    /// If set to true, if [`content_safe`] replaces a user mention it will
-    /// add their four digit discriminator with a preceeding `#`,
-    /// turning `@username` to `@username#discriminator`.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn show_discriminator(mut self, b: bool) -> Self {
-        self.show_discriminator = b;
-
-        self
-    }
-
-    /// If set, [`content_safe`] will replace a user mention with the user's
-    /// display name in passed `guild`.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn display_as_member_from<G: Into<GuildId>>(mut self, guild: G) -> Self {
-        self.guild_reference = Some(guild.into());
-
-        self
-    }
-
-    /// If set, [`content_safe`] will replace `@here` with a non-pinging
-    /// alternative.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn clean_here(mut self, b: bool) -> Self {
-        self.clean_here = b;
-
-        self
-    }
-
-    /// If set, [`content_safe`] will replace `@everyone` with a non-pinging
-    /// alternative.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn clean_everyone(mut self, b: bool) -> Self {
-        self.clean_everyone = b;
-
-        self
-    }
-}
-
-#[cfg(feature = "cache")]
-impl Default for ContentSafeOptions {
-    /// Instantiates with all options set to `true`.
-    fn default() -> Self {
-        ContentSafeOptions {
-            clean_role: true,
-            clean_user: true,
-            clean_channel: true,
-            clean_here: true,
-            clean_everyone: true,
-            show_discriminator: true,
-            guild_reference: None,
-        }
-    }
-}
-
-#[cfg(feature = "cache")]
-#[inline]
-async fn clean_roles(cache: impl AsRef<CacheRwLock>, s: &mut String) {
-    let mut progress = 0;
-
-    while let Some(mut mention_start) = s[progress..].find("<@&") {
-        mention_start += progress;
-
-        if let Some(mut mention_end) = s[mention_start..].find('>') {
-            mention_end += mention_start;
-            mention_start += "<@&".len();
-
-            if let Ok(id) = RoleId::from_str(&s[mention_start..mention_end]) {
-                let to_replace = format!("<@&{}>", &s[mention_start..mention_end]);
-
-                *s = if let Some(role) = id._to_role_cached(&cache).await {
-                    s.replace(&to_replace, &format!("@{}", &role.name))
-                } else {
-                    s.replace(&to_replace, &"@deleted-role")
-                };
-            } else {
-                let id = &s[mention_start..mention_end].to_string();
-
-                if !id.is_empty() && id.as_bytes().iter().all(u8::is_ascii_digit) {
-                    let to_replace = format!("<@&{}>", id);
-
-                    *s = s.replace(&to_replace, &"@deleted-role");
-                } else {
-                    progress = mention_end;
-                }
-            }
-        } else {
-            break;
-        }
-    }
-}
-
-#[cfg(feature = "cache")]
-#[inline]
-async fn clean_channels(cache: &AsyncRwLock<Cache>, s: &mut String) {
-    let mut progress = 0;
-
-    while let Some(mut mention_start) = s[progress..].find("<#") {
-        mention_start += progress;
-
-        if let Some(mut mention_end) = s[mention_start..].find('>') {
-            mention_end += mention_start;
-            mention_start += "<#".len();
-
-            if let Ok(id) = ChannelId::from_str(&s[mention_start..mention_end]) {
-                let to_replace = format!("<#{}>", &s[mention_start..mention_end]);
-
-                *s = if let Some(Channel::Guild(channel)) = id._to_channel_cached(&cache).await {
-                    let replacement = format!("#{}", &channel.read().await.name);
-                    s.replace(&to_replace, &replacement)
-                } else {
-                    s.replace(&to_replace, &"#deleted-channel")
-                };
-            } else {
-                let id = &s[mention_start..mention_end].to_string();
-
-                if !id.is_empty() && id.as_bytes().iter().all(u8::is_ascii_digit) {
-                    let to_replace = format!("<#{}>", id);
-
-                    *s = s.replace(&to_replace, &"#deleted-channel");
-                } else {
-                    progress = mention_end;
-                }
-            }
-        } else {
-            break;
-        }
-    }
-}
-
-#[cfg(feature = "cache")]
-#[inline]
-async fn clean_users(
-    cache: &AsyncRwLock<Cache>,
-    s: &mut String,
-    show_discriminator: bool,
-    guild: Option<GuildId>,
-) {
-    let mut progress = 0;
-
-    while let Some(mut mention_start) = s[progress..].find("<@") {
-        mention_start += progress;
-
-        if let Some(mut mention_end) = s[mention_start..].find('>') {
-            mention_end += mention_start;
-            mention_start += "<@".len();
-
-            let has_exclamation = if s[mention_start..]
-                .as_bytes()
-                .get(0)
-                .map_or(false, |c| *c == b'!')
-            {
-                mention_start += "!".len();
-
-                true
-            } else {
-                false
-            };
-
-            if let Ok(id) = UserId::from_str(&s[mention_start..mention_end]) {
-                let replacement = if let Some(guild) = guild {
-                    if let Some(guild) = cache.read().await.guild(&guild) {
-                        if let Some(member) = guild.read().await.members.get(&id) {
-                            if show_discriminator {
-                                format!("@{}", member.distinct())
-                            } else {
-                                format!("@{}", member.display_name())
-                            }
-                        } else {
-                            "@invalid-user".to_string()
-                        }
-                    } else {
-                        "@invalid-user".to_string()
-                    }
-                } else {
-                    let user = cache.read().await.users.get(&id).cloned();
-
-                    if let Some(user) = user {
-                        let user = user.read();
-                        if show_discriminator {
-                            format!("@{}#{:04}", user.name, user.discriminator)
-                        } else {
-                            format!("@{}", user.name)
-                        }
-                    } else {
-                        "@invalid-user".to_string()
-                    }
-                };
-
-                let code_start = if has_exclamation { "<@!" } else { "<@" };
-                let to_replace = format!("{}{}>", code_start, &s[mention_start..mention_end]);
-
-                *s = s.replace(&to_replace, &replacement)
-            } else {
-                let id = &s[mention_start..mention_end].to_string();
-
-                if !id.is_empty() && id.as_bytes().iter().all(u8::is_ascii_digit) {
-                    let code_start = if has_exclamation { "<@!" } else { "<@" };
-                    let to_replace = format!("{}{}>", code_start, id);
-
-                    *s = s.replace(&to_replace, &"@invalid-user");
-                } else {
-                    progress = mention_end;
-                }
-            }
-        } else {
-            break;
-        }
-    }
-}
-
-/// Transforms role, channel, user, `@everyone` and `@here` mentions
-/// into raw text by using the [`Cache`] only.
-///
-/// [`ContentSafeOptions`] decides what kind of mentions should be filtered
-/// and how the raw-text will be displayed.
-///
-/// # Examples
-///
-/// Sanitise an `@everyone` mention.
-///
-/// ```rust
-/// # use std::sync::Arc;
-/// # use serenity::client::{Cache, CacheRwLock};
-/// # use async_std::sync::RwLock;
-/// #
-/// # #[tokio::main]
-/// # async fn main() {
-/// # let cache: CacheRwLock = Arc::new(RwLock::new(Cache::default())).into();
-/// use serenity::utils::{
-///     content_safe,
-///     ContentSafeOptions,
-/// };
-///
-/// let with_mention = "@everyone";
-/// let options = ContentSafeOptions::default();
-/// let without_mention = content_safe(&cache, &with_mention, &options).await;
-///
-/// assert_eq!("@\u{200B}everyone".to_string(), without_mention);
-/// # }
-/// ```
-/// [`ContentSafeOptions`]: struct.ContentSafeOptions.html
-/// [`Cache`]: ../cache/struct.Cache.html
-#[cfg(feature = "cache")]
-pub async fn content_safe(
-    cache: impl AsRef<CacheRwLock>,
-    s: impl AsRef<str>,
-    options: &ContentSafeOptions,
-) -> String {
-    let mut s = s.as_ref().to_string();
-    let cache = cache.as_ref();
-
-    if options.clean_role {
-        clean_roles(&cache, &mut s).await;
-    }
-
-    if options.clean_channel {
-        clean_channels(&cache, &mut s).await;
-    }
-
-    if options.clean_user {
-        clean_users(
-            &cache,
-            &mut s,
-            options.show_discriminator,
-            options.guild_reference,
-        )
-        .await;
-    }
-
-    if options.clean_here {
-        s = s.replace("@here", "@\u{200B}here");
-    }
-
-    if options.clean_everyone {
-        s = s.replace("@everyone", "@\u{200B}everyone");
-    }
-
-    s
+    f(cache.as_ref())
 }
 
 #[cfg(test)]
 mod test {
     #[cfg(feature = "cache")]
-    use crate::cache::CacheRwLock;
+    use crate::cache::Cache;
 
     use super::*;
-    use crate::internal::{AsyncRwLock, SyncRwLock};
 
     #[test]
     fn test_invite_parser() {
@@ -850,6 +554,31 @@ mod test {
         assert_eq!(parse_invite("discordapp.com/invite/abc"), "abc");
     }
 
+    #[test]
+    fn test_webhook_parser() {
+        let token = "ig5AO-wdVWpCBtUUMxmgsW";
+        let id = 245037420704169985;
+
+        assert_eq!(
+            parse_webhook(
+                "https://discord.com/api/webhooks/245037420704169985/ig5AO-wdVWpCBtUUMxmgsW"
+            ),
+            Some((id, token))
+        );
+        assert_eq!(parse_webhook("https://canary.discordapp.com/api/v8/webhooks/245037420704169985/ig5AO-wdVWpCBtUUMxmgsW/?wait=true"), Some((id, token)));
+        assert_eq!(
+            parse_webhook(
+                "https://example.com/api/webhooks/245037420704169985/ig5AO-wdVWpCBtUUMxmgsW"
+            ),
+            None
+        );
+        assert_eq!(
+            parse_webhook("https://discord.com/api/webhooks/245037420704169985/"),
+            None
+        );
+        assert_eq!(parse_webhook("https://discord.com/api/webhooks/245037420704169985/ig5AO-wdVWpCBtUUMxmgsW/messages/1"), None);
+    }
+
     #[test]
     fn test_username_parser() {
         assert_eq!(parse_username("<@12345>").unwrap(), 12_345);
@@ -883,234 +612,219 @@ mod test {
     #[test]
     fn test_content_safe() {
         use crate::model::{prelude::*, user::User, Permissions};
-        use chrono::DateTime;
         use std::{collections::HashMap, sync::Arc};
 
-        run_async_test(async move {
-            let user = User {
-                id: UserId(100000000000000000),
-                avatar: None,
-                bot: false,
-                discriminator: 0000,
-                name: "Crab".to_string(),
-            };
-
-            let mut guild = Guild {
-                afk_channel_id: None,
-                afk_timeout: 0,
-                application_id: None,
-                channels: HashMap::new(),
-                default_message_notifications: DefaultMessageNotificationLevel::All,
-                emojis: HashMap::new(),
-                explicit_content_filter: ExplicitContentFilter::None,
-                features: Vec::new(),
-                icon: None,
-                id: GuildId(381880193251409931),
-                joined_at: DateTime::parse_from_str(
-                    "1983 Apr 13 12:09:14.274 +0000",
-                    "%Y %b %d %H:%M:%S%.3f %z",
-                )
-                .unwrap(),
-                large: false,
-                member_count: 1,
-                members: HashMap::new(),
-                mfa_level: MfaLevel::None,
-                name: "serenity".to_string(),
-                owner_id: UserId(114941315417899012),
-                presences: HashMap::new(),
-                region: "Ferris Island".to_string(),
-                roles: HashMap::new(),
-                splash: None,
-                system_channel_id: None,
-                verification_level: VerificationLevel::None,
-                voice_states: HashMap::new(),
-                description: None,
-                premium_tier: PremiumTier::Tier0,
-                premium_subscription_count: 0,
-                banner: None,
-                vanity_url_code: Some("bruhmoment1".to_string()),
-                preferred_locale: "en-US".to_string(),
-            };
-
-            let member = Member {
-                deaf: false,
-                guild_id: guild.id,
-                joined_at: None,
-                mute: false,
-                nick: Some("Ferris".to_string()),
-                roles: Vec::new(),
-                user: Arc::new(SyncRwLock::new(user.clone())),
-            };
-
-            let role = Role {
-                id: RoleId(333333333333333333),
-                colour: Colour::ORANGE,
-                hoist: true,
-                managed: false,
-                mentionable: true,
-                name: "ferris-club-member".to_string(),
-                permissions: Permissions::all(),
-                position: 0,
-            };
-
-            let channel = GuildChannel {
-                id: ChannelId(111880193700067777),
-                bitrate: None,
-                category_id: None,
-                guild_id: guild.id,
-                kind: ChannelType::Text,
-                last_message_id: None,
-                last_pin_timestamp: None,
-                name: "general".to_string(),
-                permission_overwrites: Vec::new(),
-                position: 0,
-                topic: None,
-                user_limit: None,
-                nsfw: false,
-                slow_mode_rate: Some(0),
-            };
-
-            let cache: CacheRwLock = Arc::new(AsyncRwLock::new(Cache::default())).into();
-
-            {
-                let mut cache = cache.try_write().unwrap();
-                guild.members.insert(user.id, member.clone());
-                guild.roles.insert(role.id, role.clone());
-                cache
-                    .users
-                    .insert(user.id, Arc::new(SyncRwLock::new(user.clone())));
-                cache
-                    .guilds
-                    .insert(guild.id, Arc::new(AsyncRwLock::new(guild.clone())));
-                cache
-                    .channels
-                    .insert(channel.id, Arc::new(AsyncRwLock::new(channel.clone())));
-            }
-
-            let with_user_metions = "<@!100000000000000000> <@!000000000000000000> <@123> <@!123> \
-        <@!123123123123123123123> <@123> <@123123123123123123> <@!invalid> \
-        <@invalid> <@日本語 한국어$§)[/__#\\(/&2032$§#> \
-        <@!i)/==(<<>z/9080)> <@!1231invalid> <@invalid123> \
-        <@123invalid> <@> <@ ";
-
-            let without_user_mentions = "@Crab#0000 @invalid-user @invalid-user @invalid-user \
-        @invalid-user @invalid-user @invalid-user <@!invalid> \
-        <@invalid> <@日本語 한국어$§)[/__#\\(/&2032$§#> \
-        <@!i)/==(<<>z/9080)> <@!1231invalid> <@invalid123> \
-        <@123invalid> <@> <@ ";
-
-            // User mentions
-            let options = ContentSafeOptions::default();
-            assert_eq!(
-                without_user_mentions,
-                content_safe(&cache, with_user_metions, &options).await
-            );
-
-            let options = ContentSafeOptions::default();
-            assert_eq!(
-                format!("@{}#{:04}", user.name, user.discriminator),
-                content_safe(&cache, "<@!100000000000000000>", &options).await
-            );
-
-            let options = ContentSafeOptions::default();
-            assert_eq!(
-                format!("@{}#{:04}", user.name, user.discriminator),
-                content_safe(&cache, "<@100000000000000000>", &options).await
-            );
-
-            let options = options.show_discriminator(false);
-            assert_eq!(
-                format!("@{}", user.name),
-                content_safe(&cache, "<@!100000000000000000>", &options).await
-            );
-
-            let options = options.show_discriminator(false);
-            assert_eq!(
-                format!("@{}", user.name),
-                content_safe(&cache, "<@100000000000000000>", &options).await
-            );
-
-            let options = options.display_as_member_from(guild.id);
-            assert_eq!(
-                format!("@{}", member.nick.unwrap()),
-                content_safe(&cache, "<@!100000000000000000>", &options).await
-            );
-
-            let options = options.clean_user(false);
-            assert_eq!(
-                with_user_metions,
-                content_safe(&cache, with_user_metions, &options).await
-            );
-
-            // Channel mentions
-            let with_channel_mentions = "<#> <#deleted-channel> #deleted-channel <#0> \
-        #unsafe-club <#111880193700067777> <#ferrisferrisferris> \
-        <#000000000000000000>";
-
-            let without_channel_mentions = "<#> <#deleted-channel> #deleted-channel \
-        #deleted-channel #unsafe-club #general <#ferrisferrisferris> \
-        #deleted-channel";
-
-            assert_eq!(
-                without_channel_mentions,
-                content_safe(&cache, with_channel_mentions, &options).await
-            );
-
-            let options = options.clean_channel(false);
-            assert_eq!(
-                with_channel_mentions,
-                content_safe(&cache, with_channel_mentions, &options).await
-            );
-
-            // Role mentions
-            let with_role_mentions = "<@&> @deleted-role <@&9829> \
-        <@&333333333333333333> <@&000000000000000000>";
-
-            let without_role_mentions = "<@&> @deleted-role @deleted-role \
-        @ferris-club-member @deleted-role";
-
-            assert_eq!(
-                without_role_mentions,
-                content_safe(&cache, with_role_mentions, &options).await
-            );
-
-            let options = options.clean_role(false);
-            assert_eq!(
-                with_role_mentions,
-                content_safe(&cache, with_role_mentions, &options).await
-            );
-
-            // Everyone mentions
-            let with_everyone_mention = "@everyone";
-
-            let without_everyone_mention = "@\u{200B}everyone";
-
-            assert_eq!(
-                without_everyone_mention,
-                content_safe(&cache, with_everyone_mention, &options).await
-            );
-
-            let options = options.clean_everyone(false);
-            assert_eq!(
-                with_everyone_mention,
-                content_safe(&cache, with_everyone_mention, &options).await
-            );
-
-            // Here mentions
-            let with_here_mention = "@here";
-
-            let without_here_mention = "@\u{200B}here";
-
-            assert_eq!(
-                without_here_mention,
-                content_safe(&cache, with_here_mention, &options).await
-            );
-
-            let options = options.clean_here(false);
-            assert_eq!(
-                with_here_mention,
-                content_safe(&cache, with_here_mention, &options).await
-            );
-        });
+        let user = User {
+            id: UserId(100000000000000000),
+            avatar: None,
+            bot: false,
+            discriminator: 0000,
+            name: "Crab".to_string(),
+        };
+
+        let mut guild = Guild {
+            afk_channel_id: None,
+            afk_timeout: 0,
+            application_id: None,
+            channels: HashMap::new(),
+            default_message_notifications: DefaultMessageNotificationLevel::All,
+            emojis: HashMap::new(),
+            explicit_content_filter: ExplicitContentFilter::None,
+            features: Vec::new(),
+            icon: None,
+            id: GuildId(381880193251409931),
+            joined_at: Timestamp::parse("1983-04-13T12:09:14.274+00:00").unwrap(),
+            large: false,
+            member_count: 1,
+            members: HashMap::new(),
+            mfa_level: MfaLevel::None,
+            name: "serenity".to_string(),
+            owner_id: UserId(114941315417899012),
+            presences: HashMap::new(),
+            region: "Ferris Island".to_string(),
+            roles: HashMap::new(),
+            splash: None,
+            system_channel_id: None,
+            threads: HashMap::new(),
+            verification_level: VerificationLevel::None,
+            voice_states: HashMap::new(),
+            description: None,
+            premium_tier: PremiumTier::Tier0,
+            premium_subscription_count: 0,
+            banner: None,
+            vanity_url_code: Some("bruhmoment1".to_string()),
+            preferred_locale: "en-US".to_string(),
+        };
+
+        let member = Member {
+            deaf: false,
+            guild_id: guild.id,
+            joined_at: None,
+            mute: false,
+            nick: Some("Ferris".to_string()),
+            roles: Vec::new(),
+            user: user.clone(),
+        };
+
+        let role = Role {
+            id: RoleId(333333333333333333),
+            colour: Colour::ORANGE,
+            hoist: true,
+            managed: false,
+            mentionable: true,
+            name: "ferris-club-member".to_string(),
+            permissions: Permissions::all(),
+            position: 0,
+        };
+
+        let channel = GuildChannel {
+            id: ChannelId(111880193700067777),
+            bitrate: None,
+            category_id: None,
+            guild_id: guild.id,
+            kind: ChannelType::Text,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            name: "general".to_string(),
+            permission_overwrites: Vec::new(),
+            position: 0,
+            topic: None,
+            user_limit: None,
+            nsfw: false,
+            slow_mode_rate: Some(0),
+        };
+
+        let cache = Arc::new(Cache::default());
+
+        guild.members.insert(user.id, member.clone());
+        guild.roles.insert(role.id, role.clone());
+        cache.users.insert(user.id, user.clone());
+        cache.guilds.insert(guild.id, guild.clone());
+        cache.channels.insert(channel.id, channel.clone());
+
+        let with_user_metions = "<@!100000000000000000> <@!000000000000000000> <@123> <@!123> \
+    <@!123123123123123123123> <@123> <@123123123123123123> <@!invalid> \
+    <@invalid> <@日本語 한국어$§)[/__#\\(/&2032$§#> \
+    <@!i)/==(<<>z/9080)> <@!1231invalid> <@invalid123> \
+    <@123invalid> <@> <@ ";
+
+        let without_user_mentions = "@Crab#0000 @invalid-user @invalid-user @invalid-user \
+    @invalid-user @invalid-user @invalid-user <@!invalid> \
+    <@invalid> <@日本語 한국어$§)[/__#\\(/&2032$§#> \
+    <@!i)/==(<<>z/9080)> <@!1231invalid> <@invalid123> \
+    <@123invalid> <@> <@ ";
+
+        // User mentions
+        let options = ContentSafeOptions::default();
+        assert_eq!(
+            without_user_mentions,
+            content_safe(&cache, with_user_metions, &options)
+        );
+
+        let options = ContentSafeOptions::default();
+        assert_eq!(
+            format!("@{}#{:04}", user.name, user.discriminator),
+            content_safe(&cache, "<@!100000000000000000>", &options)
+        );
+
+        let options = ContentSafeOptions::default();
+        assert_eq!(
+            format!("@{}#{:04}", user.name, user.discriminator),
+            content_safe(&cache, "<@100000000000000000>", &options)
+        );
+
+        let options = options.show_discriminator(false);
+        assert_eq!(
+            format!("@{}", user.name),
+            content_safe(&cache, "<@!100000000000000000>", &options)
+        );
+
+        let options = options.show_discriminator(false);
+        assert_eq!(
+            format!("@{}", user.name),
+            content_safe(&cache, "<@100000000000000000>", &options)
+        );
+
+        let options = options.display_as_member_from(guild.id);
+        assert_eq!(
+            format!("@{}", member.nick.unwrap()),
+            content_safe(&cache, "<@!100000000000000000>", &options)
+        );
+
+        let options = options.clean_user(false);
+        assert_eq!(
+            with_user_metions,
+            content_safe(&cache, with_user_metions, &options)
+        );
+
+        // Channel mentions
+        let with_channel_mentions = "<#> <#deleted-channel> #deleted-channel <#0> \
+    #unsafe-club <#111880193700067777> <#ferrisferrisferris> \
+    <#000000000000000000>";
+
+        let without_channel_mentions = "<#> <#deleted-channel> #deleted-channel \
+    #deleted-channel #unsafe-club #general <#ferrisferrisferris> \
+    #deleted-channel";
+
+        assert_eq!(
+            without_channel_mentions,
+            content_safe(&cache, with_channel_mentions, &options)
+        );
+
+        let options = options.clean_channel(false);
+        assert_eq!(
+            with_channel_mentions,
+            content_safe(&cache, with_channel_mentions, &options)
+        );
+
+        // Role mentions
+        let with_role_mentions = "<@&> @deleted-role <@&9829> \
+    <@&333333333333333333> <@&000000000000000000>";
+
+        let without_role_mentions = "<@&> @deleted-role @deleted-role \
+    @ferris-club-member @deleted-role";
+
+        assert_eq!(
+            without_role_mentions,
+            content_safe(&cache, with_role_mentions, &options)
+        );
+
+        let options = options.clean_role(false);
+        assert_eq!(
+            with_role_mentions,
+            content_safe(&cache, with_role_mentions, &options)
+        );
+
+        // Everyone mentions
+        let with_everyone_mention = "@everyone";
+
+        let without_everyone_mention = "@\u{200B}everyone";
+
+        assert_eq!(
+            without_everyone_mention,
+            content_safe(&cache, with_everyone_mention, &options)
+        );
+
+        let options = options.clean_everyone(false);
+        assert_eq!(
+            with_everyone_mention,
+            content_safe(&cache, with_everyone_mention, &options)
+        );
+
+        // Here mentions
+        let with_here_mention = "@here";
+
+        let without_here_mention = "@\u{200B}here";
+
+        assert_eq!(
+            without_here_mention,
+            content_safe(&cache, with_here_mention, &options)
+        );
+
+        let options = options.clean_here(false);
+        assert_eq!(
+            with_here_mention,
+            content_safe(&cache, with_here_mention, &options)
+        );
     }
 }
@@ -0,0 +1,113 @@
+use super::MessageBuilder;
+use crate::model::{guild::Guild, guild::Member, misc::Mentionable};
+
+/// Fills in a message template with details of a member and their guild, such
+/// as for a welcome message.
+///
+/// The following placeholders are replaced:
+///
+/// - `{user}`: the member's username;
+/// - `{user.mention}`: a mention of the member;
+/// - `{guild}`: the guild's name;
+/// - `{member_count}`: the number of members in the guild.
+///
+/// Names are pushed with [`MessageBuilder::push_safe`], so that they can not
+/// add formatting or mention everyone. The rest of the template is used as is.
+/// Unknown placeholders are left untouched, and `{{` and `}}` produce literal
+/// braces.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::model::guild::{Guild, Member};
+/// use serenity::utils::template;
+///
+/// # fn run(member: &Member, guild: &Guild) {
+/// let content = template("Welcome to {guild}, {user.mention}! You are member #{member_count}.", member, guild);
+/// # }
+/// ```
+///
+/// [`MessageBuilder::push_safe`]: struct.MessageBuilder.html#method.push_safe
+pub fn template(template: &str, member: &Member, guild: &Guild) -> String {
+    let mut builder = MessageBuilder::new();
+    let mut rest = template;
+
+    while let Some(index) = rest.find(['{', '}']) {
+        builder.push(&rest[..index]);
+        rest = &rest[index..];
+
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            builder.push(&rest[..1]);
+            rest = &rest[2..];
+
+            continue;
+        }
+
+        let end = match rest.find('}') {
+            Some(end) if rest.starts_with('{') => end,
+            _ => {
+                builder.push(&rest[..1]);
+                rest = &rest[1..];
+
+                continue;
+            }
+        };
+
+        match &rest[1..end] {
+            "user" => builder.push_safe(&member.user.name),
+            "user.mention" => builder.push(member.user.mention()),
+            "guild" => builder.push_safe(&guild.name),
+            "member_count" => builder.push(guild.member_count),
+            _ => builder.push(&rest[..=end]),
+        };
+
+        rest = &rest[end + 1..];
+    }
+
+    builder.push(rest);
+    builder.build()
+}
+
+#[cfg(test)]
+mod test {
+    use super::template;
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_template() {
+        let guild: Guild =
+            serde_json::from_str(include_str!("../../tests/resources/guild_create_1.json"))
+                .unwrap();
+        let member: Member = serde_json::from_value(json!({
+            "deaf": false,
+            "guild_id": guild.id,
+            "joined_at": null,
+            "mute": false,
+            "nick": null,
+            "roles": [],
+            "user": {
+                "id": "7",
+                "avatar": null,
+                "bot": false,
+                "discriminator": "0001",
+                "username": "*ferris*",
+            },
+        }))
+        .unwrap();
+
+        let content = template(
+            "{{hi}} {user} ({user.mention}) joined {guild}, {member_count} {unknown} }",
+            &member,
+            &guild,
+        );
+
+        assert_eq!(
+            content,
+            format!(
+                "{{hi}} \\*ferris\\* (<@7>) joined {}, {} {{unknown}} }}",
+                guild.name, guild.member_count
+            )
+        );
+    }
+}
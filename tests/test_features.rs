@@ -0,0 +1,180 @@
+//! Checks that each meaningful combination of features builds, and that the
+//! API it exposes works on its own.
+//!
+//! Only the modules of the enabled features are compiled, so the tests need to
+//! be run once per combination:
+//!
+//! ```sh
+//! cargo test --test test_features --no-default-features --features "native_tls_backend gateway"
+//! ```
+//!
+//! The combinations checked are:
+//!
+//! - `gateway`, `http`, `builder`, `model` and `cache` on their own;
+//! - `cache http`, without `model`;
+//! - `model cache`, without `gateway`;
+//! - `client` on its own, without `cache` or `model`;
+//! - `client cache`, without `model`;
+//! - `standard_framework`, with and without `cache`;
+//! - the default features.
+#![allow(dead_code, unused_imports)]
+
+#[cfg(feature = "gateway")]
+mod gateway {
+    use serenity::gateway::{ConnectionStage, GatewayError, Shard};
+
+    #[test]
+    fn test_connection_stages() {
+        assert!(ConnectionStage::Connecting.is_connecting());
+        assert!(!ConnectionStage::Connected.is_connecting());
+    }
+}
+
+#[cfg(feature = "http")]
+mod http {
+    use serenity::http::{routing::Route, AttachmentType, Http, LightMethod};
+    use std::path::Path;
+
+    #[test]
+    fn test_http_without_model() {
+        let _http = Http::new_with_token("Bot token");
+
+        let attachment = AttachmentType::from("https://example.com/chart.png");
+        assert_eq!(attachment.filename().as_deref(), Some("chart.png"));
+        assert_eq!(
+            AttachmentType::from(Path::new("cards/rank.png"))
+                .filename()
+                .as_deref(),
+            Some("rank.png")
+        );
+        assert_eq!(
+            Route::channel(1),
+            "https://discordapp.com/api/v6/channels/1"
+        );
+    }
+}
+
+#[cfg(feature = "builder")]
+mod builder {
+    use serde_json::Value;
+    use serenity::builder::{CreateEmbed, CreateMessage};
+
+    #[test]
+    fn test_builders() {
+        let message = CreateMessage::default();
+        assert_eq!(message.0["tts"], Value::Bool(false));
+
+        let mut embed = CreateEmbed::default();
+        embed.attachment("chart.png");
+        assert_eq!(embed.0["image"]["url"], "attachment://chart.png");
+    }
+}
+
+#[cfg(feature = "cache")]
+mod cache {
+    use serenity::cache::Cache;
+    use serenity::model::id::UserId;
+
+    #[test]
+    fn test_empty_cache() {
+        let cache = Cache::default();
+
+        assert!(cache.user(UserId(1)).is_none());
+        assert!(cache.guilds.is_empty());
+    }
+}
+
+#[cfg(all(feature = "cache", feature = "http", not(feature = "model")))]
+mod cache_http_without_model {
+    use serenity::cache::Cache;
+    use serenity::http::{CacheHttp, Http};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_cache_http() {
+        let cache = Arc::new(Cache::default());
+        let http = Http::new_with_token("Bot token");
+        let cache_http = (&cache, &http);
+
+        assert!(cache_http.cache().is_some());
+    }
+}
+
+#[cfg(all(feature = "cache", feature = "utils"))]
+mod content_safe {
+    use serenity::cache::Cache;
+    use serenity::utils::{content_safe, ContentSafeOptions};
+
+    #[test]
+    fn test_content_safe() {
+        let cache = Cache::default();
+        let options = ContentSafeOptions::default().display_as_member_from(1);
+
+        assert_eq!(
+            content_safe(&cache, "<@1> @everyone", &options),
+            "@invalid-user @\u{200B}everyone"
+        );
+    }
+}
+
+#[cfg(all(feature = "model", not(feature = "gateway")))]
+mod model_without_gateway {
+    use serenity::model::prelude::*;
+
+    #[test]
+    fn test_model() {
+        assert!(ChannelType::News.is_text_based());
+        assert_eq!(ChannelId(1).mention().to_string(), "<#1>");
+    }
+}
+
+#[cfg(all(feature = "client", not(feature = "cache")))]
+mod client_without_cache {
+    use serenity::client::{Client, Context, EventHandler};
+
+    struct Handler;
+
+    impl EventHandler for Handler {}
+
+    async fn build() -> serenity::Result<Client> {
+        Client::new("Bot token", Handler).await
+    }
+
+    fn http(ctx: &Context) -> &serenity::http::Http {
+        &ctx.http
+    }
+}
+
+#[cfg(all(feature = "client", not(feature = "cache"), not(feature = "model")))]
+mod client_without_model {
+    use serenity::client::bridge::auto_role::AutoRole;
+
+    #[test]
+    fn test_client_bridges() {
+        let _auto_role = AutoRole::default();
+    }
+}
+
+#[cfg(all(feature = "client", feature = "cache"))]
+mod client_with_cache {
+    use serenity::cache::Cache;
+    use serenity::client::Context;
+    use std::sync::Arc;
+
+    fn cache(ctx: &Context) -> &Arc<Cache> {
+        &ctx.cache
+    }
+}
+
+#[cfg(feature = "standard_framework")]
+mod standard_framework {
+    use serenity::framework::standard::{EffectivePrefixes, StandardFramework};
+
+    #[test]
+    fn test_framework() {
+        let _framework = StandardFramework::new().configure(|c| c.prefixes(vec!["!", "!!"]));
+
+        let prefixes = EffectivePrefixes::default();
+        assert!(prefixes.collisions().is_empty());
+    }
+}
@@ -4,3 +4,38 @@ use serenity::prelude::{Mentionable, SerenityError};
 
 #[cfg(feature = "client")]
 use serenity::prelude::{Client, ClientError};
+
+#[cfg(feature = "standard_framework")]
+mod command_result {
+    use serenity::prelude::{CommandError, CommandResult, SerenityError};
+    use std::error::Error;
+    use std::io;
+
+    fn serenity_error() -> CommandResult {
+        Err(SerenityError::Other("serenity"))?
+    }
+
+    fn io_error() -> CommandResult<u8> {
+        Err(io::Error::other("io"))?
+    }
+
+    fn boxed_error() -> CommandResult {
+        let error: Box<dyn Error + Send + Sync> = "boxed".into();
+
+        Err(error)?
+    }
+
+    fn message(result: CommandResult<impl std::fmt::Debug>) -> String {
+        match result {
+            Err(CommandError::Message(message)) => message,
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_command_result_conversions() {
+        assert_eq!(message(serenity_error()), "serenity");
+        assert_eq!(message(io_error()), "io");
+        assert_eq!(message(boxed_error()), "boxed");
+    }
+}